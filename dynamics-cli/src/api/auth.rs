@@ -1,8 +1,71 @@
 use std::collections::HashMap;
 use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime};
 use tokio::sync::RwLock;
+use super::certificate::{ClientCertificate, CLIENT_ASSERTION_TYPE};
 use super::models::{CredentialSet, TokenInfo};
 
+const DEVICE_CODE_GRANT: &str = "urn:ietf:params:oauth:grant-type:device_code";
+
+/// Extra wait the server asks for with `slow_down`, per RFC 8628
+const SLOW_DOWN_STEP: Duration = Duration::from_secs(5);
+
+/// What the user needs to finish a device code sign-in
+#[derive(Debug, Clone)]
+pub struct DeviceCodePrompt {
+    /// Code to enter on the verification page
+    pub user_code: String,
+    /// Page to open in a browser
+    pub verification_uri: String,
+    /// Ready-made instructions from the server
+    pub message: String,
+    /// How long the code stays valid
+    pub expires_in: Duration,
+    device_code: String,
+    interval: Duration,
+    client_id: String,
+    tenant_id: String,
+}
+
+/// Progress reported while a device code sign-in is under way
+#[derive(Debug, Clone)]
+pub enum DeviceCodeProgress {
+    /// A code was issued; show it to the user
+    CodeIssued(DeviceCodePrompt),
+    /// The user hasn't finished signing in yet
+    Pending,
+    /// The server asked us to poll less often
+    SlowDown { interval: Duration },
+}
+
+/// Why a token poll didn't return a token
+#[derive(Debug, PartialEq)]
+enum DevicePollError {
+    Pending,
+    SlowDown,
+    Expired,
+    Declined,
+    Other(String),
+}
+
+impl DevicePollError {
+    fn from_response(body: &serde_json::Value) -> Self {
+        let error = body.get("error").and_then(|e| e.as_str()).unwrap_or_default();
+        match error {
+            "authorization_pending" => Self::Pending,
+            "slow_down" => Self::SlowDown,
+            "expired_token" | "code_expired" => Self::Expired,
+            "authorization_declined" | "access_denied" => Self::Declined,
+            _ => Self::Other(
+                body.get("error_description")
+                    .and_then(|d| d.as_str())
+                    .unwrap_or(error)
+                    .to_string(),
+            ),
+        }
+    }
+}
+
 /// Manages authentication tokens and credentials for multiple environments
 pub struct AuthManager {
     credentials: Arc<RwLock<HashMap<String, CredentialSet>>>,
@@ -50,8 +113,6 @@ impl AuthManager {
 
     // Authentication methods
     pub async fn authenticate(&self, env_name: &str, host: &str, credentials: &CredentialSet) -> anyhow::Result<()> {
-        use std::time::{SystemTime, Duration};
-
         log::info!("Authenticating to {} for environment {}", host, env_name);
 
         match credentials {
@@ -81,45 +142,210 @@ impl AuthManager {
 
                 if response.status().is_success() {
                     let token_data: serde_json::Value = response.json().await?;
+                    let token_info = token_from_response(&token_data)?;
+
+                    self.tokens.write().await.insert(env_name.to_string(), token_info);
 
-                    if let Some(access_token) = token_data.get("access_token").and_then(|t| t.as_str()) {
-                        // Calculate expiration (default to 1 hour if not provided)
-                        let expires_in = token_data
-                            .get("expires_in")
-                            .and_then(|e| e.as_u64())
-                            .unwrap_or(3600);
-
-                        let expires_at = SystemTime::now() + Duration::from_secs(expires_in);
-
-                        let refresh_token = token_data
-                            .get("refresh_token")
-                            .and_then(|t| t.as_str())
-                            .map(|s| s.to_string());
-
-                        let token_info = TokenInfo {
-                            access_token: access_token.to_string(),
-                            expires_at,
-                            refresh_token,
-                        };
-
-                        self.tokens.write().await.insert(env_name.to_string(), token_info);
-
-                        log::info!("Successfully authenticated for environment {}", env_name);
-                        Ok(())
-                    } else {
-                        anyhow::bail!("No access token in response")
-                    }
+                    log::info!("Successfully authenticated for environment {}", env_name);
+                    Ok(())
                 } else {
                     let error_text = response.text().await?;
                     anyhow::bail!("Authentication failed: {}", error_text)
                 }
             }
+            CredentialSet::Certificate { client_id, tenant_id, cert_path, cert_password } => {
+                let token_url = format!("https://login.microsoftonline.com/{}/oauth2/v2.0/token", tenant_id);
+                let certificate = ClientCertificate::load(cert_path, cert_password.as_deref())?;
+                let assertion = certificate.client_assertion(client_id, &token_url)?;
+                let scope = format!("{}/.default", host.trim_end_matches('/'));
+
+                let response = reqwest::Client::new()
+                    .post(&token_url)
+                    .form(&[
+                        ("grant_type", "client_credentials"),
+                        ("client_id", client_id.as_str()),
+                        ("scope", scope.as_str()),
+                        ("client_assertion_type", CLIENT_ASSERTION_TYPE),
+                        ("client_assertion", assertion.as_str()),
+                    ])
+                    .send()
+                    .await?;
+
+                if response.status().is_success() {
+                    let token_data: serde_json::Value = response.json().await?;
+                    let token_info = token_from_response(&token_data)?;
+
+                    self.tokens.write().await.insert(env_name.to_string(), token_info);
+
+                    log::info!("Successfully authenticated with certificate for environment {}", env_name);
+                    Ok(())
+                } else {
+                    let error_text = response.text().await?;
+                    anyhow::bail!("Certificate authentication failed: {}", error_text)
+                }
+            }
+            CredentialSet::DeviceCode { .. } => {
+                anyhow::bail!(
+                    "Environment '{}' uses device code credentials and needs an interactive sign-in: run 'dynamics-cli auth env sign-in {}'",
+                    env_name,
+                    env_name
+                )
+            }
             _ => {
                 anyhow::bail!("Authentication method not yet implemented: {:?}", credentials)
             }
         }
     }
 
+    /// Sign in with the device code flow
+    ///
+    /// Reports `CodeIssued` first, whose `user_code` and `verification_uri` have to
+    /// be shown to the user, then keeps polling until they finish signing in in a
+    /// browser, the code expires, or they decline.
+    pub async fn authenticate_device_code(
+        &self,
+        env_name: &str,
+        host: &str,
+        client_id: &str,
+        tenant_id: &str,
+        mut on_progress: impl FnMut(DeviceCodeProgress),
+    ) -> anyhow::Result<()> {
+        let prompt = self.request_device_code(host, client_id, tenant_id).await?;
+        on_progress(DeviceCodeProgress::CodeIssued(prompt.clone()));
+        self.poll_device_code(env_name, &prompt, on_progress).await
+    }
+
+    /// Request a device code for signing in to `host`
+    pub async fn request_device_code(&self, host: &str, client_id: &str, tenant_id: &str) -> anyhow::Result<DeviceCodePrompt> {
+        let url = format!("https://login.microsoftonline.com/{}/oauth2/v2.0/devicecode", tenant_id);
+        let scope = format!("{}/.default offline_access", host.trim_end_matches('/'));
+
+        let response = reqwest::Client::new()
+            .post(&url)
+            .form(&[("client_id", client_id), ("scope", scope.as_str())])
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            let error_text = response.text().await?;
+            anyhow::bail!("Failed to request device code: {}", error_text);
+        }
+
+        let data: serde_json::Value = response.json().await?;
+        let field = |name: &str| {
+            data.get(name)
+                .and_then(|v| v.as_str())
+                .map(str::to_string)
+                .ok_or_else(|| anyhow::anyhow!("Device code response is missing '{}'", name))
+        };
+        let seconds = |name: &str, default: u64| data.get(name).and_then(|v| v.as_u64()).unwrap_or(default);
+
+        Ok(DeviceCodePrompt {
+            user_code: field("user_code")?,
+            verification_uri: field("verification_uri")?,
+            message: field("message").unwrap_or_default(),
+            expires_in: Duration::from_secs(seconds("expires_in", 900)),
+            device_code: field("device_code")?,
+            interval: Duration::from_secs(seconds("interval", 5)),
+            client_id: client_id.to_string(),
+            tenant_id: tenant_id.to_string(),
+        })
+    }
+
+    /// Poll the token endpoint until the user completes the sign-in for `prompt`
+    pub async fn poll_device_code(
+        &self,
+        env_name: &str,
+        prompt: &DeviceCodePrompt,
+        mut on_progress: impl FnMut(DeviceCodeProgress),
+    ) -> anyhow::Result<()> {
+        let url = format!("https://login.microsoftonline.com/{}/oauth2/v2.0/token", prompt.tenant_id);
+        let client = reqwest::Client::new();
+        let deadline = Instant::now() + prompt.expires_in;
+        let mut interval = prompt.interval;
+
+        loop {
+            tokio::time::sleep(interval).await;
+            if Instant::now() >= deadline {
+                anyhow::bail!("The device code expired before sign-in was completed");
+            }
+
+            let response = client
+                .post(&url)
+                .form(&[
+                    ("grant_type", DEVICE_CODE_GRANT),
+                    ("client_id", prompt.client_id.as_str()),
+                    ("device_code", prompt.device_code.as_str()),
+                ])
+                .send()
+                .await?;
+
+            let status = response.status();
+            let body: serde_json::Value = response.json().await?;
+
+            if status.is_success() {
+                let token_info = token_from_response(&body)?;
+                self.tokens.write().await.insert(env_name.to_string(), token_info);
+                log::info!("Device code sign-in completed for environment {}", env_name);
+                return Ok(());
+            }
+
+            match DevicePollError::from_response(&body) {
+                DevicePollError::Pending => on_progress(DeviceCodeProgress::Pending),
+                DevicePollError::SlowDown => {
+                    interval += SLOW_DOWN_STEP;
+                    on_progress(DeviceCodeProgress::SlowDown { interval });
+                }
+                DevicePollError::Expired => {
+                    anyhow::bail!("The device code expired before sign-in was completed")
+                }
+                DevicePollError::Declined => anyhow::bail!("Sign-in was declined"),
+                DevicePollError::Other(message) => anyhow::bail!("Device code sign-in failed: {}", message),
+            }
+        }
+    }
+
+    /// Exchange a refresh token for a new access token
+    ///
+    /// Only delegated flows (`UsernamePassword`, `DeviceCode`) get refresh tokens;
+    /// app-only credentials can simply authenticate again. When the response has
+    /// no new refresh token the old one is kept, since it stays valid.
+    pub async fn refresh_token(
+        &self,
+        env_name: &str,
+        host: &str,
+        credentials: &CredentialSet,
+        refresh_token: &str,
+    ) -> anyhow::Result<String> {
+        let Some((token_url, form)) = refresh_request(host, credentials, refresh_token) else {
+            anyhow::bail!("These credentials don't use refresh tokens");
+        };
+
+        log::debug!("Refreshing token for environment {}", env_name);
+        let response = reqwest::Client::new()
+            .post(&token_url)
+            .form(&form)
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            let error_text = response.text().await?;
+            anyhow::bail!("Token refresh failed: {}", error_text);
+        }
+
+        let token_data: serde_json::Value = response.json().await?;
+        let mut token_info = token_from_response(&token_data)?;
+        if token_info.refresh_token.is_none() {
+            token_info.refresh_token = Some(refresh_token.to_string());
+        }
+
+        let access_token = token_info.access_token.clone();
+        self.tokens.write().await.insert(env_name.to_string(), token_info);
+
+        log::info!("Refreshed token for environment {}", env_name);
+        Ok(access_token)
+    }
+
     /// Get token for environment (used by ClientManager)
     pub async fn get_token(&self, env_name: &str) -> anyhow::Result<TokenInfo> {
         self.tokens.read().await.get(env_name)
@@ -129,15 +355,118 @@ impl AuthManager {
 
     /// Check if token exists and is valid for environment
     pub async fn has_valid_token(&self, env_name: &str) -> bool {
-        if let Some(token_info) = self.tokens.read().await.get(env_name) {
-            // Check if token is still valid
-            if let Ok(elapsed) = token_info.expires_at.elapsed() {
-                elapsed.as_secs() == 0 // Token is valid if it hasn't elapsed
-            } else {
-                true // If we can't determine elapsed time, assume valid
-            }
-        } else {
-            false
-        }
+        self.tokens.read().await
+            .get(env_name)
+            .is_some_and(|token_info| !token_info.is_expired())
     }
-}
\ No newline at end of file
+}
+
+/// Token endpoint and form for a `refresh_token` grant, matching the endpoint
+/// the credentials originally signed in with
+fn refresh_request(
+    host: &str,
+    credentials: &CredentialSet,
+    refresh_token: &str,
+) -> Option<(String, Vec<(&'static str, String)>)> {
+    match credentials {
+        CredentialSet::UsernamePassword { client_id, client_secret, .. } => Some((
+            "https://login.microsoftonline.com/common/oauth2/token".to_string(),
+            vec![
+                ("grant_type", "refresh_token".to_string()),
+                ("client_id", client_id.clone()),
+                ("client_secret", client_secret.clone()),
+                ("refresh_token", refresh_token.to_string()),
+                ("resource", host.to_string()),
+            ],
+        )),
+        CredentialSet::DeviceCode { client_id, tenant_id } => Some((
+            format!("https://login.microsoftonline.com/{}/oauth2/v2.0/token", tenant_id),
+            vec![
+                ("grant_type", "refresh_token".to_string()),
+                ("client_id", client_id.clone()),
+                ("refresh_token", refresh_token.to_string()),
+                ("scope", format!("{}/.default offline_access", host.trim_end_matches('/'))),
+            ],
+        )),
+        CredentialSet::ClientCredentials { .. } | CredentialSet::Certificate { .. } => None,
+    }
+}
+
+/// Build a `TokenInfo` from a successful token endpoint response
+fn token_from_response(token_data: &serde_json::Value) -> anyhow::Result<TokenInfo> {
+    let access_token = token_data
+        .get("access_token")
+        .and_then(|t| t.as_str())
+        .ok_or_else(|| anyhow::anyhow!("No access token in response"))?;
+
+    // Calculate expiration (default to 1 hour if not provided)
+    let expires_in = token_data
+        .get("expires_in")
+        .and_then(|e| e.as_u64())
+        .unwrap_or(3600);
+
+    let refresh_token = token_data
+        .get("refresh_token")
+        .and_then(|t| t.as_str())
+        .map(|s| s.to_string());
+
+    Ok(TokenInfo {
+        access_token: access_token.to_string(),
+        expires_at: SystemTime::now() + Duration::from_secs(expires_in),
+        refresh_token,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_device_poll_errors() {
+        assert_eq!(DevicePollError::from_response(&json!({"error": "authorization_pending"})), DevicePollError::Pending);
+        assert_eq!(DevicePollError::from_response(&json!({"error": "slow_down"})), DevicePollError::SlowDown);
+        assert_eq!(DevicePollError::from_response(&json!({"error": "expired_token"})), DevicePollError::Expired);
+        assert_eq!(DevicePollError::from_response(&json!({"error": "authorization_declined"})), DevicePollError::Declined);
+        assert_eq!(
+            DevicePollError::from_response(&json!({"error": "invalid_grant", "error_description": "AADSTS70000: bad grant"})),
+            DevicePollError::Other("AADSTS70000: bad grant".to_string())
+        );
+    }
+
+    #[test]
+    fn test_refresh_request_per_credential_type() {
+        let device_code = CredentialSet::DeviceCode {
+            client_id: "client".to_string(),
+            tenant_id: "tenant".to_string(),
+        };
+        let (url, form) = refresh_request("https://org.crm.dynamics.com/", &device_code, "rt").unwrap();
+
+        assert_eq!(url, "https://login.microsoftonline.com/tenant/oauth2/v2.0/token");
+        assert!(form.contains(&("grant_type", "refresh_token".to_string())));
+        assert!(form.contains(&("refresh_token", "rt".to_string())));
+        assert!(form.contains(&("scope", "https://org.crm.dynamics.com/.default offline_access".to_string())));
+
+        let client_credentials = CredentialSet::ClientCredentials {
+            client_id: "client".to_string(),
+            client_secret: "secret".to_string(),
+            tenant_id: "tenant".to_string(),
+        };
+        assert!(refresh_request("https://org.crm.dynamics.com", &client_credentials, "rt").is_none());
+    }
+
+    #[test]
+    fn test_token_from_response() {
+        let token = token_from_response(&json!({
+            "access_token": "abc",
+            "expires_in": 60,
+            "refresh_token": "def"
+        }))
+        .unwrap();
+
+        assert_eq!(token.access_token, "abc");
+        assert_eq!(token.refresh_token.as_deref(), Some("def"));
+        assert!(token.expires_at > SystemTime::now());
+        assert!(token_from_response(&json!({"error": "nope"})).is_err());
+    }
+}
@@ -0,0 +1,215 @@
+//! Client assertions for certificate credentials
+//!
+//! Instead of a client secret, Entra ID accepts a JWT signed with the private key
+//! of a certificate uploaded to the app registration. The JWT header carries the
+//! certificate's SHA-1 thumbprint (`x5t`) so the server knows which one to check
+//! the signature against.
+
+use anyhow::{Context, Result};
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use openssl::hash::MessageDigest;
+use openssl::pkcs12::Pkcs12;
+use openssl::pkey::{PKey, Private};
+use openssl::sign::Signer;
+use openssl::x509::X509;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// `client_assertion_type` for JWT client assertions
+pub const CLIENT_ASSERTION_TYPE: &str = "urn:ietf:params:oauth:client-assertion-type:jwt-bearer";
+
+/// How long a signed assertion stays valid
+const ASSERTION_LIFETIME_SECS: u64 = 600;
+
+/// A certificate together with its private key
+pub struct ClientCertificate {
+    certificate: X509,
+    key: PKey<Private>,
+}
+
+impl ClientCertificate {
+    /// Load a PEM file (certificate and private key) or a PFX/PKCS#12 bundle
+    ///
+    /// The password decrypts the PFX, or an encrypted private key in a PEM file.
+    pub fn load(path: impl AsRef<Path>, password: Option<&str>) -> Result<Self> {
+        let path = path.as_ref();
+        let bytes = std::fs::read(path)
+            .with_context(|| format!("Failed to read certificate '{}'", path.display()))?;
+
+        let certificate = if bytes.starts_with(b"-----BEGIN") {
+            Self::from_pem(&bytes, password)
+        } else {
+            Self::from_pkcs12(&bytes, password.unwrap_or_default())
+        };
+        certificate.with_context(|| format!("Failed to load certificate '{}'", path.display()))
+    }
+
+    /// Parse a PEM file holding both the certificate and its private key
+    pub fn from_pem(pem: &[u8], password: Option<&str>) -> Result<Self> {
+        let certificate = X509::from_pem(pem).context("No certificate found in PEM file")?;
+        let key = match password {
+            Some(password) => PKey::private_key_from_pem_passphrase(pem, password.as_bytes()),
+            None => PKey::private_key_from_pem(pem),
+        }
+        .context("No usable private key found in PEM file (is it encrypted?)")?;
+
+        Self::new(certificate, key)
+    }
+
+    /// Parse a PFX/PKCS#12 bundle
+    pub fn from_pkcs12(der: &[u8], password: &str) -> Result<Self> {
+        let parsed = Pkcs12::from_der(der)
+            .context("Not a PEM or PFX file")?
+            .parse2(password)
+            .context("Failed to open PFX file (wrong password?)")?;
+
+        let certificate = parsed.cert.context("PFX file has no certificate")?;
+        let key = parsed.pkey.context("PFX file has no private key")?;
+        Self::new(certificate, key)
+    }
+
+    fn new(certificate: X509, key: PKey<Private>) -> Result<Self> {
+        if key.rsa().is_err() {
+            anyhow::bail!("Certificate key must be RSA");
+        }
+        if !certificate.public_key()?.public_eq(&key) {
+            anyhow::bail!("Private key does not match the certificate");
+        }
+        Ok(Self { certificate, key })
+    }
+
+    /// Base64url SHA-1 thumbprint, as used in the `x5t` header
+    pub fn thumbprint(&self) -> Result<String> {
+        let digest = self.certificate.digest(MessageDigest::sha1())?;
+        Ok(URL_SAFE_NO_PAD.encode(digest))
+    }
+
+    /// Hex SHA-1 thumbprint, as shown in the Azure portal
+    pub fn thumbprint_hex(&self) -> Result<String> {
+        let digest = self.certificate.digest(MessageDigest::sha1())?;
+        Ok(digest.iter().map(|b| format!("{:02X}", b)).collect())
+    }
+
+    /// Sign a client assertion for `client_id`, addressed to the token endpoint
+    pub fn client_assertion(&self, client_id: &str, token_url: &str) -> Result<String> {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+
+        let header = serde_json::json!({
+            "alg": "RS256",
+            "typ": "JWT",
+            "x5t": self.thumbprint()?,
+        });
+        let claims = serde_json::json!({
+            "aud": token_url,
+            "iss": client_id,
+            "sub": client_id,
+            "jti": uuid::Uuid::new_v4().to_string(),
+            "nbf": now,
+            "iat": now,
+            "exp": now + ASSERTION_LIFETIME_SECS,
+        });
+
+        let signing_input = format!(
+            "{}.{}",
+            URL_SAFE_NO_PAD.encode(serde_json::to_vec(&header)?),
+            URL_SAFE_NO_PAD.encode(serde_json::to_vec(&claims)?)
+        );
+
+        let mut signer = Signer::new(MessageDigest::sha256(), &self.key)?;
+        signer.update(signing_input.as_bytes())?;
+        let signature = signer.sign_to_vec()?;
+
+        Ok(format!("{}.{}", signing_input, URL_SAFE_NO_PAD.encode(signature)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use openssl::asn1::Asn1Time;
+    use openssl::rsa::Rsa;
+    use openssl::sign::Verifier;
+    use openssl::x509::X509NameBuilder;
+
+    fn self_signed() -> (X509, PKey<Private>) {
+        let key = PKey::from_rsa(Rsa::generate(2048).unwrap()).unwrap();
+
+        let mut name = X509NameBuilder::new().unwrap();
+        name.append_entry_by_text("CN", "dynamics-cli-test").unwrap();
+        let name = name.build();
+
+        let mut builder = X509::builder().unwrap();
+        builder.set_version(2).unwrap();
+        builder.set_subject_name(&name).unwrap();
+        builder.set_issuer_name(&name).unwrap();
+        builder.set_pubkey(&key).unwrap();
+        builder.set_not_before(&Asn1Time::days_from_now(0).unwrap()).unwrap();
+        builder.set_not_after(&Asn1Time::days_from_now(1).unwrap()).unwrap();
+        builder.sign(&key, MessageDigest::sha256()).unwrap();
+
+        (builder.build(), key)
+    }
+
+    fn decode_segment(segment: &str) -> serde_json::Value {
+        serde_json::from_slice(&URL_SAFE_NO_PAD.decode(segment).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_assertion_is_signed_with_certificate_key() {
+        let (cert, key) = self_signed();
+        let mut pem = cert.to_pem().unwrap();
+        pem.extend(key.private_key_to_pem_pkcs8().unwrap());
+
+        let certificate = ClientCertificate::from_pem(&pem, None).unwrap();
+        let token_url = "https://login.microsoftonline.com/tenant/oauth2/v2.0/token";
+        let assertion = certificate.client_assertion("client-id", token_url).unwrap();
+
+        let parts: Vec<&str> = assertion.split('.').collect();
+        assert_eq!(parts.len(), 3);
+
+        let header = decode_segment(parts[0]);
+        assert_eq!(header["alg"], "RS256");
+        assert_eq!(
+            header["x5t"],
+            URL_SAFE_NO_PAD.encode(cert.digest(MessageDigest::sha1()).unwrap())
+        );
+
+        let claims = decode_segment(parts[1]);
+        assert_eq!(claims["aud"], token_url);
+        assert_eq!(claims["iss"], "client-id");
+        assert_eq!(claims["sub"], "client-id");
+
+        let signature = URL_SAFE_NO_PAD.decode(parts[2]).unwrap();
+        let mut verifier = Verifier::new(MessageDigest::sha256(), &key).unwrap();
+        verifier.update(format!("{}.{}", parts[0], parts[1]).as_bytes()).unwrap();
+        assert!(verifier.verify(&signature).unwrap());
+    }
+
+    #[test]
+    fn test_password_protected_pfx() {
+        let (cert, key) = self_signed();
+        let pfx = Pkcs12::builder()
+            .name("dynamics-cli-test")
+            .pkey(&key)
+            .cert(&cert)
+            .build2("secret")
+            .unwrap()
+            .to_der()
+            .unwrap();
+
+        let certificate = ClientCertificate::from_pkcs12(&pfx, "secret").unwrap();
+        assert_eq!(certificate.thumbprint_hex().unwrap().len(), 40);
+        assert!(ClientCertificate::from_pkcs12(&pfx, "wrong").is_err());
+    }
+
+    #[test]
+    fn test_mismatched_key_is_rejected() {
+        let (cert, _) = self_signed();
+        let (_, other_key) = self_signed();
+        let mut pem = cert.to_pem().unwrap();
+        pem.extend(other_key.private_key_to_pem_pkcs8().unwrap());
+
+        assert!(ClientCertificate::from_pem(&pem, None).is_err());
+    }
+}
@@ -1,11 +1,26 @@
+use super::compression::{self, EncodedBody};
 use super::constants::{self, headers, methods};
-use super::operations::{Operation, OperationResult, BatchRequestBuilder, BatchResponseParser};
+use super::error::ApiError;
+use super::operations::{Operation, OperationResult, BatchMode, BatchRequestBuilder, BatchResponseParser};
+use super::models::{HealthCheck, OrganizationInfo, RetrieveCurrentOrganizationResponse, WhoAmIResponse};
 use super::query::{Query, QueryResult, QueryResponse};
-use super::resilience::{RetryPolicy, RetryConfig, ResilienceConfig, RateLimiter, ApiLogger, OperationContext, OperationMetrics, MetricsCollector};
+use super::resilience::{RetryPolicy, RetryConfig, ResilienceConfig, RateLimiter, ApiLogger, OperationContext, OperationMetrics, MetricsCollector, TimeoutConfig, DEFAULT_BUCKET};
 use serde_json::Value;
 use std::collections::HashMap;
+use std::sync::Arc;
 use std::time::Duration;
 
+/// Outcome of a conditional GET (`If-None-Match`)
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConditionalRecord {
+    /// HTTP 304: the record still has the ETag the caller already holds
+    NotModified,
+    /// The record changed (or no ETag was given); carries the fresh record
+    Modified(Value),
+    /// HTTP 404: the record no longer exists
+    NotFound,
+}
+
 /// Modern Dynamics 365 Web API client with connection pooling
 #[derive(Clone)]
 pub struct DynamicsClient {
@@ -13,21 +28,28 @@ pub struct DynamicsClient {
     http_client: reqwest::Client,
     access_token: String,
     retry_policy: RetryPolicy, // Default retry policy for backwards compatibility
-    rate_limiter: RateLimiter, // Global rate limiter for this client instance
+    rate_limiter: RateLimiter, // Rate limiter, possibly shared with clients for other environments
+    rate_limit_key: String, // Bucket this client draws from (its environment)
     api_logger: ApiLogger, // Structured logger for operations
     metrics_collector: MetricsCollector, // Performance metrics collector
+    caller_id: Option<uuid::Uuid>, // systemuser to impersonate (MSCRMCallerID)
+    return_representation: bool, // Ask for the written entity back on create/update/upsert
+    entity_set_overrides: Arc<HashMap<String, String>>, // Configured entity set names, checked before pluralizing
+    compression: bool, // gzip responses (Accept-Encoding) and large request bodies
+    timeouts: TimeoutConfig, // Per-request timeouts by operation type
+    who_am_i: Arc<tokio::sync::OnceCell<WhoAmIResponse>>, // Caller identity, fetched once per client
 }
 
 impl DynamicsClient {
-    /// Apply rate limiting using the client's global rate limiter
+    /// Apply rate limiting using this client's environment bucket
     async fn apply_rate_limiting(&self) -> anyhow::Result<()> {
-        self.rate_limiter.acquire().await;
+        self.rate_limiter.acquire_for(&self.rate_limit_key).await;
         Ok(())
     }
 
     /// Get rate limiter statistics for monitoring
     pub fn rate_limiter_stats(&self) -> crate::api::resilience::RateLimiterStats {
-        self.rate_limiter.stats()
+        self.rate_limiter.stats_for(&self.rate_limit_key)
     }
 
     /// Get performance metrics snapshot
@@ -52,6 +74,13 @@ impl DynamicsClient {
             rate_limiter: RateLimiter::new(ResilienceConfig::default().rate_limit),
             api_logger: ApiLogger::new(ResilienceConfig::default().monitoring),
             metrics_collector: MetricsCollector::new(ResilienceConfig::default().monitoring),
+            caller_id: None,
+            rate_limit_key: DEFAULT_BUCKET.to_string(),
+            return_representation: false,
+            entity_set_overrides: Arc::default(),
+            compression: false,
+            timeouts: TimeoutConfig::default(),
+            who_am_i: Arc::default(),
         }
     }
 
@@ -65,6 +94,13 @@ impl DynamicsClient {
             rate_limiter: RateLimiter::new(ResilienceConfig::default().rate_limit),
             api_logger: ApiLogger::new(ResilienceConfig::default().monitoring),
             metrics_collector: MetricsCollector::new(ResilienceConfig::default().monitoring),
+            caller_id: None,
+            rate_limit_key: DEFAULT_BUCKET.to_string(),
+            return_representation: false,
+            entity_set_overrides: Arc::default(),
+            compression: false,
+            timeouts: TimeoutConfig::default(),
+            who_am_i: Arc::default(),
         }
     }
 
@@ -87,7 +123,117 @@ impl DynamicsClient {
             rate_limiter: RateLimiter::new(ResilienceConfig::default().rate_limit),
             api_logger: ApiLogger::new(ResilienceConfig::default().monitoring),
             metrics_collector: MetricsCollector::new(ResilienceConfig::default().monitoring),
+            caller_id: None,
+            rate_limit_key: DEFAULT_BUCKET.to_string(),
+            return_representation: false,
+            entity_set_overrides: Arc::default(),
+            compression: false,
+            timeouts: TimeoutConfig::default(),
+            who_am_i: Arc::default(),
+        }
+    }
+
+    /// Draw from `environment`'s bucket of a (shared) rate limiter
+    ///
+    /// Clients for the same environment then share one request budget, while
+    /// other environments are throttled independently.
+    pub fn with_rate_limiter(mut self, rate_limiter: RateLimiter, environment: impl Into<String>) -> Self {
+        self.rate_limiter = rate_limiter;
+        self.rate_limit_key = environment.into();
+        self
+    }
+
+    /// Send every request from this client on behalf of another user
+    ///
+    /// Adds the `MSCRMCallerID` header with the user's `systemuserid`, so records
+    /// are created and modified as (and owned by) that user, and audited as such.
+    /// Clone the client first to scope impersonation to part of the work; a
+    /// `$batch` can still switch users per changeset through
+    /// `BatchRequestBuilder::impersonate`.
+    ///
+    /// Works with any auth flow, but the authenticated identity needs the
+    /// "Act on Behalf of Another User" privilege (e.g. the Delegate role). In
+    /// practice that means an application user for `ClientCredentials` or
+    /// `Certificate` credentials; `UsernamePassword` and `DeviceCode` only work
+    /// if that interactive user has been granted the privilege too.
+    pub fn with_impersonation(mut self, user_id: uuid::Uuid) -> Self {
+        self.caller_id = Some(user_id);
+        // WhoAmI now answers for the impersonated user
+        self.who_am_i = Arc::default();
+        self
+    }
+
+    /// Have creates, updates and upserts return the written entity
+    ///
+    /// Sends `Prefer: return=representation`, so `OperationResult::data` holds the
+    /// record as stored (including server-computed fields) rather than nothing.
+    pub fn with_return_representation(mut self, enabled: bool) -> Self {
+        self.return_representation = enabled;
+        self
+    }
+
+    /// Use configured entity set names instead of guessing them for these entities
+    ///
+    /// Keys are lowercase logical names; anything not listed is still pluralized.
+    pub fn with_entity_set_overrides(mut self, overrides: Arc<HashMap<String, String>>) -> Self {
+        self.entity_set_overrides = overrides;
+        self
+    }
+
+    /// Entity set name used in URLs for a logical entity name
+    pub fn entity_set_name(&self, entity_name: &str) -> String {
+        super::pluralization::resolve_entity_set_name(entity_name, &self.entity_set_overrides)
+    }
+
+    /// Ask for gzipped responses and gzip large request bodies
+    ///
+    /// Off by default, since some proxies mishandle `Content-Encoding` on requests.
+    pub fn with_compression(mut self, enabled: bool) -> Self {
+        self.compression = enabled;
+        self
+    }
+
+    /// Give up on metadata, query and write requests after these durations
+    ///
+    /// A timed-out request is retried like other transient failures; when
+    /// retries run out it fails with `ApiError::Timeout`.
+    pub fn with_timeouts(mut self, timeouts: TimeoutConfig) -> Self {
+        self.timeouts = timeouts;
+        self
+    }
+
+    /// The `systemuserid` this client impersonates, if any
+    pub fn impersonated_user(&self) -> Option<uuid::Uuid> {
+        self.caller_id
+    }
+
+    /// Headers sent on every request: impersonation and, when compression is on, `Accept-Encoding`
+    fn request_headers(&self) -> reqwest::header::HeaderMap {
+        let mut headers = reqwest::header::HeaderMap::new();
+        if let Some(caller_id) = self.caller_id {
+            let value = reqwest::header::HeaderValue::from_str(&caller_id.to_string())
+                .expect("UUID is a valid header value");
+            headers.insert(constants::headers::MSCRM_CALLER_ID, value);
+        }
+        if self.compression {
+            headers.insert(
+                reqwest::header::ACCEPT_ENCODING,
+                reqwest::header::HeaderValue::from_static("gzip"),
+            );
+        }
+        headers
+    }
+
+    /// `Prefer: return=representation` header for writes (empty unless enabled)
+    fn representation_headers(&self) -> reqwest::header::HeaderMap {
+        let mut headers = reqwest::header::HeaderMap::new();
+        if self.return_representation {
+            headers.insert(
+                reqwest::header::HeaderName::from_static("prefer"),
+                reqwest::header::HeaderValue::from_static(constants::headers::PREFER_RETURN_REPRESENTATION),
+            );
         }
+        headers
     }
 
     /// Execute a single operation
@@ -100,19 +246,32 @@ impl DynamicsClient {
                     "CreateWithRefs operation can only be executed within a batch changeset. Use execute_batch() instead."
                 ))
             }
-            Operation::Update { entity, id, data } => self.update_record(entity, id, data, resilience).await,
-            Operation::Delete { entity, id } => self.delete_record(entity, id, resilience).await,
-            Operation::Upsert { entity, key_field, key_value, data } => {
-                self.upsert_record(entity, key_field, key_value, data, resilience).await
+            Operation::Update { entity, id, data, etag } => {
+                self.update_record(entity, id, data, etag.as_deref(), resilience).await
             }
+            Operation::Delete { entity, id } => self.delete_record(entity, id, resilience).await,
+            Operation::Upsert { entity, keys, data } => self.upsert_record(entity, keys, data, resilience).await,
             Operation::AssociateRef { entity, entity_ref, navigation_property, target_ref } => {
                 self.associate_ref(entity, entity_ref, navigation_property, target_ref, resilience).await
             }
+            Operation::DisassociateRef { entity, entity_ref, navigation_property, target_id } => {
+                self.disassociate_ref(entity, entity_ref, navigation_property, target_id, resilience).await
+            }
         }
     }
 
     /// Execute multiple operations as a batch
     pub async fn execute_batch(&self, operations: &[Operation], resilience: &ResilienceConfig) -> anyhow::Result<Vec<OperationResult>> {
+        self.execute_batch_with_mode(operations, BatchMode::ChangeSet, resilience).await
+    }
+
+    /// Execute multiple operations as a batch, either atomically or as independent requests
+    pub async fn execute_batch_with_mode(
+        &self,
+        operations: &[Operation],
+        mode: BatchMode,
+        resilience: &ResilienceConfig,
+    ) -> anyhow::Result<Vec<OperationResult>> {
         if operations.is_empty() {
             return Ok(Vec::new());
         }
@@ -122,7 +281,7 @@ impl DynamicsClient {
             return Ok(vec![result]);
         }
 
-        self.execute_batch_request(operations, resilience).await
+        self.execute_batch_request(operations, mode, resilience).await
     }
 
     /// Execute an OData query
@@ -130,10 +289,12 @@ impl DynamicsClient {
         let url = constants::entity_endpoint(&self.base_url, &query.entity);
         let params = query.to_query_params();
 
-        let response = self.retry_policy.execute(|| async {
+        let response = self.retry_policy.execute_response(|| async {
             self.http_client
                 .get(&url)
                 .bearer_auth(&self.access_token)
+                .timeout(self.timeouts.query)
+                .headers(self.request_headers())
                 .header("Accept", headers::CONTENT_TYPE_JSON)
                 .header("OData-Version", headers::ODATA_VERSION)
                 .header("Prefer", "odata.include-annotations=\"OData.Community.Display.V1.FormattedValue\"")
@@ -152,22 +313,27 @@ impl DynamicsClient {
         let encoded_fetchxml = urlencoding::encode(fetchxml);
 
         // Pluralize entity name for the endpoint
-        let plural_entity = super::pluralization::pluralize_entity_name(entity_name);
+        let plural_entity = self.entity_set_name(entity_name);
 
-        let response = self.retry_policy.execute(|| async {
+        let started = std::time::Instant::now();
+        let response = self.retry_policy.execute_response(|| async {
             self.http_client
                 .get(&format!("{}{}/{}?fetchXml={}", self.base_url, constants::api_path(), plural_entity, encoded_fetchxml))
                 .bearer_auth(&self.access_token)
+                .timeout(self.timeouts.query)
+                .headers(self.request_headers())
                 .header("Accept", headers::CONTENT_TYPE_JSON)
                 .header("OData-Version", headers::ODATA_VERSION)
                 .header("OData-MaxVersion", headers::ODATA_VERSION)
                 .header("Prefer", headers::PREFER_INCLUDE_ANNOTATIONS)
                 .send()
                 .await
-        }).await?;
+        }).await.inspect_err(|_| self.record_request("fetchxml", entity_name, started, None, false))?;
 
-        let query_result = self.parse_query_response(response).await?;
-        match query_result.data {
+        let status = response.status().as_u16();
+        let query_result = self.parse_query_response(response).await;
+        self.record_request("fetchxml", entity_name, started, Some(status), query_result.as_ref().is_ok_and(|r| r.success));
+        match query_result?.data {
             Some(query_response) => {
                 // Return the structured OData response
                 let mut result = serde_json::json!({
@@ -179,6 +345,12 @@ impl DynamicsClient {
                 if let Some(next_link) = query_response.next_link {
                     result["@odata.nextLink"] = serde_json::Value::from(next_link);
                 }
+                if let Some(paging_cookie) = query_response.paging_cookie {
+                    result[constants::annotations::FETCHXML_PAGING_COOKIE] = serde_json::Value::from(paging_cookie);
+                }
+                if query_response.more_records {
+                    result[constants::annotations::MORE_RECORDS] = serde_json::Value::from(true);
+                }
                 Ok(result)
             },
             None => Ok(serde_json::json!({"value": []}))
@@ -208,10 +380,12 @@ impl DynamicsClient {
             url.push_str(&format!("?$select={}", fields.join(",")));
         }
 
-        let response = self.retry_policy.execute(|| async {
+        let response = self.retry_policy.execute_response(|| async {
             self.http_client
                 .get(&url)
                 .bearer_auth(&self.access_token)
+                .timeout(self.timeouts.query)
+                .headers(self.request_headers())
                 .header("Accept", headers::CONTENT_TYPE_JSON)
                 .header("OData-Version", headers::ODATA_VERSION)
                 .header("OData-MaxVersion", headers::ODATA_VERSION)
@@ -233,10 +407,12 @@ impl DynamicsClient {
 
     /// Execute the next page of results using @odata.nextLink
     pub async fn execute_next_page(&self, next_link: &str) -> anyhow::Result<QueryResult> {
-        let response = self.retry_policy.execute(|| async {
+        let response = self.retry_policy.execute_response(|| async {
             self.http_client
                 .get(next_link)
                 .bearer_auth(&self.access_token)
+                .timeout(self.timeouts.query)
+                .headers(self.request_headers())
                 .header("Accept", headers::CONTENT_TYPE_JSON)
                 .header("OData-Version", headers::ODATA_VERSION)
                 .send()
@@ -279,6 +455,10 @@ impl DynamicsClient {
         } else {
             None
         };
+        let body = json_data
+            .as_ref()
+            .map(|json| EncodedBody::json(json, self.compression))
+            .transpose()?;
 
         // Validate HTTP method first
         let method_upper = method.to_uppercase();
@@ -286,8 +466,15 @@ impl DynamicsClient {
             return Err(anyhow::anyhow!("Unsupported HTTP method: {}", method));
         }
 
+        let operation_type = format!("raw_{}", method_upper.to_lowercase());
+        let entity = raw_endpoint_entity(endpoint);
+
+        // Reads get the query timeout, anything else is a write
+        let timeout = if method_upper == "GET" { self.timeouts.query } else { self.timeouts.write };
+
         // Execute request with retry policy
-        let response = self.retry_policy.execute(|| async {
+        let started = std::time::Instant::now();
+        let response = self.retry_policy.execute_response(|| async {
             let mut request = match method_upper.as_str() {
                 "GET" => self.http_client.get(&url),
                 "POST" => self.http_client.post(&url),
@@ -298,45 +485,66 @@ impl DynamicsClient {
 
             request = request
                 .bearer_auth(&self.access_token)
+                .timeout(timeout)
+                .headers(self.request_headers())
                 .header("Accept", headers::CONTENT_TYPE_JSON)
                 .header("OData-Version", headers::ODATA_VERSION)
                 .header("OData-MaxVersion", headers::ODATA_VERSION);
 
             // Add body for POST/PATCH
-            if let Some(ref json) = json_data {
+            if let Some(ref body) = body {
                 request = request
                     .header("Content-Type", headers::CONTENT_TYPE_JSON)
-                    .json(json);
+                    .headers(body.headers())
+                    .body(body.bytes());
             }
 
             request.send().await
-        }).await?;
+        }).await.inspect_err(|_| self.record_request(&operation_type, entity, started, None, false))?;
 
         let status = response.status();
 
         // Handle different status codes
-        if status.is_success() {
+        let result = if status.is_success() {
             // Try to parse as JSON, return empty object if no content
             if status == reqwest::StatusCode::NO_CONTENT {
                 Ok(serde_json::json!({}))
             } else {
-                let text = response.text().await?;
-                if text.is_empty() {
-                    Ok(serde_json::json!({}))
-                } else {
-                    serde_json::from_str(&text)
-                        .map_err(|e| anyhow::anyhow!("Failed to parse response as JSON: {}", e))
-                }
+                compression::response_text(response).await.and_then(|text| {
+                    if text.is_empty() {
+                        Ok(serde_json::json!({}))
+                    } else {
+                        serde_json::from_str(&text)
+                            .map_err(|e| anyhow::anyhow!("Failed to parse response as JSON: {}", e))
+                    }
+                })
             }
         } else {
             // Error response - try to extract error details
-            let error_text = response.text().await?;
-            Err(anyhow::anyhow!(
-                "API request failed with status {}: {}",
-                status,
-                error_text
-            ))
-        }
+            compression::response_text(response).await.and_then(|error_text| {
+                Err(anyhow::anyhow!(
+                    "API request failed with status {}: {}",
+                    status,
+                    error_text
+                ))
+            })
+        };
+
+        self.record_request(&operation_type, entity, started, Some(status.as_u16()), result.is_ok());
+        result
+    }
+
+    /// Record metrics for a request made outside the operation logging path (queries, raw calls)
+    fn record_request(&self, operation_type: &str, entity: &str, started: std::time::Instant, status_code: Option<u16>, success: bool) {
+        let metrics = crate::api::resilience::OperationMetrics {
+            duration: started.elapsed(),
+            retry_attempts: 0,
+            success,
+            status_code,
+            error_message: None,
+            rate_limit_delays: Vec::new(),
+        };
+        self.metrics_collector.record_operation(operation_type, entity, &metrics);
     }
 
     /// Create a new record
@@ -362,17 +570,21 @@ impl DynamicsClient {
         let body_str = serde_json::to_string_pretty(data).unwrap_or_default();
         log::debug!("Create request body:\n{}", body_str);
 
+        let body = EncodedBody::json(data, self.compression)?;
         let retry_policy = crate::api::resilience::RetryPolicy::new(resilience.retry.clone());
         let request_start = std::time::Instant::now();
-        let response = retry_policy.execute(|| async {
+        let response = retry_policy.execute_response(|| async {
             self.http_client
                 .post(&url)
                 .bearer_auth(&self.access_token)
+                .timeout(self.timeouts.write)
+                .headers(self.request_headers())
                 .header("Content-Type", headers::CONTENT_TYPE_JSON)
                 .header("OData-Version", headers::ODATA_VERSION)
-                .header("Prefer", headers::PREFER_RETURN_REPRESENTATION)
+                .headers(self.representation_headers())
                 .header(headers::X_CORRELATION_ID, &correlation_id)
-                .json(data)
+                .headers(body.headers())
+                .body(body.bytes())
                 .send()
                 .await
         }).await?;
@@ -408,33 +620,48 @@ impl DynamicsClient {
         result
     }
 
-    /// Update an existing record
-    async fn update_record(&self, entity: &str, id: &str, data: &Value, resilience: &ResilienceConfig) -> anyhow::Result<OperationResult> {
+    /// Update an existing record, optionally only if it still has `etag`
+    ///
+    /// A 412 response is returned as `ApiError::PreconditionFailed`.
+    async fn update_record(&self, entity: &str, id: &str, data: &Value, etag: Option<&str>, resilience: &ResilienceConfig) -> anyhow::Result<OperationResult> {
         let url = constants::entity_record_endpoint(&self.base_url, entity, id);
         let correlation_id = uuid::Uuid::new_v4().to_string();
 
         // Apply rate limiting before making the request
         self.apply_rate_limiting().await?;
 
+        let body = EncodedBody::json(data, self.compression)?;
         let retry_policy = crate::api::resilience::RetryPolicy::new(resilience.retry.clone());
-        let response = retry_policy.execute(|| async {
+        let response = retry_policy.execute_response(|| async {
             self.http_client
                 .patch(&url)
                 .bearer_auth(&self.access_token)
+                .timeout(self.timeouts.write)
+                .headers(self.request_headers())
                 .header("Content-Type", headers::CONTENT_TYPE_JSON)
                 .header("OData-Version", headers::ODATA_VERSION)
-                .header("If-Match", headers::IF_MATCH_ANY)
-                .header("Prefer", headers::PREFER_RETURN_REPRESENTATION)
+                .header("If-Match", etag.unwrap_or(headers::IF_MATCH_ANY))
+                .headers(self.representation_headers())
                 .header(headers::X_CORRELATION_ID, &correlation_id)
-                .json(data)
+                .headers(body.headers())
+                .body(body.bytes())
                 .send()
                 .await
         }).await?;
 
+        if response.status() == reqwest::StatusCode::PRECONDITION_FAILED {
+            return Err(ApiError::PreconditionFailed {
+                entity: entity.to_string(),
+                id: id.to_string(),
+                etag: etag.unwrap_or(headers::IF_MATCH_ANY).to_string(),
+            }.into());
+        }
+
         self.parse_response(Operation::Update {
             entity: entity.to_string(),
             id: id.to_string(),
             data: data.clone(),
+            etag: etag.map(str::to_string),
         }, response).await
     }
 
@@ -447,10 +674,12 @@ impl DynamicsClient {
         self.apply_rate_limiting().await?;
 
         let retry_policy = crate::api::resilience::RetryPolicy::new(resilience.retry.clone());
-        let response = retry_policy.execute(|| async {
+        let response = retry_policy.execute_response(|| async {
             self.http_client
                 .delete(&url)
                 .bearer_auth(&self.access_token)
+                .timeout(self.timeouts.write)
+                .headers(self.request_headers())
                 .header("OData-Version", headers::ODATA_VERSION)
                 .header(headers::X_CORRELATION_ID, &correlation_id)
                 .send()
@@ -463,32 +692,35 @@ impl DynamicsClient {
         }, response).await
     }
 
-    /// Upsert a record using alternate key
-    async fn upsert_record(&self, entity: &str, key_field: &str, key_value: &str, data: &Value, resilience: &ResilienceConfig) -> anyhow::Result<OperationResult> {
-        let url = constants::upsert_endpoint(&self.base_url, entity, key_field, key_value);
+    /// Upsert a record using alternate key(s)
+    async fn upsert_record(&self, entity: &str, keys: &[(String, Value)], data: &Value, resilience: &ResilienceConfig) -> anyhow::Result<OperationResult> {
+        let url = constants::upsert_endpoint(&self.base_url, entity, keys);
         let correlation_id = uuid::Uuid::new_v4().to_string();
 
         // Apply rate limiting before making the request
         self.apply_rate_limiting().await?;
 
+        let body = EncodedBody::json(data, self.compression)?;
         let retry_policy = crate::api::resilience::RetryPolicy::new(resilience.retry.clone());
-        let response = retry_policy.execute(|| async {
+        let response = retry_policy.execute_response(|| async {
             self.http_client
                 .patch(&url)
                 .bearer_auth(&self.access_token)
+                .timeout(self.timeouts.write)
+                .headers(self.request_headers())
                 .header("Content-Type", headers::CONTENT_TYPE_JSON)
                 .header("OData-Version", headers::ODATA_VERSION)
-                .header("Prefer", headers::PREFER_RETURN_REPRESENTATION)
+                .header("Prefer", headers::PREFER_RETURN_REPRESENTATION) // 201 vs 200 tells create from update
                 .header(headers::X_CORRELATION_ID, &correlation_id)
-                .json(data)
+                .headers(body.headers())
+                .body(body.bytes())
                 .send()
                 .await
         }).await?;
 
         self.parse_response(Operation::Upsert {
             entity: entity.to_string(),
-            key_field: key_field.to_string(),
-            key_value: key_value.to_string(),
+            keys: keys.to_vec(),
             data: data.clone(),
         }, response).await
     }
@@ -496,25 +728,28 @@ impl DynamicsClient {
     /// Associate records via navigation property ($ref)
     async fn associate_ref(&self, entity: &str, entity_ref: &str, navigation_property: &str, target_ref: &str, resilience: &ResilienceConfig) -> anyhow::Result<OperationResult> {
         // POST /entities(id)/navigation_property/$ref with body {"@odata.id": "target"}
-        let url = format!("{}/{}({})/{}/$ref", self.base_url, entity, entity_ref, navigation_property);
+        let url = format!("{}{}", self.base_url, constants::associate_ref_path(entity, entity_ref, navigation_property));
         let correlation_id = uuid::Uuid::new_v4().to_string();
 
         // Apply rate limiting before making the request
         self.apply_rate_limiting().await?;
 
-        let body = serde_json::json!({
-            "@odata.id": target_ref
-        });
+        let body = EncodedBody::json(&serde_json::json!({
+            "@odata.id": constants::odata_id_url(&self.base_url, target_ref)
+        }), self.compression)?;
 
         let retry_policy = crate::api::resilience::RetryPolicy::new(resilience.retry.clone());
-        let response = retry_policy.execute(|| async {
+        let response = retry_policy.execute_response(|| async {
             self.http_client
                 .post(&url)
                 .bearer_auth(&self.access_token)
+                .timeout(self.timeouts.write)
+                .headers(self.request_headers())
                 .header("Content-Type", headers::CONTENT_TYPE_JSON)
                 .header("OData-Version", headers::ODATA_VERSION)
                 .header(headers::X_CORRELATION_ID, &correlation_id)
-                .json(&body)
+                .headers(body.headers())
+                .body(body.bytes())
                 .send()
                 .await
         }).await?;
@@ -527,8 +762,38 @@ impl DynamicsClient {
         }, response).await
     }
 
+    /// Remove an N:N association via navigation property ($ref)
+    async fn disassociate_ref(&self, entity: &str, entity_ref: &str, navigation_property: &str, target_id: &str, resilience: &ResilienceConfig) -> anyhow::Result<OperationResult> {
+        // DELETE /entities(id)/navigation_property(target_id)/$ref
+        let url = format!("{}{}", self.base_url, constants::disassociate_ref_path(entity, entity_ref, navigation_property, target_id));
+        let correlation_id = uuid::Uuid::new_v4().to_string();
+
+        // Apply rate limiting before making the request
+        self.apply_rate_limiting().await?;
+
+        let retry_policy = crate::api::resilience::RetryPolicy::new(resilience.retry.clone());
+        let response = retry_policy.execute_response(|| async {
+            self.http_client
+                .delete(&url)
+                .bearer_auth(&self.access_token)
+                .timeout(self.timeouts.write)
+                .headers(self.request_headers())
+                .header("OData-Version", headers::ODATA_VERSION)
+                .header(headers::X_CORRELATION_ID, &correlation_id)
+                .send()
+                .await
+        }).await?;
+
+        self.parse_response(Operation::DisassociateRef {
+            entity: entity.to_string(),
+            entity_ref: entity_ref.to_string(),
+            navigation_property: navigation_property.to_string(),
+            target_id: target_id.to_string(),
+        }, response).await
+    }
+
     /// Execute operations using the $batch endpoint
-    async fn execute_batch_request(&self, operations: &[Operation], resilience: &ResilienceConfig) -> anyhow::Result<Vec<OperationResult>> {
+    async fn execute_batch_request(&self, operations: &[Operation], mode: BatchMode, resilience: &ResilienceConfig) -> anyhow::Result<Vec<OperationResult>> {
         let url = constants::batch_endpoint(&self.base_url);
         let correlation_id = uuid::Uuid::new_v4().to_string();
 
@@ -537,7 +802,8 @@ impl DynamicsClient {
 
         // Build the batch request using the proper builder
         let batch_request = BatchRequestBuilder::new(&self.base_url)
-            .add_changeset(operations)
+            .return_representation(self.return_representation)
+            .add_operations(operations, mode)
             .build();
 
         let content_type = batch_request.content_type().to_string();
@@ -552,19 +818,27 @@ impl DynamicsClient {
             body.clone()
         };
         log::debug!("Batch request body:\n{}", truncated_body);
+        let encoded_body = EncodedBody::new(body.into_bytes(), self.compression);
 
         let retry_policy = crate::api::resilience::RetryPolicy::new(resilience.retry.clone());
         let request_start = std::time::Instant::now();
-        let response = retry_policy.execute(|| async {
-            self.http_client
+        let response = retry_policy.execute_response(|| async {
+            let request = self.http_client
                 .post(&url)
                 .bearer_auth(&self.access_token)
+                .timeout(self.timeouts.write)
+                .headers(self.request_headers())
                 .header("Content-Type", content_type.clone())
                 .header("OData-Version", headers::ODATA_VERSION)
-                .header(headers::X_CORRELATION_ID, &correlation_id)
-                .body(body.clone())
-                .send()
-                .await
+                .header(headers::X_CORRELATION_ID, &correlation_id);
+
+            // Without this the server stops at the first failed independent request
+            let request = match mode {
+                BatchMode::Independent => request.header("Prefer", headers::PREFER_CONTINUE_ON_ERROR),
+                BatchMode::ChangeSet => request,
+            };
+
+            request.headers(encoded_body.headers()).body(encoded_body.bytes()).send().await
         }).await?;
 
         let request_duration = request_start.elapsed();
@@ -572,7 +846,7 @@ impl DynamicsClient {
 
         log::debug!("Batch request completed: status={}, duration={:?}", status_code, request_duration);
 
-        let response_text = response.text().await?;
+        let response_text = compression::response_text(response).await?;
 
         // Log full response for debugging (first 2000 chars to avoid log spam)
         let truncated_response = if response_text.len() > 2000 {
@@ -628,7 +902,7 @@ impl DynamicsClient {
                 None
             } else {
                 // Get response text first, then try to parse as JSON
-                let text = response.text().await.unwrap_or_default();
+                let text = compression::response_text(response).await.unwrap_or_default();
                 if text.is_empty() {
                     None
                 } else {
@@ -648,7 +922,7 @@ impl DynamicsClient {
                 headers,
             })
         } else {
-            let error_text = response.text().await.unwrap_or_else(|_| "Unknown error".to_string());
+            let error_text = compression::response_text(response).await.unwrap_or_else(|_| "Unknown error".to_string());
 
             // Log the error
             log::error!(
@@ -662,7 +936,7 @@ impl DynamicsClient {
             Ok(OperationResult {
                 operation,
                 success: false,
-                data: None,
+                data: serde_json::from_str::<Value>(&error_text).ok(),
                 error: Some(error_text),
                 status_code: Some(status_code),
                 headers,
@@ -683,7 +957,7 @@ impl DynamicsClient {
         }
 
         if response.status().is_success() {
-            let text = response.text().await.unwrap_or_default();
+            let text = compression::response_text(response).await.unwrap_or_default();
             if text.is_empty() {
                 return Ok(QueryResult::error(
                     "Empty response from server".to_string(),
@@ -710,11 +984,91 @@ impl DynamicsClient {
                 )),
             }
         } else {
-            let error_text = response.text().await.unwrap_or_else(|_| "Unknown error".to_string());
+            let error_text = compression::response_text(response).await.unwrap_or_else(|_| "Unknown error".to_string());
             Ok(QueryResult::error(error_text, Some(status_code), headers))
         }
     }
 
+    /// The calling user, their business unit and organization (`WhoAmI`)
+    ///
+    /// Fetched on first use and cached for the life of this client and its clones.
+    pub async fn who_am_i(&self) -> anyhow::Result<WhoAmIResponse> {
+        let identity = self.who_am_i.get_or_try_init(|| async {
+            let text = self.get_function("WhoAmI").await?;
+            serde_json::from_str::<WhoAmIResponse>(&text)
+                .map_err(|e| anyhow::anyhow!("Failed to parse WhoAmI response: {}", e))
+        }).await?;
+        Ok(identity.clone())
+    }
+
+    /// Name, version and web URL of the current organization
+    pub async fn retrieve_organization_info(&self) -> anyhow::Result<OrganizationInfo> {
+        let text = self.get_function(
+            "RetrieveCurrentOrganization(AccessType=@p1)?@p1=Microsoft.Dynamics.CRM.EndpointAccessType'Default'"
+        ).await?;
+        let response: RetrieveCurrentOrganizationResponse = serde_json::from_str(&text)
+            .map_err(|e| anyhow::anyhow!("Failed to parse RetrieveCurrentOrganization response: {}", e))?;
+        Ok(response.into())
+    }
+
+    /// GET an unbound function (`function` includes any parameters) and return the body
+    async fn get_function(&self, function: &str) -> anyhow::Result<String> {
+        let url = format!("{}/{}/{}", self.base_url, constants::api_path(), function);
+
+        self.apply_rate_limiting().await?;
+
+        let response = self.retry_policy.execute_response(|| async {
+            self.http_client
+                .get(&url)
+                .bearer_auth(&self.access_token)
+                .timeout(self.timeouts.query)
+                .headers(self.request_headers())
+                .header("Accept", headers::CONTENT_TYPE_JSON)
+                .header("OData-Version", headers::ODATA_VERSION)
+                .send()
+                .await
+        }).await?;
+
+        let status = response.status();
+        let text = compression::response_text(response).await?;
+        if !status.is_success() {
+            let name = function.split('(').next().unwrap_or(function);
+            anyhow::bail!("{} failed with status {}: {}", name, status, text);
+        }
+        Ok(text)
+    }
+
+    /// Check the environment answers and the token is accepted with a `WhoAmI` call
+    ///
+    /// Sent once, without retries, so an unreachable environment fails fast.
+    pub async fn health_check(&self) -> anyhow::Result<HealthCheck> {
+        let url = format!("{}/{}/WhoAmI", self.base_url, constants::api_path());
+
+        self.apply_rate_limiting().await?;
+
+        let started = std::time::Instant::now();
+        let response = self.http_client
+            .get(&url)
+            .bearer_auth(&self.access_token)
+            .timeout(self.timeouts.query)
+            .headers(self.request_headers())
+            .header("Accept", headers::CONTENT_TYPE_JSON)
+            .header("OData-Version", headers::ODATA_VERSION)
+            .send()
+            .await?;
+
+        let status = response.status();
+        let text = compression::response_text(response).await?;
+        let latency = started.elapsed();
+        if !status.is_success() {
+            anyhow::bail!("WhoAmI failed with status {}: {}", status, text);
+        }
+
+        let identity: WhoAmIResponse = serde_json::from_str(&text)?;
+        let _ = self.who_am_i.set(identity.clone());
+        Ok(HealthCheck { latency, identity })
+    }
+
     /// Fetch entity metadata from Dynamics 365 $metadata endpoint
     pub async fn fetch_metadata(&self) -> anyhow::Result<String> {
         let metadata_url = format!("{}/{}/$metadata", self.base_url, constants::api_path());
@@ -722,10 +1076,12 @@ impl DynamicsClient {
         // Apply rate limiting before making the request
         self.apply_rate_limiting().await?;
 
-        let response = self.retry_policy.execute(|| async {
+        let response = self.retry_policy.execute_response(|| async {
             self.http_client
                 .get(&metadata_url)
                 .bearer_auth(&self.access_token)
+                .timeout(self.timeouts.metadata)
+                .headers(self.request_headers())
                 .header("Accept", "application/xml")
                 .header("OData-Version", headers::ODATA_VERSION)
                 .send()
@@ -734,10 +1090,10 @@ impl DynamicsClient {
 
         let status = response.status();
         if status.is_success() {
-            let metadata_xml = response.text().await?;
+            let metadata_xml = compression::response_text(response).await?;
             Ok(metadata_xml)
         } else {
-            let error_text = response.text().await.unwrap_or_else(|_| "Unknown error".to_string());
+            let error_text = compression::response_text(response).await.unwrap_or_else(|_| "Unknown error".to_string());
             anyhow::bail!("Metadata fetch failed with status {}: {}", status, error_text)
         }
     }
@@ -779,6 +1135,7 @@ impl DynamicsClient {
                     is_primary_key: false, // TODO: detect from Key element
                     max_length: None,
                     related_entity: None,
+                    option_set: None,
                 });
             }
         }
@@ -824,6 +1181,7 @@ impl DynamicsClient {
                     is_primary_key: false,
                     max_length: None,
                     related_entity,
+                    option_set: None,
                 });
             }
         }
@@ -832,15 +1190,7 @@ impl DynamicsClient {
     }
 
     fn parse_field_type(type_str: &str, targets: Option<&Vec<serde_json::Value>>) -> super::metadata::FieldType {
-        match type_str {
-            "Edm.String" => super::metadata::FieldType::String,
-            "Edm.Int32" => super::metadata::FieldType::Integer,
-            "Edm.Decimal" | "Edm.Double" => super::metadata::FieldType::Decimal,
-            "Edm.Boolean" => super::metadata::FieldType::Boolean,
-            "Edm.DateTime" | "Edm.DateTimeOffset" => super::metadata::FieldType::DateTime,
-            "Edm.Guid" => super::metadata::FieldType::UniqueIdentifier,
-            other => super::metadata::FieldType::Other(other.to_string()),
-        }
+        super::metadata::edm_field_type(type_str)
     }
 
     /// Fetch entity field definitions from EntityDefinitions endpoint (attributes only, no navigation properties)
@@ -855,10 +1205,12 @@ impl DynamicsClient {
         // Apply rate limiting before making the request
         self.apply_rate_limiting().await?;
 
-        let response = self.retry_policy.execute(|| async {
+        let response = self.retry_policy.execute_response(|| async {
             self.http_client
                 .get(&url)
                 .bearer_auth(&self.access_token)
+                .timeout(self.timeouts.metadata)
+                .headers(self.request_headers())
                 .header("Accept", headers::CONTENT_TYPE_JSON)
                 .header("OData-Version", headers::ODATA_VERSION)
                 .send()
@@ -867,7 +1219,7 @@ impl DynamicsClient {
 
         let status = response.status();
         if status.is_success() {
-            let json: Value = response.json().await?;
+            let json: Value = compression::response_json(response).await?;
             let attributes = json["value"].as_array()
                 .ok_or_else(|| anyhow::anyhow!("Expected 'value' array in response"))?;
 
@@ -918,13 +1270,14 @@ impl DynamicsClient {
                         is_primary_key,
                         max_length,
                         related_entity,
+                        option_set: None,
                     })
                 })
                 .collect();
 
             Ok(fields)
         } else {
-            let error_text = response.text().await.unwrap_or_else(|_| "Unknown error".to_string());
+            let error_text = compression::response_text(response).await.unwrap_or_else(|_| "Unknown error".to_string());
             anyhow::bail!("Field metadata fetch failed with status {}: {}", status, error_text)
         }
     }
@@ -1006,6 +1359,40 @@ impl DynamicsClient {
         Ok(result)
     }
 
+    /// Fetch the choice labels of an entity's option set attributes
+    pub async fn fetch_entity_option_sets(&self, entity_name: &str) -> anyhow::Result<super::metadata::EntityOptionSets> {
+        let url = format!(
+            "{}/{}/EntityDefinitions(LogicalName='{}')/Attributes/Microsoft.Dynamics.CRM.EnumAttributeMetadata?$select=LogicalName&$expand=OptionSet($select=Options),GlobalOptionSet($select=Options)",
+            self.base_url,
+            constants::api_path(),
+            entity_name
+        );
+
+        // Apply rate limiting before making the request
+        self.apply_rate_limiting().await?;
+
+        let response = self.retry_policy.execute_response(|| async {
+            self.http_client
+                .get(&url)
+                .bearer_auth(&self.access_token)
+                .timeout(self.timeouts.metadata)
+                .headers(self.request_headers())
+                .header("Accept", headers::CONTENT_TYPE_JSON)
+                .header("OData-Version", headers::ODATA_VERSION)
+                .send()
+                .await
+        }).await?;
+
+        let status = response.status();
+        if status.is_success() {
+            let json: Value = compression::response_json(response).await?;
+            super::metadata::parse_option_sets(&json)
+        } else {
+            let error_text = compression::response_text(response).await.unwrap_or_else(|_| "Unknown error".to_string());
+            anyhow::bail!("Option set fetch for {} failed with status {}: {}", entity_name, status, error_text)
+        }
+    }
+
     /// Fetch entity forms from systemforms endpoint
     pub async fn fetch_entity_forms(&self, entity_name: &str) -> anyhow::Result<Vec<super::metadata::FormMetadata>> {
         let url = format!(
@@ -1018,10 +1405,12 @@ impl DynamicsClient {
         // Apply rate limiting before making the request
         self.apply_rate_limiting().await?;
 
-        let response = self.retry_policy.execute(|| async {
+        let response = self.retry_policy.execute_response(|| async {
             self.http_client
                 .get(&url)
                 .bearer_auth(&self.access_token)
+                .timeout(self.timeouts.metadata)
+                .headers(self.request_headers())
                 .header("Accept", headers::CONTENT_TYPE_JSON)
                 .header("OData-Version", headers::ODATA_VERSION)
                 .send()
@@ -1030,7 +1419,7 @@ impl DynamicsClient {
 
         let status = response.status();
         if status.is_success() {
-            let json: Value = response.json().await?;
+            let json: Value = compression::response_json(response).await?;
             let forms_array = json["value"].as_array()
                 .ok_or_else(|| anyhow::anyhow!("Expected 'value' array in response"))?;
 
@@ -1070,7 +1459,7 @@ impl DynamicsClient {
 
             Ok(forms)
         } else {
-            let error_text = response.text().await.unwrap_or_else(|_| "Unknown error".to_string());
+            let error_text = compression::response_text(response).await.unwrap_or_else(|_| "Unknown error".to_string());
             anyhow::bail!("Form metadata fetch failed with status {}: {}", status, error_text)
         }
     }
@@ -1087,10 +1476,12 @@ impl DynamicsClient {
         // Apply rate limiting before making the request
         self.apply_rate_limiting().await?;
 
-        let response = self.retry_policy.execute(|| async {
+        let response = self.retry_policy.execute_response(|| async {
             self.http_client
                 .get(&url)
                 .bearer_auth(&self.access_token)
+                .timeout(self.timeouts.metadata)
+                .headers(self.request_headers())
                 .header("Accept", headers::CONTENT_TYPE_JSON)
                 .header("OData-Version", headers::ODATA_VERSION)
                 .send()
@@ -1099,7 +1490,7 @@ impl DynamicsClient {
 
         let status = response.status();
         if status.is_success() {
-            let json: Value = response.json().await?;
+            let json: Value = compression::response_json(response).await?;
             let views_array = json["value"].as_array()
                 .ok_or_else(|| anyhow::anyhow!("Expected 'value' array in response"))?;
 
@@ -1140,7 +1531,7 @@ impl DynamicsClient {
 
             Ok(views)
         } else {
-            let error_text = response.text().await.unwrap_or_else(|_| "Unknown error".to_string());
+            let error_text = compression::response_text(response).await.unwrap_or_else(|_| "Unknown error".to_string());
             anyhow::bail!("View metadata fetch failed with status {}: {}", status, error_text)
         }
     }
@@ -1275,6 +1666,63 @@ impl DynamicsClient {
         columns
     }
 
+    /// Retrieve a single record by ID, optionally limited to `select` fields
+    /// Returns `None` when the record does not exist; formatted values and
+    /// lookup annotations are included in the response
+    pub async fn get_record(
+        &self,
+        entity_name: &str,
+        record_id: &str,
+        select: &[String],
+    ) -> anyhow::Result<Option<Value>> {
+        match self.get_record_if_none_match(entity_name, record_id, select, None).await? {
+            ConditionalRecord::Modified(record) => Ok(Some(record)),
+            ConditionalRecord::NotFound => Ok(None),
+            ConditionalRecord::NotModified => anyhow::bail!("Unexpected 304 for an unconditional request"),
+        }
+    }
+
+    /// Retrieve a single record unless it still matches `etag` (its previous `@odata.etag`)
+    /// Lets callers holding a copy of the record skip re-downloading it when nothing changed
+    pub async fn get_record_if_none_match(
+        &self,
+        entity_name: &str,
+        record_id: &str,
+        select: &[String],
+        etag: Option<&str>,
+    ) -> anyhow::Result<ConditionalRecord> {
+        self.apply_rate_limiting().await?;
+
+        let plural_entity = self.entity_set_name(entity_name);
+        let url = constants::record_select_endpoint(&self.base_url, &plural_entity, record_id, select);
+
+        let response = self.retry_policy.execute_response(|| async {
+            let mut request = self.http_client
+                .get(&url)
+                .bearer_auth(&self.access_token)
+                .timeout(self.timeouts.query)
+                .headers(self.request_headers())
+                .header("Accept", headers::CONTENT_TYPE_JSON)
+                .header("OData-Version", headers::ODATA_VERSION)
+                .header("Prefer", headers::PREFER_INCLUDE_ANNOTATIONS);
+            if let Some(etag) = etag {
+                request = request.header(headers::IF_NONE_MATCH, etag);
+            }
+            request.send().await
+        }).await?;
+
+        match response.status() {
+            reqwest::StatusCode::NOT_MODIFIED => return Ok(ConditionalRecord::NotModified),
+            reqwest::StatusCode::NOT_FOUND => return Ok(ConditionalRecord::NotFound),
+            status if !status.is_success() => {
+                anyhow::bail!("Failed to fetch record: {} - {}", status, compression::response_text(response).await?);
+            }
+            _ => {}
+        }
+
+        Ok(ConditionalRecord::Modified(compression::response_json(response).await?))
+    }
+
     /// Fetch a single record by ID
     /// Returns the full record as JSON with all fields and formatted values
     pub async fn fetch_record_by_id(
@@ -1285,7 +1733,7 @@ impl DynamicsClient {
         self.apply_rate_limiting().await?;
 
         // Pluralize entity name for the endpoint
-        let plural_entity = super::pluralization::pluralize_entity_name(entity_name);
+        let plural_entity = self.entity_set_name(entity_name);
 
         // Build URL with $select=* to get all fields
         // Also add Prefer header to include formatted values and lookup properties
@@ -1296,10 +1744,12 @@ impl DynamicsClient {
             record_id
         );
 
-        let response = self.retry_policy.execute(|| async {
+        let response = self.retry_policy.execute_response(|| async {
             self.http_client
                 .get(&url)
                 .bearer_auth(&self.access_token)
+                .timeout(self.timeouts.query)
+                .headers(self.request_headers())
                 .header("Accept", headers::CONTENT_TYPE_JSON)
                 .header("OData-Version", headers::ODATA_VERSION)
                 .header("Prefer", "odata.include-annotations=\"OData.Community.Display.V1.FormattedValue\"")
@@ -1308,10 +1758,10 @@ impl DynamicsClient {
         }).await?;
 
         if !response.status().is_success() {
-            anyhow::bail!("Failed to fetch record: {} - {}", response.status(), response.text().await?);
+            anyhow::bail!("Failed to fetch record: {} - {}", response.status(), compression::response_text(response).await?);
         }
 
-        let record: serde_json::Value = response.json().await?;
+        let record: serde_json::Value = compression::response_json(response).await?;
 
         log::debug!("Fetched record for entity '{}' with ID '{}'", entity_name, record_id);
         log::debug!("Record has {} top-level fields", record.as_object().map(|o| o.len()).unwrap_or(0));
@@ -1321,4 +1771,16 @@ impl DynamicsClient {
 
         Ok(record)
     }
-}
\ No newline at end of file
+}
+
+/// Entity set a raw endpoint targets, e.g. `accounts(<id>)/contact_customer_accounts?...` -> `accounts`
+fn raw_endpoint_entity(endpoint: &str) -> &str {
+    let api_path = constants::api_path();
+    let path = match endpoint.find(&api_path) {
+        Some(start) => &endpoint[start + api_path.len()..],
+        None => endpoint,
+    };
+    let path = path.trim_start_matches('/');
+    let end = path.find(['(', '?', '/']).unwrap_or(path.len());
+    &path[..end]
+}
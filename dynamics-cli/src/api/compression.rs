@@ -0,0 +1,150 @@
+//! Optional gzip compression for Web API traffic
+//!
+//! reqwest is built without its `gzip` feature, so when a client opts in we
+//! send `Accept-Encoding: gzip` ourselves and inflate responses here. Request
+//! bodies are only gzipped above a size threshold, where the saving outweighs
+//! the CPU cost.
+
+use anyhow::{Context, Result};
+use flate2::Compression;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use reqwest::header::{CONTENT_ENCODING, HeaderMap, HeaderValue};
+use std::io::{Read, Write};
+
+/// Bodies smaller than this are sent uncompressed
+pub const MIN_COMPRESSED_BODY_BYTES: usize = 8 * 1024;
+
+const GZIP: &str = "gzip";
+
+/// A request body, gzipped when compression is enabled and it is large enough
+#[derive(Debug, Clone)]
+pub struct EncodedBody {
+    bytes: Vec<u8>,
+    gzipped: bool,
+}
+
+impl EncodedBody {
+    pub fn new(bytes: Vec<u8>, compress: bool) -> Self {
+        if !compress || bytes.len() < MIN_COMPRESSED_BODY_BYTES {
+            return Self { bytes, gzipped: false };
+        }
+
+        match gzip(&bytes) {
+            Ok(compressed) => {
+                log_ratio("Request", bytes.len(), compressed.len());
+                Self { bytes: compressed, gzipped: true }
+            }
+            Err(e) => {
+                log::warn!("Failed to gzip request body, sending it uncompressed: {}", e);
+                Self { bytes, gzipped: false }
+            }
+        }
+    }
+
+    /// Serialize a JSON value as the body
+    pub fn json(value: &serde_json::Value, compress: bool) -> Result<Self> {
+        let bytes = serde_json::to_vec(value).context("Failed to serialize request body")?;
+        Ok(Self::new(bytes, compress))
+    }
+
+    /// Body bytes (cloned, since retries resend them)
+    pub fn bytes(&self) -> Vec<u8> {
+        self.bytes.clone()
+    }
+
+    /// `Content-Encoding: gzip` when the body was compressed
+    pub fn headers(&self) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        if self.gzipped {
+            headers.insert(CONTENT_ENCODING, HeaderValue::from_static(GZIP));
+        }
+        headers
+    }
+
+    pub fn is_gzipped(&self) -> bool {
+        self.gzipped
+    }
+}
+
+/// Read a response body, inflating it if the server gzipped it
+pub async fn response_bytes(response: reqwest::Response) -> Result<Vec<u8>> {
+    let gzipped = response
+        .headers()
+        .get(CONTENT_ENCODING)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.eq_ignore_ascii_case(GZIP));
+
+    let bytes = response.bytes().await.context("Failed to read response body")?;
+    if !gzipped {
+        return Ok(bytes.to_vec());
+    }
+
+    let inflated = gunzip(&bytes).context("Failed to decompress gzip response")?;
+    log_ratio("Response", inflated.len(), bytes.len());
+    Ok(inflated)
+}
+
+/// Read a response body as text
+pub async fn response_text(response: reqwest::Response) -> Result<String> {
+    let bytes = response_bytes(response).await?;
+    String::from_utf8(bytes).context("Response body is not valid UTF-8")
+}
+
+/// Read a response body as JSON
+pub async fn response_json<T: serde::de::DeserializeOwned>(response: reqwest::Response) -> Result<T> {
+    let bytes = response_bytes(response).await?;
+    serde_json::from_slice(&bytes).context("Failed to parse response as JSON")
+}
+
+pub fn gzip(data: &[u8]) -> std::io::Result<Vec<u8>> {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(data)?;
+    encoder.finish()
+}
+
+pub fn gunzip(data: &[u8]) -> std::io::Result<Vec<u8>> {
+    let mut inflated = Vec::new();
+    GzDecoder::new(data).read_to_end(&mut inflated)?;
+    Ok(inflated)
+}
+
+fn log_ratio(direction: &str, original: usize, compressed: usize) {
+    log::debug!(
+        "{} body gzip: {} -> {} bytes ({:.1}% of original)",
+        direction,
+        original,
+        compressed,
+        compressed as f64 / original.max(1) as f64 * 100.0
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gzip_round_trip() {
+        let data = "{\"name\":\"Contoso\"}".repeat(1000);
+
+        let compressed = gzip(data.as_bytes()).unwrap();
+
+        assert!(compressed.len() < data.len());
+        assert_eq!(gunzip(&compressed).unwrap(), data.as_bytes());
+    }
+
+    #[test]
+    fn test_only_large_bodies_are_compressed() {
+        let small = EncodedBody::new(b"{}".to_vec(), true);
+        assert!(!small.is_gzipped());
+        assert!(small.headers().is_empty());
+
+        let large = vec![b'a'; MIN_COMPRESSED_BODY_BYTES];
+        assert!(!EncodedBody::new(large.clone(), false).is_gzipped());
+
+        let body = EncodedBody::new(large.clone(), true);
+        assert!(body.is_gzipped());
+        assert_eq!(body.headers()[CONTENT_ENCODING], GZIP);
+        assert_eq!(gunzip(&body.bytes()).unwrap(), large);
+    }
+}
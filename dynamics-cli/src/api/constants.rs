@@ -1,5 +1,7 @@
 //! API Constants and Configuration for Dynamics 365 Web API
 
+use serde_json::Value;
+
 /// Dynamics 365 Web API version
 pub const API_VERSION: &str = "v9.2";
 
@@ -40,13 +42,37 @@ pub mod headers {
     /// Header to get entity ID on create
     pub const PREFER_INCLUDE_ANNOTATIONS: &str = "odata.include-annotations=\"*\"";
 
+    /// Prefer header letting a non-transactional batch run past failed requests
+    pub const PREFER_CONTINUE_ON_ERROR: &str = "odata.continue-on-error";
+
     /// If-Match header for updates (any version)
     pub const IF_MATCH_ANY: &str = "*";
 
+    /// Header making a GET conditional on the record having changed
+    pub const IF_NONE_MATCH: &str = "If-None-Match";
+
+    /// Impersonate a systemuser (value is its `systemuserid`)
+    pub const MSCRM_CALLER_ID: &str = "MSCRMCallerID";
+
     /// Correlation ID header for request tracking
     pub const X_CORRELATION_ID: &str = "x-correlation-id";
 }
 
+/// OData annotations returned by Dynamics 365
+pub mod annotations {
+    /// Record version, usable as an `If-Match` / `If-None-Match` value
+    pub const ODATA_ETAG: &str = "@odata.etag";
+
+    /// Link to the next page of a collection
+    pub const ODATA_NEXT_LINK: &str = "@odata.nextLink";
+
+    /// Paging cookie returned with FetchXML results that have further pages
+    pub const FETCHXML_PAGING_COOKIE: &str = "@Microsoft.Dynamics.CRM.fetchxmlpagingcookie";
+
+    /// Whether more FetchXML result pages are available
+    pub const MORE_RECORDS: &str = "@Microsoft.Dynamics.CRM.morerecords";
+}
+
 /// HTTP methods for operations
 pub mod methods {
     pub const GET: &str = "GET";
@@ -65,12 +91,158 @@ pub fn entity_record_endpoint(base_url: &str, entity: &str, id: &str) -> String
     format!("{}{}/{}({})", base_url, api_path(), entity, id)
 }
 
+/// Build entity record endpoint URL with an optional `$select` list
+pub fn record_select_endpoint(base_url: &str, entity: &str, id: &str, select: &[String]) -> String {
+    let url = entity_record_endpoint(base_url, entity, id);
+    if select.is_empty() {
+        url
+    } else {
+        format!("{}?$select={}", url, select.join(","))
+    }
+}
+
+/// Build the `$ref` path used to associate a record through a navigation property
+pub fn associate_ref_path(entity: &str, entity_ref: &str, navigation_property: &str) -> String {
+    format!("{}/{}({})/{}/$ref", api_path(), entity, entity_ref, navigation_property)
+}
+
+/// Build the `$ref` path used to remove a single N:N association
+pub fn disassociate_ref_path(entity: &str, entity_ref: &str, navigation_property: &str, target_id: &str) -> String {
+    format!("{}/{}({})/{}({})/$ref", api_path(), entity, entity_ref, navigation_property, target_id)
+}
+
+/// Resolve a (possibly relative) record reference to the absolute URL expected in `@odata.id`
+pub fn odata_id_url(base_url: &str, target_ref: &str) -> String {
+    if target_ref.starts_with("http://") || target_ref.starts_with("https://") {
+        target_ref.to_string()
+    } else {
+        format!("{}{}", base_url, target_ref)
+    }
+}
+
 /// Build upsert endpoint URL with alternate key
-pub fn upsert_endpoint(base_url: &str, entity: &str, key_field: &str, key_value: &str) -> String {
-    format!("{}{}/{}({}='{}')", base_url, api_path(), entity, key_field, key_value)
+pub fn upsert_endpoint(base_url: &str, entity: &str, keys: &[(String, Value)]) -> String {
+    format!("{}{}", base_url, upsert_path(entity, keys))
+}
+
+/// Build the path addressing a record by its alternate key(s)
+///
+/// Numbers, booleans and GUIDs are written as bare OData literals; other
+/// values become string literals (single quotes doubled) and are
+/// percent-encoded. Composite keys are comma-separated, e.g.
+/// `/api/data/v9.2/cgk_deadlines(cgk_name='O''Neil',cgk_year=2025)`.
+pub fn upsert_path(entity: &str, keys: &[(String, Value)]) -> String {
+    let segment: Vec<String> = keys
+        .iter()
+        .map(|(field, value)| format!("{}={}", field, key_literal(value)))
+        .collect();
+    format!("{}/{}({})", api_path(), entity, segment.join(","))
+}
+
+/// Format an alternate key value as an OData literal
+fn key_literal(value: &Value) -> String {
+    match value {
+        Value::Number(n) => n.to_string(),
+        Value::Bool(b) => b.to_string(),
+        Value::Null => "null".to_string(),
+        Value::String(s) if uuid::Uuid::parse_str(s).is_ok() => s.clone(),
+        Value::String(s) => format!("'{}'", encode_key_value(s)),
+        other => format!("'{}'", encode_key_value(&other.to_string())),
+    }
+}
+
+/// Escape an alternate key value for use inside a quoted OData literal in a URL path
+fn encode_key_value(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.replace('\'', "''").bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' | b'\'' | b'@' | b':' | b'!' | b'$' | b'*' | b';' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
 }
 
 /// Build batch endpoint URL
 pub fn batch_endpoint(base_url: &str) -> String {
     format!("{}{}/{}", base_url, api_path(), BATCH_ENDPOINT)
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_record_select_endpoint() {
+        let id = "00000000-0000-0000-0000-000000000001";
+        assert_eq!(
+            record_select_endpoint("https://org.crm.dynamics.com", "accounts", id, &[]),
+            format!("https://org.crm.dynamics.com/api/data/v9.2/accounts({})", id)
+        );
+        assert_eq!(
+            record_select_endpoint(
+                "https://org.crm.dynamics.com",
+                "accounts",
+                id,
+                &["name".to_string(), "revenue".to_string()]
+            ),
+            format!("https://org.crm.dynamics.com/api/data/v9.2/accounts({})?$select=name,revenue", id)
+        );
+    }
+
+    #[test]
+    fn test_disassociate_ref_path() {
+        assert_eq!(
+            disassociate_ref_path("accounts", "a1", "contact_customer_accounts", "c1"),
+            "/api/data/v9.2/accounts(a1)/contact_customer_accounts(c1)/$ref"
+        );
+    }
+
+    #[test]
+    fn test_odata_id_url() {
+        let base = "https://org.crm.dynamics.com";
+        assert_eq!(
+            odata_id_url(base, "/api/data/v9.2/contacts(c1)"),
+            "https://org.crm.dynamics.com/api/data/v9.2/contacts(c1)"
+        );
+        assert_eq!(
+            odata_id_url(base, "https://other.crm.dynamics.com/api/data/v9.2/contacts(c1)"),
+            "https://other.crm.dynamics.com/api/data/v9.2/contacts(c1)"
+        );
+    }
+
+    #[test]
+    fn test_upsert_path_quotes_and_encodes_keys() {
+        let keys = vec![("emailaddress1".to_string(), json!("test@example.com"))];
+        assert_eq!(
+            upsert_path("contacts", &keys),
+            "/api/data/v9.2/contacts(emailaddress1='test@example.com')"
+        );
+
+        let keys = vec![
+            ("lastname".to_string(), json!("O'Neil")),
+            ("cgk_code".to_string(), json!("A/B 1&2")),
+        ];
+        assert_eq!(
+            upsert_endpoint("https://org.crm.dynamics.com", "contacts", &keys),
+            "https://org.crm.dynamics.com/api/data/v9.2/contacts(lastname='O''Neil',cgk_code='A%2FB%201%262')"
+        );
+    }
+
+    #[test]
+    fn test_upsert_path_leaves_typed_keys_unquoted() {
+        let keys = vec![
+            ("cgk_year".to_string(), json!(2025)),
+            ("cgk_rate".to_string(), json!(1.5)),
+            ("cgk_active".to_string(), json!(true)),
+            ("cgk_parentid".to_string(), json!("00000000-0000-0000-0000-000000000001")),
+        ];
+        assert_eq!(
+            upsert_path("cgk_deadlines", &keys),
+            "/api/data/v9.2/cgk_deadlines(cgk_year=2025,cgk_rate=1.5,cgk_active=true,cgk_parentid=00000000-0000-0000-0000-000000000001)"
+        );
+    }
+}
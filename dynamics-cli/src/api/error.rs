@@ -0,0 +1,43 @@
+//! Typed errors for Web API responses callers need to react to
+//!
+//! Most failures are reported through `anyhow` or a failed `OperationResult`;
+//! the cases here are returned as `anyhow::Error` too, but can be recovered
+//! with `error.downcast_ref::<ApiError>()`.
+
+/// Web API failures with a specific recovery path
+#[derive(Debug, Clone, PartialEq)]
+pub enum ApiError {
+    /// HTTP 412: the record changed since `etag` was read (re-fetch and retry)
+    PreconditionFailed {
+        /// Entity set name
+        entity: String,
+        /// Record ID (GUID)
+        id: String,
+        /// The ETag the update was conditioned on
+        etag: String,
+    },
+    /// No response within the request's timeout, even after retrying
+    Timeout {
+        /// Request URL
+        url: String,
+    },
+}
+
+impl std::fmt::Display for ApiError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::PreconditionFailed { entity, id, etag } => write!(
+                f,
+                "Record {}({}) was modified since it was read (ETag {}); re-fetch it and retry",
+                entity, id, etag
+            ),
+            Self::Timeout { url } => write!(
+                f,
+                "Request to {} timed out; the server may be busy, or raise the api.timeout options",
+                url
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ApiError {}
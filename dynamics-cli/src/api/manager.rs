@@ -2,8 +2,9 @@ use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::RwLock;
 use super::client::DynamicsClient;
-use super::auth::AuthManager;
+use super::auth::{AuthManager, DeviceCodeProgress, DeviceCodePrompt};
 use super::models::{Environment, CredentialSet, TokenInfo};
+use super::resilience::{RateLimitConfig, RateLimiter, RateLimiterStats, ResilienceConfig, TimeoutConfig};
 
 
 /// Manages multiple Dynamics client instances for different environments
@@ -13,6 +14,10 @@ pub struct ClientManager {
     environments: Arc<RwLock<HashMap<String, Environment>>>,
     current_env: Arc<RwLock<Option<String>>>,
     tokens: Arc<RwLock<HashMap<String, TokenInfo>>>,
+    rate_limiter: RateLimiter, // Shared by all clients, one token bucket per environment
+    entity_set_overrides: Arc<RwLock<Arc<HashMap<String, String>>>>, // Cached copy of the config table
+    compression: bool, // api.compression.enabled, applied to every client
+    timeouts: TimeoutConfig, // api.timeout.*, applied to every client
 }
 
 impl ClientManager {
@@ -54,6 +59,10 @@ impl ClientManager {
             environments: Arc::new(RwLock::new(environments)),
             current_env: Arc::new(RwLock::new(Some(".env".to_string()))),
             tokens: Arc::new(RwLock::new(HashMap::new())),
+            rate_limiter: RateLimiter::new(RateLimitConfig::default()),
+            entity_set_overrides: Arc::default(),
+            compression: false,
+            timeouts: TimeoutConfig::default(),
         })
     }
 
@@ -100,12 +109,23 @@ impl ClientManager {
             }
         }
 
+        let resilience = ResilienceConfig::load_from_options().await
+            .unwrap_or_default();
+
+        let entity_set_overrides = config.load_entity_set_overrides().await?;
+        let compression = config.options.get_bool("api.compression.enabled").await
+            .unwrap_or(false);
+
         Ok(Self {
             clients: Arc::new(RwLock::new(HashMap::new())),
             auth_manager,
             environments: Arc::new(RwLock::new(environments)),
             current_env: Arc::new(RwLock::new(current_env)),
             tokens: Arc::new(RwLock::new(tokens)),
+            rate_limiter: RateLimiter::new(resilience.rate_limit),
+            entity_set_overrides: Arc::new(RwLock::new(Arc::new(entity_set_overrides))),
+            compression,
+            timeouts: resilience.timeouts,
         })
     }
 
@@ -297,51 +317,72 @@ impl ClientManager {
         &self.auth_manager
     }
 
-    /// Check if a token is expired
-    fn is_expired(token: &TokenInfo) -> bool {
-        if let Ok(elapsed) = token.expires_at.elapsed() {
-            elapsed.as_secs() > 0
-        } else {
-            false // If we can't determine elapsed time, assume valid
-        }
-    }
-
     /// Get or refresh token for environment, with automatic authentication
     async fn get_or_refresh_token(&self, env_name: &str) -> anyhow::Result<TokenInfo> {
+        let mut expired = None;
+
         // 1. Check memory cache first
         if let Some(token) = self.tokens.read().await.get(env_name) {
-            if !Self::is_expired(token) {
+            if !token.is_expired() {
                 log::debug!("Using cached token for environment: {}", env_name);
                 return Ok(token.clone());
             } else {
                 log::debug!("Cached token expired for environment: {}", env_name);
+                expired = Some(token.clone());
             }
         }
 
         // 2. Check database for persisted token
         if let Some(token) = crate::global_config().get_token(env_name).await? {
-            if !Self::is_expired(&token) {
+            if !token.is_expired() {
                 log::debug!("Found valid token in database for environment: {}", env_name);
                 // Update memory cache
                 self.tokens.write().await.insert(env_name.to_string(), token.clone());
                 return Ok(token);
             } else {
                 log::debug!("Database token expired for environment: {}", env_name);
-                // Clean up expired token
-                let _ = crate::global_config().delete_token(env_name).await;
+                if token.refresh_token.is_some() {
+                    expired = Some(token);
+                } else {
+                    // Clean up expired token
+                    let _ = crate::global_config().delete_token(env_name).await;
+                }
+            }
+        }
+
+        // 3. Refresh, if the expired token came with a refresh token
+        if let Some(refresh_token) = expired.and_then(|token| token.refresh_token) {
+            match self.refresh_environment_token(env_name, &refresh_token).await {
+                Ok(token) => return Ok(token),
+                Err(e) => log::warn!("Token refresh failed for environment {}, re-authenticating: {}", env_name, e),
             }
         }
 
-        // 3. Auto-authenticate
+        // 4. Auto-authenticate
         log::info!("Auto-authenticating for environment: {}", env_name);
         self.authenticate_environment(env_name).await?;
 
-        // 4. Get the newly created token
+        // 5. Get the newly created token
         self.tokens.read().await.get(env_name)
             .cloned()
             .ok_or_else(|| anyhow::anyhow!("Authentication succeeded but token not found"))
     }
 
+    /// Exchange a refresh token and cache the result like a fresh sign-in
+    async fn refresh_environment_token(&self, env_name: &str, refresh_token: &str) -> anyhow::Result<TokenInfo> {
+        let environment = self.try_select_env(env_name).await?;
+        let credentials = self.auth_manager.try_select_credentials(&environment.credentials_ref).await?;
+
+        self.auth_manager
+            .refresh_token(env_name, &environment.host, &credentials, refresh_token)
+            .await?;
+        self.cache_token(env_name).await?;
+
+        self.tokens.read().await.get(env_name)
+            .cloned()
+            .ok_or_else(|| anyhow::anyhow!("Token refresh succeeded but token not found"))
+    }
+
     /// Authenticate a specific environment
     async fn authenticate_environment(&self, env_name: &str) -> anyhow::Result<()> {
         let environment = self.try_select_env(env_name).await?;
@@ -350,7 +391,70 @@ impl ClientManager {
         // Authenticate
         self.auth_manager.authenticate(env_name, &environment.host, &credentials).await?;
 
-        // Get token and save to both memory and database
+        self.cache_token(env_name).await
+    }
+
+    /// Sign in to an environment with device code credentials
+    ///
+    /// `on_progress` receives the code to show the user first, then a report for
+    /// every poll while waiting for the browser sign-in to finish.
+    pub async fn sign_in_with_device_code(
+        &self,
+        env_name: &str,
+        on_progress: impl FnMut(DeviceCodeProgress),
+    ) -> anyhow::Result<()> {
+        let environment = self.try_select_env(env_name).await?;
+        let (client_id, tenant_id) = self.device_code_credentials(&environment).await?;
+
+        self.auth_manager
+            .authenticate_device_code(env_name, &environment.host, &client_id, &tenant_id, on_progress)
+            .await?;
+
+        self.cache_token(env_name).await
+    }
+
+    /// Request a device code for an environment without waiting for the sign-in
+    ///
+    /// Pass the prompt to `complete_device_code_sign_in` once it has been shown.
+    pub async fn request_device_code(&self, env_name: &str) -> anyhow::Result<DeviceCodePrompt> {
+        let environment = self.try_select_env(env_name).await?;
+        let (client_id, tenant_id) = self.device_code_credentials(&environment).await?;
+
+        self.auth_manager
+            .request_device_code(&environment.host, &client_id, &tenant_id)
+            .await
+    }
+
+    /// Wait for the user to finish signing in with a previously requested device code
+    pub async fn complete_device_code_sign_in(
+        &self,
+        env_name: &str,
+        prompt: &DeviceCodePrompt,
+        on_progress: impl FnMut(DeviceCodeProgress),
+    ) -> anyhow::Result<()> {
+        self.auth_manager.poll_device_code(env_name, prompt, on_progress).await?;
+        self.cache_token(env_name).await
+    }
+
+    /// Whether an environment signs in with the device code flow
+    pub async fn uses_device_code(&self, env_name: &str) -> anyhow::Result<bool> {
+        let environment = self.try_select_env(env_name).await?;
+        let credentials = self.auth_manager.try_select_credentials(&environment.credentials_ref).await?;
+        Ok(matches!(credentials, CredentialSet::DeviceCode { .. }))
+    }
+
+    async fn device_code_credentials(&self, environment: &Environment) -> anyhow::Result<(String, String)> {
+        match self.auth_manager.try_select_credentials(&environment.credentials_ref).await? {
+            CredentialSet::DeviceCode { client_id, tenant_id } => Ok((client_id, tenant_id)),
+            _ => anyhow::bail!(
+                "Credentials '{}' are not device code credentials",
+                environment.credentials_ref
+            ),
+        }
+    }
+
+    /// Copy a fresh token from the auth manager into the cache and the database
+    async fn cache_token(&self, env_name: &str) -> anyhow::Result<()> {
         if let Ok(token) = self.auth_manager.get_token(env_name).await {
             // Save to memory cache
             self.tokens.write().await.insert(env_name.to_string(), token.clone());
@@ -374,7 +478,58 @@ impl ClientManager {
         Ok(DynamicsClient::new(
             environment.host.clone(),
             token_info.access_token,
-        ))
+        )
+        .with_rate_limiter(self.rate_limiter.clone(), env_name)
+        .with_entity_set_overrides(self.entity_set_overrides.read().await.clone())
+        .with_compression(self.compression)
+        .with_timeouts(self.timeouts.clone()))
+    }
+
+    /// Override the entity set name used for an entity in API URLs
+    pub async fn set_entity_set_override(&self, entity_name: &str, entity_set_name: &str) -> anyhow::Result<()> {
+        crate::global_config().set_entity_set_override(entity_name, entity_set_name).await?;
+        self.reload_entity_set_overrides().await
+    }
+
+    /// Remove an entity set name override, falling back to pluralization
+    pub async fn remove_entity_set_override(&self, entity_name: &str) -> anyhow::Result<()> {
+        crate::global_config().delete_entity_set_override(entity_name).await?;
+        self.reload_entity_set_overrides().await
+    }
+
+    /// Entity set name overrides keyed by lowercase logical name, as handed to clients
+    ///
+    /// For code that builds entity set URLs itself; pass the map to
+    /// `resolve_entity_set_name` so overrides apply there too.
+    pub async fn entity_set_overrides(&self) -> Arc<HashMap<String, String>> {
+        self.entity_set_overrides.read().await.clone()
+    }
+
+    /// Entity set name overrides, sorted by entity name
+    pub async fn list_entity_set_overrides(&self) -> Vec<(String, String)> {
+        let mut overrides: Vec<_> = self.entity_set_overrides.read().await
+            .iter()
+            .map(|(entity, entity_set)| (entity.clone(), entity_set.clone()))
+            .collect();
+        overrides.sort();
+        overrides
+    }
+
+    /// Refresh the cached overrides from the config database
+    async fn reload_entity_set_overrides(&self) -> anyhow::Result<()> {
+        let overrides = crate::global_config().load_entity_set_overrides().await?;
+        *self.entity_set_overrides.write().await = Arc::new(overrides);
+        Ok(())
+    }
+
+    /// Rate limiter statistics per environment, e.g. to show which one is being throttled
+    pub fn rate_limiter_stats(&self) -> Vec<RateLimiterStats> {
+        self.rate_limiter.environment_stats()
+    }
+
+    /// Most metadata requests a screen should run at once, from the rate limit options
+    pub fn max_concurrent_fetches(&self) -> usize {
+        self.rate_limiter.max_concurrent_fetches()
     }
 
     /// Get a configured DynamicsClient for the current environment
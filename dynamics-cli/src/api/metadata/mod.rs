@@ -3,13 +3,15 @@
 pub mod models;
 
 pub use models::{
-    EntityMetadata, FieldMetadata, FieldType, FormMetadata, FormStructure, FormTab,
-    FormSection, FormField, RelationshipMetadata, RelationshipType, ViewMetadata,
+    EntityMetadata, EntityOptionSets, FieldMetadata, FieldType, FormMetadata, FormStructure,
+    FormTab, FormSection, FormField, RelationshipMetadata, RelationshipType, ViewMetadata,
     ViewColumn,
 };
 
 use anyhow::Result;
-use roxmltree::Document;
+use roxmltree::{Document, Node};
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap, HashSet};
 
 /// Parse Dynamics 365 metadata XML and extract all entity names
 pub fn parse_entity_list(metadata_xml: &str) -> Result<Vec<String>> {
@@ -37,9 +39,448 @@ pub fn parse_entity_list(metadata_xml: &str) -> Result<Vec<String>> {
     Ok(entities)
 }
 
-/// Parse full entity metadata from XML
-/// TODO: Implement full metadata parsing
-pub fn parse_entity_metadata(_metadata_xml: &str, _entity_name: &str) -> Result<EntityMetadata> {
-    // Placeholder - will implement actual XML parsing later
-    Ok(EntityMetadata::default())
+/// Namespace prefix used for entity and enum types in the Dynamics 365 EDMX
+const CRM_NAMESPACE: &str = "Microsoft.Dynamics.CRM.";
+
+/// Map an EDM primitive type name to a field type
+pub fn edm_field_type(type_name: &str) -> FieldType {
+    match type_name {
+        "Edm.String" => FieldType::String,
+        "Edm.Int16" | "Edm.Int32" | "Edm.Int64" => FieldType::Integer,
+        "Edm.Decimal" | "Edm.Double" => FieldType::Decimal,
+        "Edm.Boolean" => FieldType::Boolean,
+        "Edm.DateTime" | "Edm.DateTimeOffset" | "Edm.Date" => FieldType::DateTime,
+        "Edm.Guid" => FieldType::UniqueIdentifier,
+        other => FieldType::Other(other.to_string()),
+    }
+}
+
+/// Parse the fields and relationships of one entity from metadata XML
+///
+/// Views and forms are not part of the EDMX document and are left empty.
+/// Lookups show up as `_name_value` properties and are reported under their
+/// logical name (`name`) with the target entity taken from the matching
+/// navigation property.
+pub fn parse_entity_metadata(metadata_xml: &str, entity_name: &str) -> Result<EntityMetadata> {
+    let doc = Document::parse(metadata_xml)
+        .map_err(|e| anyhow::anyhow!("Failed to parse metadata XML: {}", e))?;
+
+    let entity_type = find_entity_type(&doc, entity_name)
+        .ok_or_else(|| anyhow::anyhow!("Entity '{}' not found in metadata", entity_name))?;
+
+    let enum_types: HashSet<&str> = doc
+        .descendants()
+        .filter(|node| node.has_tag_name("EnumType"))
+        .filter_map(|node| node.attribute("Name"))
+        .collect();
+
+    let keys: HashSet<&str> = entity_type
+        .children()
+        .filter(|node| node.has_tag_name("Key"))
+        .flat_map(|key| key.children())
+        .filter(|node| node.has_tag_name("PropertyRef"))
+        .filter_map(|node| node.attribute("Name"))
+        .collect();
+
+    // Lookup property (`_name_value`) -> target entity of its navigation property
+    let mut lookup_targets = HashMap::new();
+    for navigation in entity_type.children().filter(|node| node.has_tag_name("NavigationProperty")) {
+        let Some(target) = navigation.attribute("Type").map(strip_namespace) else { continue };
+        for constraint in navigation.children().filter(|node| node.has_tag_name("ReferentialConstraint")) {
+            if let Some(property) = constraint.attribute("Property") {
+                lookup_targets.insert(property, target.to_string());
+            }
+        }
+    }
+
+    let mut fields = Vec::new();
+    for property in entity_type.children().filter(|node| node.has_tag_name("Property")) {
+        let Some(name) = property.attribute("Name") else { continue };
+        let type_name = property.attribute("Type").unwrap_or_default();
+
+        let lookup_name = name.strip_prefix('_').and_then(|n| n.strip_suffix("_value"));
+        let (logical_name, field_type, related_entity, option_set) = match lookup_name {
+            Some(logical_name) => (
+                logical_name.to_string(),
+                FieldType::Lookup,
+                lookup_targets.get(name).cloned(),
+                None,
+            ),
+            None => match type_name.strip_prefix(CRM_NAMESPACE) {
+                Some(enum_name) if enum_types.contains(enum_name) => (
+                    name.to_string(),
+                    FieldType::OptionSet,
+                    None,
+                    Some(enum_name.to_string()),
+                ),
+                _ => (name.to_string(), edm_field_type(type_name), None, None),
+            },
+        };
+
+        fields.push(FieldMetadata {
+            logical_name,
+            display_name: None,
+            field_type,
+            is_required: property.attribute("Nullable") == Some("false"),
+            is_primary_key: keys.contains(name),
+            max_length: property.attribute("MaxLength").and_then(|v| v.parse().ok()),
+            related_entity,
+            option_set,
+        });
+    }
+
+    let mut relationships = Vec::new();
+    for navigation in entity_type.children().filter(|node| node.has_tag_name("NavigationProperty")) {
+        let (Some(name), Some(type_name)) = (navigation.attribute("Name"), navigation.attribute("Type")) else {
+            continue;
+        };
+        let related_entity = strip_namespace(type_name).to_string();
+
+        let (relationship_type, related_attribute) = if type_name.starts_with("Collection(") {
+            let partner = navigation.attribute("Partner").and_then(|partner| {
+                find_entity_type(&doc, &related_entity)?
+                    .children()
+                    .find(|node| node.has_tag_name("NavigationProperty") && node.attribute("Name") == Some(partner))
+            });
+
+            match partner {
+                Some(partner) if partner.attribute("Type").is_some_and(|t| t.starts_with("Collection(")) => {
+                    let target_key = find_entity_type(&doc, &related_entity)
+                        .and_then(entity_key)
+                        .unwrap_or_default();
+                    (RelationshipType::ManyToMany, target_key)
+                }
+                Some(partner) => {
+                    let attribute = referential_property(partner)
+                        .map(|p| lookup_logical_name(p).to_string())
+                        .unwrap_or_default();
+                    (RelationshipType::OneToMany, attribute)
+                }
+                None => (RelationshipType::OneToMany, String::new()),
+            }
+        } else {
+            let attribute = navigation
+                .children()
+                .find(|node| node.has_tag_name("ReferentialConstraint"))
+                .and_then(|c| c.attribute("ReferencedProperty"))
+                .unwrap_or_default();
+            (RelationshipType::ManyToOne, attribute.to_string())
+        };
+
+        relationships.push(RelationshipMetadata {
+            name: name.to_string(),
+            relationship_type,
+            related_entity,
+            related_attribute,
+        });
+    }
+
+    log::debug!(
+        "Parsed {} fields and {} relationships for entity '{}'",
+        fields.len(),
+        relationships.len(),
+        entity_name
+    );
+
+    Ok(EntityMetadata {
+        fields,
+        relationships,
+        ..EntityMetadata::default()
+    })
+}
+
+/// Extract relationships from field list
+/// Includes all Lookup fields and NavigationProperties (collection relationships)
+pub fn extract_relationships(fields: &[FieldMetadata]) -> Vec<RelationshipMetadata> {
+    fields.iter()
+        .filter_map(|f| {
+            // Check for Lookup (ManyToOne) or Relationship:* (OneToMany/ManyToMany)
+            let relationship_type = match &f.field_type {
+                FieldType::Lookup => {
+                    Some(RelationshipType::ManyToOne)
+                }
+                FieldType::Other(t) if t.starts_with("Relationship:") => {
+                    // Extract relationship type from "Relationship:OneToMany"
+                    if t.contains("OneToMany") {
+                        Some(RelationshipType::OneToMany)
+                    } else if t.contains("ManyToMany") {
+                        Some(RelationshipType::ManyToMany)
+                    } else {
+                        Some(RelationshipType::ManyToOne)
+                    }
+                }
+                _ => None,
+            };
+
+            relationship_type.map(|rel_type| RelationshipMetadata {
+                name: f.logical_name.clone(),
+                relationship_type: rel_type,
+                related_entity: f.related_entity.clone().unwrap_or_else(|| "unknown".to_string()),
+                related_attribute: f.logical_name.clone(),
+            })
+        })
+        .collect()
+}
+
+/// Process lookup fields by filtering out _*_value virtual fields and ensuring
+/// base lookup fields are properly typed
+pub fn process_lookup_fields(fields: Vec<FieldMetadata>) -> Vec<FieldMetadata> {
+    // Build map of _*_value fields to their related entity info
+    let mut value_field_map: HashMap<String, Option<String>> = HashMap::new();
+
+    for field in &fields {
+        // Detect _*_value pattern
+        if field.logical_name.starts_with('_') && field.logical_name.ends_with("_value") {
+            // Extract base field name: _cgk_deadlineid_value -> cgk_deadlineid
+            if let Some(base_name) = field.logical_name
+                .strip_prefix('_')
+                .and_then(|s| s.strip_suffix("_value"))
+            {
+                value_field_map.insert(base_name.to_string(), field.related_entity.clone());
+            }
+        }
+    }
+
+    // Filter and update fields
+    fields.into_iter()
+        .filter_map(|mut field| {
+            // Filter out _*_value fields
+            if field.logical_name.starts_with('_') && field.logical_name.ends_with("_value") {
+                log::debug!("Filtering out virtual lookup value field: {}", field.logical_name);
+                return None;
+            }
+
+            // Filter out Virtual type fields (formatted display values)
+            // These include *name, *yominame suffixes for lookups and optionsets
+            if matches!(field.field_type, FieldType::Other(ref t) if t == "Virtual") {
+                log::debug!("Filtering out virtual display field: {}", field.logical_name);
+                return None;
+            }
+
+            // Check if this field has a corresponding _*_value field
+            if let Some(related_entity) = value_field_map.get(&field.logical_name) {
+                // Ensure field is marked as Lookup
+                if !matches!(field.field_type, FieldType::Lookup) {
+                    log::debug!("Converting field {} to Lookup type (found _*_value)", field.logical_name);
+                    field.field_type = FieldType::Lookup;
+                }
+
+                // Update related_entity if we extracted it from the _value field
+                if field.related_entity.is_none() && related_entity.is_some() {
+                    field.related_entity = related_entity.clone();
+                }
+            }
+
+            Some(field)
+        })
+        .collect()
+}
+
+/// Parse the option sets of an entity's `EnumAttributeMetadata` attributes
+///
+/// Expects the response of `EntityDefinitions(...)/Attributes/Microsoft.Dynamics.CRM.EnumAttributeMetadata`
+/// with `OptionSet` and `GlobalOptionSet` expanded. Labels are the user's
+/// localized label, falling back to the first localized one.
+pub fn parse_option_sets(json: &Value) -> Result<EntityOptionSets> {
+    let attributes = json["value"].as_array()
+        .ok_or_else(|| anyhow::anyhow!("Expected 'value' array in response"))?;
+
+    let mut option_sets = EntityOptionSets::default();
+    for attribute in attributes {
+        let Some(logical_name) = attribute["LogicalName"].as_str() else { continue };
+        let options = attribute["OptionSet"]["Options"].as_array()
+            .or_else(|| attribute["GlobalOptionSet"]["Options"].as_array());
+        let Some(options) = options else { continue };
+
+        let labels: BTreeMap<i64, String> = options.iter()
+            .filter_map(|option| {
+                let value = option["Value"].as_i64()?;
+                let label = option["Label"]["UserLocalizedLabel"]["Label"].as_str()
+                    .or_else(|| option["Label"]["LocalizedLabels"][0]["Label"].as_str())?;
+                Some((value, label.to_string()))
+            })
+            .collect();
+        option_sets.attributes.insert(logical_name.to_string(), labels);
+    }
+
+    Ok(option_sets)
+}
+
+/// Find an `<EntityType>` by name, ignoring case
+fn find_entity_type<'a, 'input>(doc: &'a Document<'input>, entity_name: &str) -> Option<Node<'a, 'input>> {
+    doc.descendants().find(|node| {
+        node.has_tag_name("EntityType")
+            && node.attribute("Name").is_some_and(|name| name.eq_ignore_ascii_case(entity_name))
+    })
+}
+
+/// First key property of an `<EntityType>`
+fn entity_key(entity_type: Node<'_, '_>) -> Option<String> {
+    entity_type
+        .children()
+        .find(|node| node.has_tag_name("Key"))?
+        .children()
+        .find(|node| node.has_tag_name("PropertyRef"))?
+        .attribute("Name")
+        .map(str::to_string)
+}
+
+/// Dependent property of a navigation property's referential constraint
+fn referential_property<'a>(navigation: Node<'a, '_>) -> Option<&'a str> {
+    navigation
+        .children()
+        .find(|node| node.has_tag_name("ReferentialConstraint"))?
+        .attribute("Property")
+}
+
+/// `_name_value` -> `name`, anything else unchanged
+fn lookup_logical_name(property: &str) -> &str {
+    property
+        .strip_prefix('_')
+        .and_then(|p| p.strip_suffix("_value"))
+        .unwrap_or(property)
+}
+
+/// `Microsoft.Dynamics.CRM.account` / `Collection(Microsoft.Dynamics.CRM.account)` -> `account`
+fn strip_namespace(type_name: &str) -> &str {
+    let inner = type_name
+        .strip_prefix("Collection(")
+        .and_then(|t| t.strip_suffix(')'))
+        .unwrap_or(type_name);
+    inner.strip_prefix(CRM_NAMESPACE).unwrap_or(inner)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIXTURE: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<edmx:Edmx Version="4.0" xmlns:edmx="http://docs.oasis-open.org/odata/ns/edmx">
+  <edmx:DataServices>
+    <Schema Namespace="Microsoft.Dynamics.CRM" xmlns="http://docs.oasis-open.org/odata/ns/edm">
+      <EnumType Name="industrycode">
+        <Member Name="Accounting" Value="1" />
+      </EnumType>
+      <EntityType Name="account">
+        <Key><PropertyRef Name="accountid" /></Key>
+        <Property Name="accountid" Type="Edm.Guid" Nullable="false" />
+        <Property Name="name" Type="Edm.String" MaxLength="160" />
+        <Property Name="numberofemployees" Type="Edm.Int32" />
+        <Property Name="revenue" Type="Edm.Decimal" />
+        <Property Name="donotemail" Type="Edm.Boolean" />
+        <Property Name="createdon" Type="Edm.DateTimeOffset" />
+        <Property Name="industry" Type="Microsoft.Dynamics.CRM.industrycode" />
+        <Property Name="_primarycontactid_value" Type="Edm.Guid" />
+        <NavigationProperty Name="primarycontactid" Type="Microsoft.Dynamics.CRM.contact" Partner="account_primary_contact">
+          <ReferentialConstraint Property="_primarycontactid_value" ReferencedProperty="contactid" />
+        </NavigationProperty>
+        <NavigationProperty Name="contact_customer_accounts" Type="Collection(Microsoft.Dynamics.CRM.contact)" Partner="parentcustomerid_account" />
+        <NavigationProperty Name="accountleads_association" Type="Collection(Microsoft.Dynamics.CRM.lead)" Partner="accountleads_association" />
+      </EntityType>
+      <EntityType Name="contact">
+        <Key><PropertyRef Name="contactid" /></Key>
+        <Property Name="contactid" Type="Edm.Guid" Nullable="false" />
+        <Property Name="_parentcustomerid_value" Type="Edm.Guid" />
+        <NavigationProperty Name="parentcustomerid_account" Type="Microsoft.Dynamics.CRM.account" Partner="contact_customer_accounts">
+          <ReferentialConstraint Property="_parentcustomerid_value" ReferencedProperty="accountid" />
+        </NavigationProperty>
+      </EntityType>
+      <EntityType Name="lead">
+        <Key><PropertyRef Name="leadid" /></Key>
+        <Property Name="leadid" Type="Edm.Guid" Nullable="false" />
+        <NavigationProperty Name="accountleads_association" Type="Collection(Microsoft.Dynamics.CRM.account)" Partner="accountleads_association" />
+      </EntityType>
+    </Schema>
+  </edmx:DataServices>
+</edmx:Edmx>"#;
+
+    fn field<'a>(metadata: &'a EntityMetadata, name: &str) -> &'a FieldMetadata {
+        metadata.fields.iter().find(|f| f.logical_name == name).unwrap()
+    }
+
+    fn relationship<'a>(metadata: &'a EntityMetadata, name: &str) -> &'a RelationshipMetadata {
+        metadata.relationships.iter().find(|r| r.name == name).unwrap()
+    }
+
+    #[test]
+    fn test_parse_entity_fields() {
+        let metadata = parse_entity_metadata(FIXTURE, "Account").unwrap();
+
+        let id = field(&metadata, "accountid");
+        assert_eq!(id.field_type, FieldType::UniqueIdentifier);
+        assert!(id.is_primary_key && id.is_required);
+
+        let name = field(&metadata, "name");
+        assert_eq!(name.field_type, FieldType::String);
+        assert_eq!(name.max_length, Some(160));
+        assert!(!name.is_required && !name.is_primary_key);
+
+        assert_eq!(field(&metadata, "numberofemployees").field_type, FieldType::Integer);
+        assert_eq!(field(&metadata, "revenue").field_type, FieldType::Decimal);
+        assert_eq!(field(&metadata, "donotemail").field_type, FieldType::Boolean);
+        assert_eq!(field(&metadata, "createdon").field_type, FieldType::DateTime);
+
+        let industry = field(&metadata, "industry");
+        assert_eq!(industry.field_type, FieldType::OptionSet);
+        assert_eq!(industry.option_set.as_deref(), Some("industrycode"));
+
+        let contact = field(&metadata, "primarycontactid");
+        assert_eq!(contact.field_type, FieldType::Lookup);
+        assert_eq!(contact.related_entity.as_deref(), Some("contact"));
+    }
+
+    #[test]
+    fn test_parse_entity_relationships() {
+        let metadata = parse_entity_metadata(FIXTURE, "account").unwrap();
+        assert_eq!(metadata.relationships.len(), 3);
+
+        let lookup = relationship(&metadata, "primarycontactid");
+        assert_eq!(lookup.relationship_type, RelationshipType::ManyToOne);
+        assert_eq!(lookup.related_entity, "contact");
+        assert_eq!(lookup.related_attribute, "contactid");
+
+        let children = relationship(&metadata, "contact_customer_accounts");
+        assert_eq!(children.relationship_type, RelationshipType::OneToMany);
+        assert_eq!(children.related_entity, "contact");
+        assert_eq!(children.related_attribute, "parentcustomerid");
+
+        let leads = relationship(&metadata, "accountleads_association");
+        assert_eq!(leads.relationship_type, RelationshipType::ManyToMany);
+        assert_eq!(leads.related_entity, "lead");
+        assert_eq!(leads.related_attribute, "leadid");
+    }
+
+    #[test]
+    fn test_unknown_entity_is_an_error() {
+        assert!(parse_entity_metadata(FIXTURE, "opportunity").is_err());
+    }
+
+    #[test]
+    fn test_parse_local_and_global_option_sets() {
+        let json = serde_json::json!({
+            "value": [
+                {
+                    "LogicalName": "industrycode",
+                    "OptionSet": { "Options": [
+                        { "Value": 1, "Label": { "UserLocalizedLabel": { "Label": "Accounting" } } },
+                        { "Value": 2, "Label": { "UserLocalizedLabel": null, "LocalizedLabels": [{ "Label": "Agriculture" }] } }
+                    ] },
+                    "GlobalOptionSet": null
+                },
+                {
+                    "LogicalName": "statuscode",
+                    "OptionSet": null,
+                    "GlobalOptionSet": { "Options": [
+                        { "Value": 1, "Label": { "UserLocalizedLabel": { "Label": "Active" } } }
+                    ] }
+                }
+            ]
+        });
+
+        let option_sets = parse_option_sets(&json).unwrap();
+        assert_eq!(option_sets.label("industrycode", 1), Some("Accounting"));
+        assert_eq!(option_sets.label("industrycode", 2), Some("Agriculture"));
+        assert_eq!(option_sets.label("industrycode", 3), None);
+        assert_eq!(option_sets.label("statuscode", 1), Some("Active"));
+    }
 }
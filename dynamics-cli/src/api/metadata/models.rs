@@ -1,6 +1,7 @@
 //! Dynamics 365 metadata models
 
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
 
 /// Dynamics 365 field metadata
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -12,6 +13,8 @@ pub struct FieldMetadata {
     pub is_primary_key: bool,
     pub max_length: Option<i32>,
     pub related_entity: Option<String>, // For lookups
+    #[serde(default)]
+    pub option_set: Option<String>, // For option sets backed by a named enum type
 }
 
 /// Field data types in Dynamics 365
@@ -115,6 +118,20 @@ pub struct FormMetadata {
     pub form_structure: Option<FormStructure>,
 }
 
+/// Choice labels of an entity's option set attributes (local and global)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct EntityOptionSets {
+    /// Attribute logical name -> option value -> label
+    pub attributes: HashMap<String, BTreeMap<i64, String>>,
+}
+
+impl EntityOptionSets {
+    /// Label of `value` in `attribute`'s option set
+    pub fn label(&self, attribute: &str, value: i64) -> Option<&str> {
+        self.attributes.get(attribute)?.get(&value).map(String::as_str)
+    }
+}
+
 /// Complete entity metadata
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EntityMetadata {
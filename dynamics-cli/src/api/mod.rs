@@ -6,8 +6,11 @@
 //! batch processing, and enterprise-grade features.
 
 pub mod auth;
+pub mod certificate;
 pub mod client;
+pub mod compression;
 pub mod constants;
+pub mod error;
 pub mod manager;
 pub mod metadata;
 pub mod models;
@@ -16,15 +19,16 @@ pub mod pluralization;
 pub mod query;
 pub mod resilience;
 
-pub use auth::AuthManager;
-pub use client::DynamicsClient;
+pub use auth::{AuthManager, DeviceCodeProgress, DeviceCodePrompt};
+pub use client::{ConditionalRecord, DynamicsClient};
+pub use error::ApiError;
 pub use manager::ClientManager;
-pub use models::{Environment, CredentialSet, TokenInfo};
+pub use models::{Environment, CredentialSet, TokenInfo, WhoAmIResponse, HealthCheck, OrganizationInfo};
 pub use operations::{Operation, OperationResult, Operations};
 pub use query::{Query, QueryBuilder, QueryResult, Filter, FilterValue, OrderBy};
-pub use resilience::{RetryPolicy, RetryConfig, ResilienceConfig, RateLimitConfig, MonitoringConfig, LogLevel, RateLimiterStats, RateLimiter, RetryableError, ApiLogger, OperationContext, OperationMetrics, MetricsCollector, MetricsSnapshot, OperationTypeMetrics, EntityMetrics, GlobalMetrics};
+pub use resilience::{RetryPolicy, RetryConfig, ResilienceConfig, RateLimitConfig, EnvironmentRateLimit, MonitoringConfig, LogLevel, RateLimiterStats, RateLimiter, RetryableError, ApiLogger, OperationContext, OperationMetrics, MetricsCollector, MetricsSnapshot, OperationTypeMetrics, EntityMetrics, GlobalMetrics};
 pub use metadata::{
-    parse_entity_list, parse_entity_metadata,
-    EntityMetadata, FieldMetadata, FieldType, FormMetadata, RelationshipMetadata,
+    parse_entity_list, parse_entity_metadata, parse_option_sets,
+    EntityMetadata, EntityOptionSets, FieldMetadata, FieldType, FormMetadata, RelationshipMetadata,
     RelationshipType, ViewMetadata,
 };
\ No newline at end of file
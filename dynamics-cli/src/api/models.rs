@@ -1,4 +1,5 @@
-use std::time::SystemTime;
+use serde::Deserialize;
+use std::time::{Duration, SystemTime};
 
 /// Environment configuration linking to credentials
 #[derive(Debug, Clone)]
@@ -30,6 +31,8 @@ pub enum CredentialSet {
         client_id: String,
         tenant_id: String,
         cert_path: String,
+        /// Password for a PFX file or an encrypted PEM key
+        cert_password: Option<String>,
     },
 }
 
@@ -39,4 +42,150 @@ pub struct TokenInfo {
     pub access_token: String,
     pub expires_at: SystemTime,
     pub refresh_token: Option<String>,
+}
+
+impl TokenInfo {
+    /// How long before `expires_at` a token is treated as expired, so it is
+    /// never sent with only a few seconds of validity left
+    pub const EXPIRY_SKEW: Duration = Duration::from_secs(60);
+
+    /// Whether the token has expired or is about to
+    pub fn is_expired(&self) -> bool {
+        SystemTime::now() + Self::EXPIRY_SKEW >= self.expires_at
+    }
+}
+
+/// Response of the `WhoAmI` function: the signed-in user and their organization
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct WhoAmIResponse {
+    pub user_id: uuid::Uuid,
+    pub business_unit_id: uuid::Uuid,
+    pub organization_id: uuid::Uuid,
+}
+
+/// Result of [`crate::api::DynamicsClient::health_check`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct HealthCheck {
+    /// Round trip of the `WhoAmI` request
+    pub latency: Duration,
+    pub identity: WhoAmIResponse,
+}
+
+/// The current organization, from `RetrieveCurrentOrganization`
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrganizationInfo {
+    pub organization_id: uuid::Uuid,
+    /// Display name
+    pub name: String,
+    pub unique_name: String,
+    /// Server version, e.g. `9.2.24054.00185`
+    pub version: String,
+    /// Web application URL; `None` if the endpoint list doesn't include one
+    pub url: Option<String>,
+}
+
+/// Response body of `RetrieveCurrentOrganization`
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub(crate) struct RetrieveCurrentOrganizationResponse {
+    detail: OrganizationDetail,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct OrganizationDetail {
+    organization_id: uuid::Uuid,
+    friendly_name: String,
+    unique_name: String,
+    organization_version: String,
+    endpoints: OrganizationEndpoints,
+}
+
+/// Endpoint types and their URLs, as parallel lists
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct OrganizationEndpoints {
+    keys: Vec<String>,
+    values: Vec<String>,
+}
+
+impl From<RetrieveCurrentOrganizationResponse> for OrganizationInfo {
+    fn from(response: RetrieveCurrentOrganizationResponse) -> Self {
+        let detail = response.detail;
+        let endpoints = detail.endpoints;
+        let url = endpoints.keys.iter()
+            .position(|key| key == "WebApplication")
+            .and_then(|idx| endpoints.values.get(idx).cloned());
+
+        Self {
+            organization_id: detail.organization_id,
+            name: detail.friendly_name,
+            unique_name: detail.unique_name,
+            version: detail.organization_version,
+            url,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_token_expires_within_skew() {
+        let token = |expires_in: u64| TokenInfo {
+            access_token: "token".to_string(),
+            expires_at: SystemTime::now() + Duration::from_secs(expires_in),
+            refresh_token: None,
+        };
+
+        assert!(!token(3600).is_expired());
+        assert!(token(30).is_expired());
+        assert!(token(0).is_expired());
+    }
+
+    #[test]
+    fn test_who_am_i_parses_response() {
+        let body = r#"{
+            "@odata.context": "https://org.crm.dynamics.com/api/data/v9.2/$metadata#Microsoft.Dynamics.CRM.WhoAmIResponse",
+            "BusinessUnitId": "6f202e6c-e471-ec11-8941-000d3a8b7a1c",
+            "UserId": "9a3b5f5e-f571-ec11-8941-000d3a8b7a1c",
+            "OrganizationId": "0b0e0c8a-e371-ec11-8941-000d3a8b7a1c"
+        }"#;
+
+        let who: WhoAmIResponse = serde_json::from_str(body).unwrap();
+        assert_eq!(who.user_id.to_string(), "9a3b5f5e-f571-ec11-8941-000d3a8b7a1c");
+        assert_eq!(who.organization_id.to_string(), "0b0e0c8a-e371-ec11-8941-000d3a8b7a1c");
+    }
+
+    #[test]
+    fn test_organization_info_picks_web_application_url() {
+        let body = r#"{
+            "Detail": {
+                "OrganizationId": "0b0e0c8a-e371-ec11-8941-000d3a8b7a1c",
+                "FriendlyName": "Contoso",
+                "OrganizationVersion": "9.2.24054.00185",
+                "EnvironmentId": "Default-1234",
+                "UrlName": "contoso",
+                "UniqueName": "unq0b0e0c8ae371",
+                "State": "Enabled",
+                "Endpoints": {
+                    "Count": 2,
+                    "IsReadOnly": false,
+                    "Keys": ["OrganizationService", "WebApplication"],
+                    "Values": [
+                        "https://contoso.api.crm.dynamics.com/XRMServices/2011/Organization.svc",
+                        "https://contoso.crm.dynamics.com/"
+                    ]
+                }
+            }
+        }"#;
+
+        let response: RetrieveCurrentOrganizationResponse = serde_json::from_str(body).unwrap();
+        let info = OrganizationInfo::from(response);
+        assert_eq!(info.name, "Contoso");
+        assert_eq!(info.version, "9.2.24054.00185");
+        assert_eq!(info.url.as_deref(), Some("https://contoso.crm.dynamics.com/"));
+    }
 }
\ No newline at end of file
@@ -10,12 +10,24 @@ use uuid::Uuid;
 
 const CRLF: &str = "\r\n";
 
+/// How operations are grouped inside a $batch request
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BatchMode {
+    /// All operations in one changeset: all succeed or all are rolled back
+    #[default]
+    ChangeSet,
+    /// Each operation is its own request; failures don't affect the others
+    Independent,
+}
+
 /// Builder for creating Dynamics 365 $batch requests
 pub struct BatchRequestBuilder {
     batch_id: String,
     changeset_id: String,
     base_url: String,
     requests: Vec<BatchItem>,
+    caller_id: Option<Uuid>,
+    return_representation: bool,
 }
 
 /// Individual item in a batch request
@@ -23,6 +35,8 @@ pub struct BatchRequestBuilder {
 pub enum BatchItem {
     /// A direct batch request (like GET operations)
     DirectRequest {
+        /// Content-ID part header, used to map the response back
+        content_id: Option<u32>,
         method: String,
         path: String,
         headers: Vec<(String, String)>,
@@ -52,9 +66,26 @@ impl BatchRequestBuilder {
             changeset_id: format!("changeset_{}", Uuid::new_v4().to_string().replace('-', "_")),
             base_url: base_url.into(),
             requests: Vec::new(),
+            caller_id: None,
+            return_representation: false,
         }
     }
 
+    /// Ask the server to return the created/updated entity for writes added after this call
+    pub fn return_representation(mut self, enabled: bool) -> Self {
+        self.return_representation = enabled;
+        self
+    }
+
+    /// Impersonate a user (by `systemuserid`) for operations added after this call
+    ///
+    /// Overrides the impersonation of the client sending the batch, so changesets
+    /// within one batch can act as different users. `None` reverts to the client's.
+    pub fn impersonate(mut self, user_id: Option<Uuid>) -> Self {
+        self.caller_id = user_id;
+        self
+    }
+
     /// Add operations as a changeset (transactional)
     pub fn add_changeset(mut self, operations: &[Operation]) -> Self {
         if operations.is_empty() {
@@ -79,8 +110,50 @@ impl BatchRequestBuilder {
         self.add_changeset(&[operation.clone()])
     }
 
-    /// Convert an Operation to a ChangeSetOperation
+    /// Add operations as independent (non-transactional) requests
+    ///
+    /// Each request gets a Content-ID matching its 1-based position, so
+    /// responses can be mapped back to the originating operation.
+    pub fn add_independent(mut self, operations: &[Operation]) -> Self {
+        for (index, operation) in operations.iter().enumerate() {
+            let request = self.operation_to_changeset_operation(operation, (index + 1) as u32);
+
+            self.requests.push(BatchItem::DirectRequest {
+                content_id: Some(request.content_id),
+                method: request.method,
+                path: request.path,
+                headers: request.headers,
+                body: request.body,
+            });
+        }
+
+        self
+    }
+
+    /// Add operations using the given batch mode
+    pub fn add_operations(self, operations: &[Operation], mode: BatchMode) -> Self {
+        match mode {
+            BatchMode::ChangeSet => self.add_changeset(operations),
+            BatchMode::Independent => self.add_independent(operations),
+        }
+    }
+
+    /// Convert an Operation to a ChangeSetOperation, carrying the current impersonation
+    /// and representation preference
     fn operation_to_changeset_operation(&self, operation: &Operation, content_id: u32) -> ChangeSetOperation {
+        let mut request = self.operation_request(operation, content_id);
+        // Upserts always ask, since only then does 201 vs 200 tell a create from an update
+        if (self.return_representation && operation.returns_entity()) || matches!(operation, Operation::Upsert { .. }) {
+            request.headers.push(("Prefer".to_string(), headers::PREFER_RETURN_REPRESENTATION.to_string()));
+        }
+        if let Some(caller_id) = self.caller_id {
+            request.headers.push((headers::MSCRM_CALLER_ID.to_string(), caller_id.to_string()));
+        }
+        request
+    }
+
+    /// Build the request for a single Operation
+    fn operation_request(&self, operation: &Operation, content_id: u32) -> ChangeSetOperation {
         match operation {
             Operation::Create { entity, data } => {
                 let path = format!("{}/{}", constants::api_path(), entity);
@@ -92,7 +165,6 @@ impl BatchRequestBuilder {
                     path,
                     headers: vec![
                         ("Content-Type".to_string(), headers::CONTENT_TYPE_JSON.to_string()),
-                        ("Prefer".to_string(), headers::PREFER_RETURN_REPRESENTATION.to_string()),
                     ],
                     body: Some(body),
                 }
@@ -116,12 +188,11 @@ impl BatchRequestBuilder {
                     path,
                     headers: vec![
                         ("Content-Type".to_string(), headers::CONTENT_TYPE_JSON.to_string()),
-                        ("Prefer".to_string(), headers::PREFER_RETURN_REPRESENTATION.to_string()),
                     ],
                     body: Some(body),
                 }
             }
-            Operation::Update { entity, id, data } => {
+            Operation::Update { entity, id, data, etag } => {
                 let path = format!("{}/{}({})", constants::api_path(), entity, id);
                 let body = serde_json::to_string(data).unwrap_or_default();
 
@@ -131,8 +202,7 @@ impl BatchRequestBuilder {
                     path,
                     headers: vec![
                         ("Content-Type".to_string(), headers::CONTENT_TYPE_JSON.to_string()),
-                        ("If-Match".to_string(), headers::IF_MATCH_ANY.to_string()),
-                        ("Prefer".to_string(), headers::PREFER_RETURN_REPRESENTATION.to_string()),
+                        ("If-Match".to_string(), etag.as_deref().unwrap_or(headers::IF_MATCH_ANY).to_string()),
                     ],
                     body: Some(body),
                 }
@@ -148,8 +218,8 @@ impl BatchRequestBuilder {
                     body: None,
                 }
             }
-            Operation::Upsert { entity, key_field, key_value, data } => {
-                let path = format!("{}/{}({}='{}')", constants::api_path(), entity, key_field, key_value);
+            Operation::Upsert { entity, keys, data } => {
+                let path = constants::upsert_path(entity, keys);
                 let body = serde_json::to_string(data).unwrap_or_default();
 
                 ChangeSetOperation {
@@ -158,29 +228,17 @@ impl BatchRequestBuilder {
                     path,
                     headers: vec![
                         ("Content-Type".to_string(), headers::CONTENT_TYPE_JSON.to_string()),
-                        ("Prefer".to_string(), headers::PREFER_RETURN_REPRESENTATION.to_string()),
                     ],
                     body: Some(body),
                 }
             }
             Operation::AssociateRef { entity, entity_ref, navigation_property, target_ref } => {
                 // POST /entities(id)/navigation_property/$ref with body {"@odata.id": "target"}
-                let path = format!("{}/{}({})/{}/$ref",
-                    constants::api_path(),
-                    entity,
-                    entity_ref,
-                    navigation_property
-                );
+                let path = constants::associate_ref_path(entity, entity_ref, navigation_property);
 
                 // Convert relative target_ref to absolute URL for @odata.id
-                let absolute_target_ref = if target_ref.starts_with("http://") || target_ref.starts_with("https://") {
-                    target_ref.clone()
-                } else {
-                    format!("{}{}", self.base_url, target_ref)
-                };
-
                 let body_json = serde_json::json!({
-                    "@odata.id": absolute_target_ref
+                    "@odata.id": constants::odata_id_url(&self.base_url, target_ref)
                 });
                 let body = serde_json::to_string(&body_json).unwrap_or_default();
 
@@ -194,6 +252,15 @@ impl BatchRequestBuilder {
                     body: Some(body),
                 }
             }
+            Operation::DisassociateRef { entity, entity_ref, navigation_property, target_id } => {
+                ChangeSetOperation {
+                    content_id,
+                    method: methods::DELETE.to_string(),
+                    path: constants::disassociate_ref_path(entity, entity_ref, navigation_property, target_id),
+                    headers: vec![],
+                    body: None,
+                }
+            }
         }
     }
 
@@ -203,11 +270,14 @@ impl BatchRequestBuilder {
 
         for request in &self.requests {
             match request {
-                BatchItem::DirectRequest { method, path, headers, body: req_body } => {
+                BatchItem::DirectRequest { content_id, method, path, headers, body: req_body } => {
                     // Add direct request to batch
                     body.push_str(&format!("--{}{}", self.batch_id, CRLF));
                     body.push_str(&format!("Content-Type: application/http{}", CRLF));
                     body.push_str(&format!("Content-Transfer-Encoding: binary{}", CRLF));
+                    if let Some(content_id) = content_id {
+                        body.push_str(&format!("Content-ID: {}{}", content_id, CRLF));
+                    }
                     body.push_str(CRLF);
 
                     // HTTP request line
@@ -355,6 +425,27 @@ mod tests {
         assert!(batch.body.ends_with("--\r\n"));
     }
 
+    #[test]
+    fn test_independent_operations_outside_changeset() {
+        let operations = vec![
+            Operation::create("contacts", json!({"firstname": "John"})),
+            Operation::delete("contacts", "789-012"),
+        ];
+
+        let batch = BatchRequestBuilder::new("https://test.crm.dynamics.com")
+            .add_operations(&operations, BatchMode::Independent)
+            .build();
+
+        assert!(!batch.body.contains("changeset_"));
+        assert!(batch.body.contains(
+            "Content-Transfer-Encoding: binary\r\nContent-ID: 1\r\n\r\nPOST /api/data/v9.2/contacts HTTP/1.1"
+        ));
+        assert!(batch.body.contains(
+            "Content-Transfer-Encoding: binary\r\nContent-ID: 2\r\n\r\nDELETE /api/data/v9.2/contacts(789-012) HTTP/1.1"
+        ));
+        assert!(batch.body.ends_with("--\r\n"));
+    }
+
     #[test]
     fn test_upsert_operation() {
         let operation = Operation::upsert(
@@ -371,4 +462,106 @@ mod tests {
         assert!(batch.body.contains("PATCH /api/data/v9.2/contacts(emailaddress1='test@example.com') HTTP/1.1"));
         assert!(batch.body.contains("\"firstname\":\"Jane\""));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_upsert_composite_key() {
+        let operation = Operation::upsert_with_keys(
+            "cgk_deadlines",
+            vec![
+                ("cgk_name".to_string(), json!("Kid's day")),
+                ("cgk_year".to_string(), json!(2025)),
+            ],
+            json!({"cgk_info": "x"}),
+        );
+
+        let batch = BatchRequestBuilder::new("https://test.crm.dynamics.com")
+            .add_operation(&operation)
+            .build();
+
+        assert!(batch.body.contains("PATCH /api/data/v9.2/cgk_deadlines(cgk_name='Kid''s%20day',cgk_year=2025) HTTP/1.1"));
+    }
+
+    #[test]
+    fn test_update_if_match_uses_etag() {
+        let unconditional = Operation::update("contacts", "c1", json!({"firstname": "Jane"}));
+        let conditional = Operation::update_if_match("contacts", "c2", "W/\"12345\"", json!({"firstname": "Joe"}));
+
+        let batch = BatchRequestBuilder::new("https://test.crm.dynamics.com")
+            .add_operation(&unconditional)
+            .add_operation(&conditional)
+            .build();
+
+        assert!(batch.body.contains("PATCH /api/data/v9.2/contacts(c1) HTTP/1.1\r\nContent-Type: application/json\r\nIf-Match: *\r\n"));
+        assert!(batch.body.contains("PATCH /api/data/v9.2/contacts(c2) HTTP/1.1\r\nContent-Type: application/json\r\nIf-Match: W/\"12345\"\r\n"));
+    }
+
+    #[test]
+    fn test_impersonation_per_changeset() {
+        let user = Uuid::parse_str("11111111-2222-3333-4444-555555555555").unwrap();
+
+        let batch = BatchRequestBuilder::new("https://test.crm.dynamics.com")
+            .impersonate(Some(user))
+            .add_operation(&Operation::create("contacts", json!({"firstname": "Jane"})))
+            .impersonate(None)
+            .add_operation(&Operation::create("contacts", json!({"firstname": "Joe"})))
+            .build();
+
+        assert_eq!(batch.body.matches("MSCRMCallerID: 11111111-2222-3333-4444-555555555555\r\n").count(), 1);
+        let impersonated = batch.body.find("MSCRMCallerID").unwrap();
+        assert!(impersonated < batch.body.find("Jane").unwrap());
+    }
+
+    #[test]
+    fn test_associate_operation_payload() {
+        let operation = Operation::associate("accounts", "a1", "contact_customer_accounts", ("contacts", "c1"));
+
+        let batch = BatchRequestBuilder::new("https://test.crm.dynamics.com")
+            .add_operation(&operation)
+            .build();
+
+        assert!(batch.body.contains("POST /api/data/v9.2/accounts(a1)/contact_customer_accounts/$ref HTTP/1.1"));
+        assert!(batch.body.contains("{\"@odata.id\":\"https://test.crm.dynamics.com/api/data/v9.2/contacts(c1)\"}"));
+    }
+
+    #[test]
+    fn test_bulk_associate_single_changeset() {
+        let operations = crate::api::operations::Operations::new()
+            .associate_many("accounts", "a1", "contact_customer_accounts", vec![("contacts", "c1"), ("contacts", "c2")]);
+
+        let batch = BatchRequestBuilder::new("https://test.crm.dynamics.com")
+            .add_changeset(operations.operations())
+            .build();
+
+        assert_eq!(batch.body.matches("/contact_customer_accounts/$ref HTTP/1.1").count(), 2);
+        assert!(batch.body.contains("contacts(c2)"));
+    }
+
+    #[test]
+    fn test_disassociate_operation_url() {
+        let operation = Operation::disassociate("accounts", "a1", "contact_customer_accounts", "c1");
+
+        let batch = BatchRequestBuilder::new("https://test.crm.dynamics.com")
+            .add_operation(&operation)
+            .build();
+
+        assert!(batch.body.contains("DELETE /api/data/v9.2/accounts(a1)/contact_customer_accounts(c1)/$ref HTTP/1.1"));
+        assert_eq!(operation.http_method(), "DELETE");
+    }
+
+    #[test]
+    fn test_return_representation_is_opt_in() {
+        let create = Operation::create("contacts", json!({"firstname": "Jane"}));
+        let delete = Operation::delete("contacts", "c1");
+
+        let batch = BatchRequestBuilder::new("https://test.crm.dynamics.com")
+            .add_changeset(&[create.clone(), delete.clone()])
+            .build();
+        assert!(!batch.body.contains("Prefer: return=representation"));
+
+        let batch = BatchRequestBuilder::new("https://test.crm.dynamics.com")
+            .return_representation(true)
+            .add_changeset(&[create, delete])
+            .build();
+        assert_eq!(batch.body.matches("Prefer: return=representation\r\n").count(), 1);
+    }
+}
@@ -5,5 +5,5 @@
 pub mod builder;
 pub mod parser;
 
-pub use builder::{BatchRequest, BatchRequestBuilder};
+pub use builder::{BatchMode, BatchRequest, BatchRequestBuilder};
 pub use parser::{BatchResponse, BatchResponseItem, BatchResponseParser};
\ No newline at end of file
@@ -249,38 +249,59 @@ impl BatchResponseParser {
     }
 
     /// Map batch response items to operation results
+    ///
+    /// Responses are matched by Content-ID when the server provides them, and by
+    /// position only when every operation got a response. Operations left without a
+    /// response after a failure were rolled back with their changeset and are
+    /// reported as not applied, referencing the failure that caused it.
     fn map_to_operation_results(
         batch_response: BatchResponse,
         operations: &[Operation],
     ) -> anyhow::Result<Vec<OperationResult>> {
         let mut results = Vec::new();
 
+        let has_content_ids = batch_response.results.iter().any(|item| item.content_id.is_some());
+        let match_by_index = !has_content_ids && batch_response.results.len() == operations.len();
+
+        // Describe the failure that rolled back the changeset, for unmatched operations
+        let rollback_cause = batch_response
+            .results
+            .iter()
+            .find(|item| !item.is_success)
+            .map(|item| {
+                let message = Self::extract_error_message(item.body.as_ref())
+                    .unwrap_or_else(|| format!("HTTP {}", item.status_code));
+                match item.content_id {
+                    Some(id) => format!("operation {} failed: {}", id, message),
+                    None => message,
+                }
+            });
+
         for (index, operation) in operations.iter().enumerate() {
             // Find matching response by Content-ID or index
-            let response_item = batch_response
-                .results
-                .iter()
-                .find(|item| {
-                    item.content_id.map(|id| id as usize) == Some(index + 1)
-                })
-                .or_else(|| batch_response.results.get(index));
+            let response_item = if match_by_index {
+                batch_response.results.get(index)
+            } else {
+                batch_response
+                    .results
+                    .iter()
+                    .find(|item| item.content_id.map(|id| id as usize) == Some(index + 1))
+            };
 
             if let Some(item) = response_item {
-                let data = if item.is_success {
-                    // Try to parse JSON response
-                    item.body
-                        .as_ref()
-                        .and_then(|body| {
-                            let trimmed = body.trim();
-                            if trimmed.is_empty() {
-                                None
-                            } else {
-                                serde_json::from_str::<Value>(trimmed).ok()
-                            }
-                        })
-                } else {
-                    None
-                };
+                // Successful responses keep their JSON; failures keep the raw error body too
+                let data = item.body
+                    .as_ref()
+                    .and_then(|body| {
+                        let trimmed = body.trim();
+                        if trimmed.is_empty() {
+                            None
+                        } else if item.is_success {
+                            serde_json::from_str::<Value>(trimmed).ok()
+                        } else {
+                            Some(serde_json::from_str::<Value>(trimmed).unwrap_or_else(|_| Value::String(trimmed.to_string())))
+                        }
+                    });
 
                 let error = if !item.is_success {
                     // Try to extract detailed error from response body
@@ -301,11 +322,15 @@ impl BatchResponseParser {
                 });
             } else {
                 // No response found for this operation
+                let error = match rollback_cause {
+                    Some(ref cause) => format!("Not applied: changeset rolled back ({})", cause),
+                    None => "No response found in batch".to_string(),
+                };
                 results.push(OperationResult {
                     operation: operation.clone(),
                     success: false,
                     data: None,
-                    error: Some("No response found in batch".to_string()),
+                    error: Some(error),
                     status_code: None,
                     headers: HashMap::new(),
                 });
@@ -352,7 +377,7 @@ impl BatchResponseParser {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::api::operations::Operation;
+    use crate::api::operations::{Operation, OperationOutcome, Operations};
     use serde_json::json;
 
     #[test]
@@ -413,6 +438,129 @@ OData-Version: 4.0
         assert_eq!(results[0].status_code, Some(400));
     }
 
+    #[test]
+    fn test_mixed_result_batch_surfaces_failed_items() {
+        let response = r#"--batchresponse_f44bd09d-573f-4a30-bca0-2e500ee7e139
+Content-Type: multipart/mixed; boundary=changesetresponse_ee30dcdb-1094-4c24-8170-262eae9336a4
+
+--changesetresponse_ee30dcdb-1094-4c24-8170-262eae9336a4
+Content-Type: application/http
+Content-Transfer-Encoding: binary
+Content-ID: 1
+
+HTTP/1.1 204 No Content
+OData-Version: 4.0
+OData-EntityId: https://test.crm.dynamics.com/api/data/v9.2/contacts(abc-123)
+
+--changesetresponse_ee30dcdb-1094-4c24-8170-262eae9336a4
+Content-Type: application/http
+Content-Transfer-Encoding: binary
+Content-ID: 2
+
+HTTP/1.1 400 Bad Request
+Content-Type: application/json; odata.metadata=minimal
+OData-Version: 4.0
+
+{"error":{"code":"0x80040203","message":"Invalid lastname"}}
+--changesetresponse_ee30dcdb-1094-4c24-8170-262eae9336a4--
+--batchresponse_f44bd09d-573f-4a30-bca0-2e500ee7e139--"#;
+
+        let operations = vec![
+            Operation::create("contacts", json!({"firstname": "John"})),
+            Operation::create("contacts", json!({"lastname": ""})),
+            Operation::create("contacts", json!({"firstname": "Jane"})),
+        ];
+
+        let results = BatchResponseParser::parse(response, &operations).unwrap();
+        assert_eq!(results.len(), 3);
+
+        assert_eq!(results[0].outcome(), OperationOutcome::Succeeded);
+
+        // The rejected item carries the server message
+        assert_eq!(results[1].outcome(), OperationOutcome::Failed);
+        assert_eq!(results[1].status_code, Some(400));
+        assert!(results[1].error.as_deref().unwrap().contains("Invalid lastname"));
+
+        // The item without a response is reported as rolled back, pointing at the failure
+        assert_eq!(results[2].outcome(), OperationOutcome::NotApplied);
+        let error = results[2].error.as_deref().unwrap();
+        assert!(error.starts_with("Not applied"));
+        assert!(error.contains("operation 2 failed"));
+
+        // Only the unsuccessful items are retried
+        let retry = Operations::from_unsuccessful(&results);
+        assert_eq!(retry.len(), 2);
+        match &retry.operations()[0] {
+            Operation::Create { data, .. } => assert_eq!(data, &json!({"lastname": ""})),
+            other => panic!("unexpected operation: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_unattributed_failure_not_assigned_to_first_operation() {
+        let response = r#"--batchresponse_f44bd09d-573f-4a30-bca0-2e500ee7e139
+Content-Type: application/http
+Content-Transfer-Encoding: binary
+
+HTTP/1.1 400 Bad Request
+Content-Type: application/json; odata.metadata=minimal
+OData-Version: 4.0
+
+{"error":{"code":"0x80040203","message":"Invalid lastname"}}
+--batchresponse_f44bd09d-573f-4a30-bca0-2e500ee7e139--"#;
+
+        let operations = vec![
+            Operation::create("contacts", json!({"firstname": "John"})),
+            Operation::create("contacts", json!({"lastname": ""})),
+        ];
+
+        let results = BatchResponseParser::parse(response, &operations).unwrap();
+        assert!(results.iter().all(|r| r.outcome() == OperationOutcome::NotApplied));
+        assert!(results[0].error.as_deref().unwrap().contains("Invalid lastname"));
+    }
+
+    #[test]
+    fn test_independent_batch_maps_failures_to_their_index() {
+        let response = r#"--batchresponse_f44bd09d-573f-4a30-bca0-2e500ee7e139
+Content-Type: application/http
+Content-Transfer-Encoding: binary
+
+HTTP/1.1 204 No Content
+OData-Version: 4.0
+
+--batchresponse_f44bd09d-573f-4a30-bca0-2e500ee7e139
+Content-Type: application/http
+Content-Transfer-Encoding: binary
+
+HTTP/1.1 400 Bad Request
+Content-Type: application/json; odata.metadata=minimal
+OData-Version: 4.0
+
+{"error":{"code":"0x80040203","message":"Invalid lastname"}}
+--batchresponse_f44bd09d-573f-4a30-bca0-2e500ee7e139
+Content-Type: application/http
+Content-Transfer-Encoding: binary
+
+HTTP/1.1 204 No Content
+OData-Version: 4.0
+
+--batchresponse_f44bd09d-573f-4a30-bca0-2e500ee7e139--"#;
+
+        let operations = vec![
+            Operation::create("contacts", json!({"firstname": "John"})),
+            Operation::create("contacts", json!({"lastname": ""})),
+            Operation::create("contacts", json!({"firstname": "Jane"})),
+        ];
+
+        let results = BatchResponseParser::parse(response, &operations).unwrap();
+
+        // Independent requests are not rolled back: only the failed one is reported
+        assert_eq!(results[0].outcome(), OperationOutcome::Succeeded);
+        assert_eq!(results[1].outcome(), OperationOutcome::Failed);
+        assert!(results[1].error.as_deref().unwrap().contains("Invalid lastname"));
+        assert_eq!(results[2].outcome(), OperationOutcome::Succeeded);
+    }
+
     #[test]
     fn test_extract_batch_boundary() {
         let text = "--batchresponse_12345\nContent-Type: application/http";
@@ -7,6 +7,6 @@ pub mod operation;
 pub mod operations;
 pub mod batch;
 
-pub use operation::{Operation, OperationResult};
+pub use operation::{Operation, OperationOutcome, OperationResult, UpsertOutcome};
 pub use operations::Operations;
-pub use batch::{BatchRequest, BatchRequestBuilder, BatchResponseParser};
\ No newline at end of file
+pub use batch::{BatchMode, BatchRequest, BatchRequestBuilder, BatchResponseParser};
\ No newline at end of file
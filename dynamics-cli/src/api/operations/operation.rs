@@ -33,6 +33,10 @@ pub enum Operation {
         id: String,
         /// Updated field data as JSON
         data: Value,
+        /// `@odata.etag` the record was read with; the update fails with HTTP 412
+        /// if the record changed since. `None` updates regardless of version.
+        #[serde(default)]
+        etag: Option<String>,
     },
     /// Delete a record
     Delete {
@@ -45,10 +49,10 @@ pub enum Operation {
     Upsert {
         /// Entity logical name
         entity: String,
-        /// Alternate key field (e.g., "emailaddress1")
-        key_field: String,
-        /// Key value to match against
-        key_value: String,
+        /// Alternate key fields and the values to match against
+        /// (e.g., [("emailaddress1", "jane@example.com")]; several entries form a composite key).
+        /// Numbers, booleans and GUID strings are sent as unquoted OData literals
+        keys: Vec<(String, Value)>,
         /// Record data as JSON
         data: Value,
     },
@@ -64,6 +68,58 @@ pub enum Operation {
         /// Target entity reference (e.g., "/cgk_supports(guid)")
         target_ref: String,
     },
+    /// Remove an N:N association via navigation property
+    /// DELETE /entities(id)/navigation_property(target_id)/$ref
+    DisassociateRef {
+        /// Base entity collection name (e.g., "cgk_deadlines")
+        entity: String,
+        /// Entity ID
+        entity_ref: String,
+        /// Navigation property name (e.g., "cgk_cgk_deadline_cgk_support")
+        navigation_property: String,
+        /// ID of the associated record to unlink
+        target_id: String,
+    },
+}
+
+/// Per-item outcome of an executed operation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OperationOutcome {
+    /// The server applied the operation
+    Succeeded,
+    /// The server rejected the operation
+    Failed,
+    /// No response was returned for the operation (e.g. its changeset was rolled back)
+    NotApplied,
+}
+
+impl OperationOutcome {
+    /// Get the display symbol for this outcome
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Self::Succeeded => "✓",
+            Self::Failed => "✗",
+            Self::NotApplied => "↺",
+        }
+    }
+
+    /// Get the display word for this outcome
+    pub fn word(&self) -> &'static str {
+        match self {
+            Self::Succeeded => "Success",
+            Self::Failed => "Failed",
+            Self::NotApplied => "Not applied",
+        }
+    }
+}
+
+/// What an upsert did to the target record
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UpsertOutcome {
+    /// No record matched the key, so one was created (201)
+    Created,
+    /// An existing record was updated (200)
+    Updated,
 }
 
 /// Result of executing an Operation
@@ -98,6 +154,22 @@ impl Operation {
             entity: entity.into(),
             id: id.into(),
             data,
+            etag: None,
+        }
+    }
+
+    /// Create a new Update operation that only applies if the record still has `etag`
+    pub fn update_if_match(
+        entity: impl Into<String>,
+        id: impl Into<String>,
+        etag: impl Into<String>,
+        data: Value,
+    ) -> Self {
+        Self::Update {
+            entity: entity.into(),
+            id: id.into(),
+            data,
+            etag: Some(etag.into()),
         }
     }
 
@@ -109,21 +181,61 @@ impl Operation {
         }
     }
 
-    /// Create a new Upsert operation
+    /// Create a new Upsert operation on a single alternate key
     pub fn upsert(
         entity: impl Into<String>,
         key_field: impl Into<String>,
-        key_value: impl Into<String>,
+        key_value: impl Into<Value>,
         data: Value,
     ) -> Self {
+        Self::upsert_with_keys(entity, vec![(key_field.into(), key_value.into())], data)
+    }
+
+    /// Create a new Upsert operation on a (possibly composite) alternate key
+    pub fn upsert_with_keys(entity: impl Into<String>, keys: Vec<(String, Value)>, data: Value) -> Self {
         Self::Upsert {
             entity: entity.into(),
-            key_field: key_field.into(),
-            key_value: key_value.into(),
+            keys,
             data,
         }
     }
 
+    /// Create a new AssociateRef operation linking `entity(id)` to `target` (entity set, id)
+    pub fn associate(
+        entity: impl Into<String>,
+        id: impl Into<String>,
+        relationship: impl Into<String>,
+        target: (impl Into<String>, impl Into<String>),
+    ) -> Self {
+        let (target_entity, target_id) = target;
+        Self::AssociateRef {
+            entity: entity.into(),
+            entity_ref: id.into(),
+            navigation_property: relationship.into(),
+            target_ref: format!(
+                "{}/{}({})",
+                crate::api::constants::api_path(),
+                target_entity.into(),
+                target_id.into()
+            ),
+        }
+    }
+
+    /// Create a new DisassociateRef operation removing the link to `target_id`
+    pub fn disassociate(
+        entity: impl Into<String>,
+        id: impl Into<String>,
+        relationship: impl Into<String>,
+        target_id: impl Into<String>,
+    ) -> Self {
+        Self::DisassociateRef {
+            entity: entity.into(),
+            entity_ref: id.into(),
+            navigation_property: relationship.into(),
+            target_id: target_id.into(),
+        }
+    }
+
     /// Get the entity name for this operation
     pub fn entity(&self) -> &str {
         match self {
@@ -133,6 +245,7 @@ impl Operation {
             Self::Delete { entity, .. } => entity,
             Self::Upsert { entity, .. } => entity,
             Self::AssociateRef { entity, .. } => entity,
+            Self::DisassociateRef { entity, .. } => entity,
         }
     }
 
@@ -145,6 +258,7 @@ impl Operation {
             Self::Delete { .. } => "DELETE",
             Self::Upsert { .. } => "PATCH", // Upsert uses PATCH with specific headers
             Self::AssociateRef { .. } => "POST",
+            Self::DisassociateRef { .. } => "DELETE",
         }
     }
 
@@ -157,9 +271,18 @@ impl Operation {
             Self::Delete { .. } => "delete",
             Self::Upsert { .. } => "upsert",
             Self::AssociateRef { .. } => "associate_ref",
+            Self::DisassociateRef { .. } => "disassociate_ref",
         }
     }
 
+    /// Whether the server can return the written entity (`Prefer: return=representation`)
+    pub fn returns_entity(&self) -> bool {
+        matches!(
+            self,
+            Self::Create { .. } | Self::CreateWithRefs { .. } | Self::Update { .. } | Self::Upsert { .. }
+        )
+    }
+
     /// Execute this operation individually against a Dynamics client
     pub async fn execute(&self, client: &crate::api::DynamicsClient, resilience: &crate::api::ResilienceConfig) -> anyhow::Result<OperationResult> {
         client.execute(self, resilience).await
@@ -201,6 +324,34 @@ impl OperationResult {
         !self.success
     }
 
+    /// Check if the server rejected a conditional update because the record changed
+    pub fn is_precondition_failed(&self) -> bool {
+        self.status_code == Some(412)
+    }
+
+    /// Classify this result for display: succeeded, rejected by the server, or never applied
+    pub fn outcome(&self) -> OperationOutcome {
+        if self.success {
+            OperationOutcome::Succeeded
+        } else if self.status_code.is_some() {
+            OperationOutcome::Failed
+        } else {
+            OperationOutcome::NotApplied
+        }
+    }
+
+    /// For a successful upsert, tell whether the record was created or updated
+    pub fn upsert_outcome(&self) -> Option<UpsertOutcome> {
+        if !self.success || !matches!(self.operation, Operation::Upsert { .. }) {
+            return None;
+        }
+        match self.status_code {
+            Some(201) => Some(UpsertOutcome::Created),
+            Some(_) => Some(UpsertOutcome::Updated),
+            None => None,
+        }
+    }
+
     /// Get the result data, returning an error if the operation failed
     pub fn into_result(self) -> Result<Value, String> {
         if self.success {
@@ -209,4 +360,38 @@ impl OperationResult {
             Err(self.error.unwrap_or_else(|| "Unknown error".to_string()))
         }
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_upsert_outcome_from_status_code() {
+        let upsert = Operation::upsert("contacts", "emailaddress1", "a@b.c", json!({}));
+
+        let mut result = OperationResult::success(upsert.clone(), None);
+        result.status_code = Some(201);
+        assert_eq!(result.upsert_outcome(), Some(UpsertOutcome::Created));
+
+        result.status_code = Some(204);
+        assert_eq!(result.upsert_outcome(), Some(UpsertOutcome::Updated));
+
+        let failed = OperationResult::error(upsert, "Bad request".to_string(), Some(400));
+        assert_eq!(failed.upsert_outcome(), None);
+
+        let create = OperationResult::success(Operation::create("contacts", json!({})), None);
+        assert_eq!(create.upsert_outcome(), None);
+    }
+
+    #[test]
+    fn test_precondition_failed_result() {
+        let update = Operation::update_if_match("contacts", "c1", "W/\"1\"", json!({}));
+
+        let stale = OperationResult::error(update.clone(), "Precondition failed".to_string(), Some(412));
+        assert!(stale.is_precondition_failed());
+
+        let other = OperationResult::error(update, "Bad request".to_string(), Some(400));
+        assert!(!other.is_precondition_failed());
+    }
+}
@@ -8,6 +8,9 @@ use serde_json::Value;
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Operations {
     operations: Vec<Operation>,
+    /// Ask for written entities back (`Prefer: return=representation`)
+    #[serde(default)]
+    return_representation: bool,
 }
 
 impl Operations {
@@ -15,12 +18,25 @@ impl Operations {
     pub fn new() -> Self {
         Self {
             operations: Vec::new(),
+            return_representation: false,
         }
     }
 
     /// Create operations from a vector of operations
     pub fn from_operations(operations: Vec<Operation>) -> Self {
-        Self { operations }
+        Self {
+            operations,
+            return_representation: false,
+        }
+    }
+
+    /// Have creates, updates and upserts return the written entity in `OperationResult::data`
+    ///
+    /// Saves re-querying a record for server-computed fields. Off by default,
+    /// in which case only the record ID comes back (in the `OData-EntityId` header).
+    pub fn with_representation(mut self, enabled: bool) -> Self {
+        self.return_representation = enabled;
+        self
     }
 
     /// Add a single operation to the collection
@@ -41,6 +57,18 @@ impl Operations {
         self
     }
 
+    /// Add an update operation conditioned on the record's `@odata.etag`
+    pub fn update_if_match(
+        mut self,
+        entity: impl Into<String>,
+        id: impl Into<String>,
+        etag: impl Into<String>,
+        data: Value,
+    ) -> Self {
+        self.operations.push(Operation::update_if_match(entity, id, etag, data));
+        self
+    }
+
     /// Add a delete operation
     pub fn delete(mut self, entity: impl Into<String>, id: impl Into<String>) -> Self {
         self.operations.push(Operation::delete(entity, id));
@@ -52,13 +80,64 @@ impl Operations {
         mut self,
         entity: impl Into<String>,
         key_field: impl Into<String>,
-        key_value: impl Into<String>,
+        key_value: impl Into<Value>,
         data: Value,
     ) -> Self {
         self.operations.push(Operation::upsert(entity, key_field, key_value, data));
         self
     }
 
+    /// Add an upsert operation on a composite alternate key
+    pub fn upsert_with_keys(mut self, entity: impl Into<String>, keys: Vec<(String, Value)>, data: Value) -> Self {
+        self.operations.push(Operation::upsert_with_keys(entity, keys, data));
+        self
+    }
+
+    /// Add an associate operation (N:N link via `$ref`)
+    pub fn associate(
+        mut self,
+        entity: impl Into<String>,
+        id: impl Into<String>,
+        relationship: impl Into<String>,
+        target: (impl Into<String>, impl Into<String>),
+    ) -> Self {
+        self.operations.push(Operation::associate(entity, id, relationship, target));
+        self
+    }
+
+    /// Add one associate operation per target; executed together these go out as a single $batch
+    pub fn associate_many<E, I>(
+        mut self,
+        entity: impl Into<String>,
+        id: impl Into<String>,
+        relationship: impl Into<String>,
+        targets: impl IntoIterator<Item = (E, I)>,
+    ) -> Self
+    where
+        E: Into<String>,
+        I: Into<String>,
+    {
+        let entity = entity.into();
+        let id = id.into();
+        let relationship = relationship.into();
+        for target in targets {
+            self.operations.push(Operation::associate(entity.clone(), id.clone(), relationship.clone(), target));
+        }
+        self
+    }
+
+    /// Add a disassociate operation (removes an N:N link)
+    pub fn disassociate(
+        mut self,
+        entity: impl Into<String>,
+        id: impl Into<String>,
+        relationship: impl Into<String>,
+        target_id: impl Into<String>,
+    ) -> Self {
+        self.operations.push(Operation::disassociate(entity, id, relationship, target_id));
+        self
+    }
+
     /// Get the number of operations in this collection
     pub fn len(&self) -> usize {
         self.operations.len()
@@ -74,8 +153,22 @@ impl Operations {
         &self.operations
     }
 
+    /// Collect the operations that did not succeed, in their original order
+    ///
+    /// Used to retry only the failed subset of a batch as a new batch.
+    pub fn from_unsuccessful(results: &[OperationResult]) -> Self {
+        Self::from_operations(
+            results
+                .iter()
+                .filter(|result| !result.success)
+                .map(|result| result.operation.clone())
+                .collect(),
+        )
+    }
+
     /// Extend this collection with operations from another collection
     pub fn extend(mut self, other: Operations) -> Self {
+        self.return_representation |= other.return_representation;
         self.operations.extend(other.operations);
         self
     }
@@ -84,15 +177,16 @@ impl Operations {
     /// - Single operation: execute individually
     /// - Multiple operations: execute as batch
     pub async fn execute(&self, client: &crate::api::DynamicsClient, resilience: &crate::api::ResilienceConfig) -> anyhow::Result<Vec<OperationResult>> {
-        client.execute_batch(&self.operations, resilience).await
+        self.client_for(client).execute_batch(&self.operations, resilience).await
     }
 
     /// Force individual execution (each operation as separate HTTP request)
     pub async fn execute_individual(&self, client: &crate::api::DynamicsClient, resilience: &crate::api::ResilienceConfig) -> anyhow::Result<Vec<OperationResult>> {
+        let client = self.client_for(client);
         let mut results = Vec::with_capacity(self.operations.len());
 
         for operation in &self.operations {
-            let result = operation.execute(client, resilience).await?;
+            let result = operation.execute(&client, resilience).await?;
             results.push(result);
         }
 
@@ -101,7 +195,12 @@ impl Operations {
 
     /// Force batch execution (all operations in single HTTP request)
     pub async fn execute_batch(&self, client: &crate::api::DynamicsClient, resilience: &crate::api::ResilienceConfig) -> anyhow::Result<Vec<OperationResult>> {
-        client.execute_batch(&self.operations, resilience).await
+        self.client_for(client).execute_batch(&self.operations, resilience).await
+    }
+
+    /// Batch execution with an explicit mode (atomic changeset or independent requests)
+    pub async fn execute_batch_with_mode(&self, client: &crate::api::DynamicsClient, mode: super::BatchMode, resilience: &crate::api::ResilienceConfig) -> anyhow::Result<Vec<OperationResult>> {
+        self.client_for(client).execute_batch_with_mode(&self.operations, mode, resilience).await
     }
 
     /// Execute operations in parallel (each operation as separate concurrent HTTP request)
@@ -115,7 +214,7 @@ impl Operations {
 
         for operation in &self.operations {
             let op_clone = operation.clone();
-            let client_clone = client.clone(); // Assuming DynamicsClient implements Clone
+            let client_clone = self.client_for(client);
 
             let resilience_clone = resilience.clone();
             let handle = tokio::spawn(async move {
@@ -132,6 +231,15 @@ impl Operations {
 
         Ok(results)
     }
+
+    /// The client to execute with, asking for representations if this collection wants them
+    fn client_for(&self, client: &crate::api::DynamicsClient) -> crate::api::DynamicsClient {
+        if self.return_representation {
+            client.clone().with_return_representation(true)
+        } else {
+            client.clone()
+        }
+    }
 }
 
 impl Default for Operations {
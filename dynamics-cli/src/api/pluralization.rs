@@ -1,5 +1,18 @@
 //! Entity name pluralization utilities for Dynamics 365 Web API
 
+use std::collections::HashMap;
+
+/// Resolve the entity set name for an entity, preferring a configured override
+///
+/// Overrides are keyed by lowercase logical name and cover custom entities whose
+/// entity set name the grammar rules below can't guess.
+pub fn resolve_entity_set_name(entity_name: &str, overrides: &HashMap<String, String>) -> String {
+    overrides
+        .get(&entity_name.to_lowercase())
+        .cloned()
+        .unwrap_or_else(|| pluralize_entity_name(entity_name))
+}
+
 /// Convert entity name to plural form using English grammar rules
 pub fn pluralize_entity_name(entity_name: &str) -> String {
     if entity_name.is_empty() {
@@ -106,4 +119,12 @@ mod tests {
         assert_eq!(pluralize_entity_name("cgk_contact"), "cgk_contacts");
         assert_eq!(pluralize_entity_name("prefix_item"), "prefix_items");
     }
+
+    #[test]
+    fn test_overrides_take_precedence() {
+        let overrides = HashMap::from([("new_status".to_string(), "new_statuslist".to_string())]);
+        assert_eq!(resolve_entity_set_name("new_status", &overrides), "new_statuslist");
+        assert_eq!(resolve_entity_set_name("New_Status", &overrides), "new_statuslist");
+        assert_eq!(resolve_entity_set_name("contact", &overrides), "contacts");
+    }
 }
\ No newline at end of file
@@ -3,6 +3,7 @@
 //! Provides a fluent API that builds Query objects for execution
 
 use super::query::Query;
+use super::expand::ExpandBuilder;
 use super::filters::Filter;
 use super::orderby::{OrderBy, OrderByClause};
 use super::result::QueryResult;
@@ -46,9 +47,18 @@ impl QueryBuilder {
         self
     }
 
-    /// Expand related entities
-    pub fn expand(mut self, expansions: &[&str]) -> Self {
-        self.query.expand = Some(expansions.iter().map(|e| e.to_string()).collect());
+    /// Expand a related entity, configuring its own select/filter/orderby/top/expand
+    ///
+    /// Repeated calls add further expansions, which are comma-joined.
+    pub fn expand(mut self, navigation_property: impl Into<String>, build: impl FnOnce(ExpandBuilder) -> ExpandBuilder) -> Self {
+        let expand = build(ExpandBuilder::new(navigation_property)).build();
+        self.query.expand.get_or_insert_with(Vec::new).push(expand.to_odata_string());
+        self
+    }
+
+    /// Expand related entities from pre-built expand strings
+    pub fn expand_raw(mut self, expansions: &[&str]) -> Self {
+        self.query.expand.get_or_insert_with(Vec::new).extend(expansions.iter().map(|e| e.to_string()));
         self
     }
 
@@ -181,7 +191,7 @@ mod tests {
     fn test_expand_and_select() {
         let query = QueryBuilder::new("contacts")
             .select(&["firstname", "lastname"])
-            .expand(&["parentcontactid($select=fullname)", "account($select=name)"])
+            .expand_raw(&["parentcontactid($select=fullname)", "account($select=name)"])
             .build();
 
         assert_eq!(query.expand, Some(vec![
@@ -189,4 +199,29 @@ mod tests {
             "account($select=name)".to_string()
         ]));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_multiple_nested_expands() {
+        let query = QueryBuilder::new("accounts")
+            .select(&["name"])
+            .expand("primarycontactid", |b| b.select(&["fullname", "emailaddress1"]))
+            .expand("contact_customer_accounts", |b| {
+                b.select(&["fullname"])
+                    .filter(Filter::eq("statecode", 0))
+                    .orderby(OrderBy::desc("createdon"))
+                    .top(3)
+                    .expand("owninguser", |b| b.select(&["fullname"]))
+            })
+            .build();
+
+        let expected = "primarycontactid($select=fullname,emailaddress1),contact_customer_accounts($select=fullname;$filter=statecode eq 0;$orderby=createdon desc;$top=3;$expand=owninguser($select=fullname))";
+        assert_eq!(query.to_query_params().get("$expand").unwrap(), expected);
+        assert_eq!(
+            query.to_url("https://test.crm.dynamics.com"),
+            format!(
+                "https://test.crm.dynamics.com/api/data/v9.2/accounts?$select=name&$expand={}",
+                urlencoding::encode(expected)
+            )
+        );
+    }
+}
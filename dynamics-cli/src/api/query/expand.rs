@@ -0,0 +1,152 @@
+//! OData $expand building
+//!
+//! Provides nested expand clauses with their own query options, e.g.
+//! `primarycontactid($select=fullname,emailaddress1;$top=5)`
+
+use super::filters::Filter;
+use super::orderby::{OrderBy, OrderByClause};
+
+/// A single `$expand` entry for a navigation property
+#[derive(Debug, Clone)]
+pub struct Expand {
+    pub navigation_property: String,
+    pub select: Option<Vec<String>>,
+    pub filter: Option<Filter>,
+    pub orderby: OrderByClause,
+    pub top: Option<u32>,
+    pub expand: Vec<Expand>,
+}
+
+impl Expand {
+    pub fn new(navigation_property: impl Into<String>) -> Self {
+        Self {
+            navigation_property: navigation_property.into(),
+            select: None,
+            filter: None,
+            orderby: OrderByClause::new(),
+            top: None,
+            expand: Vec::new(),
+        }
+    }
+
+    /// Convert to OData expand string
+    /// Nested query options are separated by `;`, as OData requires inside parentheses
+    pub fn to_odata_string(&self) -> String {
+        let mut options = Vec::new();
+
+        if let Some(select) = &self.select {
+            options.push(format!("$select={}", select.join(",")));
+        }
+
+        if let Some(filter) = &self.filter {
+            options.push(format!("$filter={}", filter.to_odata_string()));
+        }
+
+        if let Some(orderby) = self.orderby.to_odata_string() {
+            options.push(format!("$orderby={}", orderby));
+        }
+
+        if let Some(top) = self.top {
+            options.push(format!("$top={}", top));
+        }
+
+        if !self.expand.is_empty() {
+            let nested: Vec<String> = self.expand.iter().map(|e| e.to_odata_string()).collect();
+            options.push(format!("$expand={}", nested.join(",")));
+        }
+
+        if options.is_empty() {
+            self.navigation_property.clone()
+        } else {
+            format!("{}({})", self.navigation_property, options.join(";"))
+        }
+    }
+}
+
+/// Fluent builder for the query options of one expanded navigation property
+#[derive(Debug, Clone)]
+pub struct ExpandBuilder {
+    expand: Expand,
+}
+
+impl ExpandBuilder {
+    pub fn new(navigation_property: impl Into<String>) -> Self {
+        Self {
+            expand: Expand::new(navigation_property),
+        }
+    }
+
+    /// Select specific fields of the related entity
+    pub fn select(mut self, fields: &[&str]) -> Self {
+        self.expand.select = Some(fields.iter().map(|f| f.to_string()).collect());
+        self
+    }
+
+    /// Filter the related records (collection-valued navigation properties)
+    pub fn filter(mut self, filter: Filter) -> Self {
+        self.expand.filter = Some(filter);
+        self
+    }
+
+    /// Order the related records
+    pub fn orderby(mut self, order: OrderBy) -> Self {
+        self.expand.orderby = self.expand.orderby.add(order);
+        self
+    }
+
+    /// Limit the number of related records
+    pub fn top(mut self, top: u32) -> Self {
+        self.expand.top = Some(top);
+        self
+    }
+
+    /// Expand a navigation property of the related entity
+    pub fn expand(mut self, navigation_property: impl Into<String>, build: impl FnOnce(ExpandBuilder) -> ExpandBuilder) -> Self {
+        self.expand.expand.push(build(ExpandBuilder::new(navigation_property)).build());
+        self
+    }
+
+    pub fn build(self) -> Expand {
+        self.expand
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plain_expand() {
+        assert_eq!(ExpandBuilder::new("primarycontactid").build().to_odata_string(), "primarycontactid");
+    }
+
+    #[test]
+    fn test_expand_with_options() {
+        let expand = ExpandBuilder::new("contact_customer_accounts")
+            .select(&["fullname", "emailaddress1"])
+            .filter(Filter::eq("statecode", 0))
+            .orderby(OrderBy::asc("fullname"))
+            .top(5)
+            .build();
+
+        assert_eq!(
+            expand.to_odata_string(),
+            "contact_customer_accounts($select=fullname,emailaddress1;$filter=statecode eq 0;$orderby=fullname asc;$top=5)"
+        );
+    }
+
+    #[test]
+    fn test_two_level_nested_expand() {
+        let expand = ExpandBuilder::new("primarycontactid")
+            .select(&["fullname"])
+            .expand("parentcustomerid_account", |b| {
+                b.select(&["name"]).expand("owninguser", |b| b.select(&["fullname"]))
+            })
+            .build();
+
+        assert_eq!(
+            expand.to_odata_string(),
+            "primarycontactid($select=fullname;$expand=parentcustomerid_account($select=name;$expand=owninguser($select=fullname)))"
+        );
+    }
+}
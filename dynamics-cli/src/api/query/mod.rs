@@ -5,12 +5,14 @@
 
 pub mod query;
 pub mod builder;
+pub mod expand;
 pub mod filters;
 pub mod orderby;
 pub mod result;
 
 pub use query::Query;
 pub use builder::QueryBuilder;
+pub use expand::{Expand, ExpandBuilder};
 pub use filters::{Filter, FilterValue};
 pub use orderby::OrderBy;
 pub use result::{QueryResult, QueryResponse};
\ No newline at end of file
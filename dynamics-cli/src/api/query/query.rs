@@ -61,7 +61,7 @@ impl Query {
         }
 
         if let Some(expand) = &self.expand {
-            params.push(format!("$expand={}", expand.join(",")));
+            params.push(format!("$expand={}", urlencoding::encode(&expand.join(","))));
         }
 
         if let Some(top) = self.top {
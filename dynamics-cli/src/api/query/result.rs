@@ -2,6 +2,7 @@
 //!
 //! Handles OData query responses from Dynamics 365
 
+use crate::api::constants::annotations;
 use serde_json::Value;
 use std::collections::HashMap;
 
@@ -19,6 +20,10 @@ pub struct QueryResponse {
     pub value: Vec<Value>,
     pub count: Option<u64>,
     pub next_link: Option<String>,
+    /// Raw FetchXML paging cookie annotation, present when more pages exist
+    pub paging_cookie: Option<String>,
+    /// Whether the server reported further FetchXML pages
+    pub more_records: bool,
 }
 
 impl QueryResult {
@@ -104,10 +109,20 @@ impl QueryResponse {
             .and_then(|n| n.as_str())
             .map(|s| s.to_string());
 
+        let paging_cookie = json.get(annotations::FETCHXML_PAGING_COOKIE)
+            .and_then(|c| c.as_str())
+            .map(|s| s.to_string());
+
+        let more_records = json.get(annotations::MORE_RECORDS)
+            .and_then(|m| m.as_bool())
+            .unwrap_or(false);
+
         Ok(Self {
             value,
             count,
             next_link,
+            paging_cookie,
+            more_records,
         })
     }
 
@@ -171,6 +186,21 @@ mod tests {
         assert_eq!(response.value.len(), 1);
         assert_eq!(response.count, None);
         assert_eq!(response.next_link, None);
+        assert!(!response.more_records);
+    }
+
+    #[test]
+    fn test_query_response_fetchxml_paging_annotations() {
+        let json = json!({
+            "value": [{"accountid": "123"}],
+            "@Microsoft.Dynamics.CRM.fetchxmlpagingcookie": "<cookie pagenumber=\"2\" pagingcookie=\"%253ccookie%253e\" />",
+            "@Microsoft.Dynamics.CRM.morerecords": true
+        });
+
+        let response = QueryResponse::from_json(json).unwrap();
+
+        assert!(response.more_records);
+        assert!(response.paging_cookie.unwrap().starts_with("<cookie pagenumber=\"2\""));
     }
 
     #[test]
@@ -179,6 +209,8 @@ mod tests {
             value: vec![json!({"id": "123"})],
             count: Some(1),
             next_link: None,
+            paging_cookie: None,
+            more_records: false,
         };
 
         let result = QueryResult::success(response, 200, HashMap::new());
@@ -212,6 +244,8 @@ mod tests {
             ],
             count: None,
             next_link: None,
+            paging_cookie: None,
+            more_records: false,
         };
 
         // Test get_field_values
@@ -235,6 +269,8 @@ mod tests {
             value: vec![json!({"id": "123"})],
             count: Some(10),
             next_link: Some("https://api.example.com/contacts?$skip=5&$top=5".to_string()),
+            paging_cookie: None,
+            more_records: false,
         };
 
         let result_with_next = QueryResult::success(response_with_next, 200, HashMap::new());
@@ -248,6 +284,8 @@ mod tests {
             value: vec![json!({"id": "456"})],
             count: Some(6),
             next_link: None,
+            paging_cookie: None,
+            more_records: false,
         };
 
         let result_final = QueryResult::success(response_final, 200, HashMap::new());
@@ -4,6 +4,7 @@
 //! and monitoring features with sane defaults.
 
 use super::retry::RetryConfig;
+use std::collections::HashMap;
 use std::time::Duration;
 
 /// Global resilience configuration for API operations
@@ -12,14 +13,80 @@ pub struct ResilienceConfig {
     pub retry: RetryConfig,
     pub rate_limit: RateLimitConfig,
     pub monitoring: MonitoringConfig,
+    pub timeouts: TimeoutConfig,
+}
+
+/// Per-request timeouts by operation type
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimeoutConfig {
+    /// `$metadata`, entity definitions, forms and views
+    pub metadata: Duration,
+    /// OData and FetchXML queries, paging and single-record reads
+    pub query: Duration,
+    /// Creates, updates, deletes, associations and `$batch` requests
+    pub write: Duration,
 }
 
 /// Rate limiting configuration
+///
+/// `requests_per_minute` and `burst_capacity` apply to every environment's
+/// bucket unless overridden in `environments`.
 #[derive(Debug, Clone)]
 pub struct RateLimitConfig {
     pub requests_per_minute: u32,
     pub burst_capacity: u32,
     pub enabled: bool,
+    /// Most metadata requests a screen sends at once when loading in parallel
+    pub max_concurrent_fetches: usize,
+    /// Per-environment overrides, keyed by environment name
+    pub environments: HashMap<String, EnvironmentRateLimit>,
+}
+
+/// Token bucket settings for a single environment
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EnvironmentRateLimit {
+    pub requests_per_minute: u32,
+    pub burst_capacity: u32,
+}
+
+impl RateLimitConfig {
+    /// Override the bucket settings for one environment
+    pub fn with_environment(mut self, environment: impl Into<String>, requests_per_minute: u32, burst_capacity: u32) -> Self {
+        self.environments.insert(
+            environment.into(),
+            EnvironmentRateLimit { requests_per_minute, burst_capacity },
+        );
+        self
+    }
+
+    /// Parse overrides written as `env=requests_per_minute/burst`, comma-separated
+    pub fn parse_environments(spec: &str) -> anyhow::Result<HashMap<String, EnvironmentRateLimit>> {
+        spec.split(',')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+            .map(|entry| {
+                let parsed = entry.split_once('=').and_then(|(environment, limits)| {
+                    let (rpm, burst) = limits.split_once('/')?;
+                    let limit = EnvironmentRateLimit {
+                        requests_per_minute: rpm.trim().parse().ok()?,
+                        burst_capacity: burst.trim().parse().ok()?,
+                    };
+                    Some((environment.trim().to_string(), limit))
+                });
+                parsed.ok_or_else(|| {
+                    anyhow::anyhow!("Invalid rate limit '{}', expected environment=requests_per_minute/burst", entry)
+                })
+            })
+            .collect()
+    }
+
+    /// Bucket settings for an environment (its override, or the defaults)
+    pub fn for_environment(&self, environment: &str) -> EnvironmentRateLimit {
+        self.environments.get(environment).copied().unwrap_or(EnvironmentRateLimit {
+            requests_per_minute: self.requests_per_minute,
+            burst_capacity: self.burst_capacity,
+        })
+    }
 }
 
 /// Monitoring and logging configuration
@@ -46,6 +113,17 @@ impl Default for ResilienceConfig {
             retry: RetryConfig::default(),
             rate_limit: RateLimitConfig::default(),
             monitoring: MonitoringConfig::default(),
+            timeouts: TimeoutConfig::default(),
+        }
+    }
+}
+
+impl Default for TimeoutConfig {
+    fn default() -> Self {
+        Self {
+            metadata: Duration::from_secs(30),
+            query: Duration::from_secs(60),
+            write: Duration::from_secs(120),
         }
     }
 }
@@ -56,6 +134,8 @@ impl Default for RateLimitConfig {
             requests_per_minute: 90, // Conservative for Dynamics 365 (100/min limit)
             burst_capacity: 10,      // Allow small bursts
             enabled: true,
+            max_concurrent_fetches: 3,
+            environments: HashMap::new(),
         }
     }
 }
@@ -85,6 +165,8 @@ impl ResilienceConfig {
                 requests_per_minute: 60, // Very conservative
                 burst_capacity: 5,
                 enabled: true,
+                max_concurrent_fetches: 2,
+                environments: HashMap::new(),
             },
             monitoring: MonitoringConfig {
                 correlation_ids: true,
@@ -92,6 +174,7 @@ impl ResilienceConfig {
                 performance_metrics: true,
                 log_level: LogLevel::Warn, // Less verbose in production
             },
+            timeouts: TimeoutConfig::default(),
         }
     }
 
@@ -103,6 +186,8 @@ impl ResilienceConfig {
                 requests_per_minute: 200, // Higher limits for dev
                 burst_capacity: 20,
                 enabled: false, // Often disabled in dev
+                max_concurrent_fetches: 6,
+                environments: HashMap::new(),
             },
             monitoring: MonitoringConfig {
                 correlation_ids: true,
@@ -110,6 +195,7 @@ impl ResilienceConfig {
                 performance_metrics: true,
                 log_level: LogLevel::Debug, // More verbose for debugging
             },
+            timeouts: TimeoutConfig::default(),
         }
     }
 
@@ -122,11 +208,14 @@ impl ResilienceConfig {
                 max_delay: Duration::from_millis(0),
                 backoff_multiplier: 1.0,
                 jitter: false,
+                max_retry_after: Duration::from_secs(0),
             },
             rate_limit: RateLimitConfig {
                 requests_per_minute: u32::MAX,
                 burst_capacity: u32::MAX,
                 enabled: false,
+                max_concurrent_fetches: usize::MAX,
+                environments: HashMap::new(),
             },
             monitoring: MonitoringConfig {
                 correlation_ids: false,
@@ -134,6 +223,7 @@ impl ResilienceConfig {
                 performance_metrics: false,
                 log_level: LogLevel::Error,
             },
+            timeouts: TimeoutConfig::default(),
         }
     }
 
@@ -154,6 +244,8 @@ impl ResilienceConfig {
             .unwrap_or(2.0);
         let jitter = config.options.get_bool("api.retry.jitter").await
             .unwrap_or(true);
+        let max_retry_after_secs = config.options.get_uint("api.retry.max_retry_after_secs").await
+            .unwrap_or(300);
 
         // Load rate limit options
         let rate_limit_enabled = config.options.get_bool("api.rate_limit.enabled").await
@@ -162,6 +254,15 @@ impl ResilienceConfig {
             .unwrap_or(90) as u32;
         let burst_capacity = config.options.get_uint("api.rate_limit.burst_capacity").await
             .unwrap_or(10) as u32;
+        let max_concurrent_fetches = config.options.get_uint("api.rate_limit.max_concurrent_fetches").await
+            .unwrap_or(3) as usize;
+        let environment_limits = config.options.get_string("api.rate_limit.environments").await
+            .unwrap_or_default();
+        let environments = RateLimitConfig::parse_environments(&environment_limits)
+            .unwrap_or_else(|e| {
+                log::warn!("Ignoring api.rate_limit.environments: {}", e);
+                HashMap::new()
+            });
 
         // Load monitoring options
         let correlation_ids = config.options.get_bool("api.monitoring.correlation_ids").await
@@ -173,6 +274,14 @@ impl ResilienceConfig {
         let log_level_str = config.options.get_string("api.monitoring.log_level").await
             .unwrap_or_else(|_| "info".to_string());
 
+        // Load timeout options
+        let metadata_timeout_secs = config.options.get_uint("api.timeout.metadata_secs").await
+            .unwrap_or(30);
+        let query_timeout_secs = config.options.get_uint("api.timeout.query_secs").await
+            .unwrap_or(60);
+        let write_timeout_secs = config.options.get_uint("api.timeout.write_secs").await
+            .unwrap_or(120);
+
         let log_level = match log_level_str.as_str() {
             "error" => LogLevel::Error,
             "warn" => LogLevel::Warn,
@@ -189,11 +298,14 @@ impl ResilienceConfig {
                 max_delay: Duration::from_millis(max_delay_ms),
                 backoff_multiplier,
                 jitter,
+                max_retry_after: Duration::from_secs(max_retry_after_secs),
             },
             rate_limit: RateLimitConfig {
                 requests_per_minute,
                 burst_capacity,
                 enabled: rate_limit_enabled,
+                max_concurrent_fetches,
+                environments,
             },
             monitoring: MonitoringConfig {
                 correlation_ids,
@@ -201,6 +313,11 @@ impl ResilienceConfig {
                 performance_metrics,
                 log_level,
             },
+            timeouts: TimeoutConfig {
+                metadata: Duration::from_secs(metadata_timeout_secs),
+                query: Duration::from_secs(query_timeout_secs),
+                write: Duration::from_secs(write_timeout_secs),
+            },
         })
     }
 }
@@ -248,6 +365,18 @@ impl ResilienceConfigBuilder {
         self
     }
 
+    /// Give one environment its own requests per minute and burst capacity
+    pub fn environment_rate_limit(mut self, environment: impl Into<String>, rpm: u32, burst_capacity: u32) -> Self {
+        self.config.rate_limit = self.config.rate_limit.with_environment(environment, rpm, burst_capacity);
+        self
+    }
+
+    /// Configure per-request timeouts
+    pub fn timeouts(mut self, timeouts: TimeoutConfig) -> Self {
+        self.config.timeouts = timeouts;
+        self
+    }
+
     /// Configure monitoring
     pub fn monitoring_config(mut self, monitoring: MonitoringConfig) -> Self {
         self.config.monitoring = monitoring;
@@ -303,6 +432,9 @@ mod tests {
         assert!(config.rate_limit.enabled);
         assert!(config.monitoring.correlation_ids);
         assert!(config.monitoring.request_logging);
+        assert_eq!(config.timeouts.metadata, Duration::from_secs(30));
+        assert_eq!(config.timeouts.query, Duration::from_secs(60));
+        assert_eq!(config.timeouts.write, Duration::from_secs(120));
     }
 
     #[test]
@@ -348,4 +480,18 @@ mod tests {
         assert!(!config.rate_limit.enabled);
         assert!(config.monitoring.correlation_ids);
     }
+
+    #[test]
+    fn test_parse_environment_rate_limits() {
+        let environments = RateLimitConfig::parse_environments(" prod=60/5, dev = 200/20 ,").unwrap();
+        let config = RateLimitConfig { environments, ..RateLimitConfig::default() };
+
+        assert_eq!(config.for_environment("prod"), EnvironmentRateLimit { requests_per_minute: 60, burst_capacity: 5 });
+        assert_eq!(config.for_environment("dev"), EnvironmentRateLimit { requests_per_minute: 200, burst_capacity: 20 });
+        assert_eq!(config.for_environment("test").requests_per_minute, 90);
+
+        assert!(RateLimitConfig::parse_environments("").unwrap().is_empty());
+        assert!(RateLimitConfig::parse_environments("prod=60").is_err());
+        assert!(RateLimitConfig::parse_environments("prod=fast/5").is_err());
+    }
 }
\ No newline at end of file
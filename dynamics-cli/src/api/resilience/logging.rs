@@ -2,12 +2,13 @@
 //!
 //! Provides structured logging capabilities that include correlation IDs,
 //! performance metrics, and request/response tracking for debugging and monitoring.
+//! With `DYNAMICS_LOG_FORMAT=json` each event's fields become separate log fields.
 
 use super::config::{MonitoringConfig, LogLevel};
 use serde_json::{json, Value};
 use std::collections::HashMap;
 use std::time::{Duration, Instant};
-use log::{debug, info, warn, error, trace};
+use log::{log, trace, Level};
 
 /// Structured logger for API operations with correlation tracking
 #[derive(Debug, Clone)]
@@ -72,7 +73,7 @@ impl ApiLogger {
                 "timestamp": chrono::Utc::now().to_rfc3339()
             });
 
-            info!("API Operation Started: {}", log_data);
+            self.emit(Level::Info, "API Operation Started", log_data);
         }
 
         context
@@ -95,7 +96,7 @@ impl ApiLogger {
             "timestamp": chrono::Utc::now().to_rfc3339()
         });
 
-        debug!("HTTP Request: {}", log_data);
+        self.emit(Level::Debug, "HTTP Request", log_data);
     }
 
     /// Log HTTP response details
@@ -116,9 +117,9 @@ impl ApiLogger {
         });
 
         if status_code >= 400 {
-            warn!("HTTP Response (Error): {}", log_data);
+            self.emit(Level::Warn, "HTTP Response (Error)", log_data);
         } else {
-            debug!("HTTP Response: {}", log_data);
+            self.emit(Level::Debug, "HTTP Response", log_data);
         }
     }
 
@@ -139,7 +140,7 @@ impl ApiLogger {
             "timestamp": chrono::Utc::now().to_rfc3339()
         });
 
-        warn!("Retry Attempt: {}", log_data);
+        self.emit(Level::Warn, "Retry Attempt", log_data);
     }
 
     /// Log rate limiting event
@@ -158,7 +159,7 @@ impl ApiLogger {
             "timestamp": chrono::Utc::now().to_rfc3339()
         });
 
-        debug!("Rate Limited: {}", log_data);
+        self.emit(Level::Debug, "Rate Limited", log_data);
     }
 
     /// Complete an operation and log metrics
@@ -179,9 +180,9 @@ impl ApiLogger {
             });
 
             if metrics.success {
-                info!("API Operation Completed: {}", log_data);
+                self.emit(Level::Info, "API Operation Completed", log_data);
             } else {
-                error!("API Operation Failed: {}", log_data);
+                self.emit(Level::Error, "API Operation Failed", log_data);
             }
         }
     }
@@ -203,7 +204,7 @@ impl ApiLogger {
             "timestamp": chrono::Utc::now().to_rfc3339()
         });
 
-        info!("Batch Operation Completed: {}", log_data);
+        self.emit(Level::Info, "Batch Operation Completed", log_data);
     }
 
     /// Add metadata to an operation context
@@ -231,7 +232,18 @@ impl ApiLogger {
             "timestamp": chrono::Utc::now().to_rfc3339()
         });
 
-        warn!("Slow Operation Detected: {}", log_data);
+        self.emit(Level::Warn, "Slow Operation Detected", log_data);
+    }
+
+    /// Write an event: formatted into the message in text mode, as separate
+    /// fields when the log file is JSON
+    fn emit(&self, level: Level, message: &str, log_data: Value) {
+        match log_data {
+            Value::Object(fields) if crate::log_format::json_enabled() => {
+                crate::log_format::log_fields(level, module_path!(), message, &fields);
+            }
+            log_data => log!(level, "{}: {}", message, log_data),
+        }
     }
 
     /// Check if we should log at the given level
@@ -6,6 +6,7 @@
 use super::config::MonitoringConfig;
 use super::logging::OperationMetrics;
 use std::collections::HashMap;
+use std::fmt::Write;
 use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 use serde::{Serialize, Deserialize};
@@ -212,6 +213,117 @@ impl MetricsCollector {
     }
 }
 
+impl MetricsSnapshot {
+    /// Most entities exported with their own label; the rest are summed under `entity="other"`
+    pub const MAX_ENTITY_LABELS: usize = 25;
+
+    /// Render the snapshot in the Prometheus text exposition format
+    ///
+    /// Latency is exported as a summary without quantiles (`_sum`/`_count`),
+    /// since the collector keeps running totals rather than individual samples.
+    /// Status codes are grouped by class to keep label cardinality bounded.
+    pub fn to_prometheus(&self) -> String {
+        let mut out = String::new();
+
+        let mut operations: Vec<&OperationTypeMetrics> = self.operations.iter().collect();
+        operations.sort_by(|a, b| a.operation_type.cmp(&b.operation_type));
+
+        metric_header(&mut out, "dynamics_operations_total", "counter", "Operations completed, by operation type and outcome");
+        for op in &operations {
+            let label = escape_label(&op.operation_type);
+            let _ = writeln!(out, "dynamics_operations_total{{operation=\"{}\",outcome=\"success\"}} {}", label, op.successful_operations);
+            let _ = writeln!(out, "dynamics_operations_total{{operation=\"{}\",outcome=\"error\"}} {}", label, op.failed_operations);
+        }
+
+        metric_header(&mut out, "dynamics_operation_duration_seconds", "summary", "Operation latency, including retries");
+        for op in &operations {
+            let label = escape_label(&op.operation_type);
+            let _ = writeln!(out, "dynamics_operation_duration_seconds_sum{{operation=\"{}\"}} {}", label, op.total_duration.as_secs_f64());
+            let _ = writeln!(out, "dynamics_operation_duration_seconds_count{{operation=\"{}\"}} {}", label, op.total_operations);
+        }
+
+        metric_header(&mut out, "dynamics_operation_duration_max_seconds", "gauge", "Slowest operation observed");
+        for op in &operations {
+            let _ = writeln!(out, "dynamics_operation_duration_max_seconds{{operation=\"{}\"}} {}", escape_label(&op.operation_type), op.max_duration.as_secs_f64());
+        }
+
+        metric_header(&mut out, "dynamics_operation_retries_total", "counter", "Retry attempts made");
+        for op in &operations {
+            let _ = writeln!(out, "dynamics_operation_retries_total{{operation=\"{}\"}} {}", escape_label(&op.operation_type), op.total_retries);
+        }
+
+        metric_header(&mut out, "dynamics_rate_limit_delay_seconds_total", "counter", "Time spent waiting on the rate limiter");
+        for op in &operations {
+            let _ = writeln!(out, "dynamics_rate_limit_delay_seconds_total{{operation=\"{}\"}} {}", escape_label(&op.operation_type), op.total_rate_limit_delays.as_secs_f64());
+        }
+
+        metric_header(&mut out, "dynamics_http_responses_total", "counter", "HTTP responses, by operation type and status class");
+        for op in &operations {
+            let mut classes: Vec<(u16, u64)> = Vec::new();
+            for (code, count) in &op.status_codes {
+                match classes.iter_mut().find(|(class, _)| *class == code / 100) {
+                    Some((_, total)) => *total += count,
+                    None => classes.push((code / 100, *count)),
+                }
+            }
+            classes.sort();
+            for (class, count) in classes {
+                let _ = writeln!(out, "dynamics_http_responses_total{{operation=\"{}\",status_class=\"{}xx\"}} {}", escape_label(&op.operation_type), class, count);
+            }
+        }
+
+        let entities = self.bounded_entities();
+        metric_header(&mut out, "dynamics_entity_operations_total", "counter", "Operations completed, by entity and outcome");
+        for entity in &entities {
+            let label = escape_label(&entity.entity_name);
+            let _ = writeln!(out, "dynamics_entity_operations_total{{entity=\"{}\",outcome=\"success\"}} {}", label, entity.successful_operations);
+            let _ = writeln!(out, "dynamics_entity_operations_total{{entity=\"{}\",outcome=\"error\"}} {}", label, entity.failed_operations);
+        }
+
+        metric_header(&mut out, "dynamics_entity_duration_average_seconds", "gauge", "Average operation latency, by entity");
+        for entity in &entities {
+            let _ = writeln!(out, "dynamics_entity_duration_average_seconds{{entity=\"{}\"}} {}", escape_label(&entity.entity_name), entity.average_duration.as_secs_f64());
+        }
+
+        out
+    }
+
+    /// Busiest entities, with everything past `MAX_ENTITY_LABELS` folded into "other"
+    fn bounded_entities(&self) -> Vec<EntityMetrics> {
+        let mut entities = self.entities.clone();
+        entities.sort_by(|a, b| b.total_operations.cmp(&a.total_operations).then_with(|| a.entity_name.cmp(&b.entity_name)));
+
+        if entities.len() <= Self::MAX_ENTITY_LABELS {
+            return entities;
+        }
+
+        let rest = entities.split_off(Self::MAX_ENTITY_LABELS);
+        let mut other = EntityMetrics::new("other");
+        let mut total_duration = Duration::ZERO;
+        for entity in rest {
+            other.total_operations += entity.total_operations;
+            other.successful_operations += entity.successful_operations;
+            other.failed_operations += entity.failed_operations;
+            total_duration += entity.average_duration * entity.total_operations as u32;
+        }
+        if other.total_operations > 0 {
+            other.average_duration = total_duration / other.total_operations as u32;
+        }
+        entities.push(other);
+        entities
+    }
+}
+
+fn metric_header(out: &mut String, name: &str, kind: &str, help: &str) {
+    let _ = writeln!(out, "# HELP {} {}", name, help);
+    let _ = writeln!(out, "# TYPE {} {}", name, kind);
+}
+
+/// Escape a Prometheus label value
+fn escape_label(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")
+}
+
 impl OperationTypeMetrics {
     fn new(operation_type: &str) -> Self {
         Self {
@@ -466,6 +578,62 @@ mod tests {
         assert_eq!(metrics.operation_types["update"], 1);
     }
 
+    #[test]
+    fn test_prometheus_export() {
+        let collector = MetricsCollector::new(MonitoringConfig::default());
+        let ok = OperationMetrics {
+            duration: Duration::from_millis(250),
+            retry_attempts: 1,
+            success: true,
+            status_code: Some(200),
+            error_message: None,
+            rate_limit_delays: vec![],
+        };
+        let failed = OperationMetrics {
+            success: false,
+            status_code: Some(429),
+            ..ok.clone()
+        };
+        collector.record_operation("fetchxml", "account", &ok);
+        collector.record_operation("fetchxml", "account", &failed);
+
+        let text = collector.snapshot().to_prometheus();
+
+        assert!(text.contains("# TYPE dynamics_operations_total counter\n"));
+        assert!(text.contains("dynamics_operations_total{operation=\"fetchxml\",outcome=\"success\"} 1\n"));
+        assert!(text.contains("dynamics_operations_total{operation=\"fetchxml\",outcome=\"error\"} 1\n"));
+        assert!(text.contains("dynamics_operation_duration_seconds_sum{operation=\"fetchxml\"} 0.5\n"));
+        assert!(text.contains("dynamics_operation_duration_seconds_count{operation=\"fetchxml\"} 2\n"));
+        assert!(text.contains("dynamics_operation_retries_total{operation=\"fetchxml\"} 2\n"));
+        assert!(text.contains("dynamics_http_responses_total{operation=\"fetchxml\",status_class=\"4xx\"} 1\n"));
+        assert!(text.contains("dynamics_entity_operations_total{entity=\"account\",outcome=\"error\"} 1\n"));
+    }
+
+    #[test]
+    fn test_prometheus_entity_labels_are_bounded() {
+        let collector = MetricsCollector::new(MonitoringConfig::default());
+        let metrics = OperationMetrics {
+            duration: Duration::from_millis(10),
+            retry_attempts: 0,
+            success: true,
+            status_code: Some(200),
+            error_message: None,
+            rate_limit_delays: vec![],
+        };
+        for i in 0..MetricsSnapshot::MAX_ENTITY_LABELS + 5 {
+            collector.record_operation("create", &format!("entity{}", i), &metrics);
+        }
+
+        let text = collector.snapshot().to_prometheus();
+        let entity_series = text
+            .lines()
+            .filter(|line| line.starts_with("dynamics_entity_operations_total{") && line.contains("outcome=\"success\""))
+            .count();
+
+        assert_eq!(entity_series, MetricsSnapshot::MAX_ENTITY_LABELS + 1);
+        assert!(text.contains("dynamics_entity_operations_total{entity=\"other\",outcome=\"success\"} 5\n"));
+    }
+
     #[test]
     fn test_metrics_disabled() {
         let config = MonitoringConfig {
@@ -10,7 +10,7 @@ pub mod logging;
 pub mod metrics;
 
 pub use retry::{RetryPolicy, RetryConfig, RetryableError};
-pub use config::{ResilienceConfig, RateLimitConfig, MonitoringConfig, LogLevel};
-pub use rate_limiter::{RateLimiter, RateLimiterStats};
+pub use config::{ResilienceConfig, RateLimitConfig, EnvironmentRateLimit, MonitoringConfig, LogLevel, TimeoutConfig};
+pub use rate_limiter::{RateLimiter, RateLimiterStats, DEFAULT_BUCKET};
 pub use logging::{ApiLogger, OperationContext, OperationMetrics};
 pub use metrics::{MetricsCollector, MetricsSnapshot, OperationTypeMetrics, EntityMetrics, GlobalMetrics};
\ No newline at end of file
@@ -1,171 +1,239 @@
 //! Token bucket rate limiter implementation
 //!
-//! Provides a global rate limiter per client instance that implements
-//! the token bucket algorithm for controlling request rates to Dynamics 365.
+//! Dynamics 365 enforces service protection limits per organization, so the
+//! limiter keeps an independent token bucket per environment: a busy
+//! environment never throttles requests to another one. Clients created by the
+//! `ClientManager` share one limiter and pick their bucket by environment name.
 
-use super::config::RateLimitConfig;
+use super::config::{EnvironmentRateLimit, RateLimitConfig};
+use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 use tokio::time::sleep;
-use log::{debug, warn};
+use log::debug;
+
+/// Bucket used by callers that don't name an environment
+pub const DEFAULT_BUCKET: &str = "default";
 
 /// Token bucket rate limiter for controlling API request rates
 #[derive(Debug, Clone)]
 pub struct RateLimiter {
-    inner: Arc<Mutex<RateLimiterInner>>,
+    buckets: Arc<Mutex<HashMap<String, TokenBucket>>>,
     config: RateLimitConfig,
 }
 
 #[derive(Debug)]
-struct RateLimiterInner {
+struct TokenBucket {
+    limit: EnvironmentRateLimit,
     tokens: f64,
     last_refill: Instant,
     requests_made: u64,
     requests_rejected: u64,
 }
 
-impl RateLimiter {
-    /// Create a new rate limiter with the given configuration
-    pub fn new(config: RateLimitConfig) -> Self {
-        let initial_tokens = if config.enabled {
-            config.burst_capacity as f64
+impl TokenBucket {
+    fn new(limit: EnvironmentRateLimit, enabled: bool) -> Self {
+        Self {
+            limit,
+            tokens: Self::initial_tokens(limit, enabled),
+            last_refill: Instant::now(),
+            requests_made: 0,
+            requests_rejected: 0,
+        }
+    }
+
+    fn initial_tokens(limit: EnvironmentRateLimit, enabled: bool) -> f64 {
+        if enabled {
+            limit.burst_capacity as f64
         } else {
             f64::MAX // Unlimited when disabled
-        };
+        }
+    }
+
+    /// Refill tokens based on elapsed time
+    fn refill(&mut self) {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.last_refill);
+
+        // Calculate tokens to add based on elapsed time
+        let tokens_per_second = self.limit.requests_per_minute as f64 / 60.0;
+        let tokens_to_add = elapsed.as_secs_f64() * tokens_per_second;
+
+        if tokens_to_add > 0.0 {
+            self.tokens = (self.tokens + tokens_to_add).min(self.limit.burst_capacity as f64);
+            self.last_refill = now;
+            debug!("Rate limiter: Added {:.2} tokens, now have {:.2}", tokens_to_add, self.tokens);
+        }
+    }
+
+    /// Take a token if one is available
+    fn take(&mut self) -> bool {
+        self.refill();
 
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            self.requests_made += 1;
+            true
+        } else {
+            self.requests_rejected += 1;
+            false
+        }
+    }
+
+    /// Time for one token to be added
+    fn seconds_per_token(&self) -> f64 {
+        60.0 / self.limit.requests_per_minute as f64
+    }
+}
+
+impl RateLimiter {
+    /// Create a new rate limiter with the given configuration
+    pub fn new(config: RateLimitConfig) -> Self {
         Self {
-            inner: Arc::new(Mutex::new(RateLimiterInner {
-                tokens: initial_tokens,
-                last_refill: Instant::now(),
-                requests_made: 0,
-                requests_rejected: 0,
-            })),
+            buckets: Arc::new(Mutex::new(HashMap::new())),
             config,
         }
     }
 
-    /// Attempt to acquire a token for making a request
+    /// Most metadata requests a screen should send at once
+    pub fn max_concurrent_fetches(&self) -> usize {
+        self.config.max_concurrent_fetches
+    }
+
+    /// Attempt to acquire a token from the default bucket
     /// Always succeeds, but may wait if rate limited
     pub async fn acquire(&self) -> bool {
+        self.acquire_for(DEFAULT_BUCKET).await
+    }
+
+    /// Attempt to acquire a token from an environment's bucket
+    /// Always succeeds, but may wait if that environment is rate limited
+    pub async fn acquire_for(&self, environment: &str) -> bool {
         if !self.config.enabled {
             return true;
         }
 
         loop {
-            let should_wait = {
-                let mut inner = self.inner.lock().unwrap();
-
-                // Refill tokens based on time passed
-                self.refill_tokens(&mut inner);
-
-                if inner.tokens >= 1.0 {
-                    inner.tokens -= 1.0;
-                    inner.requests_made += 1;
-                    debug!("Rate limiter: Request approved, {} tokens remaining", inner.tokens);
-                    false // Don't wait
+            let wait = self.with_bucket(environment, |bucket| {
+                if bucket.take() {
+                    debug!("Rate limiter [{}]: Request approved, {} tokens remaining", environment, bucket.tokens);
+                    None
                 } else {
-                    inner.requests_rejected += 1;
-                    debug!("Rate limiter: Request needs to wait, {} tokens available", inner.tokens);
-                    true // Need to wait
+                    debug!("Rate limiter [{}]: Request needs to wait, {} tokens available", environment, bucket.tokens);
+                    Some(Duration::from_secs_f64(bucket.seconds_per_token()))
                 }
-            };
+            });
 
-            if !should_wait {
+            let Some(wait_duration) = wait else {
                 return true;
-            }
+            };
 
-            // Calculate wait time until next token is available
-            let wait_duration = self.calculate_wait_time();
-            debug!("Rate limiter: Waiting {:?} for next token", wait_duration);
+            debug!("Rate limiter [{}]: Waiting {:?} for next token", environment, wait_duration);
             sleep(wait_duration).await;
         }
     }
 
-    /// Try to acquire a token without waiting
+    /// Try to acquire a token from the default bucket without waiting
     /// Returns true if acquired, false if rate limited
     pub fn try_acquire(&self) -> bool {
+        self.try_acquire_for(DEFAULT_BUCKET)
+    }
+
+    /// Try to acquire a token from an environment's bucket without waiting
+    /// Returns true if acquired, false if rate limited
+    pub fn try_acquire_for(&self, environment: &str) -> bool {
         if !self.config.enabled {
             return true;
         }
 
-        let mut inner = self.inner.lock().unwrap();
-
-        // Refill tokens based on time passed
-        self.refill_tokens(&mut inner);
-
-        if inner.tokens >= 1.0 {
-            inner.tokens -= 1.0;
-            inner.requests_made += 1;
-            debug!("Rate limiter: Request approved (try_acquire), {} tokens remaining", inner.tokens);
-            true
-        } else {
-            inner.requests_rejected += 1;
-            debug!("Rate limiter: Request rejected (try_acquire), {} tokens available", inner.tokens);
-            false
-        }
+        self.with_bucket(environment, |bucket| {
+            let acquired = bucket.take();
+            debug!(
+                "Rate limiter [{}]: Request {} (try_acquire), {} tokens remaining",
+                environment,
+                if acquired { "approved" } else { "rejected" },
+                bucket.tokens
+            );
+            acquired
+        })
     }
 
-    /// Get current rate limiter statistics
+    /// Get statistics summed over all buckets
     pub fn stats(&self) -> RateLimiterStats {
-        let inner = self.inner.lock().unwrap();
-        RateLimiterStats {
-            tokens_available: inner.tokens,
-            requests_made: inner.requests_made,
-            requests_rejected: inner.requests_rejected,
+        let buckets = self.lock_buckets();
+        let mut stats = RateLimiterStats {
+            environment: None,
+            tokens_available: 0.0,
+            requests_made: 0,
+            requests_rejected: 0,
             enabled: self.config.enabled,
             requests_per_minute: self.config.requests_per_minute,
             burst_capacity: self.config.burst_capacity,
+        };
+
+        if buckets.is_empty() {
+            stats.tokens_available = TokenBucket::initial_tokens(self.config.for_environment(DEFAULT_BUCKET), self.config.enabled);
+        }
+        for bucket in buckets.values() {
+            stats.tokens_available += bucket.tokens;
+            stats.requests_made += bucket.requests_made;
+            stats.requests_rejected += bucket.requests_rejected;
         }
+        stats
     }
 
-    /// Reset the rate limiter statistics and token count
-    pub fn reset(&self) {
-        let mut inner = self.inner.lock().unwrap();
-        inner.tokens = if self.config.enabled {
-            self.config.burst_capacity as f64
-        } else {
-            f64::MAX
-        };
-        inner.last_refill = Instant::now();
-        inner.requests_made = 0;
-        inner.requests_rejected = 0;
+    /// Get statistics for one environment's bucket
+    pub fn stats_for(&self, environment: &str) -> RateLimiterStats {
+        self.with_bucket(environment, |bucket| self.bucket_stats(environment, bucket))
     }
 
-    /// Refill tokens based on elapsed time
-    fn refill_tokens(&self, inner: &mut RateLimiterInner) {
-        if !self.config.enabled {
-            return;
-        }
-
-        let now = Instant::now();
-        let elapsed = now.duration_since(inner.last_refill);
+    /// Get statistics for every environment that has made requests, sorted by name
+    pub fn environment_stats(&self) -> Vec<RateLimiterStats> {
+        let buckets = self.lock_buckets();
+        let mut stats: Vec<RateLimiterStats> = buckets
+            .iter()
+            .map(|(environment, bucket)| self.bucket_stats(environment, bucket))
+            .collect();
+        stats.sort_by(|a, b| a.environment.cmp(&b.environment));
+        stats
+    }
 
-        // Calculate tokens to add based on elapsed time
-        let tokens_per_second = self.config.requests_per_minute as f64 / 60.0;
-        let tokens_to_add = elapsed.as_secs_f64() * tokens_per_second;
+    /// Reset all buckets (statistics and token counts)
+    pub fn reset(&self) {
+        self.lock_buckets().clear();
+    }
 
-        if tokens_to_add > 0.0 {
-            inner.tokens = (inner.tokens + tokens_to_add).min(self.config.burst_capacity as f64);
-            inner.last_refill = now;
-            debug!("Rate limiter: Added {:.2} tokens, now have {:.2}", tokens_to_add, inner.tokens);
+    fn bucket_stats(&self, environment: &str, bucket: &TokenBucket) -> RateLimiterStats {
+        RateLimiterStats {
+            environment: Some(environment.to_string()),
+            tokens_available: bucket.tokens,
+            requests_made: bucket.requests_made,
+            requests_rejected: bucket.requests_rejected,
+            enabled: self.config.enabled,
+            requests_per_minute: bucket.limit.requests_per_minute,
+            burst_capacity: bucket.limit.burst_capacity,
         }
     }
 
-    /// Calculate how long to wait for the next token
-    fn calculate_wait_time(&self) -> Duration {
-        if !self.config.enabled {
-            return Duration::from_millis(0);
-        }
+    /// Run `f` on an environment's bucket, creating it on first use
+    fn with_bucket<T>(&self, environment: &str, f: impl FnOnce(&mut TokenBucket) -> T) -> T {
+        let mut buckets = self.lock_buckets();
+        let bucket = buckets
+            .entry(environment.to_string())
+            .or_insert_with(|| TokenBucket::new(self.config.for_environment(environment), self.config.enabled));
+        f(bucket)
+    }
 
-        // Calculate time for one token to be added
-        let seconds_per_token = 60.0 / self.config.requests_per_minute as f64;
-        Duration::from_secs_f64(seconds_per_token)
+    fn lock_buckets(&self) -> std::sync::MutexGuard<'_, HashMap<String, TokenBucket>> {
+        self.buckets.lock().unwrap()
     }
 }
 
 /// Rate limiter statistics
 #[derive(Debug, Clone)]
 pub struct RateLimiterStats {
+    /// Environment these statistics are for (`None` when summed over all)
+    pub environment: Option<String>,
     /// Current number of tokens available
     pub tokens_available: f64,
     /// Total requests that were approved
@@ -195,6 +263,11 @@ impl RateLimiterStats {
     pub fn rejection_rate(&self) -> f64 {
         1.0 - self.acceptance_rate()
     }
+
+    /// Whether the next request would have to wait for a token
+    pub fn is_throttled(&self) -> bool {
+        self.enabled && self.tokens_available < 1.0
+    }
 }
 
 #[cfg(test)]
@@ -208,6 +281,7 @@ mod tests {
             requests_per_minute: 60,
             burst_capacity: 10,
             enabled: false,
+            ..RateLimitConfig::default()
         };
 
         let limiter = RateLimiter::new(config);
@@ -224,6 +298,7 @@ mod tests {
             requests_per_minute: 60,
             burst_capacity: 5,
             enabled: true,
+            ..RateLimitConfig::default()
         };
 
         let limiter = RateLimiter::new(config);
@@ -243,6 +318,7 @@ mod tests {
             requests_per_minute: 120, // 2 requests per second
             burst_capacity: 2,
             enabled: true,
+            ..RateLimitConfig::default()
         };
 
         let limiter = RateLimiter::new(config);
@@ -266,6 +342,7 @@ mod tests {
             requests_per_minute: 60,
             burst_capacity: 3,
             enabled: true,
+            ..RateLimitConfig::default()
         };
 
         let limiter = RateLimiter::new(config);
@@ -290,6 +367,7 @@ mod tests {
             requests_per_minute: 120, // Very fast for testing
             burst_capacity: 1,
             enabled: true,
+            ..RateLimitConfig::default()
         };
 
         let limiter = RateLimiter::new(config);
@@ -305,4 +383,36 @@ mod tests {
         // Should have waited approximately 0.5 seconds (60/120 = 0.5s per token)
         assert!(elapsed >= Duration::from_millis(400)); // Allow some tolerance
     }
-}
\ No newline at end of file
+
+    #[tokio::test]
+    async fn test_rate_limiter_buckets_are_per_environment() {
+        let config = RateLimitConfig {
+            requests_per_minute: 60,
+            burst_capacity: 2,
+            enabled: true,
+            ..RateLimitConfig::default()
+        }
+        .with_environment("prod", 60, 1);
+
+        let limiter = RateLimiter::new(config);
+
+        // Exhausting one environment leaves the other untouched
+        assert!(limiter.try_acquire_for("prod"));
+        assert!(!limiter.try_acquire_for("prod"));
+        assert!(limiter.try_acquire_for("dev"));
+        assert!(limiter.try_acquire_for("dev"));
+        assert!(!limiter.try_acquire_for("dev"));
+
+        let stats = limiter.environment_stats();
+        assert_eq!(stats.len(), 2);
+        assert_eq!(stats[0].environment.as_deref(), Some("dev"));
+        assert_eq!(stats[0].burst_capacity, 2);
+        assert_eq!(stats[1].environment.as_deref(), Some("prod"));
+        assert_eq!(stats[1].burst_capacity, 1);
+        assert!(stats[1].is_throttled());
+
+        let total = limiter.stats();
+        assert_eq!(total.requests_made, 3);
+        assert_eq!(total.requests_rejected, 2);
+    }
+}
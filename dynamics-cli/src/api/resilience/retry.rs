@@ -6,6 +6,7 @@ use std::time::Duration;
 use std::future::Future;
 use log::{debug, warn, info};
 use rand::Rng;
+use crate::api::ApiError;
 
 /// Configuration for retry behavior
 #[derive(Debug, Clone)]
@@ -15,6 +16,8 @@ pub struct RetryConfig {
     pub max_delay: Duration,
     pub backoff_multiplier: f64,
     pub jitter: bool,
+    /// Upper bound on a server-requested `Retry-After` delay
+    pub max_retry_after: Duration,
 }
 
 impl Default for RetryConfig {
@@ -25,6 +28,7 @@ impl Default for RetryConfig {
             max_delay: Duration::from_secs(30),
             backoff_multiplier: 2.0,
             jitter: true,
+            max_retry_after: Duration::from_secs(300),
         }
     }
 }
@@ -38,6 +42,7 @@ impl RetryConfig {
             max_delay: Duration::from_secs(10),
             backoff_multiplier: 1.5,
             jitter: true,
+            max_retry_after: Duration::from_secs(300),
         }
     }
 
@@ -49,6 +54,7 @@ impl RetryConfig {
             max_delay: Duration::from_secs(60),
             backoff_multiplier: 2.5,
             jitter: true,
+            max_retry_after: Duration::from_secs(300),
         }
     }
 }
@@ -62,6 +68,8 @@ pub enum RetryableError {
     ServerError(u16),
     /// HTTP 429 Too Many Requests
     RateLimited,
+    /// HTTP 429/503 with a `Retry-After` header: wait this long before retrying
+    ThrottledWithDelay(Duration),
     /// HTTP 408 Request Timeout
     Timeout,
     /// Non-retryable client errors (4xx except 408, 429)
@@ -79,6 +87,7 @@ impl RetryableError {
             RetryableError::Network => true,
             RetryableError::ServerError(_) => true,
             RetryableError::RateLimited => true,
+            RetryableError::ThrottledWithDelay(_) => true,
             RetryableError::Timeout => true,
             RetryableError::ClientError(_) => false,
             RetryableError::AuthError => false,
@@ -97,6 +106,42 @@ impl RetryableError {
         }
     }
 
+    /// Classify a throttling response (429 or 503), honoring its `Retry-After` header
+    /// Returns `None` for any other response
+    pub fn from_response(response: &reqwest::Response) -> Option<Self> {
+        let status = response.status().as_u16();
+        if status != 429 && status != 503 {
+            return None;
+        }
+
+        let retry_after = response
+            .headers()
+            .get(reqwest::header::RETRY_AFTER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(parse_retry_after);
+
+        Some(match retry_after {
+            Some(delay) => RetryableError::ThrottledWithDelay(delay),
+            None => Self::from_status_code(status),
+        })
+    }
+
+    /// Classify an error returned by the API client
+    ///
+    /// Recognizes `ApiError` and `reqwest::Error` anywhere in the chain.
+    pub fn from_error(error: &anyhow::Error) -> Self {
+        if let Some(api_error) = error.downcast_ref::<ApiError>() {
+            return match api_error {
+                ApiError::Timeout { .. } => RetryableError::Timeout,
+                ApiError::PreconditionFailed { .. } => RetryableError::ClientError(412),
+            };
+        }
+        match error.chain().find_map(|cause| cause.downcast_ref::<reqwest::Error>()) {
+            Some(error) => Self::from_reqwest_error(error),
+            None => RetryableError::Unknown,
+        }
+    }
+
     /// Classify a reqwest error
     pub fn from_reqwest_error(error: &reqwest::Error) -> Self {
         if error.is_timeout() {
@@ -111,6 +156,33 @@ impl RetryableError {
     }
 }
 
+/// Turn a transport error that ended a retry loop into the error callers see
+///
+/// Timeouts become `ApiError::Timeout` so they can be told apart from other
+/// network failures.
+fn into_api_error(error: reqwest::Error) -> anyhow::Error {
+    if error.is_timeout() {
+        let url = error.url().map(|url| url.to_string()).unwrap_or_default();
+        ApiError::Timeout { url }.into()
+    } else {
+        error.into()
+    }
+}
+
+/// Parse a `Retry-After` value: either delay-seconds (`"120"`) or an HTTP-date
+/// (`"Wed, 21 Oct 2015 07:28:00 GMT"`), which is converted to the time remaining
+pub fn parse_retry_after(value: &str) -> Option<Duration> {
+    let value = value.trim();
+
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let date = chrono::DateTime::parse_from_rfc2822(value).ok()?;
+    let remaining = date.with_timezone(&chrono::Utc) - chrono::Utc::now();
+    Some(remaining.to_std().unwrap_or(Duration::ZERO))
+}
+
 /// Retry policy that implements exponential backoff with jitter
 #[derive(Debug, Clone)]
 pub struct RetryPolicy {
@@ -151,7 +223,7 @@ impl RetryPolicy {
                     if !should_retry || attempt == self.config.max_attempts {
                         warn!("Operation failed permanently on attempt {} (should_retry: {}): {}",
                               attempt, should_retry, error);
-                        return Err(error.into());
+                        return Err(into_api_error(error));
                     }
 
                     warn!("Operation failed on attempt {} (retryable): {}", attempt, error);
@@ -166,7 +238,71 @@ impl RetryPolicy {
         }
 
         // This should never be reached, but just in case
-        Err(last_error.unwrap().into())
+        Err(into_api_error(last_error.unwrap()))
+    }
+
+    /// Execute an HTTP request with retry logic
+    ///
+    /// Like `execute`, but also retries throttled responses (429, 503). When the
+    /// server sends `Retry-After` that delay is used (capped at `max_retry_after`)
+    /// instead of the exponential backoff. If the last attempt is still throttled,
+    /// its response is returned for the caller to report.
+    pub async fn execute_response<F, Fut>(&self, operation: F) -> anyhow::Result<reqwest::Response>
+    where
+        F: Fn() -> Fut,
+        Fut: Future<Output = Result<reqwest::Response, reqwest::Error>>,
+    {
+        for attempt in 1..self.config.max_attempts {
+            let response = self.execute_once(&operation, attempt).await?;
+            let Some(response) = response else { continue };
+
+            let Some(throttled) = RetryableError::from_response(&response) else {
+                return Ok(response);
+            };
+
+            let delay = self.retry_delay(&throttled, attempt);
+            warn!("Request throttled (status {}) on attempt {}, retrying in {:?}",
+                  response.status(), attempt, delay);
+            tokio::time::sleep(delay).await;
+        }
+
+        // Final attempt: return whatever comes back
+        operation().await.map_err(into_api_error)
+    }
+
+    /// Run one attempt of `execute_response`
+    /// Returns `Ok(None)` after sleeping off a retryable transport error
+    async fn execute_once<F, Fut>(&self, operation: &F, attempt: u32) -> anyhow::Result<Option<reqwest::Response>>
+    where
+        F: Fn() -> Fut,
+        Fut: Future<Output = Result<reqwest::Response, reqwest::Error>>,
+    {
+        info!("Executing request (attempt {}/{})", attempt, self.config.max_attempts);
+
+        match operation().await {
+            Ok(response) => Ok(Some(response)),
+            Err(error) => {
+                let classified = RetryableError::from_reqwest_error(&error);
+                if !classified.should_retry() {
+                    warn!("Request failed permanently on attempt {}: {}", attempt, error);
+                    return Err(into_api_error(error));
+                }
+
+                let delay = self.retry_delay(&classified, attempt);
+                warn!("Request failed on attempt {} (retryable), retrying in {:?}: {}", attempt, delay, error);
+                tokio::time::sleep(delay).await;
+                Ok(None)
+            }
+        }
+    }
+
+    /// Delay before the next attempt: the server's `Retry-After` when known,
+    /// otherwise exponential backoff
+    fn retry_delay(&self, error: &RetryableError, attempt: u32) -> Duration {
+        match error {
+            RetryableError::ThrottledWithDelay(delay) => (*delay).min(self.config.max_retry_after),
+            _ => self.calculate_delay(attempt),
+        }
     }
 
     /// Calculate exponential backoff delay with optional jitter
@@ -231,6 +367,7 @@ mod tests {
             max_delay: Duration::from_secs(10),
             backoff_multiplier: 2.0,
             jitter: false, // Disable jitter for predictable testing
+            max_retry_after: Duration::from_secs(300),
         };
 
         let policy = RetryPolicy::new(config);
@@ -250,6 +387,7 @@ mod tests {
             max_delay: Duration::from_secs(5),
             backoff_multiplier: 2.0,
             jitter: false,
+            max_retry_after: Duration::from_secs(300),
         };
 
         let policy = RetryPolicy::new(config);
@@ -267,6 +405,7 @@ mod tests {
             max_delay: Duration::from_millis(10),
             backoff_multiplier: 2.0,
             jitter: false,
+            max_retry_after: Duration::from_secs(300),
         };
 
         let policy = RetryPolicy::new(config);
@@ -298,6 +437,7 @@ mod tests {
             max_delay: Duration::from_millis(10),
             backoff_multiplier: 2.0,
             jitter: false,
+            max_retry_after: Duration::from_secs(300),
         };
 
         let policy = RetryPolicy::new(config);
@@ -307,4 +447,69 @@ mod tests {
         assert_eq!(policy.calculate_delay(1), Duration::from_millis(1));
         assert_eq!(policy.calculate_delay(2), Duration::from_millis(2));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_parse_retry_after() {
+        assert_eq!(parse_retry_after("120"), Some(Duration::from_secs(120)));
+        assert_eq!(parse_retry_after(" 0 "), Some(Duration::ZERO));
+        assert_eq!(parse_retry_after("Wed, 21 Oct 2015 07:28:00 GMT"), Some(Duration::ZERO)); // in the past
+        assert_eq!(parse_retry_after("soon"), None);
+
+        let future = (chrono::Utc::now() + chrono::Duration::seconds(90)).to_rfc2822();
+        let delay = parse_retry_after(&future).unwrap();
+        assert!(delay > Duration::from_secs(85) && delay <= Duration::from_secs(90));
+    }
+
+    #[test]
+    fn test_retry_after_delay_is_capped() {
+        let config = RetryConfig {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(10),
+            backoff_multiplier: 2.0,
+            jitter: false,
+            max_retry_after: Duration::from_secs(60),
+        };
+
+        let policy = RetryPolicy::new(config);
+
+        assert!(RetryableError::ThrottledWithDelay(Duration::from_secs(5)).should_retry());
+        assert_eq!(
+            policy.retry_delay(&RetryableError::ThrottledWithDelay(Duration::from_secs(5)), 1),
+            Duration::from_secs(5)
+        );
+        assert_eq!(
+            policy.retry_delay(&RetryableError::ThrottledWithDelay(Duration::from_secs(600)), 1),
+            Duration::from_secs(60)
+        );
+        assert_eq!(policy.retry_delay(&RetryableError::RateLimited, 2), Duration::from_millis(200));
+    }
+
+    #[tokio::test]
+    async fn test_elapsed_timeout_becomes_api_error() {
+        // Accepts connections but never answers
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/api/data/v9.2/accounts", listener.local_addr().unwrap());
+
+        let policy = RetryPolicy::new(RetryConfig {
+            max_attempts: 2,
+            base_delay: Duration::from_millis(1),
+            max_delay: Duration::from_millis(1),
+            backoff_multiplier: 1.0,
+            jitter: false,
+            max_retry_after: Duration::from_secs(1),
+        });
+        let attempts = AtomicU32::new(0);
+        let client = reqwest::Client::new();
+
+        let error = policy.execute_response(|| {
+            attempts.fetch_add(1, Ordering::SeqCst);
+            client.get(&url).timeout(Duration::from_millis(50)).send()
+        }).await.unwrap_err();
+
+        assert_eq!(attempts.load(Ordering::SeqCst), 2);
+        assert_eq!(error.downcast_ref::<ApiError>(), Some(&ApiError::Timeout { url: url.clone() }));
+        assert_eq!(RetryableError::from_error(&error), RetryableError::Timeout);
+        drop(listener);
+    }
+}
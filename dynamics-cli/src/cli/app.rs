@@ -1,7 +1,12 @@
 use super::commands::AuthCommands;
+use super::commands::completions::{self, CompletionsCommands};
+use super::commands::config::ConfigCommands;
 use super::commands::deadlines::DeadlinesCommands;
+use super::commands::diff::DiffCommands;
 use super::commands::entity::EntityCommands;
+use super::commands::get::GetCommands;
 use super::commands::migration::MigrationCommands;
+use super::commands::operations::OperationsCommands;
 use super::commands::query::QueryCommands;
 use super::commands::raw::RawCommands;
 use super::commands::settings::SettingsCommands;
@@ -23,9 +28,15 @@ pub enum Commands {
     Auth(AuthCommands),
     /// Execute FQL queries against Dynamics 365
     Query(QueryCommands),
+    /// Retrieve a single record by ID
+    Get(GetCommands),
+    /// Compare query results between two environments
+    Diff(DiffCommands),
     /// Execute raw HTTP requests to Dynamics 365 API
     Raw(RawCommands),
-    /// Entity name mapping management
+    /// Inspect and retry operations that failed in the operation queue
+    Operations(OperationsCommands),
+    /// Entity metadata lookup and name mapping management
     Entity(EntityCommands),
     /// Application settings management
     Settings(SettingsCommands),
@@ -33,8 +44,13 @@ pub enum Commands {
     Migration(MigrationCommands),
     /// Deadlines management and tracking
     Deadlines(DeadlinesCommands),
+    /// Config database maintenance
+    Config(ConfigCommands),
     /// Launch interactive TUI interface
     Tui(TuiCommands),
     /// Check for updates and install new versions
     Update(UpdateCommands),
+    /// Print a shell completion script
+    #[command(after_help = completions::INSTALL_HELP)]
+    Completions(CompletionsCommands),
 }
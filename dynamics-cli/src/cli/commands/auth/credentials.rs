@@ -2,6 +2,7 @@
 
 use anyhow::Result;
 use dialoguer::{Input, Password, Select, Confirm};
+use crate::api::certificate::ClientCertificate;
 use crate::api::models::CredentialSet;
 use crate::config::Config;
 use crate::cli::ui::with_spinner;
@@ -22,12 +23,14 @@ pub async fn handle_credential_command(cmd: CredentialCommands) -> Result<()> {
             client_secret,
             tenant_id,
             cert_path,
+            cert_password,
         } => {
             add_credentials_noninteractive(
-                name, r#type, username, password, client_id, client_secret, tenant_id, cert_path,
+                name, r#type, username, password, client_id, client_secret, tenant_id, cert_path, cert_password,
             ).await
         }
-        CredentialCommands::List => list_credentials_interactive().await,
+        CredentialCommands::List { export_plaintext: false } => list_credentials_interactive().await,
+        CredentialCommands::List { export_plaintext: true } => export_credentials_plaintext().await,
         CredentialCommands::Test { name, host } => {
             test_credentials_by_name(&name, &host).await
         }
@@ -50,6 +53,7 @@ async fn add_credentials_noninteractive(
     client_secret: Option<String>,
     tenant_id: Option<String>,
     cert_path: Option<String>,
+    cert_password: Option<String>,
 ) -> Result<()> {
     let client_manager = crate::client_manager();
     let credentials = match cred_type {
@@ -95,6 +99,7 @@ async fn add_credentials_noninteractive(
                 client_id,
                 tenant_id,
                 cert_path,
+                cert_password,
             }
         }
     };
@@ -212,12 +217,17 @@ pub async fn add_credentials_interactive() -> Result<()> {
         CredentialType::Certificate => {
             let client_id: String = Input::new().with_prompt("Client ID").interact()?;
             let tenant_id: String = Input::new().with_prompt("Tenant ID").interact()?;
-            let cert_path: String = Input::new().with_prompt("Certificate path").interact()?;
+            let cert_path: String = Input::new().with_prompt("Certificate path (PEM or PFX)").interact()?;
+            let cert_password: String = Password::new()
+                .with_prompt("Certificate password (leave empty if none)")
+                .allow_empty_password(true)
+                .interact()?;
 
             CredentialSet::Certificate {
                 client_id,
                 tenant_id,
                 cert_path,
+                cert_password: Some(cert_password).filter(|password| !password.is_empty()),
             }
         }
     };
@@ -229,6 +239,19 @@ pub async fn add_credentials_interactive() -> Result<()> {
     Ok(())
 }
 
+/// Print all credentials decrypted as JSON, with a warning on stderr
+async fn export_credentials_plaintext() -> Result<()> {
+    let credentials = crate::global_config().export_credentials_plaintext().await?;
+    let export: serde_json::Map<String, serde_json::Value> = credentials
+        .into_iter()
+        .map(|(name, data)| Ok((name, serde_json::to_value(data)?)))
+        .collect::<Result<_>>()?;
+
+    eprintln!("{} Output contains plaintext secrets", "⚠️".bright_yellow().bold());
+    println!("{}", serde_json::to_string_pretty(&export)?);
+    Ok(())
+}
+
 /// Test credentials interactively
 pub async fn test_credentials_interactive() -> Result<()> {
     let client_manager = crate::client_manager();
@@ -293,6 +316,20 @@ async fn test_credentials_by_name(name: &str, host: &str) -> Result<()> {
     let credentials = client_manager.get_credentials(name).await?
         .ok_or_else(|| anyhow::anyhow!("Credentials '{}' not found", name))?;
 
+    // Check the certificate loads before involving the server, so local problems
+    // (wrong path, wrong password, mismatched key) aren't reported as auth failures
+    if let CredentialSet::Certificate { cert_path, cert_password, .. } = &credentials {
+        match ClientCertificate::load(cert_path, cert_password.as_deref()).and_then(|cert| cert.thumbprint_hex()) {
+            Ok(thumbprint) => {
+                println!("{} Loaded certificate with thumbprint {}", "✓".bright_green().bold(), thumbprint.cyan());
+            }
+            Err(e) => {
+                println!("{} Certificate check failed for '{}': {}", "✗".bright_red().bold(), name.bright_yellow().bold(), format!("{:#}", e).red());
+                return Ok(());
+            }
+        }
+    }
+
     let result = with_spinner("Testing authentication...", async {
         // Use AuthManager directly for testing
         let mut auth_manager = crate::api::auth::AuthManager::new();
@@ -0,0 +1,106 @@
+//! Device code sign-in with a live progress spinner
+
+use anyhow::Result;
+use colored::*;
+use dialoguer::Select;
+use std::io::Write;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+use crate::api::{DeviceCodeProgress, DeviceCodePrompt};
+
+const SPINNER_FRAMES: [&str; 10] = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+const SPINNER_TICK: Duration = Duration::from_millis(120);
+
+/// Pick a device code environment and sign in to it
+pub async fn sign_in_interactive() -> Result<()> {
+    let client_manager = crate::client_manager();
+
+    let mut candidates = Vec::new();
+    for env_name in client_manager.list_environments().await {
+        if client_manager.uses_device_code(&env_name).await.unwrap_or(false) {
+            candidates.push(env_name);
+        }
+    }
+
+    if candidates.is_empty() {
+        println!("  {} No environments use device code credentials.", "⚠️".bright_yellow().bold());
+        return Ok(());
+    }
+
+    println!();
+    let selection = Select::new()
+        .with_prompt("Sign in to environment")
+        .items(&candidates)
+        .default(0)
+        .interact()?;
+
+    sign_in(&candidates[selection]).await
+}
+
+/// Sign in to an environment with the device code flow
+pub async fn sign_in(env_name: &str) -> Result<()> {
+    let client_manager = crate::client_manager();
+
+    if !client_manager.uses_device_code(env_name).await? {
+        anyhow::bail!("Environment '{}' does not use device code credentials", env_name);
+    }
+
+    // The spinner only starts once the code is on screen
+    let waiting = Arc::new(AtomicBool::new(false));
+    let code_shown = waiting.clone();
+
+    let sign_in = client_manager.sign_in_with_device_code(env_name, move |progress| match progress {
+        DeviceCodeProgress::CodeIssued(prompt) => {
+            print_prompt(&prompt);
+            code_shown.store(true, Ordering::Relaxed);
+        }
+        DeviceCodeProgress::Pending => {}
+        DeviceCodeProgress::SlowDown { interval } => {
+            clear_line();
+            println!("  {} Server asked to slow down, checking every {}s", "⏳".bright_yellow(), interval.as_secs());
+        }
+    });
+    tokio::pin!(sign_in);
+
+    let mut ticker = tokio::time::interval(SPINNER_TICK);
+    let mut frame = 0;
+    let result = loop {
+        tokio::select! {
+            result = &mut sign_in => break result,
+            _ = ticker.tick() => {
+                if waiting.load(Ordering::Relaxed) {
+                    print!(
+                        "\r  {} {}",
+                        SPINNER_FRAMES[frame % SPINNER_FRAMES.len()].bright_cyan(),
+                        "Waiting for you to sign in...".dimmed()
+                    );
+                    std::io::stdout().flush().ok();
+                    frame += 1;
+                }
+            }
+        }
+    };
+    clear_line();
+
+    result?;
+    println!("{} Signed in to environment '{}'", "✓".bright_green().bold(), env_name.bright_green().bold());
+    Ok(())
+}
+
+fn print_prompt(prompt: &DeviceCodePrompt) {
+    println!();
+    println!("  {}", "🔑 Device Code Sign-In".bright_cyan().bold());
+    println!("  {}", "══════════════════════".bright_cyan());
+    println!();
+    println!("  Open:       {}", prompt.verification_uri.bright_blue().underline());
+    println!("  Enter code: {}", prompt.user_code.bright_yellow().bold());
+    println!();
+    println!("  {}", format!("The code expires in {} minutes.", prompt.expires_in.as_secs() / 60).dimmed());
+    println!();
+}
+
+fn clear_line() {
+    print!("\r\x1b[2K");
+    std::io::stdout().flush().ok();
+}
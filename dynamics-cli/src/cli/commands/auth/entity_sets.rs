@@ -0,0 +1,48 @@
+//! Entity set name override management
+
+use anyhow::Result;
+use colored::*;
+use super::EntitySetCommands;
+
+/// Handle entity set override commands
+pub async fn handle_entity_set_command(cmd: EntitySetCommands) -> Result<()> {
+    let client_manager = crate::client_manager();
+
+    match cmd {
+        EntitySetCommands::Add { entity_name, entity_set_name } => {
+            client_manager.set_entity_set_override(&entity_name, &entity_set_name).await?;
+            println!(
+                "{} '{}' now uses entity set '{}'",
+                "✓".bright_green().bold(),
+                entity_name.bright_green().bold(),
+                entity_set_name.cyan()
+            );
+            Ok(())
+        }
+        EntitySetCommands::List => {
+            let overrides = client_manager.list_entity_set_overrides().await;
+            if overrides.is_empty() {
+                println!("  {}", "No entity set overrides configured".dimmed());
+                println!("  {}", "Entity set names are derived by pluralizing the entity name.".dimmed());
+                return Ok(());
+            }
+
+            println!();
+            println!("  {}", "Entity set overrides:".bright_white().bold());
+            for (entity_name, entity_set_name) in overrides {
+                println!("  {} → {}", entity_name.bright_green(), entity_set_name.cyan());
+            }
+            println!();
+            Ok(())
+        }
+        EntitySetCommands::Remove { entity_name } => {
+            client_manager.remove_entity_set_override(&entity_name).await?;
+            println!(
+                "{} Removed entity set override for '{}'",
+                "✓".bright_green().bold(),
+                entity_name.bright_green().bold()
+            );
+            Ok(())
+        }
+    }
+}
@@ -31,12 +31,25 @@ pub async fn handle_environment_command(cmd: EnvironmentCommands) -> Result<()>
                 select_environment_interactive().await
             }
         }
+        EnvironmentCommands::SignIn { name } => {
+            if let Some(name) = name {
+                super::device_code::sign_in(&name).await
+            } else {
+                super::device_code::sign_in_interactive().await
+            }
+        }
+        EnvironmentCommands::Clone { source, destination, host, set_current } => {
+            clone_environment(&source, destination, host, set_current).await
+        }
         EnvironmentCommands::Rename { old_name, new_name } => {
             rename_environment_noninteractive(&old_name, new_name).await
         }
         EnvironmentCommands::Remove { name, force } => {
             remove_environment_by_name(&name, force).await
         }
+        EnvironmentCommands::Protect { name } => set_environment_protection(&name, true).await,
+        EnvironmentCommands::Unprotect { name } => set_environment_protection(&name, false).await,
+        EnvironmentCommands::Test { name } => test_environment(&name).await,
     }
 }
 
@@ -62,6 +75,10 @@ async fn add_environment_noninteractive(
     client_manager.add_environment_to_config(name.clone(), environment).await?;
     println!("{} Environment '{}' added successfully", "✓".bright_green().bold(), name.bright_green().bold());
 
+    if crate::global_config().is_environment_protected(&name).await? {
+        println!("{} '{}' matches the protected name pattern; writes will require confirmation", "🔒".bright_yellow(), name.bright_green().bold());
+    }
+
     if set_current {
         client_manager.set_current_environment_in_config(name.clone()).await?;
         println!("{} Set '{}' as current environment", "✓".bright_green().bold(), name.bright_green().bold());
@@ -91,12 +108,18 @@ pub async fn list_environments_interactive() -> Result<()> {
             } else {
                 ("○", env_name.white(), "".white())
             };
-            println!("  {} {} → {} ({}){}",
+            let protected_text = if crate::global_config().is_environment_protected(env_name).await? {
+                " [protected]".bright_red()
+            } else {
+                "".white()
+            };
+            println!("  {} {} → {} ({}){}{}",
                      marker.bright_green(),
                      env_color,
                      environment.host.cyan(),
                      environment.credentials_ref.bright_yellow(),
-                     current_text);
+                     current_text,
+                     protected_text);
         }
     }
     println!();
@@ -262,6 +285,48 @@ async fn rename_environment_noninteractive(old_name: &str, new_name: String) ->
     Ok(())
 }
 
+/// Clone an environment under a new name, optionally pointing it at another host
+///
+/// Only the configuration is copied; the clone authenticates on first use.
+async fn clone_environment(source: &str, destination: String, host: Option<String>, set_current: bool) -> Result<()> {
+    let client_manager = crate::client_manager();
+    let config = crate::global_config();
+
+    let source_env = config.get_environment(source).await?
+        .ok_or_else(|| anyhow::anyhow!("Environment '{}' not found", source))?;
+
+    if config.get_environment(&destination).await?.is_some() {
+        anyhow::bail!("Environment '{}' already exists", destination);
+    }
+
+    let environment = Environment {
+        name: destination.clone(),
+        host: host.unwrap_or(source_env.host),
+        credentials_ref: source_env.credentials_ref,
+    };
+    let host = environment.host.clone();
+
+    client_manager.add_environment_to_config(destination.clone(), environment).await?;
+    println!(
+        "{} Cloned '{}' to '{}' ({})",
+        "✓".bright_green().bold(),
+        source.bright_green(),
+        destination.bright_green().bold(),
+        host.cyan()
+    );
+
+    if config.is_environment_protected(&destination).await? {
+        println!("{} '{}' matches the protected name pattern; writes will require confirmation", "🔒".bright_yellow(), destination.bright_green().bold());
+    }
+
+    if set_current {
+        client_manager.set_current_environment_in_config(destination.clone()).await?;
+        println!("{} Set '{}' as current environment", "✓".bright_green().bold(), destination.bright_green().bold());
+    }
+
+    Ok(())
+}
+
 /// Remove environment interactively
 pub async fn remove_environment_interactive() -> Result<()> {
     let client_manager = crate::client_manager();
@@ -404,4 +469,54 @@ async fn set_credentials_by_name(env_name: &str, credentials: &str) -> Result<()
 
     println!("{} Environment '{}' now uses credentials '{}'", "✓".bright_green().bold(), env_name.bright_green().bold(), credentials.bright_yellow().bold());
     Ok(())
-}
\ No newline at end of file
+}
+
+/// Set or clear the write-protection flag on an environment
+/// Sign in to an environment and time a `WhoAmI` request
+async fn test_environment(name: &str) -> Result<()> {
+    let client_manager = crate::client_manager();
+    if client_manager.get_environment(name).await?.is_none() {
+        anyhow::bail!("Environment '{}' not found", name);
+    }
+
+    let check = async {
+        let client = client_manager.get_client(name).await?;
+        let health = client.health_check().await?;
+        anyhow::Ok((client, health))
+    };
+    match check.await {
+        Ok((client, health)) => {
+            println!("{} Environment '{}' is reachable ({} ms)", "✓".bright_green().bold(), name.bright_green().bold(), health.latency.as_millis());
+            println!("  User:          {}", health.identity.user_id);
+            println!("  Business unit: {}", health.identity.business_unit_id);
+            println!("  Organization:  {}", health.identity.organization_id);
+
+            // Extra detail only; the environment already passed the check
+            match client.retrieve_organization_info().await {
+                Ok(org) => {
+                    println!("  Name:          {}", org.name);
+                    println!("  Version:       {}", org.version);
+                    if let Some(url) = org.url {
+                        println!("  URL:           {}", url);
+                    }
+                }
+                Err(e) => log::warn!("Failed to retrieve organization info for '{}': {}", name, e),
+            }
+            Ok(())
+        }
+        Err(e) => {
+            println!("{} Environment '{}' failed the health check", "✗".bright_red().bold(), name.bright_red().bold());
+            Err(e)
+        }
+    }
+}
+
+async fn set_environment_protection(name: &str, protected: bool) -> Result<()> {
+    crate::global_config().set_environment_protected(name, protected).await?;
+    if protected {
+        println!("{} Environment '{}' is now protected; writes will require confirmation", "🔒".bright_yellow(), name.bright_green().bold());
+    } else {
+        println!("{} Environment '{}' is no longer protected", "✓".bright_green().bold(), name.bright_green().bold());
+    }
+    Ok(())
+}
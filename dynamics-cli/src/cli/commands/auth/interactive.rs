@@ -70,6 +70,7 @@ enum EnvironmentMenuOption {
     Add,
     SetCredentials,
     Select,
+    SignIn,
     Rename,
     Remove,
     Back,
@@ -82,6 +83,7 @@ impl std::fmt::Display for EnvironmentMenuOption {
             EnvironmentMenuOption::Add => write!(f, "➕ {} - {}", "Add".bright_green().bold(), "Create new environment".dimmed()),
             EnvironmentMenuOption::SetCredentials => write!(f, "🔗 {} - {}", "Set Credentials".bright_yellow().bold(), "Change environment authentication".dimmed()),
             EnvironmentMenuOption::Select => write!(f, "🎯 {} - {}", "Select".bright_cyan().bold(), "Choose current environment".dimmed()),
+            EnvironmentMenuOption::SignIn => write!(f, "🔑 {} - {}", "Sign In".bright_magenta().bold(), "Sign in with a device code".dimmed()),
             EnvironmentMenuOption::Rename => write!(f, "✏️ {} - {}", "Rename".bright_yellow().bold(), "Change environment name".dimmed()),
             EnvironmentMenuOption::Remove => write!(f, "🗑️ {} - {}", "Remove".bright_red().bold(), "Delete environment".dimmed()),
             EnvironmentMenuOption::Back => write!(f, "🔙 {} - {}", "Back".white().bold(), "Return to main menu".dimmed()),
@@ -231,6 +233,7 @@ async fn run_environments_menu() -> Result<()> {
             EnvironmentMenuOption::Add,
             EnvironmentMenuOption::SetCredentials,
             EnvironmentMenuOption::Select,
+            EnvironmentMenuOption::SignIn,
             EnvironmentMenuOption::Rename,
             EnvironmentMenuOption::Remove,
             EnvironmentMenuOption::Back,
@@ -267,6 +270,12 @@ async fn run_environments_menu() -> Result<()> {
                 });
                 pause_for_user();
             }
+            EnvironmentMenuOption::SignIn => {
+                super::device_code::sign_in_interactive().await.unwrap_or_else(|e| {
+                    println!("Error: {}", e);
+                });
+                pause_for_user();
+            }
             EnvironmentMenuOption::Rename => {
                 super::environments::rename_environment_interactive().await.unwrap_or_else(|e| {
                     println!("Error: {}", e);
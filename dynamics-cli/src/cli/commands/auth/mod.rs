@@ -6,7 +6,9 @@ use is_terminal::IsTerminal;
 
 mod interactive;
 mod credentials;
+mod device_code;
 mod environments;
+mod entity_sets;
 mod status;
 
 #[derive(Args)]
@@ -25,6 +27,9 @@ pub enum AuthSubcommands {
     /// Environment management
     #[command(subcommand)]
     Env(EnvironmentCommands),
+    /// Entity set name overrides for entities the pluralization rules get wrong
+    #[command(subcommand)]
+    EntitySet(EntitySetCommands),
 }
 
 #[derive(Subcommand)]
@@ -52,12 +57,19 @@ pub enum CredentialCommands {
         /// Tenant ID (for client-credentials/device-code/certificate)
         #[arg(long)]
         tenant_id: Option<String>,
-        /// Certificate path (for certificate auth)
+        /// Certificate path, PEM or PFX (for certificate auth)
         #[arg(long)]
         cert_path: Option<String>,
+        /// Password for a PFX file or encrypted PEM key (for certificate auth)
+        #[arg(long)]
+        cert_password: Option<String>,
     },
     /// List all credentials
-    List,
+    List {
+        /// Print every credential set decrypted, as JSON (for debugging; includes secrets)
+        #[arg(long)]
+        export_plaintext: bool,
+    },
     /// Test credentials
     Test {
         /// Credential name to test
@@ -105,6 +117,7 @@ pub enum EnvironmentCommands {
     /// Set credentials for an environment
     SetCredentials {
         /// Environment name
+        #[arg(value_name = "ENV")]
         name: String,
         /// Credentials to use
         credentials: String,
@@ -112,8 +125,29 @@ pub enum EnvironmentCommands {
     /// Select current environment
     Select {
         /// Environment name to select
+        #[arg(value_name = "ENV")]
+        name: Option<String>,
+    },
+    /// Sign in to an environment that uses device code credentials
+    SignIn {
+        /// Environment name to sign in to
+        #[arg(value_name = "ENV")]
         name: Option<String>,
     },
+    /// Copy an environment's host and credentials under a new name
+    Clone {
+        /// Environment to copy
+        #[arg(value_name = "ENV")]
+        source: String,
+        /// Name for the new environment
+        destination: String,
+        /// Host URL for the new environment (defaults to the source host)
+        #[arg(long)]
+        host: Option<String>,
+        /// Set the new environment as current
+        #[arg(long)]
+        set_current: bool,
+    },
     /// Rename environment
     Rename {
         /// Current name
@@ -124,11 +158,45 @@ pub enum EnvironmentCommands {
     /// Remove environment
     Remove {
         /// Environment name to remove
+        #[arg(value_name = "ENV")]
         name: String,
         /// Force removal without confirmation
         #[arg(short, long)]
         force: bool,
     },
+    /// Require confirmation before writing to an environment
+    Protect {
+        /// Environment name to protect
+        name: String,
+    },
+    /// Allow writes to an environment without confirmation
+    Unprotect {
+        /// Environment name to unprotect
+        name: String,
+    },
+    /// Check an environment is reachable and its credentials are accepted
+    Test {
+        /// Environment name to test
+        name: String,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum EntitySetCommands {
+    /// Use a specific entity set name for an entity
+    Add {
+        /// Entity logical name (e.g. new_status)
+        entity_name: String,
+        /// Entity set name used in Web API URLs (e.g. new_statuslist)
+        entity_set_name: String,
+    },
+    /// List entity set overrides
+    List,
+    /// Remove an override and go back to pluralizing the entity name
+    Remove {
+        /// Entity logical name
+        entity_name: String,
+    },
 }
 
 #[derive(clap::ValueEnum, Clone, Debug)]
@@ -156,6 +224,7 @@ pub async fn auth_command(args: AuthCommands) -> Result<()> {
             Some(AuthSubcommands::Status) => status::status_command().await,
             Some(AuthSubcommands::Creds(cmd)) => credentials::handle_credential_command(cmd).await,
             Some(AuthSubcommands::Env(cmd)) => environments::handle_environment_command(cmd).await,
+            Some(AuthSubcommands::EntitySet(cmd)) => entity_sets::handle_entity_set_command(cmd).await,
             None => {
                 // Non-interactive mode without subcommand - show help
                 println!("Authentication management for Dynamics CLI");
@@ -166,6 +235,7 @@ pub async fn auth_command(args: AuthCommands) -> Result<()> {
                 println!("  dynamics-cli auth status              # Show current status");
                 println!("  dynamics-cli auth creds --help        # Credential management");
                 println!("  dynamics-cli auth env --help          # Environment management");
+                println!("  dynamics-cli auth entity-set --help   # Entity set name overrides");
                 Ok(())
             }
         }
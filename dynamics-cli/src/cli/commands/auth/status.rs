@@ -68,7 +68,7 @@ pub async fn status_command() -> Result<()> {
                         println!("    {}: {}", "Client ID".dimmed(), client_id.white());
                         println!("    {}: {}", "Tenant ID".dimmed(), tenant_id.white());
                     }
-                    CredentialSet::Certificate { client_id, tenant_id, cert_path } => {
+                    CredentialSet::Certificate { client_id, tenant_id, cert_path, .. } => {
                         println!("    {}: {}", "Type".dimmed(), "Certificate".bright_blue());
                         println!("    {}: {}", "Client ID".dimmed(), client_id.white());
                         println!("    {}: {}", "Tenant ID".dimmed(), tenant_id.white());
@@ -0,0 +1,477 @@
+//! Shell completion scripts generated from the `Cli` definition
+//!
+//! Scripts are built by walking the clap command tree, so new subcommands and
+//! flags are picked up without changes here. Arguments named `ENV` complete
+//! environment names by calling back into `completions --environments`.
+
+use anyhow::Result;
+use clap::{Arg, Args, CommandFactory, ValueEnum, ValueHint};
+use std::fmt::Write;
+
+use crate::cli::Cli;
+
+/// Value name marking arguments that take an environment name
+const ENV_VALUE_NAME: &str = "ENV";
+
+pub const INSTALL_HELP: &str = "\
+Installation:
+  bash:        dynamics-cli completions bash > ~/.local/share/bash-completion/completions/dynamics-cli
+  zsh:         dynamics-cli completions zsh > \"${fpath[1]}/_dynamics-cli\"
+  fish:        dynamics-cli completions fish > ~/.config/fish/completions/dynamics-cli.fish
+  powershell:  dynamics-cli completions powershell >> $PROFILE
+
+Open a new shell afterwards. Environment names are completed from the config database.";
+
+#[derive(Args)]
+pub struct CompletionsCommands {
+    /// Shell to generate the completion script for
+    #[arg(required_unless_present = "environments")]
+    pub shell: Option<Shell>,
+
+    /// Print configured environment names, one per line (used by the scripts)
+    #[arg(long, hide = true, conflicts_with = "shell")]
+    pub environments: bool,
+}
+
+impl CompletionsCommands {
+    /// Whether the command can run without loading the config
+    pub fn runs_before_load(&self) -> bool {
+        !self.environments
+    }
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum Shell {
+    Bash,
+    Zsh,
+    Fish,
+    Powershell,
+}
+
+/// Handle completions command
+pub async fn handle_completions_command(cmd: CompletionsCommands) -> Result<()> {
+    match cmd.shell {
+        Some(shell) => print!("{}", generate(shell)),
+        None => {
+            for name in crate::global_config().list_environments().await? {
+                println!("{}", name);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Completion script for `shell`
+pub fn generate(shell: Shell) -> String {
+    let mut cli = Cli::command();
+    cli.build();
+    let bin = cli.get_name().to_string();
+
+    let mut nodes = Vec::new();
+    collect(&cli, bin.clone(), &mut nodes);
+
+    match shell {
+        Shell::Bash => bash(&bin, &nodes),
+        Shell::Zsh => zsh(&bin, &nodes),
+        Shell::Fish => fish(&bin, &nodes),
+        Shell::Powershell => powershell(&bin, &nodes),
+    }
+}
+
+/// What to offer for an argument's value
+#[derive(Debug, Clone, PartialEq)]
+enum Completion {
+    Environments,
+    Values(Vec<String>),
+    Files,
+    Any,
+}
+
+impl Completion {
+    fn of(arg: &Arg) -> Self {
+        let is_env = arg.get_value_names()
+            .is_some_and(|names| names.iter().any(|n| n.as_str() == ENV_VALUE_NAME));
+        let values: Vec<String> = arg.get_possible_values().iter()
+            .filter(|v| !v.is_hide_set())
+            .map(|v| v.get_name().to_string())
+            .collect();
+
+        if is_env {
+            Self::Environments
+        } else if !values.is_empty() {
+            Self::Values(values)
+        } else if matches!(arg.get_value_hint(), ValueHint::FilePath | ValueHint::DirPath | ValueHint::AnyPath) {
+            Self::Files
+        } else {
+            Self::Any
+        }
+    }
+}
+
+struct Opt {
+    long: Option<String>,
+    short: Option<char>,
+    help: String,
+    /// `None` for flags
+    value: Option<Completion>,
+}
+
+impl Opt {
+    fn spellings(&self) -> Vec<String> {
+        self.long.iter().map(|l| format!("--{}", l))
+            .chain(self.short.map(|s| format!("-{}", s)))
+            .collect()
+    }
+}
+
+struct Subcommand {
+    name: String,
+    help: String,
+    id: String,
+}
+
+/// One command level; `id` joins the path with `__`, e.g. `dynamics-cli__auth__env`
+struct Node {
+    id: String,
+    subcommands: Vec<Subcommand>,
+    options: Vec<Opt>,
+    /// Positionals aren't counted, so their candidates are offered for any word
+    positionals: Vec<Completion>,
+}
+
+impl Node {
+    /// Subcommand names, option spellings and positional values offered at this level
+    fn words(&self) -> Vec<String> {
+        let mut words: Vec<String> = self.subcommands.iter().map(|s| s.name.clone()).collect();
+        for completion in &self.positionals {
+            if let Completion::Values(values) = completion {
+                words.extend(values.iter().cloned());
+            }
+        }
+        words.extend(self.options.iter().flat_map(Opt::spellings));
+        words
+    }
+
+    fn value_options(&self) -> impl Iterator<Item = (&Opt, &Completion)> {
+        self.options.iter().filter_map(|o| o.value.as_ref().map(|v| (o, v)))
+    }
+
+    fn completes_positional(&self, completion: &Completion) -> bool {
+        self.positionals.contains(completion)
+    }
+}
+
+fn collect(cmd: &clap::Command, id: String, nodes: &mut Vec<Node>) {
+    let mut node = Node { id, subcommands: Vec::new(), options: Vec::new(), positionals: Vec::new() };
+
+    for arg in cmd.get_arguments().filter(|a| !a.is_hide_set()) {
+        if arg.is_positional() {
+            node.positionals.push(Completion::of(arg));
+            continue;
+        }
+        node.options.push(Opt {
+            long: arg.get_long().map(str::to_string),
+            short: arg.get_short(),
+            help: help_line(arg.get_help()),
+            value: arg.get_action().takes_values().then(|| Completion::of(arg)),
+        });
+    }
+
+    let mut children = Vec::new();
+    for sub in cmd.get_subcommands().filter(|s| !s.is_hide_set()) {
+        let id = format!("{}__{}", node.id, sub.get_name());
+        for name in sub.get_name_and_visible_aliases() {
+            node.subcommands.push(Subcommand {
+                name: name.to_string(),
+                help: help_line(sub.get_about()),
+                id: id.clone(),
+            });
+        }
+        // clap's generated `help` subcommand only repeats the tree
+        if sub.get_name() != "help" {
+            children.push((sub, id));
+        }
+    }
+
+    nodes.push(node);
+    for (sub, id) in children {
+        collect(sub, id, nodes);
+    }
+}
+
+fn help_line(help: Option<&clap::builder::StyledStr>) -> String {
+    help.map(|h| h.to_string().lines().next().unwrap_or_default().to_string())
+        .unwrap_or_default()
+}
+
+fn function_name(bin: &str) -> String {
+    format!("_{}", bin.replace('-', "_"))
+}
+
+fn environments_command(bin: &str) -> String {
+    format!("{} completions --environments", bin)
+}
+
+fn bash(bin: &str, nodes: &[Node]) -> String {
+    let func = function_name(bin);
+    let envs = format!("$({} 2>/dev/null)", environments_command(bin));
+    let mut out = String::new();
+
+    let _ = writeln!(out, "{}() {{", func);
+    let _ = writeln!(out, "    local cur prev cmd opts i");
+    let _ = writeln!(out, "    COMPREPLY=()");
+    let _ = writeln!(out, "    cur=\"${{COMP_WORDS[COMP_CWORD]}}\"");
+    let _ = writeln!(out, "    prev=\"${{COMP_WORDS[COMP_CWORD-1]}}\"");
+    let _ = writeln!(out, "    cmd=\"{}\"", bin);
+    let _ = writeln!(out);
+    let _ = writeln!(out, "    for ((i = 1; i < COMP_CWORD; i++)); do");
+    let _ = writeln!(out, "        case \"${{cmd}}::${{COMP_WORDS[i]}}\" in");
+    for node in nodes {
+        for sub in &node.subcommands {
+            let _ = writeln!(out, "            \"{}::{}\") cmd=\"{}\" ;;", node.id, sub.name, sub.id);
+        }
+    }
+    let _ = writeln!(out, "        esac");
+    let _ = writeln!(out, "    done");
+    let _ = writeln!(out);
+    let _ = writeln!(out, "    case \"${{cmd}}\" in");
+    for node in nodes {
+        let _ = writeln!(out, "        \"{}\")", node.id);
+        if node.value_options().next().is_some() {
+            let _ = writeln!(out, "            case \"${{prev}}\" in");
+            for (opt, completion) in node.value_options() {
+                let reply = match completion {
+                    Completion::Environments => format!("COMPREPLY=($(compgen -W \"{}\" -- \"${{cur}}\"))", envs),
+                    Completion::Values(values) => format!("COMPREPLY=($(compgen -W \"{}\" -- \"${{cur}}\"))", values.join(" ")),
+                    Completion::Files => "COMPREPLY=($(compgen -f -- \"${cur}\"))".to_string(),
+                    Completion::Any => "COMPREPLY=()".to_string(),
+                };
+                let _ = writeln!(out, "                {}) {}; return 0 ;;", opt.spellings().join("|"), reply);
+            }
+            let _ = writeln!(out, "            esac");
+        }
+        let mut opts = node.words().join(" ");
+        if node.completes_positional(&Completion::Environments) {
+            opts.push(' ');
+            opts.push_str(&envs);
+        }
+        let _ = writeln!(out, "            opts=\"{}\"", opts);
+        let _ = writeln!(out, "            ;;");
+    }
+    let _ = writeln!(out, "    esac");
+    let _ = writeln!(out);
+    let _ = writeln!(out, "    COMPREPLY=($(compgen -W \"${{opts}}\" -- \"${{cur}}\"))");
+    let _ = writeln!(out, "}}");
+    let _ = writeln!(out);
+    let _ = writeln!(out, "complete -F {} -o bashdefault -o default {}", func, bin);
+    out
+}
+
+fn zsh(bin: &str, nodes: &[Node]) -> String {
+    let func = function_name(bin);
+    let envs = format!("${{(f)\"$({} 2>/dev/null)\"}}", environments_command(bin));
+    let mut out = String::new();
+
+    let _ = writeln!(out, "#compdef {}", bin);
+    let _ = writeln!(out);
+    let _ = writeln!(out, "{}() {{", func);
+    // `path` is tied to $PATH in zsh, hence `cmd`
+    let _ = writeln!(out, "    local cmd=\"{}\" prev=\"${{words[CURRENT-1]}}\" i", bin);
+    let _ = writeln!(out);
+    let _ = writeln!(out, "    for ((i = 2; i < CURRENT; i++)); do");
+    let _ = writeln!(out, "        case \"${{cmd}}::${{words[i]}}\" in");
+    for node in nodes {
+        for sub in &node.subcommands {
+            let _ = writeln!(out, "            \"{}::{}\") cmd=\"{}\" ;;", node.id, sub.name, sub.id);
+        }
+    }
+    let _ = writeln!(out, "        esac");
+    let _ = writeln!(out, "    done");
+    let _ = writeln!(out);
+    let _ = writeln!(out, "    case \"${{cmd}}\" in");
+    for node in nodes {
+        let _ = writeln!(out, "        \"{}\")", node.id);
+        if node.value_options().next().is_some() {
+            let _ = writeln!(out, "            case \"${{prev}}\" in");
+            for (opt, completion) in node.value_options() {
+                let reply = match completion {
+                    Completion::Environments => format!("compadd -- {}", envs),
+                    Completion::Values(values) => format!("compadd -- {}", values.join(" ")),
+                    Completion::Files => "_files".to_string(),
+                    Completion::Any => "_message value".to_string(),
+                };
+                let _ = writeln!(out, "                {}) {}; return ;;", opt.spellings().join("|"), reply);
+            }
+            let _ = writeln!(out, "            esac");
+        }
+        let _ = writeln!(out, "            compadd -- {}", node.words().join(" "));
+        if node.completes_positional(&Completion::Environments) {
+            let _ = writeln!(out, "            compadd -- {}", envs);
+        }
+        if node.completes_positional(&Completion::Files) {
+            let _ = writeln!(out, "            _files");
+        }
+        let _ = writeln!(out, "            ;;");
+    }
+    let _ = writeln!(out, "    esac");
+    let _ = writeln!(out, "}}");
+    let _ = writeln!(out);
+    let _ = writeln!(out, "if [ \"$funcstack[1]\" = \"{}\" ]; then", func);
+    let _ = writeln!(out, "    {} \"$@\"", func);
+    let _ = writeln!(out, "else");
+    let _ = writeln!(out, "    compdef {} {}", func, bin);
+    let _ = writeln!(out, "fi");
+    out
+}
+
+fn fish(bin: &str, nodes: &[Node]) -> String {
+    let path_func = format!("_{}_command", function_name(bin));
+    let envs = format!("({} 2>/dev/null)", environments_command(bin));
+    let mut out = String::new();
+
+    let _ = writeln!(out, "function {}", path_func);
+    let _ = writeln!(out, "    set -l cmd {}", bin);
+    let _ = writeln!(out, "    for word in (commandline -opc)[2..-1]");
+    let _ = writeln!(out, "        switch \"$cmd::$word\"");
+    for node in nodes {
+        for sub in &node.subcommands {
+            let _ = writeln!(out, "            case '{}::{}'", node.id, sub.name);
+            let _ = writeln!(out, "                set cmd {}", sub.id);
+        }
+    }
+    let _ = writeln!(out, "        end");
+    let _ = writeln!(out, "    end");
+    let _ = writeln!(out, "    echo $cmd");
+    let _ = writeln!(out, "end");
+    let _ = writeln!(out);
+    let _ = writeln!(out, "complete -c {} -f", bin);
+
+    for node in nodes {
+        let condition = format!("-n 'test ({}) = {}'", path_func, node.id);
+        for sub in &node.subcommands {
+            let _ = writeln!(out, "complete -c {} {} -a {} -d {}", bin, condition, fish_quote(&sub.name), fish_quote(&sub.help));
+        }
+        for opt in &node.options {
+            let mut line = format!("complete -c {} {}", bin, condition);
+            if let Some(long) = &opt.long {
+                let _ = write!(line, " -l {}", long);
+            }
+            if let Some(short) = opt.short {
+                let _ = write!(line, " -s {}", short);
+            }
+            match &opt.value {
+                Some(Completion::Environments) => { let _ = write!(line, " -x -a {}", fish_quote(&envs)); }
+                Some(Completion::Values(values)) => { let _ = write!(line, " -x -a {}", fish_quote(&values.join(" "))); }
+                Some(Completion::Files) => line.push_str(" -r -F"),
+                Some(Completion::Any) => line.push_str(" -x"),
+                None => {}
+            }
+            let _ = writeln!(out, "{} -d {}", line, fish_quote(&opt.help));
+        }
+        for completion in &node.positionals {
+            match completion {
+                Completion::Environments => { let _ = writeln!(out, "complete -c {} {} -a {}", bin, condition, fish_quote(&envs)); }
+                Completion::Values(values) => { let _ = writeln!(out, "complete -c {} {} -a {}", bin, condition, fish_quote(&values.join(" "))); }
+                Completion::Files => { let _ = writeln!(out, "complete -c {} {} -F", bin, condition); }
+                Completion::Any => {}
+            }
+        }
+    }
+    out
+}
+
+fn fish_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\\', "\\\\").replace('\'', "\\'"))
+}
+
+fn powershell(bin: &str, nodes: &[Node]) -> String {
+    let envs = format!("@({} 2>$null)", environments_command(bin));
+    let mut out = String::new();
+
+    let _ = writeln!(out, "Register-ArgumentCompleter -Native -CommandName '{}' -ScriptBlock {{", bin);
+    let _ = writeln!(out, "    param($wordToComplete, $commandAst, $cursorPosition)");
+    let _ = writeln!(out);
+    let _ = writeln!(out, "    $cmd = '{}'", bin);
+    let _ = writeln!(out, "    $prev = ''");
+    let _ = writeln!(out, "    foreach ($element in $commandAst.CommandElements | Select-Object -Skip 1) {{");
+    // The word under the cursor is the one being completed
+    let _ = writeln!(out, "        if ($element.Extent.EndOffset -ge $cursorPosition) {{ break }}");
+    let _ = writeln!(out, "        $word = $element.ToString()");
+    let _ = writeln!(out, "        switch -exact (\"$cmd::$word\") {{");
+    for node in nodes {
+        for sub in &node.subcommands {
+            let _ = writeln!(out, "            '{}::{}' {{ $cmd = '{}' }}", node.id, sub.name, sub.id);
+        }
+    }
+    let _ = writeln!(out, "        }}");
+    let _ = writeln!(out, "        $prev = $word");
+    let _ = writeln!(out, "    }}");
+    let _ = writeln!(out);
+    let _ = writeln!(out, "    $candidates = @()");
+    let _ = writeln!(out, "    switch -exact ($cmd) {{");
+    for node in nodes {
+        let _ = writeln!(out, "        '{}' {{", node.id);
+        let mut branch = "if";
+        for (opt, completion) in node.value_options() {
+            let spellings: Vec<String> = opt.spellings().iter().map(|s| ps_quote(s)).collect();
+            let body = match completion {
+                Completion::Environments => format!("$candidates = {}", envs),
+                Completion::Values(values) => format!("$candidates = @({})", values.iter().map(|v| ps_quote(v)).collect::<Vec<_>>().join(", ")),
+                // No candidates falls back to path completion
+                Completion::Files | Completion::Any => "return".to_string(),
+            };
+            let _ = writeln!(out, "            {} (@({}) -contains $prev) {{ {} }}", branch, spellings.join(", "), body);
+            branch = "elseif";
+        }
+        let words: Vec<String> = node.words().iter().map(|w| ps_quote(w)).collect();
+        let mut body = format!("$candidates = @({})", words.join(", "));
+        if node.completes_positional(&Completion::Environments) {
+            let _ = write!(body, " + {}", envs);
+        }
+        if branch == "if" {
+            let _ = writeln!(out, "            {}", body);
+        } else {
+            let _ = writeln!(out, "            else {{ {} }}", body);
+        }
+        let _ = writeln!(out, "        }}");
+    }
+    let _ = writeln!(out, "    }}");
+    let _ = writeln!(out);
+    let _ = writeln!(out, "    $candidates | Where-Object {{ $_ -like \"$wordToComplete*\" }} | ForEach-Object {{");
+    let _ = writeln!(out, "        [System.Management.Automation.CompletionResult]::new($_, $_, 'ParameterValue', $_)");
+    let _ = writeln!(out, "    }}");
+    let _ = writeln!(out, "}}");
+    out
+}
+
+fn ps_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "''"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scripts_cover_subcommands_options_and_environments() {
+        let bash = generate(Shell::Bash);
+        assert!(bash.contains("\"dynamics-cli::auth\") cmd=\"dynamics-cli__auth\" ;;"));
+        assert!(bash.contains("\"dynamics-cli__auth::env\") cmd=\"dynamics-cli__auth__env\" ;;"));
+        assert!(bash.contains("--env) COMPREPLY=($(compgen -W \"$(dynamics-cli completions --environments 2>/dev/null)\""));
+        assert!(bash.contains("--method) COMPREPLY=($(compgen -W \"get post patch delete\""));
+        assert!(bash.contains("complete -F _dynamics_cli -o bashdefault -o default dynamics-cli"));
+        // Hidden helper flag isn't offered
+        assert!(!bash.contains("opts=\"--environments"));
+
+        let fish = generate(Shell::Fish);
+        assert!(fish.contains("complete -c dynamics-cli -n 'test (__dynamics_cli_command) = dynamics-cli' -a 'completions'"));
+        assert!(fish.contains("-a 'bash zsh fish powershell'"));
+
+        let zsh = generate(Shell::Zsh);
+        assert!(zsh.starts_with("#compdef dynamics-cli\n"));
+
+        let powershell = generate(Shell::Powershell);
+        assert!(powershell.contains("'dynamics-cli__diff' {"));
+        assert!(powershell.contains("if (@('--source') -contains $prev) { $candidates = @(dynamics-cli completions --environments 2>$null) }"));
+    }
+}
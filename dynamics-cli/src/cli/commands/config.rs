@@ -0,0 +1,215 @@
+//! Config database maintenance commands
+//!
+//! Migration commands run before the config is loaded (which applies pending
+//! migrations), so they show the database as it was found and still work when
+//! validation fails.
+
+use anyhow::{Context, Result};
+use std::path::PathBuf;
+use clap::{Args, Subcommand};
+use colored::*;
+use sqlx::SqlitePool;
+
+use crate::config::Config;
+use crate::config::db;
+use crate::config::migrations::{MigrationManager, find_migration_problems};
+
+#[derive(Args)]
+pub struct ConfigCommands {
+    #[command(subcommand)]
+    pub command: ConfigSubcommands,
+}
+
+#[derive(Subcommand)]
+pub enum ConfigSubcommands {
+    /// Config database schema migrations
+    #[command(subcommand)]
+    Migrations(MigrationsCommands),
+    /// Back up or restore settings (themes, keybinds, API options)
+    #[command(subcommand)]
+    Options(OptionsCommands),
+}
+
+impl ConfigCommands {
+    /// Whether the command has to run before `Config::load` migrates the database
+    pub fn runs_before_load(&self) -> bool {
+        matches!(self.command, ConfigSubcommands::Migrations(_))
+    }
+}
+
+#[derive(Subcommand)]
+pub enum OptionsCommands {
+    /// Write every option's current value to a JSON file
+    Export {
+        /// File to write
+        path: PathBuf,
+    },
+    /// Set options from a JSON file written by export
+    Import {
+        /// File to read
+        path: PathBuf,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum MigrationsCommands {
+    /// Show applied and pending migrations
+    Status,
+    /// Check applied migrations against the ones built into this version
+    Validate,
+    /// Roll back migrations, e.g. before downgrading to an older release
+    Down {
+        /// Version to roll back to (at least 1)
+        #[arg(long)]
+        to: i64,
+        /// Skip confirmation prompt
+        #[arg(short = 'y', long)]
+        yes: bool,
+    },
+}
+
+/// Handle config command
+pub async fn handle_config_command(cmd: ConfigCommands) -> Result<()> {
+    match cmd.command {
+        ConfigSubcommands::Migrations(cmd) => {
+            let db_path = Config::get_db_path()?;
+            let pool = db::connect(&db_path).await?;
+            println!("{} {}", "Database:".dimmed(), db_path.display().to_string().cyan());
+
+            match cmd {
+                MigrationsCommands::Status => migration_status(&pool).await,
+                MigrationsCommands::Validate => validate_migrations(&pool).await,
+                MigrationsCommands::Down { to, yes } => migrate_down(&pool, to, yes).await,
+            }
+        }
+        ConfigSubcommands::Options(OptionsCommands::Export { path }) => export_options(&path).await,
+        ConfigSubcommands::Options(OptionsCommands::Import { path }) => import_options(&path).await,
+    }
+}
+
+async fn export_options(path: &PathBuf) -> Result<()> {
+    let exported = crate::global_config().options.export_all().await?;
+    let count = exported.as_object().map_or(0, |options| options.len());
+
+    std::fs::write(path, serde_json::to_string_pretty(&exported)? + "\n")
+        .with_context(|| format!("Failed to write {}", path.display()))?;
+
+    println!("{} Exported {} options to {}", "✓".bright_green().bold(), count, path.display().to_string().cyan());
+    Ok(())
+}
+
+async fn import_options(path: &PathBuf) -> Result<()> {
+    let contents = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+    let exported: serde_json::Value = serde_json::from_str(&contents)
+        .with_context(|| format!("{} is not valid JSON", path.display()))?;
+
+    let skipped = crate::global_config().options.import_all(&exported).await?;
+    for key in &skipped {
+        println!("{} Skipped unknown option '{}'", "⚠️".bright_yellow(), key);
+    }
+
+    let total = exported.as_object().map_or(0, |options| options.len());
+    println!("{} Imported {} options from {}", "✓".bright_green().bold(), total - skipped.len(), path.display().to_string().cyan());
+    Ok(())
+}
+
+async fn migration_status(pool: &SqlitePool) -> Result<()> {
+    let status = MigrationManager::new(pool).status().await?;
+
+    let current = status.current_version.map_or("none".to_string(), |v| format!("{:03}", v));
+    println!("{} {}", "Schema version:".dimmed(), current.bright_white().bold());
+    println!();
+
+    println!("{}", "Applied".bright_green().bold());
+    if status.applied_migrations.is_empty() {
+        println!("  {}", "(none)".dimmed());
+    } else {
+        let name_width = status.applied_migrations.iter().map(|m| m.name.len()).max().unwrap_or(0).max(4);
+        println!("  {:<7}  {:<name_width$}  {}", "VERSION".dimmed(), "NAME".dimmed(), "APPLIED AT".dimmed());
+        for migration in &status.applied_migrations {
+            println!(
+                "  {:<7}  {:<name_width$}  {}",
+                format!("{:03}", migration.version),
+                migration.name,
+                migration.applied_at.format("%Y-%m-%d %H:%M:%S UTC")
+            );
+        }
+    }
+
+    println!();
+    println!("{}", "Pending".bright_yellow().bold());
+    if status.pending_migrations.is_empty() {
+        println!("  {}", "(none)".dimmed());
+    } else {
+        for migration in &status.pending_migrations {
+            println!("  {:<7}  {}", format!("{:03}", migration.version), migration.name);
+        }
+        println!();
+        println!("  {}", "Pending migrations are applied the next time any other command runs.".dimmed());
+    }
+
+    Ok(())
+}
+
+async fn migrate_down(pool: &SqlitePool, target_version: i64, yes: bool) -> Result<()> {
+    if target_version < 1 {
+        anyhow::bail!("Cannot roll back below version 1 (the initial schema)");
+    }
+
+    let manager = MigrationManager::new(pool);
+    manager.validate().await?;
+
+    let plan = manager.rollback_plan(target_version).await?;
+    if plan.is_empty() {
+        println!("{} Already at or below version {}", "✓".bright_green().bold(), target_version);
+        return Ok(());
+    }
+
+    println!("{}", "Migrations to roll back".bright_yellow().bold());
+    for migration in &plan {
+        println!("  {:<7}  {}", format!("{:03}", migration.version), migration.name);
+    }
+    println!();
+    println!("  {}", "Data in tables or columns these migrations added will be lost.".yellow());
+    println!();
+
+    if !yes {
+        let confirmed = dialoguer::Confirm::new()
+            .with_prompt(format!("Roll back {} migration(s) to version {}?", plan.len(), target_version))
+            .default(false)
+            .interact()?;
+        if !confirmed {
+            println!("Cancelled");
+            return Ok(());
+        }
+    }
+
+    let rolled_back = manager.migrate_down_to(target_version).await?;
+    println!(
+        "{} Rolled back {} migration(s); schema is now at version {:03}",
+        "✓".bright_green().bold(),
+        rolled_back.len(),
+        target_version
+    );
+    println!(
+        "  {}",
+        "Any other command from this release re-applies them, so switch to the older release now.".dimmed()
+    );
+    Ok(())
+}
+
+async fn validate_migrations(pool: &SqlitePool) -> Result<()> {
+    MigrationManager::new(pool).init().await?;
+    let problems = find_migration_problems(pool).await?;
+
+    if problems.is_empty() {
+        println!("{} All applied migrations match this version", "✓".bright_green().bold());
+        return Ok(());
+    }
+
+    for problem in &problems {
+        println!("{} {}", "✗".bright_red().bold(), problem);
+    }
+    anyhow::bail!("{} migration problem(s) found", problems.len())
+}
@@ -0,0 +1,233 @@
+//! Keyed comparison of two query result sets
+
+use anyhow::Result;
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+/// A single field whose value differs between source and target
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FieldChange {
+    /// Field name on the source side
+    pub field: String,
+    /// Field name on the target side (differs when a field mapping applies)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_field: Option<String>,
+    pub source: Value,
+    pub target: Value,
+}
+
+/// A record present on both sides with at least one differing field
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ChangedRecord {
+    pub key: String,
+    pub changes: Vec<FieldChange>,
+}
+
+/// Result of diffing two result sets
+#[derive(Debug, Default, Serialize)]
+pub struct DiffReport {
+    /// Keys only present in the target
+    pub added: Vec<String>,
+    /// Keys only present in the source
+    pub removed: Vec<String>,
+    /// Records present on both sides with differing fields
+    pub changed: Vec<ChangedRecord>,
+    /// Number of records identical on both sides
+    pub unchanged: usize,
+}
+
+impl DiffReport {
+    pub fn is_identical(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// Compare two result sets, matching records by key
+///
+/// `field_map` translates source field names to target field names; unmapped
+/// fields are compared under the same name. Annotation fields (containing `@`)
+/// are ignored.
+pub fn diff_records(
+    source: &[Value],
+    target: &[Value],
+    source_key: &str,
+    target_key: &str,
+    field_map: &HashMap<String, String>,
+) -> Result<DiffReport> {
+    let source_by_key = index_by_key(source, source_key, "source")?;
+    let target_by_key = index_by_key(target, target_key, "target")?;
+
+    let mut report = DiffReport::default();
+
+    for (key, source_record) in &source_by_key {
+        let Some(target_record) = target_by_key.get(key) else {
+            report.removed.push(key.clone());
+            continue;
+        };
+
+        let changes = diff_fields(source_record, target_record, source_key, target_key, field_map);
+        if changes.is_empty() {
+            report.unchanged += 1;
+        } else {
+            report.changed.push(ChangedRecord {
+                key: key.clone(),
+                changes,
+            });
+        }
+    }
+
+    report.added = target_by_key
+        .keys()
+        .filter(|key| !source_by_key.contains_key(*key))
+        .cloned()
+        .collect();
+
+    Ok(report)
+}
+
+/// Index records by their key value (case-insensitive, so GUIDs match across environments)
+fn index_by_key<'a>(
+    records: &'a [Value],
+    key: &str,
+    side: &str,
+) -> Result<BTreeMap<String, &'a Value>> {
+    let mut indexed = BTreeMap::new();
+    for record in records {
+        let key_value = match record.get(key) {
+            Some(Value::String(s)) => s.to_lowercase(),
+            Some(Value::Null) | None => {
+                anyhow::bail!("A {} record has no value for key field '{}'", side, key)
+            }
+            Some(other) => other.to_string(),
+        };
+        if indexed.insert(key_value.clone(), record).is_some() {
+            log::warn!("Duplicate {} key '{}' for field '{}'; keeping the last record", side, key_value, key);
+        }
+    }
+    Ok(indexed)
+}
+
+/// Compare the fields of two matched records
+fn diff_fields(
+    source: &Value,
+    target: &Value,
+    source_key: &str,
+    target_key: &str,
+    field_map: &HashMap<String, String>,
+) -> Vec<FieldChange> {
+    let empty = serde_json::Map::new();
+    let source_obj = source.as_object().unwrap_or(&empty);
+    let target_obj = target.as_object().unwrap_or(&empty);
+
+    let is_data_field = |name: &str| !name.contains('@');
+
+    // Pairs of (source field, target field) to compare
+    let mut pairs: BTreeSet<(String, String)> = source_obj
+        .keys()
+        .filter(|name| is_data_field(name) && name.as_str() != source_key)
+        .map(|name| {
+            let target_name = field_map.get(name).cloned().unwrap_or_else(|| name.clone());
+            (name.clone(), target_name)
+        })
+        .collect();
+
+    let mapped_targets: BTreeSet<String> = pairs.iter().map(|(_, t)| t.clone()).collect();
+    let reverse_map: HashMap<&String, &String> = field_map.iter().map(|(s, t)| (t, s)).collect();
+    for name in target_obj.keys() {
+        if is_data_field(name) && name.as_str() != target_key && !mapped_targets.contains(name) {
+            let source_name = reverse_map.get(name).map(|s| (*s).clone()).unwrap_or_else(|| name.clone());
+            pairs.insert((source_name, name.clone()));
+        }
+    }
+
+    pairs
+        .into_iter()
+        .filter_map(|(source_field, target_field)| {
+            let source_value = source_obj.get(&source_field).cloned().unwrap_or(Value::Null);
+            let target_value = target_obj.get(&target_field).cloned().unwrap_or(Value::Null);
+            if source_value == target_value {
+                return None;
+            }
+            Some(FieldChange {
+                target_field: (target_field != source_field).then_some(target_field),
+                field: source_field,
+                source: source_value,
+                target: target_value,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_keyed_diff_added_removed_changed() {
+        let source = vec![
+            json!({"accountid": "A", "name": "Contoso", "revenue": 100}),
+            json!({"accountid": "b", "name": "Fabrikam", "revenue": 200}),
+            json!({"accountid": "c", "name": "Northwind", "revenue": 300}),
+        ];
+        let target = vec![
+            json!({"accountid": "a", "name": "Contoso", "revenue": 100}),
+            json!({"accountid": "b", "name": "Fabrikam Ltd", "revenue": 200, "@odata.etag": "W/\"1\""}),
+            json!({"accountid": "d", "name": "Tailspin", "revenue": 400}),
+        ];
+
+        let report = diff_records(&source, &target, "accountid", "accountid", &HashMap::new()).unwrap();
+
+        assert_eq!(report.added, vec!["d".to_string()]);
+        assert_eq!(report.removed, vec!["c".to_string()]);
+        assert_eq!(report.unchanged, 1);
+        assert_eq!(report.changed.len(), 1);
+        assert_eq!(report.changed[0].key, "b");
+        assert_eq!(
+            report.changed[0].changes,
+            vec![FieldChange {
+                field: "name".to_string(),
+                target_field: None,
+                source: json!("Fabrikam"),
+                target: json!("Fabrikam Ltd"),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_diff_with_field_mapping() {
+        let source = vec![json!({"code": "X1", "cgk_name": "Deadline", "cgk_date": "2024-01-01"})];
+        let target = vec![json!({"code": "X1", "nrq_name": "Deadline", "nrq_date": "2024-02-01"})];
+        let field_map = HashMap::from([
+            ("cgk_name".to_string(), "nrq_name".to_string()),
+            ("cgk_date".to_string(), "nrq_date".to_string()),
+        ]);
+
+        let report = diff_records(&source, &target, "code", "code", &field_map).unwrap();
+
+        assert_eq!(report.changed.len(), 1);
+        let changes = &report.changed[0].changes;
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].field, "cgk_date");
+        assert_eq!(changes[0].target_field.as_deref(), Some("nrq_date"));
+    }
+
+    #[test]
+    fn test_diff_field_missing_on_one_side() {
+        let source = vec![json!({"id": 1, "name": "A"})];
+        let target = vec![json!({"id": 1, "name": "A", "extra": "new"})];
+
+        let report = diff_records(&source, &target, "id", "id", &HashMap::new()).unwrap();
+
+        assert_eq!(report.changed[0].changes[0].field, "extra");
+        assert_eq!(report.changed[0].changes[0].source, Value::Null);
+    }
+
+    #[test]
+    fn test_diff_missing_key_errors() {
+        let source = vec![json!({"name": "no key"})];
+        let err = diff_records(&source, &[], "accountid", "accountid", &HashMap::new()).unwrap_err();
+        assert!(err.to_string().contains("key field 'accountid'"));
+    }
+}
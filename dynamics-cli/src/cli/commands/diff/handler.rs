@@ -0,0 +1,218 @@
+//! Cross-environment diff command handler
+
+use anyhow::{Context, Result};
+use colored::*;
+use serde_json::Value;
+use std::collections::HashMap;
+
+use crate::api::DynamicsClient;
+use crate::cli::commands::query::aliases::ColumnAliases;
+use crate::cli::commands::query::handler::fetch_pages;
+use crate::fql::{parse, to_fetchxml, tokenize};
+use super::compare::{diff_records, DiffReport};
+use super::{DiffCommands, OutputFormat};
+
+/// A query compiled to FetchXML, ready to execute
+struct CompiledQuery {
+    entity: String,
+    fetchxml: String,
+}
+
+/// Handle the diff command
+pub async fn handle_diff_command(args: DiffCommands) -> Result<()> {
+    let client_manager = crate::client_manager();
+    let config = crate::global_config();
+
+    // Handle --no-color flag
+    if args.no_color {
+        colored::control::set_override(false);
+    }
+
+    let migration = match args.migration {
+        Some(ref name) => Some(
+            config
+                .get_migration(name)
+                .await?
+                .ok_or_else(|| anyhow::anyhow!("Migration '{}' not found", name))?,
+        ),
+        None => None,
+    };
+
+    // Determine environments: explicit flags win over the migration's
+    let source_env = match args.source.clone().or_else(|| migration.as_ref().map(|m| m.source_env.clone())) {
+        Some(env) => env,
+        None => client_manager.get_current_environment().await.ok_or_else(|| {
+            anyhow::anyhow!(
+                "No source environment. Use 'dynamics-cli auth env select' to choose one or specify --source."
+            )
+        })?,
+    };
+    let target_env = args
+        .target
+        .clone()
+        .or_else(|| migration.as_ref().map(|m| m.target_env.clone()))
+        .ok_or_else(|| anyhow::anyhow!("No target environment. Specify --target or --migration."))?;
+
+    let source_query = compile_query(&args.query).context("Invalid source query")?;
+    let target_query = match args.target_query {
+        Some(ref query) => compile_query(query).context("Invalid target query")?,
+        None => compile_query(&args.query)?,
+    };
+
+    // Field mapping from the migration's comparison for this entity pair
+    let field_map = match migration {
+        Some(ref migration) => {
+            load_field_map(&migration.name, &source_query.entity, &target_query.entity).await?
+        }
+        None => HashMap::new(),
+    };
+
+    let source_key = args
+        .key
+        .clone()
+        .unwrap_or_else(|| format!("{}id", source_query.entity));
+    let target_key = field_map.get(&source_key).cloned().unwrap_or_else(|| {
+        if args.key.is_some() {
+            source_key.clone()
+        } else {
+            format!("{}id", target_query.entity)
+        }
+    });
+
+    let source_client = client_manager.get_client(&source_env).await?;
+    let target_client = client_manager.get_client(&target_env).await?;
+
+    // Every page on both sides, so records past the first page don't show up as added/removed
+    let (source_records, target_records) = tokio::try_join!(
+        fetch_all_records(&source_client, &source_query),
+        fetch_all_records(&target_client, &target_query),
+    )
+    .context("Failed to execute query")?;
+
+    let report = diff_records(&source_records, &target_records, &source_key, &target_key, &field_map)?;
+
+    match args.format {
+        OutputFormat::Json => {
+            let output = serde_json::json!({
+                "source": { "environment": source_env, "entity": source_query.entity, "count": source_records.len() },
+                "target": { "environment": target_env, "entity": target_query.entity, "count": target_records.len() },
+                "key": source_key,
+                "diff": report,
+            });
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&output).context("Failed to format JSON output")?
+            );
+        }
+        OutputFormat::Text => print_report(&report, &source_env, &target_env, &source_key),
+    }
+
+    Ok(())
+}
+
+fn compile_query(query: &str) -> Result<CompiledQuery> {
+    let tokens = tokenize(query).context("Failed to tokenize FQL query")?;
+    let ast = parse(tokens, query).context("Failed to parse FQL query")?;
+    if !ast.unions.is_empty() {
+        anyhow::bail!("Union queries are not supported by diff; compare one entity at a time");
+    }
+    let entity = ast.entity.name.clone();
+    let fetchxml = to_fetchxml(ast)
+        .context("Failed to generate FetchXML from query")?
+        .remove(0);
+    Ok(CompiledQuery { entity, fetchxml })
+}
+
+/// Resolve source→target field names from a migration's saved mappings
+async fn load_field_map(
+    migration_name: &str,
+    source_entity: &str,
+    target_entity: &str,
+) -> Result<HashMap<String, String>> {
+    let config = crate::global_config();
+
+    let comparisons = config.get_comparisons(migration_name).await?;
+    if !comparisons
+        .iter()
+        .any(|c| c.source_entity == source_entity && c.target_entity == target_entity)
+    {
+        log::warn!(
+            "Migration '{}' has no comparison for {} -> {}; comparing fields by name",
+            migration_name,
+            source_entity,
+            target_entity
+        );
+        return Ok(HashMap::new());
+    }
+
+    let mappings = config.get_field_mappings(source_entity, target_entity).await?;
+    Ok(mappings
+        .into_iter()
+        .filter_map(|(source, targets)| targets.into_iter().next().map(|target| (source, target)))
+        .collect())
+}
+
+async fn fetch_all_records(client: &DynamicsClient, query: &CompiledQuery) -> Result<Vec<Value>> {
+    let mut records = Vec::new();
+    let mut collect = |page: &mut Vec<Value>| {
+        records.append(page);
+        Ok(())
+    };
+    fetch_pages(client, &query.entity, &query.fetchxml, &ColumnAliases::default(), None, None, &mut collect).await?;
+    Ok(records)
+}
+
+fn format_value(value: &Value) -> String {
+    match value {
+        Value::Null => "null".dimmed().to_string(),
+        Value::String(s) => format!("\"{}\"", s),
+        other => other.to_string(),
+    }
+}
+
+/// Print a diff report as a colored summary, one line per record and changed field
+pub fn print_report(report: &DiffReport, source_env: &str, target_env: &str, key: &str) {
+    println!(
+        "{} {} {} {} (key: {})",
+        "Diff".bold(),
+        source_env.bright_cyan(),
+        "→".dimmed(),
+        target_env.bright_cyan(),
+        key
+    );
+    println!();
+
+    for key in &report.removed {
+        println!("  {} {}", "-".bright_red().bold(), key.bright_red());
+    }
+    for key in &report.added {
+        println!("  {} {}", "+".bright_green().bold(), key.bright_green());
+    }
+    for record in &report.changed {
+        println!("  {} {}", "~".bright_yellow().bold(), record.key.bright_yellow());
+        for change in &record.changes {
+            let field = match change.target_field {
+                Some(ref target_field) => format!("{} ({})", change.field, target_field),
+                None => change.field.clone(),
+            };
+            println!(
+                "      {}: {} {} {}",
+                field.bold(),
+                format_value(&change.source),
+                "→".dimmed(),
+                format_value(&change.target)
+            );
+        }
+    }
+
+    if !report.is_identical() {
+        println!();
+    }
+    println!(
+        "{} added, {} removed, {} changed, {} unchanged",
+        report.added.len().to_string().bright_green(),
+        report.removed.len().to_string().bright_red(),
+        report.changed.len().to_string().bright_yellow(),
+        report.unchanged
+    );
+}
@@ -0,0 +1,49 @@
+pub mod compare;
+pub mod handler;
+
+use clap::{Args, ValueEnum};
+
+pub use handler::handle_diff_command;
+
+#[derive(Args)]
+pub struct DiffCommands {
+    /// FQL query to run against both environments (e.g., '.account | .name, .revenue')
+    #[arg(help = "FQL query string")]
+    pub query: String,
+
+    /// Separate FQL query for the target side (defaults to the source query)
+    #[arg(long, help = "FQL query to run against the target environment")]
+    pub target_query: Option<String>,
+
+    /// Source environment (defaults to the migration's source or the current environment)
+    #[arg(long, value_name = "ENV", help = "Source environment name")]
+    pub source: Option<String>,
+
+    /// Target environment (defaults to the migration's target)
+    #[arg(long, value_name = "ENV", help = "Target environment name")]
+    pub target: Option<String>,
+
+    /// Saved migration whose environments and field mappings to use
+    #[arg(long, help = "Saved migration providing environments and field mappings")]
+    pub migration: Option<String>,
+
+    /// Field used to match records between sides (defaults to the primary id, e.g. accountid)
+    #[arg(long, help = "Key field used to match records")]
+    pub key: Option<String>,
+
+    /// Output format
+    #[arg(long, default_value = "text", help = "Output format")]
+    pub format: OutputFormat,
+
+    /// Disable colored output
+    #[arg(long, help = "Disable colored output")]
+    pub no_color: bool,
+}
+
+#[derive(Debug, Clone, ValueEnum)]
+pub enum OutputFormat {
+    /// Human-readable summary with per-field differences (default)
+    Text,
+    /// Structured JSON report
+    Json,
+}
@@ -1,4 +1,12 @@
-use clap::{Args, Subcommand};
+use anyhow::{Context, Result};
+use clap::{Args, Subcommand, ValueEnum};
+use colored::*;
+use serde_json::{json, Value};
+
+use crate::api::metadata::{extract_relationships, process_lookup_fields, FieldMetadata, FieldType, RelationshipMetadata};
+use crate::api::{DynamicsClient, EntityOptionSets};
+use crate::cli::commands::query::labels::load_option_sets;
+use crate::config::repository::metadata_cache::MetadataKind;
 
 #[derive(Args)]
 pub struct EntityCommands {
@@ -32,4 +40,294 @@ pub enum EntitySubcommands {
         /// New plural name
         plural_name: String,
     },
+    /// Show an entity's fields, relationships and option sets
+    Describe(DescribeArgs),
+}
+
+#[derive(Args)]
+pub struct DescribeArgs {
+    /// Entity logical name (e.g., "account")
+    pub logical_name: String,
+
+    /// Only show names containing this text (case-insensitive)
+    pub filter: Option<String>,
+
+    /// Output format
+    #[arg(long, default_value = "table", help = "Output format")]
+    pub format: DescribeFormat,
+
+    /// Show only fields, without relationships and option sets
+    #[arg(long)]
+    pub fields_only: bool,
+
+    /// Environment name (overrides current environment)
+    #[arg(long, value_name = "ENV", help = "Environment name to use")]
+    pub env: Option<String>,
+}
+
+#[derive(Debug, Clone, ValueEnum)]
+pub enum DescribeFormat {
+    /// Aligned columns per section (default)
+    Table,
+    /// One JSON object with `fields`, `relationships` and `option_sets`
+    Json,
+}
+
+/// Handle entity command
+pub async fn handle_entity_command(cmd: EntityCommands) -> Result<()> {
+    match cmd.command {
+        EntitySubcommands::Describe(args) => describe_entity(args).await,
+        _ => {
+            println!("Entity name mappings are temporarily disabled during the config system rewrite.");
+            println!("Use 'dynamics-cli entity describe <logical_name>' to look up entity metadata.");
+            Ok(())
+        }
+    }
+}
+
+/// Metadata of one entity, as shown by `entity describe`
+struct Description {
+    entity: String,
+    fields: Vec<FieldMetadata>,
+    relationships: Vec<RelationshipMetadata>,
+    /// `None` with `--fields-only`, which leaves out relationships as well
+    option_sets: Option<EntityOptionSets>,
+}
+
+async fn describe_entity(args: DescribeArgs) -> Result<()> {
+    let client_manager = crate::client_manager();
+    let env_name = if let Some(ref env) = args.env {
+        env.clone()
+    } else {
+        client_manager
+            .get_current_environment()
+            .await
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "No environment selected. Use 'dynamics-cli auth env select' to choose one or specify --env."
+                )
+            })?
+    };
+    let client = client_manager.get_client(&env_name).await?;
+
+    let entity = args.logical_name.to_lowercase();
+    let fields = load_fields(&env_name, &client, &entity).await
+        .with_context(|| format!("Failed to load metadata for '{}'", entity))?;
+    let option_sets = if args.fields_only {
+        None
+    } else {
+        Some(load_option_sets(&env_name, &client, &entity).await
+            .with_context(|| format!("Failed to load option sets for '{}'", entity))?)
+    };
+
+    let mut description = Description::new(entity, fields, option_sets, args.fields_only);
+    if let Some(filter) = &args.filter {
+        description.retain_matching(filter);
+    }
+
+    match args.format {
+        DescribeFormat::Table => description.print_table(),
+        DescribeFormat::Json => println!("{}", serde_json::to_string_pretty(&description.to_json())?),
+    }
+    Ok(())
+}
+
+/// Fields of `entity_name` (attributes and navigation properties), from the
+/// metadata cache or fetched and cached
+///
+/// Shares the cache with the migration comparison and `query --validate`, and
+/// follows `api.cache.metadata_ttl_hours` like them; 0 turns it off.
+async fn load_fields(environment_name: &str, client: &DynamicsClient, entity_name: &str) -> Result<Vec<FieldMetadata>> {
+    let config = crate::global_config();
+    let ttl_hours = config.options.get_uint("api.cache.metadata_ttl_hours").await.unwrap_or(12);
+
+    if ttl_hours > 0 {
+        let ttl = std::time::Duration::from_secs(ttl_hours * 60 * 60);
+        match config.get_metadata_cache(environment_name, entity_name, MetadataKind::Fields, ttl).await {
+            Ok(Some(fields)) => return Ok(fields),
+            Ok(None) => {}
+            Err(e) => log::warn!("Ignoring unreadable field cache for {}/{}: {}", environment_name, entity_name, e),
+        }
+    }
+
+    let fields = process_lookup_fields(client.fetch_entity_fields_combined(entity_name).await?);
+
+    if ttl_hours > 0 {
+        if let Err(e) = config.set_metadata_cache(environment_name, entity_name, MetadataKind::Fields, &fields).await {
+            log::error!("Failed to cache fields for {}/{}: {}", environment_name, entity_name, e);
+        }
+    }
+    Ok(fields)
+}
+
+impl Description {
+    /// Split navigation properties out of `fields` into relationships
+    fn new(entity: String, fields: Vec<FieldMetadata>, option_sets: Option<EntityOptionSets>, fields_only: bool) -> Self {
+        let relationships = if fields_only { Vec::new() } else { extract_relationships(&fields) };
+        let fields = fields
+            .into_iter()
+            .filter(|field| !matches!(&field.field_type, FieldType::Other(t) if t.starts_with("Relationship:")))
+            .collect();
+        Self { entity, fields, relationships, option_sets }
+    }
+
+    /// Keep fields, relationships and option sets whose names contain `filter`
+    fn retain_matching(&mut self, filter: &str) {
+        let filter = filter.to_lowercase();
+        let matches = |text: &str| text.to_lowercase().contains(&filter);
+
+        self.fields.retain(|field| {
+            matches(&field.logical_name) || field.display_name.as_deref().is_some_and(matches)
+        });
+        self.relationships.retain(|rel| matches(&rel.name) || matches(&rel.related_entity));
+        if let Some(option_sets) = &mut self.option_sets {
+            option_sets.attributes.retain(|attribute, _| matches(attribute));
+        }
+    }
+
+    fn to_json(&self) -> Value {
+        let fields: Vec<Value> = self.fields.iter().map(|field| json!({
+            "logical_name": field.logical_name,
+            "display_name": field.display_name,
+            "type": type_name(&field.field_type),
+            "required": field.is_required,
+            "primary_key": field.is_primary_key,
+            "max_length": field.max_length,
+            "related_entity": field.related_entity,
+        })).collect();
+
+        let mut description = json!({
+            "entity": self.entity,
+            "fields": fields,
+        });
+        if self.option_sets.is_some() {
+            description["relationships"] = self.relationships.iter().map(|rel| json!({
+                "name": rel.name,
+                "type": format!("{:?}", rel.relationship_type),
+                "related_entity": rel.related_entity,
+            })).collect();
+        }
+        if let Some(option_sets) = &self.option_sets {
+            description["option_sets"] = option_sets.attributes.iter()
+                .map(|(attribute, options)| {
+                    let options: serde_json::Map<String, Value> = options.iter()
+                        .map(|(value, label)| (value.to_string(), Value::from(label.as_str())))
+                        .collect();
+                    (attribute.clone(), Value::Object(options))
+                })
+                .collect::<serde_json::Map<_, _>>()
+                .into();
+        }
+        description
+    }
+
+    fn print_table(&self) {
+        println!("{} ({} fields)", self.entity.bright_white().bold(), self.fields.len());
+        let name_width = self.fields.iter().map(|f| f.logical_name.len()).max().unwrap_or(0).max(12);
+        let type_width = self.fields.iter().map(|f| type_name(&f.field_type).len()).max().unwrap_or(0).max(4);
+        println!(
+            "  {:<name_width$}  {:<type_width$}  {:<8}  {:>10}  {}",
+            "LOGICAL NAME".dimmed(), "TYPE".dimmed(), "REQUIRED".dimmed(), "MAX LENGTH".dimmed(), "TARGET / DISPLAY NAME".dimmed()
+        );
+        for field in &self.fields {
+            let name = if field.is_primary_key {
+                field.logical_name.bright_yellow()
+            } else {
+                field.logical_name.normal()
+            };
+            println!(
+                "  {:<name_width$}  {:<type_width$}  {:<8}  {:>10}  {}",
+                name,
+                type_name(&field.field_type).cyan(),
+                if field.is_required { "yes" } else { "" },
+                field.max_length.map(|len| len.to_string()).unwrap_or_default(),
+                field.related_entity.as_ref()
+                    .map(|target| format!("→ {}", target))
+                    .or_else(|| field.display_name.clone())
+                    .unwrap_or_default()
+                    .dimmed()
+            );
+        }
+
+        if self.option_sets.is_none() {
+            return;
+        }
+
+        println!();
+        println!("{} ({})", "Relationships".bright_white().bold(), self.relationships.len());
+        let rel_width = self.relationships.iter().map(|r| r.name.len()).max().unwrap_or(0).max(4);
+        for rel in &self.relationships {
+            println!(
+                "  {:<rel_width$}  {:<10}  {}",
+                rel.name,
+                format!("{:?}", rel.relationship_type).cyan(),
+                rel.related_entity
+            );
+        }
+
+        if let Some(option_sets) = &self.option_sets {
+            println!();
+            println!("{} ({})", "Option sets".bright_white().bold(), option_sets.attributes.len());
+            for (attribute, options) in &option_sets.attributes {
+                println!("  {}", attribute);
+                for (value, label) in options {
+                    println!("    {:>12}  {}", value.to_string().dimmed(), label);
+                }
+            }
+        }
+    }
+}
+
+/// Field type as shown to users: the variant name, or the raw type for `Other`
+fn type_name(field_type: &FieldType) -> String {
+    match field_type {
+        FieldType::Other(name) => name.clone(),
+        other => format!("{:?}", other),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn field(name: &str, field_type: FieldType, related_entity: Option<&str>) -> FieldMetadata {
+        FieldMetadata {
+            logical_name: name.to_string(),
+            display_name: None,
+            field_type,
+            is_required: false,
+            is_primary_key: false,
+            max_length: None,
+            related_entity: related_entity.map(str::to_string),
+            option_set: None,
+        }
+    }
+
+    #[test]
+    fn test_describe_splits_relationships_and_filters() {
+        let fields = vec![
+            field("name", FieldType::String, None),
+            field("primarycontactid", FieldType::Lookup, Some("contact")),
+            field("contact_customer_accounts", FieldType::Other("Relationship:OneToMany".to_string()), Some("contact")),
+            field("industrycode", FieldType::OptionSet, None),
+        ];
+        let mut option_sets = EntityOptionSets::default();
+        option_sets.attributes.insert("industrycode".to_string(), [(1, "Accounting".to_string())].into());
+
+        let mut description = Description::new("account".to_string(), fields, Some(option_sets), false);
+        assert_eq!(description.fields.len(), 3);
+        assert_eq!(description.relationships.len(), 2);
+
+        description.retain_matching("CONTACT");
+        let json = description.to_json();
+        assert_eq!(json["fields"].as_array().unwrap().len(), 1);
+        assert_eq!(json["fields"][0]["type"], "Lookup");
+        assert_eq!(json["relationships"].as_array().unwrap().len(), 2);
+        assert!(json["option_sets"].as_object().unwrap().is_empty());
+
+        let fields_only = Description::new("account".to_string(), vec![field("name", FieldType::String, None)], None, true);
+        let json = fields_only.to_json();
+        assert!(json.get("relationships").is_none());
+        assert!(json.get("option_sets").is_none());
+    }
 }
@@ -0,0 +1,217 @@
+//! Single record retrieval command handler
+
+use anyhow::{Context, Result};
+use colored::*;
+use serde_json::Value;
+
+use super::{GetCommands, OutputFormat};
+
+const FORMATTED_VALUE_SUFFIX: &str = "@OData.Community.Display.V1.FormattedValue";
+const LOOKUP_LOGICAL_NAME_SUFFIX: &str = "@Microsoft.Dynamics.CRM.lookuplogicalname";
+
+/// A single field of a retrieved record, with its formatted value resolved
+#[derive(Debug, PartialEq)]
+struct RecordField {
+    name: String,
+    value: String,
+    formatted: Option<String>,
+    lookup_entity: Option<String>,
+}
+
+/// Handle the get command
+pub async fn handle_get_command(args: GetCommands) -> Result<()> {
+    let client_manager = crate::client_manager();
+
+    // Handle --no-color flag
+    if args.no_color {
+        colored::control::set_override(false);
+    }
+
+    let record_id = parse_record_id(&args.id)?;
+    let select: Vec<String> = args
+        .select
+        .iter()
+        .map(|field| field.trim().to_string())
+        .filter(|field| !field.is_empty())
+        .collect();
+
+    // Determine environment
+    let env_name = if let Some(ref env) = args.env {
+        env.clone()
+    } else {
+        client_manager
+            .get_current_environment()
+            .await
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "No environment selected. Use 'dynamics-cli auth env select' to choose one or specify --env."
+                )
+            })?
+    };
+
+    let client = client_manager.get_client(&env_name).await?;
+
+    let record = client
+        .get_record(&args.entity, &record_id, &select)
+        .await
+        .with_context(|| format!("Failed to retrieve {} {}", args.entity, record_id))?;
+    let record = require_record(record, &args.entity, &record_id)?;
+
+    match args.format {
+        OutputFormat::Json => {
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&record).context("Failed to format JSON output")?
+            );
+        }
+        OutputFormat::Pretty => print_record(&args.entity, &record_id, &record),
+    }
+
+    Ok(())
+}
+
+/// Validate a record ID and normalize it to the hyphenated lowercase form
+fn parse_record_id(id: &str) -> Result<String> {
+    uuid::Uuid::parse_str(id.trim())
+        .map(|uuid| uuid.hyphenated().to_string())
+        .map_err(|_| {
+            anyhow::anyhow!(
+                "Invalid GUID '{}': expected format xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx",
+                id
+            )
+        })
+}
+
+/// Turn a missing record into a not-found error
+fn require_record(record: Option<Value>, entity: &str, id: &str) -> Result<Value> {
+    record.ok_or_else(|| anyhow::anyhow!("No {} record found with ID {}", entity, id))
+}
+
+/// Extract displayable fields from a record, resolving formatted values and lookups
+fn record_fields(record: &Value) -> Vec<RecordField> {
+    let Some(obj) = record.as_object() else {
+        return Vec::new();
+    };
+
+    let mut fields: Vec<RecordField> = obj
+        .iter()
+        .filter(|(key, _)| !key.contains('@'))
+        .map(|(key, value)| {
+            let formatted = obj
+                .get(&format!("{}{}", key, FORMATTED_VALUE_SUFFIX))
+                .and_then(|v| v.as_str())
+                .map(|s| s.to_string());
+            let lookup_entity = obj
+                .get(&format!("{}{}", key, LOOKUP_LOGICAL_NAME_SUFFIX))
+                .and_then(|v| v.as_str())
+                .map(|s| s.to_string());
+
+            // Lookup columns come back as "_field_value"; show them by their attribute name
+            let name = key
+                .strip_prefix('_')
+                .and_then(|k| k.strip_suffix("_value"))
+                .unwrap_or(key)
+                .to_string();
+
+            let value = match value {
+                Value::String(s) => s.clone(),
+                Value::Null => "null".to_string(),
+                other => other.to_string(),
+            };
+
+            RecordField {
+                name,
+                value,
+                formatted,
+                lookup_entity,
+            }
+        })
+        .collect();
+
+    fields.sort_by(|a, b| a.name.cmp(&b.name));
+    fields
+}
+
+/// Pretty-print a record as an aligned field listing
+fn print_record(entity: &str, id: &str, record: &Value) {
+    let fields = record_fields(record);
+    let width = fields.iter().map(|f| f.name.len()).max().unwrap_or(0);
+
+    println!("{} {}", entity.bright_cyan().bold(), id.dimmed());
+    println!();
+
+    for field in &fields {
+        let value = if field.value == "null" {
+            field.value.dimmed().to_string()
+        } else {
+            field.value.clone()
+        };
+
+        let extra = match (&field.formatted, &field.lookup_entity) {
+            (Some(formatted), Some(lookup)) => format!(" → {} ({})", formatted, lookup),
+            (Some(formatted), None) if formatted != &field.value => format!(" ({})", formatted),
+            (None, Some(lookup)) => format!(" ({})", lookup),
+            _ => String::new(),
+        };
+
+        println!(
+            "  {:width$}  {}{}",
+            field.name.bright_white(),
+            value,
+            extra.bright_green(),
+            width = width
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_parse_record_id_normalizes() {
+        assert_eq!(
+            parse_record_id("{6E0C3D9A-1F2B-4C5D-8E9F-0A1B2C3D4E5F}").unwrap(),
+            "6e0c3d9a-1f2b-4c5d-8e9f-0a1b2c3d4e5f"
+        );
+    }
+
+    #[test]
+    fn test_parse_record_id_invalid() {
+        let err = parse_record_id("not-a-guid").unwrap_err();
+        assert!(err.to_string().contains("Invalid GUID 'not-a-guid'"));
+    }
+
+    #[test]
+    fn test_require_record_not_found() {
+        let err = require_record(None, "account", "6e0c3d9a-1f2b-4c5d-8e9f-0a1b2c3d4e5f").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "No account record found with ID 6e0c3d9a-1f2b-4c5d-8e9f-0a1b2c3d4e5f"
+        );
+        assert!(require_record(Some(json!({})), "account", "x").is_ok());
+    }
+
+    #[test]
+    fn test_record_fields_resolve_formatted_values() {
+        let record = json!({
+            "@odata.etag": "W/\"123\"",
+            "name": "Contoso",
+            "statuscode": 1,
+            "statuscode@OData.Community.Display.V1.FormattedValue": "Active",
+            "_primarycontactid_value": "11111111-1111-1111-1111-111111111111",
+            "_primarycontactid_value@OData.Community.Display.V1.FormattedValue": "Jane Doe",
+            "_primarycontactid_value@Microsoft.Dynamics.CRM.lookuplogicalname": "contact"
+        });
+
+        let fields = record_fields(&record);
+        assert_eq!(fields.len(), 3);
+        assert_eq!(fields[0].name, "name");
+        assert_eq!(fields[1].name, "primarycontactid");
+        assert_eq!(fields[1].formatted.as_deref(), Some("Jane Doe"));
+        assert_eq!(fields[1].lookup_entity.as_deref(), Some("contact"));
+        assert_eq!(fields[2].value, "1");
+        assert_eq!(fields[2].formatted.as_deref(), Some("Active"));
+    }
+}
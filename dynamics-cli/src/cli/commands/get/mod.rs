@@ -0,0 +1,40 @@
+pub mod handler;
+
+use clap::{Args, ValueEnum};
+
+pub use handler::handle_get_command;
+
+#[derive(Args)]
+pub struct GetCommands {
+    /// Entity logical name (e.g., "account")
+    #[arg(help = "Entity logical name")]
+    pub entity: String,
+
+    /// Record ID (GUID)
+    #[arg(help = "Record ID (GUID)")]
+    pub id: String,
+
+    /// Comma-separated list of fields to retrieve
+    #[arg(long, value_delimiter = ',', help = "Fields to retrieve (comma-separated)")]
+    pub select: Vec<String>,
+
+    /// Output format
+    #[arg(long, default_value = "pretty", help = "Output format")]
+    pub format: OutputFormat,
+
+    /// Environment name (overrides current environment)
+    #[arg(long, value_name = "ENV", help = "Environment name to use")]
+    pub env: Option<String>,
+
+    /// Disable colored output
+    #[arg(long, help = "Disable colored output")]
+    pub no_color: bool,
+}
+
+#[derive(Debug, Clone, ValueEnum)]
+pub enum OutputFormat {
+    /// Field/value listing with formatted values resolved (default)
+    Pretty,
+    /// Raw JSON record, including annotations (for scripting)
+    Json,
+}
@@ -1,7 +1,12 @@
 pub mod auth;
+pub mod completions;
+pub mod config;
 pub mod deadlines;
+pub mod diff;
 pub mod entity;
+pub mod get;
 pub mod migration;
+pub mod operations;
 pub mod query;
 pub mod raw;
 pub mod settings;
@@ -11,9 +16,27 @@ pub mod update;
 // Re-export new auth command
 pub use auth::{AuthCommands, auth_command};
 
+// Re-export completions command
+pub use completions::{CompletionsCommands, handle_completions_command};
+
+// Re-export config command
+pub use config::{ConfigCommands, handle_config_command};
+
 // Re-export new query command
 pub use query::{QueryCommands, handle_query_command};
 
+// Re-export get command
+pub use get::{GetCommands, handle_get_command};
+
+// Re-export diff command
+pub use diff::{DiffCommands, handle_diff_command};
+
+// Re-export entity command
+pub use entity::{EntityCommands, handle_entity_command};
+
+// Re-export operations command
+pub use operations::{OperationsCommands, handle_operations_command};
+
 // Re-export new raw command
 pub use raw::{RawCommands, handle_raw_command};
 
@@ -0,0 +1,145 @@
+//! Failed queue operations: inspect them and retry them without the TUI
+//!
+//! The operation queue records every operation that fails under the ID of
+//! the queue item's run; `operations retry` re-sends just those.
+
+use anyhow::{Context, Result};
+use clap::{Args, Subcommand};
+use colored::*;
+
+use crate::api::operations::{OperationResult, Operations};
+use crate::api::resilience::ResilienceConfig;
+use crate::config::FailedOperation;
+
+#[derive(Args)]
+pub struct OperationsCommands {
+    #[command(subcommand)]
+    pub command: OperationsSubcommands,
+}
+
+#[derive(Subcommand)]
+pub enum OperationsSubcommands {
+    /// List runs with failed operations, or the failures of one run with their errors
+    Failed {
+        /// Run ID (the queue item ID) to show
+        run_id: Option<String>,
+    },
+    /// Re-send the failed operations of a run; ones that succeed are cleared
+    Retry {
+        /// Run ID (the queue item ID) to retry
+        run_id: String,
+        /// Skip confirmation for writes against protected environments
+        #[arg(short, long)]
+        yes: bool,
+    },
+}
+
+/// Handle operations command
+pub async fn handle_operations_command(cmd: OperationsCommands) -> Result<()> {
+    match cmd.command {
+        OperationsSubcommands::Failed { run_id: None } => list_failed_runs().await,
+        OperationsSubcommands::Failed { run_id: Some(run_id) } => show_failed_run(&run_id).await,
+        OperationsSubcommands::Retry { run_id, yes } => retry_run(&run_id, yes).await,
+    }
+}
+
+async fn list_failed_runs() -> Result<()> {
+    let runs = crate::global_config().list_failed_runs().await?;
+    if runs.is_empty() {
+        println!("{} No failed operations", "✓".bright_green().bold());
+        return Ok(());
+    }
+
+    let id_width = runs.iter().map(|run| run.run_id.len()).max().unwrap_or(0).max(6);
+    println!(
+        "  {:<id_width$}  {:<12}  {:>6}  {:<20}  {}",
+        "RUN ID".dimmed(), "ENVIRONMENT".dimmed(), "FAILED".dimmed(), "LAST FAILURE".dimmed(), "DESCRIPTION".dimmed()
+    );
+    for run in &runs {
+        println!(
+            "  {:<id_width$}  {:<12}  {:>6}  {:<20}  {}",
+            run.run_id,
+            run.environment_name,
+            run.failed_count,
+            run.failed_at.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M:%S"),
+            run.description
+        );
+    }
+    println!();
+    println!("  {}", "Retry a run with: dynamics-cli operations retry <RUN ID>".dimmed());
+    Ok(())
+}
+
+async fn show_failed_run(run_id: &str) -> Result<()> {
+    let failed = crate::global_config().list_failed_operations(run_id).await?;
+    let Some(first) = failed.first() else {
+        println!("{} No failed operations recorded for run '{}'", "✓".bright_green().bold(), run_id);
+        return Ok(());
+    };
+
+    println!("{} {} ({})", "Run".dimmed(), run_id.bright_white().bold(), first.environment_name.bright_green());
+    println!("{} {}", "Description:".dimmed(), first.description);
+    println!();
+    for (idx, operation) in failed.iter().enumerate() {
+        print_failure(idx + 1, operation);
+    }
+    Ok(())
+}
+
+async fn retry_run(run_id: &str, yes: bool) -> Result<()> {
+    let config = crate::global_config();
+    let failed = config.list_failed_operations(run_id).await?;
+    let Some(first) = failed.first() else {
+        anyhow::bail!("No failed operations recorded for run '{}'", run_id);
+    };
+    let environment_name = first.environment_name.clone();
+    let description = first.description.clone();
+
+    crate::cli::ui::confirm_protected_write(&environment_name, &format!("retry {} operation(s)", failed.len()), yes).await?;
+
+    println!(
+        "Retrying {} failed operation(s) of '{}' in {}...",
+        failed.len(),
+        description,
+        environment_name.bright_green().bold()
+    );
+
+    let client = crate::client_manager().get_client(&environment_name).await?;
+    let resilience = ResilienceConfig::load_from_options().await.unwrap_or_default();
+    let operations = Operations::from_operations(failed.into_iter().map(|f| f.operation).collect());
+    let results = operations.execute(&client, &resilience).await
+        .context("Failed to execute operations; the failures are kept for another retry")?;
+
+    config.record_failed_operations(run_id, &environment_name, &description, &results).await?;
+
+    let still_failing: Vec<&OperationResult> = results.iter().filter(|r| !r.success).collect();
+    let succeeded = results.len() - still_failing.len();
+    println!("{} {} succeeded and cleared", "✓".bright_green().bold(), succeeded);
+    if still_failing.is_empty() {
+        return Ok(());
+    }
+
+    println!("{} {} still failing:", "✗".bright_red().bold(), still_failing.len());
+    for (idx, result) in still_failing.iter().enumerate() {
+        println!(
+            "  {}. {} {} {}",
+            idx + 1,
+            result.operation.operation_type(),
+            result.operation.entity(),
+            result.status_code.map(|code| format!("({})", code)).unwrap_or_default().dimmed()
+        );
+        println!("     {}", result.error.as_deref().unwrap_or("Unknown error").red());
+    }
+    anyhow::bail!("{} operation(s) of run '{}' still failing", still_failing.len(), run_id)
+}
+
+fn print_failure(number: usize, failed: &FailedOperation) {
+    println!(
+        "  {}. {} {} {}",
+        number,
+        failed.operation.operation_type(),
+        failed.operation.entity(),
+        failed.status_code.map(|code| format!("({})", code)).unwrap_or_default().dimmed()
+    );
+    println!("     {}", failed.error.red());
+}
@@ -0,0 +1,135 @@
+//! Column aliases from FQL projections
+//!
+//! `.name as company` emits `alias="company"` in FetchXML, but depending on the
+//! attribute the Web API may still key the value by its logical name (or
+//! `_name_value` for lookups, `c.name` for joined attributes). Records are
+//! renamed here so output always uses the alias.
+
+use serde_json::{Map, Value};
+
+use crate::fql::ast::Query;
+
+/// Renames applied to every record of one FetchXML document
+#[derive(Debug, Clone, Default)]
+pub struct ColumnAliases {
+    /// (keys the value may arrive under, alias to use instead)
+    renames: Vec<(Vec<String>, String)>,
+}
+
+impl ColumnAliases {
+    /// Collect the aliased attributes of a query (ignoring its union branches)
+    pub fn from_query(query: &Query) -> Self {
+        let mut renames = Vec::new();
+
+        for attribute in &query.attributes {
+            let Some(alias) = &attribute.alias else { continue };
+            let keys = match &attribute.entity_alias {
+                Some(entity_alias) if Some(entity_alias) != query.entity.alias.as_ref() => {
+                    vec![format!("{}.{}", entity_alias, attribute.name)]
+                }
+                _ => vec![attribute.name.clone(), format!("_{}_value", attribute.name)],
+            };
+            renames.push((keys, alias.clone()));
+        }
+
+        for join in &query.joins {
+            let join_alias = join.entity.alias.as_deref().unwrap_or(&join.entity.name);
+            for attribute in &join.attributes {
+                if let Some(alias) = &attribute.alias {
+                    renames.push((vec![format!("{}.{}", join_alias, attribute.name)], alias.clone()));
+                }
+            }
+        }
+
+        Self { renames }
+    }
+
+    /// Move values (and their annotations) from logical names to aliases
+    pub fn apply(&self, record: &mut Value) {
+        let Value::Object(obj) = record else { return };
+
+        for (keys, alias) in &self.renames {
+            if obj.contains_key(alias) {
+                continue;
+            }
+            if let Some(key) = keys.iter().find(|key| obj.contains_key(key.as_str())) {
+                rename_key(obj, key, alias);
+            }
+        }
+    }
+}
+
+/// Rename `from` to `to`, carrying along `from@...` annotations
+fn rename_key(obj: &mut Map<String, Value>, from: &str, to: &str) {
+    if let Some(value) = obj.remove(from) {
+        obj.insert(to.to_string(), value);
+    }
+
+    let prefix = format!("{}@", from);
+    let annotations: Vec<String> = obj.keys().filter(|k| k.starts_with(&prefix)).cloned().collect();
+    for key in annotations {
+        if let Some(value) = obj.remove(&key) {
+            obj.insert(format!("{}@{}", to, &key[prefix.len()..]), value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fql::{parse, tokenize};
+    use serde_json::json;
+
+    fn aliases_for(fql: &str) -> ColumnAliases {
+        let tokens = tokenize(fql).unwrap();
+        ColumnAliases::from_query(&parse(tokens, fql).unwrap())
+    }
+
+    #[test]
+    fn test_aliases_rename_logical_names_and_annotations() {
+        let aliases = aliases_for(".account | .name as company, .revenue as total_revenue, .accountnumber");
+        let mut record = json!({
+            "name": "Contoso",
+            "revenue": 1000,
+            "revenue@OData.Community.Display.V1.FormattedValue": "$1,000.00",
+            "accountnumber": "AC-1"
+        });
+
+        aliases.apply(&mut record);
+
+        assert_eq!(
+            record,
+            json!({
+                "company": "Contoso",
+                "total_revenue": 1000,
+                "total_revenue@OData.Community.Display.V1.FormattedValue": "$1,000.00",
+                "accountnumber": "AC-1"
+            })
+        );
+    }
+
+    #[test]
+    fn test_aliases_for_lookups_and_joins() {
+        let aliases = aliases_for(
+            ".account | .primarycontactid as contact | join(.contact as c on c.contactid -> account.primarycontactid | .fullname as contact_name)",
+        );
+        let mut record = json!({
+            "_primarycontactid_value": "guid",
+            "c.fullname": "Jane Doe"
+        });
+
+        aliases.apply(&mut record);
+
+        assert_eq!(record, json!({ "contact": "guid", "contact_name": "Jane Doe" }));
+    }
+
+    #[test]
+    fn test_already_aliased_record_is_untouched() {
+        let aliases = aliases_for(".account | .name as company");
+        let mut record = json!({ "company": "Contoso" });
+
+        aliases.apply(&mut record);
+
+        assert_eq!(record, json!({ "company": "Contoso" }));
+    }
+}
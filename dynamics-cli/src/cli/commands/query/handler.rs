@@ -2,13 +2,26 @@
 
 use anyhow::{Context, Result};
 use colored::*;
+use serde_json::Value;
+use std::collections::HashMap;
+use is_terminal::IsTerminal;
 use std::fs;
+use std::io::{BufWriter, Write};
 use std::time::Instant;
 
-use crate::api::ClientManager;
+use crate::api::{ClientManager, DynamicsClient, EntityOptionSets};
 use crate::config::Config;
-use crate::fql::{parse, to_fetchxml, to_fetchxml_pretty, tokenize};
-use super::{DisplayStyle, OutputFormat, QueryCommands};
+use crate::fql::lexer::ParseError;
+use crate::fql::{format_fetchxml, parse, to_fetchxml, to_fetchxml_pretty, tokenize};
+use crate::cli::commands::diff::compare::diff_records;
+use crate::cli::commands::diff::handler::print_report;
+use crate::cli::commands::diff::OutputFormat as DiffFormat;
+use super::aliases::ColumnAliases;
+use super::labels::{load_option_sets, resolve_labels};
+use super::validation::check_query;
+use super::paging::follow_pages;
+use super::writer::{json_value_to_string, RecordWriter};
+use super::{DisplayStyle, QueryCommands};
 
 /// Handle the query command with the new streamlined interface
 pub async fn handle_query_command(args: QueryCommands) -> Result<()> {
@@ -20,8 +33,8 @@ pub async fn handle_query_command(args: QueryCommands) -> Result<()> {
     }
 
     // Validate arguments
-    if args.query.is_none() && args.file.is_none() {
-        anyhow::bail!("Either provide a query string or use --file to specify a query file");
+    if args.query.is_none() && args.file.is_none() && args.saved.is_none() {
+        anyhow::bail!("Either provide a query string, use --file to specify a query file or --saved to run a saved query");
     }
 
     if args.query.is_some() && args.file.is_some() {
@@ -31,6 +44,16 @@ pub async fn handle_query_command(args: QueryCommands) -> Result<()> {
     // Read query from source
     let query_text = if let Some(query) = args.query {
         query
+    } else if let Some(ref name) = args.saved {
+        let config = crate::global_config();
+        let saved = config.get_saved_query(name).await?
+            .ok_or_else(|| anyhow::anyhow!("No saved query named '{}'", name))?;
+        config.touch_saved_query(name).await?;
+
+        if matches!(args.style, DisplayStyle::Verbose) {
+            println!("Running saved query: {}", name.cyan());
+        }
+        saved.fql
     } else if let Some(file_path) = args.file {
         if !file_path.exists() {
             anyhow::bail!("Query file does not exist: {}", file_path.display());
@@ -49,7 +72,7 @@ pub async fn handle_query_command(args: QueryCommands) -> Result<()> {
         }
         trimmed.to_string()
     } else {
-        unreachable!("Validation above ensures one of query or file is present");
+        unreachable!("Validation above ensures one of query, file or saved is present");
     };
 
     if matches!(args.style, DisplayStyle::Verbose) {
@@ -65,15 +88,29 @@ pub async fn handle_query_command(args: QueryCommands) -> Result<()> {
     }
 
     let tokens = tokenize(&query_text)
-        .context("Failed to tokenize FQL query")?;
-
-    let ast = parse(tokens, &query_text)
-        .context("Failed to parse FQL query")?;
+        .map_err(|e| report_parse_error(e, "Failed to tokenize FQL query"))?;
+
+    let mut ast = parse(tokens, &query_text)
+        .map_err(|e| report_parse_error(e, "Failed to parse FQL query"))?;
+    if args.validate {
+        let env_name = match &args.env {
+            Some(env) => Some(env.clone()),
+            None => client_manager.get_current_environment().await,
+        };
+        check_query(env_name.as_deref(), &ast).await?;
+    }
+    if args.count {
+        ast = ast.into_count()?;
+    }
 
-    // Extract entity name from AST for pluralization
-    let entity_name = ast.entity.name.clone();
+    // Extract entity names from AST for pluralization (one per union branch)
+    let entity_names = ast.entity_names();
+    let column_aliases: Vec<ColumnAliases> = std::iter::once(&ast)
+        .chain(&ast.unions)
+        .map(ColumnAliases::from_query)
+        .collect();
 
-    let fetchxml = if args.dry {
+    let fetchxml_documents = if args.dry {
         to_fetchxml_pretty(ast)
     } else {
         to_fetchxml(ast)
@@ -91,7 +128,13 @@ pub async fn handle_query_command(args: QueryCommands) -> Result<()> {
             println!("Generated FetchXML:");
             println!();
         }
-        println!("{}", fetchxml);
+        // Highlight only for a terminal; redirected output stays plain XML
+        let color = !args.no_color && std::io::stdout().is_terminal();
+        let formatted = fetchxml_documents
+            .iter()
+            .map(|document| format_fetchxml(document, color))
+            .collect::<Result<Vec<_>>>()?;
+        println!("{}", formatted.join("\n\n"));
         return Ok(());
     }
 
@@ -118,74 +161,246 @@ pub async fn handle_query_command(args: QueryCommands) -> Result<()> {
 
     let client = client_manager.get_client(&env_name).await?;
 
-    // Execute the query using the new API client with entity name
-    let result = client.execute_fetchxml(&entity_name, &fetchxml).await
-        .context("Failed to execute query")?;
+    if args.count {
+        let mut total = 0;
+        for (entity_name, fetchxml) in entity_names.iter().zip(&fetchxml_documents) {
+            let result = client.execute_fetchxml(entity_name, fetchxml).await
+                .context("Failed to execute count query")?;
+            total += read_count(&result)
+                .with_context(|| format!("Count query for {} returned no count", entity_name))?;
+        }
+
+        // Just the number, so scripts can capture it
+        println!("{}", total);
+        if matches!(args.style, DisplayStyle::Verbose) {
+            println!("Execution time: {:.2}ms", start_exec.elapsed().as_secs_f64() * 1000.0);
+        }
+        return Ok(());
+    }
+
+    // Every page is fetched unless capped with --max-pages
+    let page_limit = args.max_pages;
+
+    if let Some(ref diff_env) = args.diff_env {
+        if fetchxml_documents.len() > 1 {
+            anyhow::bail!("Union queries are not supported with --diff-env; compare one entity at a time");
+        }
+        let other_client = client_manager.get_client(diff_env).await?;
+        let query = DiffQuery {
+            entity_name: &entity_names[0],
+            fetchxml: &fetchxml_documents[0],
+            aliases: &column_aliases[0],
+            page_limit,
+        };
+        let key = args.key.clone().unwrap_or_else(|| format!("{}id", entity_names[0]));
+        return run_diff(&query, (&env_name, &client), (diff_env, &other_client), &key, &args.diff_format).await;
+    }
+
+    // One entry per union branch, loaded before any output is written
+    let mut option_sets: Vec<Option<EntityOptionSets>> = Vec::new();
+    for entity_name in &entity_names {
+        let labels = if args.resolve_labels {
+            let labels = load_option_sets(&env_name, &client, entity_name).await
+                .with_context(|| format!("Failed to load option set labels for {}", entity_name))?;
+            Some(labels)
+        } else {
+            None
+        };
+        option_sets.push(labels);
+    }
 
+    let out: Box<dyn Write> = match args.output {
+        Some(ref output_path) => Box::new(BufWriter::new(
+            fs::File::create(output_path)
+                .with_context(|| format!("Failed to write output to: {}", output_path.display()))?,
+        )),
+        None => {
+            if matches!(args.style, DisplayStyle::Verbose) {
+                println!("Results:");
+                println!();
+            }
+            Box::new(BufWriter::new(std::io::stdout()))
+        }
+    };
+    let mut writer = RecordWriter::new(out, args.format.clone())?;
+
+    // Each union branch is paged separately and streamed into the same output
+    let mut pages = 0;
+    let mut fetched = Ok(());
+    for (branch, (entity_name, fetchxml)) in entity_names.iter().zip(&fetchxml_documents).enumerate() {
+        let mut write_page = |records: &mut Vec<Value>| writer.write_records(records);
+        let labels = option_sets[branch].as_ref();
+        let branch_pages = fetch_pages(&client, entity_name, fetchxml, &column_aliases[branch], labels, page_limit, &mut write_page)
+            .await
+            .with_context(|| {
+                if fetchxml_documents.len() == 1 {
+                    "Failed to execute query".to_string()
+                } else {
+                    format!("Failed to execute union branch {} ({})", branch + 1, entity_name)
+                }
+            });
+        match branch_pages {
+            Ok((branch_pages, more_records)) => {
+                pages += branch_pages;
+                if more_records {
+                    eprintln!(
+                        "{}",
+                        format!(
+                            "Warning: {} has more records than fetched ({} page(s)); raise --max-pages to fetch more",
+                            entity_name, branch_pages
+                        )
+                        .yellow()
+                    );
+                }
+            }
+            Err(e) => {
+                fetched = Err(e);
+                break;
+            }
+        }
+    }
+
+    // Metrics are written even when the query failed, so failures get scraped too
+    if let Some(ref metrics_path) = args.metrics_file {
+        fs::write(metrics_path, client.metrics_snapshot().to_prometheus())
+            .with_context(|| format!("Failed to write metrics to: {}", metrics_path.display()))?;
+    }
+    fetched?;
+
+    let record_count = writer.finish()?;
     let exec_duration = start_exec.elapsed();
 
     if matches!(args.style, DisplayStyle::Verbose) {
+        println!();
+        println!("Fetched {} record(s) in {} page(s)", record_count, pages);
         println!("Execution time: {:.2}ms", exec_duration.as_secs_f64() * 1000.0);
         println!("Total time: {:.2}ms", (parse_duration + exec_duration).as_secs_f64() * 1000.0);
-        println!();
-    }
-
-    // Format and output results
-    let formatted_output = format_output(&result, &args.format)?;
-
-    if let Some(output_path) = args.output {
-        fs::write(&output_path, &formatted_output)
-            .with_context(|| format!("Failed to write output to: {}", output_path.display()))?;
-        if matches!(args.style, DisplayStyle::Verbose) {
+        if let Some(output_path) = args.output {
             println!("Results saved to: {}", output_path.display().to_string().bright_green());
         }
-    } else {
-        if matches!(args.style, DisplayStyle::Verbose) {
-            println!("Results:");
-            println!();
-        }
-        println!("{}", formatted_output);
     }
 
     Ok(())
 }
 
-/// Format query results according to the specified output format
-fn format_output(data: &serde_json::Value, format: &OutputFormat) -> Result<String> {
+/// Value of the `count` alias in the single row an aggregate count query returns
+fn read_count(result: &Value) -> Option<u64> {
+    let count = result.get("value")?.get(0)?.get("count")?;
+    count.as_u64().or_else(|| count.as_str()?.parse().ok())
+}
+
+/// A single-entity query to run against both sides of a --diff-env comparison
+struct DiffQuery<'a> {
+    entity_name: &'a str,
+    fetchxml: &'a str,
+    aliases: &'a ColumnAliases,
+    page_limit: Option<u32>,
+}
+
+/// Run the query against both environments and print a keyed diff of the results
+///
+/// The current environment is the source side, `--diff-env` the target side.
+async fn run_diff(
+    query: &DiffQuery<'_>,
+    (env_name, client): (&str, &DynamicsClient),
+    (other_env, other_client): (&str, &DynamicsClient),
+    key: &str,
+    format: &DiffFormat,
+) -> Result<()> {
+    let (records, other_records) = tokio::try_join!(
+        collect_records(client, query),
+        collect_records(other_client, query),
+    )
+    .context("Failed to execute query")?;
+
+    let report = diff_records(&records, &other_records, key, key, &HashMap::new())?;
+
     match format {
-        OutputFormat::Json => {
-            serde_json::to_string_pretty(data)
-                .context("Failed to format JSON output")
-        }
-        OutputFormat::JsonCompact => {
-            serde_json::to_string(data)
-                .context("Failed to format JSON output")
+        DiffFormat::Json => {
+            let output = serde_json::json!({
+                "source": { "environment": env_name, "entity": query.entity_name, "count": records.len() },
+                "target": { "environment": other_env, "entity": query.entity_name, "count": other_records.len() },
+                "key": key,
+                "diff": report,
+            });
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&output).context("Failed to format JSON output")?
+            );
         }
-        OutputFormat::Xml => {
-            // Convert JSON to XML representation
-            let xml_string = json_to_xml(data)?;
-            // Pretty-print XML
-            match quick_xml::reader::Reader::from_str(&xml_string) {
-                mut reader => {
-                    let mut writer = quick_xml::Writer::new_with_indent(Vec::new(), b' ', 2);
-                    let mut buf = Vec::new();
-                    loop {
-                        match reader.read_event_into(&mut buf) {
-                            Ok(quick_xml::events::Event::Eof) => break,
-                            Ok(event) => writer.write_event(event)?,
-                            Err(_) => return Ok(xml_string), // fallback to raw
-                        }
-                        buf.clear();
+        DiffFormat::Text => print_report(&report, env_name, other_env, key),
+    }
+
+    Ok(())
+}
+
+async fn collect_records(client: &DynamicsClient, query: &DiffQuery<'_>) -> Result<Vec<Value>> {
+    let mut records = Vec::new();
+    let mut collect = |page: &mut Vec<Value>| {
+        records.append(page);
+        Ok(())
+    };
+    let (pages, more_records) =
+        fetch_pages(client, query.entity_name, query.fetchxml, query.aliases, None, query.page_limit, &mut collect).await?;
+    // Records past the cap would show up as missing from this side, so a partial diff is never reported
+    if more_records {
+        anyhow::bail!(
+            "{} has more records than the {} page(s) allowed by --max-pages; drop or raise --max-pages to compare every record",
+            query.entity_name,
+            pages
+        );
+    }
+    Ok(records)
+}
+
+/// Execute a FetchXML document page by page, following paging cookies
+///
+/// Each page's records, with choice labels resolved (when `labels` is given)
+/// and column aliases applied, are handed to `on_page`.
+/// Stops when the server reports no more records or `page_limit` pages have
+/// been fetched. Returns the number of pages fetched and whether records were left behind.
+pub(crate) async fn fetch_pages(
+    client: &DynamicsClient,
+    entity_name: &str,
+    fetchxml: &str,
+    aliases: &ColumnAliases,
+    labels: Option<&EntityOptionSets>,
+    page_limit: Option<u32>,
+    on_page: &mut dyn FnMut(&mut Vec<Value>) -> Result<()>,
+) -> Result<(u32, bool)> {
+    let execute = |fetchxml: String| async move { client.execute_fetchxml(entity_name, &fetchxml).await };
+    let mut handle_page = |mut result: Value| {
+        match result.get_mut("value").and_then(|v| v.as_array_mut()) {
+            Some(records) => {
+                for record in records.iter_mut() {
+                    if let Some(labels) = labels {
+                        resolve_labels(record, labels);
                     }
-                    Ok(String::from_utf8(writer.into_inner())
-                        .unwrap_or(xml_string))
+                    aliases.apply(record);
                 }
+                on_page(records)
             }
+            None => Ok(()),
         }
-        OutputFormat::Csv => {
-            json_to_csv(data)
+    };
+    follow_pages(fetchxml, page_limit, execute, &mut handle_page).await
+}
+
+/// Render query results as a pretty-printed XML document
+pub(super) fn format_xml(data: &serde_json::Value) -> Result<String> {
+    let xml_string = json_to_xml(data)?;
+    let mut reader = quick_xml::reader::Reader::from_str(&xml_string);
+    let mut writer = quick_xml::Writer::new_with_indent(Vec::new(), b' ', 2);
+    let mut buf = Vec::new();
+    loop {
+        match reader.read_event_into(&mut buf) {
+            Ok(quick_xml::events::Event::Eof) => break,
+            Ok(event) => writer.write_event(event)?,
+            Err(_) => return Ok(xml_string), // fallback to raw
         }
+        buf.clear();
     }
+    Ok(String::from_utf8(writer.into_inner()).unwrap_or(xml_string))
 }
 
 /// Convert JSON data to XML representation
@@ -211,63 +426,20 @@ fn json_to_xml(data: &serde_json::Value) -> Result<String> {
     }
 }
 
-/// Convert JSON data to CSV representation
-fn json_to_csv(data: &serde_json::Value) -> Result<String> {
-    match data {
-        serde_json::Value::Array(arr) => {
-            if arr.is_empty() {
-                return Ok("No data\n".to_string());
-            }
-
-            let mut csv = String::new();
-
-            // Extract headers from first object
-            if let Some(serde_json::Value::Object(first_obj)) = arr.first() {
-                let headers: Vec<String> = first_obj.keys().cloned().collect();
-                csv.push_str(&headers.join(","));
-                csv.push('\n');
-
-                // Add data rows
-                for item in arr {
-                    if let serde_json::Value::Object(obj) = item {
-                        let row: Vec<String> = headers.iter()
-                            .map(|h| csv_escape(&json_value_to_string(obj.get(h).unwrap_or(&serde_json::Value::Null))))
-                            .collect();
-                        csv.push_str(&row.join(","));
-                        csv.push('\n');
-                    }
-                }
-            }
-            Ok(csv)
+/// Print a located FQL error as a source snippet with a caret under the failing token
+///
+/// Errors without position information are passed through with `context` attached.
+fn report_parse_error(error: anyhow::Error, context: &'static str) -> anyhow::Error {
+    match error.downcast_ref::<ParseError>() {
+        Some(parse_error) => {
+            eprintln!("{}", parse_error.render(2).red());
+            anyhow::anyhow!(
+                "{} at line {}, column {}",
+                context,
+                parse_error.line(),
+                parse_error.column()
+            )
         }
-        serde_json::Value::Object(obj) => {
-            let mut csv = String::from("key,value\n");
-            for (key, value) in obj {
-                csv.push_str(&format!("{},{}\n", csv_escape(key), csv_escape(&json_value_to_string(value))));
-            }
-            Ok(csv)
-        }
-        _ => Ok(format!("value\n{}\n", csv_escape(&json_value_to_string(data))))
+        None => error.context(context),
     }
 }
-
-/// Convert a JSON value to a string representation
-fn json_value_to_string(value: &serde_json::Value) -> String {
-    match value {
-        serde_json::Value::Null => "null".to_string(),
-        serde_json::Value::Bool(b) => b.to_string(),
-        serde_json::Value::Number(n) => n.to_string(),
-        serde_json::Value::String(s) => s.clone(),
-        serde_json::Value::Array(_) | serde_json::Value::Object(_) => value.to_string(),
-    }
-}
-
-/// Escape a string for CSV output
-fn csv_escape(s: &str) -> String {
-    if s.contains(',') || s.contains('"') || s.contains('\n') {
-        format!("\"{}\"", s.replace('"', "\"\""))
-    } else {
-        s.to_string()
-    }
-}
-
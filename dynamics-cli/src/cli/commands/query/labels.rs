@@ -0,0 +1,103 @@
+//! Choice labels for `query --resolve-labels`
+//!
+//! Option set attributes come back as integers (multi-select ones as a
+//! comma-separated string). With `--resolve-labels` they are replaced by their
+//! labels before aliases are applied, so only attributes of the queried entity
+//! itself are resolved; joined attributes keep their values.
+
+use anyhow::Result;
+use serde_json::Value;
+
+use crate::api::{DynamicsClient, EntityOptionSets};
+use crate::config::repository::metadata_cache::MetadataKind;
+
+/// Option sets of `entity_name`, from the metadata cache or fetched and cached
+///
+/// The cache follows `api.cache.metadata_ttl_hours` like other entity metadata;
+/// 0 turns it off.
+pub async fn load_option_sets(environment_name: &str, client: &DynamicsClient, entity_name: &str) -> Result<EntityOptionSets> {
+    let config = crate::global_config();
+    let ttl_hours = config.options.get_uint("api.cache.metadata_ttl_hours").await.unwrap_or(12);
+
+    if ttl_hours > 0 {
+        let ttl = std::time::Duration::from_secs(ttl_hours * 60 * 60);
+        match config.get_metadata_cache(environment_name, entity_name, MetadataKind::OptionSets, ttl).await {
+            Ok(Some(option_sets)) => return Ok(option_sets),
+            Ok(None) => {}
+            Err(e) => log::warn!("Ignoring unreadable option set cache for {}/{}: {}", environment_name, entity_name, e),
+        }
+    }
+
+    let option_sets = client.fetch_entity_option_sets(entity_name).await?;
+
+    if ttl_hours > 0 {
+        if let Err(e) = config.set_metadata_cache(environment_name, entity_name, MetadataKind::OptionSets, &option_sets).await {
+            log::error!("Failed to cache option sets for {}/{}: {}", environment_name, entity_name, e);
+        }
+    }
+    Ok(option_sets)
+}
+
+/// Replace option set values in `record` with their labels
+///
+/// Values without a known label are left untouched.
+pub fn resolve_labels(record: &mut Value, option_sets: &EntityOptionSets) {
+    let Value::Object(obj) = record else { return };
+
+    for (key, value) in obj.iter_mut() {
+        if !option_sets.attributes.contains_key(key) {
+            continue;
+        }
+        let label = match value {
+            Value::Number(n) => n.as_i64().and_then(|n| option_sets.label(key, n)).map(str::to_string),
+            // Multi-select choices: "1,3"
+            Value::String(values) => values
+                .split(',')
+                .map(|v| v.trim().parse().ok().and_then(|v| option_sets.label(key, v)))
+                .collect::<Option<Vec<_>>>()
+                .map(|labels| labels.join("; ")),
+            _ => None,
+        };
+        if let Some(label) = label {
+            *value = Value::String(label);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_resolve_single_and_multi_select_values() {
+        let mut option_sets = EntityOptionSets::default();
+        option_sets.attributes.insert(
+            "industrycode".to_string(),
+            [(1, "Accounting".to_string()), (2, "Agriculture".to_string())].into(),
+        );
+        option_sets.attributes.insert(
+            "regions".to_string(),
+            [(10, "North".to_string()), (20, "South".to_string())].into(),
+        );
+
+        let mut record = json!({
+            "name": "Contoso",
+            "industrycode": 2,
+            "regions": "10,20",
+            "c.industrycode": 1,
+        });
+        resolve_labels(&mut record, &option_sets);
+        assert_eq!(record, json!({
+            "name": "Contoso",
+            "industrycode": "Agriculture",
+            "regions": "North; South",
+            "c.industrycode": 1,
+        }));
+
+        // Unknown values are kept as they are
+        let mut record = json!({ "industrycode": 99, "regions": "10,30" });
+        resolve_labels(&mut record, &option_sets);
+        assert_eq!(record, json!({ "industrycode": 99, "regions": "10,30" }));
+    }
+}
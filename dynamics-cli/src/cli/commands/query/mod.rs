@@ -1,4 +1,9 @@
+pub mod aliases;
 pub mod handler;
+pub mod labels;
+pub mod paging;
+pub mod validation;
+pub mod writer;
 
 use clap::{Args, ValueEnum};
 use std::path::PathBuf;
@@ -15,6 +20,10 @@ pub struct QueryCommands {
     #[arg(short, long, help = "Path to file containing FQL query")]
     pub file: Option<PathBuf>,
 
+    /// Execute a query saved in the TUI's Saved Queries
+    #[arg(long, value_name = "NAME", conflicts_with_all = ["query", "file"], help = "Name of a saved query to run")]
+    pub saved: Option<String>,
+
     /// Output format
     #[arg(long, default_value = "json", help = "Output format")]
     pub format: OutputFormat,
@@ -24,7 +33,7 @@ pub struct QueryCommands {
     pub style: DisplayStyle,
 
     /// Environment name (overrides current environment)
-    #[arg(long, help = "Environment name to use")]
+    #[arg(long, value_name = "ENV", help = "Environment name to use")]
     pub env: Option<String>,
 
     /// Disable colored output
@@ -38,6 +47,39 @@ pub struct QueryCommands {
     /// Save query results to file
     #[arg(short, long, help = "Save results to file")]
     pub output: Option<PathBuf>,
+
+    /// Maximum number of FetchXML pages (up to 5000 records each) to fetch;
+    /// every page is fetched when not given
+    #[arg(long, help = "Maximum number of result pages to fetch (default: all)")]
+    pub max_pages: Option<u32>,
+
+    /// Print only the number of matching records (a FetchXML `count` aggregate)
+    #[arg(long, conflicts_with_all = ["max_pages", "diff_env", "output"], help = "Print only the number of matching records")]
+    pub count: bool,
+
+    /// Replace option set (choice) values with their labels
+    #[arg(long, conflicts_with_all = ["count", "diff_env"], help = "Show choice labels instead of option set values")]
+    pub resolve_labels: bool,
+
+    /// Check entity and attribute names against cached metadata before running
+    #[arg(long, help = "Check entity and attribute names against cached metadata")]
+    pub validate: bool,
+
+    /// Run the query against this environment too and print a keyed diff of the results
+    #[arg(long, value_name = "ENV", conflicts_with_all = ["dry", "output"], help = "Compare results with another environment")]
+    pub diff_env: Option<String>,
+
+    /// Field used to match records with --diff-env (defaults to the primary id, e.g. accountid)
+    #[arg(long, requires = "diff_env", help = "Key field used to match records with --diff-env")]
+    pub key: Option<String>,
+
+    /// Output format of the --diff-env report
+    #[arg(long, default_value = "text", requires = "diff_env", help = "Diff output format")]
+    pub diff_format: crate::cli::commands::diff::OutputFormat,
+
+    /// Write request metrics in Prometheus text format to this file after execution
+    #[arg(long, hide = true)]
+    pub metrics_file: Option<PathBuf>,
 }
 
 #[derive(Debug, Clone, ValueEnum)]
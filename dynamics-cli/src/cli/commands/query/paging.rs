@@ -0,0 +1,279 @@
+//! FetchXML paging cookie handling
+//!
+//! Dynamics 365 returns at most 5000 rows per FetchXML request. When more rows
+//! exist the response carries a paging cookie annotation, which has to be fed
+//! back into the `<fetch>` element together with the next page number.
+
+use anyhow::{Context, Result};
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::encoding::Decoder;
+use quick_xml::{Reader, Writer};
+use serde_json::Value;
+use std::future::Future;
+
+use crate::api::constants::annotations;
+
+/// Execute a FetchXML document page by page with `execute`, following paging cookies
+///
+/// Each response is handed to `on_page`. Stops when the server reports no more
+/// records or `page_limit` pages have been fetched. Returns the number of pages
+/// fetched and whether the server still had more records.
+pub async fn follow_pages<F, Fut>(
+    fetchxml: &str,
+    page_limit: Option<u32>,
+    mut execute: F,
+    on_page: &mut dyn FnMut(Value) -> Result<()>,
+) -> Result<(u32, bool)>
+where
+    F: FnMut(String) -> Fut,
+    Fut: Future<Output = Result<Value>>,
+{
+    let mut fetchxml = fetchxml.to_string();
+    let mut pages = 0;
+
+    loop {
+        let result = execute(fetchxml.clone()).await?;
+        pages += 1;
+
+        let more_records = result
+            .get(annotations::MORE_RECORDS)
+            .and_then(|m| m.as_bool())
+            .unwrap_or(false);
+        let paging_cookie = match result
+            .get(annotations::FETCHXML_PAGING_COOKIE)
+            .and_then(|c| c.as_str())
+        {
+            Some(annotation) => extract_paging_cookie(annotation)?,
+            None => None,
+        };
+        on_page(result)?;
+
+        if !more_records {
+            return Ok((pages, false));
+        }
+        if page_limit.is_some_and(|limit| pages >= limit) {
+            return Ok((pages, true));
+        }
+        fetchxml = next_page_fetchxml(&fetchxml, paging_cookie.as_deref())?;
+    }
+}
+
+/// Extract the value for the `paging-cookie` attribute from the
+/// `@Microsoft.Dynamics.CRM.fetchxmlpagingcookie` annotation
+///
+/// The annotation looks like `<cookie pagenumber="2" pagingcookie="%253ccookie..." />`,
+/// where `pagingcookie` is URL-encoded twice.
+pub fn extract_paging_cookie(annotation: &str) -> Result<Option<String>> {
+    let mut reader = Reader::from_str(annotation);
+
+    loop {
+        match reader.read_event().context("Invalid paging cookie annotation")? {
+            Event::Start(element) | Event::Empty(element) if element.name().as_ref() == b"cookie" => {
+                for attribute in element.attributes() {
+                    let attribute = attribute.context("Invalid paging cookie attribute")?;
+                    if attribute.key.as_ref() == b"pagingcookie" {
+                        let value = attribute.decode_and_unescape_value(reader.decoder())?;
+                        let once = urlencoding::decode(&value)?;
+                        let twice = urlencoding::decode(&once)?;
+                        return Ok(Some(twice.into_owned()));
+                    }
+                }
+                return Ok(None);
+            }
+            Event::Eof => return Ok(None),
+            _ => {}
+        }
+    }
+}
+
+/// Rewrite the `<fetch>` element to request the page after the current one
+///
+/// The current page is read from the `page` attribute (defaulting to 1) and the
+/// paging cookie, when given, replaces any existing `paging-cookie` attribute.
+pub fn next_page_fetchxml(fetchxml: &str, paging_cookie: Option<&str>) -> Result<String> {
+    let mut reader = Reader::from_str(fetchxml);
+    let mut writer = Writer::new(Vec::new());
+    let mut rewritten = false;
+
+    loop {
+        match reader.read_event().context("Invalid FetchXML")? {
+            Event::Start(element) if !rewritten && element.name().as_ref() == b"fetch" => {
+                let fetch = rewrite_fetch_element(&element, reader.decoder(), paging_cookie)?;
+                writer.write_event(Event::Start(fetch))?;
+                rewritten = true;
+            }
+            Event::Eof => break,
+            event => writer.write_event(event)?,
+        }
+    }
+
+    if !rewritten {
+        anyhow::bail!("FetchXML has no <fetch> element");
+    }
+
+    String::from_utf8(writer.into_inner()).context("FetchXML is not valid UTF-8")
+}
+
+fn rewrite_fetch_element(
+    element: &BytesStart<'_>,
+    decoder: Decoder,
+    paging_cookie: Option<&str>,
+) -> Result<BytesStart<'static>> {
+    let mut current_page = 1u32;
+    let mut rewritten = BytesStart::new("fetch");
+
+    for attribute in element.attributes() {
+        let attribute = attribute.context("Invalid <fetch> attribute")?;
+        match attribute.key.as_ref() {
+            b"page" => {
+                current_page = attribute
+                    .decode_and_unescape_value(decoder)?
+                    .parse()
+                    .context("Invalid page attribute on <fetch>")?;
+            }
+            b"paging-cookie" => {}
+            _ => rewritten.push_attribute(attribute),
+        }
+    }
+
+    rewritten.push_attribute(("page", (current_page + 1).to_string().as_str()));
+    if let Some(cookie) = paging_cookie {
+        rewritten.push_attribute(("paging-cookie", cookie));
+    }
+
+    Ok(rewritten.into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_follow_pages_fetches_until_no_more_records() {
+        let fetchxml = r#"<fetch><entity name="account"></entity></fetch>"#;
+        let cookie = r#"<cookie pagenumber="2" pagingcookie="%253ccookie%2520page%253d%25221%2522%2520%252f%253e" />"#;
+        let mut requests = Vec::new();
+        let mut records = Vec::new();
+
+        let (pages, more) = follow_pages(
+            fetchxml,
+            None,
+            |xml| {
+                requests.push(xml);
+                let page = requests.len();
+                async move {
+                    Ok(match page {
+                        1 => serde_json::json!({
+                            "value": [{ "name": "A" }, { "name": "B" }],
+                            "@Microsoft.Dynamics.CRM.morerecords": true,
+                            "@Microsoft.Dynamics.CRM.fetchxmlpagingcookie": cookie,
+                        }),
+                        _ => serde_json::json!({ "value": [{ "name": "C" }] }),
+                    })
+                }
+            },
+            &mut |result| {
+                records.extend(result["value"].as_array().cloned().unwrap_or_default());
+                Ok(())
+            },
+        )
+        .await
+        .unwrap();
+
+        assert_eq!((pages, more), (2, false));
+        assert_eq!(records.len(), 3);
+        assert_eq!(
+            requests[1],
+            r#"<fetch page="2" paging-cookie="&lt;cookie page=&quot;1&quot; /&gt;"><entity name="account"></entity></fetch>"#
+        );
+
+        // A page limit stops early and reports the records left behind
+        let (pages, more) = follow_pages(
+            fetchxml,
+            Some(1),
+            |_| async { Ok(serde_json::json!({ "value": [], "@Microsoft.Dynamics.CRM.morerecords": true })) },
+            &mut |_| Ok(()),
+        )
+        .await
+        .unwrap();
+        assert_eq!((pages, more), (1, true));
+    }
+
+    #[tokio::test]
+    async fn test_every_page_is_fetched_without_flags() {
+        use crate::cli::commands::query::QueryCommands;
+        use clap::Parser;
+
+        #[derive(Parser)]
+        struct Cli {
+            #[command(flatten)]
+            query: QueryCommands,
+        }
+
+        let cli = Cli::try_parse_from(["dynamics-cli", ".account | .name"]).unwrap();
+        let page_limit = cli.query.max_pages;
+        assert_eq!(page_limit, None);
+
+        let cookie = |page: u32| format!(r#"<cookie pagenumber="{}" pagingcookie="%253ccookie%2520page%253d%2522{}%2522%2520%252f%253e" />"#, page + 1, page);
+        let mut requests = 0;
+        let mut records = 0;
+
+        let (pages, more) = follow_pages(
+            r#"<fetch><entity name="account"></entity></fetch>"#,
+            page_limit,
+            |_| {
+                requests += 1;
+                let page = requests;
+                let response = if page < 3 {
+                    serde_json::json!({
+                        "value": [{ "name": page }],
+                        "@Microsoft.Dynamics.CRM.morerecords": true,
+                        "@Microsoft.Dynamics.CRM.fetchxmlpagingcookie": cookie(page),
+                    })
+                } else {
+                    serde_json::json!({ "value": [{ "name": page }] })
+                };
+                async move { Ok(response) }
+            },
+            &mut |result| {
+                records += result["value"].as_array().map_or(0, |v| v.len());
+                Ok(())
+            },
+        )
+        .await
+        .unwrap();
+
+        assert_eq!((pages, more), (3, false));
+        assert_eq!(records, 3);
+    }
+
+    #[test]
+    fn test_extract_paging_cookie_decodes_twice() {
+        let annotation = r#"<cookie pagenumber="2" pagingcookie="%253ccookie%2520page%253d%25221%2522%253e%253caccountid%2520last%253d%2522%257bA%257d%2522%2520%252f%253e%253c%252fcookie%253e" istracking="False" />"#;
+
+        let cookie = extract_paging_cookie(annotation).unwrap().unwrap();
+
+        assert_eq!(cookie, r#"<cookie page="1"><accountid last="{A}" /></cookie>"#);
+    }
+
+    #[test]
+    fn test_next_page_sets_page_and_escaped_cookie() {
+        let fetchxml = r#"<fetch version="1.0" mapping="logical" distinct="false"><entity name="account"><attribute name="name" /></entity></fetch>"#;
+
+        let next = next_page_fetchxml(fetchxml, Some(r#"<cookie page="1" />"#)).unwrap();
+
+        assert_eq!(
+            next,
+            r#"<fetch version="1.0" mapping="logical" distinct="false" page="2" paging-cookie="&lt;cookie page=&quot;1&quot; /&gt;"><entity name="account"><attribute name="name" /></entity></fetch>"#
+        );
+    }
+
+    #[test]
+    fn test_next_page_replaces_existing_paging_attributes() {
+        let fetchxml = r#"<fetch page="3" count="50" paging-cookie="old"><entity name="account"></entity></fetch>"#;
+
+        let next = next_page_fetchxml(fetchxml, None).unwrap();
+
+        assert_eq!(next, r#"<fetch count="50" page="4"><entity name="account"></entity></fetch>"#);
+    }
+}
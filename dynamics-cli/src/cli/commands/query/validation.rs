@@ -0,0 +1,81 @@
+//! Cached metadata for `query --validate` and the TUI query runner
+//!
+//! Validation only reads caches filled by other commands and apps (the entity
+//! list, per-entity fields); it never fetches metadata, so it costs nothing
+//! when the caches are cold.
+
+use anyhow::Result;
+use colored::*;
+use std::collections::BTreeSet;
+
+use crate::api::metadata::FieldMetadata;
+use crate::config::repository::metadata_cache::MetadataKind;
+use crate::fql::ast::Query;
+use crate::fql::{validate, ValidationMetadata};
+
+/// Maximum age of the cached entity list, as elsewhere it is read
+const ENTITY_CACHE_MAX_AGE_HOURS: i64 = 24;
+
+/// Whatever metadata is cached for the entities `query` touches
+///
+/// Unreadable cache entries are logged and treated as missing.
+pub async fn load_validation_metadata(environment_name: &str, query: &Query) -> ValidationMetadata {
+    let config = crate::global_config();
+    let mut metadata = ValidationMetadata::default();
+
+    match config.get_entity_cache(environment_name, ENTITY_CACHE_MAX_AGE_HOURS).await {
+        Ok(entities) => metadata.entities = entities,
+        Err(e) => log::warn!("Ignoring unreadable entity cache for {}: {}", environment_name, e),
+    }
+
+    // A TTL of 0 turns the metadata cache off, so nothing in it is current
+    let ttl_hours = config.options.get_uint("api.cache.metadata_ttl_hours").await.unwrap_or(12);
+    if ttl_hours == 0 {
+        return metadata;
+    }
+    let ttl = std::time::Duration::from_secs(ttl_hours * 60 * 60);
+
+    let entity_names: BTreeSet<&str> = std::iter::once(query)
+        .chain(&query.unions)
+        .flat_map(|branch| std::iter::once(&branch.entity).chain(branch.joins.iter().map(|join| &join.entity)))
+        .map(|entity| entity.name.as_str())
+        .collect();
+    for entity_name in entity_names {
+        match config.get_metadata_cache::<Vec<FieldMetadata>>(environment_name, entity_name, MetadataKind::Fields, ttl).await {
+            Ok(Some(fields)) => {
+                metadata.fields.insert(entity_name.to_string(), fields);
+            }
+            Ok(None) => {}
+            Err(e) => log::warn!("Ignoring unreadable field cache for {}/{}: {}", environment_name, entity_name, e),
+        }
+    }
+    metadata
+}
+
+/// Validate `query` for `query --validate`, printing each issue
+///
+/// Without an environment or any cached metadata validation is skipped with a note.
+pub async fn check_query(environment_name: Option<&str>, query: &Query) -> Result<()> {
+    let Some(environment_name) = environment_name else {
+        eprintln!("{}", "No environment selected; skipping validation".yellow());
+        return Ok(());
+    };
+
+    let metadata = load_validation_metadata(environment_name, query).await;
+    if metadata.is_empty() {
+        eprintln!(
+            "{}",
+            format!("No cached metadata for {}; skipping validation", environment_name).yellow()
+        );
+        return Ok(());
+    }
+
+    let issues = validate(query, &metadata);
+    if issues.is_empty() {
+        return Ok(());
+    }
+    for issue in &issues {
+        eprintln!("{} {}", "✗".bright_red().bold(), issue);
+    }
+    anyhow::bail!("Query validation failed with {} issue(s)", issues.len())
+}
@@ -0,0 +1,240 @@
+//! Incremental output writers for query results
+//!
+//! Records are written page by page as they arrive, so paging through large
+//! result sets does not hold the whole set in memory. XML output is the
+//! exception: it is rendered as one document once all pages are in.
+//!
+//! CSV columns are fixed by the first page (after aliases are applied), since
+//! the header row is already written by the time later pages arrive.
+
+use anyhow::{Context, Result};
+use serde_json::Value;
+use std::io::Write;
+
+use super::OutputFormat;
+
+/// Writes query records to an output in the requested format
+pub struct RecordWriter {
+    out: Box<dyn Write>,
+    format: OutputFormat,
+    written: usize,
+    csv_headers: Option<Vec<String>>,
+    xml_records: Vec<Value>,
+}
+
+impl RecordWriter {
+    /// Create a writer and emit the opening part of the document
+    pub fn new(out: Box<dyn Write>, format: OutputFormat) -> Result<Self> {
+        let mut writer = Self {
+            out,
+            format,
+            written: 0,
+            csv_headers: None,
+            xml_records: Vec::new(),
+        };
+
+        match writer.format {
+            OutputFormat::Json => write!(writer.out, "{{\n  \"value\": [")?,
+            OutputFormat::JsonCompact => write!(writer.out, "{{\"value\":[")?,
+            OutputFormat::Xml | OutputFormat::Csv => {}
+        }
+
+        Ok(writer)
+    }
+
+    /// Append one page of records
+    pub fn write_records(&mut self, records: &[Value]) -> Result<()> {
+        if matches!(self.format, OutputFormat::Csv) {
+            return self.write_csv_page(records);
+        }
+
+        for record in records {
+            match self.format {
+                OutputFormat::Json => {
+                    let pretty = serde_json::to_string_pretty(record)
+                        .context("Failed to format JSON output")?;
+                    let separator = if self.written > 0 { "," } else { "" };
+                    write!(self.out, "{}\n    {}", separator, pretty.replace('\n', "\n    "))?;
+                }
+                OutputFormat::JsonCompact => {
+                    let compact = serde_json::to_string(record)
+                        .context("Failed to format JSON output")?;
+                    let separator = if self.written > 0 { "," } else { "" };
+                    write!(self.out, "{}{}", separator, compact)?;
+                }
+                OutputFormat::Xml => self.xml_records.push(record.clone()),
+                OutputFormat::Csv => unreachable!("CSV pages are written by write_csv_page"),
+            }
+            self.written += 1;
+        }
+
+        self.out.flush()?;
+        Ok(())
+    }
+
+    /// Close the document and return the number of records written
+    pub fn finish(mut self) -> Result<usize> {
+        match self.format {
+            OutputFormat::Json if self.written > 0 => writeln!(self.out, "\n  ]\n}}")?,
+            OutputFormat::Json => writeln!(self.out, "]\n}}")?,
+            OutputFormat::JsonCompact => writeln!(self.out, "]}}")?,
+            OutputFormat::Csv if self.written == 0 => writeln!(self.out, "No data")?,
+            OutputFormat::Csv => {}
+            OutputFormat::Xml => {
+                let records = std::mem::take(&mut self.xml_records);
+                let xml = super::handler::format_xml(&serde_json::json!({ "value": records }))?;
+                writeln!(self.out, "{}", xml)?;
+            }
+        }
+
+        self.out.flush()?;
+        Ok(self.written)
+    }
+
+    fn write_csv_page(&mut self, records: &[Value]) -> Result<()> {
+        let rows: Vec<&serde_json::Map<String, Value>> =
+            records.iter().filter_map(|record| record.as_object()).collect();
+        if rows.is_empty() {
+            return Ok(());
+        }
+
+        let mut csv = csv::WriterBuilder::new()
+            .has_headers(false)
+            .from_writer(&mut self.out);
+
+        // Records omit null attributes, so the header is every key seen on the first page
+        let headers = match &self.csv_headers {
+            Some(headers) => {
+                if let Some(key) = rows.iter().flat_map(|row| row.keys()).find(|key| !headers.contains(key)) {
+                    anyhow::bail!(
+                        "Column '{}' first appeared after the CSV header was written; use --format json for this query",
+                        key
+                    );
+                }
+                headers
+            }
+            None => {
+                let mut headers: Vec<String> = Vec::new();
+                for key in rows.iter().flat_map(|row| row.keys()) {
+                    if !headers.contains(key) {
+                        headers.push(key.clone());
+                    }
+                }
+                csv.write_record(&headers).context("Failed to write CSV header")?;
+                self.csv_headers.insert(headers)
+            }
+        };
+
+        for row in &rows {
+            csv.write_record(
+                headers
+                    .iter()
+                    .map(|h| json_value_to_string(row.get(h).unwrap_or(&Value::Null))),
+            )
+            .context("Failed to write CSV row")?;
+        }
+
+        csv.flush()?;
+        self.written += rows.len();
+        Ok(())
+    }
+}
+
+/// Convert a JSON value to a string representation
+pub(super) fn json_value_to_string(value: &Value) -> String {
+    match value {
+        Value::Null => "null".to_string(),
+        Value::Bool(b) => b.to_string(),
+        Value::Number(n) => n.to_string(),
+        Value::String(s) => s.clone(),
+        Value::Array(_) | Value::Object(_) => value.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::sync::{Arc, Mutex};
+
+    /// Shared buffer so the test can read what the writer produced
+    #[derive(Clone, Default)]
+    struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for SharedBuffer {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn write_pages(format: OutputFormat, pages: &[Vec<Value>]) -> String {
+        let buffer = SharedBuffer::default();
+        let mut writer = RecordWriter::new(Box::new(buffer.clone()), format).unwrap();
+        for page in pages {
+            writer.write_records(page).unwrap();
+        }
+        writer.finish().unwrap();
+        let bytes = buffer.0.lock().unwrap().clone();
+        String::from_utf8(bytes).unwrap()
+    }
+
+    #[test]
+    fn test_streamed_json_matches_buffered_output() {
+        let pages = vec![
+            vec![json!({"name": "A"}), json!({"name": "B"})],
+            vec![json!({"name": "C"})],
+        ];
+        let all: Vec<Value> = pages.iter().flatten().cloned().collect();
+
+        let streamed = write_pages(OutputFormat::Json, &pages);
+        let buffered = serde_json::to_string_pretty(&json!({ "value": all })).unwrap();
+        assert_eq!(streamed, format!("{}\n", buffered));
+
+        let compact = write_pages(OutputFormat::JsonCompact, &pages);
+        let buffered = serde_json::to_string(&json!({ "value": all })).unwrap();
+        assert_eq!(compact, format!("{}\n", buffered));
+    }
+
+    #[test]
+    fn test_empty_json_output() {
+        let streamed = write_pages(OutputFormat::Json, &[]);
+        let buffered = serde_json::to_string_pretty(&json!({ "value": [] })).unwrap();
+        assert_eq!(streamed, format!("{}\n", buffered));
+    }
+
+    #[test]
+    fn test_csv_headers_written_once_across_pages() {
+        let pages = vec![
+            vec![json!({"name": "A, Inc", "revenue": 10})],
+            vec![json!({"name": "B", "revenue": null})],
+        ];
+
+        let csv = write_pages(OutputFormat::Csv, &pages);
+
+        assert_eq!(csv, "name,revenue\n\"A, Inc\",10\nB,null\n");
+    }
+
+    #[test]
+    fn test_csv_headers_cover_whole_first_page() {
+        let pages = vec![vec![json!({"name": "A"}), json!({"name": "B", "revenue": 5})]];
+
+        let csv = write_pages(OutputFormat::Csv, &pages);
+
+        assert_eq!(csv, "name,revenue\nA,null\nB,5\n");
+    }
+
+    #[test]
+    fn test_csv_rejects_columns_after_first_page() {
+        let buffer = SharedBuffer::default();
+        let mut writer = RecordWriter::new(Box::new(buffer), OutputFormat::Csv).unwrap();
+        writer.write_records(&[json!({"name": "A"})]).unwrap();
+
+        let error = writer.write_records(&[json!({"name": "B", "revenue": 5})]).unwrap_err();
+
+        assert!(error.to_string().contains("'revenue'"));
+    }
+}
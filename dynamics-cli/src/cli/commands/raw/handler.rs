@@ -1,10 +1,17 @@
 //! Raw API command handler
 
 use anyhow::{Context, Result};
+use clap::ValueEnum;
 use colored::*;
+use serde::Deserialize;
+use serde_json::json;
 use std::fs;
+use std::path::Path;
 use std::time::Instant;
 
+use crate::api::constants::annotations;
+use crate::api::DynamicsClient;
+use super::select_path::SelectPath;
 use super::{DisplayStyle, HttpMethod, OutputFormat, RawCommands};
 
 /// Handle the raw API command
@@ -16,6 +23,9 @@ pub async fn handle_raw_command(args: RawCommands) -> Result<()> {
         colored::control::set_override(false);
     }
 
+    // Parse the selection up front so a typo doesn't cost a request
+    let select_path = args.select_path.as_deref().map(SelectPath::parse).transpose()?;
+
     // Determine environment
     let env_name = if let Some(ref env) = args.env {
         env.clone()
@@ -30,16 +40,27 @@ pub async fn handle_raw_command(args: RawCommands) -> Result<()> {
             })?
     };
 
+    if let Some(ref batch_path) = args.batch {
+        return handle_batch(&args, &env_name, batch_path, select_path.as_ref()).await;
+    }
+    let endpoint = args.endpoint.as_deref().unwrap_or_default();
+
     if matches!(args.style, DisplayStyle::Verbose) {
         println!("Using environment: {}", env_name.bright_green().bold());
         println!("Method: {}", format!("{:?}", args.method).bright_yellow());
-        println!("Endpoint: {}", args.endpoint.cyan());
+        println!("Endpoint: {}", endpoint.cyan());
         if let Some(ref data) = args.data {
             println!("Data: {}", data.dimmed());
         }
         println!();
     }
 
+    // Writes against protected environments need confirmation
+    if !matches!(args.method, HttpMethod::Get) {
+        let action = format!("send a {} request", format!("{:?}", args.method).to_uppercase());
+        crate::cli::ui::confirm_protected_write(&env_name, &action, args.yes).await?;
+    }
+
     // Execute request
     let start_exec = Instant::now();
 
@@ -50,39 +71,30 @@ pub async fn handle_raw_command(args: RawCommands) -> Result<()> {
     let client = client_manager.get_client(&env_name).await?;
 
     // Execute the raw API request
-    let result = match args.method {
-        HttpMethod::Get => {
-            client
-                .execute_raw("GET", &args.endpoint, None)
-                .await
-                .context("Failed to execute GET request")?
-        }
-        HttpMethod::Post => {
-            let data = args
-                .data
-                .as_deref()
-                .ok_or_else(|| anyhow::anyhow!("POST request requires --data"))?;
-            client
-                .execute_raw("POST", &args.endpoint, Some(data))
-                .await
-                .context("Failed to execute POST request")?
-        }
-        HttpMethod::Patch => {
-            let data = args
-                .data
-                .as_deref()
-                .ok_or_else(|| anyhow::anyhow!("PATCH request requires --data"))?;
-            client
-                .execute_raw("PATCH", &args.endpoint, Some(data))
-                .await
-                .context("Failed to execute PATCH request")?
-        }
-        HttpMethod::Delete => {
-            client
-                .execute_raw("DELETE", &args.endpoint, None)
-                .await
-                .context("Failed to execute DELETE request")?
+    if matches!(args.method, HttpMethod::Post | HttpMethod::Patch) && args.data.is_none() {
+        anyhow::bail!("{} request requires --data", verb(&args.method));
+    }
+    let result = match execute(&client, &args.method, endpoint, args.data.as_deref()).await {
+        Ok(first) if args.follow && matches!(args.method, HttpMethod::Get) => {
+            follow_pages(&client, first, args.max_pages).await.map(|(merged, pages)| {
+                if matches!(args.style, DisplayStyle::Verbose) {
+                    println!("Pages fetched: {}", pages);
+                }
+                merged
+            })
         }
+        other => other,
+    };
+
+    // Metrics are written even when the request failed, so failures get scraped too
+    if let Some(ref metrics_path) = args.metrics_file {
+        fs::write(metrics_path, client.metrics_snapshot().to_prometheus())
+            .with_context(|| format!("Failed to write metrics to: {}", metrics_path.display()))?;
+    }
+    let result = result?;
+    let result = match select_path {
+        Some(ref path) => path.apply(&result),
+        None => result,
     };
 
     let exec_duration = start_exec.elapsed();
@@ -115,6 +127,264 @@ pub async fn handle_raw_command(args: RawCommands) -> Result<()> {
     Ok(())
 }
 
+/// Send one request, with the body for POST and PATCH
+async fn execute(
+    client: &DynamicsClient,
+    method: &HttpMethod,
+    endpoint: &str,
+    data: Option<&str>,
+) -> Result<serde_json::Value> {
+    let data = match method {
+        HttpMethod::Post | HttpMethod::Patch => data,
+        HttpMethod::Get | HttpMethod::Delete => None,
+    };
+    client
+        .execute_raw(verb(method), endpoint, data)
+        .await
+        .with_context(|| format!("Failed to execute {} request", verb(method)))
+}
+
+/// Keep requesting `@odata.nextLink` and append each page's `value` to the first
+///
+/// Responses without a `value` array are returned as-is. Returns the merged
+/// response and the number of pages fetched; when `max_pages` stops paging the
+/// remaining next link is left on the result.
+async fn follow_pages(
+    client: &DynamicsClient,
+    mut merged: serde_json::Value,
+    max_pages: Option<usize>,
+) -> Result<(serde_json::Value, usize)> {
+    if !merged.get("value").is_some_and(serde_json::Value::is_array) {
+        return Ok((merged, 1));
+    }
+
+    let mut pages = 1;
+    while let Some(next_link) = take_next_link(&mut merged) {
+        if max_pages.is_some_and(|limit| pages >= limit) {
+            eprintln!(
+                "{}",
+                format!("Warning: stopped after {} page(s); more results are available", pages).yellow()
+            );
+            merged[annotations::ODATA_NEXT_LINK] = serde_json::Value::String(next_link);
+            break;
+        }
+
+        let page = client
+            .execute_raw("GET", &next_link, None)
+            .await
+            .with_context(|| format!("Failed to fetch page {}", pages + 1))?;
+        append_page(&mut merged, page);
+        pages += 1;
+    }
+
+    Ok((merged, pages))
+}
+
+fn take_next_link(response: &mut serde_json::Value) -> Option<String> {
+    match response.as_object_mut()?.remove(annotations::ODATA_NEXT_LINK)? {
+        serde_json::Value::String(link) => Some(link),
+        _ => None,
+    }
+}
+
+/// Append a page's records and carry over its next link
+fn append_page(merged: &mut serde_json::Value, mut page: serde_json::Value) {
+    if let Some(next_link) = take_next_link(&mut page) {
+        merged[annotations::ODATA_NEXT_LINK] = serde_json::Value::String(next_link);
+    }
+    if let (Some(records), Some(serde_json::Value::Array(more))) = (
+        merged.get_mut("value").and_then(serde_json::Value::as_array_mut),
+        page.get_mut("value").map(serde_json::Value::take),
+    ) {
+        records.extend(more);
+    }
+}
+
+fn verb(method: &HttpMethod) -> &'static str {
+    match method {
+        HttpMethod::Get => "GET",
+        HttpMethod::Post => "POST",
+        HttpMethod::Patch => "PATCH",
+        HttpMethod::Delete => "DELETE",
+    }
+}
+
+/// One line of a `--batch` file as written by the user
+#[derive(Debug, Deserialize)]
+struct BatchLine {
+    #[serde(default)]
+    method: Option<String>,
+    endpoint: String,
+    /// JSON body; a string is sent as-is
+    #[serde(default)]
+    data: Option<serde_json::Value>,
+}
+
+/// A batch request ready to send
+#[derive(Debug)]
+struct BatchRequest {
+    line: usize,
+    method: HttpMethod,
+    endpoint: String,
+    data: Option<String>,
+}
+
+/// Parse a JSON Lines batch file, skipping blank lines
+///
+/// The method defaults to GET; POST and PATCH lines need `data`.
+fn parse_batch(content: &str) -> Result<Vec<BatchRequest>> {
+    let mut requests = Vec::new();
+
+    for (index, text) in content.lines().enumerate() {
+        let line = index + 1;
+        if text.trim().is_empty() {
+            continue;
+        }
+
+        let parsed: BatchLine = serde_json::from_str(text)
+            .with_context(|| format!("Invalid batch request on line {}", line))?;
+
+        let method = match parsed.method {
+            Some(ref method) => HttpMethod::from_str(method, true)
+                .map_err(|_| anyhow::anyhow!("Unknown method '{}' on line {}", method, line))?,
+            None => HttpMethod::Get,
+        };
+
+        let data = match parsed.data {
+            None | Some(serde_json::Value::Null) => None,
+            Some(serde_json::Value::String(body)) => Some(body),
+            Some(body) => Some(body.to_string()),
+        };
+        if matches!(method, HttpMethod::Post | HttpMethod::Patch) && data.is_none() {
+            anyhow::bail!("{} request on line {} requires data", verb(&method), line);
+        }
+
+        requests.push(BatchRequest { line, method, endpoint: parsed.endpoint, data });
+    }
+
+    if requests.is_empty() {
+        anyhow::bail!("Batch file contains no requests");
+    }
+    Ok(requests)
+}
+
+/// Run every request in a batch file in order
+///
+/// Progress goes to stderr, one line per request; the combined results are
+/// written in the chosen output format. Stops at the first failure unless
+/// `--continue-on-error` is set.
+async fn handle_batch(
+    args: &RawCommands,
+    env_name: &str,
+    batch_path: &Path,
+    select_path: Option<&SelectPath>,
+) -> Result<()> {
+    let content = fs::read_to_string(batch_path)
+        .with_context(|| format!("Failed to read batch file: {}", batch_path.display()))?;
+    let requests = parse_batch(&content)?;
+    let total = requests.len();
+
+    // One confirmation covers every write in the file
+    let writes = requests.iter().filter(|r| !matches!(r.method, HttpMethod::Get)).count();
+    if writes > 0 {
+        let action = format!("send {} write request(s) from {}", writes, batch_path.display());
+        crate::cli::ui::confirm_protected_write(env_name, &action, args.yes).await?;
+    }
+
+    if matches!(args.style, DisplayStyle::Verbose) {
+        println!("Using environment: {}", env_name.bright_green().bold());
+        println!("Batch: {} ({} requests)", batch_path.display().to_string().cyan(), total);
+        println!();
+    }
+
+    let client = crate::client_manager().get_client(env_name).await?;
+    let start_batch = Instant::now();
+    let mut results = Vec::new();
+    let mut failed = 0;
+
+    for (index, request) in requests.iter().enumerate() {
+        let start_exec = Instant::now();
+        let outcome = match execute(&client, &request.method, &request.endpoint, request.data.as_deref()).await {
+            Ok(first) if args.follow && matches!(request.method, HttpMethod::Get) => {
+                follow_pages(&client, first, args.max_pages).await.map(|(merged, _)| merged)
+            }
+            other => other,
+        };
+        let label = format!(
+            "[{}/{}] line {}: {} {}",
+            index + 1,
+            total,
+            request.line,
+            verb(&request.method),
+            request.endpoint
+        );
+
+        match outcome {
+            Ok(response) => {
+                let response = match select_path {
+                    Some(path) => path.apply(&response),
+                    None => response,
+                };
+                eprintln!(
+                    "{} {} ({:.0}ms)",
+                    "✓".green(),
+                    label,
+                    start_exec.elapsed().as_secs_f64() * 1000.0
+                );
+                results.push(json!({
+                    "line": request.line,
+                    "method": verb(&request.method),
+                    "endpoint": request.endpoint,
+                    "success": true,
+                    "response": response,
+                }));
+            }
+            Err(e) => {
+                failed += 1;
+                eprintln!("{} {}: {:#}", "✗".red(), label, e);
+                results.push(json!({
+                    "line": request.line,
+                    "method": verb(&request.method),
+                    "endpoint": request.endpoint,
+                    "success": false,
+                    "error": format!("{:#}", e),
+                }));
+                if !args.continue_on_error {
+                    break;
+                }
+            }
+        }
+    }
+
+    if let Some(ref metrics_path) = args.metrics_file {
+        fs::write(metrics_path, client.metrics_snapshot().to_prometheus())
+            .with_context(|| format!("Failed to write metrics to: {}", metrics_path.display()))?;
+    }
+
+    let succeeded = results.len() - failed;
+    let skipped = total - results.len();
+    let formatted_output = format_output(&serde_json::Value::Array(results), &args.format)?;
+    if let Some(ref output_path) = args.output {
+        fs::write(output_path, &formatted_output)
+            .with_context(|| format!("Failed to write output to: {}", output_path.display()))?;
+    } else {
+        println!("{}", formatted_output);
+    }
+
+    eprintln!(
+        "{} succeeded, {} failed, {} skipped in {:.2}s",
+        succeeded.to_string().green(),
+        failed.to_string().red(),
+        skipped,
+        start_batch.elapsed().as_secs_f64()
+    );
+
+    if failed > 0 {
+        anyhow::bail!("{} of {} batch requests failed", failed, total);
+    }
+    Ok(())
+}
+
 /// Format API results according to the specified output format
 fn format_output(data: &serde_json::Value, format: &OutputFormat) -> Result<String> {
     match format {
@@ -252,3 +522,53 @@ fn csv_escape(s: &str) -> String {
         s.to_string()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_append_page_concatenates_values_and_moves_next_link() {
+        let mut merged = json!({"@odata.context": "ctx", "value": [{"id": 1}], "@odata.nextLink": "page2"});
+        assert_eq!(take_next_link(&mut merged).as_deref(), Some("page2"));
+
+        append_page(&mut merged, json!({"value": [{"id": 2}, {"id": 3}], "@odata.nextLink": "page3"}));
+        assert_eq!(take_next_link(&mut merged).as_deref(), Some("page3"));
+
+        append_page(&mut merged, json!({"value": [{"id": 4}]}));
+        assert_eq!(merged, json!({"@odata.context": "ctx", "value": [{"id": 1}, {"id": 2}, {"id": 3}, {"id": 4}]}));
+        assert!(take_next_link(&mut merged).is_none());
+    }
+
+    #[test]
+    fn test_parse_batch_resolves_methods_and_bodies() {
+        let content = r#"{"endpoint": "accounts?$top=1"}
+
+{"method": "patch", "endpoint": "accounts(1)", "data": {"name": "Contoso"}}
+{"method": "DELETE", "endpoint": "accounts(2)"}
+{"method": "POST", "endpoint": "contacts", "data": "{\"lastname\":\"Doe\"}"}"#;
+
+        let requests = parse_batch(content).unwrap();
+
+        assert_eq!(requests.len(), 4);
+        assert!(matches!(requests[0].method, HttpMethod::Get));
+        assert_eq!(requests[1].line, 3);
+        assert!(matches!(requests[1].method, HttpMethod::Patch));
+        assert_eq!(requests[1].data.as_deref(), Some(r#"{"name":"Contoso"}"#));
+        assert!(matches!(requests[2].method, HttpMethod::Delete));
+        assert_eq!(requests[3].data.as_deref(), Some(r#"{"lastname":"Doe"}"#));
+    }
+
+    #[test]
+    fn test_parse_batch_reports_line_numbers() {
+        let err = parse_batch("{\"endpoint\": \"accounts\"}\n{\"method\": \"post\", \"endpoint\": \"accounts\"}").unwrap_err();
+        assert_eq!(err.to_string(), "POST request on line 2 requires data");
+
+        let err = parse_batch("{\"method\": \"put\", \"endpoint\": \"accounts\"}").unwrap_err();
+        assert_eq!(err.to_string(), "Unknown method 'put' on line 1");
+
+        let err = parse_batch("not json").unwrap_err();
+        assert_eq!(err.to_string(), "Invalid batch request on line 1");
+        assert!(parse_batch("\n  \n").is_err());
+    }
+}
@@ -1,4 +1,5 @@
 pub mod handler;
+pub mod select_path;
 
 use clap::{Args, ValueEnum};
 use std::path::PathBuf;
@@ -8,8 +9,16 @@ pub use handler::handle_raw_command;
 #[derive(Args)]
 pub struct RawCommands {
     /// API endpoint path (e.g., "accounts?$select=name&$top=5")
-    #[arg(help = "API endpoint path")]
-    pub endpoint: String,
+    #[arg(help = "API endpoint path", required_unless_present = "batch")]
+    pub endpoint: Option<String>,
+
+    /// Run every request in a JSON Lines file of `{"method", "endpoint", "data"}` objects
+    #[arg(long, value_name = "FILE", conflicts_with_all = ["endpoint", "data"], help = "Run requests from a JSON Lines file, one per line")]
+    pub batch: Option<PathBuf>,
+
+    /// Keep going after a failed batch request instead of stopping
+    #[arg(long, requires = "batch", help = "Continue with the remaining batch requests after a failure")]
+    pub continue_on_error: bool,
 
     /// HTTP method
     #[arg(long, default_value = "get", help = "HTTP method")]
@@ -19,6 +28,18 @@ pub struct RawCommands {
     #[arg(long, help = "Request body data (JSON string)")]
     pub data: Option<String>,
 
+    /// Follow `@odata.nextLink` on GET collections and merge the `value` arrays
+    #[arg(long, help = "Follow @odata.nextLink for GET requests and merge all pages")]
+    pub follow: bool,
+
+    /// Upper bound on pages fetched with --follow
+    #[arg(long, requires = "follow", help = "Maximum number of pages to fetch with --follow")]
+    pub max_pages: Option<usize>,
+
+    /// JMESPath expression applied to the response before formatting (e.g. "value[].{name: name}")
+    #[arg(long, value_name = "EXPR", help = "JMESPath expression to select fields from the response")]
+    pub select_path: Option<String>,
+
     /// Output format
     #[arg(long, default_value = "json", help = "Output format")]
     pub format: OutputFormat,
@@ -28,7 +49,7 @@ pub struct RawCommands {
     pub style: DisplayStyle,
 
     /// Environment name (overrides current environment)
-    #[arg(long, help = "Environment name to use")]
+    #[arg(long, value_name = "ENV", help = "Environment name to use")]
     pub env: Option<String>,
 
     /// Disable colored output
@@ -38,6 +59,14 @@ pub struct RawCommands {
     /// Save results to file
     #[arg(short, long, help = "Save results to file")]
     pub output: Option<PathBuf>,
+
+    /// Skip confirmation for writes against protected environments
+    #[arg(short, long, help = "Skip confirmation for writes against protected environments")]
+    pub yes: bool,
+
+    /// Write request metrics in Prometheus text format to this file after execution
+    #[arg(long, hide = true)]
+    pub metrics_file: Option<PathBuf>,
 }
 
 #[derive(Debug, Clone, ValueEnum)]
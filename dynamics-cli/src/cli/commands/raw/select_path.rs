@@ -0,0 +1,444 @@
+//! `--select-path` expressions: the JMESPath subset used to trim raw responses
+//!
+//! Supported: field access (`a.b`, `"quoted name"`), indexes (`[0]`, `[-1]`),
+//! list projections (`[*]`), flattening (`[]`), object projections (`*`),
+//! multi-select lists and hashes (`[a, b]`, `{x: a, y: b}`), `@` and pipes.
+//! Projections drop null results, as in JMESPath.
+
+use anyhow::{bail, Result};
+use serde_json::{Map, Value};
+
+/// A parsed expression, checked before any request is sent
+#[derive(Debug, Clone, PartialEq)]
+pub struct SelectPath {
+    root: Node,
+}
+
+impl SelectPath {
+    pub fn parse(expr: &str) -> Result<Self> {
+        let tokens = tokenize(expr)?;
+        let mut parser = Parser { tokens, pos: 0 };
+        let root = parser.expression()?;
+        if let Some((token, at)) = parser.tokens.get(parser.pos) {
+            bail!("Invalid --select-path at position {}: unexpected {}", at, token.describe());
+        }
+        Ok(Self { root })
+    }
+
+    /// Evaluate the expression against a response
+    pub fn apply(&self, data: &Value) -> Value {
+        self.root.eval(data)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Node {
+    Current,
+    Field(String),
+    Index(Box<Node>, i64),
+    Sub(Box<Node>, Box<Node>),
+    Pipe(Box<Node>, Box<Node>),
+    /// Apply the right side to every element of the left side's array
+    Projection(Box<Node>, Box<Node>),
+    /// Apply the right side to every value of the left side's object
+    ObjectProjection(Box<Node>, Box<Node>),
+    Flatten(Box<Node>),
+    MultiList(Vec<Node>),
+    MultiHash(Vec<(String, Node)>),
+}
+
+impl Node {
+    fn eval(&self, value: &Value) -> Value {
+        match self {
+            Node::Current => value.clone(),
+            Node::Field(name) => value.get(name).cloned().unwrap_or(Value::Null),
+            Node::Index(node, index) => match node.eval(value) {
+                Value::Array(items) => {
+                    let len = items.len() as i64;
+                    let index = if *index < 0 { len + index } else { *index };
+                    usize::try_from(index).ok()
+                        .and_then(|i| items.into_iter().nth(i))
+                        .unwrap_or(Value::Null)
+                }
+                _ => Value::Null,
+            },
+            Node::Sub(left, right) => match left.eval(value) {
+                Value::Null => Value::Null,
+                inner => right.eval(&inner),
+            },
+            Node::Pipe(left, right) => right.eval(&left.eval(value)),
+            Node::Projection(left, right) => match left.eval(value) {
+                Value::Array(items) => project(items.iter(), right),
+                _ => Value::Null,
+            },
+            Node::ObjectProjection(left, right) => match left.eval(value) {
+                Value::Object(map) => project(map.values(), right),
+                _ => Value::Null,
+            },
+            Node::Flatten(node) => match node.eval(value) {
+                Value::Array(items) => Value::Array(
+                    items.into_iter()
+                        .flat_map(|item| match item {
+                            Value::Array(inner) => inner,
+                            other => vec![other],
+                        })
+                        .collect(),
+                ),
+                _ => Value::Null,
+            },
+            Node::MultiList(nodes) => {
+                if value.is_null() {
+                    return Value::Null;
+                }
+                Value::Array(nodes.iter().map(|n| n.eval(value)).collect())
+            }
+            Node::MultiHash(pairs) => {
+                if value.is_null() {
+                    return Value::Null;
+                }
+                let map: Map<String, Value> = pairs.iter()
+                    .map(|(key, node)| (key.clone(), node.eval(value)))
+                    .collect();
+                Value::Object(map)
+            }
+        }
+    }
+}
+
+fn project<'a>(items: impl Iterator<Item = &'a Value>, node: &Node) -> Value {
+    Value::Array(items.map(|item| node.eval(item)).filter(|v| !v.is_null()).collect())
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Ident(String),
+    Number(i64),
+    Dot,
+    Star,
+    At,
+    Comma,
+    Colon,
+    Pipe,
+    LBracket,
+    RBracket,
+    LBrace,
+    RBrace,
+    LParen,
+    RParen,
+}
+
+impl Token {
+    fn describe(&self) -> String {
+        match self {
+            Token::Ident(name) => format!("'{}'", name),
+            Token::Number(n) => format!("'{}'", n),
+            Token::Dot => "'.'".to_string(),
+            Token::Star => "'*'".to_string(),
+            Token::At => "'@'".to_string(),
+            Token::Comma => "','".to_string(),
+            Token::Colon => "':'".to_string(),
+            Token::Pipe => "'|'".to_string(),
+            Token::LBracket => "'['".to_string(),
+            Token::RBracket => "']'".to_string(),
+            Token::LBrace => "'{'".to_string(),
+            Token::RBrace => "'}'".to_string(),
+            Token::LParen => "'('".to_string(),
+            Token::RParen => "')'".to_string(),
+        }
+    }
+}
+
+/// Split an expression into tokens, each with its character position
+fn tokenize(expr: &str) -> Result<Vec<(Token, usize)>> {
+    let chars: Vec<char> = expr.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        let start = i;
+        let token = match c {
+            ' ' | '\t' | '\n' | '\r' => {
+                i += 1;
+                continue;
+            }
+            '.' => Token::Dot,
+            '*' => Token::Star,
+            '@' => Token::At,
+            ',' => Token::Comma,
+            ':' => Token::Colon,
+            '|' => Token::Pipe,
+            '[' => Token::LBracket,
+            ']' => Token::RBracket,
+            '{' => Token::LBrace,
+            '}' => Token::RBrace,
+            '(' => Token::LParen,
+            ')' => Token::RParen,
+            '"' => {
+                let mut name = String::new();
+                i += 1;
+                loop {
+                    match chars.get(i) {
+                        Some('"') => break,
+                        Some('\\') => {
+                            i += 1;
+                            match chars.get(i) {
+                                Some(escaped) => name.push(*escaped),
+                                None => bail!("Invalid --select-path at position {}: unterminated string", start),
+                            }
+                        }
+                        Some(other) => name.push(*other),
+                        None => bail!("Invalid --select-path at position {}: unterminated string", start),
+                    }
+                    i += 1;
+                }
+                Token::Ident(name)
+            }
+            c if c == '-' || c.is_ascii_digit() => {
+                i += 1;
+                while chars.get(i).is_some_and(char::is_ascii_digit) {
+                    i += 1;
+                }
+                let text: String = chars[start..i].iter().collect();
+                let n = text.parse().map_err(|_| {
+                    anyhow::anyhow!("Invalid --select-path at position {}: bad number '{}'", start, text)
+                })?;
+                tokens.push((Token::Number(n), start));
+                continue;
+            }
+            c if c.is_alphabetic() || c == '_' => {
+                while chars.get(i).is_some_and(|c| c.is_alphanumeric() || *c == '_') {
+                    i += 1;
+                }
+                tokens.push((Token::Ident(chars[start..i].iter().collect()), start));
+                continue;
+            }
+            other => bail!("Invalid --select-path at position {}: unexpected '{}'", start, other),
+        };
+        tokens.push((token, start));
+        i += 1;
+    }
+
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<(Token, usize)>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos).map(|(t, _)| t)
+    }
+
+    fn peek_at(&self, offset: usize) -> Option<&Token> {
+        self.tokens.get(self.pos + offset).map(|(t, _)| t)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).map(|(t, _)| t.clone());
+        self.pos += 1;
+        token
+    }
+
+    fn error<T>(&self, expected: &str) -> Result<T> {
+        match self.tokens.get(self.pos) {
+            Some((token, at)) => bail!(
+                "Invalid --select-path at position {}: expected {}, found {}",
+                at, expected, token.describe()
+            ),
+            None => bail!("Invalid --select-path: expected {} at end of expression", expected),
+        }
+    }
+
+    fn expect(&mut self, token: Token, expected: &str) -> Result<()> {
+        if self.peek() == Some(&token) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            self.error(expected)
+        }
+    }
+
+    /// expression := chain ('|' chain)*
+    fn expression(&mut self) -> Result<Node> {
+        let mut node = self.chain()?;
+        while self.peek() == Some(&Token::Pipe) {
+            self.pos += 1;
+            node = Node::Pipe(Box::new(node), Box::new(self.chain()?));
+        }
+        Ok(node)
+    }
+
+    /// A primary expression followed by any number of `.x`, `[...]` suffixes
+    fn chain(&mut self) -> Result<Node> {
+        let node = match self.peek() {
+            Some(Token::LBracket) => Node::Current,
+            _ => self.primary()?,
+        };
+        self.suffixes(node)
+    }
+
+    fn primary(&mut self) -> Result<Node> {
+        match self.next() {
+            Some(Token::Ident(name)) => Ok(Node::Field(name)),
+            Some(Token::At) => Ok(Node::Current),
+            Some(Token::Star) => {
+                let rest = self.suffixes(Node::Current)?;
+                Ok(Node::ObjectProjection(Box::new(Node::Current), Box::new(rest)))
+            }
+            Some(Token::LBrace) => self.multi_hash(),
+            Some(Token::LParen) => {
+                let node = self.expression()?;
+                self.expect(Token::RParen, "')'")?;
+                Ok(node)
+            }
+            _ => {
+                self.pos -= 1;
+                self.error("a field name")
+            }
+        }
+    }
+
+    fn suffixes(&mut self, mut node: Node) -> Result<Node> {
+        loop {
+            match self.peek() {
+                Some(Token::Dot) => {
+                    self.pos += 1;
+                    match self.peek() {
+                        Some(Token::Star) => {
+                            self.pos += 1;
+                            let rest = self.suffixes(Node::Current)?;
+                            return Ok(Node::ObjectProjection(Box::new(node), Box::new(rest)));
+                        }
+                        Some(Token::LBracket) => {
+                            self.pos += 1;
+                            let list = self.multi_list()?;
+                            node = Node::Sub(Box::new(node), Box::new(list));
+                        }
+                        Some(Token::LBrace) => {
+                            self.pos += 1;
+                            let hash = self.multi_hash()?;
+                            node = Node::Sub(Box::new(node), Box::new(hash));
+                        }
+                        Some(Token::Ident(_)) => {
+                            let field = self.primary()?;
+                            node = Node::Sub(Box::new(node), Box::new(field));
+                        }
+                        _ => return self.error("a field name after '.'"),
+                    }
+                }
+                Some(Token::LBracket) => match (self.peek_at(1), self.peek_at(2)) {
+                    (Some(Token::RBracket), _) => {
+                        self.pos += 2;
+                        let rest = self.suffixes(Node::Current)?;
+                        return Ok(Node::Projection(Box::new(Node::Flatten(Box::new(node))), Box::new(rest)));
+                    }
+                    (Some(Token::Star), Some(Token::RBracket)) => {
+                        self.pos += 3;
+                        let rest = self.suffixes(Node::Current)?;
+                        return Ok(Node::Projection(Box::new(node), Box::new(rest)));
+                    }
+                    (Some(Token::Number(n)), Some(Token::RBracket)) => {
+                        let n = *n;
+                        self.pos += 3;
+                        node = Node::Index(Box::new(node), n);
+                    }
+                    _ if node == Node::Current => {
+                        self.pos += 1;
+                        node = self.multi_list()?;
+                    }
+                    _ => {
+                        self.pos += 1;
+                        return self.error("an index, '*' or ']'");
+                    }
+                },
+                _ => return Ok(node),
+            }
+        }
+    }
+
+    /// After '[': expression (',' expression)* ']'
+    fn multi_list(&mut self) -> Result<Node> {
+        let mut nodes = vec![self.expression()?];
+        while self.peek() == Some(&Token::Comma) {
+            self.pos += 1;
+            nodes.push(self.expression()?);
+        }
+        self.expect(Token::RBracket, "',' or ']'")?;
+        Ok(Node::MultiList(nodes))
+    }
+
+    /// After '{': key ':' expression (',' key ':' expression)* '}'
+    fn multi_hash(&mut self) -> Result<Node> {
+        let mut pairs = Vec::new();
+        loop {
+            let key = match self.next() {
+                Some(Token::Ident(key)) => key,
+                _ => {
+                    self.pos -= 1;
+                    return self.error("a key name");
+                }
+            };
+            self.expect(Token::Colon, "':'")?;
+            pairs.push((key, self.expression()?));
+
+            match self.next() {
+                Some(Token::Comma) => continue,
+                Some(Token::RBrace) => return Ok(Node::MultiHash(pairs)),
+                _ => {
+                    self.pos -= 1;
+                    return self.error("',' or '}'");
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn select(expr: &str, data: &Value) -> Value {
+        SelectPath::parse(expr).unwrap().apply(data)
+    }
+
+    #[test]
+    fn test_projection_into_multiselect_hash() {
+        let data = json!({"value": [
+            {"name": "Contoso", "revenue": 10, "other": true},
+            {"name": "Fabrikam", "revenue": null},
+        ]});
+
+        assert_eq!(
+            select("value[].{name: name, rev: revenue}", &data),
+            json!([{"name": "Contoso", "rev": 10}, {"name": "Fabrikam", "rev": null}])
+        );
+        assert_eq!(select("value[*].revenue", &data), json!([10]));
+        assert_eq!(select("value[-1].name", &data), json!("Fabrikam"));
+        assert_eq!(select("value[0].[name, revenue]", &data), json!(["Contoso", 10]));
+    }
+
+    #[test]
+    fn test_fields_flatten_wildcards_and_pipes() {
+        let data = json!({"a": {"b": [[1, 2], [3]], "c d": "x"}, "m": {"x": {"v": 1}, "y": {"v": 2}}});
+
+        assert_eq!(select("a.b[]", &data), json!([1, 2, 3]));
+        assert_eq!(select(r#"a."c d""#, &data), json!("x"));
+        assert_eq!(select("m.*.v", &data), json!([1, 2]));
+        assert_eq!(select("a.b | [0]", &data), json!([1, 2]));
+        assert_eq!(select("missing.field", &data), Value::Null);
+    }
+
+    #[test]
+    fn test_invalid_expressions_are_rejected() {
+        let err = SelectPath::parse("value[].{name name}").unwrap_err();
+        assert_eq!(err.to_string(), "Invalid --select-path at position 14: expected ':', found 'name'");
+        assert!(SelectPath::parse("value.").is_err());
+        assert!(SelectPath::parse("value[0").is_err());
+        assert!(SelectPath::parse("a b").is_err());
+        assert!(SelectPath::parse("a ? b").is_err());
+    }
+}
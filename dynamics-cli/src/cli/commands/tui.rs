@@ -18,6 +18,10 @@ use crate::tui::MultiAppRuntime;
 pub struct TuiCommands {
     #[command(subcommand)]
     pub command: Option<TuiSubcommands>,
+
+    /// Skip confirmation for writes against protected environments
+    #[arg(short, long, global = true)]
+    pub yes: bool,
 }
 
 #[derive(Subcommand)]
@@ -27,6 +31,8 @@ pub enum TuiSubcommands {
 }
 
 pub async fn tui_command(args: TuiCommands) -> Result<()> {
+    crate::config::protection::set_force_writes(args.yes);
+
     match args.command {
         Some(TuiSubcommands::Launch) | None => {
             launch_tui().await?;
@@ -36,6 +42,9 @@ pub async fn tui_command(args: TuiCommands) -> Result<()> {
 }
 
 async fn launch_tui() -> Result<()> {
+    // Log panics with backtraces, and restore the terminal for ones the runtime can't contain
+    crate::tui::error_boundary::install_panic_hook();
+
     // Setup terminal
     enable_raw_mode()?;
     let mut stdout = io::stdout();
@@ -58,6 +67,9 @@ async fn launch_tui() -> Result<()> {
     )?;
     terminal.show_cursor()?;
 
+    // Let apps finish what must outlive them (e.g. unsaved mapping edits)
+    runtime.shutdown().await;
+
     result
 }
 
@@ -1,9 +1,15 @@
 use clap::{Args, Subcommand};
 
+use crate::update::Channel;
+
 #[derive(Args)]
 pub struct UpdateCommands {
     #[command(subcommand)]
     pub command: UpdateSubcommands,
+
+    /// Release channel to check and install from; saved for later checks
+    #[arg(long, global = true)]
+    pub channel: Option<Channel>,
 }
 
 #[derive(Subcommand)]
@@ -24,6 +30,12 @@ pub enum UpdateSubcommands {
         #[arg(short = 'y', long)]
         yes: bool,
     },
+    /// Restore the binary that was replaced by the last update
+    Rollback {
+        /// Skip confirmation prompt
+        #[arg(short = 'y', long)]
+        yes: bool,
+    },
 }
 
 /// Handle update command
@@ -31,6 +43,18 @@ pub async fn handle_update_command(cmd: UpdateCommands) -> anyhow::Result<()> {
     use colored::Colorize;
     use dialoguer::Confirm;
 
+    // An explicit channel sticks, so the background check follows it too
+    let channel = match cmd.channel {
+        Some(channel) => {
+            crate::update::set_channel(channel).await?;
+            channel
+        }
+        None => crate::update::configured_channel().await,
+    };
+    if channel != Channel::Stable {
+        println!("{}", format!("Channel: {}", channel.as_str()).dimmed());
+    }
+
     match cmd.command {
         UpdateSubcommands::Status => {
             println!("{}", "Checking for updates...".dimmed());
@@ -38,7 +62,7 @@ pub async fn handle_update_command(cmd: UpdateCommands) -> anyhow::Result<()> {
             let current = crate::update::current_version();
             println!("Current version: {}", current.green());
 
-            match crate::update::check_for_updates().await {
+            match crate::update::check_for_updates(channel).await {
                 Ok(info) => {
                     println!("Latest version:  {}", info.latest.green());
 
@@ -65,7 +89,7 @@ pub async fn handle_update_command(cmd: UpdateCommands) -> anyhow::Result<()> {
         UpdateSubcommands::Install { yes } => {
             println!("{}", "Checking for updates...".dimmed());
 
-            let info = crate::update::check_for_updates().await?;
+            let info = crate::update::check_for_updates(channel).await?;
 
             if !info.needs_update {
                 println!("{}", "✓ You are already running the latest version".green());
@@ -91,10 +115,11 @@ pub async fn handle_update_command(cmd: UpdateCommands) -> anyhow::Result<()> {
             }
 
             println!("\n{}", "Installing update...".dimmed());
-            match crate::update::install_update(true).await {
+            match crate::update::install_update(channel, true).await {
                 Ok(version) => {
                     println!("{}", format!("✓ Successfully updated to version {}", version).green());
                     println!("{}", "Please restart the application to use the new version".cyan());
+                    println!("Run {} to go back to version {}", "dynamics-cli update rollback".cyan(), info.current);
 
                     // Update last check timestamp
                     let config = crate::config::Config::load().await?;
@@ -134,6 +159,7 @@ pub async fn handle_update_command(cmd: UpdateCommands) -> anyhow::Result<()> {
                 Ok(installed_version) => {
                     println!("{}", format!("✓ Successfully installed version {}", installed_version).green());
                     println!("{}", "Please restart the application to use the new version".cyan());
+                    println!("Run {} to go back to version {}", "dynamics-cli update rollback".cyan(), current);
 
                     // Update last check timestamp
                     let config = crate::config::Config::load().await?;
@@ -146,6 +172,38 @@ pub async fn handle_update_command(cmd: UpdateCommands) -> anyhow::Result<()> {
                 }
             }
         }
+
+        UpdateSubcommands::Rollback { yes } => {
+            if !crate::update::has_backup() {
+                println!("{}", "No previous version to roll back to".yellow());
+                return Ok(());
+            }
+
+            let should_rollback = if yes {
+                true
+            } else {
+                Confirm::new()
+                    .with_prompt("Restore the version that was installed before the last update?")
+                    .default(true)
+                    .interact()?
+            };
+
+            if !should_rollback {
+                println!("{}", "Rollback cancelled".yellow());
+                return Ok(());
+            }
+
+            match crate::update::rollback().await {
+                Ok(version) => {
+                    println!("{}", format!("✓ Rolled back to version {}", version).green());
+                    println!("{}", "Please restart the application to use the restored version".cyan());
+                }
+                Err(e) => {
+                    eprintln!("{} {}", "Error:".red(), e);
+                    eprintln!("{}", "Failed to roll back. The current version is unchanged.".yellow());
+                }
+            }
+        }
     }
 
     Ok(())
@@ -1,5 +1,7 @@
 //! CLI-specific UI components
 
 pub mod spinner;
+pub mod write_guard;
 
-pub use spinner::*;
\ No newline at end of file
+pub use spinner::*;
+pub use write_guard::confirm_protected_write;
\ No newline at end of file
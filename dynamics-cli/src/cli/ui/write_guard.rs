@@ -0,0 +1,44 @@
+//! Confirmation prompt for writes against protected environments
+
+use anyhow::Result;
+use colored::*;
+use dialoguer::Confirm;
+use is_terminal::IsTerminal;
+
+use crate::config::protection::check_environment_write;
+
+/// Ensure a write against `env_name` is allowed to proceed
+///
+/// Protected environments prompt for confirmation unless `yes` is set.
+/// In a non-interactive session the write is refused instead of prompting.
+pub async fn confirm_protected_write(env_name: &str, action: &str, yes: bool) -> Result<()> {
+    let guard = check_environment_write(env_name, yes).await?;
+    if !guard.requires_confirmation() {
+        return Ok(());
+    }
+
+    if !std::io::stdin().is_terminal() {
+        anyhow::bail!(
+            "Environment '{}' is protected. Re-run with --yes to {} without confirmation.",
+            env_name,
+            action
+        );
+    }
+
+    eprintln!(
+        "{} Environment '{}' is protected",
+        "⚠️".bright_yellow().bold(),
+        env_name.bright_red().bold()
+    );
+
+    let confirmed = Confirm::new()
+        .with_prompt(format!("Really {} in '{}'?", action, env_name))
+        .default(false)
+        .interact()?;
+
+    if !confirmed {
+        anyhow::bail!("Cancelled write to protected environment '{}'", env_name);
+    }
+
+    Ok(())
+}
@@ -5,9 +5,11 @@ use sqlx::SqlitePool;
 use std::path::Path;
 use std::collections::HashMap;
 use serde::{Deserialize, Serialize};
+use crate::config::encryption::CredentialCipher;
+use crate::config::repository::credentials;
 
 /// Export SQLite database to TOML format
-pub async fn export_to_toml(pool: &SqlitePool, path: &Path) -> Result<()> {
+pub async fn export_to_toml(pool: &SqlitePool, cipher: &CredentialCipher, path: &Path) -> Result<()> {
     log::info!("Exporting database to TOML: {:?}", path);
 
     let mut config = ExportConfig::default();
@@ -18,7 +20,7 @@ pub async fn export_to_toml(pool: &SqlitePool, path: &Path) -> Result<()> {
     }
 
     // Export environments and credentials
-    let environments = get_all_environments_with_credentials(pool).await?;
+    let environments = get_all_environments_with_credentials(pool, cipher).await?;
     for (env_name, host, username, password, client_id, client_secret) in environments {
         config.environments.insert(env_name, ExportAuthConfig {
             host,
@@ -173,6 +175,7 @@ async fn get_current_environment(pool: &SqlitePool) -> Result<Option<String>> {
 
 async fn get_all_environments_with_credentials(
     pool: &SqlitePool,
+    cipher: &CredentialCipher,
 ) -> Result<Vec<(String, String, String, String, String, String)>> {
     let rows: Vec<(String, String, String)> = sqlx::query_as(
         r#"
@@ -189,8 +192,8 @@ async fn get_all_environments_with_credentials(
 
     let mut result = Vec::new();
     for (env_name, host, credential_data) in rows {
-        let cred_data: crate::config::models::CredentialData = serde_json::from_str(&credential_data)
-            .context("Failed to parse credential data")?;
+        let cred_data = credentials::decode(cipher, &credential_data)
+            .with_context(|| format!("Failed to read credentials for environment '{}'", env_name))?;
 
         if let crate::config::models::CredentialData::UsernamePassword {
             username,
@@ -461,4 +464,38 @@ async fn import_config_data(pool: &SqlitePool, config: ExportConfig) -> Result<(
 
     tx.commit().await.context("Failed to commit import transaction")?;
     Ok(())
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::models::CredentialSet;
+    use crate::config::db;
+
+    #[tokio::test]
+    async fn test_export_decrypts_stored_credentials() {
+        let pool = db::connect_memory().await.unwrap();
+        db::run_migrations(&pool).await.unwrap();
+        let cipher = CredentialCipher::generate().unwrap();
+
+        let credentials = CredentialSet::UsernamePassword {
+            username: "user".to_string(),
+            password: "s3cret".to_string(),
+            client_id: "client".to_string(),
+            client_secret: "secret".to_string(),
+        };
+        credentials::insert(&pool, &cipher, "creds".to_string(), credentials).await.unwrap();
+        sqlx::query("INSERT INTO environments (name, host, credentials_ref) VALUES ('dev', 'https://dev.crm.dynamics.com', 'creds')")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let path = std::env::temp_dir().join(format!("export_test_{}.toml", uuid::Uuid::new_v4()));
+        export_to_toml(&pool, &cipher, &path).await.unwrap();
+        let exported: ExportConfig = toml::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let environment = &exported.environments["dev"];
+        assert_eq!(environment.username, "user");
+        assert_eq!(environment.password, "s3cret");
+    }
+}
@@ -0,0 +1,144 @@
+//! Encryption of stored credentials
+//!
+//! Credential JSON is sealed with AES-256-GCM before it is written to SQLite.
+//! The key is generated on first use and kept in the OS secret store (Keychain,
+//! Windows Credential Manager, Secret Service), never in the database itself.
+
+use anyhow::{Context, Result};
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use openssl::symm::{Cipher, decrypt_aead, encrypt_aead};
+
+const KEYRING_SERVICE: &str = "dynamics-cli";
+const KEYRING_USER: &str = "credential-encryption-key";
+
+/// Marks a stored value as encrypted; anything else is legacy plaintext JSON
+const ENCRYPTED_PREFIX: &str = "enc:v1:";
+
+const KEY_LEN: usize = 32;
+const NONCE_LEN: usize = 12;
+const TAG_LEN: usize = 16;
+
+/// AES-256-GCM cipher for credential data
+pub struct CredentialCipher {
+    key: [u8; KEY_LEN],
+}
+
+impl CredentialCipher {
+    /// Load the key from the OS keyring, creating it on first use
+    pub async fn from_keyring() -> Result<Self> {
+        tokio::task::spawn_blocking(Self::load_or_create_key)
+            .await?
+            .context(
+                "Couldn't access the OS keyring, which holds the key for stored credentials \
+                 (on Linux a Secret Service provider such as gnome-keyring must be running)",
+            )
+    }
+
+    fn load_or_create_key() -> Result<Self> {
+        let entry = keyring::Entry::new(KEYRING_SERVICE, KEYRING_USER)?;
+
+        match entry.get_password() {
+            Ok(encoded) => {
+                let key: [u8; KEY_LEN] = STANDARD
+                    .decode(encoded)
+                    .ok()
+                    .and_then(|bytes| bytes.try_into().ok())
+                    .context("Credential encryption key in the OS keyring is malformed")?;
+                Ok(Self { key })
+            }
+            Err(keyring::Error::NoEntry) => {
+                let cipher = Self::generate()?;
+                entry.set_password(&STANDARD.encode(cipher.key))?;
+                log::info!("Created credential encryption key in the OS keyring");
+                Ok(cipher)
+            }
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// A cipher with a fresh random key
+    pub fn generate() -> Result<Self> {
+        let mut key = [0u8; KEY_LEN];
+        openssl::rand::rand_bytes(&mut key)?;
+        Ok(Self { key })
+    }
+
+    /// Whether a stored value was written by `encrypt`
+    pub fn is_encrypted(stored: &str) -> bool {
+        stored.starts_with(ENCRYPTED_PREFIX)
+    }
+
+    /// Encrypt to `enc:v1:` followed by base64 of nonce, ciphertext and tag
+    pub fn encrypt(&self, plaintext: &str) -> Result<String> {
+        let mut nonce = [0u8; NONCE_LEN];
+        openssl::rand::rand_bytes(&mut nonce)?;
+
+        let mut tag = [0u8; TAG_LEN];
+        let ciphertext = encrypt_aead(
+            Cipher::aes_256_gcm(),
+            &self.key,
+            Some(&nonce),
+            &[],
+            plaintext.as_bytes(),
+            &mut tag,
+        )?;
+
+        let mut sealed = Vec::with_capacity(NONCE_LEN + ciphertext.len() + TAG_LEN);
+        sealed.extend_from_slice(&nonce);
+        sealed.extend_from_slice(&ciphertext);
+        sealed.extend_from_slice(&tag);
+
+        Ok(format!("{}{}", ENCRYPTED_PREFIX, STANDARD.encode(sealed)))
+    }
+
+    /// Decrypt a value written by `encrypt`
+    pub fn decrypt(&self, stored: &str) -> Result<String> {
+        let encoded = stored
+            .strip_prefix(ENCRYPTED_PREFIX)
+            .context("Credential data is not encrypted")?;
+        let sealed = STANDARD.decode(encoded).context("Encrypted credential data is malformed")?;
+        if sealed.len() < NONCE_LEN + TAG_LEN {
+            anyhow::bail!("Encrypted credential data is truncated");
+        }
+
+        let (nonce, rest) = sealed.split_at(NONCE_LEN);
+        let (ciphertext, tag) = rest.split_at(rest.len() - TAG_LEN);
+        let plaintext = decrypt_aead(Cipher::aes_256_gcm(), &self.key, Some(nonce), &[], ciphertext, tag)
+            .context("Failed to decrypt credential data (was the keyring entry replaced?)")?;
+
+        String::from_utf8(plaintext).context("Decrypted credential data is not valid UTF-8")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip_uses_fresh_nonces() {
+        let cipher = CredentialCipher::generate().unwrap();
+        let plaintext = r#"{"type":"client_credentials","client_secret":"s3cret"}"#;
+
+        let first = cipher.encrypt(plaintext).unwrap();
+        let second = cipher.encrypt(plaintext).unwrap();
+
+        assert!(CredentialCipher::is_encrypted(&first));
+        assert!(!first.contains("s3cret"));
+        assert_ne!(first, second);
+        assert_eq!(cipher.decrypt(&first).unwrap(), plaintext);
+    }
+
+    #[test]
+    fn test_wrong_key_or_tampering_fails() {
+        let cipher = CredentialCipher::generate().unwrap();
+        let sealed = cipher.encrypt("secret").unwrap();
+
+        assert!(CredentialCipher::generate().unwrap().decrypt(&sealed).is_err());
+
+        let mut tampered = sealed.into_bytes();
+        let last = tampered.len() - 3;
+        tampered[last] = if tampered[last] == b'A' { b'B' } else { b'A' };
+        assert!(cipher.decrypt(&String::from_utf8(tampered).unwrap()).is_err());
+    }
+}
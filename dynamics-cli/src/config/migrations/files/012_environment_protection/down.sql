@@ -0,0 +1,2 @@
+-- Revert environment protection flag
+ALTER TABLE environments DROP COLUMN is_protected;
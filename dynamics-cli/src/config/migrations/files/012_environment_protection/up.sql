@@ -0,0 +1,2 @@
+-- Protected environments require confirmation before any write operation
+ALTER TABLE environments ADD COLUMN is_protected BOOLEAN NOT NULL DEFAULT FALSE;
@@ -0,0 +1,2 @@
+-- Drop entity set name overrides
+DROP TABLE IF EXISTS entity_set_overrides;
@@ -0,0 +1,6 @@
+-- Entity set names that the pluralization rules get wrong (e.g. new_status -> new_statuslist)
+CREATE TABLE IF NOT EXISTS entity_set_overrides (
+    entity_name TEXT PRIMARY KEY,
+    entity_set_name TEXT NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
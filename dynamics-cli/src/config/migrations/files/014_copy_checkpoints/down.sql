@@ -0,0 +1,2 @@
+-- Drop questionnaire copy checkpoints
+DROP TABLE IF EXISTS copy_checkpoints;
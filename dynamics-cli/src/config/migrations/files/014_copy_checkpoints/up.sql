@@ -0,0 +1,12 @@
+-- Progress of questionnaire copies, saved after each completed step so a failed copy can resume
+CREATE TABLE IF NOT EXISTS copy_checkpoints (
+    questionnaire_id TEXT PRIMARY KEY,
+    copy_name TEXT NOT NULL,
+    copy_code TEXT NOT NULL,
+    completed_step INTEGER NOT NULL,
+    id_map TEXT NOT NULL,                 -- JSON object: source ID -> created ID
+    created_ids TEXT NOT NULL,            -- JSON array of [entity_set, id], in creation order
+    partial_ids TEXT NOT NULL DEFAULT '[]', -- JSON array of [entity_set, id] created by the step that failed
+    classifications_associated INTEGER NOT NULL DEFAULT 0,
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
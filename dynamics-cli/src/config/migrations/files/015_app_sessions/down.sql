@@ -0,0 +1,2 @@
+-- Drop saved app sessions
+DROP TABLE IF EXISTS app_sessions;
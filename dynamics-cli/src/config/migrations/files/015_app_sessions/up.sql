@@ -0,0 +1,8 @@
+-- Resumable app state, saved when an app is suspended or closed and restored when it is relaunched with the same params
+CREATE TABLE IF NOT EXISTS app_sessions (
+    app TEXT NOT NULL,
+    session_key TEXT NOT NULL,            -- Identifies the params the app was launched with
+    state TEXT NOT NULL,                  -- JSON, shape owned by the app
+    saved_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (app, session_key)
+);
@@ -0,0 +1,2 @@
+-- Drop the per-kind metadata cache
+DROP TABLE IF EXISTS metadata_cache;
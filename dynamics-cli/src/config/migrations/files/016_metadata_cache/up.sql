@@ -0,0 +1,9 @@
+-- Fields, forms and views fetched for an entity, cached separately so each can expire and refresh on its own
+CREATE TABLE IF NOT EXISTS metadata_cache (
+    environment_name TEXT NOT NULL,
+    entity_name TEXT NOT NULL,
+    kind TEXT NOT NULL,                   -- 'fields', 'forms' or 'views'
+    data TEXT NOT NULL,                   -- JSON array, as returned by the API client
+    cached_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (environment_name, entity_name, kind)
+);
@@ -0,0 +1,2 @@
+-- Drop saved queries
+DROP TABLE IF EXISTS saved_queries;
@@ -0,0 +1,7 @@
+-- FQL queries saved by name, to re-run from the query command or the TUI
+CREATE TABLE IF NOT EXISTS saved_queries (
+    name TEXT PRIMARY KEY,
+    fql TEXT NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    last_used TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP  -- Updated on every run, lists are sorted by it
+);
@@ -0,0 +1,3 @@
+-- Drop failed queue operations
+DROP INDEX IF EXISTS idx_failed_operations_run_id;
+DROP TABLE IF EXISTS failed_operations;
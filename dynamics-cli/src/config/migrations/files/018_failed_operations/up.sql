@@ -0,0 +1,14 @@
+-- Operations that failed in a queue run, kept so only they can be retried
+CREATE TABLE failed_operations (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    run_id TEXT NOT NULL,                 -- ID of the queue item whose run failed
+    environment_name TEXT NOT NULL,
+    description TEXT NOT NULL,
+    operation_json TEXT NOT NULL,         -- JSON serialized Operation
+    error TEXT NOT NULL,                  -- Error message, including the response body
+    status_code INTEGER,
+    failed_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (environment_name) REFERENCES environments(name) ON DELETE CASCADE
+);
+
+CREATE INDEX idx_failed_operations_run_id ON failed_operations(run_id);
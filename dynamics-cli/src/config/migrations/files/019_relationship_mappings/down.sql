@@ -0,0 +1,3 @@
+-- Drop relationship mappings
+DROP INDEX IF EXISTS idx_relationship_mappings_source_lookup;
+DROP TABLE IF EXISTS relationship_mappings;
@@ -0,0 +1,13 @@
+-- Manual relationship mappings for entity comparison, kept apart from field mappings
+CREATE TABLE relationship_mappings (
+    id INTEGER PRIMARY KEY,
+    source_entity TEXT NOT NULL,
+    target_entity TEXT NOT NULL,
+    source_relationship TEXT NOT NULL,    -- Relationship schema name on the source entity
+    target_relationship TEXT NOT NULL,    -- Relationship schema name on the target entity
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE(source_entity, target_entity, source_relationship, target_relationship)
+);
+
+CREATE INDEX idx_relationship_mappings_source_lookup
+ON relationship_mappings(source_entity, target_entity, source_relationship);
@@ -52,9 +52,6 @@ impl<'a> MigrationManager<'a> {
         self.init().await?;
         validate_migrations(self.pool).await?;
 
-        let applied = get_applied_migrations(self.pool).await?;
-        let available = load_migrations()?;
-
         let target = target_version.unwrap_or(0);
         let current = get_current_version(self.pool).await?.unwrap_or(0);
 
@@ -63,10 +60,75 @@ impl<'a> MigrationManager<'a> {
             return Ok(());
         }
 
-        // Get migrations to rollback (in reverse order)
+        let to_rollback = self.rollback_plan(target).await?;
+        if to_rollback.is_empty() {
+            info!("No migrations to rollback");
+            return Ok(());
+        }
+
+        info!("Rolling back {} migrations to version {}", to_rollback.len(), target);
+        for migration in to_rollback {
+            self.apply_migration(&migration, Direction::Down).await?;
+        }
+
+        info!("Rollback completed successfully");
+        Ok(())
+    }
+
+    /// Roll back every migration above `target_version` in a single transaction
+    ///
+    /// Migrations are undone newest first; if any `down_sql` fails, none of them
+    /// are. Version 1 is the initial schema and can't be rolled back. Returns the
+    /// migrations that were rolled back.
+    pub async fn migrate_down_to(&self, target_version: i64) -> Result<Vec<Migration>> {
+        if target_version < 1 {
+            anyhow::bail!("Cannot roll back below version 1 (the initial schema)");
+        }
+
+        self.init().await?;
+        validate_migrations(self.pool).await?;
+
+        let to_rollback = self.rollback_plan(target_version).await?;
+        if to_rollback.is_empty() {
+            info!("Already at or below target version {}", target_version);
+            return Ok(to_rollback);
+        }
+
+        let mut tx = self.pool.begin().await.context("Failed to start rollback transaction")?;
+
+        for migration in &to_rollback {
+            info!("Rolling back migration {} '{}'", migration.version, migration.name);
+
+            if migration.down_sql.trim().is_empty() {
+                warn!("Migration {} has empty down SQL, only removing its record", migration.version);
+            } else {
+                sqlx::query(&migration.down_sql)
+                    .execute(&mut *tx)
+                    .await
+                    .with_context(|| format!("Failed to execute migration {} down SQL", migration.version))?;
+            }
+
+            sqlx::query("DELETE FROM schema_migrations WHERE version = ?")
+                .bind(migration.version)
+                .execute(&mut *tx)
+                .await
+                .context("Failed to remove migration record")?;
+        }
+
+        tx.commit().await.context("Failed to commit rollback transaction")?;
+
+        info!("Rolled back {} migrations to version {}", to_rollback.len(), target_version);
+        Ok(to_rollback)
+    }
+
+    /// Applied migrations above `target_version`, newest first
+    pub async fn rollback_plan(&self, target_version: i64) -> Result<Vec<Migration>> {
+        let applied = get_applied_migrations(self.pool).await?;
+        let available = load_migrations()?;
+
         let mut to_rollback = Vec::new();
         for applied_migration in applied.into_iter().rev() {
-            if applied_migration.version > target {
+            if applied_migration.version > target_version {
                 if let Some(migration) = available.get(&applied_migration.version) {
                     to_rollback.push(migration.clone());
                 } else {
@@ -78,18 +140,7 @@ impl<'a> MigrationManager<'a> {
             }
         }
 
-        if to_rollback.is_empty() {
-            info!("No migrations to rollback");
-            return Ok(());
-        }
-
-        info!("Rolling back {} migrations to version {}", to_rollback.len(), target);
-        for migration in to_rollback {
-            self.apply_migration(&migration, Direction::Down).await?;
-        }
-
-        info!("Rollback completed successfully");
-        Ok(())
+        Ok(to_rollback)
     }
 
     /// Apply a single migration in the specified direction
@@ -248,4 +299,56 @@ impl MigrationStatus {
             }
         }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+
+    #[tokio::test]
+    async fn test_migrate_down_to_rolls_back_newest_first() {
+        let config = Config::new_test().await.unwrap();
+        let manager = MigrationManager::new(&config.pool);
+        let latest = get_current_version(&config.pool).await.unwrap().unwrap();
+
+        let rolled_back = manager.migrate_down_to(latest - 2).await.unwrap();
+
+        let versions: Vec<i64> = rolled_back.iter().map(|m| m.version).collect();
+        assert_eq!(versions, vec![latest, latest - 1]);
+        assert_eq!(get_current_version(&config.pool).await.unwrap(), Some(latest - 2));
+        assert_eq!(get_pending_migrations(&config.pool).await.unwrap().len(), 2);
+
+        // Already there: nothing to do
+        assert!(manager.migrate_down_to(latest - 2).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_every_down_migration_reverses_its_up() {
+        let config = Config::new_test().await.unwrap();
+        let manager = MigrationManager::new(&config.pool);
+        let latest = get_current_version(&config.pool).await.unwrap();
+
+        manager.migrate_down_to(1).await.unwrap();
+        assert_eq!(get_current_version(&config.pool).await.unwrap(), Some(1));
+
+        manager.migrate_up().await.unwrap();
+        assert_eq!(get_current_version(&config.pool).await.unwrap(), latest);
+    }
+
+    #[tokio::test]
+    async fn test_migrate_down_to_refuses_unsafe_rollbacks() {
+        let config = Config::new_test().await.unwrap();
+        let manager = MigrationManager::new(&config.pool);
+        let latest = get_current_version(&config.pool).await.unwrap();
+
+        assert!(manager.migrate_down_to(0).await.is_err());
+
+        sqlx::query("UPDATE schema_migrations SET checksum = 'tampered' WHERE version = 2")
+            .execute(&config.pool)
+            .await
+            .unwrap();
+        assert!(manager.migrate_down_to(1).await.is_err());
+        assert_eq!(get_current_version(&config.pool).await.unwrap(), latest);
+    }
+}
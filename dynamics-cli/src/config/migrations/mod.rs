@@ -26,6 +26,36 @@ pub struct AppliedMigration {
     pub checksum: String,
 }
 
+/// An applied migration that no longer matches the embedded migration files
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MigrationProblem {
+    /// The migration file changed after it was applied
+    ChecksumMismatch {
+        version: i64,
+        applied: String,
+        expected: String,
+        legacy: String,
+    },
+    /// The database has a migration this build doesn't know about
+    Unknown { version: i64, name: String },
+}
+
+impl std::fmt::Display for MigrationProblem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MigrationProblem::ChecksumMismatch { version, applied, expected, legacy } => write!(
+                f,
+                "Migration {} checksum mismatch! Applied: {}, Expected: {} (or legacy: {}). \
+                This indicates the migration file has been modified after being applied.",
+                version, applied, expected, legacy
+            ),
+            MigrationProblem::Unknown { version, name } => {
+                write!(f, "Applied migration {} '{}' not found in available migrations", version, name)
+            }
+        }
+    }
+}
+
 /// Direction for migration operations
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Direction {
@@ -154,8 +184,17 @@ fn calculate_checksum_legacy(sql: &str) -> String {
 
 /// Validate that applied migrations match available ones
 pub async fn validate_migrations(pool: &SqlitePool) -> Result<()> {
+    match find_migration_problems(pool).await?.into_iter().next() {
+        Some(problem) => anyhow::bail!("{}", problem),
+        None => Ok(()),
+    }
+}
+
+/// Check every applied migration against the available ones
+pub async fn find_migration_problems(pool: &SqlitePool) -> Result<Vec<MigrationProblem>> {
     let available = load_migrations()?;
     let applied = get_applied_migrations(pool).await?;
+    let mut problems = Vec::new();
 
     for applied_migration in applied {
         if let Some(available_migration) = available.get(&applied_migration.version) {
@@ -167,25 +206,22 @@ pub async fn validate_migrations(pool: &SqlitePool) -> Result<()> {
                 || applied_migration.checksum == legacy_checksum;
 
             if !is_valid {
-                anyhow::bail!(
-                    "Migration {} checksum mismatch! Applied: {}, Expected: {} (or legacy: {}). \
-                    This indicates the migration file has been modified after being applied.",
-                    applied_migration.version,
-                    applied_migration.checksum,
-                    expected_checksum,
-                    legacy_checksum
-                );
+                problems.push(MigrationProblem::ChecksumMismatch {
+                    version: applied_migration.version,
+                    applied: applied_migration.checksum,
+                    expected: expected_checksum,
+                    legacy: legacy_checksum,
+                });
             }
         } else {
-            anyhow::bail!(
-                "Applied migration {} '{}' not found in available migrations",
-                applied_migration.version,
-                applied_migration.name
-            );
+            problems.push(MigrationProblem::Unknown {
+                version: applied_migration.version,
+                name: applied_migration.name,
+            });
         }
     }
 
-    Ok(())
+    Ok(problems)
 }
 
 /// Get pending migrations (available but not applied)
@@ -240,6 +276,28 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_find_migration_problems() {
+        let pool = crate::config::db::connect_memory().await.unwrap();
+        crate::config::db::run_migrations(&pool).await.unwrap();
+        assert!(find_migration_problems(&pool).await.unwrap().is_empty());
+
+        sqlx::query("UPDATE schema_migrations SET checksum = 'tampered' WHERE version = 2")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query("INSERT INTO schema_migrations (version, name, checksum) VALUES (999, 'from_the_future', 'x')")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let problems = find_migration_problems(&pool).await.unwrap();
+        assert_eq!(problems.len(), 2);
+        assert!(matches!(&problems[0], MigrationProblem::ChecksumMismatch { version: 2, applied, .. } if applied == "tampered"));
+        assert_eq!(problems[1], MigrationProblem::Unknown { version: 999, name: "from_the_future".to_string() });
+        assert!(validate_migrations(&pool).await.is_err());
+    }
+
     #[test]
     fn test_calculate_checksum() {
         let sql = "CREATE TABLE test (id INTEGER);";
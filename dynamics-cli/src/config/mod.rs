@@ -17,10 +17,15 @@ pub mod repository;
 pub mod migrations;
 pub mod migration;
 pub mod compat;
+pub mod encryption;
 pub mod options;
+pub mod protection;
 
 pub use models::*;
 pub use repository::migrations::{SavedMigration, SavedComparison};
+pub use repository::saved_queries::SavedQuery;
+pub use repository::failed_operations::{FailedOperation, FailedRun};
+pub use repository::mappings::MappingChanges;
 
 use crate::api::models::{Environment as ApiEnvironment, CredentialSet as ApiCredentialSet};
 
@@ -31,6 +36,9 @@ pub struct Config {
 
     /// Options system for type-safe settings
     pub options: options::Options,
+
+    /// Key for stored credentials, fetched from the OS keyring on first use
+    credential_cipher: tokio::sync::OnceCell<encryption::CredentialCipher>,
 }
 
 impl Config {
@@ -84,6 +92,7 @@ impl Config {
             pool,
             config_path: db_path,
             options,
+            credential_cipher: tokio::sync::OnceCell::new(),
         };
 
         // Print migration message only if migration actually happened
@@ -108,16 +117,37 @@ impl Config {
             pool,
             config_path: PathBuf::from(":memory:"),
             options,
+            // Tests must not touch the real keyring
+            credential_cipher: tokio::sync::OnceCell::new_with(Some(encryption::CredentialCipher::generate()?)),
         })
     }
 
+    /// The credential cipher, encrypting any leftover plaintext rows the first time
+    async fn credential_cipher(&self) -> Result<&encryption::CredentialCipher> {
+        self.credential_cipher
+            .get_or_try_init(|| async {
+                let cipher = encryption::CredentialCipher::from_keyring().await?;
+                repository::credentials::encrypt_plaintext(&self.pool, &cipher).await?;
+                Ok(cipher)
+            })
+            .await
+    }
+
     // Credential management methods
     pub async fn add_credentials(&self, name: String, credentials: ApiCredentialSet) -> Result<()> {
-        repository::credentials::insert(&self.pool, name, credentials).await
+        let cipher = self.credential_cipher().await?;
+        repository::credentials::insert(&self.pool, cipher, name, credentials).await
     }
 
     pub async fn get_credentials(&self, name: &str) -> Result<Option<ApiCredentialSet>> {
-        repository::credentials::get(&self.pool, name).await
+        let cipher = self.credential_cipher().await?;
+        repository::credentials::get(&self.pool, cipher, name).await
+    }
+
+    /// Every credential set, decrypted (for `auth creds list --export-plaintext`)
+    pub async fn export_credentials_plaintext(&self) -> Result<Vec<(String, CredentialData)>> {
+        let cipher = self.credential_cipher().await?;
+        repository::credentials::list_decrypted(&self.pool, cipher).await
     }
 
     pub async fn list_credentials(&self) -> Result<Vec<String>> {
@@ -134,7 +164,9 @@ impl Config {
 
     // Environment management methods
     pub async fn add_environment(&self, environment: ApiEnvironment) -> Result<()> {
-        repository::environments::insert(&self.pool, environment).await
+        let pattern = self.options.get_string(protection::PROTECTED_PATTERN_OPTION).await?;
+        let protect = protection::matches_protected_pattern(&environment.name, &pattern);
+        repository::environments::insert(&self.pool, environment, protect).await
     }
 
     pub async fn get_environment(&self, name: &str) -> Result<Option<ApiEnvironment>> {
@@ -153,6 +185,18 @@ impl Config {
         repository::environments::rename(&self.pool, old_name, new_name).await
     }
 
+    pub async fn is_environment_protected(&self, name: &str) -> Result<bool> {
+        repository::environments::is_protected(&self.pool, name).await
+    }
+
+    pub async fn list_protected_environments(&self) -> Result<Vec<String>> {
+        repository::environments::list_protected(&self.pool).await
+    }
+
+    pub async fn set_environment_protected(&self, name: &str, protected: bool) -> Result<()> {
+        repository::environments::set_protected(&self.pool, name, protected).await
+    }
+
     pub async fn get_current_environment(&self) -> Result<Option<String>> {
         repository::environments::get_current(&self.pool).await
     }
@@ -191,6 +235,45 @@ impl Config {
         repository::legacy::delete_entity_mapping(&self.pool, singular).await
     }
 
+    // Entity set name overrides (consulted before pluralization rules)
+    pub async fn set_entity_set_override(&self, entity_name: &str, entity_set_name: &str) -> Result<()> {
+        repository::entity_set_overrides::set(&self.pool, entity_name, entity_set_name).await
+    }
+
+    pub async fn list_entity_set_overrides(&self) -> Result<Vec<(String, String)>> {
+        repository::entity_set_overrides::list(&self.pool).await
+    }
+
+    pub async fn load_entity_set_overrides(&self) -> Result<std::collections::HashMap<String, String>> {
+        repository::entity_set_overrides::load_map(&self.pool).await
+    }
+
+    pub async fn delete_entity_set_override(&self, entity_name: &str) -> Result<()> {
+        repository::entity_set_overrides::delete(&self.pool, entity_name).await
+    }
+
+    // Questionnaire copy checkpoints (resume a failed copy)
+    pub async fn save_copy_checkpoint(&self, checkpoint: &repository::copy_checkpoints::CopyCheckpoint) -> Result<()> {
+        repository::copy_checkpoints::save(&self.pool, checkpoint).await
+    }
+
+    pub async fn get_copy_checkpoint(&self, questionnaire_id: &str) -> Result<Option<repository::copy_checkpoints::CopyCheckpoint>> {
+        repository::copy_checkpoints::get(&self.pool, questionnaire_id).await
+    }
+
+    pub async fn delete_copy_checkpoint(&self, questionnaire_id: &str) -> Result<()> {
+        repository::copy_checkpoints::delete(&self.pool, questionnaire_id).await
+    }
+
+    // App sessions (resume an app where it was left)
+    pub async fn save_app_session(&self, app: &str, session_key: &str, state: &serde_json::Value) -> Result<()> {
+        repository::app_sessions::save(&self.pool, app, session_key, state).await
+    }
+
+    pub async fn get_app_session(&self, app: &str, session_key: &str, max_age: std::time::Duration) -> Result<Option<serde_json::Value>> {
+        repository::app_sessions::get(&self.pool, app, session_key, max_age).await
+    }
+
     // Settings methods
     pub async fn get_setting(&self, key: &str) -> Result<Option<String>> {
         repository::legacy::get_setting(&self.pool, key).await
@@ -212,7 +295,8 @@ impl Config {
 
     // Export to TOML for debugging/backup
     pub async fn export_toml(&self, path: &std::path::Path) -> Result<()> {
-        compat::export_to_toml(&self.pool, path).await
+        let cipher = self.credential_cipher().await?;
+        compat::export_to_toml(&self.pool, cipher, path).await
     }
 
     // Import from TOML (for migration or restore)
@@ -241,6 +325,27 @@ impl Config {
         repository::migrations::touch(&self.pool, name).await
     }
 
+    // Saved query methods
+    pub async fn add_saved_query(&self, name: &str, fql: &str) -> Result<()> {
+        repository::saved_queries::insert(&self.pool, name, fql).await
+    }
+
+    pub async fn get_saved_query(&self, name: &str) -> Result<Option<SavedQuery>> {
+        repository::saved_queries::get(&self.pool, name).await
+    }
+
+    pub async fn list_saved_queries(&self) -> Result<Vec<SavedQuery>> {
+        repository::saved_queries::list(&self.pool).await
+    }
+
+    pub async fn delete_saved_query(&self, name: &str) -> Result<()> {
+        repository::saved_queries::delete(&self.pool, name).await
+    }
+
+    pub async fn touch_saved_query(&self, name: &str) -> Result<()> {
+        repository::saved_queries::touch(&self.pool, name).await
+    }
+
     // Comparison management methods
     pub async fn add_comparison(&self, comparison: SavedComparison) -> Result<i64> {
         repository::migrations::insert_comparison(&self.pool, comparison).await
@@ -310,6 +415,31 @@ impl Config {
         repository::entity_metadata_cache::delete_all_for_environment(&self.pool, environment_name).await
     }
 
+    // Per-kind metadata cache (fields, forms, views)
+    pub async fn get_metadata_cache<T: serde::de::DeserializeOwned>(
+        &self,
+        environment_name: &str,
+        entity_name: &str,
+        kind: repository::metadata_cache::MetadataKind,
+        max_age: std::time::Duration,
+    ) -> Result<Option<T>> {
+        repository::metadata_cache::get(&self.pool, environment_name, entity_name, kind, max_age).await
+    }
+
+    pub async fn set_metadata_cache<T: serde::Serialize>(
+        &self,
+        environment_name: &str,
+        entity_name: &str,
+        kind: repository::metadata_cache::MetadataKind,
+        data: &T,
+    ) -> Result<()> {
+        repository::metadata_cache::set(&self.pool, environment_name, entity_name, kind, data).await
+    }
+
+    pub async fn clear_metadata_cache(&self) -> Result<u64> {
+        repository::metadata_cache::clear(&self.pool).await
+    }
+
     // Entity data cache methods
     pub async fn get_entity_data_cache(
         &self,
@@ -368,6 +498,10 @@ impl Config {
         repository::mappings::delete_prefix_mapping(&self.pool, source_entity, target_entity, source_prefix).await
     }
 
+    pub async fn get_relationship_mappings(&self, source_entity: &str, target_entity: &str) -> Result<std::collections::HashMap<String, Vec<String>>> {
+        repository::mappings::get_relationship_mappings(&self.pool, source_entity, target_entity).await
+    }
+
     pub async fn get_imported_mappings(&self, source_entity: &str, target_entity: &str) -> Result<(std::collections::HashMap<String, Vec<String>>, Option<String>)> {
         repository::mappings::get_imported_mappings(&self.pool, source_entity, target_entity).await
     }
@@ -390,6 +524,11 @@ impl Config {
         repository::mappings::set_ignored_items(&self.pool, source_entity, target_entity, ignored).await
     }
 
+    /// Save a batch of mapping edits in one transaction
+    pub async fn save_mapping_changes(&self, source_entity: &str, target_entity: &str, changes: &MappingChanges) -> Result<()> {
+        repository::mappings::save_mapping_changes(&self.pool, source_entity, target_entity, changes).await
+    }
+
     /// Clear all ignored items for entity comparison
     pub async fn clear_ignored_items(&self, source_entity: &str, target_entity: &str) -> Result<()> {
         repository::mappings::clear_ignored_items(&self.pool, source_entity, target_entity).await
@@ -458,4 +597,23 @@ impl Config {
     pub async fn save_queue_settings(&self, settings: &repository::queue::QueueSettings) -> Result<()> {
         repository::queue::save_queue_settings(&self.pool, settings).await
     }
+
+    // Failed operation methods
+    pub async fn record_failed_operations(
+        &self,
+        run_id: &str,
+        environment_name: &str,
+        description: &str,
+        results: &[crate::api::operations::OperationResult],
+    ) -> Result<()> {
+        repository::failed_operations::record_run(&self.pool, run_id, environment_name, description, results).await
+    }
+
+    pub async fn list_failed_operations(&self, run_id: &str) -> Result<Vec<FailedOperation>> {
+        repository::failed_operations::list_for_run(&self.pool, run_id).await
+    }
+
+    pub async fn list_failed_runs(&self) -> Result<Vec<FailedRun>> {
+        repository::failed_operations::list_runs(&self.pool).await
+    }
 }
\ No newline at end of file
@@ -21,6 +21,7 @@ pub struct DbEnvironment {
     pub host: String,
     pub credentials_ref: String,
     pub is_current: bool,
+    pub is_protected: bool,
     pub created_at: chrono::DateTime<chrono::Utc>,
     pub updated_at: chrono::DateTime<chrono::Utc>,
 }
@@ -75,6 +76,28 @@ pub struct DbMigration {
     pub last_used: chrono::DateTime<chrono::Utc>,
 }
 
+/// Database representation of a saved FQL query
+#[derive(Debug, Clone, FromRow)]
+pub struct DbSavedQuery {
+    pub name: String,
+    pub fql: String,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub last_used: chrono::DateTime<chrono::Utc>,
+}
+
+/// Database representation of an operation that failed in a queue run
+#[derive(Debug, Clone, FromRow)]
+pub struct DbFailedOperation {
+    pub id: i64,
+    pub run_id: String,
+    pub environment_name: String,
+    pub description: String,
+    pub operation_json: String,
+    pub error: String,
+    pub status_code: Option<i64>,
+    pub failed_at: chrono::DateTime<chrono::Utc>,
+}
+
 /// Database representation of comparison
 #[derive(Debug, Clone, FromRow)]
 pub struct DbComparison {
@@ -143,6 +166,8 @@ pub enum CredentialData {
         client_id: String,
         tenant_id: String,
         cert_path: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        cert_password: Option<String>,
     },
 }
 
@@ -180,10 +205,12 @@ impl From<crate::api::models::CredentialSet> for CredentialData {
                 client_id,
                 tenant_id,
                 cert_path,
+                cert_password,
             } => CredentialData::Certificate {
                 client_id,
                 tenant_id,
                 cert_path,
+                cert_password,
             },
         }
     }
@@ -223,10 +250,12 @@ impl From<CredentialData> for crate::api::models::CredentialSet {
                 client_id,
                 tenant_id,
                 cert_path,
+                cert_password,
             } => crate::api::models::CredentialSet::Certificate {
                 client_id,
                 tenant_id,
                 cert_path,
+                cert_password,
             },
         }
     }
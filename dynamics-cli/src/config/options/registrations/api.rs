@@ -54,6 +54,14 @@ pub fn register(registry: &OptionsRegistry) -> Result<()> {
             .build()?
     )?;
 
+    registry.register(
+        OptionDefBuilder::new("api", "retry.max_retry_after_secs")
+            .display_name("Max Retry-After (s)")
+            .description("Longest server-requested Retry-After delay to honor when throttled (1-3600)")
+            .uint_type(300, Some(1), Some(3600))
+            .build()?
+    )?;
+
     // Rate limiting options
     registry.register(
         OptionDefBuilder::new("api", "rate_limit.enabled")
@@ -79,6 +87,65 @@ pub fn register(registry: &OptionsRegistry) -> Result<()> {
             .build()?
     )?;
 
+    registry.register(
+        OptionDefBuilder::new("api", "rate_limit.max_concurrent_fetches")
+            .display_name("Max Concurrent Fetches")
+            .description("Metadata requests sent at once when loading a comparison; the rest are queued (1-20)")
+            .uint_type(3, Some(1), Some(20))
+            .build()?
+    )?;
+
+    registry.register(
+        OptionDefBuilder::new("api", "rate_limit.environments")
+            .display_name("Per-Environment Rate Limits")
+            .description("Comma-separated environment=requests_per_minute/burst overrides, e.g. prod=60/5. Other environments use the limits above")
+            .string_type("", Some(1024))
+            .build()?
+    )?;
+
+    // Timeout options
+    registry.register(
+        OptionDefBuilder::new("api", "timeout.metadata_secs")
+            .display_name("Metadata Timeout (s)")
+            .description("Seconds before a metadata request (fields, forms, views) times out and is retried (5-600)")
+            .uint_type(30, Some(5), Some(600))
+            .build()?
+    )?;
+
+    registry.register(
+        OptionDefBuilder::new("api", "timeout.query_secs")
+            .display_name("Query Timeout (s)")
+            .description("Seconds before a query or record read times out and is retried (5-600)")
+            .uint_type(60, Some(5), Some(600))
+            .build()?
+    )?;
+
+    registry.register(
+        OptionDefBuilder::new("api", "timeout.write_secs")
+            .display_name("Write Timeout (s)")
+            .description("Seconds before a create, update, delete or $batch request times out and is retried (5-1800)")
+            .uint_type(120, Some(5), Some(1800))
+            .build()?
+    )?;
+
+    // Cache options
+    registry.register(
+        OptionDefBuilder::new("api", "cache.metadata_ttl_hours")
+            .display_name("Metadata Cache TTL (hours)")
+            .description("How long fetched fields, forms and views are reused before fetching again (0 disables the cache)")
+            .uint_type(12, Some(0), Some(24 * 30))
+            .build()?
+    )?;
+
+    // Compression options
+    registry.register(
+        OptionDefBuilder::new("api", "compression.enabled")
+            .display_name("Enable Compression")
+            .description("Request gzipped responses and gzip large request bodies (some proxies mishandle this)")
+            .bool_type(false)
+            .build()?
+    )?;
+
     // Monitoring options
     registry.register(
         OptionDefBuilder::new("api", "monitoring.correlation_ids")
@@ -115,6 +182,6 @@ pub fn register(registry: &OptionsRegistry) -> Result<()> {
             .build()?
     )?;
 
-    log::info!("Registered {} API options", 13);
+    log::info!("Registered {} API options", 20);
     Ok(())
 }
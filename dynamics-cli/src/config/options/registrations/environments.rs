@@ -0,0 +1,19 @@
+//! Environment-related options registration
+
+use crate::config::options::{OptionDefBuilder, OptionsRegistry};
+use anyhow::Result;
+
+/// Register all environment-related options
+pub fn register(registry: &OptionsRegistry) -> Result<()> {
+    // Write protection
+    registry.register(
+        OptionDefBuilder::new("environments", "protected_pattern")
+            .display_name("Protected Name Pattern")
+            .description("Regex (case-insensitive) for environment names that are flagged as protected when added. Empty disables auto-flagging")
+            .string_type("prod", Some(256))
+            .build()?
+    )?;
+
+    log::info!("Registered {} environment options", 1);
+    Ok(())
+}
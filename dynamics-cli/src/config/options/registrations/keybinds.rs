@@ -98,6 +98,14 @@ pub fn register(registry: &OptionsRegistry) -> Result<()> {
             .build()?
     )?;
 
+    registry.register(
+        OptionDefBuilder::new("keybind", "global.command_palette")
+            .display_name("Command Palette")
+            .description("Search and run actions from all running apps")
+            .keybind_type(KeyBinding::ctrl(KeyCode::Char('p')))
+            .build()?
+    )?;
+
     // Migration Environment app keybinds
     registry.register(
         OptionDefBuilder::new("keybind", "migration_env.create")
@@ -253,6 +261,22 @@ pub fn register(registry: &OptionsRegistry) -> Result<()> {
             .build()?
     )?;
 
+    registry.register(
+        OptionDefBuilder::new("keybind", "entity_comparison.undo")
+            .display_name("Undo")
+            .description("Undo the last mapping or ignore change")
+            .keybind_type(KeyBinding::ctrl(KeyCode::Char('z')))
+            .build()?
+    )?;
+
+    registry.register(
+        OptionDefBuilder::new("keybind", "entity_comparison.redo")
+            .display_name("Redo")
+            .description("Redo the last undone mapping or ignore change")
+            .keybind_type(KeyBinding::ctrl(KeyCode::Char('y')))
+            .build()?
+    )?;
+
     registry.register(
         OptionDefBuilder::new("keybind", "entity_comparison.toggle_technical_names")
             .display_name("Toggle Technical Names")
@@ -317,10 +341,34 @@ pub fn register(registry: &OptionsRegistry) -> Result<()> {
             .build()?
     )?;
 
+    registry.register(
+        OptionDefBuilder::new("keybind", "entity_comparison.next_unmatched")
+            .display_name("Next Unmatched")
+            .description("Select the next unmatched item in the source tree")
+            .keybind_type(KeyCode::Char('n'))
+            .build()?
+    )?;
+
+    registry.register(
+        OptionDefBuilder::new("keybind", "entity_comparison.prev_unmatched")
+            .display_name("Previous Unmatched")
+            .description("Select the previous unmatched item in the source tree")
+            .keybind_type(KeyCode::Char('N'))
+            .build()?
+    )?;
+
+    registry.register(
+        OptionDefBuilder::new("keybind", "entity_comparison.rerun_auto_match")
+            .display_name("Re-run Auto-Match")
+            .description("Match again with the current auto-match settings, keeping manual mappings")
+            .keybind_type(KeyCode::Char('A'))
+            .build()?
+    )?;
+
     registry.register(
         OptionDefBuilder::new("keybind", "entity_comparison.export")
-            .display_name("Export to Excel")
-            .description("Export comparison data to Excel file")
+            .display_name("Export Report")
+            .description("Export comparison data to an Excel or Markdown file")
             .keybind_type(KeyCode::F(10))
             .build()?
     )?;
@@ -1,6 +1,7 @@
 //! Registration of all application options
 
 pub mod api;
+pub mod environments;
 pub mod tui;
 pub mod themes;
 pub mod keybinds;
@@ -13,6 +14,7 @@ use anyhow::Result;
 /// Register all options from all modules
 pub fn register_all(registry: &OptionsRegistry) -> Result<()> {
     api::register(registry)?;
+    environments::register(registry)?;
     tui::register(registry)?;
     themes::register(registry)?;
     keybinds::register(registry)?;
@@ -19,13 +19,15 @@ pub fn register(registry: &OptionsRegistry) -> Result<()> {
     // Register built-in themes
     register_theme(registry, "mocha", &Theme::mocha())?;
     register_theme(registry, "latte", &Theme::latte())?;
+    register_theme(registry, "high_contrast", &Theme::high_contrast())?;
+    register_theme(registry, "colorblind", &Theme::colorblind_safe())?;
 
-    log::info!("Registered {} theme options (1 control + 2 themes × 21 colors)", 43);
+    log::info!("Registered {} theme options (1 control + 4 themes × 21 colors)", 85);
     Ok(())
 }
 
 /// Register all 21 color options for a single theme
-fn register_theme(registry: &OptionsRegistry, name: &str, theme: &Theme) -> Result<()> {
+pub fn register_theme(registry: &OptionsRegistry, name: &str, theme: &Theme) -> Result<()> {
     let colors = theme.colors();
 
     for (color_name, color) in colors {
@@ -123,5 +125,9 @@ mod tests {
 
         let latte = Theme::latte();
         assert_eq!(latte.colors().len(), 21);
+
+        let registry = OptionsRegistry::new();
+        register(&registry).unwrap();
+        assert_eq!(list_themes(&registry), vec!["colorblind", "high_contrast", "latte", "mocha"]);
     }
 }
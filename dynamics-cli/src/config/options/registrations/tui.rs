@@ -29,6 +29,53 @@ pub fn register(registry: &OptionsRegistry) -> Result<()> {
             .build()?
     )?;
 
-    log::info!("Registered {} TUI options", 2);
+    // Modal focus restoration option
+    registry.register(
+        OptionDefBuilder::new("tui", "restore_modal_focus")
+            .display_name("Restore Focus After Modals")
+            .description("Refocus the element that had focus before a modal opened when it closes; when off, hover focus modes pick the element under the mouse")
+            .bool_type(true)
+            .build()?
+    )?;
+
+    // Session restore option
+    registry.register(
+        OptionDefBuilder::new("tui", "session_max_age_minutes")
+            .display_name("Session Restore Age (minutes)")
+            .description("Reopening an app with the same parameters restores where you left it if it was closed less than this long ago (0 disables)")
+            .uint_type(60, Some(0), Some(7 * 24 * 60))
+            .build()?
+    )?;
+
+    // Mapping auto-save option
+    registry.register(
+        OptionDefBuilder::new("tui", "autosave_delay_ms")
+            .display_name("Mapping Auto-Save Delay (ms)")
+            .description("How long mapping edits in Entity Comparison wait for further edits before they are saved; leaving the app saves immediately")
+            .uint_type(1000, Some(0), Some(60_000))
+            .build()?
+    )?;
+
+    // Entity comparison auto-matching options
+    registry.register(
+        OptionDefBuilder::new("tui", "match_algorithm")
+            .display_name("Auto-Match Algorithm")
+            .description("How Entity Comparison matches fields by name: exact names, prefix mappings, or prefix mappings plus Levenshtein or word-based similarity for fields still unmatched")
+            .enum_type(
+                vec!["exact", "prefix", "levenshtein", "token"],
+                "prefix"
+            )
+            .build()?
+    )?;
+
+    registry.register(
+        OptionDefBuilder::new("tui", "match_threshold")
+            .display_name("Auto-Match Threshold")
+            .description("Minimum name similarity (0-100) for a Levenshtein or token match")
+            .uint_type(80, Some(0), Some(100))
+            .build()?
+    )?;
+
+    log::info!("Registered {} TUI options", 7);
     Ok(())
 }
@@ -14,6 +14,24 @@ pub fn register(registry: &OptionsRegistry) -> Result<()> {
             .build()?
     )?;
 
+    // Startup check option
+    registry.register(
+        OptionDefBuilder::new("update", "check_on_startup")
+            .display_name("Check on Startup")
+            .description("Check for updates when the TUI starts, at most once a day")
+            .bool_type(false)
+            .build()?
+    )?;
+
+    // Release channel option
+    registry.register(
+        OptionDefBuilder::new("update", "channel")
+            .display_name("Release Channel")
+            .description("Which releases count as updates: stable only, or pre-releases too")
+            .enum_type(vec!["stable", "prerelease"], "stable")
+            .build()?
+    )?;
+
     // Auto-install option
     registry.register(
         OptionDefBuilder::new("update", "auto_install")
@@ -23,6 +41,6 @@ pub fn register(registry: &OptionsRegistry) -> Result<()> {
             .build()?
     )?;
 
-    log::info!("Registered {} update options", 2);
+    log::info!("Registered {} update options", 4);
     Ok(())
 }
@@ -1,13 +1,32 @@
 //! Global registry for option definitions
 
-use super::types::OptionDefinition;
+use super::types::{OptionDefinition, OptionValue};
 use anyhow::Result;
 use std::collections::HashMap;
-use std::sync::RwLock;
+use std::sync::{Arc, RwLock};
+
+/// Callback fired with the option key and its new value
+type ChangeCallback = Arc<dyn Fn(&str, &OptionValue) + Send + Sync>;
+
+/// Which option changes a callback is interested in
+enum ChangeFilter {
+    Key(String),
+    Namespace(String),
+}
+
+impl ChangeFilter {
+    fn matches(&self, key: &str) -> bool {
+        match self {
+            ChangeFilter::Key(k) => k == key,
+            ChangeFilter::Namespace(ns) => key.strip_prefix(ns.as_str()).is_some_and(|rest| rest.starts_with('.')),
+        }
+    }
+}
 
 /// Thread-safe global registry of option definitions
 pub struct OptionsRegistry {
     definitions: RwLock<HashMap<String, OptionDefinition>>,
+    listeners: RwLock<Vec<(ChangeFilter, ChangeCallback)>>,
 }
 
 impl OptionsRegistry {
@@ -15,6 +34,7 @@ impl OptionsRegistry {
     pub fn new() -> Self {
         Self {
             definitions: RwLock::new(HashMap::new()),
+            listeners: RwLock::new(Vec::new()),
         }
     }
 
@@ -80,6 +100,41 @@ impl OptionsRegistry {
         options.sort_by(|a, b| a.key.cmp(&b.key));
         options
     }
+
+    /// Call `callback` whenever the option with this key is changed
+    pub fn on_change(&self, key: &str, callback: impl Fn(&OptionValue) + Send + Sync + 'static) {
+        self.listeners.write().unwrap().push((
+            ChangeFilter::Key(key.to_string()),
+            Arc::new(move |_, value| callback(value)),
+        ));
+    }
+
+    /// Call `callback` whenever any option in a namespace is changed
+    pub fn on_namespace_change(&self, namespace: &str, callback: impl Fn(&str, &OptionValue) + Send + Sync + 'static) {
+        self.listeners.write().unwrap().push((
+            ChangeFilter::Namespace(namespace.to_string()),
+            Arc::new(callback),
+        ));
+    }
+
+    /// Fire the callbacks registered for a changed option
+    ///
+    /// Called by the store once the new value is persisted. Callbacks run without
+    /// the listener lock held, so they may read options or register listeners.
+    pub(super) fn notify_change(&self, key: &str, value: &OptionValue) {
+        let callbacks: Vec<ChangeCallback> = self
+            .listeners
+            .read()
+            .unwrap()
+            .iter()
+            .filter(|(filter, _)| filter.matches(key))
+            .map(|(_, callback)| callback.clone())
+            .collect();
+
+        for callback in callbacks {
+            callback(key, value);
+        }
+    }
 }
 
 impl Default for OptionsRegistry {
@@ -210,4 +265,27 @@ mod tests {
         assert!(namespaces.contains(&"api".to_string()));
         assert!(namespaces.contains(&"tui".to_string()));
     }
+
+    #[test]
+    fn test_change_callbacks() {
+        use std::sync::Mutex;
+
+        let registry = OptionsRegistry::new();
+        let seen = Arc::new(Mutex::new(Vec::new()));
+
+        let by_key = seen.clone();
+        registry.on_change("tui.focus_mode", move |value| {
+            by_key.lock().unwrap().push(format!("key:{}", value.as_string().unwrap()));
+        });
+        let by_namespace = seen.clone();
+        registry.on_namespace_change("tui", move |key, _| {
+            by_namespace.lock().unwrap().push(format!("ns:{}", key));
+        });
+
+        registry.notify_change("tui.focus_mode", &OptionValue::String("click".to_string()));
+        registry.notify_change("tuix.other", &OptionValue::Bool(true));
+        registry.notify_change("theme.active", &OptionValue::String("mocha".to_string()));
+
+        assert_eq!(*seen.lock().unwrap(), vec!["key:click", "ns:tui.focus_mode"]);
+    }
 }
@@ -54,7 +54,10 @@ impl Options {
 
         // Serialize and save
         let raw_value = self.serialize_value(&value);
-        self.set_raw(key, &raw_value).await
+        self.set_raw(key, &raw_value).await?;
+
+        self.registry.notify_change(key, &value);
+        Ok(())
     }
 
     /// Get bool value
@@ -107,6 +110,49 @@ impl Options {
         self.set(key, OptionValue::String(value)).await
     }
 
+    /// Current value of every registered option, keyed by option key
+    pub async fn export_all(&self) -> Result<serde_json::Value> {
+        let mut exported = serde_json::Map::new();
+        for def in self.registry.list_all() {
+            let value = self.get(&def.key).await?;
+            exported.insert(def.key, value.to_json());
+        }
+        Ok(serde_json::Value::Object(exported))
+    }
+
+    /// Set options from an `export_all` object, returning the keys that were skipped
+    ///
+    /// Keys that aren't registered (e.g. from a newer version) are skipped with a
+    /// warning. Every other value is validated before any is written, so an
+    /// invalid file changes nothing.
+    pub async fn import_all(&self, exported: &serde_json::Value) -> Result<Vec<String>> {
+        let entries = exported
+            .as_object()
+            .context("Options export must be a JSON object of option keys to values")?;
+
+        let mut values = Vec::new();
+        let mut skipped = Vec::new();
+        for (key, json) in entries {
+            let Some(def) = self.registry.get(key) else {
+                log::warn!("Skipping unknown option '{}' during import", key);
+                skipped.push(key.clone());
+                continue;
+            };
+
+            let value = def.ty.value_from_json(json)
+                .and_then(|value| def.validate(&value).map(|()| value))
+                .with_context(|| format!("Invalid value for option '{}'", key))?;
+            values.push((key, value));
+        }
+
+        for (key, value) in values {
+            self.set_raw(key, &self.serialize_value(&value)).await?;
+            self.registry.notify_change(key, &value);
+        }
+
+        Ok(skipped)
+    }
+
     /// Delete an option by key
     pub async fn delete(&self, key: &str) -> Result<()> {
         sqlx::query("DELETE FROM options WHERE key = ?")
@@ -114,6 +160,11 @@ impl Options {
             .execute(&self.pool)
             .await
             .context("Failed to delete option")?;
+
+        // The option falls back to its default
+        if let Some(def) = self.registry.get(key) {
+            self.registry.notify_change(key, &def.default);
+        }
         Ok(())
     }
 
@@ -262,6 +313,50 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[tokio::test]
+    async fn test_export_import_roundtrip() {
+        let (store, registry) = setup_test_store().await;
+
+        registry.register(OptionDefinition {
+            key: "test.flag".to_string(),
+            namespace: "test".to_string(),
+            local_key: "flag".to_string(),
+            display_name: "Test Flag".to_string(),
+            description: "".to_string(),
+            ty: OptionType::Bool,
+            default: OptionValue::Bool(false),
+        }).unwrap();
+        registry.register(OptionDefinition {
+            key: "test.limit".to_string(),
+            namespace: "test".to_string(),
+            local_key: "limit".to_string(),
+            display_name: "Test Limit".to_string(),
+            description: "".to_string(),
+            ty: OptionType::UInt { min: Some(1), max: Some(10) },
+            default: OptionValue::UInt(5),
+        }).unwrap();
+
+        store.set_uint("test.limit", 8).await.unwrap();
+        let exported = store.export_all().await.unwrap();
+        assert_eq!(exported, serde_json::json!({ "test.flag": false, "test.limit": 8 }));
+
+        store.delete("test.limit").await.unwrap();
+        let skipped = store.import_all(&serde_json::json!({
+            "test.flag": true,
+            "test.limit": 8,
+            "test.removed": "x"
+        })).await.unwrap();
+
+        assert_eq!(skipped, vec!["test.removed".to_string()]);
+        assert!(store.get_bool("test.flag").await.unwrap());
+        assert_eq!(store.get_uint("test.limit").await.unwrap(), 8);
+
+        // One invalid value rejects the whole import
+        let result = store.import_all(&serde_json::json!({ "test.flag": false, "test.limit": 50 })).await;
+        assert!(result.is_err());
+        assert!(store.get_bool("test.flag").await.unwrap());
+    }
+
     #[tokio::test]
     async fn test_enum_validation() {
         let (store, registry) = setup_test_store().await;
@@ -52,6 +52,17 @@ impl OptionValue {
             _ => anyhow::bail!("Expected String, got {:?}", self),
         }
     }
+
+    /// Convert to the matching JSON value
+    pub fn to_json(&self) -> serde_json::Value {
+        match self {
+            OptionValue::Bool(v) => serde_json::Value::from(*v),
+            OptionValue::Int(v) => serde_json::Value::from(*v),
+            OptionValue::UInt(v) => serde_json::Value::from(*v),
+            OptionValue::Float(v) => serde_json::Value::from(*v),
+            OptionValue::String(v) => serde_json::Value::from(v.as_str()),
+        }
+    }
 }
 
 /// Type definition with constraints for validation
@@ -92,6 +103,20 @@ impl OptionType {
         }
     }
 
+    /// Read a JSON value as this type (constraints are checked by `validate`)
+    pub fn value_from_json(&self, json: &serde_json::Value) -> Result<OptionValue> {
+        let value = match self {
+            OptionType::Bool => json.as_bool().map(OptionValue::Bool),
+            OptionType::Int { .. } => json.as_i64().map(OptionValue::Int),
+            OptionType::UInt { .. } => json.as_u64().map(OptionValue::UInt),
+            OptionType::Float { .. } => json.as_f64().map(OptionValue::Float),
+            OptionType::String { .. } | OptionType::Enum { .. } => {
+                json.as_str().map(|s| OptionValue::String(s.to_string()))
+            }
+        };
+        value.with_context(|| format!("Expected {:?}, got {}", self, json))
+    }
+
     /// Validate that a value meets the constraints for this type
     pub fn validate(&self, value: &OptionValue) -> Result<()> {
         if !self.matches(value) {
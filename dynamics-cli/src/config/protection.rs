@@ -0,0 +1,157 @@
+//! Write protection for production environments
+//!
+//! Environments flagged as protected require an explicit confirmation before
+//! any write (create/update/delete/associate) is sent to them. The CLI prompts
+//! on the terminal, the TUI shows a confirmation modal, and a `--yes`/force
+//! flag skips the prompt entirely.
+
+use anyhow::Result;
+use regex::RegexBuilder;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Option key holding the pattern used to auto-flag new environments
+pub const PROTECTED_PATTERN_OPTION: &str = "environments.protected_pattern";
+
+/// Session-wide force flag, set by `dynamics-cli tui --yes`
+static FORCE_WRITES: AtomicBool = AtomicBool::new(false);
+
+/// Skip protected-environment confirmations for the rest of the session
+pub fn set_force_writes(force: bool) {
+    FORCE_WRITES.store(force, Ordering::Relaxed);
+}
+
+/// Whether protected-environment confirmations are skipped for this session
+pub fn force_writes() -> bool {
+    FORCE_WRITES.load(Ordering::Relaxed)
+}
+
+/// Outcome of checking a write against an environment
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WriteGuard {
+    /// The write may proceed without asking
+    Allowed,
+    /// The environment is protected and the user has to confirm first
+    ConfirmationRequired,
+}
+
+impl WriteGuard {
+    /// Decide whether a write needs confirmation
+    pub fn check(is_protected: bool, force: bool) -> Self {
+        if is_protected && !force {
+            WriteGuard::ConfirmationRequired
+        } else {
+            WriteGuard::Allowed
+        }
+    }
+
+    pub fn requires_confirmation(&self) -> bool {
+        matches!(self, WriteGuard::ConfirmationRequired)
+    }
+}
+
+/// Check whether an environment name matches the protected-name pattern
+///
+/// The pattern is a case-insensitive regular expression. An empty pattern
+/// disables auto-flagging; an invalid pattern is logged and treated as no match.
+pub fn matches_protected_pattern(name: &str, pattern: &str) -> bool {
+    let pattern = pattern.trim();
+    if pattern.is_empty() {
+        return false;
+    }
+
+    match RegexBuilder::new(pattern).case_insensitive(true).build() {
+        Ok(regex) => regex.is_match(name),
+        Err(e) => {
+            log::warn!("Invalid protected environment pattern '{}': {}", pattern, e);
+            false
+        }
+    }
+}
+
+/// Check a write against an environment, using the global config
+pub async fn check_environment_write(env_name: &str, force: bool) -> Result<WriteGuard> {
+    let is_protected = crate::global_config().is_environment_protected(env_name).await?;
+    Ok(WriteGuard::check(is_protected, force))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::models::{CredentialSet, Environment};
+    use crate::config::Config;
+
+    #[test]
+    fn test_protected_write_requires_confirmation() {
+        assert_eq!(WriteGuard::check(true, false), WriteGuard::ConfirmationRequired);
+        assert!(WriteGuard::check(true, false).requires_confirmation());
+    }
+
+    #[test]
+    fn test_force_bypasses_confirmation() {
+        assert_eq!(WriteGuard::check(true, true), WriteGuard::Allowed);
+    }
+
+    #[test]
+    fn test_unprotected_write_allowed() {
+        assert_eq!(WriteGuard::check(false, false), WriteGuard::Allowed);
+        assert_eq!(WriteGuard::check(false, true), WriteGuard::Allowed);
+    }
+
+    #[test]
+    fn test_pattern_matching() {
+        assert!(matches_protected_pattern("Production", "prod"));
+        assert!(matches_protected_pattern("crm-prd-eu", "prod|prd"));
+        assert!(!matches_protected_pattern("dev", "prod"));
+        assert!(!matches_protected_pattern("production", ""));
+        assert!(!matches_protected_pattern("production", "(unclosed"));
+    }
+
+    async fn config_with_credentials() -> Config {
+        let config = Config::new_test().await.unwrap();
+        config
+            .add_credentials(
+                "creds".to_string(),
+                CredentialSet::ClientCredentials {
+                    client_id: "id".to_string(),
+                    client_secret: "secret".to_string(),
+                    tenant_id: "tenant".to_string(),
+                },
+            )
+            .await
+            .unwrap();
+        config
+    }
+
+    fn environment(name: &str) -> Environment {
+        Environment {
+            name: name.to_string(),
+            host: "https://example.crm.dynamics.com".to_string(),
+            credentials_ref: "creds".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_environment_auto_flagged_by_pattern() {
+        let config = config_with_credentials().await;
+        config.add_environment(environment("production")).await.unwrap();
+        config.add_environment(environment("dev")).await.unwrap();
+
+        assert!(config.is_environment_protected("production").await.unwrap());
+        assert!(!config.is_environment_protected("dev").await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_protection_flag_survives_update() {
+        let config = config_with_credentials().await;
+        config.add_environment(environment("production")).await.unwrap();
+        config.set_environment_protected("production", false).await.unwrap();
+
+        // Re-saving (e.g. changing credentials) must not re-apply the pattern
+        config.add_environment(environment("production")).await.unwrap();
+        assert!(!config.is_environment_protected("production").await.unwrap());
+
+        config.set_environment_protected("production", true).await.unwrap();
+        let guard = WriteGuard::check(config.is_environment_protected("production").await.unwrap(), false);
+        assert!(guard.requires_confirmation());
+    }
+}
@@ -0,0 +1,84 @@
+//! Repository for saved app sessions
+//!
+//! Apps that support it save their resumable state (selection, active tab,
+//! search text...) when suspended or closed, keyed by app and launch params,
+//! and get it back when relaunched with the same params.
+
+use anyhow::{Context, Result};
+use serde_json::Value;
+use sqlx::SqlitePool;
+use std::time::Duration;
+
+/// Save an app's state, replacing any earlier one for the same key
+pub async fn save(pool: &SqlitePool, app: &str, session_key: &str, state: &Value) -> Result<()> {
+    let state = serde_json::to_string(state).context("Failed to serialize app session")?;
+
+    sqlx::query(
+        "INSERT OR REPLACE INTO app_sessions (app, session_key, state, saved_at)
+         VALUES (?, ?, ?, CURRENT_TIMESTAMP)",
+    )
+    .bind(app)
+    .bind(session_key)
+    .bind(state)
+    .execute(pool)
+    .await
+    .with_context(|| format!("Failed to save session for '{}'", app))?;
+
+    log::debug!("Saved session for {} ({})", app, session_key);
+    Ok(())
+}
+
+/// Get an app's saved state if it is younger than `max_age`
+///
+/// Sessions older than `max_age` (for any app) are deleted first.
+pub async fn get(pool: &SqlitePool, app: &str, session_key: &str, max_age: Duration) -> Result<Option<Value>> {
+    sqlx::query("DELETE FROM app_sessions WHERE saved_at < datetime('now', ?)")
+        .bind(format!("-{} seconds", max_age.as_secs()))
+        .execute(pool)
+        .await
+        .context("Failed to discard stale app sessions")?;
+
+    let state: Option<String> = sqlx::query_scalar(
+        "SELECT state FROM app_sessions WHERE app = ? AND session_key = ?",
+    )
+    .bind(app)
+    .bind(session_key)
+    .fetch_optional(pool)
+    .await
+    .with_context(|| format!("Failed to get session for '{}'", app))?;
+
+    state
+        .map(|state| serde_json::from_str(&state).context("Failed to deserialize app session"))
+        .transpose()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::db;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_session_roundtrip_and_stale_discard() {
+        let pool = db::connect_memory().await.unwrap();
+        db::run_migrations(&pool).await.unwrap();
+        let max_age = Duration::from_secs(60 * 60);
+
+        save(&pool, "Entity Comparison", "m|dev|prod|account|account", &json!({"tab": 1})).await.unwrap();
+        save(&pool, "Entity Comparison", "m|dev|prod|account|account", &json!({"tab": 2})).await.unwrap();
+        assert_eq!(get(&pool, "Entity Comparison", "m|dev|prod|account|account", max_age).await.unwrap(), Some(json!({"tab": 2})));
+        assert_eq!(get(&pool, "Entity Comparison", "m|dev|prod|contact|contact", max_age).await.unwrap(), None);
+
+        sqlx::query("UPDATE app_sessions SET saved_at = datetime('now', '-2 hours')")
+            .execute(&pool)
+            .await
+            .unwrap();
+        assert_eq!(get(&pool, "Entity Comparison", "m|dev|prod|account|account", max_age).await.unwrap(), None);
+
+        let remaining: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM app_sessions")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(remaining, 0);
+    }
+}
@@ -0,0 +1,124 @@
+//! Repository for questionnaire copy checkpoints
+//!
+//! The push app saves a checkpoint after every completed step, keyed by the
+//! source questionnaire, so a copy that fails halfway can resume instead of
+//! rolling back everything it already created.
+
+use anyhow::{Context, Result};
+use sqlx::SqlitePool;
+use std::collections::HashMap;
+
+/// Progress of a questionnaire copy after its last completed step
+#[derive(Debug, Clone, PartialEq)]
+pub struct CopyCheckpoint {
+    pub questionnaire_id: String,
+    pub copy_name: String,
+    pub copy_code: String,
+    pub completed_step: usize,
+    /// Source ID -> created ID
+    pub id_map: HashMap<String, String>,
+    /// (entity_set, id) of every created record, in creation order
+    pub created_ids: Vec<(String, String)>,
+    /// Records created by the step after `completed_step` before it failed
+    pub partial_ids: Vec<(String, String)>,
+    pub classifications_associated: usize,
+}
+
+/// Save a checkpoint, replacing any earlier one for the same questionnaire
+pub async fn save(pool: &SqlitePool, checkpoint: &CopyCheckpoint) -> Result<()> {
+    let id_map = serde_json::to_string(&checkpoint.id_map).context("Failed to serialize ID map")?;
+    let created_ids = serde_json::to_string(&checkpoint.created_ids).context("Failed to serialize created IDs")?;
+    let partial_ids = serde_json::to_string(&checkpoint.partial_ids).context("Failed to serialize partial IDs")?;
+
+    sqlx::query(
+        "INSERT OR REPLACE INTO copy_checkpoints
+         (questionnaire_id, copy_name, copy_code, completed_step, id_map, created_ids, partial_ids, classifications_associated, updated_at)
+         VALUES (?, ?, ?, ?, ?, ?, ?, ?, CURRENT_TIMESTAMP)",
+    )
+    .bind(&checkpoint.questionnaire_id)
+    .bind(&checkpoint.copy_name)
+    .bind(&checkpoint.copy_code)
+    .bind(checkpoint.completed_step as i64)
+    .bind(id_map)
+    .bind(created_ids)
+    .bind(partial_ids)
+    .bind(checkpoint.classifications_associated as i64)
+    .execute(pool)
+    .await
+    .with_context(|| format!("Failed to save copy checkpoint for '{}'", checkpoint.questionnaire_id))?;
+
+    log::debug!("Saved copy checkpoint for {} at step {}", checkpoint.questionnaire_id, checkpoint.completed_step);
+    Ok(())
+}
+
+/// Get the checkpoint for a source questionnaire
+pub async fn get(pool: &SqlitePool, questionnaire_id: &str) -> Result<Option<CopyCheckpoint>> {
+    let row: Option<(String, String, i64, String, String, String, i64)> = sqlx::query_as(
+        "SELECT copy_name, copy_code, completed_step, id_map, created_ids, partial_ids, classifications_associated
+         FROM copy_checkpoints WHERE questionnaire_id = ?",
+    )
+    .bind(questionnaire_id)
+    .fetch_optional(pool)
+    .await
+    .with_context(|| format!("Failed to get copy checkpoint for '{}'", questionnaire_id))?;
+
+    let Some((copy_name, copy_code, completed_step, id_map, created_ids, partial_ids, classifications_associated)) = row else {
+        return Ok(None);
+    };
+
+    Ok(Some(CopyCheckpoint {
+        questionnaire_id: questionnaire_id.to_string(),
+        copy_name,
+        copy_code,
+        completed_step: completed_step as usize,
+        id_map: serde_json::from_str(&id_map).context("Failed to deserialize ID map")?,
+        created_ids: serde_json::from_str(&created_ids).context("Failed to deserialize created IDs")?,
+        partial_ids: serde_json::from_str(&partial_ids).context("Failed to deserialize partial IDs")?,
+        classifications_associated: classifications_associated as usize,
+    }))
+}
+
+/// Delete the checkpoint for a source questionnaire, if any
+pub async fn delete(pool: &SqlitePool, questionnaire_id: &str) -> Result<()> {
+    sqlx::query("DELETE FROM copy_checkpoints WHERE questionnaire_id = ?")
+        .bind(questionnaire_id)
+        .execute(pool)
+        .await
+        .with_context(|| format!("Failed to delete copy checkpoint for '{}'", questionnaire_id))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::db;
+
+    #[tokio::test]
+    async fn test_checkpoint_roundtrip_and_replace() {
+        let pool = db::connect_memory().await.unwrap();
+        db::run_migrations(&pool).await.unwrap();
+
+        let mut checkpoint = CopyCheckpoint {
+            questionnaire_id: "q1".to_string(),
+            copy_name: "Copy".to_string(),
+            copy_code: "C1".to_string(),
+            completed_step: 1,
+            id_map: HashMap::from([("q1".to_string(), "new-q1".to_string())]),
+            created_ids: vec![("nrq_questionnaires".to_string(), "new-q1".to_string())],
+            partial_ids: Vec::new(),
+            classifications_associated: 0,
+        };
+        save(&pool, &checkpoint).await.unwrap();
+        assert_eq!(get(&pool, "q1").await.unwrap(), Some(checkpoint.clone()));
+
+        checkpoint.completed_step = 2;
+        checkpoint.created_ids.push(("nrq_questionnairepages".to_string(), "new-p1".to_string()));
+        checkpoint.partial_ids.push(("nrq_questionnairepagelines".to_string(), "new-pl1".to_string()));
+        save(&pool, &checkpoint).await.unwrap();
+        assert_eq!(get(&pool, "q1").await.unwrap(), Some(checkpoint));
+
+        delete(&pool, "q1").await.unwrap();
+        assert_eq!(get(&pool, "q1").await.unwrap(), None);
+    }
+}
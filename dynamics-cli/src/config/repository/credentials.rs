@@ -1,12 +1,17 @@
 //! Repository for credential operations
+//!
+//! The `data` column holds credential JSON encrypted with `CredentialCipher`.
+//! Rows written before encryption was introduced are plaintext until
+//! `encrypt_plaintext` has run over them.
 
 use anyhow::{Context, Result};
 use sqlx::SqlitePool;
 use crate::api::models::CredentialSet as ApiCredentialSet;
+use crate::config::encryption::CredentialCipher;
 use crate::config::models::{DbCredential, CredentialData};
 
 /// Insert or update credentials
-pub async fn insert(pool: &SqlitePool, name: String, credentials: ApiCredentialSet) -> Result<()> {
+pub async fn insert(pool: &SqlitePool, cipher: &CredentialCipher, name: String, credentials: ApiCredentialSet) -> Result<()> {
     let credential_data: CredentialData = credentials.into();
     let type_str = match credential_data {
         CredentialData::UsernamePassword { .. } => "username_password",
@@ -17,6 +22,7 @@ pub async fn insert(pool: &SqlitePool, name: String, credentials: ApiCredentialS
 
     let data_json = serde_json::to_string(&credential_data)
         .context("Failed to serialize credential data")?;
+    let data_json = cipher.encrypt(&data_json)?;
 
     sqlx::query(
         r#"
@@ -36,7 +42,7 @@ pub async fn insert(pool: &SqlitePool, name: String, credentials: ApiCredentialS
 }
 
 /// Get credentials by name
-pub async fn get(pool: &SqlitePool, name: &str) -> Result<Option<ApiCredentialSet>> {
+pub async fn get(pool: &SqlitePool, cipher: &CredentialCipher, name: &str) -> Result<Option<ApiCredentialSet>> {
     let row: Option<DbCredential> = sqlx::query_as(
         "SELECT name, type, data, created_at, updated_at FROM credentials WHERE name = ?",
     )
@@ -45,13 +51,61 @@ pub async fn get(pool: &SqlitePool, name: &str) -> Result<Option<ApiCredentialSe
     .await
     .with_context(|| format!("Failed to get credentials '{}'", name))?;
 
-    if let Some(row) = row {
-        let credential_data: CredentialData = serde_json::from_str(&row.data)
-            .context("Failed to deserialize credential data")?;
-        Ok(Some(credential_data.into()))
-    } else {
-        Ok(None)
+    row.map(|row| decode(cipher, &row.data).map(Into::into)).transpose()
+}
+
+/// Decrypt every credential set, for exporting them in plaintext
+pub async fn list_decrypted(pool: &SqlitePool, cipher: &CredentialCipher) -> Result<Vec<(String, CredentialData)>> {
+    let rows: Vec<(String, String)> = sqlx::query_as("SELECT name, data FROM credentials ORDER BY name")
+        .fetch_all(pool)
+        .await
+        .context("Failed to list credentials")?;
+
+    rows.into_iter()
+        .map(|(name, data)| {
+            let credential_data = decode(cipher, &data)
+                .with_context(|| format!("Failed to read credentials '{}'", name))?;
+            Ok((name, credential_data))
+        })
+        .collect()
+}
+
+/// Encrypt rows still stored as plaintext JSON, returning how many were converted
+pub async fn encrypt_plaintext(pool: &SqlitePool, cipher: &CredentialCipher) -> Result<u64> {
+    let rows: Vec<(String, String)> = sqlx::query_as("SELECT name, data FROM credentials")
+        .fetch_all(pool)
+        .await
+        .context("Failed to read credentials for encryption")?;
+
+    let mut converted = 0;
+    for (name, data) in rows {
+        if CredentialCipher::is_encrypted(&data) {
+            continue;
+        }
+
+        sqlx::query("UPDATE credentials SET data = ? WHERE name = ?")
+            .bind(cipher.encrypt(&data)?)
+            .bind(&name)
+            .execute(pool)
+            .await
+            .with_context(|| format!("Failed to encrypt credentials '{}'", name))?;
+        converted += 1;
+    }
+
+    if converted > 0 {
+        log::info!("Encrypted {} plaintext credential sets", converted);
     }
+    Ok(converted)
+}
+
+/// Parse a stored `data` column, decrypting it unless it is legacy plaintext
+pub fn decode(cipher: &CredentialCipher, data: &str) -> Result<CredentialData> {
+    let json = if CredentialCipher::is_encrypted(data) {
+        cipher.decrypt(data)?
+    } else {
+        data.to_string()
+    };
+    serde_json::from_str(&json).context("Failed to deserialize credential data")
 }
 
 /// List all credential names
@@ -152,4 +206,61 @@ pub async fn exists(pool: &SqlitePool, name: &str) -> Result<bool> {
         .with_context(|| format!("Failed to check if credentials '{}' exist", name))?;
 
     Ok(count > 0)
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::db;
+
+    #[tokio::test]
+    async fn test_plaintext_rows_are_encrypted_once() {
+        let pool = db::connect_memory().await.unwrap();
+        db::run_migrations(&pool).await.unwrap();
+        let cipher = CredentialCipher::generate().unwrap();
+
+        // A row as written before encryption existed
+        sqlx::query("INSERT INTO credentials (name, type, data) VALUES (?, ?, ?)")
+            .bind("legacy")
+            .bind("device_code")
+            .bind(r#"{"type":"device_code","client_id":"client","tenant_id":"tenant"}"#)
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        assert_eq!(encrypt_plaintext(&pool, &cipher).await.unwrap(), 1);
+        assert_eq!(encrypt_plaintext(&pool, &cipher).await.unwrap(), 0);
+
+        let (stored,): (String,) = sqlx::query_as("SELECT data FROM credentials WHERE name = 'legacy'")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert!(CredentialCipher::is_encrypted(&stored));
+
+        let credentials = get(&pool, &cipher, "legacy").await.unwrap().unwrap();
+        assert!(matches!(credentials, ApiCredentialSet::DeviceCode { client_id, .. } if client_id == "client"));
+    }
+
+    #[tokio::test]
+    async fn test_inserted_secrets_are_not_stored_in_plaintext() {
+        let pool = db::connect_memory().await.unwrap();
+        db::run_migrations(&pool).await.unwrap();
+        let cipher = CredentialCipher::generate().unwrap();
+
+        let credentials = ApiCredentialSet::ClientCredentials {
+            client_id: "client".to_string(),
+            client_secret: "s3cret".to_string(),
+            tenant_id: "tenant".to_string(),
+        };
+        insert(&pool, &cipher, "app".to_string(), credentials).await.unwrap();
+
+        let (stored,): (String,) = sqlx::query_as("SELECT data FROM credentials WHERE name = 'app'")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert!(!stored.contains("s3cret"));
+
+        let exported = list_decrypted(&pool, &cipher).await.unwrap();
+        assert!(matches!(&exported[0].1, CredentialData::ClientCredentials { client_secret, .. } if client_secret == "s3cret"));
+    }
+}
@@ -0,0 +1,93 @@
+//! Repository for entity set name overrides
+//!
+//! Maps a logical entity name to the entity set name used in Web API URLs,
+//! for custom entities whose plural the pluralization rules get wrong.
+
+use anyhow::{Context, Result};
+use sqlx::SqlitePool;
+use std::collections::HashMap;
+
+/// Add or replace the override for an entity
+pub async fn set(pool: &SqlitePool, entity_name: &str, entity_set_name: &str) -> Result<()> {
+    sqlx::query(
+        "INSERT OR REPLACE INTO entity_set_overrides (entity_name, entity_set_name) VALUES (?, ?)",
+    )
+    .bind(entity_name.to_lowercase())
+    .bind(entity_set_name)
+    .execute(pool)
+    .await
+    .with_context(|| format!("Failed to set entity set override: {} -> {}", entity_name, entity_set_name))?;
+
+    log::info!("Set entity set override: {} -> {}", entity_name, entity_set_name);
+    Ok(())
+}
+
+/// Get the override for an entity
+pub async fn get(pool: &SqlitePool, entity_name: &str) -> Result<Option<String>> {
+    let row: Option<(String,)> = sqlx::query_as(
+        "SELECT entity_set_name FROM entity_set_overrides WHERE entity_name = ?",
+    )
+    .bind(entity_name.to_lowercase())
+    .fetch_optional(pool)
+    .await
+    .with_context(|| format!("Failed to get entity set override for '{}'", entity_name))?;
+
+    Ok(row.map(|(entity_set_name,)| entity_set_name))
+}
+
+/// List all overrides, ordered by entity name
+pub async fn list(pool: &SqlitePool) -> Result<Vec<(String, String)>> {
+    let rows: Vec<(String, String)> = sqlx::query_as(
+        "SELECT entity_name, entity_set_name FROM entity_set_overrides ORDER BY entity_name",
+    )
+    .fetch_all(pool)
+    .await
+    .context("Failed to list entity set overrides")?;
+
+    Ok(rows)
+}
+
+/// Load all overrides as an entity name -> entity set name map
+pub async fn load_map(pool: &SqlitePool) -> Result<HashMap<String, String>> {
+    Ok(list(pool).await?.into_iter().collect())
+}
+
+/// Delete the override for an entity
+pub async fn delete(pool: &SqlitePool, entity_name: &str) -> Result<()> {
+    let result = sqlx::query("DELETE FROM entity_set_overrides WHERE entity_name = ?")
+        .bind(entity_name.to_lowercase())
+        .execute(pool)
+        .await
+        .with_context(|| format!("Failed to delete entity set override for '{}'", entity_name))?;
+
+    if result.rows_affected() == 0 {
+        anyhow::bail!("Entity set override for '{}' not found", entity_name);
+    }
+
+    log::info!("Deleted entity set override: {}", entity_name);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::db;
+
+    #[tokio::test]
+    async fn test_override_crud() {
+        let pool = db::connect_memory().await.unwrap();
+        db::run_migrations(&pool).await.unwrap();
+
+        set(&pool, "New_Status", "new_statuslist").await.unwrap();
+        assert_eq!(get(&pool, "new_status").await.unwrap(), Some("new_statuslist".to_string()));
+
+        set(&pool, "new_status", "new_statuses").await.unwrap();
+        let map = load_map(&pool).await.unwrap();
+        assert_eq!(map.len(), 1);
+        assert_eq!(map["new_status"], "new_statuses");
+
+        delete(&pool, "new_status").await.unwrap();
+        assert_eq!(get(&pool, "new_status").await.unwrap(), None);
+        assert!(delete(&pool, "new_status").await.is_err());
+    }
+}
@@ -6,7 +6,10 @@ use crate::api::models::Environment as ApiEnvironment;
 use crate::config::models::DbEnvironment;
 
 /// Insert or update environment
-pub async fn insert(pool: &SqlitePool, environment: ApiEnvironment) -> Result<()> {
+///
+/// `protect_if_new` is only applied when the environment does not exist yet;
+/// an existing environment keeps its current protection flag.
+pub async fn insert(pool: &SqlitePool, environment: ApiEnvironment, protect_if_new: bool) -> Result<()> {
     // Check if credentials exist
     let creds_exist: i64 = sqlx::query_scalar(
         "SELECT COUNT(*) FROM credentials WHERE name = ?",
@@ -25,13 +28,15 @@ pub async fn insert(pool: &SqlitePool, environment: ApiEnvironment) -> Result<()
 
     sqlx::query(
         r#"
-        INSERT OR REPLACE INTO environments (name, host, credentials_ref, updated_at)
-        VALUES (?, ?, ?, CURRENT_TIMESTAMP)
+        INSERT OR REPLACE INTO environments (name, host, credentials_ref, is_protected, updated_at)
+        VALUES (?, ?, ?, COALESCE((SELECT is_protected FROM environments WHERE name = ?), ?), CURRENT_TIMESTAMP)
         "#,
     )
     .bind(&environment.name)
     .bind(&environment.host)
     .bind(&environment.credentials_ref)
+    .bind(&environment.name)
+    .bind(protect_if_new)
     .execute(pool)
     .await
     .with_context(|| format!("Failed to insert environment '{}'", environment.name))?;
@@ -43,7 +48,7 @@ pub async fn insert(pool: &SqlitePool, environment: ApiEnvironment) -> Result<()
 /// Get environment by name
 pub async fn get(pool: &SqlitePool, name: &str) -> Result<Option<ApiEnvironment>> {
     let row: Option<DbEnvironment> = sqlx::query_as(
-        "SELECT name, host, credentials_ref, is_current, created_at, updated_at FROM environments WHERE name = ?",
+        "SELECT name, host, credentials_ref, is_current, is_protected, created_at, updated_at FROM environments WHERE name = ?",
     )
     .bind(name)
     .fetch_optional(pool)
@@ -195,6 +200,49 @@ pub async fn set_current(pool: &SqlitePool, name: String) -> Result<()> {
     Ok(())
 }
 
+/// Check whether an environment is flagged as protected
+pub async fn is_protected(pool: &SqlitePool, name: &str) -> Result<bool> {
+    let row: Option<(bool,)> = sqlx::query_as("SELECT is_protected FROM environments WHERE name = ?")
+        .bind(name)
+        .fetch_optional(pool)
+        .await
+        .with_context(|| format!("Failed to get protection flag for environment '{}'", name))?;
+
+    match row {
+        Some((protected,)) => Ok(protected),
+        None => anyhow::bail!("Environment '{}' not found", name),
+    }
+}
+
+/// List names of all protected environments
+pub async fn list_protected(pool: &SqlitePool) -> Result<Vec<String>> {
+    let rows: Vec<(String,)> = sqlx::query_as(
+        "SELECT name FROM environments WHERE is_protected = TRUE ORDER BY name",
+    )
+    .fetch_all(pool)
+    .await
+    .context("Failed to list protected environments")?;
+
+    Ok(rows.into_iter().map(|(name,)| name).collect())
+}
+
+/// Set or clear the protection flag on an environment
+pub async fn set_protected(pool: &SqlitePool, name: &str, protected: bool) -> Result<()> {
+    let result = sqlx::query("UPDATE environments SET is_protected = ?, updated_at = CURRENT_TIMESTAMP WHERE name = ?")
+        .bind(protected)
+        .bind(name)
+        .execute(pool)
+        .await
+        .with_context(|| format!("Failed to update protection flag for environment '{}'", name))?;
+
+    if result.rows_affected() == 0 {
+        anyhow::bail!("Environment '{}' not found", name);
+    }
+
+    log::info!("Set protection for environment {}: {}", name, protected);
+    Ok(())
+}
+
 /// Get environments using specific credentials
 pub async fn list_by_credentials(pool: &SqlitePool, credentials_ref: &str) -> Result<Vec<String>> {
     let rows: Vec<(String,)> = sqlx::query_as(
@@ -0,0 +1,205 @@
+//! Repository for operations that failed in queue runs
+//!
+//! A run is one execution of a queue item and is keyed by that item's ID.
+//! Retrying a run records its outcome under the same ID, so operations that
+//! now succeed drop out and the ones still failing keep their latest error.
+
+use anyhow::{Context, Result};
+use sqlx::SqlitePool;
+use crate::api::operations::{Operation, OperationResult};
+use crate::config::models::DbFailedOperation;
+
+/// An operation that failed, with the error the server returned
+#[derive(Debug, Clone)]
+pub struct FailedOperation {
+    pub id: i64,
+    pub run_id: String,
+    pub environment_name: String,
+    pub description: String,
+    pub operation: Operation,
+    /// Error message, including the response body
+    pub error: String,
+    pub status_code: Option<u16>,
+    pub failed_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl TryFrom<DbFailedOperation> for FailedOperation {
+    type Error = anyhow::Error;
+
+    fn try_from(row: DbFailedOperation) -> Result<Self> {
+        let operation = serde_json::from_str(&row.operation_json)
+            .with_context(|| format!("Failed to parse failed operation {}", row.id))?;
+
+        Ok(Self {
+            id: row.id,
+            run_id: row.run_id,
+            environment_name: row.environment_name,
+            description: row.description,
+            operation,
+            error: row.error,
+            status_code: row.status_code.and_then(|code| u16::try_from(code).ok()),
+            failed_at: row.failed_at,
+        })
+    }
+}
+
+/// A run with failed operations left to retry
+#[derive(Debug, Clone, PartialEq)]
+pub struct FailedRun {
+    pub run_id: String,
+    pub environment_name: String,
+    pub description: String,
+    pub failed_count: i64,
+    pub failed_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Replace a run's failed operations with the unsuccessful ones in `results`
+///
+/// An outcome without failures clears the run. A run that was recorded before
+/// keeps its original description.
+pub async fn record_run(
+    pool: &SqlitePool,
+    run_id: &str,
+    environment_name: &str,
+    description: &str,
+    results: &[OperationResult],
+) -> Result<()> {
+    let mut tx = pool.begin().await.context("Failed to start transaction")?;
+
+    let previous: Option<String> = sqlx::query_scalar("SELECT description FROM failed_operations WHERE run_id = ? LIMIT 1")
+        .bind(run_id)
+        .fetch_optional(&mut *tx)
+        .await
+        .with_context(|| format!("Failed to read failed operations of run '{}'", run_id))?;
+    let description = previous.as_deref().unwrap_or(description);
+
+    sqlx::query("DELETE FROM failed_operations WHERE run_id = ?")
+        .bind(run_id)
+        .execute(&mut *tx)
+        .await
+        .with_context(|| format!("Failed to clear failed operations of run '{}'", run_id))?;
+
+    let mut failed = 0;
+    for result in results.iter().filter(|result| !result.success) {
+        let operation_json = serde_json::to_string(&result.operation)
+            .context("Failed to serialize operation")?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO failed_operations (run_id, environment_name, description, operation_json, error, status_code)
+            VALUES (?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(run_id)
+        .bind(environment_name)
+        .bind(description)
+        .bind(operation_json)
+        .bind(result.error.as_deref().unwrap_or("Unknown error"))
+        .bind(result.status_code.map(i64::from))
+        .execute(&mut *tx)
+        .await
+        .with_context(|| format!("Failed to record failed operation of run '{}'", run_id))?;
+        failed += 1;
+    }
+
+    tx.commit().await.context("Failed to commit transaction")?;
+
+    if failed > 0 {
+        log::info!("Recorded {} failed operation(s) for run {}", failed, run_id);
+    }
+    Ok(())
+}
+
+/// Failed operations of a run, in the order they were queued
+pub async fn list_for_run(pool: &SqlitePool, run_id: &str) -> Result<Vec<FailedOperation>> {
+    let rows: Vec<DbFailedOperation> = sqlx::query_as(
+        r#"
+        SELECT id, run_id, environment_name, description, operation_json, error, status_code, failed_at
+        FROM failed_operations
+        WHERE run_id = ?
+        ORDER BY id
+        "#,
+    )
+    .bind(run_id)
+    .fetch_all(pool)
+    .await
+    .with_context(|| format!("Failed to list failed operations of run '{}'", run_id))?;
+
+    rows.into_iter().map(FailedOperation::try_from).collect()
+}
+
+/// Runs with failed operations, most recent first
+pub async fn list_runs(pool: &SqlitePool) -> Result<Vec<FailedRun>> {
+    let rows: Vec<(String, String, String, i64, chrono::DateTime<chrono::Utc>)> = sqlx::query_as(
+        r#"
+        SELECT run_id, environment_name, MIN(description), COUNT(*), MAX(failed_at) AS failed_at
+        FROM failed_operations
+        GROUP BY run_id, environment_name
+        ORDER BY failed_at DESC, run_id
+        "#,
+    )
+    .fetch_all(pool)
+    .await
+    .context("Failed to list failed runs")?;
+
+    Ok(rows
+        .into_iter()
+        .map(|(run_id, environment_name, description, failed_count, failed_at)| FailedRun {
+            run_id,
+            environment_name,
+            description,
+            failed_count,
+            failed_at,
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::db;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_retry_outcome_replaces_failures() {
+        let pool = db::connect_memory().await.unwrap();
+        db::run_migrations(&pool).await.unwrap();
+        sqlx::query("INSERT INTO credentials (name, type, data) VALUES ('creds', 'device_code', '{}')")
+            .execute(&pool).await.unwrap();
+        sqlx::query("INSERT INTO environments (name, host, credentials_ref) VALUES ('dev', 'https://dev.crm.dynamics.com', 'creds')")
+            .execute(&pool).await.unwrap();
+
+        let ok = Operation::create("contacts", json!({"firstname": "John"}));
+        let rejected = Operation::create("contacts", json!({"lastname": ""}));
+        let rolled_back = Operation::create("contacts", json!({"firstname": "Jane"}));
+        record_run(&pool, "run-1", "dev", "Rows 1-3", &[
+            OperationResult::success(ok, None),
+            OperationResult::error(rejected.clone(), "{\"error\":{\"message\":\"Invalid lastname\"}}".to_string(), Some(400)),
+            OperationResult::error(rolled_back.clone(), "Not applied".to_string(), None),
+        ]).await.unwrap();
+
+        let failed = list_for_run(&pool, "run-1").await.unwrap();
+        assert_eq!(failed.len(), 2);
+        assert_eq!(serde_json::to_value(&failed[0].operation).unwrap(), serde_json::to_value(&rejected).unwrap());
+        assert_eq!(failed[0].status_code, Some(400));
+        assert!(failed[0].error.contains("Invalid lastname"));
+
+        let runs = list_runs(&pool).await.unwrap();
+        assert_eq!(runs.len(), 1);
+        assert_eq!((runs[0].run_id.as_str(), runs[0].failed_count), ("run-1", 2));
+
+        // The retry fixes one; the other stays under the original description
+        record_run(&pool, "run-1", "dev", "Retry 2 failed of 3: Rows 1-3", &[
+            OperationResult::success(rolled_back, None),
+            OperationResult::error(rejected, "Still invalid".to_string(), Some(400)),
+        ]).await.unwrap();
+        let failed = list_for_run(&pool, "run-1").await.unwrap();
+        assert_eq!(failed.len(), 1);
+        assert_eq!(failed[0].error, "Still invalid");
+        assert_eq!(failed[0].description, "Rows 1-3");
+
+        record_run(&pool, "run-1", "dev", "Rows 1-3", &[]).await.unwrap();
+        assert!(list_for_run(&pool, "run-1").await.unwrap().is_empty());
+        assert!(list_runs(&pool).await.unwrap().is_empty());
+    }
+}
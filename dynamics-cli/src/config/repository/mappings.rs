@@ -2,7 +2,20 @@
 
 use anyhow::{Context, Result};
 use sqlx::SqlitePool;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+
+/// Current values of edited mappings, written together by `save_mapping_changes`
+#[derive(Debug, Clone, Default)]
+pub struct MappingChanges {
+    /// Source field -> targets it now maps to (empty when the mapping was removed)
+    pub field_mappings: Vec<(String, Vec<String>)>,
+    /// Source prefix -> target prefixes (empty when the mapping was removed)
+    pub prefix_mappings: Vec<(String, Vec<String>)>,
+    /// Source relationship -> target relationships (empty when the mapping was removed)
+    pub relationship_mappings: Vec<(String, Vec<String>)>,
+    /// The whole ignored set, when it changed
+    pub ignored_items: Option<HashSet<String>>,
+}
 
 /// Get all field mappings for a source/target entity pair
 /// Returns HashMap<source_field, Vec<target_fields>> to support 1-to-N mappings
@@ -134,6 +147,32 @@ pub async fn get_prefix_mappings(
     Ok(mappings)
 }
 
+/// Get all relationship mappings for a source/target entity pair
+/// Returns HashMap<source_relationship, Vec<target_relationships>>
+pub async fn get_relationship_mappings(
+    pool: &SqlitePool,
+    source_entity: &str,
+    target_entity: &str,
+) -> Result<HashMap<String, Vec<String>>> {
+    let rows: Vec<(String, String)> = sqlx::query_as(
+        "SELECT source_relationship, target_relationship FROM relationship_mappings
+         WHERE source_entity = ? AND target_entity = ?
+         ORDER BY source_relationship, target_relationship",
+    )
+    .bind(source_entity)
+    .bind(target_entity)
+    .fetch_all(pool)
+    .await
+    .context("Failed to get relationship mappings")?;
+
+    let mut mappings: HashMap<String, Vec<String>> = HashMap::new();
+    for (source_relationship, target_relationship) in rows {
+        mappings.entry(source_relationship).or_default().push(target_relationship);
+    }
+
+    Ok(mappings)
+}
+
 /// Set a prefix mapping (insert new source->target pair)
 /// With 1-to-N support, this adds a new target to a source (or does nothing if already exists)
 pub async fn set_prefix_mapping(
@@ -354,6 +393,113 @@ pub async fn set_ignored_items(
     Ok(())
 }
 
+/// A source -> targets mapping table and its key columns
+struct MappingTable {
+    table: &'static str,
+    source_column: &'static str,
+    target_column: &'static str,
+}
+
+impl MappingTable {
+    const FIELDS: Self = Self { table: "field_mappings", source_column: "source_field", target_column: "target_field" };
+    const PREFIXES: Self = Self { table: "prefix_mappings", source_column: "source_prefix", target_column: "target_prefix" };
+    const RELATIONSHIPS: Self = Self {
+        table: "relationship_mappings",
+        source_column: "source_relationship",
+        target_column: "target_relationship",
+    };
+}
+
+/// Replace all stored targets of one source in `table`
+async fn replace_targets(
+    tx: &mut sqlx::Transaction<'_, sqlx::Sqlite>,
+    table: MappingTable,
+    source_entity: &str,
+    target_entity: &str,
+    source: &str,
+    targets: &[String],
+) -> Result<()> {
+    sqlx::query(&format!(
+        "DELETE FROM {} WHERE source_entity = ? AND target_entity = ? AND {} = ?",
+        table.table, table.source_column,
+    ))
+    .bind(source_entity)
+    .bind(target_entity)
+    .bind(source)
+    .execute(&mut **tx)
+    .await
+    .with_context(|| format!("Failed to delete {} for {}", table.table, source))?;
+
+    for target in targets {
+        sqlx::query(&format!(
+            "INSERT INTO {table} (source_entity, target_entity, {source}, {target})
+             VALUES (?, ?, ?, ?)
+             ON CONFLICT(source_entity, target_entity, {source}, {target})
+             DO NOTHING",
+            table = table.table, source = table.source_column, target = table.target_column,
+        ))
+        .bind(source_entity)
+        .bind(target_entity)
+        .bind(source)
+        .bind(target)
+        .execute(&mut **tx)
+        .await
+        .with_context(|| format!("Failed to save {} {} -> {}", table.table, source, target))?;
+    }
+
+    Ok(())
+}
+
+/// Replace the stored targets of each changed source (and the ignored set, if given)
+/// in one transaction, so a batch of edits is saved entirely or not at all
+pub async fn save_mapping_changes(
+    pool: &SqlitePool,
+    source_entity: &str,
+    target_entity: &str,
+    changes: &MappingChanges,
+) -> Result<()> {
+    let mut tx = pool.begin().await.context("Failed to start transaction")?;
+
+    for (source_field, targets) in &changes.field_mappings {
+        replace_targets(&mut tx, MappingTable::FIELDS, source_entity, target_entity, source_field, targets).await?;
+    }
+    for (source_prefix, targets) in &changes.prefix_mappings {
+        replace_targets(&mut tx, MappingTable::PREFIXES, source_entity, target_entity, source_prefix, targets).await?;
+    }
+    for (source_relationship, targets) in &changes.relationship_mappings {
+        replace_targets(&mut tx, MappingTable::RELATIONSHIPS, source_entity, target_entity, source_relationship, targets).await?;
+    }
+
+    if let Some(ignored) = &changes.ignored_items {
+        sqlx::query(
+            "DELETE FROM ignored_items
+             WHERE source_entity = ? AND target_entity = ?",
+        )
+        .bind(source_entity)
+        .bind(target_entity)
+        .execute(&mut *tx)
+        .await
+        .context("Failed to clear ignored items")?;
+
+        for item_id in ignored {
+            sqlx::query(
+                "INSERT INTO ignored_items (source_entity, target_entity, item_id)
+                 VALUES (?, ?, ?)",
+            )
+            .bind(source_entity)
+            .bind(target_entity)
+            .bind(item_id)
+            .execute(&mut *tx)
+            .await
+            .context("Failed to insert ignored item")?;
+        }
+    }
+
+    tx.commit().await.context("Failed to commit mapping changes")?;
+
+    Ok(())
+}
+
 /// Clear all ignored items for entity comparison
 pub async fn clear_ignored_items(
     pool: &SqlitePool,
@@ -372,3 +518,40 @@ pub async fn clear_ignored_items(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::db;
+
+    #[tokio::test]
+    async fn test_save_mapping_changes_replaces_changed_sources() {
+        let pool = db::connect_memory().await.unwrap();
+        db::run_migrations(&pool).await.unwrap();
+
+        set_field_mapping(&pool, "account", "account", "name", "name").await.unwrap();
+        set_field_mapping(&pool, "account", "account", "phone", "telephone1").await.unwrap();
+        set_field_mapping(&pool, "account", "account", "fax", "fax").await.unwrap();
+
+        let changes = MappingChanges {
+            field_mappings: vec![
+                ("phone".to_string(), vec!["telephone1".to_string(), "telephone2".to_string()]),
+                ("fax".to_string(), vec![]),
+            ],
+            prefix_mappings: vec![("cgk_".to_string(), vec!["nrq_".to_string()])],
+            relationship_mappings: vec![("cgk_account_contacts".to_string(), vec!["nrq_account_contacts".to_string()])],
+            ignored_items: Some(HashSet::from(["fields:source:address1".to_string()])),
+        };
+        save_mapping_changes(&pool, "account", "account", &changes).await.unwrap();
+
+        let fields = get_field_mappings(&pool, "account", "account").await.unwrap();
+        assert_eq!(fields.len(), 2);
+        assert_eq!(fields["name"], vec!["name"]);
+        assert_eq!(fields["phone"], vec!["telephone1", "telephone2"]);
+        assert_eq!(get_prefix_mappings(&pool, "account", "account").await.unwrap()["cgk_"], vec!["nrq_"]);
+        assert!(get_ignored_items(&pool, "account", "account").await.unwrap().contains("fields:source:address1"));
+
+        let relationships = get_relationship_mappings(&pool, "account", "account").await.unwrap();
+        assert_eq!(relationships["cgk_account_contacts"], vec!["nrq_account_contacts"]);
+    }
+}
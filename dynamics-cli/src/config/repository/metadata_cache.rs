@@ -0,0 +1,123 @@
+//! Repository for the per-kind entity metadata cache
+//!
+//! Fields, forms and views are fetched by separate requests, so each is
+//! cached under its own (environment, entity, kind) key and expires on its own.
+
+use anyhow::{Context, Result};
+use serde::{de::DeserializeOwned, Serialize};
+use sqlx::SqlitePool;
+use std::time::Duration;
+
+/// Which metadata request a cache entry holds
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MetadataKind {
+    Fields,
+    Forms,
+    Views,
+    OptionSets,
+}
+
+impl MetadataKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MetadataKind::Fields => "fields",
+            MetadataKind::Forms => "forms",
+            MetadataKind::Views => "views",
+            MetadataKind::OptionSets => "option_sets",
+        }
+    }
+}
+
+/// Get a cached entry if it is younger than `max_age`
+pub async fn get<T: DeserializeOwned>(
+    pool: &SqlitePool,
+    environment_name: &str,
+    entity_name: &str,
+    kind: MetadataKind,
+    max_age: Duration,
+) -> Result<Option<T>> {
+    let data: Option<String> = sqlx::query_scalar(
+        "SELECT data FROM metadata_cache
+         WHERE environment_name = ? AND entity_name = ? AND kind = ?
+           AND cached_at >= datetime('now', ?)",
+    )
+    .bind(environment_name)
+    .bind(entity_name)
+    .bind(kind.as_str())
+    .bind(format!("-{} seconds", max_age.as_secs()))
+    .fetch_optional(pool)
+    .await
+    .with_context(|| format!("Failed to get cached {} for {}/{}", kind.as_str(), environment_name, entity_name))?;
+
+    data.map(|data| serde_json::from_str(&data).context("Failed to parse cached metadata JSON"))
+        .transpose()
+}
+
+/// Cache an entry, replacing (and renewing) any earlier one
+pub async fn set<T: Serialize>(
+    pool: &SqlitePool,
+    environment_name: &str,
+    entity_name: &str,
+    kind: MetadataKind,
+    data: &T,
+) -> Result<()> {
+    let data = serde_json::to_string(data).context("Failed to serialize metadata")?;
+
+    sqlx::query(
+        "INSERT OR REPLACE INTO metadata_cache (environment_name, entity_name, kind, data, cached_at)
+         VALUES (?, ?, ?, ?, CURRENT_TIMESTAMP)",
+    )
+    .bind(environment_name)
+    .bind(entity_name)
+    .bind(kind.as_str())
+    .bind(data)
+    .execute(pool)
+    .await
+    .with_context(|| format!("Failed to cache {} for {}/{}", kind.as_str(), environment_name, entity_name))?;
+
+    Ok(())
+}
+
+/// Delete every cached entry, returning how many there were
+pub async fn clear(pool: &SqlitePool) -> Result<u64> {
+    let result = sqlx::query("DELETE FROM metadata_cache")
+        .execute(pool)
+        .await
+        .context("Failed to clear metadata cache")?;
+
+    Ok(result.rows_affected())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::db;
+
+    #[tokio::test]
+    async fn test_entries_are_per_kind_and_expire() {
+        let pool = db::connect_memory().await.unwrap();
+        db::run_migrations(&pool).await.unwrap();
+        let ttl = Duration::from_secs(60 * 60);
+
+        set(&pool, "dev", "account", MetadataKind::Fields, &vec!["name", "accountnumber"]).await.unwrap();
+        set(&pool, "dev", "account", MetadataKind::Views, &vec!["Active Accounts"]).await.unwrap();
+
+        let fields: Option<Vec<String>> = get(&pool, "dev", "account", MetadataKind::Fields, ttl).await.unwrap();
+        assert_eq!(fields, Some(vec!["name".to_string(), "accountnumber".to_string()]));
+        let forms: Option<Vec<String>> = get(&pool, "dev", "account", MetadataKind::Forms, ttl).await.unwrap();
+        assert_eq!(forms, None);
+
+        sqlx::query("UPDATE metadata_cache SET cached_at = datetime('now', '-2 hours') WHERE kind = 'fields'")
+            .execute(&pool)
+            .await
+            .unwrap();
+        let fields: Option<Vec<String>> = get(&pool, "dev", "account", MetadataKind::Fields, ttl).await.unwrap();
+        assert_eq!(fields, None);
+        let views: Option<Vec<String>> = get(&pool, "dev", "account", MetadataKind::Views, ttl).await.unwrap();
+        assert_eq!(views, Some(vec!["Active Accounts".to_string()]));
+
+        assert_eq!(clear(&pool).await.unwrap(), 2);
+        let views: Option<Vec<String>> = get(&pool, "dev", "account", MetadataKind::Views, ttl).await.unwrap();
+        assert_eq!(views, None);
+    }
+}
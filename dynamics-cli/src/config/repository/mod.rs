@@ -11,4 +11,10 @@ pub mod entity_data_cache;
 pub mod mappings;
 pub mod examples;
 pub mod update_metadata;
-pub mod queue;
\ No newline at end of file
+pub mod queue;
+pub mod entity_set_overrides;
+pub mod copy_checkpoints;
+pub mod app_sessions;
+pub mod metadata_cache;
+pub mod saved_queries;
+pub mod failed_operations;
@@ -0,0 +1,136 @@
+//! Repository for saved FQL queries
+
+use anyhow::{Context, Result};
+use sqlx::SqlitePool;
+use crate::config::models::DbSavedQuery;
+
+/// An FQL query saved under a name
+#[derive(Debug, Clone, PartialEq)]
+pub struct SavedQuery {
+    pub name: String,
+    pub fql: String,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub last_used: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<DbSavedQuery> for SavedQuery {
+    fn from(row: DbSavedQuery) -> Self {
+        Self {
+            name: row.name,
+            fql: row.fql,
+            created_at: row.created_at,
+            last_used: row.last_used,
+        }
+    }
+}
+
+/// Save a query, replacing the text of an existing one with the same name
+///
+/// Overwriting keeps the original `created_at`; either way the query counts as just used.
+pub async fn insert(pool: &SqlitePool, name: &str, fql: &str) -> Result<()> {
+    sqlx::query(
+        r#"
+        INSERT INTO saved_queries (name, fql, last_used)
+        VALUES (?, ?, CURRENT_TIMESTAMP)
+        ON CONFLICT(name) DO UPDATE SET
+            fql = excluded.fql,
+            last_used = CURRENT_TIMESTAMP
+        "#,
+    )
+    .bind(name)
+    .bind(fql)
+    .execute(pool)
+    .await
+    .with_context(|| format!("Failed to save query '{}'", name))?;
+
+    log::info!("Saved query: {}", name);
+    Ok(())
+}
+
+/// Get saved query by name
+pub async fn get(pool: &SqlitePool, name: &str) -> Result<Option<SavedQuery>> {
+    let row: Option<DbSavedQuery> = sqlx::query_as(
+        "SELECT name, fql, created_at, last_used FROM saved_queries WHERE name = ?",
+    )
+    .bind(name)
+    .fetch_optional(pool)
+    .await
+    .with_context(|| format!("Failed to get saved query '{}'", name))?;
+
+    Ok(row.map(SavedQuery::from))
+}
+
+/// List saved queries, most recently used first
+pub async fn list(pool: &SqlitePool) -> Result<Vec<SavedQuery>> {
+    let rows: Vec<DbSavedQuery> = sqlx::query_as(
+        "SELECT name, fql, created_at, last_used FROM saved_queries ORDER BY last_used DESC, name",
+    )
+    .fetch_all(pool)
+    .await
+    .context("Failed to list saved queries")?;
+
+    Ok(rows.into_iter().map(SavedQuery::from).collect())
+}
+
+/// Delete saved query by name
+pub async fn delete(pool: &SqlitePool, name: &str) -> Result<()> {
+    let result = sqlx::query("DELETE FROM saved_queries WHERE name = ?")
+        .bind(name)
+        .execute(pool)
+        .await
+        .with_context(|| format!("Failed to delete saved query '{}'", name))?;
+
+    if result.rows_affected() == 0 {
+        anyhow::bail!("Saved query '{}' not found", name);
+    }
+
+    log::info!("Deleted saved query: {}", name);
+    Ok(())
+}
+
+/// Update saved query last_used timestamp
+pub async fn touch(pool: &SqlitePool, name: &str) -> Result<()> {
+    sqlx::query("UPDATE saved_queries SET last_used = CURRENT_TIMESTAMP WHERE name = ?")
+        .bind(name)
+        .execute(pool)
+        .await
+        .with_context(|| format!("Failed to update saved query '{}'", name))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::db;
+
+    #[tokio::test]
+    async fn test_saved_queries_sorted_by_recency() {
+        let pool = db::connect_memory().await.unwrap();
+        db::run_migrations(&pool).await.unwrap();
+
+        insert(&pool, "active accounts", ".account | .name").await.unwrap();
+        insert(&pool, "contacts", ".contact | .fullname").await.unwrap();
+        sqlx::query("UPDATE saved_queries SET last_used = datetime('now', '-1 hour'), created_at = datetime('now', '-1 day')")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        touch(&pool, "contacts").await.unwrap();
+        let names: Vec<String> = list(&pool).await.unwrap().into_iter().map(|q| q.name).collect();
+        assert_eq!(names, vec!["contacts", "active accounts"]);
+
+        // Overwriting replaces the text but not the creation time
+        let before = get(&pool, "active accounts").await.unwrap().unwrap();
+        insert(&pool, "active accounts", ".account | .name, .revenue").await.unwrap();
+        let after = get(&pool, "active accounts").await.unwrap().unwrap();
+        assert_eq!(after.fql, ".account | .name, .revenue");
+        assert_eq!(after.created_at, before.created_at);
+        assert_eq!(list(&pool).await.unwrap()[0].name, "active accounts");
+
+        delete(&pool, "contacts").await.unwrap();
+        assert!(get(&pool, "contacts").await.unwrap().is_none());
+        assert!(delete(&pool, "contacts").await.is_err());
+        assert_eq!(list(&pool).await.unwrap().len(), 1);
+    }
+}
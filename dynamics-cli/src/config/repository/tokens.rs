@@ -93,9 +93,9 @@ pub async fn is_valid(pool: &SqlitePool, env_name: &str) -> Result<bool> {
     }
 }
 
-/// Clean up expired tokens
+/// Clean up expired tokens that can't be refreshed
 pub async fn cleanup_expired(pool: &SqlitePool) -> Result<u64> {
-    let result = sqlx::query("DELETE FROM tokens WHERE expires_at <= CURRENT_TIMESTAMP")
+    let result = sqlx::query("DELETE FROM tokens WHERE expires_at <= CURRENT_TIMESTAMP AND refresh_token IS NULL")
         .execute(pool)
         .await
         .context("Failed to cleanup expired tokens")?;
@@ -1,9 +1,11 @@
-//! C# mapping file parser
+//! C# mapping file parser and writer
 //!
 //! Extracts field mappings from C# Dynamics 365 migration mapping files.
 //! These files contain InternalMapping methods that map source fields to target fields.
+//! [`write_cs_field_mappings`] produces the same shape, so exported files import again.
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write;
 use regex::Regex;
 
 /// Parse C# field mappings from file content
@@ -109,6 +111,73 @@ pub fn parse_cs_field_mappings(content: &str) -> Result<HashMap<String, String>,
     Ok(mappings)
 }
 
+/// Render field mappings as a C# mapping class the parser above reads back
+///
+/// `mappings` is source field -> target fields; a source mapped to several
+/// targets gets one initializer line per target. A header comment records
+/// the entities and when the file was exported. Relationships aren't set in
+/// the initializer, so `relationships` (source -> target relationship) are
+/// listed as comments for the hand-written association code.
+pub fn write_cs_field_mappings(
+    source_entity: &str,
+    target_entity: &str,
+    mappings: &BTreeMap<String, Vec<String>>,
+    relationships: &BTreeMap<String, Vec<String>>,
+    exported_at: chrono::DateTime<chrono::Utc>,
+) -> String {
+    let mut out = String::new();
+    let _ = writeln!(out, "// Field mappings exported by dynamics-cli");
+    let _ = writeln!(out, "// Source entity: {}", source_entity);
+    let _ = writeln!(out, "// Target entity: {}", target_entity);
+    let _ = writeln!(out, "// Exported: {}", exported_at.format("%Y-%m-%d %H:%M:%S UTC"));
+    if !relationships.is_empty() {
+        let _ = writeln!(out, "//");
+        let _ = writeln!(out, "// Relationship mappings:");
+        for (source_relationship, target_relationships) in relationships {
+            for target_relationship in target_relationships {
+                let _ = writeln!(out, "//   {} -> {}", source_relationship, target_relationship);
+            }
+        }
+    }
+    let _ = writeln!(out);
+    let _ = writeln!(
+        out,
+        "public class {}Mapping : DataverseToDataverseMapping<{}, {}>",
+        pascal_case(target_entity), source_entity, target_entity
+    );
+    let _ = writeln!(out, "{{");
+    let _ = writeln!(
+        out,
+        "    protected override {} InternalMapping({} source, MigrationOptions options)",
+        target_entity, source_entity
+    );
+    let _ = writeln!(out, "    {{");
+    let _ = writeln!(out, "        var entity = new {}(source.Id)", target_entity);
+    let _ = writeln!(out, "        {{");
+    for (source_field, target_fields) in mappings {
+        for target_field in target_fields {
+            let _ = writeln!(out, "            {} = source.{},", target_field, source_field);
+        }
+    }
+    let _ = writeln!(out, "        }};");
+    let _ = writeln!(out, "        return entity;");
+    let _ = writeln!(out, "    }}");
+    let _ = writeln!(out, "}}");
+    out
+}
+
+/// `nrq_deadline` -> `NrqDeadline`, for the generated class name
+fn pascal_case(name: &str) -> String {
+    name.split('_')
+        .map(|part| {
+            let mut chars = part.chars();
+            chars.next()
+                .map(|first| first.to_uppercase().chain(chars).collect::<String>())
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -276,6 +345,37 @@ mod tests {
         assert!(!result.contains_key("CreatedOn"));
     }
 
+    #[test]
+    fn test_written_file_parses_back() {
+        let content = r#"
+        protected override nrq_Request InternalMapping(cgk_request sourceRequest, MigrationOptions options)
+        {
+            var request = new nrq_Request(sourceRequest.Id)
+            {
+                nrq_AccountId = sourceRequest.cgk_accountid,
+                nrq_CategoryId = CategoryXRef.GetTargetReference(sourceRequest.cgk_categoryid?.Id),
+                nrq_Name = sourceRequest.cgk_name,
+            };
+            return request;
+        }
+        "#;
+        let imported = parse_cs_field_mappings(content).unwrap();
+
+        let mappings: BTreeMap<String, Vec<String>> = imported.iter()
+            .map(|(source, target)| (source.clone(), vec![target.clone()]))
+            .collect();
+        let exported_at = chrono::DateTime::parse_from_rfc3339("2024-05-01T12:30:00Z").unwrap().to_utc();
+        let relationships = BTreeMap::from([
+            ("cgk_request_contact".to_string(), vec!["nrq_request_contact".to_string()]),
+        ]);
+        let written = write_cs_field_mappings("cgk_request", "nrq_request", &mappings, &relationships, exported_at);
+
+        assert!(written.starts_with("// Field mappings exported by dynamics-cli\n// Source entity: cgk_request\n// Target entity: nrq_request\n// Exported: 2024-05-01 12:30:00 UTC\n"));
+        assert!(written.contains("// Relationship mappings:\n//   cgk_request_contact -> nrq_request_contact\n"));
+        assert!(written.contains("public class NrqRequestMapping : DataverseToDataverseMapping<cgk_request, nrq_request>"));
+        assert_eq!(parse_cs_field_mappings(&written).unwrap(), imported);
+    }
+
     #[test]
     fn test_real_requests_file() {
         // Test with a subset of the real Requests.cs pattern
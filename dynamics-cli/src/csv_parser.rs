@@ -2,6 +2,9 @@
 //!
 //! Parses CSV files containing field mappings for Dynamics 365 migration.
 //! CSV format: source_field, target_field, match_type, notes
+//!
+//! Other CSVs with two or more columns are read as a plain `CsvTable`, with the
+//! source/target columns detected from the header or picked by the user.
 
 use std::collections::{HashMap, HashSet};
 use csv::ReaderBuilder;
@@ -56,11 +59,13 @@ pub struct CsvImportData {
 /// Returns: CsvImportData with mappings distributed by type
 pub fn parse_csv_field_mappings(content: &str) -> Result<CsvImportData, String> {
     let mut data = CsvImportData::default();
+    let content = strip_bom(content);
 
     // Parse CSV
     let mut reader = ReaderBuilder::new()
         .has_headers(true)
         .trim(csv::Trim::All)
+        .delimiter(detect_delimiter(content))
         .from_reader(content.as_bytes());
 
     let mut row_count = 0;
@@ -181,6 +186,111 @@ pub fn parse_csv_field_mappings(content: &str) -> Result<CsvImportData, String>
     Ok(data)
 }
 
+/// Header names recognised as the source column
+const SOURCE_HEADERS: &[&str] = &["source", "source_field", "sourcefield", "src", "from", "old", "old_field"];
+
+/// Header names recognised as the target column
+const TARGET_HEADERS: &[&str] = &["target", "target_field", "targetfield", "tgt", "to", "new", "new_field", "destination"];
+
+/// A CSV file read as plain rows, before deciding which columns hold what
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CsvTable {
+    /// Column names from the header row, or "Column N" when there is none
+    pub headers: Vec<String>,
+    /// Data rows; short rows are padded with empty cells
+    pub rows: Vec<Vec<String>>,
+    /// 1-based line of the first data row, for error reports
+    pub first_line: usize,
+    /// (source, target) column indices recognised from the header
+    pub detected: Option<(usize, usize)>,
+}
+
+/// Whether the content uses the four-column match_type format
+pub fn is_typed_mapping_csv(content: &str) -> bool {
+    let header = strip_bom(content).lines().next().unwrap_or("").to_lowercase();
+    header.contains("source_field") && header.contains("match_type")
+}
+
+/// Read a CSV of two or more columns as a plain table
+///
+/// Handles a UTF-8 BOM, quoted fields and both `,` and `;` delimiters. The first
+/// row counts as a header when it names a source or target column.
+pub fn read_csv_table(content: &str) -> Result<CsvTable, String> {
+    let content = strip_bom(content);
+    let mut reader = ReaderBuilder::new()
+        .has_headers(false)
+        .flexible(true)
+        .trim(csv::Trim::All)
+        .delimiter(detect_delimiter(content))
+        .from_reader(content.as_bytes());
+
+    let mut records: Vec<(usize, Vec<String>)> = Vec::new();
+    for result in reader.records() {
+        let record = result.map_err(|e| format!("Failed to read CSV: {}", e))?;
+        let line = record.position().map(|p| p.line() as usize).unwrap_or(records.len() + 1);
+        let cells: Vec<String> = record.iter().map(str::to_string).collect();
+        if cells.iter().any(|cell| !cell.is_empty()) {
+            records.push((line, cells));
+        }
+    }
+
+    let columns = records.iter().map(|(_, cells)| cells.len()).max().unwrap_or(0);
+    if columns < 2 {
+        return Err("CSV needs at least two columns (source and target)".to_string());
+    }
+    for (_, cells) in &mut records {
+        cells.resize(columns, String::new());
+    }
+
+    let first = &records[0].1;
+    let source = find_column(first, SOURCE_HEADERS, "source");
+    let target = find_column(first, TARGET_HEADERS, "target");
+
+    let (headers, data) = if source.is_some() || target.is_some() {
+        (first.clone(), &records[1..])
+    } else {
+        ((1..=columns).map(|i| format!("Column {}", i)).collect(), &records[..])
+    };
+
+    Ok(CsvTable {
+        headers,
+        first_line: data.first().map(|(line, _)| *line).unwrap_or(1),
+        rows: data.iter().map(|(_, cells)| cells.clone()).collect(),
+        detected: match (source, target) {
+            (Some(s), Some(t)) if s != t => Some((s, t)),
+            _ => None,
+        },
+    })
+}
+
+fn strip_bom(content: &str) -> &str {
+    content.strip_prefix('\u{feff}').unwrap_or(content)
+}
+
+/// Pick `;` when the header line has more of them (outside quotes) than `,`
+fn detect_delimiter(content: &str) -> u8 {
+    let header = content.lines().next().unwrap_or("");
+    let mut in_quotes = false;
+    let (mut commas, mut semicolons) = (0, 0);
+    for c in header.chars() {
+        match c {
+            '"' => in_quotes = !in_quotes,
+            ',' if !in_quotes => commas += 1,
+            ';' if !in_quotes => semicolons += 1,
+            _ => {}
+        }
+    }
+    if semicolons > commas { b';' } else { b',' }
+}
+
+/// Index of the first header cell naming this column
+fn find_column(header: &[String], names: &[&str], prefix: &str) -> Option<usize> {
+    header.iter().position(|cell| {
+        let cell = cell.trim().to_lowercase().replace([' ', '-'], "_");
+        names.contains(&cell.as_str()) || cell.starts_with(prefix)
+    })
+}
+
 /// Extract prefix from field name
 /// Returns the prefix including the separator (e.g., "cgk_accountid" -> "cgk_")
 fn extract_prefix(field: &str) -> Option<String> {
@@ -302,4 +412,35 @@ field1,field2,unknown_type,Some field
         assert_eq!(result.imported_mappings.len(), 1);
         assert_eq!(result.imported_mappings.get("field1"), Some(&"field2".to_string()));
     }
+
+    #[test]
+    fn test_read_table_detects_header_columns() {
+        let csv = "\u{feff}Notes;Old Field;New Field\n\"a; b\";name;fullname\n;email;emailaddress1\n";
+        let table = read_csv_table(csv).unwrap();
+
+        assert_eq!(table.headers, ["Notes", "Old Field", "New Field"]);
+        assert_eq!(table.detected, Some((1, 2)));
+        assert_eq!(table.first_line, 2);
+        assert_eq!(table.rows[0], ["a; b", "name", "fullname"]);
+        assert_eq!(table.rows[1], ["", "email", "emailaddress1"]);
+    }
+
+    #[test]
+    fn test_read_table_without_header_needs_column_choice() {
+        let table = read_csv_table("name,fullname\n\nphone\n").unwrap();
+
+        assert_eq!(table.headers, ["Column 1", "Column 2"]);
+        assert_eq!(table.detected, None);
+        assert_eq!(table.rows, [vec!["name", "fullname"], vec!["phone", ""]]);
+        assert!(read_csv_table("just_one\nline\n").is_err());
+    }
+
+    #[test]
+    fn test_typed_mapping_csv_with_bom_and_semicolons() {
+        let csv = "\u{feff}source_field;target_field;match_type;notes\nfield1;field2;manual;\n";
+        assert!(is_typed_mapping_csv(csv));
+
+        let result = parse_csv_field_mappings(csv).unwrap();
+        assert_eq!(result.manual_mappings.get("field1"), Some(&"field2".to_string()));
+    }
 }
@@ -1,3 +1,4 @@
+use anyhow::Result;
 use std::collections::HashMap;
 
 #[derive(Debug, Clone, PartialEq)]
@@ -8,12 +9,66 @@ pub struct Query {
     pub joins: Vec<Join>,
     pub order: Vec<OrderBy>,
     pub aggregations: Vec<Aggregation>,
-    pub group_by: Vec<String>,
+    pub group_by: Vec<Attribute>, // Grouped columns; `entity_alias` picks a joined entity
     pub having: Option<Filter>,
     pub limit: Option<u32>,
     pub page: Option<(u32, u32)>, // (page_number, page_size)
     pub distinct: bool,
     pub options: QueryOptions,
+    pub unions: Vec<Query>, // Additional branches from union(...), each its own FetchXML document
+}
+
+impl Query {
+    /// Entity names of this query and each union branch, in FetchXML document order
+    pub fn entity_names(&self) -> Vec<String> {
+        std::iter::once(self.entity.name.clone())
+            .chain(self.unions.iter().map(|branch| branch.entity.name.clone()))
+            .collect()
+    }
+
+    /// Entity a qualified column belongs to: `None` for the main entity (unqualified,
+    /// or qualified with its name or alias), otherwise the join alias
+    pub fn column_scope<'a>(&self, entity_alias: Option<&'a str>) -> Option<&'a str> {
+        entity_alias.filter(|alias| {
+            Some(*alias) != self.entity.alias.as_deref() && *alias != self.entity.name
+        })
+    }
+
+    /// Whether `attribute` of the entity in `scope` (see `column_scope`) is listed in `group()`
+    pub fn is_grouped(&self, attribute: &str, scope: Option<&str>) -> bool {
+        self.group_by
+            .iter()
+            .any(|group| {
+                group.name == attribute && self.column_scope(group.entity_alias.as_deref()) == scope
+            })
+    }
+
+    /// Turn this query (and each union branch) into a `count()` of its matching rows
+    ///
+    /// Filters and joins are kept so the count matches what the query would
+    /// return; selected columns, ordering, paging and limits are dropped.
+    pub fn into_count(mut self) -> Result<Self> {
+        if !self.aggregations.is_empty() || !self.group_by.is_empty() {
+            anyhow::bail!("Count-only queries can't also use aggregations or group()");
+        }
+
+        self.attributes.clear();
+        self.order.clear();
+        self.limit = None;
+        self.page = None;
+        self.distinct = false;
+        for join in &mut self.joins {
+            join.attributes.clear();
+        }
+        self.aggregations.push(Aggregation {
+            function: AggregationFunction::Count,
+            attribute: None,
+            alias: Some("count".to_string()),
+            entity_alias: None,
+        });
+        self.unions = self.unions.into_iter().map(Query::into_count).collect::<Result<_>>()?;
+        Ok(self)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -58,6 +113,8 @@ pub enum FilterOperator {
     Between,
     Null,
     NotNull,
+    /// Relative date operator, holding the FetchXML operator name (e.g. "last-x-days")
+    RelativeDate(&'static str),
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -70,6 +127,8 @@ pub enum FilterValue {
     List(Vec<FilterValue>),
     Range(Box<FilterValue>, Box<FilterValue>),
     RangeTraditional(Box<FilterValue>, Box<FilterValue>),
+    /// Ready-made LIKE pattern, emitted as-is (wildcards already applied)
+    Pattern(String),
     Null,
 }
 
@@ -133,3 +192,27 @@ pub struct QueryOptions {
     pub formatted: bool,
     pub custom_options: HashMap<String, String>,
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::fql::{parse, to_fetchxml, tokenize};
+
+    fn count_fetchxml(fql: &str) -> anyhow::Result<Vec<String>> {
+        let query = parse(tokenize(fql)?, fql)?.into_count()?;
+        to_fetchxml(query)
+    }
+
+    #[test]
+    fn test_count_query_keeps_filters_and_drops_columns() {
+        let xml = count_fetchxml(".account | .name, .revenue | .statecode == 0 | order(.name) | limit(10)").unwrap();
+        assert_eq!(xml.len(), 1);
+        assert!(xml[0].contains("aggregate=\"true\""));
+        assert!(xml[0].contains("<attribute name=\"accountid\" aggregate=\"count\" alias=\"count\" />"));
+        assert!(xml[0].contains("statecode"));
+        assert!(!xml[0].contains("name=\"revenue\""));
+        assert!(!xml[0].contains("<order"));
+        assert!(!xml[0].contains("top="));
+
+        assert!(count_fetchxml(".account | group(.statecode) | count()").is_err());
+    }
+}
@@ -28,6 +28,8 @@ pub enum Token {
     Page,
     Distinct,
     Options,
+    Union,
+    Filter,
     Count,
     Sum,
     Avg,
@@ -72,6 +74,8 @@ pub enum Token {
 pub struct TokenPosition {
     pub line: usize,
     pub column: usize,
+    /// Byte offset of the token in the input
+    pub offset: usize,
 }
 
 #[derive(Debug, Clone)]
@@ -80,6 +84,9 @@ pub struct LocatedToken {
     pub position: TokenPosition,
 }
 
+/// Number of lines shown before the failing line when rendering a ParseError
+const ERROR_CONTEXT_LINES: usize = 2;
+
 /// Error type for parsing errors with position information
 #[derive(Debug)]
 pub struct ParseError {
@@ -88,36 +95,59 @@ pub struct ParseError {
     pub input: String,
 }
 
-impl std::fmt::Display for ParseError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        // Find the line in the input
+impl ParseError {
+    /// Byte offset of the failing token in the input
+    pub fn offset(&self) -> usize {
+        self.position.offset
+    }
+
+    /// 1-based line of the failing token
+    pub fn line(&self) -> usize {
+        self.position.line
+    }
+
+    /// 1-based column of the failing token
+    pub fn column(&self) -> usize {
+        self.position.column
+    }
+
+    /// Render the error with a caret under the failing token and up to
+    /// `context_lines` preceding lines of the query
+    pub fn render(&self, context_lines: usize) -> String {
         let lines: Vec<&str> = self.input.lines().collect();
-        if self.position.line > 0 && self.position.line <= lines.len() {
-            let line = lines[self.position.line - 1];
-            writeln!(
-                f,
-                "  --> line {}, column {}",
-                self.position.line, self.position.column
-            )?;
-            let line_number_prefix = format!("{:3} | ", self.position.line);
-            writeln!(
-                f,
-                "{}",
-                line_number_prefix.replace(|c: char| c.is_ascii_digit(), " ")
-            )?; // Align the separator | with content |
-            writeln!(f, "{}{}", line_number_prefix, line)?;
-            let pointer_prefix = format!("{:3} | ", ""); // Same width as line number prefix but with spaces
-            writeln!(
-                f,
-                "{}{}^",
-                pointer_prefix,
-                " ".repeat(self.position.column.saturating_sub(1))
-            )?;
-            writeln!(f, "error: {}", self.message)?;
-        } else {
-            writeln!(f, "error: {}", self.message)?;
+        let line_number = self.position.line;
+
+        if line_number == 0 || line_number > lines.len() {
+            return format!("error: {}\n", self.message);
         }
-        Ok(())
+
+        let first_line = line_number.saturating_sub(context_lines).max(1);
+        let width = line_number.to_string().len().max(3);
+        let gutter = format!("{:width$} | ", "", width = width);
+
+        let mut out = String::new();
+        out.push_str(&format!(
+            "  --> line {}, column {}\n",
+            self.position.line, self.position.column
+        ));
+        out.push_str(gutter.trim_end());
+        out.push('\n');
+        for (index, line) in lines.iter().enumerate().take(line_number).skip(first_line - 1) {
+            out.push_str(&format!("{:width$} | {}\n", index + 1, line, width = width));
+        }
+        out.push_str(&format!(
+            "{}{}^\n",
+            gutter,
+            " ".repeat(self.position.column.saturating_sub(1))
+        ));
+        out.push_str(&format!("error: {}\n", self.message));
+        out
+    }
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.render(ERROR_CONTEXT_LINES))
     }
 }
 
@@ -160,6 +190,7 @@ impl Lexer {
             let position = TokenPosition {
                 line: self.line,
                 column: self.column,
+                offset: pos,
             };
 
             match ch {
@@ -269,7 +300,7 @@ impl Lexer {
                         || self.peek_char().is_some_and(|c| c.is_ascii_digit())
                     {
                         // Negative number
-                        let (token, consumed) = self.parse_number(pos)?;
+                        let (token, consumed) = self.locate(self.parse_number(pos), &position)?;
                         self.add_token(token, position, consumed);
                         // Skip the characters we consumed (minus the current one)
                         for _ in 1..consumed {
@@ -285,7 +316,7 @@ impl Lexer {
 
                 // String literals with double or single quotes
                 quote_char if quote_char == '"' || quote_char == '\'' => {
-                    let (string_val, consumed) = self.parse_string_literal(pos)?;
+                    let (string_val, consumed) = self.locate(self.parse_string_literal(pos), &position)?;
                     self.add_token(Token::String(string_val), position, consumed);
                     // Skip the characters we consumed (minus the current one)
                     for _ in 1..consumed {
@@ -295,7 +326,7 @@ impl Lexer {
 
                 // Date literals starting with @
                 '@' => {
-                    let (date_val, consumed) = self.parse_date_literal(pos)?;
+                    let (date_val, consumed) = self.locate(self.parse_date_literal(pos), &position)?;
                     self.add_token(Token::Date(date_val), position, consumed);
                     // Skip the characters we consumed (minus the current one)
                     for _ in 1..consumed {
@@ -305,7 +336,7 @@ impl Lexer {
 
                 // Numbers
                 ch if ch.is_ascii_digit() => {
-                    let (token, consumed) = self.parse_number(pos)?;
+                    let (token, consumed) = self.locate(self.parse_number(pos), &position)?;
                     self.add_token(token, position, consumed);
                     // Skip the characters we consumed (minus the current one)
                     for _ in 1..consumed {
@@ -315,7 +346,8 @@ impl Lexer {
 
                 // Identifiers and keywords
                 ch if is_identifier_start(ch) => {
-                    let (token, consumed) = self.parse_keyword_or_identifier(pos)?;
+                    let (token, consumed) = self.locate(self.parse_keyword_or_identifier(pos), &position)?;
+                    let token = self.clause_keyword_as_name(token);
                     self.add_token(token, position, consumed);
                     // Skip the characters we consumed (minus the current one)
                     for _ in 1..consumed {
@@ -332,6 +364,7 @@ impl Lexer {
             position: TokenPosition {
                 line: self.line,
                 column: self.column,
+                offset: self.input.len(),
             },
         });
 
@@ -382,6 +415,11 @@ impl Lexer {
         }))
     }
 
+    /// Attach the token position to errors from the literal helpers
+    fn locate<T>(&self, result: Result<T>, position: &TokenPosition) -> Result<T> {
+        result.or_else(|e| self.error(&e.to_string(), position.clone()))
+    }
+
     fn parse_number(&self, start: usize) -> Result<(Token, usize)> {
         parse_number(&self.input, start)
     }
@@ -397,6 +435,20 @@ impl Lexer {
     fn parse_keyword_or_identifier(&self, start: usize) -> Result<(Token, usize)> {
         parse_keyword_or_identifier(&self.input, start)
     }
+
+    /// `union` and `filter` only start a clause after a pipe; right after `.`
+    /// or `as` they are attribute names and aliases like any other word
+    fn clause_keyword_as_name(&self, token: Token) -> Token {
+        let follows_name_position = matches!(
+            self.tokens.last().map(|t| &t.token),
+            Some(Token::Dot) | Some(Token::As)
+        );
+        match token {
+            Token::Union if follows_name_position => Token::Identifier("union".to_string()),
+            Token::Filter if follows_name_position => Token::Identifier("filter".to_string()),
+            token => token,
+        }
+    }
 }
 
 /// Helper function to determine if a character can start an identifier
@@ -556,6 +608,8 @@ fn parse_keyword_or_identifier(input: &str, start: usize) -> Result<(Token, usiz
         "page" => Token::Page,
         "distinct" => Token::Distinct,
         "options" => Token::Options,
+        "union" => Token::Union,
+        "filter" => Token::Filter,
         "count" => Token::Count,
         "sum" => Token::Sum,
         "avg" => Token::Avg,
@@ -571,3 +625,23 @@ fn parse_keyword_or_identifier(input: &str, start: usize) -> Result<(Token, usiz
 
     Ok((token, consumed))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lexer_error_reports_offset_line_and_column() {
+        let input = ".account\n| .name == \"unterminated";
+
+        let error = tokenize(input).expect_err("Expected tokenization to fail");
+        let parse_error = error
+            .downcast_ref::<ParseError>()
+            .expect("Lexer errors should carry a ParseError");
+
+        assert_eq!(parse_error.line(), 2);
+        assert_eq!(parse_error.column(), 12);
+        assert_eq!(parse_error.offset(), 20);
+        assert!(parse_error.message.contains("Unterminated string"));
+    }
+}
@@ -1,8 +1,12 @@
 pub mod ast;
+pub mod highlight;
 pub mod lexer;
 pub mod parser;
+pub mod validate;
 pub mod xml;
 
+pub use highlight::format_fetchxml;
 pub use lexer::tokenize;
 pub use parser::parse;
+pub use validate::{validate, ValidationMetadata};
 pub use xml::{to_fetchxml, to_fetchxml_pretty};
@@ -18,19 +18,28 @@ pub fn parse(tokens: Vec<LocatedToken>, input: &str) -> Result<Query> {
     // Try parsing with the regular parser
     let mut parser = Parser::new(plain_tokens);
     match parser.parse_query() {
-        Ok(query) => Ok(query),
-        Err(_) => {
-            // If parsing failed, try to give better error messages
+        Ok(query) => {
+            validate_union_columns(&query, &parser.union_starts, &tokens, input)?;
+            Ok(query)
+        }
+        Err(e) => {
             if tokens.is_empty() {
                 return Err(anyhow::anyhow!("Empty input"));
             }
 
-            // Look for common issues and provide position-aware errors
-            let mut error_message = "Parse error".to_string();
-            let mut error_position = tokens[0].position.clone();
+            // Prefer the token the parser pinpointed, otherwise the last one it consumed
+            let index = parser
+                .error_position
+                .unwrap_or_else(|| parser.current.saturating_sub(1));
+            let mut error_message = e.to_string();
+            let mut error_position = tokens
+                .get(index)
+                .or_else(|| tokens.last())
+                .map(|token| token.position.clone())
+                .unwrap_or_else(|| tokens[0].position.clone());
 
             // Check for incomplete expressions (e.g., ".account | .name ==")
-            if let Some(last_token) = tokens.last() {
+            if let Some(last_token) = tokens.last().filter(|_| parser.error_position.is_none()) {
                 if matches!(
                     last_token.token,
                     Token::Equal | Token::NotEqual | Token::GreaterThan | Token::LessThan
@@ -46,15 +55,98 @@ pub fn parse(tokens: Vec<LocatedToken>, input: &str) -> Result<Query> {
                 }
             }
 
-            let parse_error = ParseError {
+            Err(ParseError {
                 message: error_message,
                 position: error_position,
                 input: input.to_string(),
-            };
+            }
+            .into())
+        }
+    }
+}
+
+/// Check that every union branch selects the same number of columns as the main query
+///
+/// `union_starts` holds the token index of each branch's `union` keyword, so the
+/// error can point at the offending branch.
+fn validate_union_columns(
+    query: &Query,
+    union_starts: &[usize],
+    tokens: &[LocatedToken],
+    input: &str,
+) -> Result<()> {
+    if query.unions.is_empty() {
+        return Ok(());
+    }
+
+    let branch_error = |index: usize, message: String| {
+        let position = union_starts
+            .get(index)
+            .and_then(|&token_index| tokens.get(token_index))
+            .map(|token| token.position.clone())
+            .unwrap_or_else(|| tokens[0].position.clone());
+        anyhow::Error::from(ParseError {
+            message,
+            position,
+            input: input.to_string(),
+        })
+    };
+
+    let Some(expected) = union_column_count(query) else {
+        return Err(branch_error(
+            0,
+            "Union queries must select explicit columns (no '.*' or empty selection)".to_string(),
+        ));
+    };
+
+    for (index, branch) in query.unions.iter().enumerate() {
+        match union_column_count(branch) {
+            Some(count) if count == expected => {}
+            Some(count) => {
+                return Err(branch_error(
+                    index,
+                    format!(
+                        "Union branch {} (.{}) selects {} column(s), but the main query selects {}",
+                        index + 1,
+                        branch.entity.name,
+                        count,
+                        expected
+                    ),
+                ));
+            }
+            None => {
+                return Err(branch_error(
+                    index,
+                    format!(
+                        "Union branch {} (.{}) must select explicit columns (no '.*' or empty selection)",
+                        index + 1,
+                        branch.entity.name
+                    ),
+                ));
+            }
+        }
+    }
 
-            Err(anyhow::anyhow!("{}", parse_error))
+    Ok(())
+}
+
+/// Number of selected columns, or None when the query selects all attributes
+fn union_column_count(query: &Query) -> Option<usize> {
+    let attributes = query
+        .attributes
+        .iter()
+        .chain(query.joins.iter().flat_map(|join| join.attributes.iter()));
+
+    let mut count = 0;
+    for attribute in attributes {
+        if attribute.name == "*" {
+            return None;
         }
+        count += 1;
     }
+    count += query.aggregations.len();
+
+    if count == 0 { None } else { Some(count) }
 }
 
 /// Different types of query sections that can appear after pipes
@@ -71,6 +163,8 @@ enum SectionType {
     Page,
     Distinct,
     Options,
+    Union,
+    FilterStage,
 }
 
 /// Parser state for tracking current position and context
@@ -78,11 +172,23 @@ enum SectionType {
 struct Parser {
     tokens: Vec<Token>,
     current: usize,
+    /// Token index of each `union` keyword, in branch order
+    union_starts: Vec<usize>,
+    /// Token index of the last error raised with a precise location
+    error_position: Option<usize>,
+    /// Column aliases declared so far in the query (or union branch) being parsed
+    column_aliases: Vec<String>,
 }
 
 impl Parser {
     fn new(tokens: Vec<Token>) -> Self {
-        Self { tokens, current: 0 }
+        Self {
+            tokens,
+            current: 0,
+            union_starts: Vec::new(),
+            error_position: None,
+            column_aliases: Vec::new(),
+        }
     }
 
     /// Parse the main query structure
@@ -103,11 +209,18 @@ impl Parser {
             page: None,
             distinct: false,
             options: QueryOptions::default(),
+            unions: Vec::new(),
         };
 
         // Parse entity selection (required)
         query.entity = self.parse_entity()?;
 
+        // Attribute selected by the previous section, used by subject-less filter(...) stages
+        let mut subject: Option<(String, Option<String>)> = None;
+
+        // Token ranges of attribute sections, for locating ungrouped columns
+        let mut attribute_sections: Vec<(usize, usize)> = Vec::new();
+
         // Parse the rest of the query in pipe-separated sections
         while !self.is_at_end() && self.peek() == Some(&Token::Pipe) {
             self.advance(); // consume '|'
@@ -126,13 +239,23 @@ impl Parser {
 
             match section_type {
                 SectionType::Attributes => {
+                    let section_start = self.current;
                     let attrs = self.parse_attributes()?;
+                    attribute_sections.push((section_start, self.current));
+                    subject = attrs
+                        .last()
+                        .filter(|attr| attr.name != "*")
+                        .map(|attr| (attr.name.clone(), attr.entity_alias.clone()));
                     query.attributes.extend(attrs);
                 }
                 SectionType::Filters => {
                     let filter = self.parse_filter()?;
                     query.filters.push(filter);
                 }
+                SectionType::FilterStage => {
+                    let filter = self.parse_filter_stage(subject.as_ref())?;
+                    query.filters.push(filter);
+                }
                 SectionType::Aggregations => {
                     let aggs = self.parse_aggregations()?;
                     query.aggregations.extend(aggs);
@@ -157,18 +280,83 @@ impl Parser {
                     query.page = self.parse_page()?;
                 }
                 SectionType::Distinct => {
-                    self.advance();
+                    self.parse_distinct()?;
                     query.distinct = true;
                 }
                 SectionType::Options => {
                     query.options = self.parse_options()?;
                 }
+                SectionType::Union => {
+                    let mut branch = self.parse_union()?;
+                    // Flatten nested unions so every branch becomes one document
+                    let nested = std::mem::take(&mut branch.unions);
+                    query.unions.push(branch);
+                    query.unions.extend(nested);
+                }
             }
         }
 
+        self.validate_grouping(&query, &attribute_sections)?;
+
         Ok(query)
     }
 
+    /// Check that an aggregate query only selects columns listed in `group()`
+    ///
+    /// FetchXML rejects ungrouped columns in aggregate queries at runtime, so this
+    /// reports them while parsing, pointing at the offending attribute.
+    fn validate_grouping(&mut self, query: &Query, attribute_sections: &[(usize, usize)]) -> Result<()> {
+        if query.aggregations.is_empty() && query.group_by.is_empty() {
+            return Ok(());
+        }
+
+        // Selected columns, each with the entity it belongs to; a join's own
+        // columns belong to the join
+        let selected = query
+            .attributes
+            .iter()
+            .map(|attribute| (attribute, query.column_scope(attribute.entity_alias.as_deref())))
+            .chain(query.joins.iter().flat_map(|join| {
+                let scope = join.entity.alias.as_deref().unwrap_or(&join.entity.name);
+                join.attributes.iter().map(move |attribute| (attribute, Some(scope)))
+            }));
+
+        for (attribute, scope) in selected {
+            if query.is_grouped(&attribute.name, scope) {
+                continue;
+            }
+
+            // Point at the column in the main pipeline, or else wherever it first appears
+            let matches = |i: &usize| match &self.tokens[*i] {
+                Token::Identifier(name) => *name == attribute.name,
+                Token::Wildcard => attribute.name == "*",
+                _ => false,
+            };
+            let index = attribute_sections
+                .iter()
+                .flat_map(|&(start, end)| start..end)
+                .find(matches)
+                .or_else(|| (1..self.tokens.len()).find(|i| self.tokens[i - 1] == Token::Dot && matches(i)))
+                .unwrap_or(self.current);
+
+            let message = if attribute.name == "*" {
+                "Aggregate queries cannot select all attributes ('.*')".to_string()
+            } else {
+                let column = match scope {
+                    Some(scope) => format!("{}.{}", scope, attribute.name),
+                    None => attribute.name.clone(),
+                };
+                format!(
+                    "Column '{}' must be aggregated or listed in group() in an aggregate query",
+                    column
+                )
+            };
+            return Err(self.error_at(index, &message));
+        }
+
+        Ok(())
+    }
+
     /// Determine what type of section follows based on lookahead
     fn determine_section_type(&self) -> Result<SectionType> {
         match self.peek() {
@@ -181,6 +369,8 @@ impl Parser {
             Some(Token::Page) => Ok(SectionType::Page),
             Some(Token::Distinct) => Ok(SectionType::Distinct),
             Some(Token::Options) => Ok(SectionType::Options),
+            Some(Token::Union) => Ok(SectionType::Union),
+            Some(Token::Filter) => Ok(SectionType::FilterStage),
 
             // Aggregation functions
             Some(Token::Count) | Some(Token::Sum) | Some(Token::Avg) | Some(Token::Min)
@@ -233,19 +423,7 @@ impl Parser {
         if pos < self.tokens.len() {
             match &self.tokens[pos] {
                 // These indicate a filter condition
-                Token::Equal
-                | Token::NotEqual
-                | Token::GreaterThan
-                | Token::GreaterEqual
-                | Token::LessThan
-                | Token::LessEqual
-                | Token::Like
-                | Token::NotLike
-                | Token::BeginsWith
-                | Token::EndsWith
-                | Token::In
-                | Token::NotIn
-                | Token::Between => Ok(SectionType::Filters),
+                _ if self.is_operator_at(pos) => Ok(SectionType::Filters),
                 // These indicate attribute selection
                 Token::Comma | Token::Pipe | Token::As | Token::Eof => Ok(SectionType::Attributes),
                 // Default to attributes if ambiguous
@@ -275,25 +453,19 @@ impl Parser {
         }
 
         // Check for operator
-        if pos < self.tokens.len() {
-            matches!(
-                self.tokens[pos],
-                Token::Equal
-                    | Token::NotEqual
-                    | Token::GreaterThan
-                    | Token::GreaterEqual
-                    | Token::LessThan
-                    | Token::LessEqual
-                    | Token::Like
-                    | Token::NotLike
-                    | Token::BeginsWith
-                    | Token::EndsWith
-                    | Token::In
-                    | Token::NotIn
-                    | Token::Between
-            )
-        } else {
-            false
+        self.is_operator_at(pos)
+    }
+
+    /// Check whether the token at `pos` is a filter operator, including text
+    /// match functions such as `contains(`
+    fn is_operator_at(&self, pos: usize) -> bool {
+        match self.tokens.get(pos) {
+            Some(Token::Identifier(name)) => {
+                (TextMatch::from_name(name).is_some() || date_function(name).is_some())
+                    && self.tokens.get(pos + 1) == Some(&Token::LeftParen)
+            }
+            Some(token) => is_filter_operator(token),
+            None => false,
         }
     }
 
@@ -319,6 +491,22 @@ impl Parser {
         Ok(Entity { name, alias })
     }
 
+    /// Parse a union branch: union(.entity | ...)
+    fn parse_union(&mut self) -> Result<Query> {
+        self.union_starts.push(self.current);
+        self.expect(Token::Union)?;
+        self.expect(Token::LeftParen)?;
+
+        // Each branch is its own result set, so it gets its own alias scope
+        let outer_aliases = std::mem::take(&mut self.column_aliases);
+        let branch = self.parse_query();
+        self.column_aliases = outer_aliases;
+        let branch = branch?;
+
+        self.expect(Token::RightParen)?;
+        Ok(branch)
+    }
+
     /// Parse attribute list (.name, .revenue, etc.)
     fn parse_attributes(&mut self) -> Result<Vec<Attribute>> {
         let mut attributes = Vec::new();
@@ -390,8 +578,11 @@ impl Parser {
         let mut alias = None;
         if self.peek() == Some(&Token::As) {
             self.advance(); // consume 'as'
+            let alias_index = self.current;
             if let Some(Token::Identifier(alias_name)) = self.advance() {
-                alias = Some(alias_name.clone());
+                let alias_name = alias_name.clone();
+                self.declare_alias(&alias_name, alias_index)?;
+                alias = Some(alias_name);
             } else {
                 return Err(anyhow::anyhow!("Expected alias name after 'as'"));
             }
@@ -476,6 +667,66 @@ impl Parser {
             return Err(anyhow::anyhow!("Expected attribute in filter condition"));
         };
 
+        self.parse_condition_rest(attribute, entity_alias)
+    }
+
+    /// Parse a filter(...) pipeline stage
+    ///
+    /// Accepts a full filter expression, e.g. `filter(.revenue > 1000)`, or an
+    /// operator applied to the attribute selected by the previous section, e.g.
+    /// `.revenue | filter(between(1000, 5000))`.
+    fn parse_filter_stage(&mut self, subject: Option<&(String, Option<String>)>) -> Result<Filter> {
+        self.expect(Token::Filter)?;
+        self.expect(Token::LeftParen)?;
+
+        let filter = if self.is_operator_at(self.current) {
+            let Some((attribute, entity_alias)) = subject.cloned() else {
+                return Err(self.error_at(
+                    self.current,
+                    "filter() without an attribute needs a preceding attribute, e.g. '.revenue | filter(between(1000, 5000))'",
+                ));
+            };
+            self.parse_condition_rest(attribute, entity_alias)?
+        } else {
+            self.parse_filter_expression()?
+        };
+
+        self.expect(Token::RightParen)?;
+        Ok(filter)
+    }
+
+    /// Parse the operator and value of a condition whose attribute is already known
+    fn parse_condition_rest(&mut self, attribute: String, entity_alias: Option<String>) -> Result<Filter> {
+        // Text match functions carry their value in parentheses
+        let text_match = match self.peek() {
+            Some(Token::Identifier(name)) => TextMatch::from_name(name),
+            _ => None,
+        };
+        if let Some(text_match) = text_match {
+            let pattern = self.parse_text_match(text_match)?;
+            return Ok(Filter::Condition {
+                attribute,
+                operator: FilterOperator::Like,
+                value: FilterValue::Pattern(pattern),
+                entity_alias,
+            });
+        }
+
+        // Relative date functions like last_x_days(30) or this_month()
+        let date_operator = match self.peek() {
+            Some(Token::Identifier(name)) => date_function(name).map(|f| (name.clone(), f)),
+            _ => None,
+        };
+        if let Some((name, (operator, argument))) = date_operator {
+            let value = self.parse_date_function(&name, argument)?;
+            return Ok(Filter::Condition {
+                attribute,
+                operator: FilterOperator::RelativeDate(operator),
+                value,
+                entity_alias,
+            });
+        }
+
         // Parse operator
         let operator = self.parse_filter_operator()?;
 
@@ -488,28 +739,11 @@ impl Parser {
                 _ => return Err(anyhow::anyhow!("Invalid operator with null value")),
             }
         } else if operator == FilterOperator::Between {
-            // Parse "between value1 and value2" or "between [value1, value2]"
-            if self.peek() == Some(&Token::LeftBracket) {
-                // List syntax: between [value1, value2] - use separate value elements in XML
-                let list_value = self.parse_filter_value()?;
-                if let FilterValue::List(values) = list_value {
-                    if values.len() == 2 {
-                        // Use a special marker to indicate this should use separate value elements
-                        (
-                            operator,
-                            FilterValue::Range(
-                                Box::new(values[0].clone()),
-                                Box::new(values[1].clone()),
-                            ),
-                        )
-                    } else {
-                        return Err(anyhow::anyhow!(
-                            "Between operator with list syntax requires exactly 2 values"
-                        ));
-                    }
-                } else {
-                    return Err(anyhow::anyhow!("Expected list for between operator"));
-                }
+            // Parse "between value1 and value2", "between [value1, value2]" or "between(value1, value2)"
+            if matches!(self.peek(), Some(Token::LeftBracket) | Some(Token::LeftParen)) {
+                // List and call syntax use separate value elements in XML
+                let (start, end) = self.parse_between_bounds()?;
+                (operator, FilterValue::Range(Box::new(start), Box::new(end)))
             } else {
                 // Traditional syntax: between value1 and value2 - use comma-separated in single attribute
                 let start_value = self.parse_filter_value()?;
@@ -533,6 +767,87 @@ impl Parser {
         })
     }
 
+    /// Parse `contains("x")`, `startswith("x")`, `endswith("x")` or `like("x%")` into a LIKE pattern
+    fn parse_text_match(&mut self, text_match: TextMatch) -> Result<String> {
+        let name_index = self.current;
+        self.advance(); // consume function name
+        self.expect(Token::LeftParen)?;
+
+        let value = match self.advance() {
+            Some(Token::String(value)) => value.clone(),
+            _ => {
+                return Err(self.error_at(
+                    name_index,
+                    &format!("{}() expects a single string value", text_match.name()),
+                ));
+            }
+        };
+
+        self.expect(Token::RightParen)?;
+        Ok(text_match.pattern(&value))
+    }
+
+    /// Parse the argument list of a relative date function, checking it against the operator's arity
+    fn parse_date_function(&mut self, name: &str, argument: DateArgument) -> Result<FilterValue> {
+        let name_index = self.current;
+        self.advance(); // consume function name
+        self.expect(Token::LeftParen)?;
+
+        if argument == DateArgument::None {
+            if self.peek() != Some(&Token::RightParen) {
+                return Err(self.error_at(name_index, &format!("{}() does not take an argument", name)));
+            }
+            self.advance(); // consume ')'
+            return Ok(FilterValue::Null);
+        }
+
+        let value = match (argument, self.peek()) {
+            (DateArgument::Count, Some(Token::Integer(_)))
+            | (DateArgument::Date, Some(Token::Date(_) | Token::String(_))) => self.parse_filter_value()?,
+            (DateArgument::Count, _) => {
+                return Err(self.error_at(name_index, &format!("{}() expects a whole number", name)));
+            }
+            _ => return Err(self.error_at(name_index, &format!("{}() expects a date", name))),
+        };
+
+        if self.peek() != Some(&Token::RightParen) {
+            return Err(self.error_at(name_index, &format!("{}() takes exactly one argument", name)));
+        }
+        self.advance(); // consume ')'
+        Ok(value)
+    }
+
+    /// Parse the bounds of `between [a, b]` or `between(a, b)`, requiring exactly two values
+    fn parse_between_bounds(&mut self) -> Result<(FilterValue, FilterValue)> {
+        let open_index = self.current;
+        let close = match self.advance() {
+            Some(Token::LeftBracket) => Token::RightBracket,
+            Some(Token::LeftParen) => Token::RightParen,
+            _ => return Err(self.error_at(open_index, "Expected '(' or '[' after between")),
+        };
+
+        let mut values = Vec::new();
+        if self.peek() != Some(&close) {
+            values.push(self.parse_filter_value()?);
+            while self.peek() == Some(&Token::Comma) {
+                self.advance(); // consume ','
+                values.push(self.parse_filter_value()?);
+            }
+        }
+        self.expect(close)?;
+
+        if values.len() != 2 {
+            return Err(self.error_at(
+                open_index,
+                &format!("between requires exactly 2 values, found {}", values.len()),
+            ));
+        }
+
+        let end = values.pop().unwrap_or(FilterValue::Null);
+        let start = values.pop().unwrap_or(FilterValue::Null);
+        Ok((start, end))
+    }
+
     /// Parse join clauses
     fn parse_joins(&mut self) -> Result<Vec<Join>> {
         let mut joins = Vec::new();
@@ -587,23 +902,11 @@ impl Parser {
                         // Look ahead to see if this is a filter (has an operator after the attribute)
                         while lookahead < self.tokens.len() {
                             match &self.tokens[lookahead] {
-                                Token::Equal
-                                | Token::NotEqual
-                                | Token::GreaterThan
-                                | Token::GreaterEqual
-                                | Token::LessThan
-                                | Token::LessEqual
-                                | Token::Like
-                                | Token::NotLike
-                                | Token::BeginsWith
-                                | Token::EndsWith
-                                | Token::In
-                                | Token::NotIn
-                                | Token::Between => {
+                                _ if self.is_operator_at(lookahead) => {
                                     is_filter = true;
                                     break;
                                 }
-                                Token::Comma | Token::RightParen => break,
+                                Token::Comma | Token::RightParen | Token::Pipe => break,
                                 _ => lookahead += 1,
                             }
                         }
@@ -620,6 +923,12 @@ impl Parser {
                         let attrs = self.parse_attributes()?;
                         attributes.extend(attrs);
                     }
+                    Some(Token::Filter) => {
+                        // A subject-less filter() binds to the last attribute selected in the join
+                        let subject = attributes.last().map(|attr: &Attribute| (attr.name.clone(), None));
+                        let filter = self.parse_filter_stage(subject.as_ref())?;
+                        filters.push(filter);
+                    }
                     _ => {
                         let filter = self.parse_filter()?;
                         filters.push(filter);
@@ -760,8 +1069,11 @@ impl Parser {
         let mut alias = None;
         if self.peek() == Some(&Token::As) {
             self.advance(); // consume 'as'
+            let alias_index = self.current;
             if let Some(Token::Identifier(alias_name)) = self.advance() {
-                alias = Some(alias_name.clone());
+                let alias_name = alias_name.clone();
+                self.declare_alias(&alias_name, alias_index)?;
+                alias = Some(alias_name);
             }
         }
 
@@ -774,35 +1086,45 @@ impl Parser {
     }
 
     /// Parse group by clause
-    fn parse_group_by(&mut self) -> Result<Vec<String>> {
+    fn parse_group_by(&mut self) -> Result<Vec<Attribute>> {
         self.expect(Token::Group)?;
         self.expect(Token::LeftParen)?;
 
-        let mut attributes = Vec::new();
-
-        // Parse first attribute
-        self.expect(Token::Dot)?;
-        if let Some(Token::Identifier(attr_name)) = self.advance() {
-            attributes.push(attr_name.clone());
-        } else {
-            return Err(anyhow::anyhow!("Expected attribute name in group by"));
-        }
+        let mut attributes = vec![self.parse_group_by_attribute()?];
 
         // Parse additional attributes
         while self.peek() == Some(&Token::Comma) {
             self.advance(); // consume ','
-            self.expect(Token::Dot)?;
-            if let Some(Token::Identifier(attr_name)) = self.advance() {
-                attributes.push(attr_name.clone());
-            } else {
-                return Err(anyhow::anyhow!("Expected attribute name in group by"));
-            }
+            attributes.push(self.parse_group_by_attribute()?);
         }
 
         self.expect(Token::RightParen)?;
         Ok(attributes)
     }
 
+    /// Parse a grouped column: `.attr`, or `alias.attr` for a joined entity
+    fn parse_group_by_attribute(&mut self) -> Result<Attribute> {
+        let entity_alias = match self.peek() {
+            Some(Token::Identifier(alias)) => {
+                let alias = alias.clone();
+                self.advance();
+                Some(alias)
+            }
+            _ => None,
+        };
+
+        self.expect(Token::Dot)?;
+        if let Some(Token::Identifier(attr_name)) = self.advance() {
+            Ok(Attribute {
+                name: attr_name.clone(),
+                alias: None,
+                entity_alias,
+            })
+        } else {
+            Err(anyhow::anyhow!("Expected attribute name in group by"))
+        }
+    }
+
     /// Parse having clause
     fn parse_having(&mut self) -> Result<Option<Filter>> {
         self.expect(Token::Having)?;
@@ -893,6 +1215,18 @@ impl Parser {
         Ok(Some(limit))
     }
 
+    /// Parse distinct clause, either bare `distinct` or `distinct()`
+    fn parse_distinct(&mut self) -> Result<()> {
+        self.expect(Token::Distinct)?;
+
+        if matches!(self.peek(), Some(Token::LeftParen)) {
+            self.advance();
+            self.expect(Token::RightParen)?;
+        }
+
+        Ok(())
+    }
+
     /// Parse page clause
     fn parse_page(&mut self) -> Result<Option<(u32, u32)>> {
         self.expect(Token::Page)?;
@@ -1043,6 +1377,21 @@ impl Parser {
         }
     }
 
+    /// Helper: Record a column alias, rejecting duplicates within the current query
+    fn declare_alias(&mut self, alias: &str, index: usize) -> Result<()> {
+        if self.column_aliases.iter().any(|existing| existing == alias) {
+            return Err(self.error_at(index, &format!("Duplicate column alias '{}'", alias)));
+        }
+        self.column_aliases.push(alias.to_string());
+        Ok(())
+    }
+
+    /// Helper: Build an error located at the given token index
+    fn error_at(&mut self, index: usize, message: &str) -> anyhow::Error {
+        self.error_position = Some(index);
+        anyhow::anyhow!("{}", message)
+    }
+
     /// Helper: Check if current token matches expected token
     fn expect(&mut self, expected: Token) -> Result<()> {
         if let Some(token) = self.advance() {
@@ -1083,3 +1432,303 @@ impl Parser {
         self.current >= self.tokens.len() || matches!(self.peek(), Some(Token::Eof))
     }
 }
+
+/// Text match functions usable in place of an operator, all emitted as `operator="like"`
+///
+/// | FQL                    | FetchXML value | Notes                              |
+/// |------------------------|----------------|------------------------------------|
+/// | `.name contains("x")`  | `%x%`          | `%`, `_` and `[` in `x` are escaped |
+/// | `.name startswith("x")`| `x%`           | `%`, `_` and `[` in `x` are escaped |
+/// | `.name endswith("x")`  | `%x`           | `%`, `_` and `[` in `x` are escaped |
+/// | `.name like("x%")`     | `x%`           | raw pattern, passed through as-is   |
+///
+/// The symbolic operators `~`, `!~`, `^=` and `$=` are unchanged; `~` still
+/// wraps values without wildcards in `%..%`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum TextMatch {
+    Contains,
+    StartsWith,
+    EndsWith,
+    Raw,
+}
+
+impl TextMatch {
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "contains" => Some(TextMatch::Contains),
+            "startswith" => Some(TextMatch::StartsWith),
+            "endswith" => Some(TextMatch::EndsWith),
+            "like" => Some(TextMatch::Raw),
+            _ => None,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            TextMatch::Contains => "contains",
+            TextMatch::StartsWith => "startswith",
+            TextMatch::EndsWith => "endswith",
+            TextMatch::Raw => "like",
+        }
+    }
+
+    /// Build the LIKE pattern for a user-supplied value
+    fn pattern(&self, value: &str) -> String {
+        match self {
+            TextMatch::Contains => format!("%{}%", escape_like(value)),
+            TextMatch::StartsWith => format!("{}%", escape_like(value)),
+            TextMatch::EndsWith => format!("%{}", escape_like(value)),
+            TextMatch::Raw => value.to_string(),
+        }
+    }
+}
+
+/// Argument taken by a relative date function
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum DateArgument {
+    /// No argument, e.g. `this_month()`
+    None,
+    /// A whole number of units, e.g. `last_x_days(30)`
+    Count,
+    /// A date, e.g. `on_or_before(@2024-01-01)`
+    Date,
+}
+
+/// Relative date functions, keyed by FQL name: (FetchXML operator, argument)
+const DATE_FUNCTIONS: &[(&str, &str, DateArgument)] = &[
+    ("yesterday", "yesterday", DateArgument::None),
+    ("today", "today", DateArgument::None),
+    ("tomorrow", "tomorrow", DateArgument::None),
+    ("last_seven_days", "last-seven-days", DateArgument::None),
+    ("next_seven_days", "next-seven-days", DateArgument::None),
+    ("last_week", "last-week", DateArgument::None),
+    ("this_week", "this-week", DateArgument::None),
+    ("next_week", "next-week", DateArgument::None),
+    ("last_month", "last-month", DateArgument::None),
+    ("this_month", "this-month", DateArgument::None),
+    ("next_month", "next-month", DateArgument::None),
+    ("last_year", "last-year", DateArgument::None),
+    ("this_year", "this-year", DateArgument::None),
+    ("next_year", "next-year", DateArgument::None),
+    ("last_fiscal_year", "last-fiscal-year", DateArgument::None),
+    ("this_fiscal_year", "this-fiscal-year", DateArgument::None),
+    ("next_fiscal_year", "next-fiscal-year", DateArgument::None),
+    ("last_fiscal_period", "last-fiscal-period", DateArgument::None),
+    ("this_fiscal_period", "this-fiscal-period", DateArgument::None),
+    ("next_fiscal_period", "next-fiscal-period", DateArgument::None),
+    ("last_x_hours", "last-x-hours", DateArgument::Count),
+    ("next_x_hours", "next-x-hours", DateArgument::Count),
+    ("last_x_days", "last-x-days", DateArgument::Count),
+    ("next_x_days", "next-x-days", DateArgument::Count),
+    ("last_x_weeks", "last-x-weeks", DateArgument::Count),
+    ("next_x_weeks", "next-x-weeks", DateArgument::Count),
+    ("last_x_months", "last-x-months", DateArgument::Count),
+    ("next_x_months", "next-x-months", DateArgument::Count),
+    ("last_x_years", "last-x-years", DateArgument::Count),
+    ("next_x_years", "next-x-years", DateArgument::Count),
+    ("last_x_fiscal_years", "last-x-fiscal-years", DateArgument::Count),
+    ("next_x_fiscal_years", "next-x-fiscal-years", DateArgument::Count),
+    ("last_x_fiscal_periods", "last-x-fiscal-periods", DateArgument::Count),
+    ("next_x_fiscal_periods", "next-x-fiscal-periods", DateArgument::Count),
+    ("older_than_x_minutes", "olderthan-x-minutes", DateArgument::Count),
+    ("older_than_x_hours", "olderthan-x-hours", DateArgument::Count),
+    ("older_than_x_days", "olderthan-x-days", DateArgument::Count),
+    ("older_than_x_weeks", "olderthan-x-weeks", DateArgument::Count),
+    ("older_than_x_months", "olderthan-x-months", DateArgument::Count),
+    ("older_than_x_years", "olderthan-x-years", DateArgument::Count),
+    ("in_fiscal_year", "in-fiscal-year", DateArgument::Count),
+    ("in_fiscal_period", "in-fiscal-period", DateArgument::Count),
+    ("not_on", "not-on", DateArgument::Date),
+    ("on_or_before", "on-or-before", DateArgument::Date),
+    ("on_or_after", "on-or-after", DateArgument::Date),
+];
+
+/// Look up a relative date function by its FQL name
+fn date_function(name: &str) -> Option<(&'static str, DateArgument)> {
+    DATE_FUNCTIONS
+        .iter()
+        .find(|(fql_name, _, _)| *fql_name == name)
+        .map(|&(_, operator, argument)| (operator, argument))
+}
+
+/// Escape LIKE wildcards so the value matches literally (SQL Server bracket syntax)
+fn escape_like(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '%' | '_' | '[' => {
+                escaped.push('[');
+                escaped.push(c);
+                escaped.push(']');
+            }
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Whether a token is a comparison operator that can start a condition
+fn is_filter_operator(token: &Token) -> bool {
+    matches!(
+        token,
+        Token::Equal
+            | Token::NotEqual
+            | Token::GreaterThan
+            | Token::GreaterEqual
+            | Token::LessThan
+            | Token::LessEqual
+            | Token::Like
+            | Token::NotLike
+            | Token::BeginsWith
+            | Token::EndsWith
+            | Token::In
+            | Token::NotIn
+            | Token::Between
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fql::{to_fetchxml, tokenize};
+
+    #[test]
+    fn test_parser_error_renders_caret_with_context() {
+        let input = ".account\n| .name, .revenue\n| limit(10\n| order .name";
+
+        let tokens = tokenize(input).expect("Expected successful tokenization");
+        let error = parse(tokens, input).expect_err("Expected parsing to fail");
+        let parse_error = error
+            .downcast_ref::<ParseError>()
+            .expect("Parser errors should carry a ParseError");
+
+        assert_eq!(parse_error.line(), 4);
+        assert_eq!(parse_error.column(), 1);
+
+        let rendered = parse_error.render(2);
+        assert!(rendered.contains("line 4, column 1"));
+        assert!(rendered.contains("2 | | .name, .revenue"));
+        assert!(rendered.contains("3 | | limit(10"));
+        assert!(rendered.contains("4 | | order .name\n    | ^"));
+        assert!(!rendered.contains(".account"), "Only two lines of context expected");
+    }
+
+    #[test]
+    fn test_filter_and_union_as_attribute_names() {
+        let fql = ".account | .filter, .union as filter | .filter == \"x\" | filter(.union != null)";
+        let tokens = tokenize(fql).unwrap();
+        let query = parse(tokens, fql).unwrap();
+
+        let xml = to_fetchxml(query).unwrap().join("");
+        assert!(xml.contains(r#"<attribute name="filter" />"#));
+        assert!(xml.contains(r#"<attribute name="union" alias="filter" />"#));
+        assert!(xml.contains(r#"<condition attribute="filter" operator="eq" value="x" />"#));
+        assert!(xml.contains(r#"<condition attribute="union" operator="not-null" />"#));
+    }
+
+    #[test]
+    fn test_union_branch_with_filter() {
+        let fql = ".account | .name | union(.contact | .fullname | .statecode == 0)";
+        let tokens = tokenize(fql).unwrap();
+        let query = parse(tokens, fql).unwrap();
+
+        assert_eq!(query.entity_names(), vec!["account", "contact"]);
+        assert_eq!(query.unions[0].filters.len(), 1);
+        assert_eq!(to_fetchxml(query).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_union_column_count_mismatch() {
+        let fql = ".account | .name, .revenue | union(.contact | .fullname)";
+        let tokens = tokenize(fql).unwrap();
+        let error = parse(tokens, fql).unwrap_err().to_string();
+
+        assert!(error.contains("Union branch 1 (.contact) selects 1 column(s), but the main query selects 2"));
+        // Caret points at the offending union keyword
+        assert!(error.contains("line 1, column 30"));
+    }
+
+    #[test]
+    fn test_ungrouped_column_is_rejected() {
+        let fql = ".opportunity | .ownerid, .name | group(.ownerid) | sum(.estimatedvalue) as total";
+        let tokens = tokenize(fql).unwrap();
+        let error = parse(tokens, fql).unwrap_err().to_string();
+
+        assert!(error.contains("Column 'name' must be aggregated or listed in group()"));
+        assert!(error.contains("line 1, column 27"));
+
+        let fql = ".opportunity | .* | count()";
+        let tokens = tokenize(fql).unwrap();
+        let error = parse(tokens, fql).unwrap_err().to_string();
+
+        assert!(error.contains("Aggregate queries cannot select all attributes"));
+    }
+
+    #[test]
+    fn test_grouped_columns_match_entity_alias() {
+        // A joined column must be grouped under its own join alias
+        let fql = ".account | .ownerid | join(.contact as c on c.contactid -> account.primarycontactid | .lastname) | group(.ownerid) | count()";
+        let tokens = tokenize(fql).unwrap();
+        let error = parse(tokens, fql).unwrap_err().to_string();
+        assert!(error.contains("Column 'c.lastname' must be aggregated or listed in group()"));
+
+        // Grouping the main entity's column of the same name does not cover the join's
+        let fql = ".account as a | .name | join(.contact as c on c.contactid -> a.primarycontactid | .name) | group(a.name) | count()";
+        let tokens = tokenize(fql).unwrap();
+        let error = parse(tokens, fql).unwrap_err().to_string();
+        assert!(error.contains("Column 'c.name' must be aggregated or listed in group()"));
+
+        let fql = ".account | .ownerid | join(.contact as c on c.contactid -> account.primarycontactid | .lastname) | group(.ownerid, c.lastname) | count()";
+        let tokens = tokenize(fql).unwrap();
+        let query = parse(tokens, fql).unwrap();
+        assert!(query.is_grouped("lastname", Some("c")));
+        assert!(!query.is_grouped("lastname", None));
+    }
+
+    #[test]
+    fn test_duplicate_attribute_alias_is_rejected() {
+        let fql = ".account | .name as company, .accountnumber as company";
+        let tokens = tokenize(fql).unwrap();
+        let error = parse(tokens, fql).unwrap_err().to_string();
+
+        assert!(error.contains("Duplicate column alias 'company'"));
+        assert!(error.contains("line 1, column 48"));
+    }
+
+    #[test]
+    fn test_alias_scope_is_per_union_branch() {
+        let fql = ".account | .name as label | union(.contact | .fullname as label)";
+        let tokens = tokenize(fql).unwrap();
+
+        assert!(parse(tokens, fql).is_ok());
+    }
+
+    #[test]
+    fn test_between_rejects_wrong_argument_count() {
+        let fql = ".account | .revenue | filter(between(1000, 5000, 9000))";
+        let tokens = tokenize(fql).unwrap();
+        let error = parse(tokens, fql).unwrap_err().to_string();
+
+        assert!(error.contains("between requires exactly 2 values, found 3"));
+        assert!(error.contains("line 1, column 37"));
+
+        let fql = ".account | .revenue between [1000]";
+        let tokens = tokenize(fql).unwrap();
+        let error = parse(tokens, fql).unwrap_err().to_string();
+        assert!(error.contains("between requires exactly 2 values, found 1"));
+    }
+
+    #[test]
+    fn test_relative_date_arity_errors() {
+        let fql = ".contact | .createdon | filter(this_month(3))";
+        let tokens = tokenize(fql).unwrap();
+        let error = parse(tokens, fql).unwrap_err().to_string();
+        assert!(error.contains("this_month() does not take an argument"));
+        assert!(error.contains("line 1, column 32"));
+
+        let fql = ".contact | .createdon | filter(last_x_days())";
+        let tokens = tokenize(fql).unwrap();
+        let error = parse(tokens, fql).unwrap_err().to_string();
+        assert!(error.contains("last_x_days() expects a whole number"));
+    }
+}
@@ -0,0 +1,241 @@
+//! Check a parsed query against cached entity metadata before it is sent
+//!
+//! Typos in entity or attribute names otherwise only show up as server
+//! errors. Only what the metadata knows is checked: without a cached entity
+//! list entities aren't checked, and attributes are only checked for entities
+//! whose fields are cached, so a cold cache never blocks a query.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use super::ast::{Filter, Query};
+use crate::api::metadata::FieldMetadata;
+
+/// Suffixes of the virtual name attributes Dynamics adds for lookups and
+/// option sets (e.g. `owneridname`); they aren't in the field metadata
+const VIRTUAL_NAME_SUFFIXES: [&str; 2] = ["name", "yominame"];
+
+/// Cached metadata a query is validated against
+#[derive(Debug, Clone, Default)]
+pub struct ValidationMetadata {
+    /// Logical names of every entity, when the entity list is cached
+    pub entities: Option<Vec<String>>,
+    /// Fields by entity logical name, for entities whose fields are cached
+    pub fields: HashMap<String, Vec<FieldMetadata>>,
+}
+
+impl ValidationMetadata {
+    /// Whether there is nothing to validate against
+    pub fn is_empty(&self) -> bool {
+        self.entities.is_none() && self.fields.is_empty()
+    }
+}
+
+/// An unknown entity or attribute, with the closest known name if there is one
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidationIssue {
+    pub message: String,
+    pub suggestion: Option<String>,
+}
+
+impl fmt::Display for ValidationIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.suggestion {
+            Some(suggestion) => write!(f, "{} (did you mean '{}'?)", self.message, suggestion),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+/// Check entities and the attributes selected, filtered, joined on, ordered,
+/// grouped and aggregated in `query` and its union branches
+///
+/// `having()` isn't checked since it refers to aggregate aliases.
+pub fn validate(query: &Query, metadata: &ValidationMetadata) -> Vec<ValidationIssue> {
+    let mut issues = Vec::new();
+    for branch in std::iter::once(query).chain(&query.unions) {
+        validate_branch(branch, metadata, &mut issues);
+    }
+    issues
+}
+
+fn validate_branch(query: &Query, metadata: &ValidationMetadata, issues: &mut Vec<ValidationIssue>) {
+    let main = query.entity.name.as_str();
+
+    // Aliases and entity names both refer to an entity in qualified names (`a.name`)
+    let mut scope: HashMap<&str, &str> = HashMap::new();
+    for entity in std::iter::once(&query.entity).chain(query.joins.iter().map(|join| &join.entity)) {
+        scope.insert(&entity.name, &entity.name);
+        if let Some(alias) = &entity.alias {
+            scope.insert(alias, &entity.name);
+        }
+        check_entity(&entity.name, metadata, issues);
+    }
+
+    let mut check = |attribute: &str, entity_alias: Option<&String>, default_entity: &str| {
+        // An alias the query doesn't define is left to the server to report
+        let entity = match entity_alias {
+            Some(alias) => match scope.get(alias.as_str()) {
+                Some(entity) => *entity,
+                None => return,
+            },
+            None => default_entity,
+        };
+        check_attribute(attribute, entity, metadata, issues);
+    };
+
+    for attribute in &query.attributes {
+        check(&attribute.name, attribute.entity_alias.as_ref(), main);
+    }
+    for (attribute, entity_alias) in query.filters.iter().flat_map(conditions) {
+        check(attribute, entity_alias, main);
+    }
+    for join in &query.joins {
+        let joined = join.entity.name.as_str();
+        let on = &join.on_condition;
+        check(&on.from_attribute, on.from_entity_alias.as_ref(), joined);
+        check(&on.to_attribute, on.to_entity_alias.as_ref(), main);
+        for attribute in &join.attributes {
+            check(&attribute.name, attribute.entity_alias.as_ref(), joined);
+        }
+        for (attribute, entity_alias) in join.filters.iter().flat_map(conditions) {
+            check(attribute, entity_alias, joined);
+        }
+    }
+    for order in &query.order {
+        check(&order.attribute, order.entity_alias.as_ref(), main);
+    }
+    for aggregation in &query.aggregations {
+        if let Some(attribute) = &aggregation.attribute {
+            check(attribute, aggregation.entity_alias.as_ref(), main);
+        }
+    }
+    for attribute in &query.group_by {
+        check(&attribute.name, attribute.entity_alias.as_ref(), main);
+    }
+}
+
+fn check_entity(entity: &str, metadata: &ValidationMetadata, issues: &mut Vec<ValidationIssue>) {
+    let Some(entities) = &metadata.entities else { return };
+    if entities.iter().any(|name| name == entity) {
+        return;
+    }
+    issues.push(ValidationIssue {
+        message: format!("Unknown entity '{}'", entity),
+        suggestion: closest(entity, entities.iter().map(String::as_str)),
+    });
+}
+
+fn check_attribute(attribute: &str, entity: &str, metadata: &ValidationMetadata, issues: &mut Vec<ValidationIssue>) {
+    let Some(fields) = metadata.fields.get(entity) else { return };
+    let known = |name: &str| fields.iter().any(|field| field.logical_name == name);
+
+    let is_virtual_name = VIRTUAL_NAME_SUFFIXES
+        .iter()
+        .any(|suffix| attribute.strip_suffix(suffix).is_some_and(|base| known(base)));
+    if attribute == "*" || known(attribute) || is_virtual_name {
+        return;
+    }
+
+    let message = format!("Unknown attribute '{}' on {}", attribute, entity);
+    if !issues.iter().any(|issue| issue.message == message) {
+        issues.push(ValidationIssue {
+            message,
+            suggestion: closest(attribute, fields.iter().map(|field| field.logical_name.as_str())),
+        });
+    }
+}
+
+/// Attribute and entity alias of every condition in a filter tree
+fn conditions(filter: &Filter) -> Vec<(&str, Option<&String>)> {
+    match filter {
+        Filter::Condition { attribute, entity_alias, .. } => vec![(attribute.as_str(), entity_alias.as_ref())],
+        Filter::And(filters) | Filter::Or(filters) => filters.iter().flat_map(conditions).collect(),
+    }
+}
+
+/// The candidate nearest to `name` by edit distance, if it is close enough to
+/// be a likely typo (at most a third of the name's length, and at least 2)
+fn closest<'a>(name: &str, candidates: impl Iterator<Item = &'a str>) -> Option<String> {
+    let max_distance = (name.chars().count() / 3).max(2);
+    candidates
+        .map(|candidate| (edit_distance(name, candidate), candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate.to_string())
+}
+
+/// Levenshtein distance between two strings, compared case-insensitively
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.to_lowercase().chars().collect();
+    let b: Vec<char> = b.to_lowercase().chars().collect();
+
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.iter().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::metadata::FieldType;
+    use crate::fql::{parse, tokenize};
+
+    fn field(name: &str) -> FieldMetadata {
+        FieldMetadata {
+            logical_name: name.to_string(),
+            display_name: None,
+            field_type: FieldType::String,
+            is_required: false,
+            is_primary_key: false,
+            max_length: None,
+            related_entity: None,
+            option_set: None,
+        }
+    }
+
+    fn validate_fql(fql: &str, metadata: &ValidationMetadata) -> Vec<String> {
+        let query = parse(tokenize(fql).unwrap(), fql).unwrap();
+        validate(&query, metadata).iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn test_unknown_names_get_suggestions() {
+        let mut metadata = ValidationMetadata {
+            entities: Some(vec!["account".to_string(), "contact".to_string()]),
+            fields: HashMap::new(),
+        };
+        metadata.fields.insert("account".to_string(), ["accountid", "name", "revenue", "primarycontactid"].map(field).to_vec());
+        metadata.fields.insert("contact".to_string(), ["contactid", "fullname"].map(field).to_vec());
+
+        let valid = ".account as a | .name, .revenue, .primarycontactidname | .revenue > 10 \
+                     | join(.contact as c on c.contactid -> a.primarycontactid | .fullname) | order(.name)";
+        assert!(validate_fql(valid, &metadata).is_empty());
+
+        let issues = validate_fql(
+            ".account as a | .nmae | .revnue > 10 | join(.contact as c on c.contactid -> a.primarycontactid | .fulname)",
+            &metadata,
+        );
+        assert_eq!(issues, vec![
+            "Unknown attribute 'nmae' on account (did you mean 'name'?)".to_string(),
+            "Unknown attribute 'revnue' on account (did you mean 'revenue'?)".to_string(),
+            "Unknown attribute 'fulname' on contact (did you mean 'fullname'?)".to_string(),
+        ]);
+
+        // Fields of unknown entities aren't cached, so only the entity is reported
+        assert_eq!(validate_fql(".acount | .whatever", &metadata), vec![
+            "Unknown entity 'acount' (did you mean 'account'?)".to_string(),
+        ]);
+        assert_eq!(validate_fql(".account | .xyzzyplugh", &metadata), vec![
+            "Unknown attribute 'xyzzyplugh' on account".to_string(),
+        ]);
+        assert!(validate_fql(".lead | .anything", &ValidationMetadata::default()).is_empty());
+    }
+}
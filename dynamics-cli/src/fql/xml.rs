@@ -1,13 +1,16 @@
 use crate::fql::ast::*;
 use anyhow::Result;
 
-/// Converts an FQL AST into FetchXML string
+/// Converts an FQL AST into FetchXML documents
+///
+/// Returns one document for the main query followed by one per `union(...)`
+/// branch; queries without unions produce a single document.
 ///
 /// # Arguments
 /// * `query` - The parsed FQL query AST
 ///
 /// # Returns
-/// * `Ok(String)` - FetchXML string on success
+/// * `Ok(Vec<String>)` - FetchXML documents on success
 /// * `Err(anyhow::Error)` - XML generation error
 ///
 /// # Examples
@@ -20,21 +23,25 @@ use anyhow::Result;
 ///     let tokens = tokenize(fql)?;
 ///     let query = parse(tokens, fql)?;
 ///     let xml = to_fetchxml(query)?;
-///     assert!(xml.contains("<entity name=\"account\">"));
+///     assert!(xml[0].contains("<entity name=\"account\">"));
 ///     Ok(())
 /// }
 /// ```
-pub fn to_fetchxml(query: Query) -> Result<String> {
+pub fn to_fetchxml(query: Query) -> Result<Vec<String>> {
     to_fetchxml_with_options(query, false)
 }
 
-pub fn to_fetchxml_pretty(query: Query) -> Result<String> {
+pub fn to_fetchxml_pretty(query: Query) -> Result<Vec<String>> {
     to_fetchxml_with_options(query, true)
 }
 
-fn to_fetchxml_with_options(query: Query, pretty: bool) -> Result<String> {
-    let mut generator = XmlGenerator::new_with_options(pretty);
-    generator.generate(query)
+fn to_fetchxml_with_options(mut query: Query, pretty: bool) -> Result<Vec<String>> {
+    let unions = std::mem::take(&mut query.unions);
+
+    std::iter::once(query)
+        .chain(unions)
+        .map(|branch| XmlGenerator::new_with_options(pretty).generate(branch))
+        .collect()
 }
 
 /// XML generation helper struct
@@ -144,9 +151,14 @@ impl XmlGenerator {
         self.add_opening_tag("entity", &entity_attrs);
         self.indent();
 
-        // Generate group by attributes first
-        if !query.group_by.is_empty() {
-            self.generate_group_by_attributes(&query.group_by)?;
+        // Generate group by attributes first (grouped join columns go in their link-entity)
+        let main_groups: Vec<&Attribute> = query
+            .group_by
+            .iter()
+            .filter(|attr| query.column_scope(attr.entity_alias.as_deref()).is_none())
+            .collect();
+        if !main_groups.is_empty() {
+            self.generate_group_by_attributes(&main_groups, None)?;
         }
 
         // Generate aggregation attributes
@@ -154,9 +166,17 @@ impl XmlGenerator {
             self.generate_aggregation_attributes(&query.aggregations, &entity.name)?;
         }
 
-        // Generate regular attributes
-        if !query.attributes.is_empty() {
-            self.generate_attributes(&query.attributes)?;
+        // Generate regular attributes (grouped columns were already emitted with groupby)
+        let attributes: Vec<Attribute> = query
+            .attributes
+            .iter()
+            .filter(|attr| {
+                !query.is_grouped(&attr.name, query.column_scope(attr.entity_alias.as_deref()))
+            })
+            .cloned()
+            .collect();
+        if !attributes.is_empty() {
+            self.generate_attributes(&attributes)?;
         }
 
         // Generate filters that belong to the main entity (no entity alias or matching main entity alias)
@@ -184,7 +204,7 @@ impl XmlGenerator {
 
         // Generate joins (link-entity elements)
         if !query.joins.is_empty() {
-            self.generate_joins(&query.joins, &query.filters)?;
+            self.generate_joins(query)?;
         }
 
         // Generate order
@@ -215,13 +235,21 @@ impl XmlGenerator {
         Ok(())
     }
 
-    /// Generate group by attributes
-    fn generate_group_by_attributes(&mut self, group_by: &[String]) -> Result<()> {
-        for attr_name in group_by {
+    /// Generate group by attributes, aliased with the join alias when grouping a joined entity
+    fn generate_group_by_attributes(
+        &mut self,
+        group_by: &[&Attribute],
+        scope: Option<&str>,
+    ) -> Result<()> {
+        for attr in group_by {
+            let alias = match scope {
+                Some(scope) => format!("{}_{}", scope, attr.name),
+                None => attr.name.clone(),
+            };
             let attr_attrs = vec![
-                ("name", attr_name.as_str()),
+                ("name", attr.name.as_str()),
                 ("groupby", "true"),
-                ("alias", attr_name.as_str()),
+                ("alias", alias.as_str()),
             ];
             self.add_self_closing_tag("attribute", &attr_attrs);
         }
@@ -332,6 +360,25 @@ impl XmlGenerator {
                         );
                         self.add_line(&condition_str);
                     }
+                    FilterOperator::RelativeDate(_) => {
+                        // Relative date operators either take no value or a count/date
+                        let condition_str = if matches!(value, FilterValue::Null) {
+                            format!(
+                                "<condition attribute=\"{}\" operator=\"{}\" />",
+                                self.escape_xml(attribute),
+                                op_str
+                            )
+                        } else {
+                            let value_str = self.value_to_xml_with_date_prefix(value, false)?;
+                            format!(
+                                "<condition attribute=\"{}\" operator=\"{}\" value=\"{}\" />",
+                                self.escape_xml(attribute),
+                                op_str,
+                                self.escape_xml(&value_str)
+                            )
+                        };
+                        self.add_line(&condition_str);
+                    }
                     FilterOperator::Between => {
                         // Handle between operator - different formats based on syntax used
                         match value {
@@ -472,15 +519,15 @@ impl XmlGenerator {
     }
 
     /// Generate link-entity elements for joins
-    fn generate_joins(&mut self, joins: &[Join], query_filters: &[Filter]) -> Result<()> {
-        for join in joins {
-            self.generate_join(join, query_filters)?;
+    fn generate_joins(&mut self, query: &Query) -> Result<()> {
+        for join in &query.joins {
+            self.generate_join(join, query)?;
         }
         Ok(())
     }
 
     /// Generate a single join
-    fn generate_join(&mut self, join: &Join, query_filters: &[Filter]) -> Result<()> {
+    fn generate_join(&mut self, join: &Join, query: &Query) -> Result<()> {
         let mut link_attrs = vec![("name", join.entity.name.as_str())];
 
         if let Some(alias) = &join.entity.alias {
@@ -498,9 +545,25 @@ impl XmlGenerator {
         self.add_opening_tag("link-entity", &link_attrs);
         self.indent();
 
-        // Generate attributes for joined entity
-        if !join.attributes.is_empty() {
-            self.generate_attributes(&join.attributes)?;
+        // Generate grouped columns of the joined entity, then its other attributes
+        let scope = join.entity.alias.as_deref().unwrap_or(&join.entity.name);
+        let groups: Vec<&Attribute> = query
+            .group_by
+            .iter()
+            .filter(|attr| query.column_scope(attr.entity_alias.as_deref()) == Some(scope))
+            .collect();
+        if !groups.is_empty() {
+            self.generate_group_by_attributes(&groups, Some(scope))?;
+        }
+
+        let attributes: Vec<Attribute> = join
+            .attributes
+            .iter()
+            .filter(|attr| !query.is_grouped(&attr.name, Some(scope)))
+            .cloned()
+            .collect();
+        if !attributes.is_empty() {
+            self.generate_attributes(&attributes)?;
         }
 
         // Collect filters that belong to this join (by entity alias)
@@ -508,7 +571,7 @@ impl XmlGenerator {
 
         // Add entity-qualified filters from the main query that belong to this join
         if let Some(join_alias) = &join.entity.alias {
-            for filter in query_filters {
+            for filter in &query.filters {
                 if let Filter::Condition { entity_alias, .. } = filter
                     && entity_alias.as_ref() == Some(join_alias)
                 {
@@ -548,6 +611,10 @@ impl XmlGenerator {
 
     /// Convert filter operator to FetchXML operator string
     fn operator_to_xml(&self, operator: &FilterOperator, value: &FilterValue) -> &'static str {
+        if let FilterOperator::RelativeDate(op) = operator {
+            return op;
+        }
+
         // Use special date operators for date values
         if matches!(value, FilterValue::Date(_)) {
             match operator {
@@ -591,6 +658,7 @@ impl XmlGenerator {
                 FilterOperator::Between => "between",
                 FilterOperator::Null => "null",
                 FilterOperator::NotNull => "not-null",
+                FilterOperator::RelativeDate(op) => op,
             }
         }
     }
@@ -621,6 +689,7 @@ impl XmlGenerator {
                 d.clone()
             }),
             FilterValue::Null => Ok(String::new()),
+            FilterValue::Pattern(pattern) => Ok(pattern.clone()),
             FilterValue::List(values) => {
                 let str_values: Result<Vec<String>> = values
                     .iter()
@@ -709,3 +778,348 @@ impl XmlGenerator {
             .replace('\'', "&apos;")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fql::{parse, tokenize};
+
+    /// Collapse whitespace between tags so expected documents can be indented freely
+    fn normalize_xml(xml: &str) -> String {
+        xml.lines()
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty())
+            .collect::<Vec<&str>>()
+            .join("")
+            .replace("> <", "><")
+            .replace(" />", "/>")
+    }
+
+    /// Run FQL through the full pipeline and compare against the expected FetchXML
+    fn assert_fql_xml(fql: &str, expected_xml: &str) {
+        let tokens = tokenize(fql).unwrap();
+        let query = parse(tokens, fql).unwrap();
+        let xml = to_fetchxml(query).unwrap().join("\n");
+        assert_eq!(normalize_xml(&xml), normalize_xml(expected_xml), "FQL: {}", fql);
+    }
+
+    #[test]
+    fn test_attribute_aliases() {
+        let fql = ".account | .name as company, .revenue as total_revenue, .accountnumber";
+        let expected_xml = r#"<fetch version="1.0" output-format="xml-platform" mapping="logical" distinct="false">
+      <entity name="account">
+        <attribute name="name" alias="company" />
+        <attribute name="revenue" alias="total_revenue" />
+        <attribute name="accountnumber" />
+      </entity>
+    </fetch>"#;
+
+        assert_fql_xml(fql, expected_xml);
+    }
+
+    #[test]
+    fn test_distinct_call_syntax_at_end_of_pipeline() {
+        let fql = ".account | .name | distinct()";
+        let expected_xml = r#"<fetch version="1.0" output-format="xml-platform" mapping="logical" distinct="true">
+      <entity name="account">
+        <attribute name="name" />
+      </entity>
+    </fetch>"#;
+
+        assert_fql_xml(fql, expected_xml);
+    }
+
+    #[test]
+    fn test_distinct_with_join() {
+        let fql = ".account | .name | join(.contact as c on c.contactid -> account.primarycontactid | .lastname) | distinct";
+        let expected_xml = r#"<fetch version="1.0" output-format="xml-platform" mapping="logical" distinct="true">
+      <entity name="account">
+        <attribute name="name" />
+        <link-entity name="contact" alias="c" from="contactid" to="primarycontactid" link-type="inner">
+          <attribute name="lastname" />
+        </link-entity>
+      </entity>
+    </fetch>"#;
+
+        assert_fql_xml(fql, expected_xml);
+    }
+
+    #[test]
+    fn test_distinct_with_limit() {
+        let fql = ".account | distinct() | .name | limit(5)";
+        let expected_xml = r#"<fetch version="1.0" output-format="xml-platform" mapping="logical" distinct="true" top="5">
+      <entity name="account">
+        <attribute name="name" />
+      </entity>
+    </fetch>"#;
+
+        assert_fql_xml(fql, expected_xml);
+    }
+
+    #[test]
+    fn test_distinct_with_join_and_page() {
+        let fql = ".account | join(.contact as c on c.contactid -> account.primarycontactid) | .name | page(2, 50) | distinct";
+        let expected_xml = r#"<fetch version="1.0" output-format="xml-platform" mapping="logical" distinct="true" page="2" count="50">
+      <entity name="account">
+        <attribute name="name" />
+        <link-entity name="contact" alias="c" from="contactid" to="primarycontactid" link-type="inner">
+        </link-entity>
+      </entity>
+    </fetch>"#;
+
+        assert_fql_xml(fql, expected_xml);
+    }
+
+    #[test]
+    fn test_union_two_entities() {
+        let fql = ".account | .name | union(.contact | .fullname)";
+        let expected_xml = r#"<fetch version="1.0" output-format="xml-platform" mapping="logical" distinct="false">
+      <entity name="account">
+        <attribute name="name" />
+      </entity>
+    </fetch>
+    <fetch version="1.0" output-format="xml-platform" mapping="logical" distinct="false">
+      <entity name="contact">
+        <attribute name="fullname" />
+      </entity>
+    </fetch>"#;
+
+        assert_fql_xml(fql, expected_xml);
+    }
+
+    #[test]
+    fn test_sum_grouped_by_owner() {
+        let fql = ".opportunity | group(.ownerid) | sum(.estimatedvalue) as total";
+        let expected_xml = r#"<fetch version="1.0" output-format="xml-platform" mapping="logical" distinct="false" aggregate="true">
+      <entity name="opportunity">
+        <attribute name="ownerid" groupby="true" alias="ownerid" />
+        <attribute name="estimatedvalue" aggregate="sum" alias="total" />
+      </entity>
+    </fetch>"#;
+
+        assert_fql_xml(fql, expected_xml);
+    }
+
+    #[test]
+    fn test_multi_column_grouping() {
+        let fql = ".opportunity | group(.ownerid, .statecode) | count() as deals, min(.estimatedvalue) as smallest, max(.estimatedvalue) as largest";
+        let expected_xml = r#"<fetch version="1.0" output-format="xml-platform" mapping="logical" distinct="false" aggregate="true">
+      <entity name="opportunity">
+        <attribute name="ownerid" groupby="true" alias="ownerid" />
+        <attribute name="statecode" groupby="true" alias="statecode" />
+        <attribute name="opportunityid" aggregate="count" alias="deals" />
+        <attribute name="estimatedvalue" aggregate="min" alias="smallest" />
+        <attribute name="estimatedvalue" aggregate="max" alias="largest" />
+      </entity>
+    </fetch>"#;
+
+        assert_fql_xml(fql, expected_xml);
+    }
+
+    #[test]
+    fn test_selected_grouped_columns_are_emitted_once() {
+        let fql = ".opportunity | .ownerid, .statecode | group(.ownerid, .statecode) | avg(.estimatedvalue) as average";
+        let expected_xml = r#"<fetch version="1.0" output-format="xml-platform" mapping="logical" distinct="false" aggregate="true">
+      <entity name="opportunity">
+        <attribute name="ownerid" groupby="true" alias="ownerid" />
+        <attribute name="statecode" groupby="true" alias="statecode" />
+        <attribute name="estimatedvalue" aggregate="avg" alias="average" />
+      </entity>
+    </fetch>"#;
+
+        assert_fql_xml(fql, expected_xml);
+    }
+
+    #[test]
+    fn test_grouped_join_column_is_emitted_in_link_entity() {
+        let fql = ".account | .ownerid | join(.contact as c on c.contactid -> account.primarycontactid | .lastname) | group(.ownerid, c.lastname) | count() as total";
+        let expected_xml = r#"<fetch version="1.0" output-format="xml-platform" mapping="logical" distinct="false" aggregate="true">
+      <entity name="account">
+        <attribute name="ownerid" groupby="true" alias="ownerid" />
+        <attribute name="accountid" aggregate="count" alias="total" />
+        <link-entity name="contact" alias="c" from="contactid" to="primarycontactid" link-type="inner">
+          <attribute name="lastname" groupby="true" alias="c_lastname" />
+        </link-entity>
+      </entity>
+    </fetch>"#;
+
+        assert_fql_xml(fql, expected_xml);
+    }
+
+    #[test]
+    fn test_between_call_syntax() {
+        let fql = ".account | .revenue between(1000, 5000)";
+        let expected_xml = r#"<fetch version="1.0" output-format="xml-platform" mapping="logical" distinct="false">
+      <entity name="account">
+        <filter type="and">
+          <condition attribute="revenue" operator="between">
+            <value>1000</value>
+            <value>5000</value>
+          </condition>
+        </filter>
+      </entity>
+    </fetch>"#;
+
+        assert_fql_xml(fql, expected_xml);
+    }
+
+    #[test]
+    fn test_between_filter_stage() {
+        let fql = ".account | .createdon | filter(between(@2024-01-01, @2024-12-31))";
+        let expected_xml = r#"<fetch version="1.0" output-format="xml-platform" mapping="logical" distinct="false">
+      <entity name="account">
+        <attribute name="createdon" />
+        <filter type="and">
+          <condition attribute="createdon" operator="between">
+            <value>2024-01-01</value>
+            <value>2024-12-31</value>
+          </condition>
+        </filter>
+      </entity>
+    </fetch>"#;
+
+        assert_fql_xml(fql, expected_xml);
+    }
+
+    #[test]
+    fn test_filter_stage_with_expression() {
+        let fql = ".account | filter(.revenue between(1000, 5000) and .statecode == 0)";
+        let expected_xml = r#"<fetch version="1.0" output-format="xml-platform" mapping="logical" distinct="false">
+      <entity name="account">
+        <filter type="and">
+          <condition attribute="revenue" operator="between">
+            <value>1000</value>
+            <value>5000</value>
+          </condition>
+          <condition attribute="statecode" operator="eq" value="0" />
+        </filter>
+      </entity>
+    </fetch>"#;
+
+        assert_fql_xml(fql, expected_xml);
+    }
+
+    #[test]
+    fn test_text_match_functions() {
+        let fql = ".account | .name contains(\"Contoso\") and .city startswith(\"Lon\")";
+        let expected_xml = r#"<fetch version="1.0" output-format="xml-platform" mapping="logical" distinct="false">
+      <entity name="account">
+        <filter type="and">
+          <condition attribute="name" operator="like" value="%Contoso%" />
+          <condition attribute="city" operator="like" value="Lon%" />
+        </filter>
+      </entity>
+    </fetch>"#;
+
+        assert_fql_xml(fql, expected_xml);
+    }
+
+    #[test]
+    fn test_text_match_escapes_wildcards() {
+        let fql = ".product | .name | filter(contains(\"50%_off[1]\"))";
+        let expected_xml = r#"<fetch version="1.0" output-format="xml-platform" mapping="logical" distinct="false">
+      <entity name="product">
+        <attribute name="name" />
+        <filter type="and">
+          <condition attribute="name" operator="like" value="%50[%][_]off[[]1]%" />
+        </filter>
+      </entity>
+    </fetch>"#;
+
+        assert_fql_xml(fql, expected_xml);
+    }
+
+    #[test]
+    fn test_raw_like_passes_pattern_through() {
+        let fql = ".account | filter(.accountnumber like(\"AC_%\"))";
+        let expected_xml = r#"<fetch version="1.0" output-format="xml-platform" mapping="logical" distinct="false">
+      <entity name="account">
+        <filter type="and">
+          <condition attribute="accountnumber" operator="like" value="AC_%" />
+        </filter>
+      </entity>
+    </fetch>"#;
+
+        assert_fql_xml(fql, expected_xml);
+    }
+
+    #[test]
+    fn test_text_match_inside_join() {
+        let fql = ".account | .name | join(.contact as c on c.contactid -> account.primarycontactid | .fullname contains(\"smith\") | .lastname | filter(startswith(\"Sm\")))";
+        let expected_xml = r#"<fetch version="1.0" output-format="xml-platform" mapping="logical" distinct="false">
+      <entity name="account">
+        <attribute name="name" />
+        <link-entity name="contact" alias="c" from="contactid" to="primarycontactid" link-type="inner">
+          <attribute name="lastname" />
+          <filter type="and">
+            <condition attribute="fullname" operator="like" value="%smith%" />
+            <condition attribute="lastname" operator="like" value="Sm%" />
+          </filter>
+        </link-entity>
+      </entity>
+    </fetch>"#;
+
+        assert_fql_xml(fql, expected_xml);
+    }
+
+    #[test]
+    fn test_text_match_on_joined_alias() {
+        let fql = ".account | join(.contact as c on c.contactid -> account.primarycontactid) | c.fullname endswith(\"son\")";
+        let expected_xml = r#"<fetch version="1.0" output-format="xml-platform" mapping="logical" distinct="false">
+      <entity name="account">
+        <link-entity name="contact" alias="c" from="contactid" to="primarycontactid" link-type="inner">
+          <filter type="and">
+            <condition attribute="fullname" operator="like" value="%son" />
+          </filter>
+        </link-entity>
+      </entity>
+    </fetch>"#;
+
+        assert_fql_xml(fql, expected_xml);
+    }
+
+    #[test]
+    fn test_relative_date_with_count() {
+        let fql = ".account | .name, .createdon | filter(last_x_days(30))";
+        let expected_xml = r#"<fetch version="1.0" output-format="xml-platform" mapping="logical" distinct="false">
+      <entity name="account">
+        <attribute name="name" />
+        <attribute name="createdon" />
+        <filter type="and">
+          <condition attribute="createdon" operator="last-x-days" value="30" />
+        </filter>
+      </entity>
+    </fetch>"#;
+
+        assert_fql_xml(fql, expected_xml);
+    }
+
+    #[test]
+    fn test_relative_date_without_argument() {
+        let fql = ".contact | filter(.birthdate this_month()) | filter(.createdon this_fiscal_year())";
+        let expected_xml = r#"<fetch version="1.0" output-format="xml-platform" mapping="logical" distinct="false">
+      <entity name="contact">
+        <filter type="and">
+          <condition attribute="birthdate" operator="this-month" />
+          <condition attribute="createdon" operator="this-fiscal-year" />
+        </filter>
+      </entity>
+    </fetch>"#;
+
+        assert_fql_xml(fql, expected_xml);
+    }
+
+    #[test]
+    fn test_relative_date_with_date_argument() {
+        let fql = ".account | .modifiedon on_or_before(@2024-01-31)";
+        let expected_xml = r#"<fetch version="1.0" output-format="xml-platform" mapping="logical" distinct="false">
+      <entity name="account">
+        <filter type="and">
+          <condition attribute="modifiedon" operator="on-or-before" value="2024-01-31" />
+        </filter>
+      </entity>
+    </fetch>"#;
+
+        assert_fql_xml(fql, expected_xml);
+    }
+}
@@ -0,0 +1,199 @@
+//! Log file formatting: human-readable text (default) or one JSON object per line
+//!
+//! JSON mode is selected with `DYNAMICS_LOG_FORMAT=json`. It is read from the
+//! environment rather than the options registry because the logger starts
+//! before the config database is opened.
+
+use log::kv::{self, Key, Source, Value, VisitSource, VisitValue};
+use serde_json::{Map, Value as JsonValue};
+use std::io::Write;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Environment variable choosing the log format (`text` or `json`)
+pub const FORMAT_ENV_VAR: &str = "DYNAMICS_LOG_FORMAT";
+
+static JSON_MODE: AtomicBool = AtomicBool::new(false);
+
+/// Whether log records are written as JSON; callers with structured data
+/// should then pass it as key-values instead of formatting it into the message
+pub fn json_enabled() -> bool {
+    JSON_MODE.load(Ordering::Relaxed)
+}
+
+/// Initialize `env_logger` writing to `target` in the format picked by the environment
+pub fn init(target: Box<dyn Write + Send>) {
+    let json = std::env::var(FORMAT_ENV_VAR).is_ok_and(|format| format.eq_ignore_ascii_case("json"));
+    JSON_MODE.store(json, Ordering::Relaxed);
+
+    let mut builder = env_logger::Builder::from_default_env();
+    builder.target(env_logger::Target::Pipe(target));
+    if json {
+        builder.format(|buf, record| writeln!(buf, "{}", json_record(record)));
+    }
+    builder.init();
+}
+
+/// Log `message` with each entry of `fields` attached as a key-value
+///
+/// Arrays and objects are passed as JSON text; the JSON formatter nests them again.
+pub fn log_fields(level: log::Level, target: &str, message: &str, fields: &Map<String, JsonValue>) {
+    if level > log::max_level() {
+        return;
+    }
+    log::logger().log(&log::Record::builder()
+        .level(level)
+        .target(target)
+        .args(format_args!("{}", message))
+        .key_values(&JsonFields(fields))
+        .build());
+}
+
+/// A JSON object as a key-value source
+struct JsonFields<'a>(&'a Map<String, JsonValue>);
+
+impl Source for JsonFields<'_> {
+    fn visit<'kvs>(&'kvs self, visitor: &mut dyn VisitSource<'kvs>) -> Result<(), kv::Error> {
+        for (key, value) in self.0 {
+            let value = match value {
+                JsonValue::Null => Value::null(),
+                JsonValue::Bool(b) => Value::from(*b),
+                JsonValue::Number(n) => match (n.as_u64(), n.as_i64()) {
+                    (Some(n), _) => Value::from(n),
+                    (None, Some(n)) => Value::from(n),
+                    (None, None) => Value::from(n.as_f64().unwrap_or_default()),
+                },
+                JsonValue::String(text) => Value::from(text.as_str()),
+                nested => Value::from_display(nested),
+            };
+            visitor.visit_pair(Key::from_str(key), value)?;
+        }
+        Ok(())
+    }
+}
+
+/// A record as a JSON object: timestamp, level, target, message, then its key-values
+fn json_record(record: &log::Record) -> JsonValue {
+    let mut object = Map::new();
+    object.insert("timestamp".to_string(), chrono::Utc::now().to_rfc3339().into());
+    object.insert("level".to_string(), record.level().as_str().into());
+    object.insert("target".to_string(), record.target().into());
+    object.insert("message".to_string(), record.args().to_string().into());
+
+    let mut fields = FieldCollector(&mut object);
+    let _ = record.key_values().visit(&mut fields);
+    JsonValue::Object(object)
+}
+
+/// Copies key-values into the JSON object
+struct FieldCollector<'a>(&'a mut Map<String, JsonValue>);
+
+impl<'kvs> VisitSource<'kvs> for FieldCollector<'_> {
+    fn visit_pair(&mut self, key: Key<'kvs>, value: Value<'kvs>) -> Result<(), kv::Error> {
+        self.0.insert(key.as_str().to_string(), json_value(&value));
+        Ok(())
+    }
+}
+
+fn json_value(value: &Value) -> JsonValue {
+    let mut visitor = JsonVisitor(JsonValue::Null);
+    let _ = value.visit(&mut visitor);
+    visitor.0
+}
+
+/// Keeps numbers, booleans, empty options and JSON arrays/objects typed;
+/// anything else becomes its text
+struct JsonVisitor(JsonValue);
+
+impl<'v> VisitValue<'v> for JsonVisitor {
+    fn visit_any(&mut self, value: Value) -> Result<(), kv::Error> {
+        let text = value.to_string();
+        self.0 = match serde_json::from_str::<JsonValue>(&text) {
+            Ok(nested) if nested.is_array() || nested.is_object() => nested,
+            _ => text.into(),
+        };
+        Ok(())
+    }
+
+    fn visit_null(&mut self) -> Result<(), kv::Error> {
+        self.0 = JsonValue::Null;
+        Ok(())
+    }
+
+    fn visit_u64(&mut self, value: u64) -> Result<(), kv::Error> {
+        self.0 = value.into();
+        Ok(())
+    }
+
+    fn visit_i64(&mut self, value: i64) -> Result<(), kv::Error> {
+        self.0 = value.into();
+        Ok(())
+    }
+
+    fn visit_f64(&mut self, value: f64) -> Result<(), kv::Error> {
+        self.0 = value.into();
+        Ok(())
+    }
+
+    fn visit_bool(&mut self, value: bool) -> Result<(), kv::Error> {
+        self.0 = value.into();
+        Ok(())
+    }
+
+    fn visit_str(&mut self, value: &str) -> Result<(), kv::Error> {
+        self.0 = value.into();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use log::kv::ToValue;
+
+    #[test]
+    fn test_json_record_includes_typed_fields() {
+        let fields: [(&str, Value); 5] = [
+            ("entity", Value::from("contacts")),
+            ("status_code", Value::from(201u64)),
+            ("success", Value::from(true)),
+            ("duration_ms", Value::from(42u64)),
+            ("error_message", None::<&str>.to_value()),
+        ];
+        let record = log::Record::builder()
+            .level(log::Level::Info)
+            .target("dynamics_cli::api")
+            .args(format_args!("API Operation Completed"))
+            .key_values(&fields)
+            .build();
+
+        let json = json_record(&record);
+        assert_eq!(json["level"], "INFO");
+        assert_eq!(json["target"], "dynamics_cli::api");
+        assert_eq!(json["message"], "API Operation Completed");
+        assert_eq!(json["entity"], "contacts");
+        assert_eq!(json["status_code"], 201);
+        assert_eq!(json["success"], true);
+        assert_eq!(json["duration_ms"], 42);
+        assert!(json["error_message"].is_null());
+        assert!(json["timestamp"].is_string());
+    }
+
+    #[test]
+    fn test_json_fields_round_trip_through_key_values() {
+        let fields = serde_json::json!({
+            "retry_attempts": 2,
+            "status_code": null,
+            "rate_limit_delays_ms": [100, 250],
+        });
+        let fields = JsonFields(fields.as_object().unwrap());
+        let record = log::Record::builder()
+            .args(format_args!("API Operation Failed"))
+            .key_values(&fields)
+            .build();
+
+        let json = json_record(&record);
+        assert_eq!(json["retry_attempts"], 2);
+        assert!(json["status_code"].is_null());
+        assert_eq!(json["rate_limit_delays_ms"], serde_json::json!([100, 250]));
+    }
+}
@@ -6,6 +6,7 @@ use clap::Parser;
 use log::{debug, info};
 use once_cell::sync::OnceCell;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
 
 mod api;
 mod auth;
@@ -16,6 +17,7 @@ mod cs_parser;
 mod csv_parser;
 // mod dynamics; // Disabled during config rewrite
 mod fql;
+mod log_format;
 mod tui;
 mod ui;
 mod update;
@@ -70,6 +72,26 @@ pub fn reload_runtime_config(config: tui::state::RuntimeConfig) {
         .store(Arc::new(config));
 }
 
+/// Rebuild the RuntimeConfig whenever an option it is derived from changes
+fn watch_runtime_config_options(registry: &config::options::OptionsRegistry) {
+    static GENERATION: AtomicU64 = AtomicU64::new(0);
+
+    for namespace in ["tui", "theme", "keys", "keybind"] {
+        registry.on_namespace_change(namespace, |key, _| {
+            log::debug!("Option '{}' changed, reloading runtime config", key);
+            let generation = GENERATION.fetch_add(1, Ordering::SeqCst) + 1;
+            tokio::spawn(async move {
+                match tui::state::RuntimeConfig::load_from_options().await {
+                    // A newer change started its own reload, which will see this one too
+                    Ok(_) if GENERATION.load(Ordering::SeqCst) != generation => {}
+                    Ok(config) => reload_runtime_config(config),
+                    Err(e) => log::error!("Failed to reload runtime config: {}", e),
+                }
+            });
+        });
+    }
+}
+
 use cli::Cli;
 // use cli::app::Commands;
 // use cli::commands::auth::AuthSubcommands;
@@ -97,13 +119,24 @@ async fn main() -> Result<()> {
         .write(true)
         .truncate(true)
         .open("dynamics-cli.log")?;
-    env_logger::Builder::from_default_env()
-        .target(env_logger::Target::Pipe(Box::new(log_file)))
-        .init();
+    log_format::init(Box::new(log_file));
 
     let cli = Cli::parse();
     info!("Starting dynamics-cli");
 
+    // Config commands inspect the database as found, before Config::load migrates it;
+    // completion scripts don't need the config at all
+    use cli::app::Commands;
+    let command = match cli.command {
+        Commands::Config(config_args) if config_args.runs_before_load() => {
+            return cli::commands::handle_config_command(config_args).await;
+        }
+        Commands::Completions(completions_args) if completions_args.runs_before_load() => {
+            return cli::commands::handle_completions_command(completions_args).await;
+        }
+        command => command,
+    };
+
     // Initialize global OptionsRegistry first (needed by Config)
     let registry = config::options::OptionsRegistry::new();
     config::options::registrations::register_all(&registry)?;
@@ -119,6 +152,7 @@ async fn main() -> Result<()> {
     // Initialize global RuntimeConfig from options
     let runtime_config = tui::state::RuntimeConfig::load_from_options().await?;
     init_runtime_config(runtime_config);
+    watch_runtime_config_options(&global_config().options.registry());
     debug!("Initialized runtime config from options");
 
     // Initialize global ClientManager once
@@ -126,26 +160,43 @@ async fn main() -> Result<()> {
     CLIENT_MANAGER.set(client_manager).map_err(|_| anyhow::anyhow!("Failed to initialize global ClientManager"))?;
 
     // Handle commands
-    use cli::app::Commands;
-    match cli.command {
+    match command {
         Commands::Auth(auth_args) => {
             cli::commands::auth_command(auth_args).await?;
         }
         Commands::Query(query_args) => {
             cli::commands::handle_query_command(query_args).await?;
         }
+        Commands::Get(get_args) => {
+            cli::commands::handle_get_command(get_args).await?;
+        }
+        Commands::Diff(diff_args) => {
+            cli::commands::handle_diff_command(diff_args).await?;
+        }
         Commands::Raw(raw_args) => {
             cli::commands::handle_raw_command(raw_args).await?;
         }
+        Commands::Operations(operations_args) => {
+            cli::commands::handle_operations_command(operations_args).await?;
+        }
+        Commands::Entity(entity_args) => {
+            cli::commands::handle_entity_command(entity_args).await?;
+        }
+        Commands::Config(config_args) => {
+            cli::commands::handle_config_command(config_args).await?;
+        }
         Commands::Tui(tui_args) => {
             cli::commands::tui_command(tui_args).await?;
         }
         Commands::Update(update_args) => {
             cli::commands::handle_update_command(update_args).await?;
         }
+        Commands::Completions(completions_args) => {
+            cli::commands::handle_completions_command(completions_args).await?;
+        }
         _ => {
             println!("Some commands are temporarily disabled during the config system rewrite.");
-            println!("Available commands: auth, query, raw, tui, update");
+            println!("Available commands: auth, config, query, get, diff, raw, operations, entity, tui, update, completions");
             println!("Use --help with any command for more information.");
         }
     }
@@ -1,4 +1,4 @@
-use crate::tui::{Command, Element, Subscription, Theme, LayeredView, QuitPolicy, SuspendPolicy};
+use crate::tui::{Command, Element, Subscription, Theme, LayeredView, QuitPolicy, SuspendPolicy, KeyBinding, PaletteAction};
 use crate::tui::element::FocusId;
 use ratatui::text::Line;
 use std::any::Any;
@@ -30,6 +30,7 @@ pub trait AppState {
 /// New lifecycle features:
 /// - InitParams: typed parameters for app initialization
 /// - Lifecycle hooks: on_suspend, on_resume, on_destroy
+/// - Session restore: session_key, suspend_state, restore_state
 /// - QuitPolicy: control what happens when navigating away
 pub trait App: Sized + Send + 'static {
     /// The app's state type
@@ -54,6 +55,17 @@ pub trait App: Sized + Send + 'static {
     /// Return the app's title (static string for help menu, etc.)
     fn title() -> &'static str;
 
+    /// Extra keys shown in the help menu that aren't keyboard subscriptions,
+    /// e.g. keys handled by focused widgets in the current view
+    fn help_bindings(_state: &Self::State) -> Vec<(KeyBinding, String)> {
+        Vec::new()
+    }
+
+    /// Actions this app offers in the command palette while it's running
+    fn palette_actions(_state: &Self::State) -> Vec<PaletteAction<Self::Msg>> {
+        Vec::new()
+    }
+
     /// Return optional status text (dynamic, styled based on state)
     fn status(state: &Self::State) -> Option<Line<'static>> {
         None
@@ -95,6 +107,23 @@ pub trait App: Sized + Send + 'static {
         Command::None
     }
 
+    /// Identifies what this instance was launched with (usually derived from
+    /// its params); apps returning `Some` take part in session restore
+    fn session_key(_state: &Self::State) -> Option<String> {
+        None
+    }
+
+    /// Resumable state to save when the app is suspended or closed
+    fn suspend_state(_state: &Self::State) -> Option<serde_json::Value> {
+        None
+    }
+
+    /// Apply state saved by `suspend_state` when the app is relaunched with
+    /// the same session key
+    fn restore_state(_state: &mut Self::State, _snapshot: serde_json::Value) -> Command<Self::Msg> {
+        Command::None
+    }
+
     /// Check if the app is capturing raw input (e.g., keybind capture mode)
     /// When true, global keybinds should be bypassed to allow the app to handle all keys
     fn is_capturing_raw_input(_state: &Self::State) -> bool {
@@ -2,7 +2,7 @@ use crossterm::event::KeyCode;
 use ratatui::text::{Line, Span};
 use ratatui::style::Style;
 use ratatui::prelude::Stylize;
-use crate::tui::{App, AppId, Command, Element, Subscription, Theme, LayoutConstraint, FocusId, LayeredView};
+use crate::tui::{App, AppId, Command, Element, Subscription, Theme, LayoutConstraint, FocusId, LayeredView, ToastLevel, KeyBinding, PaletteAction};
 use crate::tui::element::ColumnBuilder;
 use crate::tui::widgets::{ListItem, ListState};
 
@@ -13,6 +13,9 @@ pub enum Msg {
     LaunchApp(usize),
     ListNavigate(KeyCode),
     EnvironmentLoaded(Option<String>),
+    UpdateChecked(Option<crate::update::UpdateInfo>),
+    CheckForUpdates,
+    UpdateCheckFinished(Result<crate::update::UpdateInfo, String>),
 }
 
 pub struct State {
@@ -45,6 +48,16 @@ impl Default for State {
                     name: "Copy Questionnaire".to_string(),
                     description: "Copy Dynamics 365 questionnaires with all related entities".to_string(),
                 },
+                AppInfo {
+                    id: AppId::QueryRunner,
+                    name: "Query Runner".to_string(),
+                    description: "Run FQL queries and browse the results".to_string(),
+                },
+                AppInfo {
+                    id: AppId::SavedQueries,
+                    name: "Saved Queries".to_string(),
+                    description: "Run, edit or delete saved FQL queries".to_string(),
+                },
                 AppInfo {
                     id: AppId::OperationQueue,
                     name: "Operation Queue".to_string(),
@@ -99,6 +112,13 @@ impl ListItem for AppInfo {
 
 impl crate::tui::AppState for State {}
 
+fn notify_update_available(info: &crate::update::UpdateInfo) -> Command<Msg> {
+    Command::notify(
+        format!("Update available: {} → {} (open Updates to install)", info.current, info.latest),
+        ToastLevel::Info,
+    )
+}
+
 impl App for AppLauncher {
     type State = State;
     type Msg = Msg;
@@ -116,6 +136,8 @@ impl App for AppLauncher {
                 },
                 Msg::EnvironmentLoaded
             ),
+            // Runs in the background; the launcher is usable while it's pending
+            Command::perform(crate::update::startup_check(), Msg::UpdateChecked),
             Command::set_focus(FocusId::new("app-list")),
         ]);
         (state, cmd)
@@ -140,6 +162,27 @@ impl App for AppLauncher {
                 state.current_environment = env;
                 Command::None
             }
+            Msg::UpdateChecked(Some(info)) => notify_update_available(&info),
+            Msg::UpdateChecked(None) => Command::None,
+            Msg::CheckForUpdates => Command::batch(vec![
+                Command::notify("Checking for updates...", ToastLevel::Info),
+                Command::perform(
+                    async {
+                        crate::update::check_for_updates(crate::update::configured_channel().await).await
+                            .map_err(|e| e.to_string())
+                    },
+                    Msg::UpdateCheckFinished,
+                ),
+            ]),
+            Msg::UpdateCheckFinished(Ok(info)) if info.needs_update => notify_update_available(&info),
+            Msg::UpdateCheckFinished(Ok(info)) => Command::notify(
+                format!("Up to date ({})", info.current),
+                ToastLevel::Success,
+            ),
+            Msg::UpdateCheckFinished(Err(e)) => Command::notify(
+                format!("Update check failed: {}", e),
+                ToastLevel::Error,
+            ),
         }
     }
 
@@ -172,6 +215,30 @@ impl App for AppLauncher {
         "App Launcher"
     }
 
+    fn palette_actions(state: &State) -> Vec<PaletteAction<Msg>> {
+        let mut actions: Vec<_> = state.apps.iter()
+            .enumerate()
+            .map(|(idx, app)| PaletteAction::new(format!("Open {}", app.name), Msg::LaunchApp(idx))
+                .detail(app.description.clone()))
+            .collect();
+
+        if let Some(idx) = state.apps.iter().position(|app| app.id == AppId::EnvironmentSelector) {
+            actions.push(PaletteAction::new("Switch Environment", Msg::LaunchApp(idx))
+                .detail(state.current_environment.as_deref().map(|env| format!("Current: {}", env)).unwrap_or_default()));
+        }
+        actions.push(PaletteAction::new("Check for Updates", Msg::CheckForUpdates)
+            .detail(format!("Installed: {}", crate::update::current_version())));
+        actions
+    }
+
+    fn help_bindings(_state: &State) -> Vec<(KeyBinding, String)> {
+        vec![
+            (KeyBinding::new(KeyCode::Up), "Select app".to_string()),
+            (KeyBinding::new(KeyCode::Down), "Select app".to_string()),
+            (KeyBinding::new(KeyCode::Enter), "Launch selected app".to_string()),
+        ]
+    }
+
     fn status(state: &State) -> Option<Line<'static>> {
         state.current_environment.as_ref().map(|env| {
             let theme = &crate::global_runtime_config().theme;
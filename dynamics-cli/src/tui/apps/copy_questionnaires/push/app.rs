@@ -1,16 +1,19 @@
 use super::models::*;
 use super::view;
-use super::step_commands;
+use super::step_commands::{self, ExecutionContext};
 use super::super::copy::domain::Questionnaire;
+use crate::config::protection::{check_environment_write, force_writes};
+use crate::config::repository::copy_checkpoints::CopyCheckpoint;
 use crate::tui::{
     app::App,
     command::{AppId, Command},
     subscription::Subscription,
     renderer::LayeredView,
+    ToastLevel,
 };
 use crossterm::event::KeyCode;
 use ratatui::text::Line;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::future::Future;
 use std::pin::Pin;
 use std::sync::Arc;
@@ -28,7 +31,7 @@ fn handle_step_complete<F>(
     next_phase: CopyPhase,
     next_step: usize,
     update_progress: F,
-    next_command: impl FnOnce(Arc<Questionnaire>, HashMap<String, String>, Vec<(String, String)>) -> StepFuture,
+    next_command: impl FnOnce(Arc<Questionnaire>, HashMap<String, String>, Vec<(String, String)>, ExecutionContext) -> StepFuture,
     next_msg: fn(Result<(HashMap<String, String>, Vec<(String, String)>), CopyError>) -> Msg,
 ) -> Command<Msg>
 where
@@ -49,43 +52,246 @@ where
 
             // Check if cancellation was requested
             if state.cancel_requested {
-                log::info!("Copy cancelled by user after step {}", next_step - 1);
-                let error = CopyError {
-                    phase: next_phase,
-                    step: next_step,
-                    error_message: "Copy cancelled by user".to_string(),
-                    partial_counts: HashMap::new(),
-                    rollback_complete: false,
-                    orphaned_entities_csv: None,
-                };
-                state.push_state = PushState::Failed(error);
-                state.cancel_requested = false;
-                let created_ids = state.created_ids.clone();
-                return Command::perform(
-                    super::step_commands::rollback_created_entities(created_ids),
-                    Msg::RollbackComplete
-                );
+                return cancel_and_rollback(state, next_phase, next_step);
             }
 
             // Start next step
             let questionnaire = Arc::clone(&state.questionnaire);
             let id_map = state.id_map.clone();
             let created_ids = state.created_ids.clone();
+            let next = next_command(questionnaire, id_map, created_ids, execution_context(state));
 
-            Command::perform(
-                next_command(questionnaire, id_map, created_ids),
-                next_msg
-            )
+            Command::perform(after_checkpoint(state, next_step - 1, next), next_msg)
         }
         Err(error) => {
-            state.push_state = PushState::Failed(error);
-            // Trigger rollback of all created entities
-            let created_ids = state.created_ids.clone();
+            fail(state, error)
+        }
+    }
+}
+
+/// Show the failure screen, keeping what the run created so it can resume
+///
+/// The checkpoint is saved again with the records the failed step created, which a
+/// resume deletes before running that step again. A dry run created nothing.
+fn fail(state: &mut State, mut error: CopyError) -> Command<Msg> {
+    if state.dry_run {
+        error.rollback_complete = true;
+        state.push_state = PushState::Failed(error);
+        return Command::None;
+    }
+
+    // Until now created_ids only holds what the last checkpoint covers
+    let checkpointed: HashSet<&(String, String)> = state.created_ids.iter().collect();
+    let partial_ids: Vec<(String, String)> = error.created_ids.iter()
+        .filter(|id| !checkpointed.contains(id))
+        .cloned()
+        .collect();
+    let command = if error.step > 1 {
+        let checkpoint = checkpoint(state, error.step - 1, partial_ids);
+        Command::perform(save_checkpoint(checkpoint), |_| Msg::CheckpointUpdated)
+    } else {
+        Command::None
+    };
+
+    state.created_ids = error.created_ids.clone();
+    if state.created_ids.is_empty() {
+        error.rollback_complete = true;
+    }
+    state.push_state = PushState::Failed(error);
+    command
+}
+
+/// Stop a run the user cancelled and roll back what it created
+fn cancel_and_rollback(state: &mut State, phase: CopyPhase, step: usize) -> Command<Msg> {
+    log::info!("Copy cancelled by user after step {}", step - 1);
+    state.cancel_requested = false;
+    state.push_state = PushState::Failed(CopyError {
+        phase,
+        step,
+        error_message: "Copy cancelled by user".to_string(),
+        partial_counts: HashMap::new(),
+        rollback_complete: false,
+        orphaned_entities_csv: None,
+        failed_items: Vec::new(),
+        created_ids: state.created_ids.clone(),
+    });
+    start_rollback(state)
+}
+
+/// Delete everything the run created, tracking progress on the failure screen
+///
+/// A dry run created nothing, so there is nothing to roll back.
+fn start_rollback(state: &mut State) -> Command<Msg> {
+    if state.dry_run || state.created_ids.is_empty() {
+        if let PushState::Failed(error) = &mut state.push_state {
+            error.rollback_complete = true;
+        }
+        return Command::None;
+    }
+
+    state.rolling_back = true;
+    let created_ids = state.created_ids.clone();
+    Command::perform(
+        super::step_commands::rollback_created_entities(created_ids),
+        Msg::RollbackComplete
+    )
+}
+
+/// Snapshot of the run after `completed_step`
+fn checkpoint(state: &State, completed_step: usize, partial_ids: Vec<(String, String)>) -> CopyCheckpoint {
+    CopyCheckpoint {
+        questionnaire_id: state.questionnaire_id.clone(),
+        copy_name: state.copy_name.clone(),
+        copy_code: state.copy_code.clone(),
+        completed_step,
+        id_map: state.id_map.clone(),
+        created_ids: state.created_ids.clone(),
+        partial_ids,
+        classifications_associated: state.classifications_associated,
+    }
+}
+
+async fn save_checkpoint(checkpoint: CopyCheckpoint) {
+    if let Err(e) = crate::global_config().save_copy_checkpoint(&checkpoint).await {
+        log::error!("Failed to save copy checkpoint after step {}: {}", checkpoint.completed_step, e);
+    }
+}
+
+async fn delete_checkpoint(questionnaire_id: String) {
+    if let Err(e) = crate::global_config().delete_copy_checkpoint(&questionnaire_id).await {
+        log::error!("Failed to delete copy checkpoint for {}: {}", questionnaire_id, e);
+    }
+}
+
+/// Save a checkpoint for `completed_step`, then run the next step
+///
+/// Saving before the next step starts keeps checkpoints in step order. Dry runs
+/// have nothing to resume and are not saved.
+fn after_checkpoint<T>(
+    state: &State,
+    completed_step: usize,
+    next: impl Future<Output = T> + Send + 'static,
+) -> impl Future<Output = T> + Send + 'static {
+    let checkpoint = (!state.dry_run).then(|| checkpoint(state, completed_step, Vec::new()));
+    async move {
+        if let Some(checkpoint) = checkpoint {
+            save_checkpoint(checkpoint).await;
+        }
+        next.await
+    }
+}
+
+/// Start a step from the current run state, used when resuming after step 1
+fn run_step(state: &State, step: usize) -> Command<Msg> {
+    use super::step_commands::*;
+
+    let q = Arc::clone(&state.questionnaire);
+    let id_map = state.id_map.clone();
+    let created_ids = state.created_ids.clone();
+    let ctx = execution_context(state);
+
+    match step {
+        ..=2 => Command::perform(step2_create_pages(q, id_map, created_ids, ctx), Msg::Step2Complete),
+        3 => Command::perform(step3_create_page_lines(q, id_map, created_ids, ctx), Msg::Step3Complete),
+        4 => Command::perform(step4_create_groups(q, id_map, created_ids, ctx), Msg::Step4Complete),
+        5 => Command::perform(step5_create_group_lines(q, id_map, created_ids, ctx), Msg::Step5Complete),
+        6 => Command::perform(step6_create_questions(q, id_map, created_ids, ctx), Msg::Step6Complete),
+        7 => Command::perform(step7_create_template_lines(q, id_map, created_ids, ctx), Msg::Step7Complete),
+        8 => Command::perform(step8_create_conditions(q, id_map, created_ids, ctx), Msg::Step8Complete),
+        9 => Command::perform(step9_create_condition_actions(q, id_map, created_ids, ctx), Msg::Step9Complete),
+        10 => Command::perform(step10_create_classifications(q, id_map, created_ids, ctx), Msg::Step10Complete),
+        _ => Command::perform(step11_publish_conditions(q, id_map, created_ids, ctx), Msg::Step11Complete),
+    }
+}
+
+fn execution_context(state: &State) -> ExecutionContext {
+    ExecutionContext { dry_run: state.dry_run }
+}
+
+/// Reset the run state and start step 1, for real or as a dry run
+fn start_run(state: &mut State, dry_run: bool) -> Command<Msg> {
+    // Initialize state
+    state.start_time = Some(std::time::Instant::now());
+    state.id_map.clear();
+    state.created_ids.clear();
+    state.classifications_associated = 0;
+    state.dry_run = dry_run;
+
+    // Transition to copying state
+    state.push_state = PushState::Copying(CopyProgress::new(&state.questionnaire));
+
+    // Start Step 1
+    let questionnaire = Arc::clone(&state.questionnaire);
+    let copy_name = state.copy_name.clone();
+    let copy_code = state.copy_code.clone();
+
+    Command::perform(
+        super::step_commands::step1_create_questionnaire(questionnaire, copy_name, copy_code, execution_context(state)),
+        |result| Msg::Step1Complete(result.map(|(id, _)| id))
+    )
+}
+
+/// Check the target environment before a write, asking first when it is protected
+///
+/// Once the user has confirmed, the rest of this app's writes go ahead without asking again.
+fn guard_write(state: &mut State, write: GuardedWrite) -> Command<Msg> {
+    if state.writes_confirmed {
+        return run_write(state, write);
+    }
+    Command::perform(
+        async move {
+            let env_name = crate::client_manager().get_current_environment_name().await
+                .map_err(|e| e.to_string())?
+                .ok_or_else(|| "No environment selected".to_string())?;
+            let guard = check_environment_write(&env_name, force_writes()).await
+                .map_err(|e| format!("Failed to check protection of '{}': {}", env_name, e))?;
+            Ok((env_name, guard))
+        },
+        move |result| Msg::WriteChecked(write, result),
+    )
+}
+
+/// Start a write that passed the protected-environment check
+fn run_write(state: &mut State, write: GuardedWrite) -> Command<Msg> {
+    match write {
+        GuardedWrite::Copy => {
+            log::info!("Starting copy operation");
+            start_run(state, false)
+        }
+        GuardedWrite::Resume => {
+            if state.resuming {
+                return Command::None;
+            }
+            log::info!("Resuming copy from last checkpoint");
+            state.resuming = true;
             Command::perform(
-                super::step_commands::rollback_created_entities(created_ids),
-                Msg::RollbackComplete
+                super::step_commands::prepare_resume(state.questionnaire_id.clone()),
+                Msg::ResumeReady
             )
         }
+        GuardedWrite::Rollback => {
+            log::info!("Rolling back failed copy");
+            start_rollback(state)
+        }
+        GuardedWrite::Undo => {
+            // Transition to Failed state to show rollback progress
+            // (Quick hack: reuse the failure UI which has rollback tracking)
+            if let PushState::Success(result) = &state.push_state {
+                let synthetic_error = CopyError {
+                    phase: CopyPhase::CreatingClassifications, // Last phase
+                    step: 10,
+                    error_message: "Manual undo requested by user".to_string(),
+                    partial_counts: result.entities_created.clone(),
+                    rollback_complete: false,  // Will be set by RollbackComplete
+                    orphaned_entities_csv: None,
+                    failed_items: Vec::new(),
+                    created_ids: state.created_ids.clone(),
+                };
+                state.push_state = PushState::Failed(synthetic_error);
+            }
+
+            start_rollback(state)
+        }
     }
 }
 
@@ -107,64 +313,58 @@ impl App for PushQuestionnaireApp {
             created_ids: Vec::new(),
             classifications_associated: 0,
             start_time: None,
+            dry_run: false,
             cancel_requested: false,
             show_undo_confirmation: false,
+            saved_checkpoint: None,
+            resuming: false,
+            rolling_back: false,
+            protected_write_modal: crate::tui::ModalState::Closed,
+            writes_confirmed: false,
         };
 
-        (state, Command::None)
+        // Offer to resume if an earlier copy of this questionnaire stopped halfway
+        let questionnaire_id = state.questionnaire_id.clone();
+        let command = Command::perform(
+            async move {
+                crate::global_config().get_copy_checkpoint(&questionnaire_id).await
+                    .unwrap_or_else(|e| {
+                        log::error!("Failed to load copy checkpoint: {}", e);
+                        None
+                    })
+            },
+            Msg::CheckpointLoaded,
+        );
+
+        (state, command)
     }
 
     fn update(state: &mut Self::State, msg: Self::Msg) -> Command<Self::Msg> {
         match msg {
-            Msg::StartCopy => {
-                log::info!("Starting copy operation");
+            Msg::StartCopy => guard_write(state, GuardedWrite::Copy),
 
-                // Initialize state
-                state.start_time = Some(std::time::Instant::now());
-                state.id_map.clear();
-                state.created_ids.clear();
-
-                // Transition to copying state
-                state.push_state = PushState::Copying(CopyProgress::new(&state.questionnaire));
-
-                // Start Step 1
-                let questionnaire = Arc::clone(&state.questionnaire);
-                let copy_name = state.copy_name.clone();
-                let copy_code = state.copy_code.clone();
+            Msg::StartPreview => {
+                log::info!("Starting dry run of copy operation");
+                start_run(state, true)
+            }
 
-                Command::perform(
-                    super::step_commands::step1_create_questionnaire(questionnaire, copy_name, copy_code),
-                    |result| Msg::Step1Complete(result.map(|(id, _)| id))
-                )
+            Msg::ClosePreview => {
+                state.push_state = PushState::Confirming;
+                Command::None
             }
 
             Msg::Step1Complete(result) => {
                 match result {
                     Ok(new_q_id) => {
-                        // Check if cancellation was requested
-                        if state.cancel_requested {
-                            log::info!("Copy cancelled by user after step 1");
-                            let error = CopyError {
-                                phase: CopyPhase::CreatingPages,
-                                step: 2,
-                                error_message: "Copy cancelled by user".to_string(),
-                                partial_counts: HashMap::new(),
-                                rollback_complete: false,
-                                orphaned_entities_csv: None,
-                            };
-                            state.push_state = PushState::Failed(error);
-                            state.cancel_requested = false;
-                            let created_ids = state.created_ids.clone();
-                            return Command::perform(
-                                super::step_commands::rollback_created_entities(created_ids),
-                                Msg::RollbackComplete
-                            );
-                        }
-
                         // Update id map and created_ids
                         state.id_map.insert(state.questionnaire_id.clone(), new_q_id.clone());
                         state.created_ids.push(("nrq_questionnaires".to_string(), new_q_id));
 
+                        // Check if cancellation was requested
+                        if state.cancel_requested {
+                            return cancel_and_rollback(state, CopyPhase::CreatingPages, 2);
+                        }
+
                         // Update progress
                         if let PushState::Copying(ref mut progress) = state.push_state {
                             progress.phase = CopyPhase::CreatingPages;
@@ -177,19 +377,14 @@ impl App for PushQuestionnaireApp {
                         let id_map = state.id_map.clone();
                         let created_ids = state.created_ids.clone();
 
+                        let next = super::step_commands::step2_create_pages(questionnaire, id_map, created_ids, execution_context(state));
                         Command::perform(
-                            super::step_commands::step2_create_pages(questionnaire, id_map, created_ids),
+                            after_checkpoint(state, 1, next),
                             Msg::Step2Complete  // Keep full tuple
                         )
                     }
                     Err(error) => {
-                        state.push_state = PushState::Failed(error);
-                        // Trigger rollback of all created entities
-                        let created_ids = state.created_ids.clone();
-                        Command::perform(
-                            super::step_commands::rollback_created_entities(created_ids),
-                            Msg::RollbackComplete
-                        )
+                        fail(state, error)
                     }
                 }
             }
@@ -203,7 +398,7 @@ impl App for PushQuestionnaireApp {
                     |progress| {
                         progress.complete(EntityType::Pages);
                     },
-                    |q, id_map, created_ids| Box::pin(super::step_commands::step3_create_page_lines(q, id_map, created_ids)),
+                    |q, id_map, created_ids, ctx| Box::pin(super::step_commands::step3_create_page_lines(q, id_map, created_ids, ctx)),
                     Msg::Step3Complete,
                 )
             }
@@ -217,7 +412,7 @@ impl App for PushQuestionnaireApp {
                     |progress| {
                         progress.complete(EntityType::PageLines);
                     },
-                    |q, id_map, created_ids| Box::pin(super::step_commands::step4_create_groups(q, id_map, created_ids)),
+                    |q, id_map, created_ids, ctx| Box::pin(super::step_commands::step4_create_groups(q, id_map, created_ids, ctx)),
                     Msg::Step4Complete,
                 )
             }
@@ -231,7 +426,7 @@ impl App for PushQuestionnaireApp {
                     |progress| {
                         progress.complete(EntityType::Groups);
                     },
-                    |q, id_map, created_ids| Box::pin(super::step_commands::step5_create_group_lines(q, id_map, created_ids)),
+                    |q, id_map, created_ids, ctx| Box::pin(super::step_commands::step5_create_group_lines(q, id_map, created_ids, ctx)),
                     Msg::Step5Complete,
                 )
             }
@@ -245,7 +440,7 @@ impl App for PushQuestionnaireApp {
                     |progress| {
                         progress.complete(EntityType::GroupLines);
                     },
-                    |q, id_map, created_ids| Box::pin(super::step_commands::step6_create_questions(q, id_map, created_ids)),
+                    |q, id_map, created_ids, ctx| Box::pin(super::step_commands::step6_create_questions(q, id_map, created_ids, ctx)),
                     Msg::Step6Complete,
                 )
             }
@@ -259,7 +454,7 @@ impl App for PushQuestionnaireApp {
                     |progress| {
                         progress.complete(EntityType::Questions);
                     },
-                    |q, id_map, created_ids| Box::pin(super::step_commands::step7_create_template_lines(q, id_map, created_ids)),
+                    |q, id_map, created_ids, ctx| Box::pin(super::step_commands::step7_create_template_lines(q, id_map, created_ids, ctx)),
                     Msg::Step7Complete,
                 )
             }
@@ -273,7 +468,7 @@ impl App for PushQuestionnaireApp {
                     |progress| {
                         progress.complete(EntityType::TemplateLines);
                     },
-                    |q, id_map, created_ids| Box::pin(super::step_commands::step8_create_conditions(q, id_map, created_ids)),
+                    |q, id_map, created_ids, ctx| Box::pin(super::step_commands::step8_create_conditions(q, id_map, created_ids, ctx)),
                     Msg::Step8Complete,
                 )
             }
@@ -287,7 +482,7 @@ impl App for PushQuestionnaireApp {
                     |progress| {
                         progress.complete(EntityType::Conditions);
                     },
-                    |q, id_map, created_ids| Box::pin(super::step_commands::step9_create_condition_actions(q, id_map, created_ids)),
+                    |q, id_map, created_ids, ctx| Box::pin(super::step_commands::step9_create_condition_actions(q, id_map, created_ids, ctx)),
                     Msg::Step9Complete,
                 )
             }
@@ -309,19 +504,11 @@ impl App for PushQuestionnaireApp {
                         let questionnaire = Arc::clone(&state.questionnaire);
                         let id_map = state.id_map.clone();
                         let created_ids = state.created_ids.clone();
-                        Command::perform(
-                            super::step_commands::step10_create_classifications(questionnaire, id_map, created_ids),
-                            Msg::Step10Complete
-                        )
+                        let next = super::step_commands::step10_create_classifications(questionnaire, id_map, created_ids, execution_context(state));
+                        Command::perform(after_checkpoint(state, 9, next), Msg::Step10Complete)
                     }
                     Err(error) => {
-                        state.push_state = PushState::Failed(error);
-                        // Trigger rollback of all created entities
-                        let created_ids = state.created_ids.clone();
-                        Command::perform(
-                            super::step_commands::rollback_created_entities(created_ids),
-                            Msg::RollbackComplete
-                        )
+                        fail(state, error)
                     }
                 }
             }
@@ -344,19 +531,11 @@ impl App for PushQuestionnaireApp {
                         let questionnaire = Arc::clone(&state.questionnaire);
                         let id_map = state.id_map.clone();
                         let created_ids = state.created_ids.clone();
-                        Command::perform(
-                            super::step_commands::step11_publish_conditions(questionnaire, id_map, created_ids),
-                            Msg::Step11Complete
-                        )
+                        let next = super::step_commands::step11_publish_conditions(questionnaire, id_map, created_ids, execution_context(state));
+                        Command::perform(after_checkpoint(state, 10, next), Msg::Step11Complete)
                     }
                     Err(error) => {
-                        state.push_state = PushState::Failed(error);
-                        // Trigger rollback of all created entities
-                        let created_ids = state.created_ids.clone();
-                        Command::perform(
-                            super::step_commands::rollback_created_entities(created_ids),
-                            Msg::RollbackComplete
-                        )
+                        fail(state, error)
                     }
                 }
             }
@@ -372,6 +551,15 @@ impl App for PushQuestionnaireApp {
                             progress.complete(EntityType::ConditionStatusUpdates);
                         }
 
+                        // A dry run ends with its report; its IDs are placeholders
+                        if state.dry_run {
+                            let report = DryRunReport::new(&state.questionnaire, &state.created_ids, state.classifications_associated);
+                            log::info!("[dry run] Complete: {} entities would be created", report.total_entities);
+                            state.created_ids.clear();
+                            state.push_state = PushState::Previewed(report);
+                            return Command::None;
+                        }
+
                         // Calculate final statistics
                         let new_questionnaire_id = state.id_map.get(&state.questionnaire_id)
                             .cloned()
@@ -403,16 +591,11 @@ impl App for PushQuestionnaireApp {
                             duration,
                         });
 
-                        Command::None
+                        // Nothing left to resume
+                        Command::perform(delete_checkpoint(state.questionnaire_id.clone()), |_| Msg::CheckpointUpdated)
                     }
                     Err(error) => {
-                        state.push_state = PushState::Failed(error);
-                        // Trigger rollback of all created entities
-                        let created_ids = state.created_ids.clone();
-                        Command::perform(
-                            super::step_commands::rollback_created_entities(created_ids),
-                            Msg::RollbackComplete
-                        )
+                        fail(state, error)
                     }
                 }
             }
@@ -430,6 +613,9 @@ impl App for PushQuestionnaireApp {
             }
 
             Msg::RollbackComplete(result) => {
+                state.rolling_back = false;
+                let rolled_back = result.is_ok();
+
                 // Update the error state with rollback status
                 if let PushState::Failed(ref mut error) = state.push_state {
                     match result {
@@ -444,6 +630,84 @@ impl App for PushQuestionnaireApp {
                         }
                     }
                 }
+
+                if !rolled_back {
+                    return Command::None;
+                }
+                state.created_ids.clear();
+                state.saved_checkpoint = None;
+                Command::perform(delete_checkpoint(state.questionnaire_id.clone()), |_| Msg::CheckpointUpdated)
+            }
+
+            Msg::CheckpointLoaded(checkpoint) => {
+                if let Some(checkpoint) = &checkpoint {
+                    log::info!("Found copy checkpoint for {} after step {}", checkpoint.questionnaire_id, checkpoint.completed_step);
+                }
+                state.saved_checkpoint = checkpoint;
+                Command::None
+            }
+
+            Msg::CheckpointUpdated => Command::None,
+
+            Msg::Resume => {
+                if state.resuming {
+                    return Command::None;
+                }
+                guard_write(state, GuardedWrite::Resume)
+            }
+
+            Msg::ResumeReady(Ok(checkpoint)) => {
+                state.resuming = false;
+                state.saved_checkpoint = None;
+                state.dry_run = false;
+                state.start_time = Some(std::time::Instant::now());
+                state.copy_name = checkpoint.copy_name;
+                state.copy_code = checkpoint.copy_code;
+                state.id_map = checkpoint.id_map;
+                state.created_ids = checkpoint.created_ids;
+                state.classifications_associated = checkpoint.classifications_associated;
+                state.push_state = PushState::Copying(CopyProgress::resumed(&state.questionnaire, checkpoint.completed_step));
+
+                run_step(state, checkpoint.completed_step + 1)
+            }
+
+            Msg::ResumeReady(Err(message)) => {
+                state.resuming = false;
+                log::error!("Cannot resume copy: {}", message);
+                Command::notify(format!("Cannot resume: {}", message), ToastLevel::Error)
+            }
+
+            Msg::Rollback => guard_write(state, GuardedWrite::Rollback),
+
+            Msg::WriteChecked(write, Ok((env_name, guard))) => {
+                if guard.requires_confirmation() {
+                    log::info!("Write to protected environment '{}' needs confirmation", env_name);
+                    state.protected_write_modal = crate::tui::ModalState::Open((write, env_name));
+                    return Command::None;
+                }
+                run_write(state, write)
+            }
+
+            Msg::WriteChecked(_, Err(message)) => {
+                log::error!("Cannot write to target environment: {}", message);
+                Command::notify(message, ToastLevel::Error)
+            }
+
+            Msg::ConfirmProtectedWrite => {
+                let pending = state.protected_write_modal.data().cloned();
+                state.protected_write_modal.close();
+                match pending {
+                    Some((write, env_name)) => {
+                        log::info!("Confirmed writes to protected environment: {}", env_name);
+                        state.writes_confirmed = true;
+                        run_write(state, write)
+                    }
+                    None => Command::None,
+                }
+            }
+
+            Msg::CancelProtectedWrite => {
+                state.protected_write_modal.close();
                 Command::None
             }
 
@@ -493,26 +757,7 @@ impl App for PushQuestionnaireApp {
                 // User confirmed undo - trigger rollback
                 log::info!("User confirmed undo of successful copy");
                 state.show_undo_confirmation = false;
-
-                // Transition to Failed state to show rollback progress
-                // (Quick hack: reuse the failure UI which has rollback tracking)
-                if let PushState::Success(result) = &state.push_state {
-                    let synthetic_error = CopyError {
-                        phase: CopyPhase::CreatingClassifications, // Last phase
-                        step: 10,
-                        error_message: "Manual undo requested by user".to_string(),
-                        partial_counts: result.entities_created.clone(),
-                        rollback_complete: false,  // Will be set by RollbackComplete
-                        orphaned_entities_csv: None,
-                    };
-                    state.push_state = PushState::Failed(synthetic_error);
-                }
-
-                let created_ids = state.created_ids.clone();
-                Command::perform(
-                    super::step_commands::rollback_created_entities(created_ids),
-                    Msg::RollbackComplete
-                )
+                guard_write(state, GuardedWrite::Undo)
             }
 
             Msg::CancelUndo => {
@@ -538,11 +783,30 @@ impl App for PushQuestionnaireApp {
     }
 
     fn subscriptions(state: &Self::State) -> Vec<Subscription<Self::Msg>> {
+        if state.protected_write_modal.is_open() {
+            return vec![
+                Subscription::keyboard(KeyCode::Char('y'), "Write to protected environment", Msg::ConfirmProtectedWrite),
+                Subscription::keyboard(KeyCode::Char('n'), "Cancel", Msg::CancelProtectedWrite),
+                Subscription::keyboard(KeyCode::Esc, "Cancel", Msg::CancelProtectedWrite),
+            ];
+        }
+
         match &state.push_state {
             PushState::Confirming => {
-                vec![
+                let mut subs = vec![
                     Subscription::keyboard(KeyCode::Enter, "Start Copy", Msg::StartCopy),
+                    Subscription::keyboard(KeyCode::Char('p'), "Preview (dry run)", Msg::StartPreview),
                     Subscription::keyboard(KeyCode::Esc, "Cancel", Msg::Cancel),
+                ];
+                if state.saved_checkpoint.is_some() {
+                    subs.push(Subscription::keyboard(KeyCode::Char('r'), "Resume Previous Copy", Msg::Resume));
+                }
+                subs
+            }
+            PushState::Previewed(_) => {
+                vec![
+                    Subscription::keyboard(KeyCode::Enter, "Start Copy", Msg::StartCopy),
+                    Subscription::keyboard(KeyCode::Esc, "Back", Msg::ClosePreview),
                 ]
             }
             PushState::Copying(_) => {
@@ -569,10 +833,16 @@ impl App for PushQuestionnaireApp {
                 }
             }
             PushState::Failed(_) => {
-                vec![
-                    Subscription::keyboard(KeyCode::Char('r'), "Retry", Msg::Retry),
+                let mut subs = vec![
                     Subscription::keyboard(KeyCode::Esc, "Cancel", Msg::Cancel),
-                ]
+                ];
+                if state.can_resume() {
+                    subs.push(Subscription::keyboard(KeyCode::Char('r'), "Resume", Msg::Resume));
+                    subs.push(Subscription::keyboard(KeyCode::Char('b'), "Roll Back", Msg::Rollback));
+                } else if !state.rolling_back {
+                    subs.push(Subscription::keyboard(KeyCode::Char('r'), "Retry", Msg::Retry));
+                }
+                subs
             }
         }
     }
@@ -585,3 +855,44 @@ impl App for PushQuestionnaireApp {
         view::render_status(state)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::protection::WriteGuard;
+
+    fn checked(write: GuardedWrite, guard: WriteGuard) -> Msg {
+        Msg::WriteChecked(write, Ok(("production".to_string(), guard)))
+    }
+
+    #[test]
+    fn test_protected_target_stops_push_until_confirmed() {
+        let mut state = State::default();
+
+        PushQuestionnaireApp::update(&mut state, checked(GuardedWrite::Copy, WriteGuard::ConfirmationRequired));
+        assert!(matches!(state.push_state, PushState::Confirming));
+        assert_eq!(state.protected_write_modal.data(), Some(&(GuardedWrite::Copy, "production".to_string())));
+
+        // Declining leaves nothing started
+        PushQuestionnaireApp::update(&mut state, Msg::CancelProtectedWrite);
+        assert!(matches!(state.push_state, PushState::Confirming));
+        assert!(!state.writes_confirmed);
+
+        // A failed check doesn't write either
+        PushQuestionnaireApp::update(&mut state, Msg::WriteChecked(GuardedWrite::Copy, Err("No environment selected".to_string())));
+        assert!(matches!(state.push_state, PushState::Confirming));
+
+        PushQuestionnaireApp::update(&mut state, checked(GuardedWrite::Copy, WriteGuard::ConfirmationRequired));
+        PushQuestionnaireApp::update(&mut state, Msg::ConfirmProtectedWrite);
+        assert!(matches!(state.push_state, PushState::Copying(_)));
+        assert!(state.writes_confirmed && state.protected_write_modal.is_closed());
+    }
+
+    #[test]
+    fn test_unprotected_or_forced_target_pushes_right_away() {
+        let mut state = State::default();
+        PushQuestionnaireApp::update(&mut state, checked(GuardedWrite::Copy, WriteGuard::Allowed));
+        assert!(matches!(state.push_state, PushState::Copying(_)));
+        assert!(state.protected_write_modal.is_closed());
+    }
+}
@@ -2,6 +2,9 @@ use std::time::Instant;
 use std::collections::HashMap;
 use std::sync::Arc;
 use super::super::copy::domain::Questionnaire;
+use crate::config::protection::WriteGuard;
+use crate::config::repository::copy_checkpoints::CopyCheckpoint;
+use crate::tui::ModalState;
 
 #[derive(Clone)]
 pub struct State {
@@ -16,12 +19,22 @@ pub struct State {
     pub created_ids: Vec<(String, String)>,  // (entity_set, id) for rollback
     pub classifications_associated: usize,  // Count of classification associations (not in created_ids since they're not entities)
     pub start_time: Option<std::time::Instant>,
+    pub dry_run: bool,  // Current run only logs what it would send
 
     // Cancellation flag
     pub cancel_requested: bool,
 
     // Undo confirmation flag
     pub show_undo_confirmation: bool,
+
+    // Resume / rollback after a failure
+    pub saved_checkpoint: Option<CopyCheckpoint>,  // Checkpoint left by an earlier failed run
+    pub resuming: bool,  // Checking checkpointed records before continuing
+    pub rolling_back: bool,
+
+    // Protected target environment: the write waiting for confirmation, and the environment name
+    pub protected_write_modal: ModalState<(GuardedWrite, String)>,
+    pub writes_confirmed: bool,  // Confirmed once, later writes of this app don't ask again
 }
 
 impl Default for State {
@@ -54,8 +67,49 @@ impl Default for State {
             created_ids: Vec::new(),
             classifications_associated: 0,
             start_time: None,
+            dry_run: false,
             cancel_requested: false,
             show_undo_confirmation: false,
+            saved_checkpoint: None,
+            resuming: false,
+            rolling_back: false,
+            protected_write_modal: ModalState::Closed,
+            writes_confirmed: false,
+        }
+    }
+}
+
+impl State {
+    /// Whether a failed run kept its records, so it can still resume or roll back
+    pub fn can_resume(&self) -> bool {
+        match &self.push_state {
+            PushState::Failed(error) => {
+                !self.dry_run
+                    && !self.rolling_back
+                    && !error.rollback_complete
+                    && error.orphaned_entities_csv.is_none()
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Writes to the target environment that go through the protected-environment check
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GuardedWrite {
+    Copy,
+    Resume,
+    Rollback,
+    Undo,
+}
+
+impl GuardedWrite {
+    pub fn description(&self) -> &'static str {
+        match self {
+            GuardedWrite::Copy => "Copy the questionnaire into it",
+            GuardedWrite::Resume => "Resume the copy into it",
+            GuardedWrite::Rollback => "Delete the records the failed copy created in it",
+            GuardedWrite::Undo => "Delete the copy from it",
         }
     }
 }
@@ -95,6 +149,21 @@ impl EntityType {
     }
 }
 
+/// Entity type created by each step, in step order
+const STEP_ENTITY_TYPES: [EntityType; 11] = [
+    EntityType::Questionnaire,
+    EntityType::Pages,
+    EntityType::PageLines,
+    EntityType::Groups,
+    EntityType::GroupLines,
+    EntityType::Questions,
+    EntityType::TemplateLines,
+    EntityType::Conditions,
+    EntityType::ConditionActions,
+    EntityType::Classifications,
+    EntityType::ConditionStatusUpdates,
+];
+
 /// State machine for the push/copy process
 #[derive(Clone)]
 pub enum PushState {
@@ -109,6 +178,9 @@ pub enum PushState {
 
     /// Screen 3b: Failure - show error and partial progress
     Failed(CopyError),
+
+    /// Screen 3c: Dry run finished - show what the real run would create
+    Previewed(DryRunReport),
 }
 
 /// Progress tracking for the copy operation
@@ -176,6 +248,17 @@ impl CopyProgress {
         }
     }
 
+    /// Progress of a run resuming after `completed_step`, with the earlier steps done
+    pub fn resumed(questionnaire: &Questionnaire, completed_step: usize) -> Self {
+        let mut progress = Self::new(questionnaire);
+        for entity_type in STEP_ENTITY_TYPES.iter().take(completed_step) {
+            progress.complete(*entity_type);
+        }
+        progress.step = (completed_step + 1).min(STEP_ENTITY_TYPES.len());
+        progress.phase = CopyPhase::from_step_number(progress.step);
+        progress
+    }
+
     /// Update progress for a specific entity type by marking it as complete
     pub fn complete(&mut self, entity_type: EntityType) {
         if let Some((done, total)) = self.entity_progress.get_mut(&entity_type) {
@@ -224,6 +307,23 @@ pub enum CopyPhase {
 }
 
 impl CopyPhase {
+    /// Phase run by a step; steps past the end map to the last phase
+    pub fn from_step_number(step: usize) -> Self {
+        match step {
+            0 | 1 => CopyPhase::CreatingQuestionnaire,
+            2 => CopyPhase::CreatingPages,
+            3 => CopyPhase::CreatingPageLines,
+            4 => CopyPhase::CreatingGroups,
+            5 => CopyPhase::CreatingGroupLines,
+            6 => CopyPhase::CreatingQuestions,
+            7 => CopyPhase::CreatingTemplateLines,
+            8 => CopyPhase::CreatingConditions,
+            9 => CopyPhase::CreatingConditionActions,
+            10 => CopyPhase::CreatingClassifications,
+            _ => CopyPhase::PublishingConditions,
+        }
+    }
+
     pub fn step_number(&self) -> usize {
         match self {
             CopyPhase::CreatingQuestionnaire => 1,
@@ -267,6 +367,39 @@ pub struct CopyResult {
     pub duration: std::time::Duration,
 }
 
+/// Summary of a dry run: what each step would send
+#[derive(Clone)]
+pub struct DryRunReport {
+    pub steps: Vec<(CopyPhase, usize)>,  // Operations per step, in step order
+    pub total_entities: usize,
+}
+
+impl DryRunReport {
+    /// Count the placeholder entities a dry run "created", per step
+    pub fn new(questionnaire: &Questionnaire, created_ids: &[(String, String)], classifications: usize) -> Self {
+        use super::step_commands::entity_sets;
+
+        let created = |entity_set: &str| created_ids.iter().filter(|(set, _)| set == entity_set).count();
+
+        Self {
+            steps: vec![
+                (CopyPhase::CreatingQuestionnaire, created(entity_sets::QUESTIONNAIRES)),
+                (CopyPhase::CreatingPages, created(entity_sets::PAGES)),
+                (CopyPhase::CreatingPageLines, created(entity_sets::PAGE_LINES)),
+                (CopyPhase::CreatingGroups, created(entity_sets::GROUPS)),
+                (CopyPhase::CreatingGroupLines, created(entity_sets::GROUP_LINES)),
+                (CopyPhase::CreatingQuestions, created(entity_sets::QUESTIONS)),
+                (CopyPhase::CreatingTemplateLines, created(entity_sets::TEMPLATE_LINES)),
+                (CopyPhase::CreatingConditions, created(entity_sets::CONDITIONS)),
+                (CopyPhase::CreatingConditionActions, created(entity_sets::CONDITION_ACTIONS)),
+                (CopyPhase::CreatingClassifications, classifications),
+                (CopyPhase::PublishingConditions, questionnaire.conditions.len()),
+            ],
+            total_entities: questionnaire.total_entities(),
+        }
+    }
+}
+
 /// Error during copy operation
 #[derive(Clone)]
 pub struct CopyError {
@@ -276,12 +409,16 @@ pub struct CopyError {
     pub partial_counts: HashMap<String, usize>,
     pub rollback_complete: bool,
     pub orphaned_entities_csv: Option<String>,  // Path to CSV if rollback failed
+    pub failed_items: Vec<(usize, crate::api::operations::OperationResult)>,  // Unsuccessful batch items (position within step)
+    pub created_ids: Vec<(String, String)>,  // Everything created before the failure, including the failed step's partial work
 }
 
 #[derive(Clone)]
 pub enum Msg {
     // Screen 1: Confirmation
     StartCopy,
+    StartPreview,  // Dry run: walk all steps without sending anything
+    ClosePreview,
     Cancel,
 
     // Screen 2: Progress (per-step messages)
@@ -304,6 +441,18 @@ pub enum Msg {
     // Rollback
     RollbackComplete(Result<(), String>),  // Ok if successful, Err(csv_path) if failed
 
+    // Checkpoints
+    CheckpointLoaded(Option<CopyCheckpoint>),
+    CheckpointUpdated,
+    Resume,  // Continue from the last checkpoint instead of rolling back
+    ResumeReady(Result<CopyCheckpoint, String>),
+    Rollback,  // Delete what a failed run created
+
+    // Protected environments
+    WriteChecked(GuardedWrite, Result<(String, WriteGuard), String>),  // Target environment and whether it needs confirming
+    ConfirmProtectedWrite,
+    CancelProtectedWrite,
+
     // Actions
     ViewCopy,
     CopyAnother,
@@ -25,5 +25,7 @@ pub fn build_error(
         partial_counts,
         rollback_complete: false,
         orphaned_entities_csv: None,
+        failed_items: Vec::new(),
+        created_ids: created_ids.to_vec(),
     }
 }
@@ -4,11 +4,18 @@ use super::super::models::{CopyError, CopyPhase};
 use super::super::super::copy::domain::Questionnaire;
 use super::error::build_error;
 use super::helpers::extract_entity_id;
-use crate::api::{ResilienceConfig};
-use crate::api::operations::Operations;
+use crate::api::ResilienceConfig;
+use crate::api::operations::{Operation, OperationResult, Operations};
 use std::collections::HashMap;
 use std::sync::Arc;
 
+/// Settings shared by every step of one copy run
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ExecutionContext {
+    /// Log the operations each step would send instead of sending them
+    pub dry_run: bool,
+}
+
 /// Metadata about an entity being created
 pub struct EntityInfo {
     pub old_id: Option<String>,  // Original ID (for ID mapping), None if no mapping needed
@@ -22,6 +29,7 @@ pub const BATCH_CHUNK_SIZE: usize = 75;
 /// This eliminates ~700 lines of duplication across steps 2-10
 /// Automatically chunks large batches to avoid Dynamics 365 limits
 pub async fn execute_creation_step<F>(
+    ctx: ExecutionContext,
     questionnaire: Arc<Questionnaire>,
     id_map: HashMap<String, String>,
     created_ids: &mut Vec<(String, String)>,
@@ -29,78 +37,26 @@ pub async fn execute_creation_step<F>(
     step: usize,
     expected_count: usize,
     build_operations: F,
-) -> Result<(Vec<crate::api::operations::OperationResult>, Vec<EntityInfo>), CopyError>
+) -> Result<(Vec<OperationResult>, Vec<EntityInfo>), CopyError>
 where
     F: FnOnce(Arc<Questionnaire>, HashMap<String, String>) -> Result<(Operations, Vec<EntityInfo>), String>,
 {
     log::info!("Step {}/10: Starting {} (expecting {} entities)", step, phase.name(), expected_count);
 
-    // 1. Get client (common scaffolding)
-    let client_manager = crate::client_manager();
-    let env_name = client_manager.get_current_environment_name().await
-        .map_err(|e| build_error(e.to_string(), phase.clone(), step, created_ids))?
-        .ok_or_else(|| build_error("No environment selected".to_string(), phase.clone(), step, created_ids))?;
-
-    let client = client_manager.get_client(&env_name).await
-        .map_err(|e| build_error(e.to_string(), phase.clone(), step, created_ids))?;
-
-    let resilience = ResilienceConfig::default();
-
-    // 2. Build operations (unique per step)
+    // 1. Build operations (unique per step)
     log::debug!("Building operations for {} entities", expected_count);
     let (operations, entity_info) = build_operations(questionnaire, id_map)
         .map_err(|e| build_error(e, phase.clone(), step, created_ids))?;
 
-    // 3. Execute operations with automatic chunking
-    let all_operations = operations.operations();
-    let total_ops = all_operations.len();
-
-    if total_ops == 0 {
+    if operations.is_empty() {
         log::info!("Step {}/10: No entities to create for {}", step, phase.name());
         return Ok((vec![], entity_info));
     }
 
-    let mut all_results = Vec::with_capacity(total_ops);
-
-    // Chunk operations if exceeds batch size
-    if total_ops > BATCH_CHUNK_SIZE {
-        log::info!("Chunking {} operations into batches of {} for {}",
-            total_ops, BATCH_CHUNK_SIZE, phase.name());
-
-        // Process in chunks
-        for (chunk_idx, chunk) in all_operations.chunks(BATCH_CHUNK_SIZE).enumerate() {
-            let chunk_ops = Operations::from_operations(chunk.to_vec());
-
-            log::debug!("Executing chunk {}/{} ({} operations) for {}",
-                chunk_idx + 1,
-                (total_ops + BATCH_CHUNK_SIZE - 1) / BATCH_CHUNK_SIZE,
-                chunk.len(),
-                phase.name());
-
-            let chunk_results = chunk_ops.execute(&client, &resilience).await
-                .map_err(|e| build_error(
-                    format!("Failed to execute chunk {}: {}", chunk_idx + 1, e),
-                    phase.clone(),
-                    step,
-                    created_ids
-                ))?;
-
-            log::info!("Completed chunk {}/{}: created {} entities",
-                chunk_idx + 1,
-                (total_ops + BATCH_CHUNK_SIZE - 1) / BATCH_CHUNK_SIZE,
-                chunk_results.len());
-
-            all_results.extend(chunk_results);
-        }
-    } else {
-        // Single batch execution
-        log::debug!("Executing single batch with {} operations", total_ops);
-        all_results = operations.execute(&client, &resilience).await
-            .map_err(|e| build_error(e.to_string(), phase.clone(), step, created_ids))?;
-        log::info!("Created {} entities in single batch", all_results.len());
-    }
+    // 2. Execute operations with automatic chunking
+    let all_results = execute_operations(ctx, &operations, &phase, step, created_ids).await?;
 
-    // 4. Validate result count (common scaffolding)
+    // 3. Validate result count (common scaffolding)
     if all_results.len() != expected_count {
         log::error!("Result count mismatch for {}: expected {}, got {}", phase.name(), expected_count, all_results.len());
         return Err(build_error(
@@ -115,9 +71,99 @@ where
     Ok((all_results, entity_info))
 }
 
+/// Send operations to the current environment, chunked into batches of `BATCH_CHUNK_SIZE`
+///
+/// In a dry run nothing is sent: each operation is logged and answered with a
+/// successful result carrying a placeholder ID, so later steps can still remap.
+pub async fn execute_operations(
+    ctx: ExecutionContext,
+    operations: &Operations,
+    phase: &CopyPhase,
+    step: usize,
+    created_ids: &[(String, String)],
+) -> Result<Vec<OperationResult>, CopyError> {
+    let all_operations = operations.operations();
+    let total_ops = all_operations.len();
+
+    if ctx.dry_run {
+        log::info!("[dry run] Step {}: would send {} operations for {}", step, total_ops, phase.name());
+        return Ok(all_operations.iter()
+            .enumerate()
+            .map(|(idx, operation)| dry_run_result(step, idx, operation))
+            .collect());
+    }
+
+    let client_manager = crate::client_manager();
+    let env_name = client_manager.get_current_environment_name().await
+        .map_err(|e| build_error(e.to_string(), phase.clone(), step, created_ids))?
+        .ok_or_else(|| build_error("No environment selected".to_string(), phase.clone(), step, created_ids))?;
+
+    let client = client_manager.get_client(&env_name).await
+        .map_err(|e| build_error(e.to_string(), phase.clone(), step, created_ids))?;
+
+    let resilience = ResilienceConfig::default();
+
+    // Single batch execution
+    if total_ops <= BATCH_CHUNK_SIZE {
+        log::debug!("Executing single batch with {} operations", total_ops);
+        let results = operations.execute(&client, &resilience).await
+            .map_err(|e| build_error(e.to_string(), phase.clone(), step, created_ids))?;
+        log::info!("Executed {} operations in single batch", results.len());
+        return Ok(results);
+    }
+
+    log::info!("Chunking {} operations into batches of {} for {}",
+        total_ops, BATCH_CHUNK_SIZE, phase.name());
+
+    let chunk_count = total_ops.div_ceil(BATCH_CHUNK_SIZE);
+    let mut all_results = Vec::with_capacity(total_ops);
+
+    for (chunk_idx, chunk) in all_operations.chunks(BATCH_CHUNK_SIZE).enumerate() {
+        let chunk_ops = Operations::from_operations(chunk.to_vec());
+
+        log::debug!("Executing chunk {}/{} ({} operations) for {}",
+            chunk_idx + 1, chunk_count, chunk.len(), phase.name());
+
+        let chunk_results = chunk_ops.execute(&client, &resilience).await
+            .map_err(|e| build_error(
+                format!("Failed to execute chunk {}: {}", chunk_idx + 1, e),
+                phase.clone(),
+                step,
+                created_ids
+            ))?;
+
+        log::info!("Completed chunk {}/{}: {} operations", chunk_idx + 1, chunk_count, chunk_results.len());
+
+        all_results.extend(chunk_results);
+    }
+
+    Ok(all_results)
+}
+
+/// Log an operation instead of sending it and pretend it succeeded
+fn dry_run_result(step: usize, idx: usize, operation: &Operation) -> OperationResult {
+    log::info!("[dry run] Step {} #{}: {}",
+        step, idx + 1, serde_json::to_string(operation).unwrap_or_default());
+
+    // Creates answer with an entity ID header, like the real response
+    let mut headers = HashMap::new();
+    if let Operation::Create { entity, .. } | Operation::CreateWithRefs { entity, .. } = operation {
+        headers.insert("OData-EntityId".to_string(), format!("/{}(dry-run-{}-{})", entity, step, idx + 1));
+    }
+
+    OperationResult {
+        operation: operation.clone(),
+        success: true,
+        data: None,
+        error: None,
+        status_code: Some(204),
+        headers,
+    }
+}
+
 /// Process results from creation operations - extracts IDs and handles errors
 pub fn process_creation_results(
-    results: &[crate::api::operations::OperationResult],
+    results: &[OperationResult],
     entity_info: Vec<EntityInfo>,
     id_map: &mut HashMap<String, String>,
     created_ids: &mut Vec<(String, String)>,
@@ -172,8 +218,32 @@ pub fn process_creation_results(
     // If any errors occurred, return error with ALL successful IDs tracked
     if let Some(error_msg) = first_error {
         log::error!("Step {} failed with error: {}", step, error_msg);
-        return Err(build_error(error_msg, phase, step, created_ids));
+        let mut error = build_error(error_msg, phase, step, created_ids);
+        error.failed_items = results
+            .iter()
+            .enumerate()
+            .filter(|(_, result)| !result.success)
+            .map(|(idx, result)| (idx, result.clone()))
+            .collect();
+        return Err(error);
     }
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_dry_run_result_yields_placeholder_id_for_creates_only() {
+        let create = Operation::create("nrq_questionnairepages", json!({"nrq_name": "Page 1"}));
+        let result = dry_run_result(2, 0, &create);
+        assert!(result.success);
+        assert_eq!(extract_entity_id(&result).unwrap(), "dry-run-2-1");
+
+        let delete = Operation::Delete { entity: "nrq_questionnairepages".to_string(), id: "abc".to_string() };
+        assert!(extract_entity_id(&dry_run_result(2, 1, &delete)).is_err());
+    }
+}
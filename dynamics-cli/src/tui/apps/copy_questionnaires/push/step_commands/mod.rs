@@ -8,14 +8,16 @@
 ///
 /// - `helpers` - Shared utility functions for data transformation
 /// - `error` - Error construction and handling
-/// - `execution` - Generic execution logic with automatic batching
+/// - `execution` - Generic execution logic with automatic batching and dry runs
 /// - `rollback` - Rollback operations for cleanup
+/// - `resume` - Continuing a failed copy from its last checkpoint
 /// - `steps` - Individual step implementations (step1-step11)
 
 mod helpers;
 mod error;
 mod execution;
 mod rollback;
+mod resume;
 mod steps;
 
 pub mod entity_sets;
@@ -37,6 +39,8 @@ pub use steps::{
 };
 
 pub use rollback::rollback_created_entities;
+pub use resume::prepare_resume;
+pub use execution::ExecutionContext;
 
 // Re-export helper for use in app.rs
 pub use helpers::entity_set_to_friendly_name;
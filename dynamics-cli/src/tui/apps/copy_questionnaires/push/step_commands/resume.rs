@@ -0,0 +1,102 @@
+/// Resuming a failed copy from its last checkpoint
+
+use super::rollback::rollback_created_entities;
+use crate::config::repository::copy_checkpoints::CopyCheckpoint;
+use std::collections::{BTreeMap, HashSet};
+
+/// IDs checked per existence query, keeping the filter URL short
+const VERIFY_CHUNK_SIZE: usize = 50;
+
+/// Load the checkpoint for a questionnaire and get the environment ready to continue from it
+///
+/// Records the failed step created before it stopped are deleted first, since that
+/// step runs again in full. Every checkpointed record is then checked to still exist.
+pub async fn prepare_resume(questionnaire_id: String) -> Result<CopyCheckpoint, String> {
+    let config = crate::global_config();
+    let mut checkpoint = config.get_copy_checkpoint(&questionnaire_id).await
+        .map_err(|e| format!("Failed to load checkpoint: {}", e))?
+        .ok_or_else(|| "No checkpoint saved for this questionnaire".to_string())?;
+
+    if !checkpoint.partial_ids.is_empty() {
+        log::info!("Resume: deleting {} records created by the failed step", checkpoint.partial_ids.len());
+        rollback_created_entities(std::mem::take(&mut checkpoint.partial_ids)).await
+            .map_err(|csv_path| format!("Failed to delete records from the failed step (exported to {})", csv_path))?;
+        config.save_copy_checkpoint(&checkpoint).await
+            .map_err(|e| format!("Failed to save checkpoint: {}", e))?;
+    }
+
+    verify_created_entities(&checkpoint.created_ids).await?;
+
+    log::info!("Resuming copy of {} after step {} ({} records verified)",
+        questionnaire_id, checkpoint.completed_step, checkpoint.created_ids.len());
+    Ok(checkpoint)
+}
+
+/// Check that every created record still exists in the current environment
+async fn verify_created_entities(created_ids: &[(String, String)]) -> Result<(), String> {
+    let client_manager = crate::client_manager();
+    let env_name = client_manager.get_current_environment_name().await
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| "No environment selected".to_string())?;
+    let client = client_manager.get_client(&env_name).await
+        .map_err(|e| e.to_string())?;
+
+    let mut by_entity_set: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+    for (entity_set, id) in created_ids {
+        by_entity_set.entry(entity_set.as_str()).or_default().push(id.as_str());
+    }
+
+    let mut missing = 0;
+    for (entity_set, ids) in by_entity_set {
+        let key = primary_key(entity_set);
+        for chunk in ids.chunks(VERIFY_CHUNK_SIZE) {
+            let response = client.execute_raw("GET", &existence_query(entity_set, chunk), None).await
+                .map_err(|e| format!("Failed to verify {}: {}", entity_set, e))?;
+
+            let found: HashSet<String> = response["value"].as_array()
+                .into_iter()
+                .flatten()
+                .filter_map(|record| record[key.as_str()].as_str())
+                .map(str::to_lowercase)
+                .collect();
+            missing += chunk.iter().filter(|id| !found.contains(&id.to_lowercase())).count();
+        }
+    }
+
+    if missing > 0 {
+        return Err(format!("{} checkpointed records no longer exist - roll back instead", missing));
+    }
+    Ok(())
+}
+
+/// Primary key of a copied entity set (`nrq_questionnairepages` -> `nrq_questionnairepageid`)
+fn primary_key(entity_set: &str) -> String {
+    format!("{}id", entity_set.strip_suffix('s').unwrap_or(entity_set))
+}
+
+/// Query returning only the primary keys of the given records that exist
+fn existence_query(entity_set: &str, ids: &[&str]) -> String {
+    let key = primary_key(entity_set);
+    let values = ids.iter()
+        .map(|id| format!("'{}'", id))
+        .collect::<Vec<_>>()
+        .join(",");
+    format!("{}?$select={}&$filter=Microsoft.Dynamics.CRM.In(PropertyName='{}',PropertyValues=[{}])",
+        entity_set, key, key, values)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::entity_sets;
+
+    #[test]
+    fn test_existence_query_filters_on_primary_key() {
+        assert_eq!(primary_key(entity_sets::CONDITION_ACTIONS), "nrq_questionconditionactionid");
+        assert_eq!(
+            existence_query(entity_sets::PAGES, &["a", "b"]),
+            "nrq_questionnairepages?$select=nrq_questionnairepageid\
+             &$filter=Microsoft.Dynamics.CRM.In(PropertyName='nrq_questionnairepageid',PropertyValues=['a','b'])"
+        );
+    }
+}
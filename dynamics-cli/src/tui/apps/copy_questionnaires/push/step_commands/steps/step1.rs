@@ -5,8 +5,8 @@ use super::super::field_specs;
 use super::super::super::super::copy::domain::Questionnaire;
 use super::super::super::models::{CopyError, CopyPhase};
 use super::super::error::build_error;
+use super::super::execution::{execute_operations, ExecutionContext};
 use super::super::helpers::{extract_entity_id, build_payload, get_shared_entities};
-use crate::api::{ResilienceConfig};
 use crate::api::operations::Operations;
 use serde_json::{json, Value};
 use std::sync::Arc;
@@ -16,21 +16,11 @@ pub async fn step1_create_questionnaire(
     questionnaire: Arc<Questionnaire>,
     copy_name: String,
     copy_code: String,
+    ctx: ExecutionContext,
 ) -> Result<(String, Vec<(String, String)>), CopyError> {
     log::info!("Step 1/11: Starting Creating Questionnaire (expecting 1 entity)");
     log::debug!("Copy name: '{}', copy code: '{}', source ID: {}", copy_name, copy_code, questionnaire.id);
 
-    let client_manager = crate::client_manager();
-
-    let env_name = client_manager.get_current_environment_name().await
-        .map_err(|e| build_error(e.to_string(), CopyPhase::CreatingQuestionnaire, 1, &[]))?
-        .ok_or_else(|| build_error("No environment selected".to_string(), CopyPhase::CreatingQuestionnaire, 1, &[]))?;
-
-    let client = client_manager.get_client(&env_name).await
-        .map_err(|e| build_error(e.to_string(), CopyPhase::CreatingQuestionnaire, 1, &[]))?;
-
-    let resilience = ResilienceConfig::default();
-
     log::debug!("Preparing questionnaire data");
     let shared_entities = get_shared_entities();
     let id_map = HashMap::new(); // Step 1 has no remapping yet
@@ -44,8 +34,7 @@ pub async fn step1_create_questionnaire(
 
     log::debug!("Executing questionnaire creation");
     let operations = Operations::new().create(entity_sets::QUESTIONNAIRES, data);
-    let results = operations.execute(&client, &resilience).await
-        .map_err(|e| build_error(e.to_string(), CopyPhase::CreatingQuestionnaire, 1, &[]))?;
+    let results = execute_operations(ctx, &operations, &CopyPhase::CreatingQuestionnaire, 1, &[]).await?;
 
     if !results[0].success {
         let error_msg = results[0].error.clone().unwrap_or_else(|| "Unknown error".to_string());
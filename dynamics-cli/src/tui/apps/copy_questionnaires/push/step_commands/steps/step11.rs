@@ -4,8 +4,7 @@ use super::super::entity_sets;
 use super::super::super::super::copy::domain::Questionnaire;
 use super::super::super::models::{CopyError, CopyPhase};
 use super::super::error::build_error;
-use super::super::execution::BATCH_CHUNK_SIZE;
-use crate::api::{ResilienceConfig};
+use super::super::execution::{execute_operations, ExecutionContext};
 use crate::api::operations::Operations;
 use serde_json::json;
 use std::collections::HashMap;
@@ -15,6 +14,7 @@ pub async fn step11_publish_conditions(
     questionnaire: Arc<Questionnaire>,
     id_map: HashMap<String, String>,
     created_ids: Vec<(String, String)>,
+    ctx: ExecutionContext,
 ) -> Result<(HashMap<String, String>, Vec<(String, String)>), CopyError> {
     if questionnaire.conditions.is_empty() {
         return Ok((id_map, created_ids));
@@ -53,46 +53,8 @@ pub async fn step11_publish_conditions(
         );
     }
 
-    let client_manager = crate::client_manager();
-    let env_name = client_manager.get_current_environment_name().await
-        .map_err(|e| build_error(e.to_string(), CopyPhase::PublishingConditions, 11, &created_ids))?
-        .ok_or_else(|| build_error("No environment selected".to_string(), CopyPhase::PublishingConditions, 11, &created_ids))?;
-
-    let client = client_manager.get_client(&env_name).await
-        .map_err(|e| build_error(e.to_string(), CopyPhase::PublishingConditions, 11, &created_ids))?;
-
-    let resilience = ResilienceConfig::default();
-
-    // Execute with automatic chunking (same pattern as classifications)
-    let all_operations = operations.operations();
-    let mut results = Vec::with_capacity(conditions_count);
-
-    if conditions_count > BATCH_CHUNK_SIZE {
-        log::info!("Chunking {} condition updates into batches of {}",
-            conditions_count, BATCH_CHUNK_SIZE);
-
-        for (chunk_idx, chunk) in all_operations.chunks(BATCH_CHUNK_SIZE).enumerate() {
-            let chunk_ops = Operations::from_operations(chunk.to_vec());
-
-            log::debug!("Executing condition publish chunk {}/{} ({} operations)",
-                chunk_idx + 1,
-                (conditions_count + BATCH_CHUNK_SIZE - 1) / BATCH_CHUNK_SIZE,
-                chunk.len());
-
-            let chunk_results = chunk_ops.execute(&client, &resilience).await
-                .map_err(|e| build_error(
-                    format!("Failed to execute condition publish chunk {}: {}", chunk_idx + 1, e),
-                    CopyPhase::PublishingConditions,
-                    11,
-                    &created_ids
-                ))?;
-
-            results.extend(chunk_results);
-        }
-    } else {
-        results = operations.execute(&client, &resilience).await
-            .map_err(|e| build_error(e.to_string(), CopyPhase::PublishingConditions, 11, &created_ids))?;
-    }
+    // Execute with automatic chunking (same as other steps)
+    let results = execute_operations(ctx, &operations, &CopyPhase::PublishingConditions, 11, &created_ids).await?;
 
     // Validate result count matches expected count
     if results.len() != conditions_count {
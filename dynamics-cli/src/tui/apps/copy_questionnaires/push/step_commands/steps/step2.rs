@@ -4,7 +4,7 @@ use super::super::field_specs;
 
 use super::super::super::super::copy::domain::Questionnaire;
 use super::super::super::models::{CopyError, CopyPhase};
-use super::super::execution::{execute_creation_step, process_creation_results, EntityInfo};
+use super::super::execution::{execute_creation_step, process_creation_results, EntityInfo, ExecutionContext};
 use super::super::helpers::{get_shared_entities, build_payload};
 use crate::api::operations::Operations;
 use serde_json::json;
@@ -15,6 +15,7 @@ pub async fn step2_create_pages(
     questionnaire: Arc<Questionnaire>,
     id_map: HashMap<String, String>,
     mut created_ids: Vec<(String, String)>,
+    ctx: ExecutionContext,
 ) -> Result<(HashMap<String, String>, Vec<(String, String)>), CopyError> {
     if questionnaire.pages.is_empty() {
         return Ok((id_map, created_ids));
@@ -25,6 +26,7 @@ pub async fn step2_create_pages(
 
     // Execute creation using generic helper
     let (results, entity_info) = execute_creation_step(
+        ctx,
         Arc::clone(&questionnaire),
         id_map,
         &mut created_ids,
@@ -4,7 +4,7 @@ use super::super::field_specs;
 
 use super::super::super::super::copy::domain::Questionnaire;
 use super::super::super::models::{CopyError, CopyPhase};
-use super::super::execution::{execute_creation_step, process_creation_results, EntityInfo};
+use super::super::execution::{execute_creation_step, process_creation_results, EntityInfo, ExecutionContext};
 use super::super::helpers::{get_shared_entities, build_payload};
 use crate::api::operations::Operations;
 use std::collections::HashMap;
@@ -14,6 +14,7 @@ pub async fn step4_create_groups(
     questionnaire: Arc<Questionnaire>,
     id_map: HashMap<String, String>,
     mut created_ids: Vec<(String, String)>,
+    ctx: ExecutionContext,
 ) -> Result<(HashMap<String, String>, Vec<(String, String)>), CopyError> {
     // Count total groups across all pages
     let groups_count: usize = questionnaire.pages.iter().map(|p| p.groups.len()).sum();
@@ -24,6 +25,7 @@ pub async fn step4_create_groups(
     let mut new_id_map = id_map.clone();
 
     let (results, entity_info) = execute_creation_step(
+        ctx,
         Arc::clone(&questionnaire),
         id_map,
         &mut created_ids,
@@ -4,7 +4,7 @@ use super::super::field_specs;
 
 use super::super::super::super::copy::domain::Questionnaire;
 use super::super::super::models::{CopyError, CopyPhase};
-use super::super::execution::{execute_creation_step, process_creation_results, EntityInfo};
+use super::super::execution::{execute_creation_step, process_creation_results, EntityInfo, ExecutionContext};
 use super::super::helpers::{get_shared_entities, build_payload};
 use crate::api::operations::Operations;
 use std::collections::HashMap;
@@ -14,6 +14,7 @@ pub async fn step5_create_group_lines(
     questionnaire: Arc<Questionnaire>,
     id_map: HashMap<String, String>,
     mut created_ids: Vec<(String, String)>,
+    ctx: ExecutionContext,
 ) -> Result<(HashMap<String, String>, Vec<(String, String)>), CopyError> {
     if questionnaire.group_lines.is_empty() {
         return Ok((id_map, created_ids));
@@ -23,6 +24,7 @@ pub async fn step5_create_group_lines(
     let mut new_id_map = id_map.clone();
 
     let (results, entity_info) = execute_creation_step(
+        ctx,
         Arc::clone(&questionnaire),
         id_map,
         &mut created_ids,
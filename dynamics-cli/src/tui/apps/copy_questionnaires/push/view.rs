@@ -1,4 +1,4 @@
-use super::models::{State, PushState, CopyProgress, CopyResult, CopyError, CopyPhase, EntityType};
+use super::models::{State, PushState, CopyProgress, CopyResult, CopyError, CopyPhase, DryRunReport, EntityType};
 use crate::tui::{Element, renderer::LayeredView, LayoutConstraint};
 use crate::{button_row, col, spacer, use_constraints};
 use ratatui::{
@@ -15,6 +15,7 @@ pub fn render_view(state: &State) -> LayeredView<super::models::Msg> {
         PushState::Copying(progress) => render_progress_screen(state, progress, theme),
         PushState::Success(result) => render_success_screen(state, result, theme),
         PushState::Failed(error) => render_failure_screen(state, error, theme),
+        PushState::Previewed(report) => render_preview_screen(state, report, theme),
     };
 
     let panel = Element::panel(content)
@@ -29,6 +30,11 @@ pub fn render_view(state: &State) -> LayeredView<super::models::Msg> {
         view = view.with_app_modal(modal, crate::tui::Alignment::Center);
     }
 
+    if let Some((write, env_name)) = state.protected_write_modal.data() {
+        let modal = render_protected_write_modal(*write, env_name);
+        view = view.with_app_modal(modal, crate::tui::Alignment::Center);
+    }
+
     view
 }
 
@@ -150,11 +156,30 @@ fn render_confirmation_screen(
             Element::styled_text(Line::from(vec![
                 Span::styled("  → Classifications (categories, domains, funds, etc.)", Style::default().fg(theme.text_tertiary)),
             ])).build(),
+
+            spacer!(),
+
+            match (&state.saved_checkpoint, state.resuming) {
+                (_, true) => Element::styled_text(Line::from(vec![
+                    Span::styled("⏳ Resuming: ", Style::default().fg(theme.accent_info)),
+                    Span::styled("Checking the records from the last checkpoint...", Style::default().fg(theme.text_primary)),
+                ])).build(),
+                (Some(checkpoint), false) => Element::styled_text(Line::from(vec![
+                    Span::styled("⚠ Previous copy ", Style::default().fg(theme.accent_warning)),
+                    Span::styled(checkpoint.copy_name.clone(), Style::default().fg(theme.text_primary).bold()),
+                    Span::styled(
+                        format!(" stopped after step {}/11 - press r to resume it", checkpoint.completed_step),
+                        Style::default().fg(theme.text_primary),
+                    ),
+                ])).build(),
+                (None, false) => spacer!(),
+            },
         ]).build() => Fill(1),
 
-        Element::button("start_copy_button", "Start Copy")
-            .on_press(super::Msg::StartCopy)
-            .build() => Length(3),
+        button_row![
+            ("preview_button", "Preview (p)", super::Msg::StartPreview),
+            ("start_copy_button", "Start Copy", super::Msg::StartCopy),
+        ] => Length(3),
     ]
 }
 
@@ -344,6 +369,103 @@ fn render_success_screen(
     ]
 }
 
+/// Screen 3c: Dry run report - what each step would send
+fn render_preview_screen(
+    state: &State,
+    report: &DryRunReport,
+    theme: &crate::tui::Theme,
+) -> Element<super::models::Msg> {
+    use_constraints!();
+
+    let mut lines = vec![
+        Element::styled_text(Line::from(vec![
+            Span::styled("✓ ", Style::default().fg(theme.accent_info).bold()),
+            Span::styled("Dry Run Complete - nothing was sent", Style::default().fg(theme.accent_info).bold()),
+        ])).build(),
+
+        spacer!(),
+
+        Element::styled_text(Line::from(vec![
+            Span::styled("Copy Name: ", Style::default().fg(theme.text_secondary)),
+            Span::styled(state.copy_name.clone(), Style::default().fg(theme.text_primary).bold()),
+        ])).build(),
+
+        spacer!(),
+
+        Element::styled_text(Line::from(vec![
+            Span::styled("Operations per step:", Style::default().fg(theme.text_primary).bold()),
+        ])).build(),
+
+        spacer!(),
+    ];
+
+    for (phase, count) in &report.steps {
+        let color = if *count > 0 { theme.text_primary } else { theme.text_tertiary };
+        lines.push(Element::styled_text(Line::from(vec![
+            Span::styled(format!("  {:>2}. ", phase.step_number()), Style::default().fg(theme.text_secondary)),
+            Span::styled(format!("{:<30}", phase.name()), Style::default().fg(color)),
+            Span::styled(format!("{:>6}", count), Style::default().fg(color).bold()),
+        ])).build());
+    }
+
+    lines.push(spacer!());
+    lines.push(Element::styled_text(Line::from(vec![
+        Span::styled("Total: ", Style::default().fg(theme.text_secondary)),
+        Span::styled(format!("{} entities would be created", report.total_entities), Style::default().fg(theme.accent_info).bold()),
+    ])).build());
+    lines.push(Element::styled_text(Line::from(vec![
+        Span::styled("Payloads for every operation are written to the log", Style::default().fg(theme.text_tertiary).italic()),
+    ])).build());
+
+    col![
+        Element::column(lines).build() => Fill(1),
+
+        button_row![
+            ("preview_back_button", "Back", super::Msg::ClosePreview),
+            ("start_copy_button", "Start Copy", super::Msg::StartCopy),
+        ] => Length(3),
+    ]
+}
+
+/// Maximum number of failed batch items listed on the failure screen
+const MAX_FAILED_ITEMS_SHOWN: usize = 10;
+
+/// Per-item results of the batch that failed, so the user sees which records were rejected
+fn render_failed_items(error: &CopyError, theme: &crate::tui::Theme) -> Element<super::models::Msg> {
+    use crate::tui::apps::queue::views::build_operation_results_table;
+
+    if error.failed_items.is_empty() {
+        return Element::None;
+    }
+
+    let mut lines = vec![
+        Element::styled_text(Line::from(vec![
+            Span::styled(
+                format!("Failed Items ({}):", error.failed_items.len()),
+                Style::default().fg(theme.text_primary).bold()
+            ),
+        ])).build(),
+    ];
+
+    lines.extend(build_operation_results_table(
+        error.failed_items.iter().take(MAX_FAILED_ITEMS_SHOWN).map(|(idx, result)| (*idx, result)),
+        theme,
+    ));
+
+    if error.failed_items.len() > MAX_FAILED_ITEMS_SHOWN {
+        lines.push(Element::styled_text(Line::from(vec![
+            Span::styled(
+                format!("  ... and {} more", error.failed_items.len() - MAX_FAILED_ITEMS_SHOWN),
+                Style::default().fg(theme.text_secondary).italic()
+            ),
+        ])).build());
+    }
+
+    lines.push(spacer!());
+
+    Element::column(lines).spacing(0).build()
+}
+
 /// Screen 3b: Failure - show error and partial progress
 fn render_failure_screen(
     state: &State,
@@ -356,7 +478,7 @@ fn render_failure_screen(
         Element::column(vec![
             Element::styled_text(Line::from(vec![
                 Span::styled("✗ ", Style::default().fg(theme.accent_error).bold()),
-                Span::styled("Copy Failed", Style::default().fg(theme.accent_error).bold()),
+                Span::styled(if state.dry_run { "Preview Failed" } else { "Copy Failed" }, Style::default().fg(theme.accent_error).bold()),
             ])).build(),
 
             spacer!(),
@@ -380,6 +502,8 @@ fn render_failure_screen(
 
             spacer!(),
 
+            render_failed_items(error, theme),
+
             Element::styled_text(Line::from(vec![
                 Span::styled("Partial Progress (before failure):", Style::default().fg(theme.text_primary).bold()),
             ])).build(),
@@ -414,7 +538,13 @@ fn render_failure_screen(
 
             spacer!(),
 
-            if error.rollback_complete {
+            if state.dry_run {
+                // Dry run sent nothing
+                Element::styled_text(Line::from(vec![
+                    Span::styled("Dry run: ", Style::default().fg(theme.text_secondary)),
+                    Span::styled("Nothing was created, no rollback needed", Style::default().fg(theme.text_primary)),
+                ])).build()
+            } else if error.rollback_complete {
                 // Rollback succeeded
                 Element::styled_text(Line::from(vec![
                     Span::styled("✓ Rollback: ", Style::default().fg(theme.accent_success)),
@@ -463,18 +593,39 @@ fn render_failure_screen(
                         Span::styled("  3. Or delete manually via Advanced Find", Style::default().fg(theme.text_secondary)),
                     ])).build(),
                 ]).build()
-            } else {
+            } else if state.rolling_back {
                 // Rollback in progress
                 Element::styled_text(Line::from(vec![
                     Span::styled("⏳ Rollback in progress: ", Style::default().fg(theme.accent_warning)),
                     Span::styled("Deleting partially created entities...", Style::default().fg(theme.text_primary)),
                 ])).build()
+            } else if state.resuming {
+                Element::styled_text(Line::from(vec![
+                    Span::styled("⏳ Resuming: ", Style::default().fg(theme.accent_info)),
+                    Span::styled("Checking the records from the last checkpoint...", Style::default().fg(theme.text_primary)),
+                ])).build()
+            } else {
+                // Records kept - the user picks resume or rollback
+                Element::styled_text(Line::from(vec![
+                    Span::styled(format!("Kept {} created entities. ", state.created_ids.len()), Style::default().fg(theme.accent_warning)),
+                    Span::styled(
+                        format!("Resume to run step {} again, or roll back to delete them", error.step),
+                        Style::default().fg(theme.text_primary),
+                    ),
+                ])).build()
             },
         ]).build() => Fill(1),
 
-        Element::button("retry_button", "Retry")
-            .on_press(super::Msg::Retry)
-            .build() => Length(3),
+        if state.can_resume() {
+            button_row![
+                ("resume_button", "Resume (r)", super::Msg::Resume),
+                ("rollback_button", "Roll Back (b)", super::Msg::Rollback),
+            ]
+        } else {
+            Element::button("retry_button", "Retry")
+                .on_press(super::Msg::Retry)
+                .build()
+        } => Length(3),
     ]
 }
 
@@ -493,7 +644,8 @@ pub fn render_status(state: &State) -> Option<Line<'static>> {
         PushState::Copying(progress) => {
             Some(Line::from(vec![
                 Span::styled(
-                    format!("Copying... {}% ({}/{})",
+                    format!("{}... {}% ({}/{})",
+                        if state.dry_run { "Previewing" } else { "Copying" },
                         progress.percentage(),
                         progress.total_created,
                         progress.total_entities
@@ -513,14 +665,36 @@ pub fn render_status(state: &State) -> Option<Line<'static>> {
         PushState::Failed(_) => {
             Some(Line::from(vec![
                 Span::styled(
-                    "✗ Copy failed",
+                    if state.dry_run { "✗ Preview failed" } else { "✗ Copy failed" },
                     Style::default().fg(theme.accent_error),
                 ),
             ]))
         }
+        PushState::Previewed(report) => {
+            Some(Line::from(vec![
+                Span::styled(
+                    format!("Preview: {} entities would be created", report.total_entities),
+                    Style::default().fg(theme.accent_info),
+                ),
+            ]))
+        }
     }
 }
 
+/// Render the confirmation for a write to a protected environment
+fn render_protected_write_modal(write: super::models::GuardedWrite, env_name: &str) -> Element<super::models::Msg> {
+    use crate::tui::modals::ConfirmationModal;
+
+    ConfirmationModal::new("Protected Environment")
+        .message(format!("'{}' is a protected environment.\n{}?", env_name, write.description()))
+        .confirm_text("Write (y)")
+        .cancel_text("Cancel (n)")
+        .on_confirm(super::models::Msg::ConfirmProtectedWrite)
+        .on_cancel(super::models::Msg::CancelProtectedWrite)
+        .width(60)
+        .build()
+}
+
 /// Render undo confirmation modal
 fn render_undo_confirmation_modal(theme: &crate::tui::Theme) -> Element<super::models::Msg> {
     let content = Element::column(vec![
@@ -1,5 +1,6 @@
 use crossterm::event::KeyCode;
 use std::collections::HashMap;
+use std::sync::Arc;
 use crate::tui::{App, AppId, Command, Element, Subscription, Theme, LayeredView, FocusId};
 use crate::tui::element::LayoutConstraint::*;
 use crate::tui::widgets::list::{ListItem, ListState};
@@ -77,6 +78,8 @@ pub struct State {
     total_deadlines_queued: usize,
     /// Accumulated associations from completed deadline creations (deadline_guid -> operations)
     pending_associations: HashMap<String, Vec<crate::api::operations::Operation>>,
+    /// Configured entity set name overrides, loaded with the environment when queueing
+    entity_sets: Arc<HashMap<String, String>>,
 }
 
 impl State {
@@ -96,6 +99,7 @@ impl State {
             queued_items: HashMap::new(),
             total_deadlines_queued: 0,
             pending_associations: HashMap::new(),
+            entity_sets: Arc::default(),
         }
     }
 }
@@ -113,7 +117,7 @@ pub enum Msg {
     SetViewportHeight(usize),
     Back,
     AddToQueueAndView,
-    EnvironmentLoaded(Result<String, String>),
+    EnvironmentLoaded(Result<(String, Arc<HashMap<String, String>>), String>),
     QueueItemCompleted(String, crate::tui::apps::queue::models::QueueResult, crate::tui::apps::queue::models::QueueMetadata),
 }
 
@@ -168,14 +172,17 @@ impl App for DeadlinesInspectionApp {
                 Command::perform(
                     async {
                         let manager = crate::client_manager();
-                        manager.get_current_environment_name().await
+                        let environment_name = manager.get_current_environment_name().await
                             .map_err(|e| e.to_string())?
-                            .ok_or_else(|| "No environment selected".to_string())
+                            .ok_or_else(|| "No environment selected".to_string())?;
+                        Ok((environment_name, manager.entity_set_overrides().await))
                     },
                     Msg::EnvironmentLoaded
                 )
             }
-            Msg::EnvironmentLoaded(Ok(environment_name)) => {
+            Msg::EnvironmentLoaded(Ok((environment_name, entity_sets))) => {
+                state.entity_sets = entity_sets;
+
                 // Collect all valid records
                 let mut valid_records: Vec<&TransformedDeadline> = state.transformed_records.iter()
                     .filter(|record| !record.has_warnings())
@@ -193,6 +200,7 @@ impl App for DeadlinesInspectionApp {
                 let queue_items = batch_deadline_creates(
                     &valid_records,
                     &state.entity_type,
+                    &state.entity_sets,
                     &environment_name,
                     &mut state.queued_items,
                     50
@@ -258,6 +266,7 @@ impl App for DeadlinesInspectionApp {
                         let association_ops = build_association_operations(
                             &created_guid,
                             &state.entity_type,
+                            &state.entity_sets,
                             &record.checkbox_relationships
                         );
 
@@ -607,6 +616,7 @@ fn extract_entity_guid_from_result(result: &crate::api::operations::OperationRes
 fn batch_deadline_creates(
     records: &[&TransformedDeadline],
     entity_type: &str,
+    entity_sets: &HashMap<String, String>,
     environment_name: &str,
     queued_items: &mut HashMap<String, Vec<TransformedDeadline>>,
     max_per_batch: usize,
@@ -620,7 +630,7 @@ fn batch_deadline_creates(
 
     for record in records {
         // Add to current batch
-        let operations_vec = record.to_operations(entity_type);
+        let operations_vec = record.to_operations(entity_type, entity_sets);
         current_batch_ops.push(operations_vec[0].clone()); // Each deadline is 1 Create operation
         current_batch_records.push((*record).clone());
 
@@ -633,6 +643,7 @@ fn batch_deadline_creates(
                 description: format!("Deadline batch {} ({} deadlines)", batch_num, current_batch_ops.len()),
                 row_number: None,
                 environment_name: environment_name.to_string(),
+                retry_of: None,
             };
             let priority = 64; // High priority for deadline creates
             let queue_item = QueueItem::new(operations, metadata, priority);
@@ -658,6 +669,7 @@ fn batch_deadline_creates(
             description: format!("Deadline batch {} ({} deadlines)", batch_num, current_batch_ops.len()),
             row_number: None,
             environment_name: environment_name.to_string(),
+            retry_of: None,
         };
         let priority = 64; // High priority for deadline creates
         let queue_item = QueueItem::new(operations, metadata, priority);
@@ -675,14 +687,15 @@ fn batch_deadline_creates(
 fn build_association_operations(
     entity_guid: &str,
     entity_type: &str,
+    entity_sets: &HashMap<String, String>,
     checkbox_relationships: &HashMap<String, Vec<String>>,
 ) -> Vec<crate::api::operations::Operation> {
     use crate::api::operations::Operation;
-    use crate::api::pluralization::pluralize_entity_name;
+    use crate::api::pluralization::resolve_entity_set_name;
     use super::operation_builder::{get_junction_entity_name, extract_related_entity_from_relationship};
 
     let mut operations = Vec::new();
-    let entity_set = pluralize_entity_name(entity_type);
+    let entity_set = resolve_entity_set_name(entity_type, entity_sets);
 
     for (relationship_name, related_ids) in checkbox_relationships {
         if related_ids.is_empty() {
@@ -691,7 +704,7 @@ fn build_association_operations(
 
         let junction_entity = get_junction_entity_name(entity_type, relationship_name);
         let related_entity = extract_related_entity_from_relationship(relationship_name);
-        let related_entity_set = pluralize_entity_name(&related_entity);
+        let related_entity_set = resolve_entity_set_name(&related_entity, entity_sets);
 
         for related_id in related_ids {
             // Relative URI - batch builder will convert to absolute
@@ -737,6 +750,7 @@ fn batch_associations(
                 description: format!("Association batch {} ({} operations)", batch_num, current_batch_count),
                 row_number: None,
                 environment_name: environment_name.to_string(),
+                retry_of: None,
             };
             let priority = 128; // Medium priority for associations
             queue_items.push(QueueItem::new(operations, metadata, priority));
@@ -761,6 +775,7 @@ fn batch_associations(
             description: format!("Association batch {} ({} operations)", batch_num, current_batch_count),
             row_number: None,
             environment_name: environment_name.to_string(),
+            retry_of: None,
         };
         let priority = 128; // Medium priority for associations
         queue_items.push(QueueItem::new(operations, metadata, priority));
@@ -221,8 +221,8 @@ async fn fetch_entity_data(
         .await
         .map_err(|e| e.to_string())?;
 
-    // Pluralize entity name for Web API (entity sets are plural)
-    let plural_name = crate::api::pluralization::pluralize_entity_name(entity_name);
+    // Entity set name for the Web API (configured override or plural)
+    let plural_name = client.entity_set_name(entity_name);
     log::debug!("Fetching {} (plural: {})", entity_name, plural_name);
 
     // Fetch all records for this entity using query builder
@@ -10,7 +10,7 @@
 //! - Proper @odata.bind formatting for lookups
 
 use crate::api::operations::Operation;
-use crate::api::pluralization::pluralize_entity_name;
+use crate::api::pluralization::resolve_entity_set_name;
 use super::models::TransformedDeadline;
 use super::field_mappings::get_constant_fields;
 use serde_json::{json, Value};
@@ -19,10 +19,11 @@ use std::collections::HashMap;
 impl TransformedDeadline {
     /// Convert this TransformedDeadline to a list of Operations ready for batch execution
     ///
-    /// Returns a Vec with a single operation that creates the deadline
-    pub fn to_operations(&self, entity_type: &str) -> Vec<Operation> {
-        let entity_set = pluralize_entity_name(entity_type);
-        let payload = self.build_create_payload(entity_type);
+    /// Returns a Vec with a single operation that creates the deadline. Entity set
+    /// names come from `entity_sets` overrides, falling back to pluralization.
+    pub fn to_operations(&self, entity_type: &str, entity_sets: &HashMap<String, String>) -> Vec<Operation> {
+        let entity_set = resolve_entity_set_name(entity_type, entity_sets);
+        let payload = self.build_create_payload(entity_type, entity_sets);
 
         vec![Operation::Create {
             entity: entity_set,
@@ -31,7 +32,7 @@ impl TransformedDeadline {
     }
 
     /// Build the JSON payload for creating the main deadline entity
-    fn build_create_payload(&self, entity_type: &str) -> Value {
+    fn build_create_payload(&self, entity_type: &str, entity_sets: &HashMap<String, String>) -> Value {
         let mut payload = json!({});
 
         // 0. Constant fields (entity-specific defaults)
@@ -67,7 +68,7 @@ impl TransformedDeadline {
         // 2. Lookup fields (@odata.bind format)
         for (field, (id, target_entity)) in &self.lookup_fields {
             let bind_field = format!("{}@odata.bind", field);
-            let entity_set = pluralize_entity_name(target_entity);
+            let entity_set = resolve_entity_set_name(target_entity, entity_sets);
             payload[bind_field] = json!(format!("/{}({})", entity_set, id));
         }
 
@@ -294,6 +295,26 @@ mod tests {
         assert_eq!(result, "2025-03-15T13:30:00.000Z");
     }
 
+    #[test]
+    fn test_operations_use_entity_set_overrides() {
+        let mut deadline = TransformedDeadline::new(2);
+        deadline.lookup_fields.insert("cgk_pillarid".to_string(), ("p1".to_string(), "cgk_pillar".to_string()));
+        deadline.lookup_fields.insert("cgk_fundid".to_string(), ("f1".to_string(), "cgk_fund".to_string()));
+        let overrides = HashMap::from([
+            ("cgk_deadline".to_string(), "cgk_deadlineset".to_string()),
+            ("cgk_pillar".to_string(), "cgk_pillarset".to_string()),
+        ]);
+
+        let operations = deadline.to_operations("cgk_deadline", &overrides);
+
+        let Operation::Create { entity, data } = &operations[0] else {
+            panic!("Expected a create operation");
+        };
+        assert_eq!(entity, "cgk_deadlineset");
+        assert_eq!(data["cgk_pillarid@odata.bind"], "/cgk_pillarset(p1)");
+        assert_eq!(data["cgk_fundid@odata.bind"], "/cgk_funds(f1)");
+    }
+
     #[test]
     fn test_capitalize() {
         assert_eq!(capitalize_first_letter("support"), "Support");
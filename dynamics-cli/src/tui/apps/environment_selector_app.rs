@@ -1,16 +1,44 @@
 use crossterm::event::KeyCode;
-use crate::tui::{App, AppId, Command, Element, Subscription, Theme, FocusId, Resource};
+use crate::tui::{App, AppId, Command, Element, Subscription, Theme, FocusId, Resource, SpinnerStyle};
 use crate::tui::renderer::LayeredView;
 use crate::tui::widgets::{SelectField, SelectEvent, TextInputField, TextInputEvent};
 use crate::tui::apps::screens::ErrorScreenParams;
 use crate::api::models::{Environment as ApiEnvironment, CredentialSet};
+use crate::api::DeviceCodePrompt;
 use ratatui::text::{Line, Span};
 use ratatui::style::{Style, Stylize};
 use crate::{col, row, spacer, use_constraints};
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
 use_constraints!();
 
 pub struct EnvironmentSelectorApp;
 
+/// How long a health check result is trusted before the environment is checked again
+const HEALTH_TTL: Duration = Duration::from_secs(60);
+
+/// Outcome of the last `WhoAmI` health check for an environment
+#[derive(Clone, Debug, PartialEq)]
+enum EnvHealth {
+    Checking,
+    Healthy(Duration),
+    Unhealthy(String),
+}
+
+#[derive(Clone)]
+struct HealthEntry {
+    health: EnvHealth,
+    checked_at: Instant,
+}
+
+impl HealthEntry {
+    /// Whether the result can still be shown without checking again;
+    /// a check in flight counts as fresh so it isn't started twice
+    fn is_fresh(&self, now: Instant) -> bool {
+        self.health == EnvHealth::Checking || now.duration_since(self.checked_at) < HEALTH_TTL
+    }
+}
+
 // ============================================================================
 // State
 // ============================================================================
@@ -48,14 +76,25 @@ pub struct State {
     cred_save_state: Resource<()>,
     cred_delete_state: Resource<()>,
     set_current_state: Resource<()>,
+    sign_in_state: Resource<()>,
+
+    // Device code sign-in
+    device_code_prompt: Option<DeviceCodePrompt>,
+    spinner_frame: usize,
 
     // UI state
     env_panel_dirty: bool,
     cred_panel_dirty: bool,
 
+    // Environment awaiting a typed delete confirmation, with the text typed so far
+    delete_confirm: Option<(String, TextInputField)>,
+
     // Track recently saved items to auto-select them after reload
     recently_saved_env: Option<String>,
     recently_saved_cred: Option<String>,
+
+    // Health check results by environment name
+    health: HashMap<String, HealthEntry>,
 }
 
 impl State {
@@ -87,12 +126,20 @@ impl State {
             cred_save_state: Resource::NotAsked,
             cred_delete_state: Resource::NotAsked,
             set_current_state: Resource::NotAsked,
+            sign_in_state: Resource::NotAsked,
+
+            device_code_prompt: None,
+            spinner_frame: 0,
 
             env_panel_dirty: false,
             cred_panel_dirty: false,
 
+            delete_confirm: None,
+
             recently_saved_env: None,
             recently_saved_cred: None,
+
+            health: HashMap::new(),
         }
     }
 
@@ -101,6 +148,27 @@ impl State {
             .and_then(|name| self.environments.iter().find(|e| e.name == name))
     }
 
+    /// Start a health check for `name` unless a recent result is cached
+    fn check_health(&mut self, name: &str) -> Command<Msg> {
+        let now = Instant::now();
+        if self.health.get(name).is_some_and(|entry| entry.is_fresh(now)) {
+            return Command::None;
+        }
+
+        self.health.insert(name.to_string(), HealthEntry { health: EnvHealth::Checking, checked_at: now });
+        let name = name.to_string();
+        Command::perform(
+            async move {
+                let result = async {
+                    let client = crate::client_manager().get_client(&name).await?;
+                    client.health_check().await
+                }.await;
+                (name, result.map(|check| check.latency).map_err(|e| e.to_string()))
+            },
+            |(name, result)| Msg::HealthChecked(name, result)
+        )
+    }
+
     fn get_selected_credential_name(&self) -> Option<&str> {
         self.cred_selector.value()
     }
@@ -153,6 +221,9 @@ pub enum Msg {
     SaveEnvironment,
     EnvironmentSaved(Result<(), String>),
     DeleteEnvironment,
+    DeleteConfirmInput(TextInputEvent),
+    ConfirmDeleteEnvironment,
+    CancelDeleteEnvironment,
     EnvironmentDeleted(Result<(), String>),
     NewEnvironment,
 
@@ -181,6 +252,15 @@ pub enum Msg {
     // Global actions
     SetCurrentEnvironment,
     CurrentEnvironmentSet(Result<(), String>),
+
+    // Device code sign-in
+    SignIn,
+    DeviceCodeIssued(Result<DeviceCodePrompt, String>),
+    SignInFinished(Result<(), String>),
+    SignInTick,
+
+    // Environment health
+    HealthChecked(String, Result<Duration, String>),
 }
 
 #[derive(Clone)]
@@ -272,6 +352,11 @@ impl App for EnvironmentSelectorApp {
                     state.recently_saved_env = None;
                 }
 
+                let health_cmd = match state.env_selector.value().map(str::to_string) {
+                    Some(name) => state.check_health(&name),
+                    None => Command::None,
+                };
+
                 // Set active environment selector with proper index
                 if let Some(ref current) = state.current_environment {
                     let env_names: Vec<String> = state.environments.iter()
@@ -299,20 +384,23 @@ impl App for EnvironmentSelectorApp {
                         // Clear the recently saved flag after using it
                         state.recently_saved_cred = None;
 
-                        return Command::perform(
-                            async move {
-                                let config = crate::global_config();
-                                log::debug!("Auto-loading credential: {}", cred_name);
-                                config.get_credentials(&cred_name).await
-                                    .map_err(|e| e.to_string())?
-                                    .ok_or_else(|| "Credential not found".to_string())
-                            },
-                            Msg::CredentialDataLoaded
-                        );
+                        return Command::batch(vec![
+                            health_cmd,
+                            Command::perform(
+                                async move {
+                                    let config = crate::global_config();
+                                    log::debug!("Auto-loading credential: {}", cred_name);
+                                    config.get_credentials(&cred_name).await
+                                        .map_err(|e| e.to_string())?
+                                        .ok_or_else(|| "Credential not found".to_string())
+                                },
+                                Msg::CredentialDataLoaded
+                            ),
+                        ]);
                     }
                 }
 
-                Command::None
+                health_cmd
             }
             Msg::DataLoaded(Err(err)) => {
                 state.data_load_state = Resource::Failure(err.clone());
@@ -336,10 +424,12 @@ impl App for EnvironmentSelectorApp {
                 if let Some(SelectEvent::Select(idx)) = selection {
                     if let Some(env) = state.environments.get(idx) {
                         // Populate environment form fields inline
+                        let name = env.name.clone();
                         state.env_name_field.set_value(env.name.clone());
                         state.env_host_field.set_value(env.host.clone());
                         state.env_creds_selector.set_value(Some(env.credentials_ref.clone()));
                         state.env_panel_dirty = false;
+                        return Command::batch(vec![cmd, state.check_health(&name)]);
                     }
                 }
 
@@ -490,7 +580,35 @@ impl App for EnvironmentSelectorApp {
 
             Msg::DeleteEnvironment => {
                 if let Some(env_name) = state.env_selector.value() {
-                    let env_name = env_name.to_string();
+                    state.delete_confirm = Some((env_name.to_string(), TextInputField::new()));
+                    Command::set_focus(FocusId::new("env-delete-confirm-input"))
+                } else {
+                    Command::None
+                }
+            }
+
+            Msg::DeleteConfirmInput(event) => {
+                let matched = match state.delete_confirm.as_mut() {
+                    Some((env_name, input)) => input.handle_event(event, None).is_some_and(|value| value == *env_name),
+                    None => return Command::None,
+                };
+                if matched {
+                    Self::update(state, Msg::ConfirmDeleteEnvironment)
+                } else {
+                    Command::None
+                }
+            }
+
+            Msg::CancelDeleteEnvironment => {
+                state.delete_confirm = None;
+                Command::None
+            }
+
+            Msg::ConfirmDeleteEnvironment => {
+                // Only delete once the environment's name has been typed exactly
+                let confirmed = state.delete_confirm.take()
+                    .filter(|(env_name, input)| input.value() == env_name);
+                if let Some((env_name, _)) = confirmed {
                     state.env_delete_state = Resource::Loading;
 
                     Command::perform(
@@ -626,9 +744,9 @@ impl App for EnvironmentSelectorApp {
                         state.cred_tenant_id_field.set_value(tenant_id);
                         state.cred_cert_path_field.set_value(String::new());
                     }
-                    CredentialSet::Certificate { client_id, tenant_id, cert_path } => {
+                    CredentialSet::Certificate { client_id, tenant_id, cert_path, cert_password } => {
                         state.cred_username_field.set_value(String::new());
-                        state.cred_password_field.set_value(String::new());
+                        state.cred_password_field.set_value(cert_password.unwrap_or_default());
                         state.cred_client_id_field.set_value(client_id);
                         state.cred_client_secret_field.set_value(String::new());
                         state.cred_tenant_id_field.set_value(tenant_id);
@@ -763,6 +881,8 @@ impl App for EnvironmentSelectorApp {
                             client_id: state.cred_client_id_field.value().to_string(),
                             tenant_id: state.cred_tenant_id_field.value().to_string(),
                             cert_path: state.cred_cert_path_field.value().to_string(),
+                            cert_password: Some(state.cred_password_field.value().to_string())
+                                .filter(|password| !password.is_empty()),
                         }
                     }
                     _ => {
@@ -955,6 +1075,84 @@ impl App for EnvironmentSelectorApp {
                 log::error!("Failed to set current environment: {}", err);
                 Command::None
             }
+
+            Msg::SignIn => {
+                let Some(env_name) = state.env_selector.value().map(|s| s.to_string()) else {
+                    return Command::None;
+                };
+                if state.sign_in_state.is_loading() {
+                    return Command::None;
+                }
+
+                state.sign_in_state = Resource::Loading;
+                state.device_code_prompt = None;
+
+                Command::perform(
+                    async move {
+                        crate::client_manager().request_device_code(&env_name).await
+                            .map_err(|e| e.to_string())
+                    },
+                    Msg::DeviceCodeIssued
+                )
+            }
+
+            Msg::DeviceCodeIssued(Ok(prompt)) => {
+                let Some(env_name) = state.env_selector.value().map(|s| s.to_string()) else {
+                    state.sign_in_state = Resource::NotAsked;
+                    return Command::None;
+                };
+                state.device_code_prompt = Some(prompt.clone());
+
+                Command::perform(
+                    async move {
+                        crate::client_manager()
+                            .complete_device_code_sign_in(&env_name, &prompt, |progress| {
+                                log::debug!("Device code sign-in: {:?}", progress);
+                            })
+                            .await
+                            .map_err(|e| e.to_string())
+                    },
+                    Msg::SignInFinished
+                )
+            }
+
+            Msg::DeviceCodeIssued(Err(err)) | Msg::SignInFinished(Err(err)) => {
+                log::error!("Device code sign-in failed: {}", err);
+                state.sign_in_state = Resource::Failure(err);
+                state.device_code_prompt = None;
+                Command::None
+            }
+
+            Msg::SignInFinished(Ok(())) => {
+                state.sign_in_state = Resource::Success(());
+                state.device_code_prompt = None;
+
+                // New tokens make any earlier failure stale
+                match state.env_selector.value().map(str::to_string) {
+                    Some(name) => {
+                        state.health.remove(&name);
+                        state.check_health(&name)
+                    }
+                    None => Command::None,
+                }
+            }
+
+            Msg::SignInTick => {
+                state.spinner_frame = state.spinner_frame.wrapping_add(1);
+                Command::None
+            }
+
+            Msg::HealthChecked(name, result) => {
+                let health = match result {
+                    Ok(latency) => EnvHealth::Healthy(latency),
+                    Err(err) => {
+                        log::warn!("Health check for '{}' failed: {}", name, err);
+                        EnvHealth::Unhealthy(err)
+                    }
+                };
+                state.health.insert(name, HealthEntry { health, checked_at: Instant::now() });
+                Command::None
+            }
         }
     }
 
@@ -984,11 +1182,36 @@ impl App for EnvironmentSelectorApp {
             cred_panel => Fill(1)
         ];
 
+        let main_content = match &state.delete_confirm {
+            Some((env_name, input)) => Element::modal_confirm_typed(
+                main_content,
+                "Delete environment",
+                format!("Delete \"{}\" and its saved settings?", env_name),
+                env_name,
+                "env-delete-confirm-input",
+                input.value(),
+                &input.state,
+                Msg::DeleteConfirmInput,
+                "env-delete-cancel",
+                Msg::CancelDeleteEnvironment,
+                "env-delete-confirm",
+                Msg::ConfirmDeleteEnvironment,
+            ),
+            None => main_content,
+        };
+
         LayeredView::new(main_content)
     }
 
-    fn subscriptions(_state: &State) -> Vec<Subscription<Msg>> {
-        vec![]
+    fn subscriptions(state: &State) -> Vec<Subscription<Msg>> {
+        let mut subs = Vec::new();
+        if state.sign_in_state.is_loading() {
+            subs.push(Subscription::timer(std::time::Duration::from_millis(80), Msg::SignInTick));
+        }
+        if state.delete_confirm.is_some() {
+            subs.push(Subscription::keyboard(KeyCode::Esc, "Cancel delete", Msg::CancelDeleteEnvironment));
+        }
+        subs
     }
 
     fn title() -> &'static str {
@@ -1098,20 +1321,32 @@ where
         .on_press(AppMsg::NewEnvironment.into())
         .build();
 
+    let sign_in_btn = if state.env_selector.value().is_some() && !state.sign_in_state.is_loading() {
+        Element::button("env-sign-in-btn", "Sign In")
+            .on_press(AppMsg::SignIn.into())
+            .build()
+    } else {
+        Element::button("env-sign-in-btn", "Sign In").build()
+    };
+
     let button_row = row![
         save_btn => Length(10),
         spacer!() => Length(1),
         delete_btn => Length(10),
         spacer!() => Length(1),
-        new_btn => Length(10)
+        new_btn => Length(10),
+        spacer!() => Length(1),
+        sign_in_btn => Length(11)
     ];
 
     let form_fields = col![
         env_select_panel => Length(3),
+        build_health_status(state) => Length(1),
         name_panel => Length(3),
         host_panel => Length(3),
         creds_panel => Length(3),
-        button_row => Length(3)
+        button_row => Length(3),
+        build_sign_in_status(state) => Length(2)
     ];
 
     let details_panel = Element::panel(form_fields)
@@ -1142,6 +1377,64 @@ where
     ]
 }
 
+/// Colored dot for the selected environment's last health check
+fn build_health_status<Msg>(state: &State) -> Element<Msg> {
+    let theme = &crate::global_runtime_config().theme;
+
+    let health = state.env_selector.value()
+        .and_then(|name| state.health.get(name))
+        .map(|entry| &entry.health);
+    let (color, text) = match health {
+        Some(EnvHealth::Healthy(latency)) => (theme.accent_success, format!("Reachable ({} ms)", latency.as_millis())),
+        Some(EnvHealth::Unhealthy(err)) => (theme.accent_error, format!("Unreachable: {}", err)),
+        Some(EnvHealth::Checking) => (theme.text_tertiary, "Checking...".to_string()),
+        None => (theme.text_tertiary, "Not checked".to_string()),
+    };
+
+    Element::styled_text(Line::from(vec![
+        Span::styled(" ● ", Style::default().fg(color)),
+        Span::styled(text, Style::default().fg(theme.text_secondary)),
+    ])).build()
+}
+
+/// Device code instructions while signing in, or the outcome afterwards
+fn build_sign_in_status<Msg>(state: &State) -> Element<Msg> {
+    let theme = &crate::global_runtime_config().theme;
+
+    let lines = match (&state.sign_in_state, &state.device_code_prompt) {
+        (Resource::Loading, Some(prompt)) => {
+            return Element::column(vec![
+                Element::styled_text(Line::from(vec![
+                    Span::styled("Open ", Style::default().fg(theme.text_tertiary)),
+                    Span::styled(prompt.verification_uri.clone(), Style::default().fg(theme.accent_secondary)),
+                    Span::styled(" and enter ", Style::default().fg(theme.text_tertiary)),
+                    Span::styled(prompt.user_code.clone(), Style::default().fg(theme.accent_warning).bold()),
+                ])).build(),
+                Element::progress_bar_indeterminate(state.spinner_frame)
+                    .label("Waiting for you to sign in...")
+                    .spinner_style(SpinnerStyle::Dots)
+                    .build(),
+            ]).build();
+        }
+        (Resource::Loading, None) => {
+            return Element::progress_bar_indeterminate(state.spinner_frame)
+                .label("Requesting device code...")
+                .build();
+        }
+        (Resource::Success(()), _) => vec![Line::from(Span::styled(
+            "✓ Signed in",
+            Style::default().fg(theme.accent_success),
+        ))],
+        (Resource::Failure(err), _) => vec![Line::from(Span::styled(
+            format!("Sign-in failed: {}", err),
+            Style::default().fg(theme.accent_error),
+        ))],
+        (Resource::NotAsked, _) => Vec::new(),
+    };
+
+    Element::column(lines.into_iter().map(|line| Element::styled_text(line).build()).collect()).build()
+}
+
 fn build_credential_panel<Msg: Clone + Send + 'static>(
     state: &mut State,
 ) -> Element<Msg>
@@ -1329,13 +1622,26 @@ where
             .on_event(|e| AppMsg::CredCertPathChanged(e).into())
             .build();
             let cert_path_panel = Element::panel(cert_path_input)
-                .title("Certificate Path")
+                .title("Certificate Path (PEM or PFX)")
+                .build();
+
+            let cert_password_input = Element::text_input(
+                "cred-password",
+                state.cred_password_field.value(),
+                &state.cred_password_field.state
+            )
+            .masked(true)
+            .on_event(|e| AppMsg::CredPasswordChanged(e).into())
+            .build();
+            let cert_password_panel = Element::panel(cert_password_input)
+                .title("Certificate Password (optional)")
                 .build();
 
             builder = builder
                 .add(client_id_panel, Length(3))
                 .add(tenant_id_panel, Length(3))
-                .add(cert_path_panel, Length(3));
+                .add(cert_path_panel, Length(3))
+                .add(cert_password_panel, Length(3));
         }
         _ => {}
     }
@@ -1381,3 +1687,44 @@ where
         details_panel => Fill(1)
     ]
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_health_results_expire_after_ttl() {
+        let checked_at = Instant::now();
+        let healthy = HealthEntry { health: EnvHealth::Healthy(Duration::from_millis(120)), checked_at };
+        assert!(healthy.is_fresh(checked_at + Duration::from_secs(30)));
+        assert!(!healthy.is_fresh(checked_at + HEALTH_TTL));
+
+        let checking = HealthEntry { health: EnvHealth::Checking, checked_at };
+        assert!(checking.is_fresh(checked_at + HEALTH_TTL * 2));
+    }
+
+    #[test]
+    fn test_delete_waits_for_typed_environment_name() {
+        let mut state = State::new();
+        state.env_selector.set_value(Some("production".to_string()));
+
+        EnvironmentSelectorApp::update(&mut state, Msg::DeleteEnvironment);
+        assert!(state.delete_confirm.is_some());
+        assert!(!state.env_delete_state.is_loading());
+
+        // A near miss closes nothing and deletes nothing
+        if let Some((_, input)) = state.delete_confirm.as_mut() {
+            input.set_value("Production".to_string());
+        }
+        EnvironmentSelectorApp::update(&mut state, Msg::DeleteConfirmInput(TextInputEvent::Submit));
+        assert!(state.delete_confirm.is_some());
+        assert!(!state.env_delete_state.is_loading());
+
+        if let Some((_, input)) = state.delete_confirm.as_mut() {
+            input.set_value("production".to_string());
+        }
+        EnvironmentSelectorApp::update(&mut state, Msg::ConfirmDeleteEnvironment);
+        assert!(state.delete_confirm.is_none());
+        assert!(state.env_delete_state.is_loading());
+    }
+}
@@ -19,6 +19,7 @@ use ratatui::{
 use std::collections::HashMap;
 use super::{Msg, Side, ExamplesState, ExamplePair, ActiveTab, FetchType, fetch_with_cache, extract_relationships, extract_entities, MatchInfo};
 use super::matching::recompute_all_matches;
+use super::update::autosave::SaveStatus;
 use super::tree_sync::{update_mirrored_selection, mirror_container_toggle};
 use super::view::{render_main_layout, render_back_confirmation_modal, render_examples_modal};
 
@@ -33,6 +34,41 @@ pub struct ImportResults {
     pub unparsed: Vec<String>,             // Lines that couldn't be parsed
 }
 
+/// Column choice for a CSV whose header didn't name the source/target columns
+#[derive(Clone)]
+pub struct CsvColumnPicker {
+    pub table: crate::csv_parser::CsvTable,
+    pub filename: String,
+    pub source_column: crate::tui::widgets::SelectField,
+    pub target_column: crate::tui::widgets::SelectField,
+}
+
+impl CsvColumnPicker {
+    pub fn new(table: crate::csv_parser::CsvTable, filename: String) -> Self {
+        let mut source_column = crate::tui::widgets::SelectField::new();
+        let mut target_column = crate::tui::widgets::SelectField::new();
+        source_column.set_value_with_options(table.headers.first().cloned(), &table.headers);
+        target_column.set_value_with_options(table.headers.get(1).cloned(), &table.headers);
+        Self { table, filename, source_column, target_column }
+    }
+}
+
+/// Other saved comparisons to copy mappings from
+#[derive(Clone)]
+pub struct ComparisonPicker {
+    pub comparisons: Resource<Vec<crate::config::SavedComparison>>,
+    pub list_state: crate::tui::widgets::ListState,
+}
+
+impl ComparisonPicker {
+    pub fn new() -> Self {
+        Self {
+            comparisons: Resource::Loading,
+            list_state: crate::tui::widgets::ListState::with_selection(),
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct State {
     // Context
@@ -52,6 +88,7 @@ pub struct State {
     // Mapping state
     pub(super) field_mappings: HashMap<String, Vec<String>>,  // source -> targets (manual, 1-to-N support)
     pub(super) prefix_mappings: HashMap<String, Vec<String>>, // source_prefix -> target_prefixes (1-to-N support)
+    pub(super) relationship_mappings: HashMap<String, Vec<String>>, // source_relationship -> target_relationships (manual)
     pub(super) imported_mappings: HashMap<String, Vec<String>>, // source -> targets (from C# file, 1-to-N support)
     pub(super) import_source_file: Option<String>,       // filename of imported C# file
     pub(super) hide_mode: super::models::HideMode,
@@ -79,6 +116,7 @@ pub struct State {
     pub(super) target_forms_tree: TreeState,
     pub(super) target_entities_tree: TreeState,
     pub(super) focused_side: Side,
+    pub(super) refresh_snapshots: Option<Vec<crate::tui::widgets::TreeSnapshot>>, // Tree views saved across a refresh
 
     // Examples
     pub(super) examples: ExamplesState,
@@ -89,6 +127,8 @@ pub struct State {
     pub(super) examples_source_input: crate::tui::widgets::TextInputField,
     pub(super) examples_target_input: crate::tui::widgets::TextInputField,
     pub(super) examples_label_input: crate::tui::widgets::TextInputField,
+    pub(super) show_example_diff_modal: bool,
+    pub(super) example_diff_tree: TreeState,
 
     // Prefix mappings modal state
     pub(super) show_prefix_mappings_modal: bool,
@@ -106,11 +146,19 @@ pub struct State {
     pub(super) show_import_results_modal: bool,
     pub(super) import_results: Option<ImportResults>,
     pub(super) import_results_list: crate::tui::widgets::ListState,
+    pub(super) csv_column_picker: Option<CsvColumnPicker>,
+    pub(super) comparison_picker: Option<ComparisonPicker>,
 
     // Ignore state
     pub(super) ignored_items: std::collections::HashSet<String>,
+    pub(super) mapping_history: super::update::history::UndoStack<super::update::history::MappingAction>,
+    pub(super) autosave: super::update::autosave::AutoSave,
+    /// Auto-matching settings in use; refreshed from the options by re-running auto-match
+    pub(super) match_settings: super::models::MatchSettings,
     pub(super) show_ignore_modal: bool,
     pub(super) ignore_list_state: crate::tui::widgets::ListState,
+    /// Typed confirmation for clearing every ignored item, while it is open
+    pub(super) clear_ignored_confirm: Option<crate::tui::widgets::TextInputField>,
 
     // Search state
     pub(super) search_mode: super::models::SearchMode,
@@ -121,6 +169,7 @@ pub struct State {
 
     // Modal state
     pub(super) show_back_confirmation: bool,
+    pub(super) show_export_modal: bool,
 }
 
 pub struct EntityComparisonParams {
@@ -158,6 +207,7 @@ impl Default for State {
             target_metadata: Resource::NotAsked,
             field_mappings: HashMap::new(),
             prefix_mappings: HashMap::new(),
+            relationship_mappings: HashMap::new(),
             imported_mappings: HashMap::new(),
             import_source_file: None,
             hide_mode: super::models::HideMode::default(),
@@ -179,12 +229,15 @@ impl Default for State {
             target_forms_tree: TreeState::with_selection(),
             target_entities_tree: TreeState::with_selection(),
             focused_side: Side::Source,
+            refresh_snapshots: None,
             examples: ExamplesState::new(),
             show_examples_modal: false,
             examples_list_state: crate::tui::widgets::ListState::new(),
             examples_source_input: crate::tui::widgets::TextInputField::new(),
             examples_target_input: crate::tui::widgets::TextInputField::new(),
             examples_label_input: crate::tui::widgets::TextInputField::new(),
+            show_example_diff_modal: false,
+            example_diff_tree: TreeState::with_selection(),
             show_prefix_mappings_modal: false,
             prefix_mappings_list_state: crate::tui::widgets::ListState::new(),
             prefix_source_input: crate::tui::widgets::TextInputField::new(),
@@ -198,20 +251,59 @@ impl Default for State {
             show_import_results_modal: false,
             import_results: None,
             import_results_list: crate::tui::widgets::ListState::new(),
+            csv_column_picker: None,
+            comparison_picker: None,
             ignored_items: std::collections::HashSet::new(),
+            mapping_history: super::update::history::UndoStack::new(),
+            autosave: super::update::autosave::AutoSave::new(),
+            match_settings: super::models::MatchSettings::default(),
             show_ignore_modal: false,
             ignore_list_state: crate::tui::widgets::ListState::new(),
+            clear_ignored_confirm: None,
             search_mode: super::models::SearchMode::default(),
             match_mode: super::models::MatchMode::default(),
             unified_search: crate::tui::widgets::TextInputField::new(),
             source_search: crate::tui::widgets::TextInputField::new(),
             target_search: crate::tui::widgets::TextInputField::new(),
             show_back_confirmation: false,
+            show_export_modal: false,
         }
     }
 }
 
 impl State {
+    /// All source and target tree states, in the same order as `all_trees_mut`
+    pub(super) fn all_trees(&self) -> [&TreeState; 10] {
+        [
+            &self.source_fields_tree,
+            &self.source_relationships_tree,
+            &self.source_views_tree,
+            &self.source_forms_tree,
+            &self.source_entities_tree,
+            &self.target_fields_tree,
+            &self.target_relationships_tree,
+            &self.target_views_tree,
+            &self.target_forms_tree,
+            &self.target_entities_tree,
+        ]
+    }
+
+    /// All source and target tree states, in a fixed order
+    pub(super) fn all_trees_mut(&mut self) -> [&mut TreeState; 10] {
+        [
+            &mut self.source_fields_tree,
+            &mut self.source_relationships_tree,
+            &mut self.source_views_tree,
+            &mut self.source_forms_tree,
+            &mut self.source_entities_tree,
+            &mut self.target_fields_tree,
+            &mut self.target_relationships_tree,
+            &mut self.target_views_tree,
+            &mut self.target_forms_tree,
+            &mut self.target_entities_tree,
+        ]
+    }
+
     /// Get the appropriate source tree state for the active tab
     pub(super) fn source_tree_for_tab(&mut self) -> &mut TreeState {
         match self.active_tab {
@@ -252,6 +344,7 @@ impl App for EntityComparisonApp {
             target_metadata: Resource::Loading,
             field_mappings: HashMap::new(),
             prefix_mappings: HashMap::new(),
+            relationship_mappings: HashMap::new(),
             imported_mappings: HashMap::new(),
             import_source_file: None,
             hide_mode: super::models::HideMode::default(),
@@ -273,12 +366,15 @@ impl App for EntityComparisonApp {
             target_forms_tree: TreeState::with_selection(),
             target_entities_tree: TreeState::with_selection(),
             focused_side: Side::Source,
+            refresh_snapshots: None,
             examples: ExamplesState::new(),
             show_examples_modal: false,
             examples_list_state: crate::tui::widgets::ListState::new(),
             examples_source_input: crate::tui::widgets::TextInputField::new(),
             examples_target_input: crate::tui::widgets::TextInputField::new(),
             examples_label_input: crate::tui::widgets::TextInputField::new(),
+            show_example_diff_modal: false,
+            example_diff_tree: TreeState::with_selection(),
             show_prefix_mappings_modal: false,
             prefix_mappings_list_state: crate::tui::widgets::ListState::new(),
             prefix_source_input: crate::tui::widgets::TextInputField::new(),
@@ -292,15 +388,22 @@ impl App for EntityComparisonApp {
             show_import_results_modal: false,
             import_results: None,
             import_results_list: crate::tui::widgets::ListState::new(),
+            csv_column_picker: None,
+            comparison_picker: None,
             ignored_items: std::collections::HashSet::new(),
+            mapping_history: super::update::history::UndoStack::new(),
+            autosave: super::update::autosave::AutoSave::new(),
+            match_settings: super::models::MatchSettings::default(),
             show_ignore_modal: false,
             ignore_list_state: crate::tui::widgets::ListState::new(),
+            clear_ignored_confirm: None,
             search_mode: super::models::SearchMode::default(),
             match_mode: super::models::MatchMode::default(),
             unified_search: crate::tui::widgets::TextInputField::new(),
             source_search: crate::tui::widgets::TextInputField::new(),
             target_search: crate::tui::widgets::TextInputField::new(),
             show_back_confirmation: false,
+            show_export_modal: false,
         };
 
         // First, load mappings to know which example pairs to fetch
@@ -319,6 +422,11 @@ impl App for EntityComparisonApp {
                         log::error!("Failed to load prefix mappings: {}", e);
                         HashMap::new()
                     });
+                let relationship_mappings = config.get_relationship_mappings(&source_entity, &target_entity).await
+                    .unwrap_or_else(|e| {
+                        log::error!("Failed to load relationship mappings: {}", e);
+                        HashMap::new()
+                    });
                 let (imported_mappings, import_source_file) = config.get_imported_mappings(&source_entity, &target_entity).await
                     .unwrap_or_else(|e| {
                         log::error!("Failed to load imported mappings: {}", e);
@@ -334,10 +442,10 @@ impl App for EntityComparisonApp {
                         log::error!("Failed to load ignored items: {}", e);
                         std::collections::HashSet::new()
                     });
-                (field_mappings, prefix_mappings, imported_mappings, import_source_file, example_pairs, ignored_items)
+                (field_mappings, prefix_mappings, relationship_mappings, imported_mappings, import_source_file, example_pairs, ignored_items)
             }
-        }, |(field_mappings, prefix_mappings, imported_mappings, import_source_file, example_pairs, ignored_items)| {
-            Msg::MappingsLoaded(field_mappings, prefix_mappings, imported_mappings, import_source_file, example_pairs, ignored_items)
+        }, |(field_mappings, prefix_mappings, relationship_mappings, imported_mappings, import_source_file, example_pairs, ignored_items)| {
+            Msg::MappingsLoaded(field_mappings, prefix_mappings, relationship_mappings, imported_mappings, import_source_file, example_pairs, ignored_items)
         });
 
         (state, init_cmd)
@@ -359,6 +467,10 @@ impl App for EntityComparisonApp {
             view = view.with_app_modal(render_examples_modal(state), LayerAlignment::Center);
         }
 
+        if state.show_example_diff_modal {
+            view = view.with_app_modal(super::view::render_example_diff_modal(state), LayerAlignment::Center);
+        }
+
         if state.show_prefix_mappings_modal {
             view = view.with_app_modal(super::view::render_prefix_mappings_modal(state), LayerAlignment::Center);
         }
@@ -371,12 +483,29 @@ impl App for EntityComparisonApp {
             view = view.with_app_modal(super::view::render_import_modal(state), LayerAlignment::Center);
         }
 
+        if state.csv_column_picker.is_some() {
+            view = view.with_app_modal(super::view::render_csv_columns_modal(state), LayerAlignment::Center);
+        }
+
+        if state.comparison_picker.is_some() {
+            view = view.with_app_modal(super::view::render_comparison_picker_modal(state), LayerAlignment::Center);
+        }
+
         if state.show_import_results_modal {
             view = view.with_app_modal(super::view::render_import_results_modal(state), LayerAlignment::Center);
         }
 
         if state.show_ignore_modal {
-            view = view.with_app_modal(super::view::render_ignore_modal(state), LayerAlignment::Center);
+            let ignore_modal = super::view::render_ignore_modal(state);
+            let ignore_modal = match &state.clear_ignored_confirm {
+                Some(input) => super::view::render_clear_ignored_confirm(ignore_modal, input, state.ignored_items.len()),
+                None => ignore_modal,
+            };
+            view = view.with_app_modal(ignore_modal, LayerAlignment::Center);
+        }
+
+        if state.show_export_modal {
+            view = view.with_app_modal(super::view::render_export_modal(state), LayerAlignment::Center);
         }
 
         view
@@ -403,6 +532,8 @@ impl App for EntityComparisonApp {
             // Manual mapping actions (supports 1-to-N and N-to-1 via multi-select)
             Subscription::keyboard(config.get_keybind("entity_comparison.create_mapping"), "Create manual mapping (multi-select supported)", Msg::CreateManualMapping),
             Subscription::keyboard(config.get_keybind("entity_comparison.delete_mapping"), "Delete manual mapping", Msg::DeleteManualMapping),
+            Subscription::keyboard(config.get_keybind("entity_comparison.undo"), "Undo mapping change", Msg::Undo),
+            Subscription::keyboard(config.get_keybind("entity_comparison.redo"), "Redo mapping change", Msg::Redo),
 
             // Cycle hide mode
             Subscription::keyboard(config.get_keybind("entity_comparison.toggle_hide_matched"), "Cycle hide mode", Msg::CycleHideMode),
@@ -430,8 +561,15 @@ impl App for EntityComparisonApp {
             Subscription::keyboard(config.get_keybind("entity_comparison.ignore_item"), "Ignore item", Msg::IgnoreItem),
             Subscription::keyboard(config.get_keybind("entity_comparison.ignore_manager"), "Ignore manager", Msg::OpenIgnoreModal),
 
+            // Jump between unmatched source items
+            Subscription::keyboard(config.get_keybind("entity_comparison.next_unmatched"), "Next unmatched item", Msg::NextUnmatched),
+            Subscription::keyboard(config.get_keybind("entity_comparison.prev_unmatched"), "Previous unmatched item", Msg::PrevUnmatched),
+
+            // Re-run auto-matching with the current settings
+            Subscription::keyboard(config.get_keybind("entity_comparison.rerun_auto_match"), "Re-run auto-match", Msg::RerunAutoMatch),
+
             // Export
-            Subscription::keyboard(config.get_keybind("entity_comparison.export"), "Export to Excel", Msg::ExportToExcel),
+            Subscription::keyboard(config.get_keybind("entity_comparison.export"), "Export report", Msg::OpenExportModal),
         ];
 
         // Multi-selection shortcuts (active when no modal is open and search is not focused)
@@ -442,7 +580,10 @@ impl App for EntityComparisonApp {
             || state.show_manual_mappings_modal
             || state.show_import_modal
             || state.show_import_results_modal
-            || state.show_ignore_modal;
+            || state.csv_column_picker.is_some()
+            || state.comparison_picker.is_some()
+            || state.show_ignore_modal
+            || state.show_export_modal;
 
         if !any_modal_open {
             use crate::tui::widgets::TreeEvent;
@@ -535,7 +676,10 @@ impl App for EntityComparisonApp {
             || state.show_manual_mappings_modal
             || state.show_import_modal
             || state.show_import_results_modal
-            || state.show_ignore_modal;
+            || state.csv_column_picker.is_some()
+            || state.comparison_picker.is_some()
+            || state.show_ignore_modal
+            || state.show_export_modal;
 
         if !any_modal_open {
             subs.push(Subscription::keyboard(KeyCode::Char('/'), "Focus search", Msg::ToggleSearch));
@@ -545,6 +689,13 @@ impl App for EntityComparisonApp {
         // Match mode toggle - always available
         subs.push(Subscription::keyboard(KeyCode::Char('f'), "Toggle match mode", Msg::ToggleMatchMode));
 
+        if state.show_export_modal {
+            subs.push(Subscription::keyboard(KeyCode::Char('x'), "Export to Excel", Msg::ExportToExcel));
+            subs.push(Subscription::keyboard(KeyCode::Char('m'), "Export to Markdown", Msg::ExportToMarkdown));
+            subs.push(Subscription::keyboard(KeyCode::Char('c'), "Export to C# mapping file", Msg::ExportToCsharp));
+            subs.push(Subscription::keyboard(KeyCode::Esc, "Close export", Msg::CloseExportModal));
+        }
+
         // When showing confirmation modal, add y/n hotkeys
         if state.show_back_confirmation {
             subs.push(Subscription::keyboard(KeyCode::Char('y'), "Confirm", Msg::ConfirmBack));
@@ -555,9 +706,13 @@ impl App for EntityComparisonApp {
         }
 
         // When showing examples modal, add hotkeys
-        if state.show_examples_modal {
+        if state.show_example_diff_modal {
+            subs.push(Subscription::keyboard(KeyCode::Char('c'), "Close diff", Msg::CloseExampleDiff));
+            subs.push(Subscription::keyboard(KeyCode::Esc, "Close diff", Msg::CloseExampleDiff));
+        } else if state.show_examples_modal {
             subs.push(Subscription::keyboard(KeyCode::Char('a'), "Add example pair", Msg::AddExamplePair));
             subs.push(Subscription::keyboard(KeyCode::Char('d'), "Delete example pair", Msg::DeleteExamplePair));
+            subs.push(Subscription::keyboard(KeyCode::Char('v'), "View diff of example pair", Msg::OpenExampleDiff));
             subs.push(Subscription::keyboard(KeyCode::Char('c'), "Close modal", Msg::CloseExamplesModal));
             subs.push(Subscription::keyboard(KeyCode::Esc, "Close modal", Msg::CloseExamplesModal));
         }
@@ -566,6 +721,7 @@ impl App for EntityComparisonApp {
         if state.show_prefix_mappings_modal {
             subs.push(Subscription::keyboard(KeyCode::Char('a'), "Add prefix mapping", Msg::AddPrefixMapping));
             subs.push(Subscription::keyboard(KeyCode::Char('d'), "Delete prefix mapping", Msg::DeletePrefixMapping));
+            subs.push(Subscription::keyboard(KeyCode::Char('g'), "Apply prefix mapping to unmapped fields", Msg::ApplyPrefixMapping));
             subs.push(Subscription::keyboard(KeyCode::Char('c'), "Close modal", Msg::ClosePrefixMappingsModal));
             subs.push(Subscription::keyboard(KeyCode::Esc, "Close modal", Msg::ClosePrefixMappingsModal));
         }
@@ -579,10 +735,20 @@ impl App for EntityComparisonApp {
 
         // When showing import modal, add hotkeys
         if state.show_import_modal {
+            subs.push(Subscription::keyboard(KeyCode::Char('o'), "Copy from another comparison", Msg::OpenComparisonPicker));
             subs.push(Subscription::keyboard(KeyCode::Char('c'), "Close modal", Msg::CloseImportModal));
             subs.push(Subscription::keyboard(KeyCode::Esc, "Close modal", Msg::CloseImportModal));
         }
 
+        // When picking CSV columns, Esc cancels (the dropdowns pass it through when closed)
+        if state.csv_column_picker.is_some() {
+            subs.push(Subscription::keyboard(KeyCode::Esc, "Cancel import", Msg::CancelCsvColumns));
+        }
+
+        if state.comparison_picker.is_some() {
+            subs.push(Subscription::keyboard(KeyCode::Esc, "Close modal", Msg::CloseComparisonPicker));
+        }
+
         // When showing import results modal, add hotkeys
         if state.show_import_results_modal {
             subs.push(Subscription::keyboard(KeyCode::Up, "Navigate up", Msg::ImportResultsNavigate(KeyCode::Up)));
@@ -591,8 +757,10 @@ impl App for EntityComparisonApp {
             subs.push(Subscription::keyboard(KeyCode::Esc, "Close modal", Msg::CloseImportResultsModal));
         }
 
-        // When showing ignore modal, add hotkeys
-        if state.show_ignore_modal {
+        // While confirming a clear, only the confirmation reacts to keys
+        if state.clear_ignored_confirm.is_some() {
+            subs.push(Subscription::keyboard(KeyCode::Esc, "Cancel clear", Msg::CancelClearAllIgnored));
+        } else if state.show_ignore_modal {
             subs.push(Subscription::keyboard(KeyCode::Up, "Navigate up", Msg::IgnoreListNavigate(KeyCode::Up)));
             subs.push(Subscription::keyboard(KeyCode::Down, "Navigate down", Msg::IgnoreListNavigate(KeyCode::Down)));
             subs.push(Subscription::keyboard(KeyCode::Char('d'), "Delete ignored item", Msg::DeleteIgnoredItem));
@@ -600,6 +768,11 @@ impl App for EntityComparisonApp {
             subs.push(Subscription::keyboard(KeyCode::Esc, "Close modal", Msg::CloseIgnoreModal));
         }
 
+        // Debounce timer, only while edits are waiting to be saved
+        if state.autosave.is_waiting() {
+            subs.push(Subscription::timer(super::update::autosave::TICK_INTERVAL, Msg::AutoSaveTick));
+        }
+
         subs
     }
 
@@ -607,39 +780,30 @@ impl App for EntityComparisonApp {
         "Entity Comparison"
     }
 
-    fn status(state: &Self::State) -> Option<Line<'static>> {
-        // Build tab indicator with active tab highlighted
-        let theme = &crate::global_runtime_config().theme;
-        let tabs = [
-            ActiveTab::Fields,
-            ActiveTab::Relationships,
-            ActiveTab::Views,
-            ActiveTab::Forms,
-            ActiveTab::Entities,
-        ];
+    fn on_suspend(state: &mut Self::State) -> Command<Self::Msg> {
+        super::update::autosave::flush(state)
+    }
 
-        let mut spans = vec![];
+    fn on_destroy(state: &mut Self::State) -> Command<Self::Msg> {
+        super::update::autosave::flush_all(state)
+    }
 
-        for (i, tab) in tabs.iter().enumerate() {
-            if i > 0 {
-                spans.push(Span::styled(" ", Style::default()));
-            }
+    fn session_key(state: &Self::State) -> Option<String> {
+        Some(super::session::session_key(state))
+    }
 
-            let is_active = *tab == state.active_tab;
-            let label = format!("[{}] {}", tab.number(), tab.label());
-
-            spans.push(Span::styled(
-                label,
-                if is_active {
-                    Style::default().fg(theme.accent_primary).italic()
-                } else {
-                    Style::default().fg(theme.text_secondary)
-                },
-            ));
-        }
+    fn suspend_state(state: &Self::State) -> Option<serde_json::Value> {
+        super::session::suspend_state(state)
+    }
 
-        // Add separator
-        spans.push(Span::styled(" | ", Style::default().fg(theme.border_primary)));
+    fn restore_state(state: &mut Self::State, snapshot: serde_json::Value) -> Command<Self::Msg> {
+        super::session::restore_state(state, snapshot)
+    }
+
+    fn status(state: &Self::State) -> Option<Line<'static>> {
+        // Tabs are drawn by the view, so the status line only shows modes
+        let theme = &crate::global_runtime_config().theme;
+        let mut spans = vec![];
 
         // Hide mode
         spans.push(Span::styled(
@@ -668,6 +832,18 @@ impl App for EntityComparisonApp {
             Style::default().fg(theme.text_secondary),
         ));
 
+        // Auto-save indicator
+        let save_status = match state.autosave.status() {
+            SaveStatus::Idle => None,
+            SaveStatus::Pending | SaveStatus::Saving => Some(("Saving…", theme.accent_warning)),
+            SaveStatus::Saved => Some(("Saved", theme.accent_success)),
+            SaveStatus::Failed(_) => Some(("Save failed", theme.accent_error)),
+        };
+        if let Some((label, color)) = save_status {
+            spans.push(Span::styled(" | ", Style::default().fg(theme.border_primary)));
+            spans.push(Span::styled(label, Style::default().fg(color)));
+        }
+
         // Example display status
         if state.examples.enabled {
             if let Some(active_pair_id) = &state.examples.active_pair_id {
@@ -0,0 +1,71 @@
+//! C# mapping file export, the counterpart of the C# import
+
+use anyhow::{Context, Result};
+use std::collections::BTreeMap;
+
+use super::super::app::State;
+use super::super::models::MatchType;
+
+/// Writes the current mappings in the format `cs_parser` imports
+pub struct CsharpExporter;
+
+impl CsharpExporter {
+    /// Export the field and relationship mappings to a C# mapping file
+    pub fn export_to_csharp(state: &State, file_path: &str) -> Result<()> {
+        let mappings = csharp_mappings(state);
+        let relationships = relationship_mappings(state);
+        let content = crate::cs_parser::write_cs_field_mappings(
+            &state.source_entity,
+            &state.target_entity,
+            &mappings,
+            &relationships,
+            chrono::Utc::now(),
+        );
+        std::fs::write(file_path, content)
+            .with_context(|| format!("Failed to save C# file: {}", file_path))?;
+
+        log::info!("C# mapping file with {} mapped source fields exported to: {}", mappings.len(), file_path);
+        Ok(())
+    }
+}
+
+/// Source field -> target fields from imported, manual and prefix mappings
+///
+/// Manual mappings override imported ones for the same source, as they do in
+/// matching. Imported targets keep the casing of the file they came from, so
+/// exporting an imported file reproduces its mappings. Prefix mappings pair
+/// prefixes rather than fields, so the fields they matched are exported.
+pub fn csharp_mappings(state: &State) -> BTreeMap<String, Vec<String>> {
+    let mut mappings: BTreeMap<String, Vec<String>> = state.imported_mappings.iter()
+        .chain(&state.field_mappings)
+        .map(|(source, targets)| (source.clone(), targets.clone()))
+        .collect();
+
+    for (source, match_info) in &state.field_matches {
+        if mappings.contains_key(source) {
+            continue;
+        }
+        let prefix_targets: Vec<String> = match_info.target_fields.iter()
+            .filter(|target| match_info.match_types.get(*target) == Some(&MatchType::Prefix))
+            .cloned()
+            .collect();
+        if !prefix_targets.is_empty() {
+            mappings.insert(source.clone(), prefix_targets);
+        }
+    }
+    mappings
+}
+
+/// Source relationship -> target relationships for every matched relationship
+/// whose kind and related entity agree
+fn relationship_mappings(state: &State) -> BTreeMap<String, Vec<String>> {
+    state.relationship_matches.iter()
+        .filter_map(|(source, match_info)| {
+            let targets: Vec<String> = match_info.target_fields.iter()
+                .filter(|target| match_info.match_types.get(*target) != Some(&MatchType::TypeMismatch))
+                .cloned()
+                .collect();
+            (!targets.is_empty()).then(|| (source.clone(), targets))
+        })
+        .collect()
+}
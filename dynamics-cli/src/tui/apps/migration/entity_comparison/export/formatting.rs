@@ -75,3 +75,30 @@ pub fn create_example_value_format() -> Format {
     Format::new()
         .set_background_color(Color::RGB(0xAFEEEE))  // Pale Turquoise/Cyan
 }
+
+// Conditional highlights for the Mapping Type column, so the colors follow the
+// cell value rather than the row's fill
+
+pub fn create_matched_highlight_format() -> Format {
+    Format::new()
+        .set_background_color(Color::RGB(0xC6EFCE))  // Green
+        .set_font_color(Color::RGB(0x006100))
+}
+
+pub fn create_prefix_highlight_format() -> Format {
+    Format::new()
+        .set_background_color(Color::RGB(0xFFEB9C))  // Yellow
+        .set_font_color(Color::RGB(0x9C5700))
+}
+
+pub fn create_unmapped_highlight_format() -> Format {
+    Format::new()
+        .set_background_color(Color::RGB(0xFFC7CE))  // Red
+        .set_font_color(Color::RGB(0x9C0006))
+}
+
+pub fn create_totals_format() -> Format {
+    Format::new()
+        .set_bold()
+        .set_border_top(FormatBorder::Thin)
+}
@@ -5,7 +5,99 @@ use rust_xlsxwriter::*;
 use std::process::Command;
 
 use crate::api::metadata::FieldMetadata;
-use super::formatting::create_required_format;
+use super::super::models::MatchType;
+use super::formatting::*;
+
+/// Rows written per mapping status on a sheet, for its totals and the summary sheet
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MappingCounts {
+    pub exact: u32,
+    pub manual: u32,
+    pub prefix: u32,
+    pub type_mismatch: u32,
+    pub example: u32,
+    pub unmapped: u32,
+}
+
+impl MappingCounts {
+    /// Count one item by its primary match type; `None` counts as unmapped
+    pub fn add(&mut self, match_type: Option<MatchType>) {
+        match match_type {
+            Some(MatchType::Exact) => self.exact += 1,
+            Some(MatchType::Manual | MatchType::Import) => self.manual += 1,
+            Some(MatchType::Prefix | MatchType::Related | MatchType::Fuzzy) => self.prefix += 1,
+            Some(MatchType::TypeMismatch) => self.type_mismatch += 1,
+            Some(MatchType::ExampleValue) => self.example += 1,
+            None => self.unmapped += 1,
+        }
+    }
+
+    pub fn mapped(&self) -> u32 {
+        self.exact + self.manual + self.prefix + self.type_mismatch + self.example
+    }
+
+    pub fn total(&self) -> u32 {
+        self.mapped() + self.unmapped
+    }
+
+    /// Count per status, labelled as in the Mapping Type column
+    pub fn rows(&self) -> [(&'static str, u32); 6] {
+        [
+            ("Exact", self.exact),
+            ("Manual", self.manual),
+            ("Prefix", self.prefix),
+            ("Type Mismatch", self.type_mismatch),
+            ("Example", self.example),
+            ("Unmapped", self.unmapped),
+        ]
+    }
+}
+
+/// Write a totals block below a sheet's rows, returning the row after it
+pub fn write_totals(sheet: &mut Worksheet, row: u32, counts: &MappingCounts) -> Result<u32> {
+    let header_format = create_header_format();
+    let totals_format = create_totals_format();
+
+    sheet.write_string_with_format(row, 0, "TOTALS", &header_format)?;
+    sheet.write_string_with_format(row, 1, "Count", &header_format)?;
+    let mut row = row + 1;
+
+    for (label, count) in counts.rows() {
+        sheet.write_string(row, 0, &format!("    {}", label))?;
+        sheet.write_number(row, 1, count)?;
+        row += 1;
+    }
+
+    sheet.write_string_with_format(row, 0, "    Total", &totals_format)?;
+    sheet.write_number_with_format(row, 1, counts.total(), &totals_format)?;
+    Ok(row + 1)
+}
+
+/// Color a Mapping Type column by its values: green when matched, yellow for
+/// prefix matches and red when unmapped
+pub fn add_mapping_highlights(sheet: &mut Worksheet, first_row: u32, last_row: u32, col: u16) -> Result<()> {
+    if last_row < first_row {
+        return Ok(());
+    }
+
+    let matched = create_matched_highlight_format();
+    let rules = [
+        ("Unmapped", create_unmapped_highlight_format()),
+        ("Prefix", create_prefix_highlight_format()),
+        ("Exact", matched.clone()),
+        ("Manual", matched.clone()),
+        ("Import", matched.clone()),
+        ("Example", matched),
+    ];
+
+    for (text, format) in rules {
+        let highlight = ConditionalFormatText::new()
+            .set_rule(ConditionalFormatTextRule::Contains(text.to_string()))
+            .set_format(format);
+        sheet.add_conditional_format(first_row, col, last_row, col, &highlight)?;
+    }
+    Ok(())
+}
 
 /// Write a field row with consistent formatting
 pub fn write_field_row(
@@ -0,0 +1,290 @@
+//! Markdown export for migration analysis, for pasting into wikis and tickets
+
+use anyhow::{Context, Result};
+use std::collections::HashSet;
+use std::fmt::Write;
+
+use crate::api::metadata::FieldMetadata;
+use crate::tui::Resource;
+use super::super::app::State;
+use super::super::models::{MatchInfo, MatchType};
+use super::helpers::MappingCounts;
+
+/// Markdown export of the same data the Excel sheets show
+pub struct MarkdownExporter;
+
+impl MarkdownExporter {
+    /// Export migration analysis to a single Markdown file
+    pub fn export_to_markdown(state: &State, file_path: &str) -> Result<()> {
+        std::fs::write(file_path, render_report(state))
+            .with_context(|| format!("Failed to save Markdown file: {}", file_path))?;
+
+        log::info!("Markdown file exported to: {}", file_path);
+        Ok(())
+    }
+}
+
+/// Render the whole report: summary header, then one section per Excel sheet group
+pub fn render_report(state: &State) -> String {
+    let source_fields = fields(&state.source_metadata);
+    let target_fields = fields(&state.target_metadata);
+    let mapped_targets: HashSet<&str> = state.field_matches.values()
+        .flat_map(|m| m.target_fields.iter().map(String::as_str))
+        .collect();
+
+    let mut source_counts = MappingCounts::default();
+    for field in source_fields {
+        source_counts.add(state.field_matches.get(&field.logical_name).map(primary_match_type));
+    }
+    let mut target_counts = MappingCounts::default();
+    for field in target_fields {
+        target_counts.add(target_match_type(state, &field.logical_name));
+    }
+
+    let mut out = String::new();
+    let _ = writeln!(out, "# Migration Report: {}", state.migration_name);
+    let _ = writeln!(out);
+    let _ = writeln!(out, "| | Source | Target |");
+    let _ = writeln!(out, "|---|---|---|");
+    let _ = writeln!(out, "| Environment | {} | {} |", cell(&state.source_env), cell(&state.target_env));
+    let _ = writeln!(out, "| Entity | {} | {} |", cell(&state.source_entity), cell(&state.target_entity));
+    let _ = writeln!(out, "| Fields | {} | {} |", source_counts.total(), target_counts.total());
+    let _ = writeln!(out, "| Mapped | {} | {} |", source_counts.mapped(), target_counts.mapped());
+    for ((label, source), (_, target)) in source_counts.rows().into_iter().zip(target_counts.rows()) {
+        let _ = writeln!(out, "| {} | {} | {} |", label, source, target);
+    }
+
+    write_field_mappings(&mut out, state, source_fields);
+    write_unmatched_fields(&mut out, state, source_fields, target_fields, &mapped_targets);
+    write_prefix_mappings(&mut out, state);
+    write_examples(&mut out, state, source_fields);
+    out
+}
+
+fn write_field_mappings(out: &mut String, state: &State, source_fields: &[FieldMetadata]) {
+    let _ = writeln!(out, "\n## Field Mappings\n");
+
+    let mut mapped: Vec<(&FieldMetadata, &MatchInfo)> = source_fields.iter()
+        .filter_map(|f| state.field_matches.get(&f.logical_name).map(|m| (f, m)))
+        .collect();
+    if mapped.is_empty() {
+        let _ = writeln!(out, "_No mapped fields_");
+        return;
+    }
+    // Same grouping order as the Excel sheet
+    mapped.sort_by_key(|(f, m)| (group_order(primary_match_type(m)), f.logical_name.clone()));
+
+    let _ = writeln!(out, "| Source Field | Type | Required | Target Field(s) | Mapping Type |");
+    let _ = writeln!(out, "|---|---|---|---|---|");
+    for (field, match_info) in mapped {
+        let _ = writeln!(
+            out,
+            "| {} | {:?} | {} | {} | {} |",
+            cell(&field.logical_name),
+            field.field_type,
+            yes_no(field.is_required),
+            cell(&match_info.target_fields.join(", ")),
+            mapping_type_label(primary_match_type(match_info)),
+        );
+    }
+}
+
+fn write_unmatched_fields(
+    out: &mut String,
+    state: &State,
+    source_fields: &[FieldMetadata],
+    target_fields: &[FieldMetadata],
+    mapped_targets: &HashSet<&str>,
+) {
+    let _ = writeln!(out, "\n## Unmatched Fields");
+
+    let sides = [
+        ("Source", &state.source_entity, source_fields.iter()
+            .filter(|f| !state.field_matches.contains_key(&f.logical_name))
+            .collect::<Vec<_>>()),
+        ("Target", &state.target_entity, target_fields.iter()
+            .filter(|f| !mapped_targets.contains(f.logical_name.as_str()))
+            .collect::<Vec<_>>()),
+    ];
+
+    for (side, entity, mut unmatched) in sides {
+        let _ = writeln!(out, "\n### {} ({})\n", side, entity);
+        if unmatched.is_empty() {
+            let _ = writeln!(out, "_All fields mapped_");
+            continue;
+        }
+        // Required fields first, they need attention
+        unmatched.sort_by_key(|f| (!f.is_required, f.logical_name.clone()));
+
+        let _ = writeln!(out, "| Field | Type | Required | Primary Key |");
+        let _ = writeln!(out, "|---|---|---|---|");
+        for field in unmatched {
+            let _ = writeln!(
+                out,
+                "| {} | {:?} | {} | {} |",
+                cell(&field.logical_name),
+                field.field_type,
+                yes_no(field.is_required),
+                yes_no(field.is_primary_key),
+            );
+        }
+    }
+}
+
+fn write_prefix_mappings(out: &mut String, state: &State) {
+    let _ = writeln!(out, "\n## Prefix Mappings\n");
+
+    if state.prefix_mappings.is_empty() {
+        let _ = writeln!(out, "_No prefix mappings configured_");
+        return;
+    }
+
+    let mut prefixes: Vec<_> = state.prefix_mappings.iter().collect();
+    prefixes.sort_by_key(|(source, _)| source.as_str());
+
+    let _ = writeln!(out, "| Source Prefix | Target Prefix(es) |");
+    let _ = writeln!(out, "|---|---|");
+    for (source, targets) in prefixes {
+        let _ = writeln!(out, "| {} | {} |", cell(source), cell(&targets.join(", ")));
+    }
+}
+
+fn write_examples(out: &mut String, state: &State, source_fields: &[FieldMetadata]) {
+    let _ = writeln!(out, "\n## Examples\n");
+
+    let Some(active) = state.examples.get_active_pair() else {
+        let _ = writeln!(out, "_No examples configured_");
+        return;
+    };
+
+    // Values come from the active pair, as in the Excel sheet
+    let _ = writeln!(out, "Example: {}\n", cell(&active.display_name()));
+    let _ = writeln!(out, "| Field | Source Value ({}) | Target Value ({}) | Status |", state.source_entity, state.target_entity);
+    let _ = writeln!(out, "|---|---|---|---|");
+
+    for field in source_fields {
+        let Some(match_info) = state.field_matches.get(&field.logical_name) else {
+            continue;
+        };
+        let target_name = match_info.target_fields.first()
+            .map(|tf| tf.split('/').next_back().unwrap_or(tf))
+            .unwrap_or("");
+
+        let source_value = state.examples.get_field_value(&field.logical_name, true, &state.source_entity);
+        let target_value = state.examples.get_field_value(target_name, false, &state.target_entity);
+        let status = match (&source_value, &target_value) {
+            (Some(s), Some(t)) if s == t => "Values Match",
+            (Some(_), Some(_)) => "Values Differ",
+            _ => "Missing Data",
+        };
+
+        let _ = writeln!(
+            out,
+            "| {} → {} | {} | {} | {} |",
+            cell(&field.logical_name),
+            cell(&match_info.target_fields.join(", ")),
+            cell(source_value.as_deref().unwrap_or("")),
+            cell(target_value.as_deref().unwrap_or("")),
+            status,
+        );
+    }
+}
+
+fn fields(metadata: &Resource<crate::api::metadata::EntityMetadata, String>) -> &[FieldMetadata] {
+    match metadata {
+        Resource::Success(metadata) => &metadata.fields,
+        _ => &[],
+    }
+}
+
+/// Match type of the primary target, which decides the field's group
+fn primary_match_type(match_info: &MatchInfo) -> MatchType {
+    match_info.primary_target()
+        .and_then(|primary| match_info.match_types.get(primary))
+        .copied()
+        .unwrap_or(MatchType::Manual)
+}
+
+/// Match type of the first source mapped onto a target field, if any
+fn target_match_type(state: &State, target_field: &str) -> Option<MatchType> {
+    let mut sources: Vec<_> = state.field_matches.iter()
+        .filter_map(|(source, m)| m.match_types.get(target_field).map(|mt| (source, *mt)))
+        .collect();
+    sources.sort_by_key(|(source, _)| source.as_str());
+    sources.first().map(|(_, mt)| *mt)
+}
+
+fn group_order(match_type: MatchType) -> u8 {
+    match match_type {
+        MatchType::Exact => 0,
+        MatchType::Manual | MatchType::Import => 1,
+        MatchType::Prefix | MatchType::Related | MatchType::Fuzzy => 2,
+        MatchType::TypeMismatch => 3,
+        MatchType::ExampleValue => 4,
+    }
+}
+
+fn mapping_type_label(match_type: MatchType) -> &'static str {
+    match_type.label().trim_matches(['[', ']'])
+}
+
+fn yes_no(value: bool) -> &'static str {
+    if value { "Yes" } else { "No" }
+}
+
+/// Escape a value for use inside a table cell
+fn cell(value: &str) -> String {
+    value.replace('|', "\\|").replace(['\r', '\n'], " ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::metadata::{EntityMetadata, FieldType};
+
+    fn field(name: &str, is_required: bool) -> FieldMetadata {
+        FieldMetadata {
+            logical_name: name.to_string(),
+            display_name: None,
+            field_type: FieldType::String,
+            is_required,
+            is_primary_key: false,
+            max_length: None,
+            related_entity: None,
+            option_set: None,
+        }
+    }
+
+    #[test]
+    fn test_report_has_summary_and_tables() {
+        let mut state = State::default();
+        state.migration_name = "Accounts".to_string();
+        state.source_entity = "account".to_string();
+        state.target_entity = "nrq_account".to_string();
+        state.source_metadata = Resource::Success(EntityMetadata {
+            fields: vec![field("name", true), field("code", false), field("notes", false)],
+            ..Default::default()
+        });
+        state.target_metadata = Resource::Success(EntityMetadata {
+            fields: vec![field("name", true), field("nrq_code", false), field("extra|old", true)],
+            ..Default::default()
+        });
+        state.field_matches.insert("name".to_string(), MatchInfo::single("name".to_string(), MatchType::Exact, 1.0));
+        state.field_matches.insert("code".to_string(), MatchInfo::single("nrq_code".to_string(), MatchType::Prefix, 0.9));
+        state.prefix_mappings.insert("cgk_".to_string(), vec!["nrq_".to_string()]);
+
+        let report = render_report(&state);
+
+        assert!(report.starts_with("# Migration Report: Accounts\n"));
+        assert!(report.contains("| Entity | account | nrq_account |"));
+        assert!(report.contains("| Fields | 3 | 3 |"));
+        assert!(report.contains("| Mapped | 2 | 2 |"));
+        assert!(report.contains("| Prefix | 1 | 1 |"));
+        assert!(report.contains("| Unmapped | 1 | 1 |"));
+        assert!(report.contains("| name | String | Yes | name | Exact |\n| code | String | No | nrq_code | Prefix |"));
+        assert!(report.contains("### Source (account)\n\n| Field | Type | Required | Primary Key |\n|---|---|---|---|\n| notes | String | No | No |"));
+        assert!(report.contains(r"| extra\|old | String | Yes | No |"));
+        assert!(report.contains("| cgk_ | nrq_ |"));
+        assert!(report.contains("_No examples configured_"));
+    }
+}
@@ -1,7 +1,9 @@
 //! Excel export functionality for migration analysis
 
+mod csharp;
 mod formatting;
 mod helpers;
+mod markdown;
 pub mod sheets;
 
 use anyhow::{Context, Result};
@@ -11,6 +13,9 @@ use super::app::State;
 use sheets::*;
 use helpers::try_open_file;
 
+pub use csharp::CsharpExporter;
+pub use markdown::MarkdownExporter;
+
 /// Excel export functionality for migration analysis
 pub struct MigrationExporter;
 
@@ -27,19 +32,25 @@ impl MigrationExporter {
         let mut workbook = Workbook::new();
 
         // Create all worksheets
-        create_source_entity_sheet(&mut workbook, state)?;
-        create_target_entity_sheet(&mut workbook, state)?;
+        let source_fields = create_source_entity_sheet(&mut workbook, state)?;
+        let target_fields = create_target_entity_sheet(&mut workbook, state)?;
         create_source_relationships_sheet(&mut workbook, state)?;
         create_target_relationships_sheet(&mut workbook, state)?;
         create_source_views_sheet(&mut workbook, state)?;
         create_target_views_sheet(&mut workbook, state)?;
         create_source_forms_sheet(&mut workbook, state)?;
         create_target_forms_sheet(&mut workbook, state)?;
-        create_source_entities_sheet(&mut workbook, state)?;
-        create_target_entities_sheet(&mut workbook, state)?;
+        let source_entities = create_source_entities_sheet(&mut workbook, state)?;
+        let target_entities = create_target_entities_sheet(&mut workbook, state)?;
         create_examples_sheet(&mut workbook, state)?;
         create_source_examples_sheet(&mut workbook, state)?;
         create_target_examples_sheet(&mut workbook, state)?;
+        create_summary_sheet(&mut workbook, state, &[
+            ("Source Entity", source_fields),
+            ("Target Entity", target_fields),
+            ("Source Entities", source_entities),
+            ("Target Entities", target_entities),
+        ])?;
 
         workbook
             .save(file_path)
@@ -49,3 +60,106 @@ impl MigrationExporter {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Read;
+    use calamine::{open_workbook, Data, Reader, Xlsx};
+    use crate::api::metadata::{EntityMetadata, FieldMetadata, FieldType};
+    use crate::tui::Resource;
+    use super::super::models::{MatchInfo, MatchType};
+
+    fn field(name: &str) -> FieldMetadata {
+        FieldMetadata {
+            logical_name: name.to_string(),
+            display_name: None,
+            field_type: FieldType::String,
+            is_required: false,
+            is_primary_key: false,
+            max_length: None,
+            related_entity: None,
+            option_set: None,
+        }
+    }
+
+    fn metadata(fields: &[&str]) -> Resource<EntityMetadata, String> {
+        Resource::Success(EntityMetadata {
+            fields: fields.iter().map(|name| field(name)).collect(),
+            ..Default::default()
+        })
+    }
+
+    fn state() -> State {
+        let mut state = State::default();
+        state.source_entity = "account".to_string();
+        state.target_entity = "nrq_account".to_string();
+        state.source_metadata = metadata(&["name", "code", "notes"]);
+        state.target_metadata = metadata(&["name", "nrq_code", "extra"]);
+        state.field_matches.insert("name".to_string(), MatchInfo::single("name".to_string(), MatchType::Exact, 1.0));
+        state.field_matches.insert("code".to_string(), MatchInfo::single("nrq_code".to_string(), MatchType::Prefix, 0.9));
+        state.source_entities = vec![("contact".to_string(), 3), ("lead".to_string(), 2)];
+        state.entity_matches.insert(
+            "entity_contact".to_string(),
+            MatchInfo::single("entity_contact".to_string(), MatchType::Exact, 1.0),
+        );
+        state
+    }
+
+    fn row_of(range: &calamine::Range<Data>, label: &str) -> Vec<Data> {
+        range.rows()
+            .find(|row| row.first().is_some_and(|cell| cell.to_string().trim() == label))
+            .unwrap_or_else(|| panic!("no row labelled {}", label))
+            .to_vec()
+    }
+
+    fn zip_entry(path: &std::path::Path, name: &str) -> String {
+        let mut archive = zip::ZipArchive::new(std::fs::File::open(path).unwrap()).unwrap();
+        let mut content = String::new();
+        archive.by_name(name).unwrap().read_to_string(&mut content).unwrap();
+        content
+    }
+
+    #[test]
+    fn test_export_writes_totals_summary_and_highlights() {
+        let path = std::env::temp_dir().join(format!("export_test_{}.xlsx", uuid::Uuid::new_v4()));
+        MigrationExporter::export_to_excel(&state(), path.to_str().unwrap()).unwrap();
+
+        let mut workbook: Xlsx<_> = open_workbook(&path).unwrap();
+
+        // Per-sheet totals block counts rows by mapping status
+        let source = workbook.worksheet_range("Source Entity").unwrap();
+        assert_eq!(row_of(&source, "Exact")[1], Data::Float(1.0));
+        assert_eq!(row_of(&source, "Prefix")[1], Data::Float(1.0));
+        assert_eq!(row_of(&source, "Unmapped")[1], Data::Float(1.0));
+        assert_eq!(row_of(&source, "Total")[1], Data::Float(3.0));
+
+        // Usage counts are numbers with a SUM row under them
+        let entities = workbook.worksheet_range("Source Entities").unwrap();
+        assert_eq!(row_of(&entities, "contact")[1], Data::Float(3.0));
+        let formulas = workbook.worksheet_formula("Source Entities").unwrap();
+        assert!(formulas.used_cells().any(|(_, _, f)| f == "SUM(B4:B8)"), "missing usage SUM");
+
+        // Summary aggregates every entity sheet, with a SUM per numeric column
+        let summary = workbook.worksheet_range("Summary").unwrap();
+        let target = row_of(&summary, "Target Entity");
+        assert_eq!(target[1..], [1.0, 0.0, 1.0, 0.0, 0.0, 2.0, 1.0, 3.0].map(Data::Float));
+        let totals = row_of(&summary, "Total");
+        assert_eq!(totals[1], Data::Float(3.0));
+        assert_eq!(totals[7], Data::Float(3.0));
+        assert_eq!(totals[8], Data::Float(8.0));
+
+        // Mapping Type column carries the conditional highlights, Source Entity being the first sheet
+        let sheet_xml = zip_entry(&path, "xl/worksheets/sheet1.xml");
+        assert!(sheet_xml.contains(r#"<conditionalFormatting sqref="F4:F"#));
+        for text in ["Unmapped", "Prefix", "Exact"] {
+            assert!(sheet_xml.contains(&format!(r#"text="{}""#, text)), "missing {} highlight", text);
+        }
+        let styles_xml = zip_entry(&path, "xl/styles.xml");
+        for color in ["FFC6EFCE", "FFFFEB9C", "FFFFC7CE"] {
+            assert!(styles_xml.contains(color), "missing fill {}", color);
+        }
+
+        std::fs::remove_file(&path).ok();
+    }
+}
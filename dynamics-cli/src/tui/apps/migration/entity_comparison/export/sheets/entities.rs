@@ -9,10 +9,10 @@ use crate::tui::Resource;
 use super::super::super::app::State;
 use super::super::super::models::{MatchInfo, MatchType};
 use super::super::formatting::*;
-use super::super::helpers::write_field_row;
+use super::super::helpers::{add_mapping_highlights, write_field_row, write_totals, MappingCounts};
 
 /// Create source entity detail sheet with mapping information
-pub fn create_source_entity_sheet(workbook: &mut Workbook, state: &State) -> Result<()> {
+pub fn create_source_entity_sheet(workbook: &mut Workbook, state: &State) -> Result<MappingCounts> {
         let sheet = workbook.add_worksheet();
         sheet.set_name("Source Entity")?;
 
@@ -42,6 +42,7 @@ pub fn create_source_entity_sheet(workbook: &mut Workbook, state: &State) -> Res
         let unmapped_format = create_unmapped_format();
         let required_format = create_required_format();
         let indent_format = Format::new().set_indent(1);
+        let mut counts = MappingCounts::default();
 
         // Get source fields
         let source_fields = match &state.source_metadata {
@@ -49,7 +50,7 @@ pub fn create_source_entity_sheet(workbook: &mut Workbook, state: &State) -> Res
             _ => {
                 sheet.write_string(row, 0, "No metadata loaded")?;
                 sheet.autofit();
-                return Ok(());
+                return Ok(counts);
             }
         };
 
@@ -95,7 +96,7 @@ pub fn create_source_entity_sheet(workbook: &mut Workbook, state: &State) -> Res
                         .and_then(|m| {
                             m.primary_target().and_then(|primary| m.match_types.get(primary))
                         })
-                        .map(|mt| mt == &MatchType::Prefix)
+                        .map(|mt| matches!(mt, MatchType::Prefix | MatchType::Fuzzy))
                         .unwrap_or(false)
                 })
                 .collect();
@@ -124,6 +125,12 @@ pub fn create_source_entity_sheet(workbook: &mut Workbook, state: &State) -> Res
                 })
                 .collect();
 
+            counts.exact = exact_matches.len() as u32;
+            counts.manual = manual_mappings.len() as u32;
+            counts.prefix = prefix_matches.len() as u32;
+            counts.type_mismatch = type_mismatches.len() as u32;
+            counts.example = example_matches.len() as u32;
+
             // Exact Matches
             if !exact_matches.is_empty() {
                 sheet.write_string_with_format(row, 0, "  Exact Name + Type Matches", &Format::new().set_bold())?;
@@ -156,13 +163,17 @@ pub fn create_source_entity_sheet(workbook: &mut Workbook, state: &State) -> Res
 
             // Prefix Matches
             if !prefix_matches.is_empty() {
-                sheet.write_string_with_format(row, 0, "  Prefix Matches", &Format::new().set_bold())?;
+                sheet.write_string_with_format(row, 0, "  Prefix / Fuzzy Matches", &Format::new().set_bold())?;
                 row += 1;
 
                 for field in prefix_matches {
                     if let Some(match_info) = state.field_matches.get(&field.logical_name) {
                         let target_fields_str = match_info.target_fields.join(", ");
-                        write_field_row(sheet, row, field, &target_fields_str, "Prefix", &prefix_match_format, &indent_format)?;
+                        let fuzzy = match_info.primary_target()
+                            .and_then(|primary| match_info.match_types.get(primary))
+                            .is_some_and(|mt| mt == &MatchType::Fuzzy);
+                        let label = if fuzzy { "Fuzzy" } else { "Prefix" };
+                        write_field_row(sheet, row, field, &target_fields_str, label, &prefix_match_format, &indent_format)?;
                         row += 1;
                     }
                 }
@@ -204,6 +215,7 @@ pub fn create_source_entity_sheet(workbook: &mut Workbook, state: &State) -> Res
         if !unmapped_fields.is_empty() {
             sheet.write_string_with_format(row, 0, "⚠ UNMAPPED FIELDS", &header_format)?;
             row += 1;
+            counts.unmapped = unmapped_fields.len() as u32;
 
             // Group unmapped by characteristics
             let required_fields: Vec<_> = unmapped_fields.iter().filter(|f| f.is_required).collect();
@@ -243,12 +255,15 @@ pub fn create_source_entity_sheet(workbook: &mut Workbook, state: &State) -> Res
             }
         }
 
+        add_mapping_highlights(sheet, 3, row - 1, 5)?;
+        write_totals(sheet, row + 1, &counts)?;
+
         sheet.autofit();
-        Ok(())
+        Ok(counts)
     }
 
 /// Create target entity detail sheet with mapping information
-pub fn create_target_entity_sheet(workbook: &mut Workbook, state: &State) -> Result<()> {
+pub fn create_target_entity_sheet(workbook: &mut Workbook, state: &State) -> Result<MappingCounts> {
         let sheet = workbook.add_worksheet();
         sheet.set_name("Target Entity")?;
 
@@ -276,13 +291,14 @@ pub fn create_target_entity_sheet(workbook: &mut Workbook, state: &State) -> Res
         let unmapped_format = create_unmapped_format();
         let required_format = create_required_format();
         let indent_format = Format::new().set_indent(1);
+        let mut counts = MappingCounts::default();
 
         let target_fields = match &state.target_metadata {
             Resource::Success(metadata) => &metadata.fields,
             _ => {
                 sheet.write_string(row, 0, "No metadata loaded")?;
                 sheet.autofit();
-                return Ok(());
+                return Ok(counts);
             }
         };
 
@@ -337,7 +353,7 @@ pub fn create_target_entity_sheet(workbook: &mut Workbook, state: &State) -> Res
                     .and_then(|(_, m)| {
                         m.primary_target().and_then(|primary| m.match_types.get(primary))
                     })
-                    .map(|mt| mt == &MatchType::Prefix)
+                    .map(|mt| matches!(mt, MatchType::Prefix | MatchType::Fuzzy))
                     .unwrap_or(false)
             }).collect();
 
@@ -351,6 +367,11 @@ pub fn create_target_entity_sheet(workbook: &mut Workbook, state: &State) -> Res
                     .unwrap_or(false)
             }).collect();
 
+            counts.exact = exact_matches.len() as u32;
+            counts.manual = manual_mappings.len() as u32;
+            counts.prefix = prefix_matches.len() as u32;
+            counts.type_mismatch = type_mismatches.len() as u32;
+
             if !exact_matches.is_empty() {
                 sheet.write_string_with_format(row, 0, "  Exact Name + Type Matches", &Format::new().set_bold())?;
                 row += 1;
@@ -380,13 +401,17 @@ pub fn create_target_entity_sheet(workbook: &mut Workbook, state: &State) -> Res
             }
 
             if !prefix_matches.is_empty() {
-                sheet.write_string_with_format(row, 0, "  Prefix Matches", &Format::new().set_bold())?;
+                sheet.write_string_with_format(row, 0, "  Prefix / Fuzzy Matches", &Format::new().set_bold())?;
                 row += 1;
                 for field in prefix_matches {
                     if let Some(sources) = reverse_matches.get(&field.logical_name) {
                         let source_names: Vec<&str> = sources.iter().map(|(name, _)| name.as_str()).collect();
                         let source_names_str = source_names.join(", ");
-                        write_field_row(sheet, row, field, &source_names_str, "Prefix", &prefix_match_format, &indent_format)?;
+                        let fuzzy = sources.first()
+                            .and_then(|(_, m)| m.primary_target().and_then(|primary| m.match_types.get(primary)))
+                            .is_some_and(|mt| mt == &MatchType::Fuzzy);
+                        let label = if fuzzy { "Fuzzy" } else { "Prefix" };
+                        write_field_row(sheet, row, field, &source_names_str, label, &prefix_match_format, &indent_format)?;
                         row += 1;
                     }
                 }
@@ -412,6 +437,7 @@ pub fn create_target_entity_sheet(workbook: &mut Workbook, state: &State) -> Res
         if !unmapped_fields.is_empty() {
             sheet.write_string_with_format(row, 0, "⚠ UNMAPPED FIELDS", &header_format)?;
             row += 1;
+            counts.unmapped = unmapped_fields.len() as u32;
 
             let required_fields: Vec<_> = unmapped_fields.iter().filter(|f| f.is_required).collect();
             let primary_key_fields: Vec<_> = unmapped_fields.iter().filter(|f| f.is_primary_key && !f.is_required).collect();
@@ -447,7 +473,10 @@ pub fn create_target_entity_sheet(workbook: &mut Workbook, state: &State) -> Res
             }
         }
 
+        add_mapping_highlights(sheet, 3, row - 1, 5)?;
+        write_totals(sheet, row + 1, &counts)?;
+
         sheet.autofit();
-        Ok(())
+        Ok(counts)
     }
 
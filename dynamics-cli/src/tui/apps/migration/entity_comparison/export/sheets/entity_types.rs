@@ -6,8 +6,9 @@ use rust_xlsxwriter::*;
 use super::super::super::app::State;
 use super::super::super::models::{MatchInfo, MatchType};
 use super::super::formatting::*;
+use super::super::helpers::{add_mapping_highlights, write_totals, MappingCounts};
 
-pub fn create_source_entities_sheet(workbook: &mut Workbook, state: &State) -> Result<()> {
+pub fn create_source_entities_sheet(workbook: &mut Workbook, state: &State) -> Result<MappingCounts> {
     let sheet = workbook.add_worksheet();
     sheet.set_name("Source Entities")?;
 
@@ -33,6 +34,7 @@ pub fn create_source_entities_sheet(workbook: &mut Workbook, state: &State) -> R
     let example_value_format = create_example_value_format();
     let unmapped_format = create_unmapped_format();
     let indent_format = Format::new().set_indent(1);
+    let mut counts = MappingCounts::default();
 
     if state.source_entities.is_empty() {
         sheet.write_string(row, 0, "No related entities found")?;
@@ -63,6 +65,8 @@ pub fn create_source_entities_sheet(workbook: &mut Workbook, state: &State) -> R
                         MatchType::Manual => ("Manual", &manual_mapping_format),
                         MatchType::Import => ("Import", &manual_mapping_format),
                         MatchType::Prefix => ("Prefix", &prefix_match_format),
+                        MatchType::Related => ("Related", &prefix_match_format),
+                        MatchType::Fuzzy => ("Fuzzy", &prefix_match_format),
                         MatchType::ExampleValue => ("Example", &example_value_format),
                         MatchType::TypeMismatch => ("Type Mismatch", &unmapped_format),
                     };
@@ -70,9 +74,10 @@ pub fn create_source_entities_sheet(workbook: &mut Workbook, state: &State) -> R
                     let target_fields_str = match_info.target_fields.join(", ");
 
                     sheet.write_string_with_format(row, 0, &format!("    {}", entity_name), &indent_format)?;
-                    sheet.write_string_with_format(row, 1, &usage_count.to_string(), format)?;
+                    sheet.write_number_with_format(row, 1, *usage_count as f64, format)?;
                     sheet.write_string_with_format(row, 2, &target_fields_str, format)?;
                     sheet.write_string_with_format(row, 3, mapping_type, format)?;
+                    counts.add(Some(primary_match_type));
                     row += 1;
                 }
             }
@@ -86,20 +91,25 @@ pub fn create_source_entities_sheet(workbook: &mut Workbook, state: &State) -> R
 
             for (entity_name, usage_count) in unmapped_entities {
                 sheet.write_string_with_format(row, 0, &format!("    {}", entity_name), &indent_format)?;
-                sheet.write_string_with_format(row, 1, &usage_count.to_string(), &unmapped_format)?;
+                sheet.write_number_with_format(row, 1, *usage_count as f64, &unmapped_format)?;
                 sheet.write_string_with_format(row, 2, "", &unmapped_format)?;
                 sheet.write_string_with_format(row, 3, "Unmapped", &unmapped_format)?;
+                counts.add(None);
                 row += 1;
             }
         }
+
+        row = write_usage_total(sheet, row, &state.source_entities)?;
+        add_mapping_highlights(sheet, 3, row - 1, 3)?;
+        write_totals(sheet, row + 1, &counts)?;
     }
 
     sheet.autofit();
-    Ok(())
+    Ok(counts)
 }
 
 /// Create target entities sheet (NEW - not in legacy version)
-pub fn create_target_entities_sheet(workbook: &mut Workbook, state: &State) -> Result<()> {
+pub fn create_target_entities_sheet(workbook: &mut Workbook, state: &State) -> Result<MappingCounts> {
     let sheet = workbook.add_worksheet();
     sheet.set_name("Target Entities")?;
 
@@ -125,6 +135,7 @@ pub fn create_target_entities_sheet(workbook: &mut Workbook, state: &State) -> R
     let example_value_format = create_example_value_format();
     let unmapped_format = create_unmapped_format();
     let indent_format = Format::new().set_indent(1);
+    let mut counts = MappingCounts::default();
 
     // Reverse lookup for entities
     let mut reverse_entity_matches: std::collections::HashMap<String, (String, MatchInfo)> = std::collections::HashMap::new();
@@ -163,14 +174,17 @@ pub fn create_target_entities_sheet(workbook: &mut Workbook, state: &State) -> R
                         MatchType::Manual => ("Manual", &manual_mapping_format),
                         MatchType::Import => ("Import", &manual_mapping_format),
                         MatchType::Prefix => ("Prefix", &prefix_match_format),
+                        MatchType::Related => ("Related", &prefix_match_format),
+                        MatchType::Fuzzy => ("Fuzzy", &prefix_match_format),
                         MatchType::ExampleValue => ("Example", &example_value_format),
                         MatchType::TypeMismatch => ("Type Mismatch", &unmapped_format),
                     };
 
                     sheet.write_string_with_format(row, 0, &format!("    {}", entity_name), &indent_format)?;
-                    sheet.write_string_with_format(row, 1, &usage_count.to_string(), format)?;
+                    sheet.write_number_with_format(row, 1, *usage_count as f64, format)?;
                     sheet.write_string_with_format(row, 2, source_name, format)?;
                     sheet.write_string_with_format(row, 3, mapping_type, format)?;
+                    counts.add(Some(primary_match_type));
                     row += 1;
                 }
             }
@@ -184,16 +198,32 @@ pub fn create_target_entities_sheet(workbook: &mut Workbook, state: &State) -> R
 
             for (entity_name, usage_count) in unmapped_entities {
                 sheet.write_string_with_format(row, 0, &format!("    {}", entity_name), &indent_format)?;
-                sheet.write_string_with_format(row, 1, &usage_count.to_string(), &unmapped_format)?;
+                sheet.write_number_with_format(row, 1, *usage_count as f64, &unmapped_format)?;
                 sheet.write_string_with_format(row, 2, "", &unmapped_format)?;
                 sheet.write_string_with_format(row, 3, "Unmapped", &unmapped_format)?;
+                counts.add(None);
                 row += 1;
             }
         }
+
+        row = write_usage_total(sheet, row, &state.target_entities)?;
+        add_mapping_highlights(sheet, 3, row - 1, 3)?;
+        write_totals(sheet, row + 1, &counts)?;
     }
 
     sheet.autofit();
-    Ok(())
+    Ok(counts)
+}
+
+/// Write a total row under the Usage Count column, returning the row after it
+fn write_usage_total(sheet: &mut Worksheet, row: u32, entities: &[(String, usize)]) -> Result<u32> {
+    let totals_format = create_totals_format();
+    let usage: usize = entities.iter().map(|(_, count)| count).sum();
+    let formula = Formula::new(format!("=SUM(B4:B{})", row)).set_result(usage.to_string());
+
+    sheet.write_string_with_format(row, 0, "    Total", &totals_format)?;
+    sheet.write_formula_with_format(row, 1, formula, &totals_format)?;
+    Ok(row + 1)
 }
 
 // Placeholder for examples sheets - will implement separately due to size
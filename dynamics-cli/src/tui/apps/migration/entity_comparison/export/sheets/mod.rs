@@ -6,6 +6,7 @@ pub mod views;
 pub mod forms;
 pub mod entity_types;
 pub mod examples;
+pub mod summary;
 
 pub use entities::{create_source_entity_sheet, create_target_entity_sheet};
 pub use relationships::{create_source_relationships_sheet, create_target_relationships_sheet};
@@ -13,3 +14,4 @@ pub use views::{create_source_views_sheet, create_target_views_sheet};
 pub use forms::{create_source_forms_sheet, create_target_forms_sheet};
 pub use entity_types::{create_source_entities_sheet, create_target_entities_sheet};
 pub use examples::{create_examples_sheet, create_source_examples_sheet, create_target_examples_sheet};
+pub use summary::create_summary_sheet;
@@ -6,6 +6,7 @@ use rust_xlsxwriter::*;
 use crate::api::metadata::RelationshipMetadata;
 use crate::tui::Resource;
 use super::super::super::app::State;
+use super::super::super::models::MatchType;
 use super::super::formatting::*;
 
 pub fn create_source_relationships_sheet(workbook: &mut Workbook, state: &State) -> Result<()> {
@@ -22,13 +23,17 @@ pub fn create_source_relationships_sheet(workbook: &mut Workbook, state: &State)
         &title_format,
     )?;
 
-    let headers = ["Relationship Name", "Related Entity", "Type", "Schema Name"];
+    let headers = ["Relationship Name", "Related Entity", "Type", "Schema Name", "Mapped To", "Mapping Type"];
     for (col, header) in headers.iter().enumerate() {
         sheet.write_string_with_format(2, col as u16, *header, &header_format)?;
     }
 
     let mut row = 3u32;
     let relationship_format = create_relationship_format();
+    let exact_match_format = create_exact_match_format();
+    let manual_mapping_format = create_manual_mapping_format();
+    let prefix_match_format = create_prefix_match_format();
+    let unmapped_format = create_unmapped_format();
     let indent_format = Format::new().set_indent(1);
 
     let relationships = match &state.source_metadata {
@@ -51,6 +56,32 @@ pub fn create_source_relationships_sheet(workbook: &mut Workbook, state: &State)
             sheet.write_string_with_format(row, 1, &rel.related_entity, &relationship_format)?;
             sheet.write_string_with_format(row, 2, &format!("{:?}", rel.relationship_type), &relationship_format)?;
             sheet.write_string_with_format(row, 3, &rel.related_attribute, &relationship_format)?;
+
+            match state.relationship_matches.get(&rel.name) {
+                Some(match_info) => {
+                    let primary_match_type = match_info.primary_target()
+                        .and_then(|primary| match_info.match_types.get(primary))
+                        .copied()
+                        .unwrap_or(MatchType::Manual);
+
+                    let (mapping_type, format) = match primary_match_type {
+                        MatchType::Exact => ("Exact", &exact_match_format),
+                        MatchType::Manual => ("Manual", &manual_mapping_format),
+                        MatchType::Import => ("Import", &manual_mapping_format),
+                        MatchType::Prefix => ("Prefix", &prefix_match_format),
+                        MatchType::Related => ("Related", &prefix_match_format),
+                        MatchType::Fuzzy => ("Fuzzy", &prefix_match_format),
+                        MatchType::ExampleValue => ("Example", &manual_mapping_format),
+                        MatchType::TypeMismatch => ("Type Mismatch", &unmapped_format),
+                    };
+                    sheet.write_string_with_format(row, 4, &match_info.target_fields.join(", "), format)?;
+                    sheet.write_string_with_format(row, 5, mapping_type, format)?;
+                }
+                None => {
+                    sheet.write_string_with_format(row, 4, "", &unmapped_format)?;
+                    sheet.write_string_with_format(row, 5, "Unmapped", &unmapped_format)?;
+                }
+            }
             row += 1;
         }
     }
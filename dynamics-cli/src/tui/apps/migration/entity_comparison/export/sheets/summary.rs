@@ -0,0 +1,70 @@
+//! Summary sheet - mapping counts aggregated across the entity sheets
+
+use anyhow::Result;
+use rust_xlsxwriter::*;
+
+use super::super::super::app::State;
+use super::super::formatting::*;
+use super::super::helpers::MappingCounts;
+
+/// Create the summary sheet from the counts each entity sheet reported
+pub fn create_summary_sheet(workbook: &mut Workbook, state: &State, sheets: &[(&str, MappingCounts)]) -> Result<()> {
+    let sheet = workbook.add_worksheet();
+    sheet.set_name("Summary")?;
+
+    let header_format = create_header_format();
+    let title_format = create_title_format();
+    let totals_format = create_totals_format();
+
+    sheet.write_string_with_format(
+        0,
+        0,
+        &format!("{} → {} - Mapping Summary", state.source_entity, state.target_entity),
+        &title_format,
+    )?;
+
+    let headers = ["Sheet", "Exact", "Manual", "Prefix", "Type Mismatch", "Example", "Mapped", "Unmapped", "Total"];
+    for (col, header) in headers.iter().enumerate() {
+        sheet.write_string_with_format(2, col as u16, *header, &header_format)?;
+    }
+
+    let columns = headers.len() as u16;
+    let mut row = 3u32;
+    for (name, counts) in sheets {
+        sheet.write_string(row, 0, *name)?;
+        for col in 1..columns {
+            sheet.write_number(row, col, column_value(counts, col))?;
+        }
+        row += 1;
+    }
+
+    // Totals row, one SUM per numeric column
+    sheet.write_string_with_format(row, 0, "Total", &totals_format)?;
+    for col in 1..columns {
+        let letter = column_number_to_name(col);
+        let total: u32 = sheets.iter().map(|(_, counts)| column_value(counts, col)).sum();
+        let formula = Formula::new(format!("=SUM({letter}4:{letter}{row})")).set_result(total.to_string());
+        sheet.write_formula_with_format(row, col, formula, &totals_format)?;
+    }
+
+    // Any unmapped items are worth a look
+    if row > 3 {
+        let highlight = ConditionalFormatCell::new()
+            .set_rule(ConditionalFormatCellRule::GreaterThan(0))
+            .set_format(create_unmapped_highlight_format());
+        sheet.add_conditional_format(3, 7, row, 7, &highlight)?;
+    }
+
+    sheet.autofit();
+    Ok(())
+}
+
+/// Value of a numeric summary column for one sheet's counts
+fn column_value(counts: &MappingCounts, col: u16) -> u32 {
+    match col {
+        1..=5 => counts.rows()[col as usize - 1].1,
+        6 => counts.mapped(),
+        7 => counts.unmapped,
+        _ => counts.total(),
+    }
+}
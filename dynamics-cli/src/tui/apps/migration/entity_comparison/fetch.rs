@@ -1,6 +1,9 @@
 //! Entity metadata fetching with caching
 
 use super::FetchedData;
+use crate::api::metadata::process_lookup_fields;
+pub use crate::api::metadata::extract_relationships;
+use crate::config::repository::metadata_cache::MetadataKind;
 
 /// Type of data to fetch
 pub enum FetchType {
@@ -12,68 +15,79 @@ pub enum FetchType {
     TargetViews,
 }
 
-/// Fetch specific metadata type with optional 12-hour caching
+/// Fetch specific metadata type, reusing the metadata cache when `use_cache` is set
+///
+/// Whatever is fetched from the API is written back to the cache, so a
+/// refresh (`use_cache == false`) bypasses it and repopulates it.
 pub async fn fetch_with_cache(
     environment_name: &str,
     entity_name: &str,
     fetch_type: FetchType,
     use_cache: bool,
 ) -> Result<FetchedData, String> {
-    let config = crate::global_config();
     let manager = crate::client_manager();
-
-    // Check cache first (12 hours) - use full metadata cache, only if use_cache is true
-    if use_cache {
-        let cached_metadata = config.get_entity_metadata_cache(environment_name, entity_name, 12).await
-            .ok()
-            .flatten();
-
-        // If we have cached metadata, extract the requested type
-        // Note: Cached metadata already has relationships extracted and lookup fields removed
-        if let Some(cached) = cached_metadata {
-            return match fetch_type {
-                FetchType::SourceFields => Ok(FetchedData::SourceFields(cached.fields)),
-                FetchType::SourceForms => Ok(FetchedData::SourceForms(cached.forms)),
-                FetchType::SourceViews => Ok(FetchedData::SourceViews(cached.views)),
-                FetchType::TargetFields => Ok(FetchedData::TargetFields(cached.fields)),
-                FetchType::TargetForms => Ok(FetchedData::TargetForms(cached.forms)),
-                FetchType::TargetViews => Ok(FetchedData::TargetViews(cached.views)),
-            };
-        }
-    }
-
-    // Fetch from API
-    let client = manager.get_client(environment_name).await
-        .map_err(|e| e.to_string())?;
+    let source = matches!(fetch_type, FetchType::SourceFields | FetchType::SourceForms | FetchType::SourceViews);
 
     match fetch_type {
-        FetchType::SourceFields => {
-            let mut fields = client.fetch_entity_fields_combined(entity_name).await.map_err(|e| e.to_string())?;
-            fields = process_lookup_fields(fields);
-            Ok(FetchedData::SourceFields(fields))
-        }
-        FetchType::SourceForms => {
-            let forms = client.fetch_entity_forms(entity_name).await.map_err(|e| e.to_string())?;
-            Ok(FetchedData::SourceForms(forms))
+        FetchType::SourceFields | FetchType::TargetFields => {
+            let fields = cached(environment_name, entity_name, MetadataKind::Fields, use_cache, || async {
+                let client = manager.get_client(environment_name).await?;
+                let fields = client.fetch_entity_fields_combined(entity_name).await?;
+                Ok(process_lookup_fields(fields))
+            }).await?;
+            Ok(if source { FetchedData::SourceFields(fields) } else { FetchedData::TargetFields(fields) })
         }
-        FetchType::SourceViews => {
-            let views = client.fetch_entity_views(entity_name).await.map_err(|e| e.to_string())?;
-            Ok(FetchedData::SourceViews(views))
+        FetchType::SourceForms | FetchType::TargetForms => {
+            let forms = cached(environment_name, entity_name, MetadataKind::Forms, use_cache, || async {
+                manager.get_client(environment_name).await?.fetch_entity_forms(entity_name).await
+            }).await?;
+            Ok(if source { FetchedData::SourceForms(forms) } else { FetchedData::TargetForms(forms) })
         }
-        FetchType::TargetFields => {
-            let mut fields = client.fetch_entity_fields_combined(entity_name).await.map_err(|e| e.to_string())?;
-            fields = process_lookup_fields(fields);
-            Ok(FetchedData::TargetFields(fields))
+        FetchType::SourceViews | FetchType::TargetViews => {
+            let views = cached(environment_name, entity_name, MetadataKind::Views, use_cache, || async {
+                manager.get_client(environment_name).await?.fetch_entity_views(entity_name).await
+            }).await?;
+            Ok(if source { FetchedData::SourceViews(views) } else { FetchedData::TargetViews(views) })
         }
-        FetchType::TargetForms => {
-            let forms = client.fetch_entity_forms(entity_name).await.map_err(|e| e.to_string())?;
-            Ok(FetchedData::TargetForms(forms))
+    }
+}
+
+/// Read one kind of metadata from the cache, or fetch and cache it
+///
+/// The cache is skipped entirely when `api.cache.metadata_ttl_hours` is 0.
+async fn cached<T, F, Fut>(
+    environment_name: &str,
+    entity_name: &str,
+    kind: MetadataKind,
+    use_cache: bool,
+    fetch: F,
+) -> Result<T, String>
+where
+    T: serde::Serialize + serde::de::DeserializeOwned,
+    F: FnOnce() -> Fut,
+    Fut: std::future::Future<Output = anyhow::Result<T>>,
+{
+    let config = crate::global_config();
+    let ttl_hours = config.options.get_uint("api.cache.metadata_ttl_hours").await.unwrap_or(12);
+    let cache_enabled = ttl_hours > 0;
+
+    if use_cache && cache_enabled {
+        let ttl = std::time::Duration::from_secs(ttl_hours * 60 * 60);
+        match config.get_metadata_cache(environment_name, entity_name, kind, ttl).await {
+            Ok(Some(data)) => return Ok(data),
+            Ok(None) => {}
+            Err(e) => log::warn!("Ignoring unreadable metadata cache for {}/{}: {}", environment_name, entity_name, e),
         }
-        FetchType::TargetViews => {
-            let views = client.fetch_entity_views(entity_name).await.map_err(|e| e.to_string())?;
-            Ok(FetchedData::TargetViews(views))
+    }
+
+    let data = fetch().await.map_err(|e| e.to_string())?;
+
+    if cache_enabled {
+        if let Err(e) = config.set_metadata_cache(environment_name, entity_name, kind, &data).await {
+            log::error!("Failed to cache {} for {}/{}: {}", kind.as_str(), environment_name, entity_name, e);
         }
     }
+    Ok(data)
 }
 
 /// Extract unique entity types from relationships with usage counts
@@ -98,95 +112,6 @@ pub fn extract_entities(relationships: &[crate::api::metadata::RelationshipMetad
     entities
 }
 
-/// Extract relationships from field list
-/// Includes all Lookup fields and NavigationProperties (collection relationships)
-pub fn extract_relationships(fields: &[crate::api::metadata::FieldMetadata]) -> Vec<crate::api::metadata::RelationshipMetadata> {
-    fields.iter()
-        .filter_map(|f| {
-            // Check for Lookup (ManyToOne) or Relationship:* (OneToMany/ManyToMany)
-            let relationship_type = match &f.field_type {
-                crate::api::metadata::FieldType::Lookup => {
-                    Some(crate::api::metadata::RelationshipType::ManyToOne)
-                }
-                crate::api::metadata::FieldType::Other(t) if t.starts_with("Relationship:") => {
-                    // Extract relationship type from "Relationship:OneToMany"
-                    if t.contains("OneToMany") {
-                        Some(crate::api::metadata::RelationshipType::OneToMany)
-                    } else if t.contains("ManyToMany") {
-                        Some(crate::api::metadata::RelationshipType::ManyToMany)
-                    } else {
-                        Some(crate::api::metadata::RelationshipType::ManyToOne)
-                    }
-                }
-                _ => None,
-            };
-
-            relationship_type.map(|rel_type| crate::api::metadata::RelationshipMetadata {
-                name: f.logical_name.clone(),
-                relationship_type: rel_type,
-                related_entity: f.related_entity.clone().unwrap_or_else(|| "unknown".to_string()),
-                related_attribute: f.logical_name.clone(),
-            })
-        })
-        .collect()
-}
-
-/// Process lookup fields by filtering out _*_value virtual fields and ensuring
-/// base lookup fields are properly typed
-fn process_lookup_fields(fields: Vec<crate::api::metadata::FieldMetadata>) -> Vec<crate::api::metadata::FieldMetadata> {
-    use std::collections::HashMap;
-
-    // Build map of _*_value fields to their related entity info
-    let mut value_field_map: HashMap<String, Option<String>> = HashMap::new();
-
-    for field in &fields {
-        // Detect _*_value pattern
-        if field.logical_name.starts_with('_') && field.logical_name.ends_with("_value") {
-            // Extract base field name: _cgk_deadlineid_value -> cgk_deadlineid
-            if let Some(base_name) = field.logical_name
-                .strip_prefix('_')
-                .and_then(|s| s.strip_suffix("_value"))
-            {
-                value_field_map.insert(base_name.to_string(), field.related_entity.clone());
-            }
-        }
-    }
-
-    // Filter and update fields
-    fields.into_iter()
-        .filter_map(|mut field| {
-            // Filter out _*_value fields
-            if field.logical_name.starts_with('_') && field.logical_name.ends_with("_value") {
-                log::debug!("Filtering out virtual lookup value field: {}", field.logical_name);
-                return None;
-            }
-
-            // Filter out Virtual type fields (formatted display values)
-            // These include *name, *yominame suffixes for lookups and optionsets
-            if matches!(field.field_type, crate::api::metadata::FieldType::Other(ref t) if t == "Virtual") {
-                log::debug!("Filtering out virtual display field: {}", field.logical_name);
-                return None;
-            }
-
-            // Check if this field has a corresponding _*_value field
-            if let Some(related_entity) = value_field_map.get(&field.logical_name) {
-                // Ensure field is marked as Lookup
-                if !matches!(field.field_type, crate::api::metadata::FieldType::Lookup) {
-                    log::debug!("Converting field {} to Lookup type (found _*_value)", field.logical_name);
-                    field.field_type = crate::api::metadata::FieldType::Lookup;
-                }
-
-                // Update related_entity if we extracted it from the _value field
-                if field.related_entity.is_none() && related_entity.is_some() {
-                    field.related_entity = related_entity.clone();
-                }
-            }
-
-            Some(field)
-        })
-        .collect()
-}
-
 /// Fetch example record data for a pair
 pub async fn fetch_example_pair_data(
     source_env: &str,
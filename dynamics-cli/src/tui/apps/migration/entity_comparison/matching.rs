@@ -1,6 +1,6 @@
 //! Matching logic for fields, relationships, and containers
 
-use super::models::{MatchInfo, MatchType};
+use super::models::{MatchAlgorithm, MatchInfo, MatchSettings, MatchType};
 use crate::api::metadata::{FieldMetadata, RelationshipMetadata};
 use std::collections::HashMap;
 
@@ -126,7 +126,7 @@ fn find_example_value_match(
 
 /// Compute field matches between source and target
 /// Returns map of source_field_name -> MatchInfo
-/// Priority: Manual > Import > Exact > Prefix > Example
+/// Priority: Manual > Import > Exact > Prefix > Example > Fuzzy
 pub fn compute_field_matches(
     source_fields: &[FieldMetadata],
     target_fields: &[FieldMetadata],
@@ -136,6 +136,7 @@ pub fn compute_field_matches(
     examples: &super::ExamplesState,
     source_entity: &str,
     target_entity: &str,
+    settings: MatchSettings,
 ) -> HashMap<String, MatchInfo> {
     let mut matches = HashMap::new();
 
@@ -222,7 +223,11 @@ pub fn compute_field_matches(
         }
 
         // 4. Check prefix-transformed matches (1-to-N support)
-        let transformed_names = apply_prefix_transform(source_name, prefix_mappings);
+        let transformed_names = if settings.algorithm == MatchAlgorithm::Exact {
+            Vec::new()
+        } else {
+            apply_prefix_transform(source_name, prefix_mappings)
+        };
         let mut valid_transformed = Vec::new();
         for transformed in transformed_names {
             if let Some(target_field) = target_lookup.get(&transformed) {
@@ -270,9 +275,115 @@ pub fn compute_field_matches(
         // No match found - don't insert anything
     }
 
+    // 6. Fuzzy name matches between what is left on both sides, best pairs first
+    if matches!(settings.algorithm, MatchAlgorithm::Levenshtein | MatchAlgorithm::Token) {
+        let threshold = f64::from(settings.threshold) / 100.0;
+        let mut candidates = Vec::new();
+        for source_field in source_fields.iter().filter(|f| !matches.contains_key(&f.logical_name)) {
+            for target_field in target_fields.iter().filter(|f| !already_matched.contains(&f.logical_name)) {
+                if source_field.field_type != target_field.field_type {
+                    continue;
+                }
+                let score = field_similarity(settings.algorithm, source_field, target_field);
+                if score >= threshold {
+                    candidates.push((score, source_field, target_field));
+                }
+            }
+        }
+        candidates.sort_by(|(a_score, a_source, a_target), (b_score, b_source, b_target)| {
+            b_score.total_cmp(a_score)
+                .then_with(|| a_source.logical_name.cmp(&b_source.logical_name))
+                .then_with(|| a_target.logical_name.cmp(&b_target.logical_name))
+        });
+
+        for (score, source_field, target_field) in candidates {
+            if matches.contains_key(&source_field.logical_name) || already_matched.contains(&target_field.logical_name) {
+                continue;
+            }
+            matches.insert(
+                source_field.logical_name.clone(),
+                MatchInfo::single(target_field.logical_name.clone(), MatchType::Fuzzy, score),
+            );
+            already_matched.insert(target_field.logical_name.clone());
+        }
+    }
+
     matches
 }
 
+/// Similarity (0.0-1.0) of two fields' names: the better of their logical names
+/// without publisher prefix and their display names
+fn field_similarity(algorithm: MatchAlgorithm, source: &FieldMetadata, target: &FieldMetadata) -> f64 {
+    let logical = name_similarity(
+        algorithm,
+        strip_publisher_prefix(&source.logical_name),
+        strip_publisher_prefix(&target.logical_name),
+    );
+    match (&source.display_name, &target.display_name) {
+        (Some(source_display), Some(target_display)) => logical.max(name_similarity(
+            algorithm,
+            &source_display.to_lowercase(),
+            &target_display.to_lowercase(),
+        )),
+        _ => logical,
+    }
+}
+
+fn name_similarity(algorithm: MatchAlgorithm, a: &str, b: &str) -> f64 {
+    match algorithm {
+        MatchAlgorithm::Levenshtein => {
+            let longest = a.chars().count().max(b.chars().count());
+            if longest == 0 {
+                return 1.0;
+            }
+            1.0 - levenshtein(a, b) as f64 / longest as f64
+        }
+        MatchAlgorithm::Token => {
+            // Dice coefficient over the words of each name
+            let words = |name: &str| -> std::collections::HashSet<String> {
+                name.split(|c: char| !c.is_alphanumeric())
+                    .filter(|word| !word.is_empty())
+                    .map(str::to_string)
+                    .collect()
+            };
+            let (a, b) = (words(a), words(b));
+            if a.is_empty() && b.is_empty() {
+                return 1.0;
+            }
+            2.0 * a.intersection(&b).count() as f64 / (a.len() + b.len()) as f64
+        }
+        MatchAlgorithm::Exact | MatchAlgorithm::Prefix => if a == b { 1.0 } else { 0.0 },
+    }
+}
+
+/// `cgk_deadline_date` -> `deadline_date`; names without a publisher prefix are unchanged
+fn strip_publisher_prefix(name: &str) -> &str {
+    match name.split_once('_') {
+        Some((prefix, rest)) if !prefix.is_empty() && prefix.len() <= 8 && !rest.is_empty() => rest,
+        _ => name,
+    }
+}
+
+/// Edit distance (insertions, deletions, substitutions) between two strings
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, a_char) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, b_char) in b.iter().enumerate() {
+            let above = row[j + 1];
+            row[j + 1] = if a_char == *b_char {
+                diagonal
+            } else {
+                1 + diagonal.min(above).min(row[j])
+            };
+            diagonal = above;
+        }
+    }
+    row[b.len()]
+}
+
 /// Check if two entity names match, considering entity mappings
 fn entities_match(
     source_entity: &str,
@@ -287,13 +398,29 @@ fn entities_match(
     source_entity == target_entity
 }
 
+/// Whether two relationships are the same kind (1:N, N:1, N:N) to matching related entities
+fn relationships_compatible(
+    source: &RelationshipMetadata,
+    target: &RelationshipMetadata,
+    entity_matches: &HashMap<String, MatchInfo>,
+) -> bool {
+    source.relationship_type == target.relationship_type
+        && entities_match(&source.related_entity, &target.related_entity, entity_matches)
+}
+
 /// Compute relationship matches between source and target
 /// Returns map of source_relationship_name -> MatchInfo
-/// Now entity-aware: uses entity_matches to resolve entity type mappings
+/// Entity-aware: uses entity_matches to resolve entity type mappings.
+/// Order: manual → exact → prefix → the only compatible relationship left
+///
+/// Relationship mappings made before they had their own table were saved with
+/// the field mappings, so `field_mappings` is checked when a relationship has
+/// no mapping of its own.
 pub fn compute_relationship_matches(
     source_relationships: &[RelationshipMetadata],
     target_relationships: &[RelationshipMetadata],
-    manual_mappings: &HashMap<String, Vec<String>>,
+    relationship_mappings: &HashMap<String, Vec<String>>,
+    field_mappings: &HashMap<String, Vec<String>>,
     prefix_mappings: &HashMap<String, Vec<String>>,
     entity_matches: &HashMap<String, MatchInfo>,
 ) -> HashMap<String, MatchInfo> {
@@ -309,7 +436,8 @@ pub fn compute_relationship_matches(
         let source_name = &source_rel.name;
 
         // 1. Check manual mappings first (1-to-N support)
-        if let Some(target_names) = manual_mappings.get(source_name) {
+        let manual = relationship_mappings.get(source_name).or_else(|| field_mappings.get(source_name));
+        if let Some(target_names) = manual {
             let valid_targets: Vec<String> = target_names.iter()
                 .filter(|tn| target_lookup.contains_key(*tn))
                 .cloned()
@@ -333,8 +461,7 @@ pub fn compute_relationship_matches(
         // 2. Check exact name match
         if let Some(target_rel) = target_lookup.get(source_name) {
             // Compare relationship type and related entity (entity-aware)
-            let types_match = source_rel.relationship_type == target_rel.relationship_type
-                && entities_match(&source_rel.related_entity, &target_rel.related_entity, entity_matches);
+            let types_match = relationships_compatible(source_rel, target_rel, entity_matches);
             matches.insert(
                 source_name.clone(),
                 MatchInfo::single(
@@ -352,8 +479,7 @@ pub fn compute_relationship_matches(
         for transformed in transformed_names {
             if let Some(target_rel) = target_lookup.get(&transformed) {
                 // Compare relationship type and related entity (entity-aware)
-                let types_match = source_rel.relationship_type == target_rel.relationship_type
-                    && entities_match(&source_rel.related_entity, &target_rel.related_entity, entity_matches);
+                let types_match = relationships_compatible(source_rel, target_rel, entity_matches);
                 valid_transformed.push((
                     transformed.clone(),
                     if types_match { MatchType::Prefix } else { MatchType::TypeMismatch },
@@ -377,6 +503,35 @@ pub fn compute_relationship_matches(
         }
     }
 
+    // 4. Pair what is left when exactly one unmatched relationship on each side
+    // has the same kind and related entity, so 1:N and N:N never cross-map
+    let taken: std::collections::HashSet<&String> = matches.values()
+        .flat_map(|m| m.target_fields.iter())
+        .collect();
+    let unmatched_sources: Vec<&RelationshipMetadata> = source_relationships.iter()
+        .filter(|r| !matches.contains_key(&r.name))
+        .collect();
+    let unmatched_targets: Vec<&RelationshipMetadata> = target_relationships.iter()
+        .filter(|r| !taken.contains(&r.name))
+        .collect();
+
+    let mut related = Vec::new();
+    for source_rel in &unmatched_sources {
+        let candidates: Vec<&&RelationshipMetadata> = unmatched_targets.iter()
+            .filter(|t| relationships_compatible(source_rel, t, entity_matches))
+            .collect();
+        let [target_rel] = candidates.as_slice() else { continue };
+        let rivals = unmatched_sources.iter()
+            .filter(|s| relationships_compatible(s, target_rel, entity_matches))
+            .count();
+        if rivals == 1 {
+            related.push((source_rel.name.clone(), target_rel.name.clone()));
+        }
+    }
+    for (source_name, target_name) in related {
+        matches.insert(source_name, MatchInfo::single(target_name, MatchType::Related, 0.8));
+    }
+
     matches
 }
 
@@ -734,15 +889,18 @@ fn build_metadata_paths(metadata: &crate::api::EntityMetadata, tab_type: &str) -
 
 /// Recompute field and relationship matches based on current mappings
 /// Call this after manual mappings, imported mappings, or prefix mappings change
+/// `settings` only applies to the Fields tab; other tabs always match by name and prefix
 pub fn recompute_all_matches(
     source_metadata: &crate::api::EntityMetadata,
     target_metadata: &crate::api::EntityMetadata,
     field_mappings: &HashMap<String, Vec<String>>,
     imported_mappings: &HashMap<String, Vec<String>>,
     prefix_mappings: &HashMap<String, Vec<String>>,
+    relationship_mappings: &HashMap<String, Vec<String>>,
     examples: &super::ExamplesState,
     source_entity: &str,
     target_entity: &str,
+    settings: MatchSettings,
 ) -> (
     HashMap<String, MatchInfo>,  // field_matches
     HashMap<String, MatchInfo>,  // relationship_matches
@@ -760,6 +918,7 @@ pub fn recompute_all_matches(
         examples,
         source_entity,
         target_entity,
+        settings,
     );
 
     // Hierarchical matching for Forms tab
@@ -800,6 +959,7 @@ pub fn recompute_all_matches(
     let relationship_matches = compute_relationship_matches(
         &source_metadata.relationships,
         &target_metadata.relationships,
+        relationship_mappings,
         field_mappings,
         prefix_mappings,
         &entity_matches,
@@ -807,3 +967,102 @@ pub fn recompute_all_matches(
 
     (all_field_matches, relationship_matches, entity_matches, source_entities, target_entities)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::metadata::{FieldType, RelationshipType};
+
+    fn relationship(name: &str, kind: RelationshipType, related_entity: &str) -> RelationshipMetadata {
+        RelationshipMetadata {
+            name: name.to_string(),
+            relationship_type: kind,
+            related_entity: related_entity.to_string(),
+            related_attribute: String::new(),
+        }
+    }
+
+    fn field(name: &str, display_name: Option<&str>, field_type: FieldType) -> FieldMetadata {
+        FieldMetadata {
+            logical_name: name.to_string(),
+            display_name: display_name.map(str::to_string),
+            field_type,
+            is_required: false,
+            is_primary_key: false,
+            max_length: None,
+            related_entity: None,
+            option_set: None,
+        }
+    }
+
+    #[test]
+    fn test_relationship_suggestions_respect_kind_and_related_entity() {
+        let source = vec![
+            relationship("cgk_deadline_contacts", RelationshipType::ManyToMany, "contact"),
+            relationship("cgk_deadline_owner", RelationshipType::ManyToOne, "contact"),
+            relationship("cgk_deadline_fund", RelationshipType::ManyToOne, "cgk_fund"),
+            relationship("legacy_link", RelationshipType::OneToMany, "task"),
+        ];
+        let target = vec![
+            relationship("nrq_deadline_contact", RelationshipType::ManyToOne, "contact"),
+            relationship("nrq_deadline_contacts", RelationshipType::ManyToMany, "contact"),
+            relationship("nrq_deadline_tasks", RelationshipType::OneToMany, "task"),
+        ];
+        let legacy = HashMap::from([("legacy_link".to_string(), vec!["nrq_deadline_tasks".to_string()])]);
+
+        let matches = compute_relationship_matches(&source, &target, &HashMap::new(), &legacy, &HashMap::new(), &HashMap::new());
+
+        // Each kind pairs with the only target of that kind to contact
+        assert_eq!(matches["cgk_deadline_contacts"].target_fields, vec!["nrq_deadline_contacts"]);
+        assert_eq!(matches["cgk_deadline_contacts"].match_types["nrq_deadline_contacts"], MatchType::Related);
+        assert_eq!(matches["cgk_deadline_owner"].target_fields, vec!["nrq_deadline_contact"]);
+        // No target relates to cgk_fund
+        assert!(!matches.contains_key("cgk_deadline_fund"));
+        // Mappings saved with the field mappings still apply
+        assert_eq!(matches["legacy_link"].match_types["nrq_deadline_tasks"], MatchType::Manual);
+    }
+
+    #[test]
+    fn test_fuzzy_matching_follows_settings_and_keeps_manual_mappings() {
+        let source = vec![
+            field("cgk_deadlinedate", Some("Deadline Date"), FieldType::DateTime),
+            field("cgk_description", None, FieldType::Memo),
+            field("cgk_amount", None, FieldType::Money),
+            field("cgk_owner", None, FieldType::String),
+        ];
+        let target = vec![
+            field("nrq_deadline_date", Some("Deadline date"), FieldType::DateTime),
+            field("nrq_descr", None, FieldType::Memo),
+            field("nrq_amount", None, FieldType::Integer),
+            field("nrq_title", None, FieldType::String),
+        ];
+        let manual = HashMap::from([("cgk_owner".to_string(), vec!["nrq_title".to_string()])]);
+        let examples = super::super::ExamplesState::default();
+        let run = |algorithm, threshold| compute_field_matches(
+            &source, &target, &manual, &HashMap::new(), &HashMap::new(), &examples, "cgk_deadline", "nrq_deadline",
+            MatchSettings { algorithm, threshold },
+        );
+
+        // Name-only matching finds nothing beyond the manual mapping
+        let prefix = run(MatchAlgorithm::Prefix, 80);
+        assert_eq!(prefix.len(), 1);
+        assert_eq!(prefix["cgk_owner"].match_types["nrq_title"], MatchType::Manual);
+
+        let levenshtein = run(MatchAlgorithm::Levenshtein, 80);
+        assert_eq!(levenshtein["cgk_deadlinedate"].match_types["nrq_deadline_date"], MatchType::Fuzzy);
+        assert_eq!(levenshtein["cgk_deadlinedate"].badge().as_deref(), Some("[Fuzzy] 100"));
+        // "description" is too far from "descr", and amounts differ in type
+        assert!(!levenshtein.contains_key("cgk_description"));
+        assert!(!levenshtein.contains_key("cgk_amount"));
+        assert_eq!(levenshtein["cgk_owner"].badge().as_deref(), Some("[Manual]"));
+
+        let loose = run(MatchAlgorithm::Levenshtein, 40);
+        assert_eq!(loose["cgk_description"].target_fields, vec!["nrq_descr"]);
+        assert_eq!(loose["cgk_description"].badge().as_deref(), Some("[Fuzzy] 45"));
+
+        // Words only line up in the display names
+        let token = run(MatchAlgorithm::Token, 80);
+        assert_eq!(token["cgk_deadlinedate"].target_fields, vec!["nrq_deadline_date"]);
+        assert!(!token.contains_key("cgk_description"));
+    }
+}
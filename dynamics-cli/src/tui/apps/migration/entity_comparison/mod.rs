@@ -8,6 +8,7 @@ mod view;
 mod tree_sync;
 mod update;
 mod export;
+mod session;
 
 pub use app::{EntityComparisonApp, EntityComparisonParams, State as EntityComparisonState};
 pub use models::*;
@@ -35,13 +36,19 @@ pub enum Msg {
     CycleHideMode,        // Cycle through hide modes (Off -> HideMatched -> HideIgnored -> HideBoth)
     ToggleSortMode,       // Toggle between Alphabetical and MatchesFirst sorting
     ToggleTechnicalNames, // Toggle between technical (logical) and display names
-    MappingsLoaded(std::collections::HashMap<String, Vec<String>>, std::collections::HashMap<String, Vec<String>>, std::collections::HashMap<String, Vec<String>>, Option<String>, Vec<ExamplePair>, std::collections::HashSet<String>), // field_mappings, prefix_mappings, imported_mappings, import_source_file, example_pairs, ignored_items
+    Undo,                 // Revert the last mapping/ignore edit
+    Redo,                 // Re-apply the last undone edit
+    RerunAutoMatch,       // Match again with the current auto-match settings
+    NextUnmatched,        // Jump the source selection to the next unmatched item
+    PrevUnmatched,        // Jump the source selection to the previous unmatched item
+    MappingsLoaded(std::collections::HashMap<String, Vec<String>>, std::collections::HashMap<String, Vec<String>>, std::collections::HashMap<String, Vec<String>>, std::collections::HashMap<String, Vec<String>>, Option<String>, Vec<ExamplePair>, std::collections::HashSet<String>), // field_mappings, prefix_mappings, relationship_mappings, imported_mappings, import_source_file, example_pairs, ignored_items
 
     // Examples modal messages
     OpenExamplesModal,
     CloseExamplesModal,
     ExamplesListNavigate(crossterm::event::KeyCode),
     ExamplesListSelect(usize),
+    ExamplesListEvent(crate::tui::widgets::ListEvent),
     SourceInputEvent(crate::tui::widgets::TextInputEvent),
     TargetInputEvent(crate::tui::widgets::TextInputEvent),
     LabelInputEvent(crate::tui::widgets::TextInputEvent),
@@ -50,6 +57,10 @@ pub enum Msg {
     ExampleDataFetched(String, Result<(serde_json::Value, serde_json::Value), String>), // pair_id, (source_data, target_data)
     CycleExamplePair,
     ToggleExamples,
+    OpenExampleDiff,
+    CloseExampleDiff,
+    ExampleDiffTreeEvent(crate::tui::widgets::TreeEvent),
+    ExampleDiffViewportHeight(usize),
 
     // Prefix mappings modal messages
     OpenPrefixMappingsModal,
@@ -60,6 +71,7 @@ pub enum Msg {
     PrefixTargetInputEvent(crate::tui::widgets::TextInputEvent),
     AddPrefixMapping,
     DeletePrefixMapping,
+    ApplyPrefixMapping,   // Turn the selected prefix mapping into manual mappings for unmapped fields
 
     // Manual mappings modal messages
     OpenManualMappingsModal,
@@ -82,7 +94,12 @@ pub enum Msg {
     SearchSelectFirstMatch,    // Enter in search box
 
     // Export
+    OpenExportModal,
+    CloseExportModal,
     ExportToExcel,
+    ExportToMarkdown,
+    ExportToCsharp,
+    ExportFinished(Result<String, String>),  // Exported filename, or the error
 
     // Import from C# file
     OpenImportModal,
@@ -90,6 +107,17 @@ pub enum Msg {
     ImportFileSelected(std::path::PathBuf),
     ImportMappingsLoaded(std::collections::HashMap<String, String>, String), // mappings, filename (for .cs files - gets converted to Vec in handler)
     ImportCsvLoaded(crate::csv_parser::CsvImportData, String), // csv_data, filename (for .csv files)
+    ImportCsvTableLoaded(crate::csv_parser::CsvTable, String), // table, filename (for plain source/target .csv files)
+    CsvSourceColumnEvent(crate::tui::widgets::SelectEvent),
+    CsvTargetColumnEvent(crate::tui::widgets::SelectEvent),
+    ConfirmCsvColumns,
+    CancelCsvColumns,
+    OpenComparisonPicker,
+    CloseComparisonPicker,
+    ComparisonsLoaded(Result<Vec<crate::config::SavedComparison>, String>),
+    ComparisonPickerNavigate(crossterm::event::KeyCode),
+    CopyFromComparison(usize),
+    ComparisonMappingsLoaded(String, Result<update::import::ComparisonMappings, String>), // comparison label, its mappings
     ClearImportedMappings,
     ImportNavigate(crossterm::event::KeyCode),
     ImportSetViewportHeight(usize),
@@ -105,9 +133,13 @@ pub enum Msg {
     IgnoreListNavigate(crossterm::event::KeyCode),
     IgnoreListSelect(usize),
     DeleteIgnoredItem,
-    ClearAllIgnored,
+    ClearAllIgnored,                     // Ask to clear all ignored items (typed confirmation)
+    ClearIgnoredInput(crate::tui::widgets::TextInputEvent),
+    ConfirmClearAllIgnored,
+    CancelClearAllIgnored,
     IgnoreSetViewportHeight(usize),
-    IgnoredItemsSaved, // Dummy message after async save completes
+    AutoSaveTick,                        // Debounce timer for pending mapping edits
+    MappingsSaved(Result<(), String>),   // A batch of mapping edits was written (or failed)
 }
 
 #[derive(Clone)]
@@ -67,7 +67,7 @@ impl HideMode {
 }
 
 /// Active tab in the comparison view
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum ActiveTab {
     #[default]
     Fields,
@@ -78,6 +78,15 @@ pub enum ActiveTab {
 }
 
 impl ActiveTab {
+    /// All tabs in display order
+    pub const ALL: [ActiveTab; 5] = [
+        ActiveTab::Fields,
+        ActiveTab::Relationships,
+        ActiveTab::Views,
+        ActiveTab::Forms,
+        ActiveTab::Entities,
+    ];
+
     /// Get tab label for display
     pub fn label(&self) -> &'static str {
         match self {
@@ -114,7 +123,7 @@ impl ActiveTab {
 }
 
 /// Which side of the comparison is focused
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum Side {
     #[default]
     Source,
@@ -122,7 +131,7 @@ pub enum Side {
 }
 
 /// Search mode for filtering tree items
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum SearchMode {
     #[default]
     Unified,      // One search box filters both sides
@@ -262,6 +271,18 @@ impl MatchInfo {
     pub fn is_empty(&self) -> bool {
         self.target_fields.is_empty()
     }
+
+    /// Tree badge for the primary target: its match type, plus a 0-100
+    /// confidence score when it was matched automatically
+    pub fn badge(&self) -> Option<String> {
+        let primary = self.primary_target()?;
+        let match_type = self.match_types.get(primary)?;
+        if !match_type.is_automatic() {
+            return Some(match_type.label().to_string());
+        }
+        let confidence = self.confidences.get(primary).copied().unwrap_or(1.0);
+        Some(format!("{} {}", match_type.label(), (confidence * 100.0).round() as u8))
+    }
 }
 
 /// Type of field match/mapping
@@ -273,6 +294,8 @@ pub enum MatchType {
     Manual,       // User-created mapping (overrides type checking)
     ExampleValue, // Value-based match from example data
     Import,       // Imported from C# mapping file
+    Related,      // Only relationship of the same kind to the same related entity
+    Fuzzy,        // Similar name (per the configured algorithm), types match
 }
 
 impl MatchType {
@@ -285,8 +308,64 @@ impl MatchType {
             MatchType::Manual => "[Manual]",
             MatchType::ExampleValue => "[Example]",
             MatchType::Import => "[Import]",
+            MatchType::Related => "[Related]",
+            MatchType::Fuzzy => "[Fuzzy]",
         }
     }
+
+    /// Whether the match was found by auto-matching rather than set by the user
+    pub fn is_automatic(&self) -> bool {
+        !matches!(self, MatchType::Manual | MatchType::Import)
+    }
+}
+
+/// How fields are auto-matched by name (`tui.match_algorithm`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MatchAlgorithm {
+    /// Identical logical names only
+    Exact,
+    /// Identical names, then names rewritten by the prefix mappings
+    #[default]
+    Prefix,
+    /// As `Prefix`, then names within an edit-distance similarity of each other
+    Levenshtein,
+    /// As `Prefix`, then names sharing most of their `_`-separated words
+    Token,
+}
+
+impl MatchAlgorithm {
+    pub fn from_option(value: &str) -> Self {
+        match value {
+            "exact" => Self::Exact,
+            "levenshtein" => Self::Levenshtein,
+            "token" => Self::Token,
+            _ => Self::Prefix,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Exact => "Exact",
+            Self::Prefix => "Prefix",
+            Self::Levenshtein => "Levenshtein",
+            Self::Token => "Token",
+        }
+    }
+}
+
+/// Auto-matching settings, read from the options when the comparison opens
+/// and when auto-matching is re-run
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MatchSettings {
+    pub algorithm: MatchAlgorithm,
+    /// Minimum similarity (0-100) for a fuzzy match
+    pub threshold: u8,
+}
+
+impl Default for MatchSettings {
+    fn default() -> Self {
+        Self { algorithm: MatchAlgorithm::default(), threshold: 80 }
+    }
 }
 
 /// Examples state
@@ -326,6 +405,19 @@ impl ExamplesState {
         self.enabled = !self.enabled;
     }
 
+    /// Get the cached (source, target) records of a pair, if both were fetched
+    pub fn pair_data(&self, pair: &ExamplePair, source_entity: &str, target_entity: &str) -> Option<(&serde_json::Value, &serde_json::Value)> {
+        // Pairs fetched on load use entity:record_id keys, newly added ones the bare record id
+        let lookup = |entity: &str, record_id: &str| {
+            self.cache.get(&format!("{}:{}", entity, record_id))
+                .or_else(|| self.cache.get(record_id))
+        };
+        Some((
+            lookup(source_entity, &pair.source_record_id)?,
+            lookup(target_entity, &pair.target_record_id)?,
+        ))
+    }
+
     /// Get example value for a field
     /// For hierarchical paths (Forms/Views), extracts just the field name from the path
     /// field_name can be either a simple name like "accountname" or a path like "formtype/main/form/MainForm/tab/General/accountname"
@@ -0,0 +1,138 @@
+//! Session restore: reopening a comparison puts back the tab, search and tree views
+
+use crate::tui::command::Command;
+use crate::tui::element::FocusId;
+use crate::tui::widgets::TreeSnapshot;
+use serde::{Deserialize, Serialize};
+use super::{Msg, ActiveTab, Side, SearchMode};
+use super::app::State;
+
+/// Resumable part of the comparison view
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Session {
+    active_tab: ActiveTab,
+    focused_side: Side,
+    search_mode: SearchMode,
+    unified_search: String,
+    source_search: String,
+    target_search: String,
+    /// One per tree, in `State::all_trees` order
+    trees: Vec<TreeSnapshot>,
+}
+
+/// A comparison is the same session when all of its params match
+pub fn session_key(state: &State) -> String {
+    [
+        state.migration_name.as_str(),
+        &state.source_env,
+        &state.target_env,
+        &state.source_entity,
+        &state.target_entity,
+    ]
+    .join("|")
+}
+
+/// Focus ID of the tree on the given side
+pub fn tree_focus_id(side: Side) -> FocusId {
+    match side {
+        Side::Source => FocusId::new("source_tree"),
+        Side::Target => FocusId::new("target_tree"),
+    }
+}
+
+/// Nothing is saved until metadata has loaded, so leaving early doesn't
+/// replace a good session with empty trees
+pub fn suspend_state(state: &State) -> Option<serde_json::Value> {
+    if !state.source_metadata.is_success() || !state.target_metadata.is_success() {
+        return None;
+    }
+
+    let session = Session {
+        active_tab: state.active_tab,
+        focused_side: state.focused_side,
+        search_mode: state.search_mode,
+        unified_search: state.unified_search.value().to_string(),
+        source_search: state.source_search.value().to_string(),
+        target_search: state.target_search.value().to_string(),
+        trees: state.all_trees().map(|tree| tree.snapshot()).to_vec(),
+    };
+    serde_json::to_value(session).ok()
+}
+
+/// Trees are restored right away if metadata is already loaded, otherwise
+/// once it arrives (the same way a refresh keeps them)
+pub fn restore_state(state: &mut State, snapshot: serde_json::Value) -> Command<Msg> {
+    let session: Session = match serde_json::from_value(snapshot) {
+        Ok(session) => session,
+        Err(e) => {
+            log::warn!("Ignoring unreadable entity comparison session: {}", e);
+            return Command::None;
+        }
+    };
+
+    state.active_tab = session.active_tab;
+    state.focused_side = session.focused_side;
+    state.search_mode = session.search_mode;
+    state.unified_search.set_value(session.unified_search);
+    state.source_search.set_value(session.source_search);
+    state.target_search.set_value(session.target_search);
+
+    if state.source_metadata.is_success() && state.target_metadata.is_success() {
+        for (tree, snapshot) in state.all_trees_mut().into_iter().zip(session.trees) {
+            tree.restore(snapshot);
+            tree.invalidate_cache();
+        }
+        Command::set_focus(tree_focus_id(state.focused_side))
+    } else {
+        // Search changed, so rebuild whatever the trees already show
+        for tree in state.all_trees_mut() {
+            tree.invalidate_cache();
+        }
+        state.refresh_snapshots = Some(session.trees);
+        Command::None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tui::app::App;
+    use super::super::{EntityComparisonApp, EntityComparisonParams};
+
+    fn params(target_entity: &str) -> EntityComparisonParams {
+        EntityComparisonParams {
+            migration_name: "m".to_string(),
+            source_env: "dev".to_string(),
+            target_env: "prod".to_string(),
+            source_entity: "account".to_string(),
+            target_entity: target_entity.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_session_restores_before_metadata_loads() {
+        let (mut state, _) = EntityComparisonApp::init(params("account"));
+        assert_eq!(session_key(&state), "m|dev|prod|account|account");
+        let (other, _) = EntityComparisonApp::init(params("contact"));
+        assert_ne!(session_key(&state), session_key(&other));
+
+        // Still loading: nothing worth saving yet
+        assert!(suspend_state(&state).is_none());
+
+        state.source_metadata = crate::tui::Resource::Success(Default::default());
+        state.target_metadata = crate::tui::Resource::Success(Default::default());
+        state.active_tab = ActiveTab::Views;
+        state.focused_side = Side::Target;
+        state.search_mode = SearchMode::Independent;
+        state.source_search.set_value("name".to_string());
+        let saved = suspend_state(&state).unwrap();
+
+        let (mut restored, _) = EntityComparisonApp::init(params("account"));
+        restore_state(&mut restored, saved);
+        assert_eq!(restored.active_tab, ActiveTab::Views);
+        assert_eq!(restored.focused_side, Side::Target);
+        assert_eq!(restored.search_mode, SearchMode::Independent);
+        assert_eq!(restored.source_search.value(), "name");
+        assert_eq!(restored.refresh_snapshots.as_ref().map(Vec::len), Some(10));
+    }
+}
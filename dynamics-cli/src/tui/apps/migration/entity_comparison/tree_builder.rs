@@ -175,6 +175,7 @@ fn build_views_tree(
                             is_primary_key: col.is_primary,
                             max_length: real_field.max_length,
                             related_entity: real_field.related_entity.clone(),
+                            option_set: real_field.option_set.clone(),
                         }
                     } else {
                         // Fallback to placeholder if field not found
@@ -186,6 +187,7 @@ fn build_views_tree(
                             is_primary_key: col.is_primary,
                             max_length: None,
                             related_entity: None,
+                            option_set: None,
                         }
                     };
 
@@ -330,6 +332,7 @@ fn build_forms_tree(
                                         is_primary_key: real_field.is_primary_key,
                                         max_length: real_field.max_length,
                                         related_entity: real_field.related_entity.clone(),
+                                        option_set: real_field.option_set.clone(),
                                     }
                                 } else {
                                     // Fallback to placeholder if field not found
@@ -341,6 +344,7 @@ fn build_forms_tree(
                                         is_primary_key: false,
                                         max_length: None,
                                         related_entity: None,
+                                        option_set: None,
                                     }
                                 };
 
@@ -5,6 +5,24 @@ use crate::api::{FieldMetadata, RelationshipMetadata, ViewMetadata, FormMetadata
 use ratatui::{style::Style, text::{Line, Span}, prelude::Stylize};
 use super::models::{MatchInfo, MatchType};
 
+/// Color for an item by the match type of its primary target
+///
+/// Only semantic theme slots are used (success = matched, warning = partial,
+/// error = unmatched), so themes like the colorblind-safe one can remap them.
+fn match_type_color(theme: &Theme, match_type: Option<MatchType>) -> ratatui::style::Color {
+    match match_type {
+        Some(MatchType::Exact) => theme.accent_success,        // Exact name (+ type) match
+        Some(MatchType::Prefix) => theme.accent_success,       // Prefix name (+ type) match
+        Some(MatchType::Manual) => theme.accent_success,       // User override
+        Some(MatchType::Import) => theme.accent_success,       // Imported from C# file
+        Some(MatchType::ExampleValue) => theme.palette_4,      // Example value match
+        Some(MatchType::Related) => theme.palette_4,           // Same kind and related entity
+        Some(MatchType::Fuzzy) => theme.palette_4,             // Similar name (+ type)
+        Some(MatchType::TypeMismatch) => theme.accent_warning, // Name match but type differs
+        None => theme.accent_error,  // No match
+    }
+}
+
 /// Unified tree item that can represent any metadata type
 #[derive(Clone)]
 pub enum ComparisonTreeItem {
@@ -110,14 +128,12 @@ impl TreeItem for ComparisonTreeItem {
                         Style::default().fg(theme.accent_secondary),
                     ));
 
-                    // Show match type of primary target
-                    if let Some(primary) = match_info.primary_target() {
-                        if let Some(match_type) = match_info.match_types.get(primary) {
-                            spans.push(Span::styled(
-                                format!(" {}", match_type.label()),
-                                Style::default().fg(theme.border_primary),
-                            ));
-                        }
+                    // Show match type of primary target, scored when auto-matched
+                    if let Some(badge) = match_info.badge() {
+                        spans.push(Span::styled(
+                            format!(" {}", badge),
+                            Style::default().fg(theme.border_primary),
+                        ));
                     }
                 }
 
@@ -224,15 +240,7 @@ impl TreeItem for FieldNode {
                 .and_then(|primary| match_info.match_types.get(primary))
                 .copied();
 
-            match primary_match_type {
-                Some(MatchType::Exact) => theme.accent_success,        // Exact name + type match
-                Some(MatchType::Prefix) => theme.accent_success,       // Prefix name + type match
-                Some(MatchType::Manual) => theme.accent_success,       // User override
-                Some(MatchType::Import) => theme.accent_success,       // Imported from C# file
-                Some(MatchType::ExampleValue) => theme.palette_4,   // Example value match
-                Some(MatchType::TypeMismatch) => theme.accent_warning, // Name match but type differs
-                None => theme.accent_error,  // No match
-            }
+            match_type_color(theme, primary_match_type)
         } else {
             theme.accent_error  // No match
         };
@@ -281,14 +289,12 @@ impl TreeItem for FieldNode {
 
         // Mapping source badge (if mapped)
         if let Some(match_info) = &self.match_info {
-            // Show match type of primary target
-            if let Some(primary) = match_info.primary_target() {
-                if let Some(match_type) = match_info.match_types.get(primary) {
-                    spans.push(Span::styled(
-                        format!(" {}", match_type.label()),
-                        Style::default().fg(theme.border_primary),
-                    ));
-                }
+            // Show match type of primary target, scored when auto-matched
+            if let Some(badge) = match_info.badge() {
+                spans.push(Span::styled(
+                    format!(" {}", badge),
+                    Style::default().fg(theme.border_primary),
+                ));
             }
         }
 
@@ -368,15 +374,7 @@ impl TreeItem for RelationshipNode {
                 .and_then(|primary| match_info.match_types.get(primary))
                 .copied();
 
-            match primary_match_type {
-                Some(MatchType::Exact) => theme.accent_success,        // Exact name + type match
-                Some(MatchType::Prefix) => theme.accent_success,       // Prefix name + type match
-                Some(MatchType::Manual) => theme.accent_success,       // User override
-                Some(MatchType::Import) => theme.accent_success,       // Imported from C# file
-                Some(MatchType::ExampleValue) => theme.palette_4,   // Example value match
-                Some(MatchType::TypeMismatch) => theme.accent_warning, // Name match but type differs
-                None => theme.accent_error,  // No match
-            }
+            match_type_color(theme, primary_match_type)
         } else {
             theme.accent_error  // No match
         };
@@ -420,14 +418,12 @@ impl TreeItem for RelationshipNode {
 
         // Mapping source badge (if mapped)
         if let Some(match_info) = &self.match_info {
-            // Show match type of primary target
-            if let Some(primary) = match_info.primary_target() {
-                if let Some(match_type) = match_info.match_types.get(primary) {
-                    spans.push(Span::styled(
-                        format!(" {}", match_type.label()),
-                        Style::default().fg(theme.border_primary),
-                    ));
-                }
+            // Show match type of primary target, scored when auto-matched
+            if let Some(badge) = match_info.badge() {
+                spans.push(Span::styled(
+                    format!(" {}", badge),
+                    Style::default().fg(theme.border_primary),
+                ));
             }
         }
 
@@ -627,15 +623,7 @@ impl TreeItem for EntityNode {
                 .and_then(|primary| match_info.match_types.get(primary))
                 .copied();
 
-            match primary_match_type {
-                Some(MatchType::Exact) => theme.accent_success,        // Exact name match
-                Some(MatchType::Prefix) => theme.accent_success,       // Prefix name match
-                Some(MatchType::Manual) => theme.accent_success,       // User override
-                Some(MatchType::Import) => theme.accent_success,       // Imported from C# file
-                Some(MatchType::ExampleValue) => theme.palette_4,   // Example value match
-                Some(MatchType::TypeMismatch) => theme.accent_warning, // Should not happen for entities
-                None => theme.accent_error,  // No match
-            }
+            match_type_color(theme, primary_match_type)
         } else {
             theme.accent_error  // No match
         };
@@ -668,14 +656,12 @@ impl TreeItem for EntityNode {
 
         // Mapping source badge (if mapped)
         if let Some(match_info) = &self.match_info {
-            // Show match type of primary target
-            if let Some(primary) = match_info.primary_target() {
-                if let Some(match_type) = match_info.match_types.get(primary) {
-                    spans.push(Span::styled(
-                        format!(" {}", match_type.label()),
-                        Style::default().fg(theme.border_primary),
-                    ));
-                }
+            // Show match type of primary target, scored when auto-matched
+            if let Some(badge) = match_info.badge() {
+                spans.push(Span::styled(
+                    format!(" {}", badge),
+                    Style::default().fg(theme.border_primary),
+                ));
             }
         }
 
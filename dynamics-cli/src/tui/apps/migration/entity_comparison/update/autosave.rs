@@ -0,0 +1,282 @@
+//! Debounced saving of mapping edits (manual, prefix and relationship mappings, ignores)
+//!
+//! Edits only mark what changed; once no edit has come in for
+//! `tui.autosave_delay_ms` the current values of everything marked are written
+//! in one transaction. Leaving the app saves straight away, and `on_destroy`
+//! rewrites whatever hasn't landed yet.
+
+use std::collections::BTreeSet;
+use std::time::{Duration, Instant};
+
+use once_cell::sync::Lazy;
+
+use crate::config::MappingChanges;
+use crate::tui::command::Command;
+use crate::tui::ToastLevel;
+use super::super::Msg;
+use super::super::app::State;
+
+/// How often the debounce timer checks whether pending edits are due
+pub const TICK_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Held while a save runs, so an older snapshot never lands after a newer one
+static SAVE_LOCK: Lazy<tokio::sync::Mutex<()>> = Lazy::new(|| tokio::sync::Mutex::new(()));
+
+/// Sources (and the ignored set) whose current values need writing
+#[derive(Clone, Debug, Default, PartialEq)]
+struct Dirty {
+    fields: BTreeSet<String>,
+    prefixes: BTreeSet<String>,
+    relationships: BTreeSet<String>,
+    ignores: bool,
+}
+
+impl Dirty {
+    fn is_empty(&self) -> bool {
+        self.fields.is_empty() && self.prefixes.is_empty() && self.relationships.is_empty() && !self.ignores
+    }
+
+    fn merge(&mut self, other: Dirty) {
+        self.fields.extend(other.fields);
+        self.prefixes.extend(other.prefixes);
+        self.relationships.extend(other.relationships);
+        self.ignores |= other.ignores;
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum SaveStatus {
+    Idle,
+    Pending,
+    Saving,
+    Saved,
+    /// The last save failed; its edits stay pending until the next edit or leaving the app
+    Failed(String),
+}
+
+#[derive(Clone)]
+pub struct AutoSave {
+    pending: Dirty,
+    /// What the save in flight is writing, kept until it lands
+    saving: Option<Dirty>,
+    last_edit: Option<Instant>,
+    /// Save the rest as soon as the save in flight lands, without waiting for the delay
+    flush_requested: bool,
+    status: SaveStatus,
+}
+
+impl Default for AutoSave {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AutoSave {
+    pub fn new() -> Self {
+        Self {
+            pending: Dirty::default(),
+            saving: None,
+            last_edit: None,
+            flush_requested: false,
+            status: SaveStatus::Idle,
+        }
+    }
+
+    pub fn mark_field(&mut self, source: &str) {
+        self.pending.fields.insert(source.to_string());
+        self.touch();
+    }
+
+    pub fn mark_prefix(&mut self, source: &str) {
+        self.pending.prefixes.insert(source.to_string());
+        self.touch();
+    }
+
+    pub fn mark_relationship(&mut self, source: &str) {
+        self.pending.relationships.insert(source.to_string());
+        self.touch();
+    }
+
+    pub fn mark_ignores(&mut self) {
+        self.pending.ignores = true;
+        self.touch();
+    }
+
+    pub fn status(&self) -> &SaveStatus {
+        &self.status
+    }
+
+    /// Whether the debounce timer has anything to wait for
+    pub fn is_waiting(&self) -> bool {
+        self.status == SaveStatus::Pending && self.saving.is_none()
+    }
+
+    fn touch(&mut self) {
+        self.last_edit = Some(Instant::now());
+        if self.saving.is_none() {
+            self.status = SaveStatus::Pending;
+        }
+    }
+
+    /// Whether pending edits have been idle for `delay`
+    fn is_due(&self, now: Instant, delay: Duration) -> bool {
+        self.is_waiting() && self.last_edit.is_some_and(|edit| now.duration_since(edit) >= delay)
+    }
+
+    /// Move pending edits to the save in flight
+    fn start(&mut self) -> Option<Dirty> {
+        if self.saving.is_some() || self.pending.is_empty() {
+            return None;
+        }
+        let dirty = std::mem::take(&mut self.pending);
+        self.saving = Some(dirty.clone());
+        self.status = SaveStatus::Saving;
+        Some(dirty)
+    }
+
+    /// Record the result of the save in flight; returns whether to save again right away
+    fn finish(&mut self, result: Result<(), String>) -> bool {
+        let saving = self.saving.take();
+        let flush = std::mem::take(&mut self.flush_requested);
+        match result {
+            Ok(()) if self.pending.is_empty() => {
+                self.status = SaveStatus::Saved;
+                false
+            }
+            Ok(()) => {
+                self.status = SaveStatus::Pending;
+                flush
+            }
+            Err(e) => {
+                if let Some(saving) = saving {
+                    self.pending.merge(saving);
+                }
+                self.status = SaveStatus::Failed(e);
+                false
+            }
+        }
+    }
+}
+
+/// Timer tick: save pending edits once they have been idle long enough
+pub fn handle_tick(state: &mut State) -> Command<Msg> {
+    let delay = Duration::from_millis(crate::global_runtime_config().autosave_delay_ms);
+    if state.autosave.is_due(Instant::now(), delay) {
+        flush(state)
+    } else {
+        Command::None
+    }
+}
+
+/// Save pending edits now; with a save already in flight, they follow as soon as it lands
+pub fn flush(state: &mut State) -> Command<Msg> {
+    if state.autosave.saving.is_some() {
+        state.autosave.flush_requested = true;
+        return Command::None;
+    }
+    match state.autosave.start() {
+        Some(dirty) => save(state, dirty),
+        None => Command::None,
+    }
+}
+
+/// Save everything not yet written, including what the save in flight was writing,
+/// for when the app goes away before that save's result can arrive
+pub fn flush_all(state: &mut State) -> Command<Msg> {
+    if let Some(saving) = state.autosave.saving.take() {
+        state.autosave.pending.merge(saving);
+    }
+    flush(state)
+}
+
+pub fn handle_saved(state: &mut State, result: Result<(), String>) -> Command<Msg> {
+    if let Err(e) = &result {
+        log::error!("Failed to save mapping changes: {}", e);
+    }
+    let notify = result.as_ref().err().map(|e| format!("Failed to save mappings: {}", e));
+
+    if state.autosave.finish(result) {
+        return flush(state);
+    }
+    match notify {
+        Some(message) => Command::notify(message, ToastLevel::Error),
+        None => Command::None,
+    }
+}
+
+/// Write the current values of `dirty`
+fn save(state: &State, dirty: Dirty) -> Command<Msg> {
+    let changes = MappingChanges {
+        field_mappings: dirty.fields.into_iter()
+            .map(|source| {
+                let targets = state.field_mappings.get(&source).cloned().unwrap_or_default();
+                (source, targets)
+            })
+            .collect(),
+        prefix_mappings: dirty.prefixes.into_iter()
+            .map(|source| {
+                let targets = state.prefix_mappings.get(&source).cloned().unwrap_or_default();
+                (source, targets)
+            })
+            .collect(),
+        relationship_mappings: dirty.relationships.into_iter()
+            .map(|source| {
+                let targets = state.relationship_mappings.get(&source).cloned().unwrap_or_default();
+                (source, targets)
+            })
+            .collect(),
+        ignored_items: dirty.ignores.then(|| state.ignored_items.clone()),
+    };
+    let source_entity = state.source_entity.clone();
+    let target_entity = state.target_entity.clone();
+
+    // Spawned rather than left to the command, so the write still happens
+    // if the app is destroyed before the command is first polled
+    let task = tokio::spawn(async move {
+        let _guard = SAVE_LOCK.lock().await;
+        crate::global_config()
+            .save_mapping_changes(&source_entity, &target_entity, &changes)
+            .await
+            .map_err(|e| e.to_string())
+    });
+
+    Command::perform(
+        async move { task.await.unwrap_or_else(|e| Err(e.to_string())) },
+        Msg::MappingsSaved,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_edits_wait_for_delay_and_failed_saves_are_kept() {
+        let delay = Duration::from_millis(500);
+        let mut autosave = AutoSave::new();
+        autosave.mark_field("name");
+        autosave.mark_field("name");
+        autosave.mark_ignores();
+
+        let edited = autosave.last_edit.unwrap();
+        assert!(!autosave.is_due(edited, delay));
+        assert!(autosave.is_due(edited + delay, delay));
+
+        let first = autosave.start().unwrap();
+        assert_eq!(first.fields.len(), 1);
+        assert_eq!(autosave.status(), &SaveStatus::Saving);
+
+        // An edit during the save waits for it, then goes out when flushed
+        autosave.mark_prefix("cgk_");
+        assert!(autosave.start().is_none());
+        autosave.flush_requested = true;
+        assert!(autosave.finish(Ok(())));
+
+        let second = autosave.start().unwrap();
+        assert!(second.fields.is_empty() && second.prefixes.contains("cgk_"));
+        assert!(!autosave.finish(Err("database is locked".to_string())));
+        assert_eq!(autosave.status(), &SaveStatus::Failed("database is locked".to_string()));
+        assert!(!autosave.is_waiting());
+        assert_eq!(autosave.pending, second);
+    }
+}
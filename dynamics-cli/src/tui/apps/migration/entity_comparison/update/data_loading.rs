@@ -4,6 +4,7 @@ use std::collections::HashMap;
 use super::super::{Msg, FetchType, FetchedData, ExamplePair, fetch_with_cache, extract_relationships};
 use super::super::app::State;
 use super::super::matching::recompute_all_matches;
+use super::super::session::tree_focus_id;
 
 pub fn handle_parallel_data_loaded(
     state: &mut State,
@@ -15,33 +16,12 @@ pub fn handle_parallel_data_loaded(
             // Update the appropriate metadata based on the data variant
             match data {
                 FetchedData::SourceFields(mut fields) => {
-                    // Extract relationships if we have Lookup fields (fresh from API)
-                    // If no Lookup fields, they came from cache and relationships need to be loaded separately
-                    let has_lookup_fields = fields.iter().any(|f| matches!(&f.field_type, crate::api::metadata::FieldType::Lookup) || matches!(&f.field_type, crate::api::metadata::FieldType::Other(t) if t.starts_with("Relationship:")));
-
-                    let relationships = if has_lookup_fields {
-                        let rels = extract_relationships(&fields);
-                        fields.retain(|f| {
-                            !matches!(&f.field_type, crate::api::metadata::FieldType::Lookup)
-                                && !matches!(&f.field_type, crate::api::metadata::FieldType::Other(t) if t.starts_with("Relationship:"))
-                        });
-                        rels
-                    } else {
-                        // From cache - load relationships from cache
-                        let config = crate::global_config();
-                        let source_env = state.source_env.clone();
-                        let source_entity = state.source_entity.clone();
-                        tokio::task::block_in_place(|| {
-                            tokio::runtime::Handle::current().block_on(async {
-                                config.get_entity_metadata_cache(&source_env, &source_entity, 12)
-                                    .await
-                                    .ok()
-                                    .flatten()
-                                    .map(|cached| cached.relationships)
-                                    .unwrap_or_default()
-                            })
-                        })
-                    };
+                    // Lookups and collection relationships become the relationships tab
+                    let relationships = extract_relationships(&fields);
+                    fields.retain(|f| {
+                        !matches!(&f.field_type, crate::api::metadata::FieldType::Lookup)
+                            && !matches!(&f.field_type, crate::api::metadata::FieldType::Other(t) if t.starts_with("Relationship:"))
+                    });
 
                     if let Resource::Success(ref mut meta) = state.source_metadata {
                         meta.fields = fields;
@@ -75,33 +55,12 @@ pub fn handle_parallel_data_loaded(
                     }
                 }
                 FetchedData::TargetFields(mut fields) => {
-                    // Extract relationships if we have Lookup fields (fresh from API)
-                    // If no Lookup fields, they came from cache and relationships need to be loaded separately
-                    let has_lookup_fields = fields.iter().any(|f| matches!(&f.field_type, crate::api::metadata::FieldType::Lookup) || matches!(&f.field_type, crate::api::metadata::FieldType::Other(t) if t.starts_with("Relationship:")));
-
-                    let relationships = if has_lookup_fields {
-                        let rels = extract_relationships(&fields);
-                        fields.retain(|f| {
-                            !matches!(&f.field_type, crate::api::metadata::FieldType::Lookup)
-                                && !matches!(&f.field_type, crate::api::metadata::FieldType::Other(t) if t.starts_with("Relationship:"))
-                        });
-                        rels
-                    } else {
-                        // From cache - load relationships from cache
-                        let config = crate::global_config();
-                        let target_env = state.target_env.clone();
-                        let target_entity = state.target_entity.clone();
-                        tokio::task::block_in_place(|| {
-                            tokio::runtime::Handle::current().block_on(async {
-                                config.get_entity_metadata_cache(&target_env, &target_entity, 12)
-                                    .await
-                                    .ok()
-                                    .flatten()
-                                    .map(|cached| cached.relationships)
-                                    .unwrap_or_default()
-                            })
-                        })
-                    };
+                    // Lookups and collection relationships become the relationships tab
+                    let relationships = extract_relationships(&fields);
+                    fields.retain(|f| {
+                        !matches!(&f.field_type, crate::api::metadata::FieldType::Lookup)
+                            && !matches!(&f.field_type, crate::api::metadata::FieldType::Other(t) if t.starts_with("Relationship:"))
+                    });
 
                     if let Resource::Success(ref mut meta) = state.target_metadata {
                         meta.fields = fields;
@@ -184,9 +143,11 @@ pub fn handle_parallel_data_loaded(
                             &state.field_mappings,
                             &state.imported_mappings,
                             &state.prefix_mappings,
+                            &state.relationship_mappings,
                             &state.examples,
                             &state.source_entity,
                             &state.target_entity,
+                            state.match_settings,
                         );
 
                     state.field_matches = field_matches;
@@ -195,32 +156,14 @@ pub fn handle_parallel_data_loaded(
                     state.source_entities = source_entities;
                     state.target_entities = target_entities;
 
-                    // Cache both metadata objects asynchronously
-                    let source_env = state.source_env.clone();
-                    let source_entity = state.source_entity.clone();
-                    let source_meta = source.clone();
-                    tokio::spawn(async move {
-                        let config = crate::global_config();
-                        if let Err(e) = config.set_entity_metadata_cache(&source_env, &source_entity, &source_meta).await {
-                            log::error!("Failed to cache source metadata for {}/{}: {}", source_env, source_entity, e);
-                        } else {
-                            log::debug!("Cached source metadata for {}/{}", source_env, source_entity);
+                    // Restore tree views saved when a refresh started
+                    if let Some(snapshots) = state.refresh_snapshots.take() {
+                        for (tree, snapshot) in state.all_trees_mut().into_iter().zip(snapshots) {
+                            tree.restore(snapshot);
                         }
-                    });
-
-                    let target_env = state.target_env.clone();
-                    let target_entity = state.target_entity.clone();
-                    let target_meta = target.clone();
-                    tokio::spawn(async move {
-                        let config = crate::global_config();
-                        if let Err(e) = config.set_entity_metadata_cache(&target_env, &target_entity, &target_meta).await {
-                            log::error!("Failed to cache target metadata for {}/{}: {}", target_env, target_entity, e);
-                        } else {
-                            log::debug!("Cached target metadata for {}/{}", target_env, target_entity);
-                        }
-                    });
+                    }
 
-                    return Command::set_focus("source_tree".into());
+                    return Command::set_focus(tree_focus_id(state.focused_side));
                 }
             }
         }
@@ -244,6 +187,7 @@ pub fn handle_mappings_loaded(
     state: &mut State,
     field_mappings: HashMap<String, Vec<String>>,
     prefix_mappings: HashMap<String, Vec<String>>,
+    relationship_mappings: HashMap<String, Vec<String>>,
     imported_mappings: HashMap<String, Vec<String>>,
     import_source_file: Option<String>,
     example_pairs: Vec<ExamplePair>,
@@ -252,11 +196,15 @@ pub fn handle_mappings_loaded(
     // Update state with loaded mappings and examples
     state.field_mappings = field_mappings;
     state.prefix_mappings = prefix_mappings;
+    state.relationship_mappings = relationship_mappings;
     state.imported_mappings = imported_mappings;
     state.import_source_file = import_source_file;
     state.examples.pairs = example_pairs.clone();
     state.ignored_items = ignored_items;
 
+    // Matching starts from the current options; re-running auto-match picks up later changes
+    state.match_settings = crate::global_runtime_config().match_settings;
+
     // Set first pair as active if any exist
     if !state.examples.pairs.is_empty() {
         state.examples.active_pair_id = Some(state.examples.pairs[0].id.clone());
@@ -359,6 +307,7 @@ pub fn handle_mappings_loaded(
         .on_complete(AppId::EntityComparison)
         .on_cancel(AppId::MigrationComparisonSelect)
         .cancellable(true)
+        .max_concurrent(crate::client_manager().max_concurrent_fetches())
         .build(|_task_idx, result| {
             let data = result.downcast::<Result<FetchedData, String>>().unwrap();
             Msg::ParallelDataLoaded(0, *data)
@@ -373,6 +322,10 @@ pub fn handle_refresh(state: &mut State) -> Command<Msg> {
     // Clear example cache to force re-fetch
     state.examples.cache.clear();
 
+    // Keep expansion, selection and scroll so the reloaded trees look the same
+    let snapshots = state.all_trees_mut().map(|tree| tree.snapshot()).to_vec();
+    state.refresh_snapshots = Some(snapshots);
+
     let mut builder = Command::perform_parallel()
         // Source entity fetches - bypass cache for manual refresh
         .add_task(
@@ -469,6 +422,7 @@ pub fn handle_refresh(state: &mut State) -> Command<Msg> {
         .on_complete(AppId::EntityComparison)
         .on_cancel(AppId::MigrationComparisonSelect)
         .cancellable(true)
+        .max_concurrent(crate::client_manager().max_concurrent_fetches())
         .build(|_task_idx, result| {
             let data = result.downcast::<Result<FetchedData, String>>().unwrap();
             Msg::ParallelDataLoaded(0, *data)
@@ -1,5 +1,6 @@
 use crate::tui::command::Command;
-use crate::tui::widgets::TextInputEvent;
+use crate::tui::widgets::{ListEvent, TextInputEvent, TreeEvent, TreeState};
+use crate::tui::ToastLevel;
 use crossterm::event::KeyCode;
 use super::super::{Msg, ExamplePair};
 use super::super::app::State;
@@ -14,6 +15,40 @@ pub fn handle_close_modal(state: &mut State) -> Command<Msg> {
     Command::None
 }
 
+pub fn handle_open_diff(state: &mut State) -> Command<Msg> {
+    let Some(pair) = state.examples_list_state.selected()
+        .and_then(|idx| state.examples.pairs.get(idx))
+    else {
+        return Command::None;
+    };
+
+    let Some((source, target)) = state.examples.pair_data(pair, &state.source_entity, &state.target_entity) else {
+        return Command::notify("Example data for this pair has not been fetched yet", ToastLevel::Warning);
+    };
+
+    // Start with only the branches that differ opened
+    let nodes = crate::tui::widgets::json_diff::diff_json::<Msg>(source, target);
+    state.example_diff_tree = TreeState::with_selection();
+    crate::tui::widgets::json_diff::expand_changes(&nodes, &mut state.example_diff_tree);
+    state.show_example_diff_modal = true;
+    Command::None
+}
+
+pub fn handle_close_diff(state: &mut State) -> Command<Msg> {
+    state.show_example_diff_modal = false;
+    Command::None
+}
+
+pub fn handle_diff_tree_event(state: &mut State, event: TreeEvent) -> Command<Msg> {
+    state.example_diff_tree.handle_event(event);
+    Command::None
+}
+
+pub fn handle_diff_viewport_height(state: &mut State, height: usize) -> Command<Msg> {
+    state.example_diff_tree.set_viewport_height(height);
+    Command::None
+}
+
 pub fn handle_list_navigate(state: &mut State, key: KeyCode) -> Command<Msg> {
     state.examples_list_state.handle_key(key, state.examples.pairs.len(), 10);
     Command::None
@@ -25,6 +60,20 @@ pub fn handle_list_select(state: &mut State, index: usize) -> Command<Msg> {
     Command::None
 }
 
+pub fn handle_list_event(state: &mut State, event: ListEvent) -> Command<Msg> {
+    let item_count = state.examples.pairs.len();
+    if let ListEvent::Reorder { from, to } = event {
+        if from >= item_count || to >= item_count {
+            return Command::None;
+        }
+        // Order only lasts for the session; pairs load newest first
+        let pair = state.examples.pairs.remove(from);
+        state.examples.pairs.insert(to, pair);
+    }
+    state.examples_list_state.handle_event(event, item_count, 10);
+    Command::None
+}
+
 pub fn handle_source_input_event(state: &mut State, event: TextInputEvent) -> Command<Msg> {
     state.examples_source_input.handle_event(event, None);
     Command::None
@@ -198,9 +247,11 @@ pub fn handle_cycle_example_pair(state: &mut State) -> Command<Msg> {
                 &state.field_mappings,
                 &state.imported_mappings,
                 &state.prefix_mappings,
+                &state.relationship_mappings,
                 &state.examples,
                 &state.source_entity,
                 &state.target_entity,
+                state.match_settings,
             );
         state.field_matches = field_matches;
         state.relationship_matches = relationship_matches;
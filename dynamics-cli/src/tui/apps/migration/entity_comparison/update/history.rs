@@ -0,0 +1,226 @@
+//! Undo/redo for mapping edits (manual mappings, prefix mappings, ignores)
+
+use crate::tui::command::Command;
+use crate::tui::{Resource, ToastLevel};
+use super::super::Msg;
+use super::super::app::State;
+use super::super::matching::recompute_all_matches;
+
+/// Most edits kept on each stack; the oldest are dropped first
+const MAX_HISTORY: usize = 100;
+
+/// An action that can produce its own inverse
+pub trait Reversible: Clone {
+    fn reversed(&self) -> Self;
+}
+
+/// Undo and redo stacks of recorded edits
+///
+/// Each edit is a group of actions undone together, e.g. one N-to-1 mapping
+/// creating several source mappings at once.
+#[derive(Debug, Clone)]
+pub struct UndoStack<A> {
+    undo: Vec<Vec<A>>,
+    redo: Vec<Vec<A>>,
+}
+
+impl<A> Default for UndoStack<A> {
+    fn default() -> Self {
+        Self { undo: Vec::new(), redo: Vec::new() }
+    }
+}
+
+impl<A: Reversible> UndoStack<A> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a new edit, dropping anything that could have been redone
+    pub fn record(&mut self, actions: Vec<A>) {
+        if actions.is_empty() {
+            return;
+        }
+        self.redo.clear();
+        push_capped(&mut self.undo, actions);
+    }
+
+    /// Pop the last edit and return the actions that revert it, in order
+    pub fn undo(&mut self) -> Option<Vec<A>> {
+        let actions = self.undo.pop()?;
+        let reverted = actions.iter().rev().map(Reversible::reversed).collect();
+        push_capped(&mut self.redo, actions);
+        Some(reverted)
+    }
+
+    /// Pop the last undone edit and return its actions to apply again
+    pub fn redo(&mut self) -> Option<Vec<A>> {
+        let actions = self.redo.pop()?;
+        push_capped(&mut self.undo, actions.clone());
+        Some(actions)
+    }
+}
+
+fn push_capped<A>(stack: &mut Vec<Vec<A>>, actions: Vec<A>) {
+    stack.push(actions);
+    if stack.len() > MAX_HISTORY {
+        stack.remove(0);
+    }
+}
+
+/// A reversible change to the comparison's mappings
+///
+/// Mapping actions keep the targets before and after the change; `None` means
+/// the source had no mapping.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MappingAction {
+    FieldMapping { source: String, before: Option<Vec<String>>, after: Option<Vec<String>> },
+    PrefixMapping { source: String, before: Option<Vec<String>>, after: Option<Vec<String>> },
+    RelationshipMapping { source: String, before: Option<Vec<String>>, after: Option<Vec<String>> },
+    Ignore { item: String, ignored: bool },
+}
+
+impl Reversible for MappingAction {
+    fn reversed(&self) -> Self {
+        match self.clone() {
+            Self::FieldMapping { source, before, after } => Self::FieldMapping { source, before: after, after: before },
+            Self::PrefixMapping { source, before, after } => Self::PrefixMapping { source, before: after, after: before },
+            Self::RelationshipMapping { source, before, after } => Self::RelationshipMapping { source, before: after, after: before },
+            Self::Ignore { item, ignored } => Self::Ignore { item, ignored: !ignored },
+        }
+    }
+}
+
+pub fn handle_undo(state: &mut State) -> Command<Msg> {
+    match state.mapping_history.undo() {
+        Some(actions) => apply(state, actions, "Undid"),
+        None => Command::notify("Nothing to undo", ToastLevel::Info),
+    }
+}
+
+pub fn handle_redo(state: &mut State) -> Command<Msg> {
+    match state.mapping_history.redo() {
+        Some(actions) => apply(state, actions, "Redid"),
+        None => Command::notify("Nothing to redo", ToastLevel::Info),
+    }
+}
+
+/// Apply actions to state, recompute matches and persist the result
+fn apply(state: &mut State, actions: Vec<MappingAction>, verb: &str) -> Command<Msg> {
+    let count = actions.len();
+
+    for action in actions {
+        match action {
+            MappingAction::FieldMapping { source, after, .. } => {
+                state.autosave.mark_field(&source);
+                match after {
+                    Some(targets) => state.field_mappings.insert(source, targets),
+                    None => state.field_mappings.remove(&source),
+                };
+            }
+            MappingAction::PrefixMapping { source, after, .. } => {
+                state.autosave.mark_prefix(&source);
+                match after {
+                    Some(targets) => state.prefix_mappings.insert(source, targets),
+                    None => state.prefix_mappings.remove(&source),
+                };
+            }
+            MappingAction::RelationshipMapping { source, after, .. } => {
+                state.autosave.mark_relationship(&source);
+                match after {
+                    Some(targets) => state.relationship_mappings.insert(source, targets),
+                    None => state.relationship_mappings.remove(&source),
+                };
+            }
+            MappingAction::Ignore { item, ignored } => {
+                if ignored {
+                    state.ignored_items.insert(item);
+                } else {
+                    state.ignored_items.remove(&item);
+                }
+                state.autosave.mark_ignores();
+            }
+        }
+    }
+
+    if let (Resource::Success(source), Resource::Success(target)) =
+        (&state.source_metadata, &state.target_metadata)
+    {
+        let (field_matches, relationship_matches, entity_matches, source_entities, target_entities) =
+            recompute_all_matches(
+                source,
+                target,
+                &state.field_mappings,
+                &state.imported_mappings,
+                &state.prefix_mappings,
+                &state.relationship_mappings,
+                &state.examples,
+                &state.source_entity,
+                &state.target_entity,
+                state.match_settings,
+            );
+        state.field_matches = field_matches;
+        state.relationship_matches = relationship_matches;
+        state.entity_matches = entity_matches;
+        state.source_entities = source_entities;
+        state.target_entities = target_entities;
+    }
+
+    let message = if count == 1 {
+        format!("{} 1 mapping change", verb)
+    } else {
+        format!("{} {} mapping changes", verb, count)
+    };
+    Command::notify(message, ToastLevel::Info)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ignore(item: &str) -> MappingAction {
+        MappingAction::Ignore { item: item.to_string(), ignored: true }
+    }
+
+    #[test]
+    fn test_undo_reverts_group_in_reverse_and_redo_reapplies() {
+        let mut stack = UndoStack::new();
+        let create = MappingAction::FieldMapping {
+            source: "name".to_string(),
+            before: None,
+            after: Some(vec!["fullname".to_string()]),
+        };
+        stack.record(vec![create.clone(), ignore("a")]);
+
+        let undone = stack.undo().unwrap();
+        assert_eq!(undone, vec![
+            MappingAction::Ignore { item: "a".to_string(), ignored: false },
+            MappingAction::FieldMapping {
+                source: "name".to_string(),
+                before: Some(vec!["fullname".to_string()]),
+                after: None,
+            },
+        ]);
+        assert!(stack.undo().is_none());
+
+        assert_eq!(stack.redo().unwrap(), vec![create, ignore("a")]);
+        assert!(stack.redo().is_none());
+    }
+
+    #[test]
+    fn test_recording_clears_redo_and_caps_depth() {
+        let mut stack = UndoStack::new();
+        stack.record(vec![ignore("a")]);
+        stack.undo();
+        stack.record(vec![ignore("b")]);
+        assert!(stack.redo().is_none());
+
+        for i in 0..MAX_HISTORY + 5 {
+            stack.record(vec![ignore(&i.to_string())]);
+        }
+        let mut undone = 0;
+        while stack.undo().is_some() {
+            undone += 1;
+        }
+        assert_eq!(undone, MAX_HISTORY);
+    }
+}
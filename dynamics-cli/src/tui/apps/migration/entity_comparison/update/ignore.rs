@@ -2,9 +2,14 @@
 
 use super::super::Msg;
 use super::super::app::State;
-use crate::tui::Command;
+use super::history::MappingAction;
+use crate::tui::{Command, FocusId};
+use crate::tui::widgets::{TextInputEvent, TextInputField};
 use crossterm::event::KeyCode;
 
+/// Phrase that must be typed before every ignored item is cleared
+pub const CLEAR_IGNORED_PHRASE: &str = "clear all";
+
 /// Get the item identifier for currently selected item based on active tab
 fn get_selected_item_id(state: &mut State) -> Option<String> {
     // Capture values before borrowing trees
@@ -39,28 +44,17 @@ fn get_selected_item_id(state: &mut State) -> Option<String> {
 pub fn handle_ignore_item(state: &mut State) -> Command<Msg> {
     if let Some(item_id) = get_selected_item_id(state) {
         // Toggle: if already ignored, un-ignore it; otherwise ignore it
-        if state.ignored_items.contains(&item_id) {
-            log::info!("Un-ignoring item: {}", item_id);
-            state.ignored_items.remove(&item_id);
-        } else {
+        let ignored = !state.ignored_items.contains(&item_id);
+        if ignored {
             log::info!("Ignoring item: {}", item_id);
             state.ignored_items.insert(item_id.clone());
+        } else {
+            log::info!("Un-ignoring item: {}", item_id);
+            state.ignored_items.remove(&item_id);
         }
-
-        // Persist to config (async, don't wait)
-        let source_entity = state.source_entity.clone();
-        let target_entity = state.target_entity.clone();
-        let ignored = state.ignored_items.clone();
-
-        Command::perform(
-            async move {
-                let config = crate::global_config();
-                if let Err(e) = config.set_ignored_items(&source_entity, &target_entity, &ignored).await {
-                    log::error!("Failed to save ignored items: {}", e);
-                }
-            },
-            |_| Msg::IgnoredItemsSaved  // Dummy message - doesn't trigger another ignore
-        )
+        state.mapping_history.record(vec![MappingAction::Ignore { item: item_id, ignored }]);
+        state.autosave.mark_ignores();
+        Command::None
     } else {
         log::warn!("No item selected to ignore");
         Command::None
@@ -115,6 +109,10 @@ pub fn handle_delete_item(state: &mut State) -> Command<Msg> {
             let item_to_remove = &ignored_vec[selected_index];
             log::info!("Removing ignored item: {}", item_to_remove);
             state.ignored_items.remove(item_to_remove);
+            state.mapping_history.record(vec![MappingAction::Ignore {
+                item: item_to_remove.clone(),
+                ignored: false,
+            }]);
 
             // Adjust selection after deletion
             let new_count = state.ignored_items.len();
@@ -129,44 +127,57 @@ pub fn handle_delete_item(state: &mut State) -> Command<Msg> {
                 new_count,
             );
 
-            // Persist to config
-            let source_entity = state.source_entity.clone();
-            let target_entity = state.target_entity.clone();
-            let ignored = state.ignored_items.clone();
-
-            return Command::perform(
-                async move {
-                    let config = crate::global_config();
-                    if let Err(e) = config.set_ignored_items(&source_entity, &target_entity, &ignored).await {
-                        log::error!("Failed to save ignored items: {}", e);
-                    }
-                },
-                |_| Msg::IgnoredItemsSaved  // Dummy message - doesn't close modal
-            );
+            state.autosave.mark_ignores();
         }
     }
     Command::None
 }
 
-/// Clear all ignored items
+/// Open the typed confirmation for clearing all ignored items
+pub fn handle_request_clear_all(state: &mut State) -> Command<Msg> {
+    if state.ignored_items.is_empty() {
+        return Command::None;
+    }
+    state.clear_ignored_confirm = Some(TextInputField::new());
+    Command::set_focus(FocusId::new("ignore-clear-input"))
+}
+
+/// Handle typing in the clear confirmation
+pub fn handle_clear_input(state: &mut State, event: TextInputEvent) -> Command<Msg> {
+    let submitted = match state.clear_ignored_confirm.as_mut() {
+        Some(input) => input.handle_event(event, None),
+        None => return Command::None,
+    };
+    match submitted {
+        Some(value) if value == CLEAR_IGNORED_PHRASE => handle_clear_all(state),
+        _ => Command::None,
+    }
+}
+
+/// Close the clear confirmation without clearing anything
+pub fn handle_cancel_clear_all(state: &mut State) -> Command<Msg> {
+    state.clear_ignored_confirm = None;
+    Command::None
+}
+
+/// Clear all ignored items once the confirmation phrase has been typed
 pub fn handle_clear_all(state: &mut State) -> Command<Msg> {
+    let confirmed = state
+        .clear_ignored_confirm
+        .take()
+        .is_some_and(|input| input.value() == CLEAR_IGNORED_PHRASE);
+    if !confirmed {
+        return Command::None;
+    }
+
     log::info!("Clearing all ignored items");
-    state.ignored_items.clear();
+    let cleared = std::mem::take(&mut state.ignored_items);
+    state.mapping_history.record(
+        cleared.into_iter().map(|item| MappingAction::Ignore { item, ignored: false }).collect()
+    );
     state.ignore_list_state.select_and_scroll(None, 0);
-
-    // Persist cleared state to config
-    let source_entity = state.source_entity.clone();
-    let target_entity = state.target_entity.clone();
-
-    Command::perform(
-        async move {
-            let config = crate::global_config();
-            if let Err(e) = config.clear_ignored_items(&source_entity, &target_entity).await {
-                log::error!("Failed to clear ignored items in config: {}", e);
-            }
-        },
-        |_| Msg::IgnoredItemsSaved  // Dummy message - doesn't close modal
-    )
+    state.autosave.mark_ignores();
+    Command::None
 }
 
 /// Update viewport height for ignore list scrolling
@@ -1,9 +1,13 @@
-//! Import handlers for C# mapping file import
+//! Import handlers for C# and CSV mapping file import, and for copying the
+//! mappings of another saved comparison
 
 use super::super::Msg;
 use super::super::app::State;
-use crate::tui::{Command, Resource};
-use crate::tui::widgets::{FileBrowserEvent, FileBrowserAction};
+use super::history::MappingAction;
+use crate::config::SavedComparison;
+use crate::tui::{Command, Resource, ToastLevel};
+use crate::tui::widgets::{FileBrowserEvent, FileBrowserAction, SelectEvent};
+use crate::csv_parser::{CsvImportData, CsvTable};
 use std::path::PathBuf;
 use std::collections::HashMap;
 use crossterm::event::KeyCode;
@@ -86,15 +90,22 @@ pub fn handle_file_selected(_state: &mut State, path: PathBuf) -> Command<Msg> {
                 let content = tokio::fs::read_to_string(&path).await
                     .map_err(|e| format!("Failed to read file: {}", e))?;
 
-                // Parse CSV mappings
-                let csv_data = crate::csv_parser::parse_csv_field_mappings(&content)?;
-
                 // Extract filename
                 let filename = path.file_name()
                     .unwrap_or_default()
                     .to_string_lossy()
                     .to_string();
 
+                // Plain source/target CSVs get their columns picked before mapping
+                if !crate::csv_parser::is_typed_mapping_csv(&content) {
+                    let table = crate::csv_parser::read_csv_table(&content)?;
+                    log::info!("Read {} CSV rows from {} (columns: {:?})", table.rows.len(), filename, table.headers);
+                    return Ok(Msg::ImportCsvTableLoaded(table, filename));
+                }
+
+                // Parse CSV mappings
+                let csv_data = crate::csv_parser::parse_csv_field_mappings(&content)?;
+
                 log::info!("Successfully parsed CSV from {}: {} manual, {} prefix, {} imported, {} source ignores, {} target ignores",
                     filename,
                     csv_data.manual_mappings.len(),
@@ -103,11 +114,11 @@ pub fn handle_file_selected(_state: &mut State, path: PathBuf) -> Command<Msg> {
                     csv_data.source_ignores.len(),
                     csv_data.target_ignores.len()
                 );
-                Ok((csv_data, filename))
+                Ok(Msg::ImportCsvLoaded(csv_data, filename))
             },
-            |result: Result<(crate::csv_parser::CsvImportData, String), String>| {
+            |result: Result<Msg, String>| {
                 match result {
-                    Ok((csv_data, filename)) => Msg::ImportCsvLoaded(csv_data, filename),
+                    Ok(msg) => msg,
                     Err(err) => {
                         log::error!("Failed to parse CSV mappings: {}", err);
                         // TODO: Show error modal instead of just closing
@@ -150,8 +161,360 @@ pub fn handle_file_selected(_state: &mut State, path: PathBuf) -> Command<Msg> {
     }
 }
 
+/// Handle plain CSV table loaded - map it right away when the header names both columns
+pub fn handle_csv_table_loaded(state: &mut State, table: CsvTable, filename: String) -> Command<Msg> {
+    state.show_import_modal = false;
+
+    match table.detected {
+        Some((source_col, target_col)) => import_csv_columns(state, &table, source_col, target_col, filename),
+        None => {
+            state.csv_column_picker = Some(super::super::app::CsvColumnPicker::new(table, filename));
+            Command::set_focus(crate::tui::FocusId::new("csv-source-column"))
+        }
+    }
+}
+
+pub fn handle_csv_source_column_event(state: &mut State, event: SelectEvent) -> Command<Msg> {
+    match &mut state.csv_column_picker {
+        Some(picker) => picker.source_column.handle_event(event, &picker.table.headers).0,
+        None => Command::None,
+    }
+}
+
+pub fn handle_csv_target_column_event(state: &mut State, event: SelectEvent) -> Command<Msg> {
+    match &mut state.csv_column_picker {
+        Some(picker) => picker.target_column.handle_event(event, &picker.table.headers).0,
+        None => Command::None,
+    }
+}
+
+/// Import the CSV with the columns picked in the modal
+pub fn handle_confirm_csv_columns(state: &mut State) -> Command<Msg> {
+    let Some(picker) = state.csv_column_picker.take() else {
+        return Command::None;
+    };
+
+    let source_col = picker.source_column.state.selected();
+    let target_col = picker.target_column.state.selected();
+    if source_col == target_col {
+        state.csv_column_picker = Some(picker);
+        return Command::notify("Source and target must be different columns", ToastLevel::Warning);
+    }
+
+    import_csv_columns(state, &picker.table, source_col, target_col, picker.filename)
+}
+
+pub fn handle_cancel_csv_columns(state: &mut State) -> Command<Msg> {
+    state.csv_column_picker = None;
+    Command::None
+}
+
+/// Turn the chosen columns into imported mappings
+///
+/// Rows with an empty cell or a field that doesn't exist on its entity are
+/// reported as unparsed instead of being imported.
+fn import_csv_columns(state: &mut State, table: &CsvTable, source_col: usize, target_col: usize, filename: String) -> Command<Msg> {
+    let has_field = |metadata: &Resource<crate::api::EntityMetadata>, name: &str| match metadata {
+        Resource::Success(metadata) => metadata.fields.iter().any(|f| f.logical_name == name),
+        // Without metadata there is nothing to check against
+        _ => true,
+    };
+
+    let mut csv_data = CsvImportData::default();
+    let mut unparsed = Vec::new();
+
+    for (i, row) in table.rows.iter().enumerate() {
+        let line = table.first_line + i;
+        let source = row[source_col].as_str();
+        let target = row[target_col].as_str();
+
+        let problem = if source.is_empty() || target.is_empty() {
+            Some("missing source or target field".to_string())
+        } else if !has_field(&state.source_metadata, source) {
+            Some(format!("'{}' not found on {}", source, state.source_entity))
+        } else if !has_field(&state.target_metadata, target) {
+            Some(format!("'{}' not found on {}", target, state.target_entity))
+        } else {
+            None
+        };
+
+        match problem {
+            Some(problem) => unparsed.push(format!("Line {}: {}", line, problem)),
+            None => {
+                csv_data.imported_mappings.insert(source.to_string(), target.to_string());
+            }
+        }
+    }
+
+    log::info!("CSV columns {} -> {}: {} mappings, {} rows skipped",
+        table.headers[source_col], table.headers[target_col], csv_data.imported_mappings.len(), unparsed.len());
+
+    merge_csv_data(state, csv_data, filename, unparsed)
+}
+
+/// Open the picker of other comparisons to copy mappings from
+pub fn handle_open_comparison_picker(state: &mut State) -> Command<Msg> {
+    state.show_import_modal = false;
+    state.comparison_picker = Some(super::super::app::ComparisonPicker::new());
+
+    let source_entity = state.source_entity.clone();
+    let target_entity = state.target_entity.clone();
+    Command::batch(vec![
+        Command::perform(
+            async move {
+                other_comparisons(&source_entity, &target_entity).await.map_err(|e| e.to_string())
+            },
+            Msg::ComparisonsLoaded,
+        ),
+        Command::set_focus(crate::tui::FocusId::new("copy-comparison-list")),
+    ])
+}
+
+pub fn handle_close_comparison_picker(state: &mut State) -> Command<Msg> {
+    state.comparison_picker = None;
+    Command::None
+}
+
+pub fn handle_comparisons_loaded(state: &mut State, result: Result<Vec<SavedComparison>, String>) -> Command<Msg> {
+    if let Some(picker) = &mut state.comparison_picker {
+        picker.comparisons = Resource::from_result(result);
+    }
+    Command::None
+}
+
+pub fn handle_comparison_picker_navigate(state: &mut State, key: KeyCode) -> Command<Msg> {
+    if let Some(picker) = &mut state.comparison_picker {
+        let count = match &picker.comparisons {
+            Resource::Success(comparisons) => comparisons.len(),
+            _ => 0,
+        };
+        picker.list_state.handle_key(key, count, 20);
+    }
+    Command::None
+}
+
+/// Load the mappings of the picked comparison
+pub fn handle_copy_from_comparison(state: &mut State, idx: usize) -> Command<Msg> {
+    let comparison = match state.comparison_picker.take().map(|picker| picker.comparisons) {
+        Some(Resource::Success(comparisons)) => comparisons.into_iter().nth(idx),
+        _ => None,
+    };
+    let Some(comparison) = comparison else {
+        return Command::None;
+    };
+
+    let label = format!("{} / {}", comparison.migration_name, comparison.name);
+    Command::perform(
+        async move {
+            let config = crate::global_config();
+            let (source, target) = (&comparison.source_entity, &comparison.target_entity);
+            let mappings = async {
+                Ok::<_, anyhow::Error>(ComparisonMappings {
+                    field_mappings: config.get_field_mappings(source, target).await?,
+                    prefix_mappings: config.get_prefix_mappings(source, target).await?,
+                    relationship_mappings: config.get_relationship_mappings(source, target).await?,
+                })
+            };
+            mappings.await.map_err(|e| e.to_string())
+        },
+        move |result| Msg::ComparisonMappingsLoaded(label.clone(), result),
+    )
+}
+
+/// Saved comparisons of every migration, except ones between this comparison's
+/// entities (they share its mappings); one per entity pair, most recently used first
+async fn other_comparisons(source_entity: &str, target_entity: &str) -> anyhow::Result<Vec<SavedComparison>> {
+    let config = crate::global_config();
+    let mut comparisons = Vec::new();
+    for migration in config.list_migrations().await? {
+        comparisons.extend(config.get_comparisons(&migration.name).await?);
+    }
+
+    comparisons.retain(|c| c.source_entity != source_entity || c.target_entity != target_entity);
+    comparisons.sort_by(|a, b| b.last_used.cmp(&a.last_used));
+    let mut seen = std::collections::HashSet::new();
+    comparisons.retain(|c| seen.insert((c.source_entity.clone(), c.target_entity.clone())));
+    Ok(comparisons)
+}
+
+/// Manual field, prefix and relationship mappings of another comparison
+#[derive(Clone, Debug, Default)]
+pub struct ComparisonMappings {
+    pub field_mappings: HashMap<String, Vec<String>>,
+    pub prefix_mappings: HashMap<String, Vec<String>>,
+    pub relationship_mappings: HashMap<String, Vec<String>>,
+}
+
+/// Result of copying one kind of mapping
+#[derive(Debug, Default)]
+struct CopyOutcome {
+    /// (source, targets before, targets after) per changed source
+    changes: Vec<(String, Option<Vec<String>>, Vec<String>)>,
+    added: Vec<(String, String)>,
+    updated: Vec<(String, String)>,
+    skipped: Vec<String>,
+}
+
+/// Add the copied targets to `existing`, keeping what is already there
+///
+/// Sources and targets that don't exist in this comparison are skipped and
+/// reported; targets a source already maps to are left alone.
+fn merge_copied(
+    existing: &mut HashMap<String, Vec<String>>,
+    copied: &HashMap<String, Vec<String>>,
+    kind: &str,
+    source_exists: impl Fn(&str) -> Option<String>,
+    target_exists: impl Fn(&str) -> Option<String>,
+) -> CopyOutcome {
+    let mut outcome = CopyOutcome::default();
+    let mut sources: Vec<&String> = copied.keys().collect();
+    sources.sort();
+
+    for source in sources {
+        if let Some(problem) = source_exists(source) {
+            outcome.skipped.push(format!("[{}] {}: {}", kind, source, problem));
+            continue;
+        }
+
+        let before = existing.get(source).cloned();
+        let mut after = before.clone().unwrap_or_default();
+        let mut new_targets = Vec::new();
+        for target in &copied[source] {
+            if after.contains(target) {
+                continue;
+            }
+            match target_exists(target) {
+                Some(problem) => outcome.skipped.push(format!("[{}] {} → {}: {}", kind, source, target, problem)),
+                None => {
+                    after.push(target.clone());
+                    new_targets.push(target.clone());
+                }
+            }
+        }
+        if new_targets.is_empty() {
+            continue;
+        }
+
+        let label = format!("[{}] {}", kind, source);
+        let list = if before.is_some() { &mut outcome.updated } else { &mut outcome.added };
+        list.extend(new_targets.into_iter().map(|target| (label.clone(), target)));
+        existing.insert(source.clone(), after.clone());
+        outcome.changes.push((source.clone(), before, after));
+    }
+    outcome
+}
+
+/// Merge the copied mappings into this comparison and show what changed
+pub fn handle_comparison_mappings_loaded(state: &mut State, label: String, result: Result<ComparisonMappings, String>) -> Command<Msg> {
+    let copied = match result {
+        Ok(copied) => copied,
+        Err(e) => {
+            log::error!("Failed to load mappings of {}: {}", label, e);
+            return Command::notify(format!("Failed to load mappings of {}: {}", label, e), ToastLevel::Error);
+        }
+    };
+
+    // Without metadata there is nothing to check against
+    let not_found = |metadata: &Resource<crate::api::EntityMetadata>, entity: &str, name: &str, relationship: bool| {
+        let exists = match metadata {
+            Resource::Success(metadata) if relationship => metadata.relationships.iter().any(|r| r.name == name),
+            Resource::Success(metadata) => metadata.fields.iter().any(|f| f.logical_name == name),
+            _ => true,
+        };
+        (!exists).then(|| format!("not found on {}", entity))
+    };
+    let (source_metadata, target_metadata) = (&state.source_metadata, &state.target_metadata);
+    let (source_entity, target_entity) = (state.source_entity.as_str(), state.target_entity.as_str());
+
+    let fields = merge_copied(
+        &mut state.field_mappings,
+        &copied.field_mappings,
+        "manual",
+        |name| not_found(source_metadata, source_entity, name, false),
+        |name| not_found(target_metadata, target_entity, name, false),
+    );
+    // Prefixes aren't fields, so there is nothing to check them against
+    let prefixes = merge_copied(&mut state.prefix_mappings, &copied.prefix_mappings, "prefix", |_| None, |_| None);
+    let relationships = merge_copied(
+        &mut state.relationship_mappings,
+        &copied.relationship_mappings,
+        "relationship",
+        |name| not_found(source_metadata, source_entity, name, true),
+        |name| not_found(target_metadata, target_entity, name, true),
+    );
+
+    let mut actions = Vec::new();
+    for (source, before, after) in fields.changes {
+        state.autosave.mark_field(&source);
+        actions.push(MappingAction::FieldMapping { source, before, after: Some(after) });
+    }
+    for (source, before, after) in prefixes.changes {
+        state.autosave.mark_prefix(&source);
+        actions.push(MappingAction::PrefixMapping { source, before, after: Some(after) });
+    }
+    for (source, before, after) in relationships.changes {
+        state.autosave.mark_relationship(&source);
+        actions.push(MappingAction::RelationshipMapping { source, before, after: Some(after) });
+    }
+    state.mapping_history.record(actions);
+
+    let mut results = super::super::app::ImportResults {
+        filename: label.clone(),
+        added: Vec::new(),
+        updated: Vec::new(),
+        removed: Vec::new(),
+        unparsed: Vec::new(),
+    };
+    for outcome in [fields.added, prefixes.added, relationships.added] {
+        results.added.extend(outcome);
+    }
+    for outcome in [fields.updated, prefixes.updated, relationships.updated] {
+        results.updated.extend(outcome);
+    }
+    for outcome in [fields.skipped, prefixes.skipped, relationships.skipped] {
+        results.unparsed.extend(outcome);
+    }
+    log::info!("Copied mappings from {}: {} added, {} updated, {} skipped",
+        label, results.added.len(), results.updated.len(), results.unparsed.len());
+
+    state.import_results = Some(results);
+    state.import_results_list = crate::tui::widgets::ListState::new();
+    state.show_import_results_modal = true;
+
+    if let (Resource::Success(source_metadata), Resource::Success(target_metadata)) = (
+        &state.source_metadata,
+        &state.target_metadata,
+    ) {
+        let (field_matches, relationship_matches, entity_matches, source_entities, target_entities) =
+            super::super::matching::recompute_all_matches(
+                source_metadata,
+                target_metadata,
+                &state.field_mappings,
+                &state.imported_mappings,
+                &state.prefix_mappings,
+                &state.relationship_mappings,
+                &state.examples,
+                &state.source_entity,
+                &state.target_entity,
+                state.match_settings,
+            );
+
+        state.field_matches = field_matches;
+        state.relationship_matches = relationship_matches;
+        state.entity_matches = entity_matches;
+        state.source_entities = source_entities;
+        state.target_entities = target_entities;
+    }
+
+    Command::None
+}
+
 /// Handle CSV data loaded - merge into multiple mapping types
-pub fn handle_csv_loaded(state: &mut State, csv_data: crate::csv_parser::CsvImportData, filename: String) -> Command<Msg> {
+pub fn handle_csv_loaded(state: &mut State, csv_data: CsvImportData, filename: String) -> Command<Msg> {
+    merge_csv_data(state, csv_data, filename, vec![])
+}
+
+fn merge_csv_data(state: &mut State, csv_data: CsvImportData, filename: String, unparsed: Vec<String>) -> Command<Msg> {
     log::info!("Loading CSV data from {}", filename);
 
     // Track changes across all mapping types for import results
@@ -174,6 +537,9 @@ pub fn handle_csv_loaded(state: &mut State, csv_data: crate::csv_parser::CsvImpo
     let converted: HashMap<String, Vec<String>> = csv_data.manual_mappings.iter()
         .map(|(k, v)| (k.clone(), vec![v.clone()]))
         .collect();
+    for src in converted.keys() {
+        state.autosave.mark_field(src);
+    }
     state.field_mappings.extend(converted);
 
     // Process prefix mappings (MERGE) - convert single values to Vec for 1-to-N support
@@ -191,6 +557,9 @@ pub fn handle_csv_loaded(state: &mut State, csv_data: crate::csv_parser::CsvImpo
     let converted: HashMap<String, Vec<String>> = csv_data.prefix_mappings.iter()
         .map(|(k, v)| (k.clone(), vec![v.clone()]))
         .collect();
+    for src in converted.keys() {
+        state.autosave.mark_prefix(src);
+    }
     state.prefix_mappings.extend(converted);
 
     // Process imported mappings (MERGE) - convert single values to Vec for 1-to-N support
@@ -238,7 +607,7 @@ pub fn handle_csv_loaded(state: &mut State, csv_data: crate::csv_parser::CsvImpo
         added: all_added,
         updated: all_updated,
         removed: all_removed,
-        unparsed: vec![],
+        unparsed,
     });
     state.show_import_results_modal = true;
     state.show_import_modal = false;
@@ -255,9 +624,11 @@ pub fn handle_csv_loaded(state: &mut State, csv_data: crate::csv_parser::CsvImpo
                 &state.field_mappings,
                 &state.imported_mappings,
                 &state.prefix_mappings,
+                &state.relationship_mappings,
                 &state.examples,
                 &state.source_entity,
                 &state.target_entity,
+                state.match_settings,
             );
 
         state.field_matches = field_matches;
@@ -267,48 +638,24 @@ pub fn handle_csv_loaded(state: &mut State, csv_data: crate::csv_parser::CsvImpo
         state.target_entities = target_entities;
     }
 
-    // Persist all mapping types to config (async, don't wait)
+    // Manual and prefix mappings and ignores go through auto-save; imported
+    // mappings are saved as a whole set right away
+    if !csv_data.source_ignores.is_empty() || !csv_data.target_ignores.is_empty() {
+        state.autosave.mark_ignores();
+    }
     let source_entity = state.source_entity.clone();
     let target_entity = state.target_entity.clone();
-    let field_mappings = state.field_mappings.clone();
-    let prefix_mappings = state.prefix_mappings.clone();
     let imported_mappings = state.imported_mappings.clone();
     let import_file = state.import_source_file.clone();
-    let ignored_items = state.ignored_items.clone();
 
     Command::perform(
         async move {
-            let config = crate::global_config();
-
-            // Save field mappings (loop through source->targets pairs, save each target individually)
-            for (src, tgts) in field_mappings {
-                for tgt in tgts {
-                    if let Err(e) = config.set_field_mapping(&source_entity, &target_entity, &src, &tgt).await {
-                        log::error!("Failed to save field mapping {} -> {}: {}", src, tgt, e);
-                    }
-                }
-            }
-
-            // Save prefix mappings (loop through source->targets pairs, save each target individually)
-            for (src, tgts) in prefix_mappings {
-                for tgt in tgts {
-                    if let Err(e) = config.set_prefix_mapping(&source_entity, &target_entity, &src, &tgt).await {
-                        log::error!("Failed to save prefix mapping {} -> {}: {}", src, tgt, e);
-                    }
-                }
-            }
-
-            // Save imported mappings (use existing batch method)
             if let Some(file) = import_file {
+                let config = crate::global_config();
                 if let Err(e) = config.set_imported_mappings(&source_entity, &target_entity, &imported_mappings, &file).await {
                     log::error!("Failed to save imported mappings: {}", e);
                 }
             }
-
-            // Save ignored items (use existing batch method)
-            if let Err(e) = config.set_ignored_items(&source_entity, &target_entity, &ignored_items).await {
-                log::error!("Failed to save ignored items: {}", e);
-            }
         },
         |_| Msg::CloseImportModal  // Dummy message, modal already closed
     )
@@ -381,9 +728,11 @@ pub fn handle_mappings_loaded(state: &mut State, mappings: HashMap<String, Strin
                 &state.field_mappings,
                 &state.imported_mappings,
                 &state.prefix_mappings,
+                &state.relationship_mappings,
                 &state.examples,
                 &state.source_entity,
                 &state.target_entity,
+                state.match_settings,
             );
 
         state.field_matches = field_matches;
@@ -436,9 +785,11 @@ pub fn handle_clear_imported(state: &mut State) -> Command<Msg> {
                 &state.field_mappings,
                 &state.imported_mappings, // Now empty
                 &state.prefix_mappings,
+                &state.relationship_mappings,
                 &state.examples,
                 &state.source_entity,
                 &state.target_entity,
+                state.match_settings,
             );
 
         state.field_matches = field_matches;
@@ -554,3 +905,45 @@ pub fn handle_results_set_viewport_height(state: &mut State, height: usize) -> C
     }
     Command::None
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_copied_mappings_skip_missing_fields_and_keep_existing_targets() {
+        let mut existing = HashMap::from([
+            ("cgk_name".to_string(), vec!["nrq_name".to_string()]),
+            ("cgk_code".to_string(), vec!["nrq_code".to_string()]),
+        ]);
+        let copied = HashMap::from([
+            ("cgk_name".to_string(), vec!["nrq_name".to_string(), "nrq_title".to_string()]),
+            ("cgk_code".to_string(), vec!["nrq_code".to_string()]),
+            ("cgk_date".to_string(), vec!["nrq_date".to_string(), "nrq_gone".to_string()]),
+            ("cgk_other".to_string(), vec!["nrq_other".to_string()]),
+        ]);
+
+        let outcome = merge_copied(
+            &mut existing,
+            &copied,
+            "manual",
+            |name| (name == "cgk_other").then(|| "not found on cgk_deadline".to_string()),
+            |name| (name == "nrq_gone").then(|| "not found on nrq_deadline".to_string()),
+        );
+
+        assert_eq!(existing["cgk_name"], vec!["nrq_name", "nrq_title"]);
+        assert_eq!(existing["cgk_code"], vec!["nrq_code"]);
+        assert_eq!(existing["cgk_date"], vec!["nrq_date"]);
+        assert!(!existing.contains_key("cgk_other"));
+
+        assert_eq!(outcome.added, vec![("[manual] cgk_date".to_string(), "nrq_date".to_string())]);
+        assert_eq!(outcome.updated, vec![("[manual] cgk_name".to_string(), "nrq_title".to_string())]);
+        assert_eq!(outcome.skipped, vec![
+            "[manual] cgk_date → nrq_gone: not found on nrq_deadline".to_string(),
+            "[manual] cgk_other: not found on cgk_deadline".to_string(),
+        ]);
+        // Unchanged sources aren't recorded, so undo only reverts what the copy added
+        assert_eq!(outcome.changes.len(), 2);
+        assert_eq!(outcome.changes[1], ("cgk_name".to_string(), Some(vec!["nrq_name".to_string()]), vec!["nrq_name".to_string(), "nrq_title".to_string()]));
+    }
+}
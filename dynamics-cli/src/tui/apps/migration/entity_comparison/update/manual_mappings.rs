@@ -3,6 +3,7 @@ use crate::tui::Resource;
 use super::super::Msg;
 use super::super::app::State;
 use super::super::matching::recompute_all_matches;
+use super::history::MappingAction;
 
 pub fn handle_open_modal(state: &mut State) -> Command<Msg> {
     state.show_manual_mappings_modal = true;
@@ -34,7 +35,12 @@ pub fn handle_delete_manual_mapping(state: &mut State) -> Command<Msg> {
             let source_field = source_field.to_string();
 
             // Remove from state
-            state.field_mappings.remove(&source_field);
+            let before = state.field_mappings.remove(&source_field);
+            state.mapping_history.record(vec![MappingAction::FieldMapping {
+                source: source_field.clone(),
+                before,
+                after: None,
+            }]);
 
             // Recompute matches
             if let (Resource::Success(source), Resource::Success(target)) =
@@ -47,9 +53,11 @@ pub fn handle_delete_manual_mapping(state: &mut State) -> Command<Msg> {
                         &state.field_mappings,
                         &state.imported_mappings,
                         &state.prefix_mappings,
+                        &state.relationship_mappings,
                         &state.examples,
                         &state.source_entity,
                         &state.target_entity,
+                        state.match_settings,
                     );
                 state.field_matches = field_matches;
                 state.relationship_matches = relationship_matches;
@@ -58,15 +66,8 @@ pub fn handle_delete_manual_mapping(state: &mut State) -> Command<Msg> {
                 state.target_entities = target_entities;
             }
 
-            // Delete from database
-            let source_entity = state.source_entity.clone();
-            let target_entity = state.target_entity.clone();
-            tokio::spawn(async move {
-                let config = crate::global_config();
-                if let Err(e) = config.delete_field_mapping(&source_entity, &target_entity, &source_field).await {
-                    log::error!("Failed to delete field mapping: {}", e);
-                }
-            });
+            // Removed from the database once edits settle
+            state.autosave.mark_field(&source_field);
         }
     }
 
@@ -1,8 +1,9 @@
 use crate::tui::command::Command;
-use crate::tui::Resource;
+use crate::tui::{Resource, ToastLevel};
 use super::super::{Msg, ActiveTab};
 use super::super::app::State;
 use super::super::matching::recompute_all_matches;
+use super::history::MappingAction;
 
 pub fn handle_create_manual_mapping(state: &mut State) -> Command<Msg> {
     // Get all selected items from source tree (multi-selection support)
@@ -50,6 +51,8 @@ pub fn handle_create_manual_mapping(state: &mut State) -> Command<Msg> {
             "1-to-1"
         };
 
+        let mut actions = Vec::new();
+
         // Case 1: 1-to-N (one source, multiple targets)
         if source_count == 1 {
             let source_id = &source_ids[0];
@@ -79,28 +82,7 @@ pub fn handle_create_manual_mapping(state: &mut State) -> Command<Msg> {
             };
 
             // Add all targets to state mappings (1-to-N support)
-            state.field_mappings.insert(source_key.clone(), target_keys.clone());
-
-            // Save to database: delete old mappings first, then insert new ones
-            // This ensures we replace (not append to) existing mappings
-            let source_entity = state.source_entity.clone();
-            let target_entity = state.target_entity.clone();
-            tokio::spawn(async move {
-                let config = crate::global_config();
-
-                // First delete all existing targets for this source
-                if let Err(e) = config.delete_field_mapping(&source_entity, &target_entity, &source_key).await {
-                    log::error!("Failed to delete old field mappings for {}: {}", source_key, e);
-                    return;
-                }
-
-                // Then add new targets
-                for target_key in target_keys {
-                    if let Err(e) = config.set_field_mapping(&source_entity, &target_entity, &source_key, &target_key).await {
-                        log::error!("Failed to save field mapping {} -> {}: {}", source_key, target_key, e);
-                    }
-                }
-            });
+            actions.push(set_manual_mapping(state, source_key, target_keys));
         }
         // Case 2: N-to-1 or 1-to-1 (multiple/single sources, one target)
         else {
@@ -131,29 +113,12 @@ pub fn handle_create_manual_mapping(state: &mut State) -> Command<Msg> {
                 };
 
                 // Add to state mappings (wrap single target in Vec)
-                state.field_mappings.insert(source_key.clone(), vec![target_key.clone()]);
-
-                // Save to database: delete old mappings first, then insert new one
-                let source_entity = state.source_entity.clone();
-                let target_entity = state.target_entity.clone();
-                let source_key_clone = source_key.clone();
-                let target_key_clone = target_key.clone();
-                tokio::spawn(async move {
-                    let config = crate::global_config();
-
-                    // First delete all existing targets for this source
-                    if let Err(e) = config.delete_field_mapping(&source_entity, &target_entity, &source_key_clone).await {
-                        log::warn!("Failed to delete old field mappings for {}: {}", source_key_clone, e);
-                    }
-
-                    // Then add new target
-                    if let Err(e) = config.set_field_mapping(&source_entity, &target_entity, &source_key_clone, &target_key_clone).await {
-                        log::error!("Failed to save field mapping: {}", e);
-                    }
-                });
+                actions.push(set_manual_mapping(state, source_key, vec![target_key.clone()]));
             }
         }
 
+        state.mapping_history.record(actions);
+
         // Recompute matches once after all mappings are added
         if let (Resource::Success(source), Resource::Success(target)) =
             (&state.source_metadata, &state.target_metadata)
@@ -165,9 +130,11 @@ pub fn handle_create_manual_mapping(state: &mut State) -> Command<Msg> {
                     &state.field_mappings,
                     &state.imported_mappings,
                     &state.prefix_mappings,
+                    &state.relationship_mappings,
                     &state.examples,
                     &state.source_entity,
                     &state.target_entity,
+                    state.match_settings,
                 );
             state.field_matches = field_matches;
             state.relationship_matches = relationship_matches;
@@ -204,9 +171,34 @@ pub fn handle_delete_manual_mapping(state: &mut State) -> Command<Msg> {
             ActiveTab::Forms | ActiveTab::Views => source_id.clone(),
         };
 
-        // Try to remove from field_mappings and get the targets that were deleted
-        if let Some(deleted_targets) = state.field_mappings.remove(&source_key) {
+        // Relationship mappings live in their own table; ones saved before that
+        // are still in field_mappings
+        let relationship = state.active_tab == ActiveTab::Relationships
+            && state.relationship_mappings.contains_key(&source_key);
+
+        // Try to remove the mapping and get the targets that were deleted
+        let deleted = if relationship {
+            state.relationship_mappings.remove(&source_key)
+        } else {
+            state.field_mappings.remove(&source_key)
+        };
+
+        if let Some(deleted_targets) = deleted {
             let target_count = deleted_targets.len();
+            let action = if relationship {
+                MappingAction::RelationshipMapping {
+                    source: source_key.clone(),
+                    before: Some(deleted_targets.clone()),
+                    after: None,
+                }
+            } else {
+                MappingAction::FieldMapping {
+                    source: source_key.clone(),
+                    before: Some(deleted_targets.clone()),
+                    after: None,
+                }
+            };
+            state.mapping_history.record(vec![action]);
 
             // Log what's being deleted
             if target_count > 1 {
@@ -231,9 +223,11 @@ pub fn handle_delete_manual_mapping(state: &mut State) -> Command<Msg> {
                         &state.field_mappings,
                         &state.imported_mappings,
                         &state.prefix_mappings,
+                        &state.relationship_mappings,
                         &state.examples,
                         &state.source_entity,
                         &state.target_entity,
+                        state.match_settings,
                     );
                 state.field_matches = field_matches;
                 state.relationship_matches = relationship_matches;
@@ -242,20 +236,84 @@ pub fn handle_delete_manual_mapping(state: &mut State) -> Command<Msg> {
                 state.target_entities = target_entities;
             }
 
-            // Delete from database (deletes all targets for this source)
-            let source_entity = state.source_entity.clone();
-            let target_entity = state.target_entity.clone();
-            tokio::spawn(async move {
-                let config = crate::global_config();
-                if let Err(e) = config.delete_field_mapping(&source_entity, &target_entity, &source_key).await {
-                    log::error!("Failed to delete field mapping: {}", e);
-                }
-            });
+            // Removed from the database (all targets for this source) once edits settle
+            if relationship {
+                state.autosave.mark_relationship(&source_key);
+            } else {
+                state.autosave.mark_field(&source_key);
+            }
         }
     }
     Command::None
 }
 
+/// Map `source` to `targets`, in the relationship mappings on the Relationships
+/// tab and the field mappings otherwise; saved (replacing any existing targets)
+/// once edits settle
+fn set_manual_mapping(state: &mut State, source: String, targets: Vec<String>) -> MappingAction {
+    if state.active_tab == ActiveTab::Relationships {
+        let before = state.relationship_mappings.insert(source.clone(), targets.clone());
+        state.autosave.mark_relationship(&source);
+        MappingAction::RelationshipMapping { source, before, after: Some(targets) }
+    } else {
+        let before = state.field_mappings.insert(source.clone(), targets.clone());
+        state.autosave.mark_field(&source);
+        MappingAction::FieldMapping { source, before, after: Some(targets) }
+    }
+}
+
+/// Re-run auto-matching with the current `tui.match_*` options; manual and
+/// imported mappings are kept as they are
+pub fn handle_rerun_auto_match(state: &mut State) -> Command<Msg> {
+    state.match_settings = crate::global_runtime_config().match_settings;
+
+    let (Resource::Success(source), Resource::Success(target)) =
+        (&state.source_metadata, &state.target_metadata)
+    else {
+        return Command::notify("Metadata is still loading", ToastLevel::Warning);
+    };
+
+    let (field_matches, relationship_matches, entity_matches, source_entities, target_entities) =
+        recompute_all_matches(
+            source,
+            target,
+            &state.field_mappings,
+            &state.imported_mappings,
+            &state.prefix_mappings,
+            &state.relationship_mappings,
+            &state.examples,
+            &state.source_entity,
+            &state.target_entity,
+            state.match_settings,
+        );
+
+    // Fields tab only; field_matches also holds the form and view paths
+    let auto_matched = source.fields.iter()
+        .filter_map(|field| field_matches.get(&field.logical_name))
+        .filter(|m| {
+            m.primary_target()
+                .and_then(|primary| m.match_types.get(primary))
+                .is_some_and(|mt| mt.is_automatic())
+        })
+        .count();
+
+    state.field_matches = field_matches;
+    state.relationship_matches = relationship_matches;
+    state.entity_matches = entity_matches;
+    state.source_entities = source_entities;
+    state.target_entities = target_entities;
+
+    Command::notify(
+        format!(
+            "Auto-matched {} fields ({}, threshold {})",
+            auto_matched,
+            state.match_settings.algorithm.label(),
+            state.match_settings.threshold,
+        ),
+        ToastLevel::Info,
+    )
+}
+
 pub fn handle_cycle_hide_mode(state: &mut State) -> Command<Msg> {
     state.hide_mode = state.hide_mode.toggle();
     Command::None
@@ -271,7 +329,36 @@ pub fn handle_toggle_technical_names(state: &mut State) -> Command<Msg> {
     Command::None
 }
 
+pub fn handle_open_export_modal(state: &mut State) -> Command<Msg> {
+    state.show_export_modal = true;
+    Command::None
+}
+
+pub fn handle_close_export_modal(state: &mut State) -> Command<Msg> {
+    state.show_export_modal = false;
+    Command::None
+}
+
 pub fn handle_export_to_excel(state: &mut State) -> Command<Msg> {
+    export(state, "xlsx", super::super::export::MigrationExporter::export_and_open)
+}
+
+pub fn handle_export_to_markdown(state: &mut State) -> Command<Msg> {
+    export(state, "md", super::super::export::MarkdownExporter::export_to_markdown)
+}
+
+pub fn handle_export_to_csharp(state: &mut State) -> Command<Msg> {
+    export(state, "cs", super::super::export::CsharpExporter::export_to_csharp)
+}
+
+/// Write the report in the background with the given exporter
+fn export(
+    state: &mut State,
+    extension: &str,
+    exporter: fn(&State, &str) -> anyhow::Result<()>,
+) -> Command<Msg> {
+    state.show_export_modal = false;
+
     // Check if metadata is loaded
     if !matches!(state.source_metadata, Resource::Success(_)) ||
        !matches!(state.target_metadata, Resource::Success(_)) {
@@ -282,11 +369,12 @@ pub fn handle_export_to_excel(state: &mut State) -> Command<Msg> {
     // Generate filename with timestamp
     let timestamp = chrono::Local::now().format("%Y%m%d_%H%M%S");
     let filename = format!(
-        "{}_{}_to_{}_{}.xlsx",
+        "{}_{}_to_{}_{}.{}",
         state.migration_name,
         state.source_entity,
         state.target_entity,
-        timestamp
+        timestamp,
+        extension
     );
 
     // Get output directory from config or use current directory
@@ -294,16 +382,29 @@ pub fn handle_export_to_excel(state: &mut State) -> Command<Msg> {
 
     // Perform export in background
     let state_clone = state.clone();
-    tokio::spawn(async move {
-        match super::super::export::MigrationExporter::export_and_open(&state_clone, output_path.to_str().unwrap()) {
-            Ok(_) => {
-                log::info!("Successfully exported to {}", filename);
-            }
-            Err(e) => {
-                log::error!("Failed to export to Excel: {}", e);
-            }
-        }
-    });
+    Command::perform(
+        async move {
+            tokio::task::spawn_blocking(move || {
+                exporter(&state_clone, output_path.to_str().unwrap())
+                    .map(|_| filename)
+                    .map_err(|e| e.to_string())
+            })
+            .await
+            .unwrap_or_else(|e| Err(e.to_string()))
+        },
+        Msg::ExportFinished,
+    )
+}
 
-    Command::None
+pub fn handle_export_finished(result: Result<String, String>) -> Command<Msg> {
+    match result {
+        Ok(filename) => {
+            log::info!("Successfully exported to {}", filename);
+            Command::notify(format!("Exported to {}", filename), ToastLevel::Success)
+        }
+        Err(e) => {
+            log::error!("Failed to export: {}", e);
+            Command::notify(format!("Export failed: {}", e), ToastLevel::Error)
+        }
+    }
 }
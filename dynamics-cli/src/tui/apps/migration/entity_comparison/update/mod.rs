@@ -8,6 +8,8 @@ pub mod data_loading;
 pub mod import;
 pub mod ignore;
 pub mod search;
+pub mod history;
+pub mod autosave;
 
 use crate::tui::command::Command;
 use super::Msg;
@@ -20,6 +22,8 @@ pub fn update(state: &mut State, msg: Msg) -> Command<Msg> {
         Msg::ConfirmBack => navigation::handle_confirm_back(),
         Msg::CancelBack => navigation::handle_cancel_back(state),
         Msg::SwitchTab(n) => navigation::handle_switch_tab(state, n),
+        Msg::NextUnmatched => navigation::handle_jump_to_unmatched(state, true),
+        Msg::PrevUnmatched => navigation::handle_jump_to_unmatched(state, false),
 
         // Tree events
         Msg::SourceTreeEvent(event) => tree_events::handle_source_tree_event(state, event),
@@ -33,7 +37,7 @@ pub fn update(state: &mut State, msg: Msg) -> Command<Msg> {
 
         // Data loading
         Msg::ParallelDataLoaded(idx, result) => data_loading::handle_parallel_data_loaded(state, idx, result),
-        Msg::MappingsLoaded(fm, pm, im, isf, ep, ig) => data_loading::handle_mappings_loaded(state, fm, pm, im, isf, ep, ig),
+        Msg::MappingsLoaded(fm, pm, rm, im, isf, ep, ig) => data_loading::handle_mappings_loaded(state, fm, pm, rm, im, isf, ep, ig),
         Msg::Refresh => data_loading::handle_refresh(state),
 
         // Mappings
@@ -42,12 +46,16 @@ pub fn update(state: &mut State, msg: Msg) -> Command<Msg> {
         Msg::CycleHideMode => mappings::handle_cycle_hide_mode(state),
         Msg::ToggleSortMode => mappings::handle_toggle_sort_mode(state),
         Msg::ToggleTechnicalNames => mappings::handle_toggle_technical_names(state),
+        Msg::Undo => history::handle_undo(state),
+        Msg::Redo => history::handle_redo(state),
+        Msg::RerunAutoMatch => mappings::handle_rerun_auto_match(state),
 
         // Examples
         Msg::OpenExamplesModal => examples::handle_open_modal(state),
         Msg::CloseExamplesModal => examples::handle_close_modal(state),
         Msg::ExamplesListNavigate(key) => examples::handle_list_navigate(state, key),
         Msg::ExamplesListSelect(idx) => examples::handle_list_select(state, idx),
+        Msg::ExamplesListEvent(event) => examples::handle_list_event(state, event),
         Msg::SourceInputEvent(event) => examples::handle_source_input_event(state, event),
         Msg::TargetInputEvent(event) => examples::handle_target_input_event(state, event),
         Msg::LabelInputEvent(event) => examples::handle_label_input_event(state, event),
@@ -56,6 +64,10 @@ pub fn update(state: &mut State, msg: Msg) -> Command<Msg> {
         Msg::ExampleDataFetched(id, result) => examples::handle_example_data_fetched(state, id, result),
         Msg::CycleExamplePair => examples::handle_cycle_example_pair(state),
         Msg::ToggleExamples => examples::handle_toggle_examples(state),
+        Msg::OpenExampleDiff => examples::handle_open_diff(state),
+        Msg::CloseExampleDiff => examples::handle_close_diff(state),
+        Msg::ExampleDiffTreeEvent(event) => examples::handle_diff_tree_event(state, event),
+        Msg::ExampleDiffViewportHeight(height) => examples::handle_diff_viewport_height(state, height),
 
         // Prefix mappings
         Msg::OpenPrefixMappingsModal => prefix_mappings::handle_open_modal(state),
@@ -66,6 +78,7 @@ pub fn update(state: &mut State, msg: Msg) -> Command<Msg> {
         Msg::PrefixTargetInputEvent(event) => prefix_mappings::handle_target_input_event(state, event),
         Msg::AddPrefixMapping => prefix_mappings::handle_add_prefix_mapping(state),
         Msg::DeletePrefixMapping => prefix_mappings::handle_delete_prefix_mapping(state),
+        Msg::ApplyPrefixMapping => prefix_mappings::handle_apply_prefix_mapping(state),
 
         // Manual mappings
         Msg::OpenManualMappingsModal => manual_mappings::handle_open_modal(state),
@@ -88,7 +101,12 @@ pub fn update(state: &mut State, msg: Msg) -> Command<Msg> {
         Msg::SearchSelectFirstMatch => search::handle_search_select_first_match(state),
 
         // Export
+        Msg::OpenExportModal => mappings::handle_open_export_modal(state),
+        Msg::CloseExportModal => mappings::handle_close_export_modal(state),
         Msg::ExportToExcel => mappings::handle_export_to_excel(state),
+        Msg::ExportToMarkdown => mappings::handle_export_to_markdown(state),
+        Msg::ExportToCsharp => mappings::handle_export_to_csharp(state),
+        Msg::ExportFinished(result) => mappings::handle_export_finished(result),
 
         // Import from C# file or CSV
         Msg::OpenImportModal => import::handle_open_modal(state),
@@ -96,6 +114,17 @@ pub fn update(state: &mut State, msg: Msg) -> Command<Msg> {
         Msg::ImportFileSelected(path) => import::handle_file_selected(state, path),
         Msg::ImportMappingsLoaded(mappings, file) => import::handle_mappings_loaded(state, mappings, file),
         Msg::ImportCsvLoaded(csv_data, file) => import::handle_csv_loaded(state, csv_data, file),
+        Msg::ImportCsvTableLoaded(table, file) => import::handle_csv_table_loaded(state, table, file),
+        Msg::CsvSourceColumnEvent(event) => import::handle_csv_source_column_event(state, event),
+        Msg::CsvTargetColumnEvent(event) => import::handle_csv_target_column_event(state, event),
+        Msg::ConfirmCsvColumns => import::handle_confirm_csv_columns(state),
+        Msg::CancelCsvColumns => import::handle_cancel_csv_columns(state),
+        Msg::OpenComparisonPicker => import::handle_open_comparison_picker(state),
+        Msg::CloseComparisonPicker => import::handle_close_comparison_picker(state),
+        Msg::ComparisonsLoaded(result) => import::handle_comparisons_loaded(state, result),
+        Msg::ComparisonPickerNavigate(key) => import::handle_comparison_picker_navigate(state, key),
+        Msg::CopyFromComparison(idx) => import::handle_copy_from_comparison(state, idx),
+        Msg::ComparisonMappingsLoaded(label, result) => import::handle_comparison_mappings_loaded(state, label, result),
         Msg::ClearImportedMappings => import::handle_clear_imported(state),
         Msg::ImportNavigate(key) => import::handle_navigate(state, key),
         Msg::ImportSetViewportHeight(h) => import::handle_set_viewport_height(state, h),
@@ -111,8 +140,12 @@ pub fn update(state: &mut State, msg: Msg) -> Command<Msg> {
         Msg::IgnoreListNavigate(key) => ignore::handle_navigate(state, key),
         Msg::IgnoreListSelect(idx) => ignore::handle_select(state, idx),
         Msg::DeleteIgnoredItem => ignore::handle_delete_item(state),
-        Msg::ClearAllIgnored => ignore::handle_clear_all(state),
+        Msg::ClearAllIgnored => ignore::handle_request_clear_all(state),
+        Msg::ClearIgnoredInput(event) => ignore::handle_clear_input(state, event),
+        Msg::ConfirmClearAllIgnored => ignore::handle_clear_all(state),
+        Msg::CancelClearAllIgnored => ignore::handle_cancel_clear_all(state),
         Msg::IgnoreSetViewportHeight(h) => ignore::handle_set_viewport_height(state, h),
-        Msg::IgnoredItemsSaved => Command::None, // No-op message
+        Msg::AutoSaveTick => autosave::handle_tick(state),
+        Msg::MappingsSaved(result) => autosave::handle_saved(state, result),
     }
 }
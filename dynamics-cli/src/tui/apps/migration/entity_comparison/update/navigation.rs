@@ -1,6 +1,9 @@
 use crate::tui::command::{AppId, Command};
+use crate::tui::widgets::{TreeItem, TreeState};
+use crate::tui::ToastLevel;
 use super::super::{Msg, ActiveTab};
 use super::super::app::State;
+use super::super::tree_items::ComparisonTreeItem;
 
 pub fn handle_back(state: &mut State) -> Command<Msg> {
     state.show_back_confirmation = true;
@@ -22,3 +25,83 @@ pub fn handle_switch_tab(state: &mut State, n: usize) -> Command<Msg> {
     }
     Command::None
 }
+
+/// Move the source selection to the next (or previous) unmatched item, wrapping at the ends
+///
+/// Only rows currently shown are considered, so the hide mode, search filter
+/// and collapsed containers all apply; matched and ignored items are skipped.
+pub fn handle_jump_to_unmatched(state: &mut State, forward: bool) -> Command<Msg> {
+    let items = super::super::view::source_tree_items(state);
+    let tree_state = state.source_tree_for_tab();
+
+    let mut rows = Vec::new();
+    collect_visible_rows(&items, tree_state, &mut rows);
+
+    let Some(node_id) = next_unmatched(&rows, tree_state.selected(), forward) else {
+        return Command::notify("No unmatched items shown".to_string(), ToastLevel::Info);
+    };
+    tree_state.select_and_scroll(Some(node_id.clone()));
+    super::super::tree_sync::update_mirrored_selection(state, &node_id);
+    Command::None
+}
+
+/// Rows of the tree in display order, with whether each is an unmatched, unignored item
+fn collect_visible_rows(items: &[ComparisonTreeItem], tree_state: &TreeState, rows: &mut Vec<(String, bool)>) {
+    for item in items {
+        let id = item.id();
+        let unmatched = match item {
+            ComparisonTreeItem::Field(node) => node.match_info.is_none() && !node.is_ignored,
+            ComparisonTreeItem::Relationship(node) => node.match_info.is_none() && !node.is_ignored,
+            ComparisonTreeItem::Entity(node) => node.match_info.is_none() && !node.is_ignored,
+            _ => false,
+        };
+        let expanded = item.has_children() && tree_state.is_expanded(&id);
+        rows.push((id, unmatched));
+        if expanded {
+            collect_visible_rows(&item.children(), tree_state, rows);
+        }
+    }
+}
+
+/// First unmatched row after (or before) `selected`, wrapping around;
+/// without a selection the search starts at the top (or bottom)
+fn next_unmatched(rows: &[(String, bool)], selected: Option<&str>, forward: bool) -> Option<String> {
+    let len = rows.len();
+    let current = selected.and_then(|selected| rows.iter().position(|(id, _)| id == selected));
+    (1..=len)
+        .map(|step| match (current, forward) {
+            (Some(idx), true) => (idx + step) % len,
+            (Some(idx), false) => (idx + len - step) % len,
+            (None, true) => step - 1,
+            (None, false) => len - step,
+        })
+        .find(|&idx| rows[idx].1)
+        .map(|idx| rows[idx].0.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next_unmatched_skips_matched_and_wraps() {
+        let rows: Vec<(String, bool)> = [("name", false), ("code", true), ("date", false), ("owner", true)]
+            .into_iter()
+            .map(|(id, unmatched)| (id.to_string(), unmatched))
+            .collect();
+
+        assert_eq!(next_unmatched(&rows, Some("name"), true).as_deref(), Some("code"));
+        assert_eq!(next_unmatched(&rows, Some("code"), true).as_deref(), Some("owner"));
+        assert_eq!(next_unmatched(&rows, Some("owner"), true).as_deref(), Some("code"));
+        assert_eq!(next_unmatched(&rows, Some("code"), false).as_deref(), Some("owner"));
+        assert_eq!(next_unmatched(&rows, Some("date"), false).as_deref(), Some("code"));
+
+        // A selection that is filtered out starts from the ends
+        assert_eq!(next_unmatched(&rows, Some("hidden"), true).as_deref(), Some("code"));
+        assert_eq!(next_unmatched(&rows, None, false).as_deref(), Some("owner"));
+
+        let all_matched = vec![("name".to_string(), false)];
+        assert_eq!(next_unmatched(&all_matched, Some("name"), true), None);
+        assert_eq!(next_unmatched(&[], None, true), None);
+    }
+}
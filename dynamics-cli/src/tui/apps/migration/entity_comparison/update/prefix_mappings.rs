@@ -1,8 +1,11 @@
+use std::collections::HashSet;
+
 use crate::tui::command::Command;
-use crate::tui::Resource;
+use crate::tui::{Resource, ToastLevel};
 use super::super::Msg;
 use super::super::app::State;
 use super::super::matching::recompute_all_matches;
+use super::history::MappingAction;
 
 pub fn handle_open_modal(state: &mut State) -> Command<Msg> {
     state.show_prefix_mappings_modal = true;
@@ -55,7 +58,12 @@ pub fn handle_add_prefix_mapping(state: &mut State) -> Command<Msg> {
     }
 
     // Add to state (wrap single target in Vec for 1-to-N support)
-    state.prefix_mappings.insert(source_prefix.clone(), vec![target_prefix.clone()]);
+    let before = state.prefix_mappings.insert(source_prefix.clone(), vec![target_prefix.clone()]);
+    state.mapping_history.record(vec![MappingAction::PrefixMapping {
+        source: source_prefix.clone(),
+        before,
+        after: Some(vec![target_prefix.clone()]),
+    }]);
 
     // Recompute matches
     if let (Resource::Success(source), Resource::Success(target)) =
@@ -68,9 +76,11 @@ pub fn handle_add_prefix_mapping(state: &mut State) -> Command<Msg> {
                 &state.field_mappings,
                 &state.imported_mappings,
                 &state.prefix_mappings,
+                &state.relationship_mappings,
                 &state.examples,
                 &state.source_entity,
                 &state.target_entity,
+                state.match_settings,
             );
         state.field_matches = field_matches;
         state.relationship_matches = relationship_matches;
@@ -79,15 +89,8 @@ pub fn handle_add_prefix_mapping(state: &mut State) -> Command<Msg> {
         state.target_entities = target_entities;
     }
 
-    // Save to database
-    let source_entity = state.source_entity.clone();
-    let target_entity = state.target_entity.clone();
-    tokio::spawn(async move {
-        let config = crate::global_config();
-        if let Err(e) = config.set_prefix_mapping(&source_entity, &target_entity, &source_prefix, &target_prefix).await {
-            log::error!("Failed to save prefix mapping: {}", e);
-        }
-    });
+    // Saved once edits settle
+    state.autosave.mark_prefix(&source_prefix);
 
     // Clear inputs
     state.prefix_source_input.value.clear();
@@ -105,7 +108,12 @@ pub fn handle_delete_prefix_mapping(state: &mut State) -> Command<Msg> {
             let source_prefix = source_prefix.to_string();
 
             // Remove from state
-            state.prefix_mappings.remove(&source_prefix);
+            let before = state.prefix_mappings.remove(&source_prefix);
+            state.mapping_history.record(vec![MappingAction::PrefixMapping {
+                source: source_prefix.clone(),
+                before,
+                after: None,
+            }]);
 
             // Recompute matches
             if let (Resource::Success(source), Resource::Success(target)) =
@@ -118,9 +126,11 @@ pub fn handle_delete_prefix_mapping(state: &mut State) -> Command<Msg> {
                         &state.field_mappings,
                         &state.imported_mappings,
                         &state.prefix_mappings,
+                        &state.relationship_mappings,
                         &state.examples,
                         &state.source_entity,
                         &state.target_entity,
+                        state.match_settings,
                     );
                 state.field_matches = field_matches;
                 state.relationship_matches = relationship_matches;
@@ -129,17 +139,138 @@ pub fn handle_delete_prefix_mapping(state: &mut State) -> Command<Msg> {
                 state.target_entities = target_entities;
             }
 
-            // Delete from database
-            let source_entity = state.source_entity.clone();
-            let target_entity = state.target_entity.clone();
-            tokio::spawn(async move {
-                let config = crate::global_config();
-                if let Err(e) = config.delete_prefix_mapping(&source_entity, &target_entity, &source_prefix).await {
-                    log::error!("Failed to delete prefix mapping: {}", e);
-                }
-            });
+            // Removed from the database once edits settle
+            state.autosave.mark_prefix(&source_prefix);
         }
     }
 
     Command::None
 }
+
+/// Create manual mappings from the selected prefix mapping for every unmapped
+/// source field it covers, where the substituted target field exists
+pub fn handle_apply_prefix_mapping(state: &mut State) -> Command<Msg> {
+    let selected = state.prefix_mappings_list_state.selected()
+        .and_then(|idx| state.prefix_mappings.iter().nth(idx))
+        .map(|(source, targets)| (source.clone(), targets.clone()));
+    let Some((source_prefix, target_prefixes)) = selected else {
+        return Command::notify("Select a prefix mapping to apply".to_string(), ToastLevel::Warning);
+    };
+    let (Resource::Success(source), Resource::Success(target)) = (&state.source_metadata, &state.target_metadata) else {
+        return Command::notify("Metadata is still loading".to_string(), ToastLevel::Warning);
+    };
+
+    // Fields with a manual or imported mapping, or that are ignored, are left alone
+    let mut unmapped: Vec<&str> = source.fields.iter()
+        .map(|f| f.logical_name.as_str())
+        .filter(|name| !state.field_mappings.contains_key(*name) && !state.imported_mappings.contains_key(*name))
+        .filter(|name| !state.ignored_items.contains(&format!("fields:source:{}", name)))
+        .collect();
+    unmapped.sort();
+    let target_fields: HashSet<&str> = target.fields.iter().map(|f| f.logical_name.as_str()).collect();
+
+    let (created, missing) = prefix_field_mappings(&unmapped, &target_fields, &source_prefix, &target_prefixes);
+
+    let mut actions = Vec::new();
+    let mut added = Vec::new();
+    for (source_field, targets) in created {
+        added.extend(targets.iter().map(|t| (source_field.clone(), t.clone())));
+        state.field_mappings.insert(source_field.clone(), targets.clone());
+        state.autosave.mark_field(&source_field);
+        actions.push(MappingAction::FieldMapping { source: source_field, before: None, after: Some(targets) });
+    }
+    state.mapping_history.record(actions);
+    log::info!("Applied prefix mapping {} → {}: {} mappings created, {} targets missing",
+        source_prefix, target_prefixes.join(", "), added.len(), missing.len());
+
+    let target_entity = state.target_entity.clone();
+    state.import_results = Some(super::super::app::ImportResults {
+        filename: format!("prefix {} → {}", source_prefix, target_prefixes.join(", ")),
+        added,
+        updated: Vec::new(),
+        removed: Vec::new(),
+        unparsed: missing.into_iter()
+            .map(|(source_field, candidates)| format!("{} → {}: not found on {}", source_field, candidates.join(", "), target_entity))
+            .collect(),
+    });
+    state.import_results_list = crate::tui::widgets::ListState::new();
+    state.show_prefix_mappings_modal = false;
+    state.show_import_results_modal = true;
+
+    if let (Resource::Success(source), Resource::Success(target)) =
+        (&state.source_metadata, &state.target_metadata)
+    {
+        let (field_matches, relationship_matches, entity_matches, source_entities, target_entities) =
+            recompute_all_matches(
+                source,
+                target,
+                &state.field_mappings,
+                &state.imported_mappings,
+                &state.prefix_mappings,
+                &state.relationship_mappings,
+                &state.examples,
+                &state.source_entity,
+                &state.target_entity,
+                state.match_settings,
+            );
+        state.field_matches = field_matches;
+        state.relationship_matches = relationship_matches;
+        state.entity_matches = entity_matches;
+        state.source_entities = source_entities;
+        state.target_entities = target_entities;
+    }
+
+    Command::None
+}
+
+/// Substitute the prefix of each source field starting with `source_prefix`
+///
+/// Returns the mappings to create (every existing target, one per target prefix)
+/// and, for fields where none of the candidates exist, the candidates tried.
+fn prefix_field_mappings(
+    source_fields: &[&str],
+    target_fields: &HashSet<&str>,
+    source_prefix: &str,
+    target_prefixes: &[String],
+) -> (Vec<(String, Vec<String>)>, Vec<(String, Vec<String>)>) {
+    let mut created = Vec::new();
+    let mut missing = Vec::new();
+    for field in source_fields {
+        let Some(suffix) = field.strip_prefix(source_prefix) else {
+            continue;
+        };
+        let candidates: Vec<String> = target_prefixes.iter().map(|prefix| format!("{}{}", prefix, suffix)).collect();
+        let existing: Vec<String> = candidates.iter()
+            .filter(|candidate| target_fields.contains(candidate.as_str()))
+            .cloned()
+            .collect();
+        if existing.is_empty() {
+            missing.push((field.to_string(), candidates));
+        } else {
+            created.push((field.to_string(), existing));
+        }
+    }
+    (created, missing)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prefix_field_mappings_only_map_existing_targets() {
+        let source_fields = ["cgk_code", "cgk_deadline", "cgk_name", "createdon"];
+        let target_fields: HashSet<&str> = ["nrq_code", "nrq_name", "ext_name", "createdon"].into_iter().collect();
+        let target_prefixes = vec!["nrq_".to_string(), "ext_".to_string()];
+
+        let (created, missing) = prefix_field_mappings(&source_fields, &target_fields, "cgk_", &target_prefixes);
+
+        assert_eq!(created, vec![
+            ("cgk_code".to_string(), vec!["nrq_code".to_string()]),
+            ("cgk_name".to_string(), vec!["nrq_name".to_string(), "ext_name".to_string()]),
+        ]);
+        assert_eq!(missing, vec![
+            ("cgk_deadline".to_string(), vec!["nrq_deadline".to_string(), "ext_deadline".to_string()]),
+        ]);
+    }
+}
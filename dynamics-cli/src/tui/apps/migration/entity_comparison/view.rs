@@ -15,19 +15,12 @@ use super::tree_items::ComparisonTreeItem;
 use std::collections::HashMap;
 use super::models::{MatchInfo, MatchType};
 
-/// Render the main side-by-side layout with source and target trees
-pub fn render_main_layout(state: &mut State) -> Element<Msg> {
-    let theme = &crate::global_runtime_config().theme;
-    use_constraints!();
-
-    // Build tree items for the active tab from metadata
-    let active_tab = state.active_tab;
-    let hide_mode = state.hide_mode;
-    let sort_mode = state.sort_mode;
+/// Source tree items for the active tab, with the hide mode and search filter applied
+pub fn source_tree_items(state: &State) -> Vec<ComparisonTreeItem> {
     let mut source_items = if let Resource::Success(ref metadata) = state.source_metadata {
         build_tree_items(
             metadata,
-            active_tab,
+            state.active_tab,
             &state.field_matches,
             &state.relationship_matches,
             &state.entity_matches,
@@ -36,7 +29,7 @@ pub fn render_main_layout(state: &mut State) -> Element<Msg> {
             true, // is_source
             &state.source_entity,
             state.show_technical_names,
-            sort_mode,
+            state.sort_mode,
             &state.ignored_items,
         )
     } else {
@@ -44,7 +37,7 @@ pub fn render_main_layout(state: &mut State) -> Element<Msg> {
     };
 
     // Apply hide mode filtering
-    source_items = match hide_mode {
+    source_items = match state.hide_mode {
         super::models::HideMode::Off => source_items,
         super::models::HideMode::HideMatched => filter_matched_items(source_items),
         super::models::HideMode::HideIgnored => filter_ignored_items(source_items),
@@ -54,11 +47,7 @@ pub fn render_main_layout(state: &mut State) -> Element<Msg> {
     };
 
     // Apply search filter based on search mode
-    let (source_search_query, target_search_query) = super::update::search::get_search_terms(state);
-    let source_search_active = source_search_query.is_some();
-    let target_search_active = target_search_query.is_some();
-
-    if let Some(query) = source_search_query {
+    if let (Some(query), _) = super::update::search::get_search_terms(state) {
         source_items = filter_tree_items_by_search(
             source_items,
             query,
@@ -69,6 +58,24 @@ pub fn render_main_layout(state: &mut State) -> Element<Msg> {
         );
     }
 
+    source_items
+}
+
+/// Render the main side-by-side layout with source and target trees
+pub fn render_main_layout(state: &mut State) -> Element<Msg> {
+    let theme = &crate::global_runtime_config().theme;
+    use_constraints!();
+
+    // Build tree items for the active tab from metadata
+    let active_tab = state.active_tab;
+    let hide_mode = state.hide_mode;
+    let sort_mode = state.sort_mode;
+    let source_items = source_tree_items(state);
+
+    let (source_search_query, target_search_query) = super::update::search::get_search_terms(state);
+    let source_search_active = source_search_query.is_some();
+    let target_search_active = target_search_query.is_some();
+
     let mut target_items = if let Resource::Success(ref metadata) = state.target_metadata {
         // Create reverse matches for target side (target_field -> source_field)
         // For 1-to-N mappings, each target gets its own reverse mapping back to the source
@@ -310,9 +317,18 @@ pub fn render_main_layout(state: &mut State) -> Element<Msg> {
         }
     };
 
-    // Main layout with search
-    // Note: Both modes use 3 lines (1 panel with input = 3 lines height)
+    let tabs = Element::tabs(
+        "comparison-tabs",
+        ActiveTab::ALL.iter().map(|tab| tab.label().to_string()).collect(),
+        active_tab.number() - 1,
+    )
+    .on_select(|index| Msg::SwitchTab(index + 1))
+    .build();
+
+    // Main layout with tabs and search
+    // Note: Both search modes use 3 lines (1 panel with input = 3 lines height)
     col![
+        tabs => Length(1),
         search_ui => Length(3),
         row![
             source_panel => Fill(1),
@@ -335,6 +351,55 @@ pub fn render_back_confirmation_modal() -> Element<Msg> {
         .build()
 }
 
+/// Render the export format picker
+pub fn render_export_modal(state: &State) -> Element<Msg> {
+    let theme = &crate::global_runtime_config().theme;
+    use crate::tui::element::LayoutConstraint::*;
+    use crate::{col, spacer, button_row};
+    use ratatui::text::{Line, Span};
+    use ratatui::style::{Style, Stylize};
+
+    let intro = Element::styled_text(Line::from(vec![
+        Span::styled("Export ", Style::default().fg(theme.text_primary)),
+        Span::styled(
+            format!("{} → {}", state.source_entity, state.target_entity),
+            Style::default().fg(theme.accent_primary).bold(),
+        ),
+        Span::styled(" as:", Style::default().fg(theme.text_primary)),
+    ])).build();
+
+    let hint = Element::styled_text(Line::from(Span::styled(
+        "Excel opens with one sheet per section; Markdown is a single file for wikis.",
+        Style::default().fg(theme.text_secondary),
+    ))).build();
+    let csharp_hint = Element::styled_text(Line::from(Span::styled(
+        "C# writes the mappings as a mapping class the C# import reads back.",
+        Style::default().fg(theme.text_secondary),
+    ))).build();
+
+    let buttons = button_row![
+        ("export-excel", "Excel (x)", Msg::ExportToExcel),
+        ("export-markdown", "Markdown (m)", Msg::ExportToMarkdown),
+        ("export-csharp", "C# (c)", Msg::ExportToCsharp),
+        ("export-cancel", "Cancel (Esc)", Msg::CloseExportModal),
+    ];
+
+    let content = col![
+        intro => Length(1),
+        spacer!() => Length(1),
+        hint => Length(1),
+        csharp_hint => Length(1),
+        spacer!() => Length(1),
+        buttons => Length(3),
+    ];
+
+    Element::panel(Element::container(content).padding(2).build())
+        .title("Export Report")
+        .width(80)
+        .height(14)
+        .build()
+}
+
 pub fn render_examples_modal(state: &State) -> Element<Msg> {
     let theme = &crate::global_runtime_config().theme;
     use crate::tui::modals::{ExamplesModal, ExamplePairItem};
@@ -361,12 +426,53 @@ pub fn render_examples_modal(state: &State) -> Element<Msg> {
         .on_label_input_event(Msg::LabelInputEvent)
         .on_list_navigate(Msg::ExamplesListNavigate)
         .on_list_select(Msg::ExamplesListSelect)
+        .on_list_event(Msg::ExamplesListEvent)
         .on_add(Msg::AddExamplePair)
         .on_delete(Msg::DeleteExamplePair)
         .on_close(Msg::CloseExamplesModal)
         .build()
 }
 
+/// Render the side-by-side diff of the selected example pair's records
+pub fn render_example_diff_modal(state: &mut State) -> Element<Msg> {
+    use crate::tui::element::LayoutConstraint::*;
+    use crate::{col, spacer, button_row};
+
+    let pair = state.examples_list_state.selected()
+        .and_then(|idx| state.examples.pairs.get(idx));
+    let data = pair.and_then(|pair| state.examples.pair_data(pair, &state.source_entity, &state.target_entity));
+    let (Some(pair), Some((source, target))) = (pair, data) else {
+        return Element::text("No example data");
+    };
+    let title = format!("Example Diff: {} ↔ {}", pair.source_record_id, pair.target_record_id);
+    let (source, target) = (source.clone(), target.clone());
+
+    let diff = Element::json_diff("example-diff-tree", &source, &target, &mut state.example_diff_tree)
+        .on_event(Msg::ExampleDiffTreeEvent)
+        .on_render(Msg::ExampleDiffViewportHeight)
+        .build();
+
+    let diff_panel = Element::panel(diff)
+        .title(format!("{}  ↔  {}", state.source_entity, state.target_entity))
+        .build();
+
+    let buttons = button_row![
+        ("example-diff-close", "Close (Esc)", Msg::CloseExampleDiff),
+    ];
+
+    let content = col![
+        diff_panel => Fill(1),
+        spacer!() => Length(1),
+        buttons => Length(3),
+    ];
+
+    Element::panel(Element::container(content).padding(2).build())
+        .title(title)
+        .width(120)
+        .height(40)
+        .build()
+}
+
 pub fn render_prefix_mappings_modal(state: &State) -> Element<Msg> {
     let theme = &crate::global_runtime_config().theme;
     use crate::tui::modals::{PrefixMappingsModal, PrefixMappingItem};
@@ -391,6 +497,7 @@ pub fn render_prefix_mappings_modal(state: &State) -> Element<Msg> {
         .on_list_select(Msg::PrefixMappingsListSelect)
         .on_add(Msg::AddPrefixMapping)
         .on_delete(Msg::DeletePrefixMapping)
+        .on_apply(Msg::ApplyPrefixMapping)
         .on_close(Msg::ClosePrefixMappingsModal)
         .build()
 }
@@ -913,12 +1020,12 @@ pub fn render_import_results_modal(state: &mut State) -> Element<Msg> {
     // Unparsed lines
     if !results.unparsed.is_empty() {
         list_items.push(ImportResultLine {
-            text: format!("⚠ Couldn't parse {} lines", results.unparsed.len()),
+            text: format!("⚠ Skipped {} entries", results.unparsed.len()),
             style: Style::default().fg(theme.accent_warning).bold(),
         });
         for line in &results.unparsed {
-            let truncated = if line.len() > 60 {
-                format!("{}...", &line[..57])
+            let truncated = if line.chars().count() > 60 {
+                format!("{}...", line.chars().take(57).collect::<String>())
             } else {
                 line.clone()
             };
@@ -969,6 +1076,62 @@ pub fn render_import_results_modal(state: &mut State) -> Element<Msg> {
         .build()
 }
 
+/// Render the column picker for a CSV whose header didn't name source/target
+pub fn render_csv_columns_modal(state: &mut State) -> Element<Msg> {
+    let theme = &crate::global_runtime_config().theme;
+    use crate::tui::element::LayoutConstraint::*;
+    use crate::{col, spacer, button_row};
+    use ratatui::text::{Line, Span};
+    use ratatui::style::{Style, Stylize};
+
+    let picker = state.csv_column_picker.as_mut().unwrap();
+    let headers = picker.table.headers.clone();
+
+    let intro = Element::styled_text(Line::from(vec![
+        Span::styled(format!("{} ", picker.filename), Style::default().fg(theme.accent_primary).bold()),
+        Span::styled(
+            format!("has {} rows without a source/target header. Pick the columns to map:", picker.table.rows.len()),
+            Style::default().fg(theme.text_primary),
+        ),
+    ])).build();
+
+    let source_select = Element::select("csv-source-column", headers.clone(), &mut picker.source_column.state)
+        .on_event(Msg::CsvSourceColumnEvent)
+        .build();
+    let target_select = Element::select("csv-target-column", headers, &mut picker.target_column.state)
+        .on_event(Msg::CsvTargetColumnEvent)
+        .build();
+
+    // First rows of the file so the columns are recognisable
+    let mut preview = crate::tui::element::ColumnBuilder::new();
+    for row in picker.table.rows.iter().take(5) {
+        let line = Line::from(Span::styled(row.join("  │  "), Style::default().fg(theme.text_secondary)));
+        preview = preview.add(Element::styled_text(line).build(), Length(1));
+    }
+    let preview = preview.build();
+
+    let buttons = button_row![
+        ("csv-columns-import", "Import", Msg::ConfirmCsvColumns),
+        ("csv-columns-cancel", "Cancel (Esc)", Msg::CancelCsvColumns),
+    ];
+
+    let content = col![
+        intro => Length(1),
+        spacer!() => Length(1),
+        Element::panel(source_select).title("Source Field Column").build() => Length(3),
+        Element::panel(target_select).title("Target Field Column").build() => Length(3),
+        Element::panel(preview).title("Preview").build() => Fill(1),
+        spacer!() => Length(1),
+        buttons => Length(3),
+    ];
+
+    Element::panel(Element::container(content).padding(2).build())
+        .title("Import CSV Mappings")
+        .width(80)
+        .height(26)
+        .build()
+}
+
 /// Render the C# mapping import modal with file browser
 pub fn render_import_modal(state: &mut State) -> Element<Msg> {
     let theme = &crate::global_runtime_config().theme;
@@ -1013,6 +1176,7 @@ pub fn render_import_modal(state: &mut State) -> Element<Msg> {
 
     // Buttons
     let buttons = button_row![
+        ("import-copy-comparison", "Copy from Comparison (o)", Msg::OpenComparisonPicker),
         ("import-close", "Close (Esc)", Msg::CloseImportModal),
     ];
 
@@ -1034,7 +1198,111 @@ pub fn render_import_modal(state: &mut State) -> Element<Msg> {
         .build()
 }
 
+/// Render the picker of other comparisons to copy mappings from
+pub fn render_comparison_picker_modal(state: &State) -> Element<Msg> {
+    let theme = &crate::global_runtime_config().theme;
+    use crate::tui::element::LayoutConstraint::*;
+    use crate::{spacer, button_row};
+    use ratatui::text::{Line, Span};
+    use ratatui::style::{Style, Stylize};
+    use crate::tui::widgets::ListItem;
+
+    #[derive(Clone)]
+    struct ComparisonItem(crate::config::SavedComparison);
+
+    impl ListItem for ComparisonItem {
+        type Msg = Msg;
+
+        fn to_element(&self, is_selected: bool, _is_hovered: bool) -> Element<Self::Msg> {
+            let theme = &crate::global_runtime_config().theme;
+            let name_color = if is_selected { theme.accent_primary } else { theme.text_primary };
+            let mut builder = Element::styled_text(Line::from(vec![
+                Span::styled(format!("  {}", self.0.name), Style::default().fg(name_color)),
+                Span::styled(format!("  {}", self.0.migration_name), Style::default().fg(theme.text_tertiary)),
+                Span::styled(
+                    format!("  {} → {}", self.0.source_entity, self.0.target_entity),
+                    Style::default().fg(theme.text_secondary),
+                ),
+            ]));
+            if is_selected {
+                builder = builder.background(Style::default().bg(theme.bg_surface));
+            }
+            builder.build()
+        }
+    }
+
+    let picker = state.comparison_picker.as_ref().unwrap();
+    let message = |text: &str| Element::styled_text(Line::from(Span::styled(
+        text.to_string(),
+        Style::default().fg(theme.text_tertiary).italic(),
+    ))).build();
+
+    let content = match &picker.comparisons {
+        Resource::Success(comparisons) if !comparisons.is_empty() => {
+            let items: Vec<ComparisonItem> = comparisons.iter().cloned().map(ComparisonItem).collect();
+            Element::list("copy-comparison-list", &items, &picker.list_state, theme)
+                .on_navigate(Msg::ComparisonPickerNavigate)
+                .on_activate(Msg::CopyFromComparison)
+                .build()
+        }
+        Resource::Success(_) => message("No other comparisons to copy from"),
+        Resource::Failure(err) => Element::styled_text(Line::from(vec![
+            Span::styled("Error: ", Style::default().fg(theme.accent_error).bold()),
+            Span::styled(err.clone(), Style::default().fg(theme.text_primary)),
+        ])).build(),
+        Resource::Loading | Resource::NotAsked => message("Loading comparisons..."),
+    };
+
+    let help_text = Element::styled_text(Line::from(vec![
+        Span::styled("Press ", Style::default().fg(theme.text_tertiary)),
+        Span::styled("Enter", Style::default().fg(theme.accent_primary).bold()),
+        Span::styled(" to add its mappings to this comparison. Existing mappings are kept.", Style::default().fg(theme.text_tertiary)),
+    ])).build();
+
+    let buttons = button_row![
+        ("copy-comparison-close", "Close (Esc)", Msg::CloseComparisonPicker),
+    ];
+
+    let content = col![
+        help_text => Length(1),
+        spacer!() => Length(1),
+        Element::panel(content).title("Saved Comparisons").build() => Fill(1),
+        spacer!() => Length(1),
+        buttons => Length(3),
+    ];
+
+    Element::panel(Element::container(content).padding(2).build())
+        .title("Copy Mappings from Comparison")
+        .width(90)
+        .height(30)
+        .build()
+}
+
 /// Render the ignore manager modal
+/// Typed confirmation shown over the ignore manager before clearing it
+pub fn render_clear_ignored_confirm(
+    background: Element<Msg>,
+    input: &crate::tui::widgets::TextInputField,
+    count: usize,
+) -> Element<Msg> {
+    use super::update::ignore::CLEAR_IGNORED_PHRASE;
+
+    Element::modal_confirm_typed(
+        background,
+        "Clear all ignored items",
+        format!("Un-ignore all {} items? Undo can restore them.", count),
+        CLEAR_IGNORED_PHRASE,
+        "ignore-clear-input",
+        input.value(),
+        &input.state,
+        Msg::ClearIgnoredInput,
+        "ignore-clear-cancel",
+        Msg::CancelClearAllIgnored,
+        "ignore-clear-confirm",
+        Msg::ConfirmClearAllIgnored,
+    )
+}
+
 pub fn render_ignore_modal(state: &mut State) -> Element<Msg> {
     let theme = &crate::global_runtime_config().theme;
     use crate::tui::element::LayoutConstraint::*;
@@ -8,6 +8,7 @@ pub mod environment_selector_app;
 pub mod deadlines;
 pub mod queue;
 pub mod copy_questionnaires;
+pub mod query_runner;
 
 pub use app_launcher::AppLauncher;
 pub use screens::{LoadingScreen, ErrorScreen};
@@ -16,4 +17,5 @@ pub use update_app::UpdateApp;
 pub use environment_selector_app::EnvironmentSelectorApp;
 pub use deadlines::{DeadlinesFileSelectApp, DeadlinesMappingApp, DeadlinesInspectionApp};
 pub use queue::OperationQueueApp;
-pub use copy_questionnaires::{SelectQuestionnaireApp, CopyQuestionnaireApp};
\ No newline at end of file
+pub use copy_questionnaires::{SelectQuestionnaireApp, CopyQuestionnaireApp};
+pub use query_runner::{QueryRunnerApp, SavedQueriesApp};
\ No newline at end of file
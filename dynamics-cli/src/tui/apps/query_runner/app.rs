@@ -0,0 +1,578 @@
+//! Query runner: write FQL, watch the FetchXML it compiles to, run it and browse the results
+
+use crate::tui::{
+    app::App,
+    command::{AppId, Command},
+    element::{Element, FocusId},
+    subscription::Subscription,
+    renderer::LayeredView,
+    widgets::{ScrollableState, TableEvent, TableState, TextAreaField, TextAreaState, TextInputEvent, TextInputField},
+    Alignment, KeyBinding, PaletteAction, Resource, ToastLevel,
+};
+use crate::config::SavedQuery;
+use crate::{button_row, col, row, spacer, use_constraints};
+use crossterm::event::KeyCode;
+use ratatui::style::{Style, Stylize};
+use ratatui::text::{Line, Span};
+use super::compile::{compile, CompiledQuery, QueryError};
+use super::results::{export_results, run_query, table_columns, QueryResults};
+
+/// Height of the query editor in rows
+const EDITOR_ROWS: usize = 10;
+
+pub struct QueryRunnerApp;
+
+/// Opens the runner on a saved query
+#[derive(Debug, Clone, Default)]
+pub struct QueryRunnerParams {
+    pub saved_query: Option<SavedQuery>,
+    /// Run it right away instead of only loading it into the editor
+    pub run: bool,
+}
+
+pub struct State {
+    query: TextAreaField,
+    /// Saved query the editor was loaded from or last saved as
+    saved_name: Option<String>,
+    show_save_modal: bool,
+    save_name: TextInputField,
+    /// None while the editor is blank
+    compiled: Option<Result<CompiledQuery, QueryError>>,
+    preview_scroll: ScrollableState,
+    results: Resource<QueryResults>,
+    table_state: TableState,
+    current_environment: Option<String>,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self {
+            query: TextAreaField {
+                value: String::new(),
+                state: TextAreaState::new().with_visible_rows(EDITOR_ROWS),
+            },
+            saved_name: None,
+            show_save_modal: false,
+            save_name: TextInputField::default(),
+            compiled: None,
+            preview_scroll: ScrollableState::new(),
+            results: Resource::NotAsked,
+            table_state: TableState::new(),
+            current_environment: None,
+        }
+    }
+}
+
+impl crate::tui::AppState for State {}
+
+#[derive(Clone)]
+pub enum Msg {
+    EnvironmentLoaded(Option<String>),
+
+    // Editor
+    QueryEvent(TextInputEvent),
+    Run,
+    ResultsLoaded(Result<QueryResults, String>),
+
+    // FetchXML preview scrolling
+    PreviewScroll(KeyCode),
+    PreviewSetDimensions(usize, usize, usize, usize),  // (viewport_height, content_height, viewport_width, content_width)
+
+    // Results table
+    TableEvent(TableEvent),
+    SortBy(usize),
+    TableRendered(usize),
+
+    // Results export
+    ExportCsv,
+    Exported(Result<String, String>),
+
+    // Saved queries
+    OpenSaved,
+    RequestSave,
+    SaveNameEvent(TextInputEvent),
+    SubmitSave,
+    CancelSave,
+    QuerySaved(Result<String, String>),
+
+    Back,
+}
+
+impl App for QueryRunnerApp {
+    type State = State;
+    type Msg = Msg;
+    type InitParams = QueryRunnerParams;
+
+    fn init(params: QueryRunnerParams) -> (State, Command<Msg>) {
+        let mut state = State::default();
+        let mut commands = vec![
+            Command::perform(
+                async { crate::client_manager().get_current_environment().await },
+                Msg::EnvironmentLoaded,
+            ),
+            Command::set_focus(FocusId::new("query-editor")),
+        ];
+
+        if let Some(saved) = params.saved_query {
+            state.query.set_value(saved.fql);
+            state.compiled = Some(compile(state.query.value()));
+            state.saved_name = Some(saved.name);
+            if params.run {
+                commands.push(Self::update(&mut state, Msg::Run));
+            }
+        }
+
+        (state, Command::batch(commands))
+    }
+
+    fn update(state: &mut State, msg: Msg) -> Command<Msg> {
+        match msg {
+            Msg::EnvironmentLoaded(env) => {
+                state.current_environment = env;
+                Command::None
+            }
+
+            Msg::QueryEvent(event) => {
+                let before = state.query.value().to_string();
+                if state.query.handle_event(event, None).is_some() {
+                    return Self::update(state, Msg::Run);
+                }
+                if state.query.value() != before {
+                    let text = state.query.value();
+                    state.compiled = (!text.trim().is_empty()).then(|| compile(text));
+                }
+                Command::None
+            }
+
+            Msg::Run => {
+                if state.results.is_loading() {
+                    return Command::None;
+                }
+                match &state.compiled {
+                    None => Command::notify("Write a query to run first", ToastLevel::Info),
+                    Some(Err(error)) => {
+                        let location = match error.position {
+                            Some((line, column)) => format!(" at line {}, column {}", line, column),
+                            None => String::new(),
+                        };
+                        Command::notify(format!("The query has an error{}", location), ToastLevel::Warning)
+                    }
+                    Some(Ok(query)) => {
+                        let query = query.clone();
+                        let saved_name = state.saved_name.clone();
+                        state.results = Resource::Loading;
+                        Command::perform(
+                            async move {
+                                // Running counts as use, so the query moves up in Saved Queries
+                                if let Some(name) = saved_name {
+                                    if let Err(e) = crate::global_config().touch_saved_query(&name).await {
+                                        log::warn!("Failed to update last use of saved query '{}': {}", name, e);
+                                    }
+                                }
+                                run_query(query).await.map_err(|e| format!("{:#}", e))
+                            },
+                            Msg::ResultsLoaded,
+                        )
+                    }
+                }
+            }
+
+            Msg::ResultsLoaded(result) => {
+                state.table_state = TableState::new();
+                match result {
+                    Ok(results) => {
+                        state.current_environment = Some(results.environment.clone());
+                        let cmd = if results.truncated.is_empty() {
+                            Command::None
+                        } else {
+                            Command::notify(
+                                format!(
+                                    "Only the first page of {} was fetched; use `dynamics-cli query --all` for everything",
+                                    results.truncated.join(", ")
+                                ),
+                                ToastLevel::Warning,
+                            )
+                        };
+                        state.results = Resource::Success(results);
+                        cmd
+                    }
+                    Err(e) => {
+                        state.results = Resource::Failure(e);
+                        Command::None
+                    }
+                }
+            }
+
+            Msg::PreviewScroll(key) => {
+                // Dimensions are tracked from last on_render call
+                let viewport_height = state.preview_scroll.viewport_height().unwrap_or(20);
+                let content_height = state.preview_scroll.content_height().unwrap_or(20);
+                state.preview_scroll.handle_key(key, content_height, viewport_height);
+                Command::None
+            }
+
+            Msg::PreviewSetDimensions(viewport_height, content_height, viewport_width, content_width) => {
+                state.preview_scroll.set_viewport_height(viewport_height);
+                state.preview_scroll.update_scroll(viewport_height, content_height);
+                state.preview_scroll.set_viewport_width(viewport_width);
+                state.preview_scroll.update_horizontal_scroll(viewport_width, content_width);
+                Command::None
+            }
+
+            Msg::TableEvent(event) => {
+                if let Resource::Success(results) = &state.results {
+                    state.table_state.handle_event(event, results.rows.len());
+                }
+                Command::None
+            }
+
+            Msg::SortBy(column) => {
+                state.table_state.sort_by(column);
+                if let Resource::Success(results) = &mut state.results {
+                    state.table_state.sort_rows(&mut results.rows);
+                }
+                Command::None
+            }
+
+            Msg::TableRendered(height) => {
+                state.table_state.set_viewport_height(height);
+                Command::None
+            }
+
+            Msg::ExportCsv => match &state.results {
+                Resource::Success(results) if !results.rows.is_empty() => {
+                    let results = results.clone();
+                    Command::perform(
+                        async move { export_results(&results).map_err(|e| e.to_string()) },
+                        Msg::Exported,
+                    )
+                }
+                _ => Command::notify("No results to export", ToastLevel::Info),
+            },
+
+            Msg::Exported(result) => match result {
+                Ok(path) => Command::notify(format!("Exported results to {}", path), ToastLevel::Success),
+                Err(err) => Command::notify(format!("Export failed: {}", err), ToastLevel::Error),
+            },
+
+            Msg::OpenSaved => Command::start_app(AppId::SavedQueries, ()),
+
+            Msg::RequestSave => {
+                if state.query.value().trim().is_empty() {
+                    return Command::notify("Write a query to save first", ToastLevel::Info);
+                }
+                state.save_name.set_value(state.saved_name.clone().unwrap_or_default());
+                state.show_save_modal = true;
+                Command::set_focus(FocusId::new("save-name-input"))
+            }
+
+            Msg::SaveNameEvent(event) => {
+                if state.save_name.handle_event(event, Some(100)).is_some() {
+                    return Self::update(state, Msg::SubmitSave);
+                }
+                Command::None
+            }
+
+            Msg::SubmitSave => {
+                let name = state.save_name.value().trim().to_string();
+                if name.is_empty() {
+                    return Command::None;
+                }
+                state.show_save_modal = false;
+                let fql = state.query.value().to_string();
+                Command::perform(
+                    async move {
+                        crate::global_config().add_saved_query(&name, &fql).await
+                            .map(|_| name)
+                            .map_err(|e| e.to_string())
+                    },
+                    Msg::QuerySaved,
+                )
+            }
+
+            Msg::CancelSave => {
+                state.show_save_modal = false;
+                Command::None
+            }
+
+            Msg::QuerySaved(result) => match result {
+                Ok(name) => {
+                    let cmd = Command::notify(format!("Saved query '{}'", name), ToastLevel::Success);
+                    state.saved_name = Some(name);
+                    cmd
+                }
+                Err(err) => Command::notify(format!("Failed to save query: {}", err), ToastLevel::Error),
+            },
+
+            Msg::Back => Command::navigate_to(AppId::AppLauncher),
+        }
+    }
+
+    fn view(state: &mut State) -> LayeredView<Msg> {
+        use_constraints!();
+
+        let editor = Element::text_area("query-editor", state.query.value(), &state.query.state)
+            .placeholder(".account | .name, .revenue | limit(10)")
+            .on_event(Msg::QueryEvent)
+            .build();
+
+        let run_button = Element::button("run-btn", "[r] Run")
+            .on_press(Msg::Run)
+            .build();
+        let export_button = Element::button("export-btn", "[e] Export CSV")
+            .on_press(Msg::ExportCsv)
+            .build();
+        let save_button = Element::button("save-btn", "[w] Save")
+            .on_press(Msg::RequestSave)
+            .build();
+        let saved_button = Element::button("saved-btn", "[o] Saved")
+            .on_press(Msg::OpenSaved)
+            .build();
+        let buttons = row![
+            run_button => Length(11),
+            Element::None => Length(1),
+            export_button => Length(18),
+            Element::None => Length(1),
+            save_button => Length(12),
+            Element::None => Length(1),
+            saved_button => Length(13),
+            Element::None => Fill(1),
+        ];
+
+        let editor_title = match &state.saved_name {
+            Some(name) => format!("FQL: {} (Ctrl+Enter to run)", name),
+            None => "FQL (Ctrl+Enter to run)".to_string(),
+        };
+        let query_panel = Element::panel(col![
+            editor => Length(EDITOR_ROWS as u16),
+            buttons => Length(3),
+        ])
+        .title(editor_title)
+        .build();
+
+        let preview = Element::scrollable(
+            FocusId::new("fetchxml-preview"),
+            build_preview(state.compiled.as_ref()),
+            &state.preview_scroll,
+        )
+        .on_navigate(Msg::PreviewScroll)
+        .on_render(Msg::PreviewSetDimensions)
+        .build();
+        let preview_panel = Element::panel(preview)
+            .title("FetchXML")
+            .build();
+
+        let results_panel = build_results_panel(state);
+
+        let content = col![
+            row![
+                query_panel => Fill(1),
+                preview_panel => Fill(1),
+            ] => Length(EDITOR_ROWS as u16 + 5),
+            results_panel => Fill(1),
+        ];
+
+        let view = LayeredView::new(Element::panel(content).title("Query Runner").build());
+        if state.show_save_modal {
+            view.with_app_modal(build_save_modal(state), Alignment::Center)
+        } else {
+            view
+        }
+    }
+
+    fn subscriptions(state: &State) -> Vec<Subscription<Msg>> {
+        if state.show_save_modal {
+            return vec![Subscription::keyboard(KeyBinding::new(KeyCode::Esc), "Cancel save", Msg::CancelSave)];
+        }
+
+        // Only reached while the editor isn't focused; it takes every key itself
+        vec![
+            Subscription::keyboard(KeyBinding::new(KeyCode::Char('r')), "Run query", Msg::Run),
+            Subscription::keyboard(KeyBinding::new(KeyCode::Char('e')), "Export results to CSV", Msg::ExportCsv),
+            Subscription::keyboard(KeyBinding::new(KeyCode::Char('w')), "Save query", Msg::RequestSave),
+            Subscription::keyboard(KeyBinding::new(KeyCode::Char('o')), "Open saved queries", Msg::OpenSaved),
+            Subscription::keyboard(KeyBinding::new(KeyCode::Esc), "Back to launcher", Msg::Back),
+        ]
+    }
+
+    fn title() -> &'static str {
+        "Query Runner"
+    }
+
+    fn palette_actions(state: &State) -> Vec<PaletteAction<Msg>> {
+        vec![
+            PaletteAction::new("Run Query", Msg::Run)
+                .detail(state.current_environment.as_deref().map(|env| format!("Against {}", env)).unwrap_or_default()),
+            PaletteAction::new("Export Query Results", Msg::ExportCsv)
+                .detail("Save the results table to a CSV file in Downloads"),
+            PaletteAction::new("Save Query", Msg::RequestSave)
+                .detail(state.saved_name.as_deref().map(|name| format!("Saved as {}", name)).unwrap_or_default()),
+            PaletteAction::new("Open Saved Queries", Msg::OpenSaved)
+                .detail("Pick a saved query to run or edit"),
+        ]
+    }
+
+    fn help_bindings(_state: &State) -> Vec<(KeyBinding, String)> {
+        vec![
+            (KeyBinding::ctrl(KeyCode::Enter), "Run query (while editing)".to_string()),
+            (KeyBinding::new(KeyCode::Char('s')), "Sort by focused column (results)".to_string()),
+        ]
+    }
+
+    fn status(state: &State) -> Option<Line<'static>> {
+        let theme = &crate::global_runtime_config().theme;
+        let env = state.current_environment.as_ref()?;
+        Some(Line::from(vec![
+            Span::styled("Environment: ", Style::default().fg(theme.text_tertiary)),
+            Span::styled(env.clone(), Style::default().fg(theme.accent_primary)),
+        ]))
+    }
+}
+
+/// FetchXML for the current query, or the parser error under the failing token
+fn build_preview(compiled: Option<&Result<CompiledQuery, QueryError>>) -> Element<Msg> {
+    let theme = &crate::global_runtime_config().theme;
+
+    let lines: Vec<Line<'static>> = match compiled {
+        None => vec![Line::from(Span::styled(
+            "FetchXML appears here as you type",
+            Style::default().fg(theme.text_tertiary).italic(),
+        ))],
+        Some(Ok(query)) => {
+            let mut lines = Vec::new();
+            for (idx, document) in query.pretty.iter().enumerate() {
+                if idx > 0 {
+                    lines.push(Line::from(""));
+                }
+                lines.extend(document.lines().map(|line| {
+                    Line::from(Span::styled(line.to_string(), Style::default().fg(theme.text_primary)))
+                }));
+            }
+            lines
+        }
+        Some(Err(error)) => error
+            .lines
+            .iter()
+            .map(|line| Line::from(Span::styled(line.clone(), Style::default().fg(theme.accent_error))))
+            .collect(),
+    };
+
+    Element::column(lines.into_iter().map(|line| Element::styled_text(line).build()).collect())
+        .spacing(0)
+        .build()
+}
+
+fn build_save_modal(state: &State) -> Element<Msg> {
+    use_constraints!();
+
+    let name_input = Element::panel(
+        Element::text_input("save-name-input", state.save_name.value(), &state.save_name.state)
+            .placeholder("Query name")
+            .on_event(Msg::SaveNameEvent)
+            .build()
+    )
+    .title("Name")
+    .build();
+
+    let buttons = button_row![
+        ("save-cancel", "Cancel", Msg::CancelSave),
+        ("save-confirm", "Save", Msg::SubmitSave),
+    ];
+
+    Element::panel(
+        Element::container(col![
+            name_input => Length(3),
+            spacer!() => Length(1),
+            buttons => Length(3),
+        ])
+        .padding(2)
+        .build()
+    )
+    .title("Save Query")
+    .width(60)
+    .height(13)
+    .build()
+}
+
+fn build_results_panel(state: &State) -> Element<Msg> {
+    use_constraints!();
+    let theme = &crate::global_runtime_config().theme;
+
+    let message = |text: String, color| {
+        Element::styled_text(Line::from(Span::styled(text, Style::default().fg(color)))).build()
+    };
+
+    let (title, content) = match &state.results {
+        Resource::NotAsked => (
+            "Results".to_string(),
+            message("Run the query to see its records here".to_string(), theme.text_tertiary),
+        ),
+        Resource::Loading => (
+            "Results".to_string(),
+            message("⏳ Running query...".to_string(), theme.accent_tertiary),
+        ),
+        Resource::Failure(err) => (
+            "Results".to_string(),
+            Element::styled_text(Line::from(vec![
+                Span::styled("Error: ", Style::default().fg(theme.accent_error).bold()),
+                Span::styled(err.clone(), Style::default().fg(theme.text_primary)),
+            ])).build(),
+        ),
+        Resource::Success(results) if results.rows.is_empty() => (
+            "Results (0)".to_string(),
+            message(format!("No records in {} ({} ms)", results.environment, results.duration_ms), theme.text_tertiary),
+        ),
+        Resource::Success(results) => {
+            let mut summary = vec![Span::styled(
+                format!("{} record(s) from {} in {} ms", results.rows.len(), results.environment, results.duration_ms),
+                Style::default().fg(theme.text_secondary),
+            )];
+            if !results.truncated.is_empty() {
+                summary.push(Span::styled(
+                    format!("  (more records available for {})", results.truncated.join(", ")),
+                    Style::default().fg(theme.accent_warning),
+                ));
+            }
+
+            let columns = table_columns(&results.columns, &results.rows);
+            let table = Element::table("results-table", &columns, &results.rows, &state.table_state)
+                .on_sort(Msg::SortBy)
+                .on_event(Msg::TableEvent)
+                .on_render(Msg::TableRendered)
+                .build();
+
+            (
+                format!("Results ({})", results.rows.len()),
+                col![
+                    Element::styled_text(Line::from(summary)).build() => Length(1),
+                    table => Fill(1),
+                ],
+            )
+        }
+    };
+
+    Element::panel(content).title(title).build()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_typing_compiles_and_errors_block_run() {
+        let (mut state, _) = QueryRunnerApp::init(QueryRunnerParams::default());
+        for c in ".account | .name,,".chars() {
+            QueryRunnerApp::update(&mut state, Msg::QueryEvent(TextInputEvent::Changed(KeyCode::Char(c))));
+        }
+        assert!(matches!(state.compiled, Some(Err(ref e)) if e.position.is_some()));
+
+        // Ctrl+Enter on a broken query doesn't start a run
+        QueryRunnerApp::update(&mut state, Msg::QueryEvent(TextInputEvent::Submit));
+        assert!(state.results.is_not_asked());
+
+        for _ in 0..2 {
+            QueryRunnerApp::update(&mut state, Msg::QueryEvent(TextInputEvent::Changed(KeyCode::Backspace)));
+        }
+        assert!(matches!(state.compiled, Some(Ok(_))));
+    }
+}
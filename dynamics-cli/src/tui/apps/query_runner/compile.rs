@@ -0,0 +1,83 @@
+//! FQL compilation for the query runner: the FetchXML to run and to preview
+
+use crate::cli::commands::query::aliases::ColumnAliases;
+use crate::fql::ast::Query;
+use crate::fql::lexer::ParseError;
+use crate::fql::{parse, to_fetchxml, to_fetchxml_pretty, tokenize};
+
+/// Lines of query shown above the failing one in an error snippet
+const ERROR_CONTEXT_LINES: usize = 2;
+
+/// A query ready to run, with one entry per union branch in each list
+#[derive(Debug, Clone)]
+pub struct CompiledQuery {
+    pub entity_names: Vec<String>,
+    pub fetchxml: Vec<String>,
+    pub aliases: Vec<ColumnAliases>,
+    /// Indented FetchXML for the preview pane
+    pub pretty: Vec<String>,
+    /// Parsed query, validated against cached metadata before it runs
+    pub ast: Query,
+}
+
+/// Why the query text doesn't compile
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueryError {
+    /// Line and column of the failing token, when the parser knows it
+    pub position: Option<(usize, usize)>,
+    /// Query snippet with a caret under the failing token, then the message
+    pub lines: Vec<String>,
+}
+
+/// Compile FQL into FetchXML documents
+pub fn compile(text: &str) -> Result<CompiledQuery, QueryError> {
+    compile_query(text).map_err(|e| QueryError::from_error(&e))
+}
+
+fn compile_query(text: &str) -> anyhow::Result<CompiledQuery> {
+    let ast = parse(tokenize(text)?, text)?;
+
+    let entity_names = ast.entity_names();
+    let aliases = std::iter::once(&ast)
+        .chain(&ast.unions)
+        .map(ColumnAliases::from_query)
+        .collect();
+    let pretty = to_fetchxml_pretty(ast.clone())?;
+    let fetchxml = to_fetchxml(ast.clone())?;
+
+    Ok(CompiledQuery { entity_names, fetchxml, aliases, pretty, ast })
+}
+
+impl QueryError {
+    fn from_error(error: &anyhow::Error) -> Self {
+        match error.downcast_ref::<ParseError>() {
+            Some(parse_error) => Self {
+                position: Some((parse_error.line(), parse_error.column())),
+                lines: parse_error.render(ERROR_CONTEXT_LINES).lines().map(str::to_string).collect(),
+            },
+            None => Self {
+                position: None,
+                lines: vec![format!("error: {:#}", error)],
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compile_reports_error_position() {
+        let compiled = compile(".account | .name | limit(5)").unwrap();
+        assert_eq!(compiled.entity_names, vec!["account".to_string()]);
+        assert_eq!(compiled.fetchxml.len(), 1);
+        assert!(compiled.pretty[0].contains('\n'));
+
+        let error = compile(".account\n| .name,, .revenue").unwrap_err();
+        let (line, _) = error.position.unwrap();
+        assert_eq!(line, 2);
+        assert!(error.lines.iter().any(|l| l.trim_end().ends_with('^')));
+        assert!(error.lines.last().unwrap().starts_with("error: "));
+    }
+}
@@ -0,0 +1,8 @@
+pub mod app;
+pub mod compile;
+pub mod results;
+
+pub mod saved_queries_app;
+
+pub use app::{QueryRunnerApp, QueryRunnerParams};
+pub use saved_queries_app::SavedQueriesApp;
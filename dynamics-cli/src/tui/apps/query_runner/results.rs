@@ -0,0 +1,176 @@
+//! Running a compiled query and turning its records into table rows and CSV
+
+use anyhow::{Context, Result};
+use ratatui::layout::{Alignment, Constraint};
+use serde_json::Value;
+use std::time::Instant;
+use crate::api::constants::annotations;
+use crate::tui::widgets::TableColumn;
+use crate::cli::commands::query::validation::load_validation_metadata;
+use crate::fql::validate;
+use super::compile::CompiledQuery;
+
+/// Widest a column starts out, so one long value doesn't crowd out the rest
+const MAX_COLUMN_WIDTH: usize = 40;
+
+/// Records of a finished run, flattened into rows
+#[derive(Debug, Clone)]
+pub struct QueryResults {
+    pub environment: String,
+    pub columns: Vec<String>,
+    pub rows: Vec<Vec<String>>,
+    /// Entities that had more records than their first page
+    pub truncated: Vec<String>,
+    pub duration_ms: u128,
+}
+
+/// Run every union branch against the current environment
+///
+/// The query is first checked against whatever metadata is cached for the
+/// environment, so misspelled names fail with a suggestion instead of a
+/// server error. Only the first page (up to 5000 records) of each branch is
+/// fetched; the CLI's `--all` is the way to pull everything.
+pub async fn run_query(query: CompiledQuery) -> Result<QueryResults> {
+    let start = Instant::now();
+    let manager = crate::client_manager();
+    let environment = manager.get_current_environment().await
+        .context("No environment selected; choose one in the Environment Selector")?;
+
+    let metadata = load_validation_metadata(&environment, &query.ast).await;
+    let issues = validate(&query.ast, &metadata);
+    if !issues.is_empty() {
+        let issues: Vec<String> = issues.iter().map(ToString::to_string).collect();
+        anyhow::bail!("{}", issues.join("; "));
+    }
+
+    let client = manager.get_client(&environment).await?;
+
+    let mut records = Vec::new();
+    let mut truncated = Vec::new();
+    for ((entity_name, fetchxml), aliases) in query.entity_names.iter().zip(&query.fetchxml).zip(&query.aliases) {
+        let mut result = client.execute_fetchxml(entity_name, fetchxml).await
+            .with_context(|| format!("Failed to query {}", entity_name))?;
+
+        if let Some(page) = result.get_mut("value").and_then(|v| v.as_array_mut()) {
+            page.iter_mut().for_each(|record| aliases.apply(record));
+            records.append(page);
+        }
+        if result.get(annotations::MORE_RECORDS).and_then(Value::as_bool).unwrap_or(false) {
+            truncated.push(entity_name.clone());
+        }
+    }
+
+    let (columns, rows) = records_to_table(&records);
+    log::info!("Query runner fetched {} record(s) from {}", rows.len(), environment);
+    Ok(QueryResults {
+        environment,
+        columns,
+        rows,
+        truncated,
+        duration_ms: start.elapsed().as_millis(),
+    })
+}
+
+/// Flatten records into a header and rows
+///
+/// Records omit null attributes, so the columns are every key seen, in
+/// first-seen order. OData annotations (`@odata.etag`, formatted values) are left out.
+pub fn records_to_table(records: &[Value]) -> (Vec<String>, Vec<Vec<String>>) {
+    let objects: Vec<&serde_json::Map<String, Value>> =
+        records.iter().filter_map(|record| record.as_object()).collect();
+
+    let mut columns: Vec<String> = Vec::new();
+    for key in objects.iter().flat_map(|object| object.keys()) {
+        if !key.contains('@') && !columns.contains(key) {
+            columns.push(key.clone());
+        }
+    }
+
+    let rows = objects
+        .iter()
+        .map(|object| columns.iter().map(|column| cell_text(object.get(column))).collect())
+        .collect();
+    (columns, rows)
+}
+
+fn cell_text(value: Option<&Value>) -> String {
+    match value {
+        None | Some(Value::Null) => String::new(),
+        Some(Value::String(s)) => s.clone(),
+        Some(other) => other.to_string(),
+    }
+}
+
+/// Table columns sized to their content, with numeric columns right-aligned
+pub fn table_columns(columns: &[String], rows: &[Vec<String>]) -> Vec<TableColumn> {
+    columns
+        .iter()
+        .enumerate()
+        .map(|(idx, header)| {
+            let cells = || rows.iter().filter_map(|row| row.get(idx)).filter(|cell| !cell.is_empty());
+            let width = cells()
+                .map(|cell| cell.chars().count())
+                .chain(std::iter::once(header.chars().count() + 2)) // room for the sort arrow
+                .max()
+                .unwrap_or_default()
+                .min(MAX_COLUMN_WIDTH);
+            let numeric = cells().next().is_some() && cells().all(|cell| cell.parse::<f64>().is_ok());
+
+            let column = TableColumn::new(header.clone()).width(Constraint::Min(width as u16));
+            if numeric { column.align(Alignment::Right) } else { column }
+        })
+        .collect()
+}
+
+/// Render the rows, in their current order, as CSV
+pub fn results_csv(columns: &[String], rows: &[Vec<String>]) -> Result<String> {
+    let mut csv = csv::Writer::from_writer(Vec::new());
+    csv.write_record(columns).context("Failed to write CSV header")?;
+    for row in rows {
+        csv.write_record(row).context("Failed to write CSV row")?;
+    }
+
+    let bytes = csv.into_inner().context("Failed to finish CSV")?;
+    String::from_utf8(bytes).context("CSV is not valid UTF-8")
+}
+
+/// Write the results CSV to the Downloads folder and return its path
+pub fn export_results(results: &QueryResults) -> Result<String> {
+    let content = results_csv(&results.columns, &results.rows)?;
+
+    let timestamp = chrono::Local::now().format("%Y%m%d_%H%M%S");
+    let downloads_dir = dirs::download_dir().context("Could not determine downloads directory")?;
+    let path = downloads_dir.join(format!("query_results_{}.csv", timestamp));
+
+    std::fs::write(&path, content)
+        .with_context(|| format!("Failed to write {}", path.display()))?;
+
+    log::info!("Exported {} query result(s) to: {}", results.rows.len(), path.display());
+    Ok(path.to_string_lossy().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_records_to_table_unions_keys_and_skips_annotations() {
+        let records = vec![
+            json!({"@odata.etag": "W/\"1\"", "name": "Contoso", "revenue": 1500.5}),
+            json!({"name": "Fabrikam", "statecode@OData.Community.Display.V1.FormattedValue": "Active", "statecode": 0}),
+        ];
+        let (columns, rows) = records_to_table(&records);
+
+        assert_eq!(columns, vec!["name", "revenue", "statecode"]);
+        assert_eq!(rows[0], vec!["Contoso", "1500.5", ""]);
+        assert_eq!(rows[1], vec!["Fabrikam", "", "0"]);
+
+        let table = table_columns(&columns, &rows);
+        assert_eq!(table[0].alignment, Alignment::Left);
+        assert_eq!(table[1].alignment, Alignment::Right);
+
+        let csv = results_csv(&columns, &rows).unwrap();
+        assert_eq!(csv, "name,revenue,statecode\nContoso,1500.5,\nFabrikam,,0\n");
+    }
+}
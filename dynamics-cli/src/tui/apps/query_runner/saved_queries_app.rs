@@ -0,0 +1,263 @@
+//! Saved queries: pick a stored FQL query to run, edit or delete, most recently used first
+
+use crossterm::event::KeyCode;
+use crate::tui::{App, AppId, Command, Element, Subscription, FocusId, Resource, KeyBinding, PaletteAction, ToastLevel};
+use crate::tui::renderer::LayeredView;
+use crate::tui::widgets::list::{ListItem, ListState};
+use crate::config::SavedQuery;
+use ratatui::text::{Line, Span};
+use ratatui::style::{Style, Stylize};
+use crate::{col, spacer, use_constraints};
+use super::app::QueryRunnerParams;
+
+pub struct SavedQueriesApp;
+
+#[derive(Clone)]
+struct SavedQueryItem(SavedQuery);
+
+impl ListItem for SavedQueryItem {
+    type Msg = Msg;
+
+    fn to_element(&self, is_selected: bool, _is_hovered: bool) -> Element<Msg> {
+        let theme = &crate::global_runtime_config().theme;
+        let (fg_color, bg_style) = if is_selected {
+            (theme.accent_primary, Some(Style::default().bg(theme.bg_surface)))
+        } else {
+            (theme.text_primary, None)
+        };
+
+        // The whole query on one line, so each entry stays a single row
+        let fql = self.0.fql.split_whitespace().collect::<Vec<_>>().join(" ");
+        let last_used = self.0.last_used.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M");
+
+        let mut builder = Element::styled_text(Line::from(vec![
+            Span::styled(format!("  {}", self.0.name), Style::default().fg(fg_color)),
+            Span::styled(format!("  {}", last_used), Style::default().fg(theme.text_tertiary)),
+            Span::styled(format!("  {}", fql), Style::default().fg(theme.text_secondary)),
+        ]));
+
+        if let Some(bg) = bg_style {
+            builder = builder.background(bg);
+        }
+
+        builder.build()
+    }
+}
+
+pub struct State {
+    queries: Resource<Vec<SavedQueryItem>>,
+    list_state: ListState,
+    delete_name: Option<String>,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self {
+            queries: Resource::Loading,
+            list_state: ListState::with_selection(),
+            delete_name: None,
+        }
+    }
+}
+
+impl crate::tui::AppState for State {}
+
+#[derive(Clone)]
+pub enum Msg {
+    QueriesLoaded(Result<Vec<SavedQuery>, String>),
+    ListNavigate(KeyCode),
+    Run(usize),
+    EditSelected,
+    NewQuery,
+    RequestDelete,
+    ConfirmDelete,
+    CancelDelete,
+    QueryDeleted(Result<(), String>),
+    Back,
+}
+
+impl App for SavedQueriesApp {
+    type State = State;
+    type Msg = Msg;
+    type InitParams = ();
+
+    fn init(_params: ()) -> (State, Command<Msg>) {
+        let cmd = Command::batch(vec![
+            reload_queries(),
+            Command::set_focus(FocusId::new("saved-query-list")),
+        ]);
+        (State::default(), cmd)
+    }
+
+    fn update(state: &mut State, msg: Msg) -> Command<Msg> {
+        match msg {
+            Msg::QueriesLoaded(result) => {
+                state.queries = Resource::from_result(result.map(|queries| queries.into_iter().map(SavedQueryItem).collect()));
+                Command::None
+            }
+            Msg::ListNavigate(key) => {
+                let visible_height = 20; // Approximate, will be corrected during render
+                state.list_state.handle_key(key, state.query_count(), visible_height);
+                Command::None
+            }
+            Msg::Run(idx) => match state.query(idx) {
+                Some(saved) => Command::start_app(AppId::QueryRunner, QueryRunnerParams { saved_query: Some(saved), run: true }),
+                None => Command::None,
+            },
+            Msg::EditSelected => match state.list_state.selected().and_then(|idx| state.query(idx)) {
+                Some(saved) => Command::start_app(AppId::QueryRunner, QueryRunnerParams { saved_query: Some(saved), run: false }),
+                None => Command::None,
+            },
+            Msg::NewQuery => Command::start_app(AppId::QueryRunner, QueryRunnerParams::default()),
+            Msg::RequestDelete => {
+                state.delete_name = state.list_state.selected()
+                    .and_then(|idx| state.query(idx))
+                    .map(|saved| saved.name);
+                Command::None
+            }
+            Msg::ConfirmDelete => match state.delete_name.take() {
+                Some(name) => Command::perform(
+                    async move {
+                        crate::global_config().delete_saved_query(&name).await.map_err(|e| e.to_string())
+                    },
+                    Msg::QueryDeleted,
+                ),
+                None => Command::None,
+            },
+            Msg::CancelDelete => {
+                state.delete_name = None;
+                Command::None
+            }
+            Msg::QueryDeleted(Ok(())) => reload_queries(),
+            Msg::QueryDeleted(Err(e)) => Command::notify(format!("Failed to delete query: {}", e), ToastLevel::Error),
+            Msg::Back => Command::navigate_to(AppId::AppLauncher),
+        }
+    }
+
+    fn view(state: &mut State) -> LayeredView<Msg> {
+        use_constraints!();
+        let theme = &crate::global_runtime_config().theme;
+
+        let content = match &state.queries {
+            Resource::Success(queries) if !queries.is_empty() => {
+                Element::list("saved-query-list", queries, &state.list_state, theme)
+                    .on_select(Msg::Run)
+                    .on_activate(Msg::Run)
+                    .on_navigate(Msg::ListNavigate)
+                    .build()
+            }
+            Resource::Success(_) => Element::styled_text(Line::from(Span::styled(
+                "  No saved queries yet. Press n to write one and save it from the Query Runner.",
+                Style::default().fg(theme.text_tertiary),
+            ))).build(),
+            Resource::Failure(err) => Element::styled_text(Line::from(vec![
+                Span::styled("  Error: ", Style::default().fg(theme.accent_error).bold()),
+                Span::styled(err.clone(), Style::default().fg(theme.text_primary)),
+            ])).build(),
+            Resource::Loading | Resource::NotAsked => Element::styled_text(Line::from(Span::styled(
+                "  Loading saved queries...",
+                Style::default().fg(theme.text_tertiary),
+            ))).build(),
+        };
+
+        let main_ui = Element::panel(content)
+            .title("Saved Queries")
+            .build();
+
+        let Some(name) = &state.delete_name else {
+            return LayeredView::new(main_ui);
+        };
+
+        let cancel_button = Element::button("delete-cancel", "Cancel".to_string())
+            .on_press(Msg::CancelDelete)
+            .build();
+        let confirm_button = Element::button("delete-confirm", "Delete".to_string())
+            .on_press(Msg::ConfirmDelete)
+            .style(Style::default().fg(theme.accent_error))
+            .build();
+        let buttons = Element::row(vec![cancel_button, confirm_button])
+            .spacing(2)
+            .build();
+
+        let modal_content = Element::panel(
+            Element::container(
+                col![
+                    Element::styled_text(Line::from(vec![
+                        Span::styled("Delete Saved Query", Style::default().fg(theme.accent_tertiary).bold())
+                    ])).build() => Length(1),
+                    spacer!() => Length(1),
+                    Element::text(format!("Delete saved query '{}'?\n\nThis action cannot be undone.", name)) => Length(3),
+                    spacer!() => Length(1),
+                    buttons => Length(3),
+                ]
+            )
+            .padding(2)
+            .build()
+        )
+        .width(60)
+        .height(13)
+        .build();
+
+        LayeredView::new(main_ui).with_app_modal(modal_content, crate::tui::Alignment::Center)
+    }
+
+    fn subscriptions(state: &State) -> Vec<Subscription<Msg>> {
+        if state.delete_name.is_some() {
+            return vec![Subscription::keyboard(KeyCode::Esc, "Cancel delete", Msg::CancelDelete)];
+        }
+
+        vec![
+            Subscription::keyboard(KeyCode::Char('e'), "Edit selected query", Msg::EditSelected),
+            Subscription::keyboard(KeyCode::Char('d'), "Delete selected query", Msg::RequestDelete),
+            Subscription::keyboard(KeyCode::Char('n'), "New query", Msg::NewQuery),
+            Subscription::keyboard(KeyCode::Esc, "Back to launcher", Msg::Back),
+        ]
+    }
+
+    fn title() -> &'static str {
+        "Saved Queries"
+    }
+
+    fn palette_actions(state: &State) -> Vec<PaletteAction<Msg>> {
+        let mut actions = vec![PaletteAction::new("New Query", Msg::NewQuery)
+            .detail("Open an empty Query Runner")];
+        if let Resource::Success(queries) = &state.queries {
+            actions.extend(queries.iter().enumerate().map(|(idx, item)| {
+                PaletteAction::new(format!("Run {}", item.0.name), Msg::Run(idx))
+                    .detail(item.0.fql.clone())
+            }));
+        }
+        actions
+    }
+
+    fn help_bindings(_state: &State) -> Vec<(KeyBinding, String)> {
+        vec![
+            (KeyBinding::new(KeyCode::Enter), "Run selected query".to_string()),
+        ]
+    }
+}
+
+impl State {
+    fn query(&self, idx: usize) -> Option<SavedQuery> {
+        match &self.queries {
+            Resource::Success(queries) => queries.get(idx).map(|item| item.0.clone()),
+            _ => None,
+        }
+    }
+
+    fn query_count(&self) -> usize {
+        match &self.queries {
+            Resource::Success(queries) => queries.len(),
+            _ => 0,
+        }
+    }
+}
+
+fn reload_queries() -> Command<Msg> {
+    Command::perform(
+        async {
+            crate::global_config().list_saved_queries().await.map_err(|e| e.to_string())
+        },
+        Msg::QueriesLoaded,
+    )
+}
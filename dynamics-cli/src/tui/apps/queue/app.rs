@@ -8,17 +8,17 @@ use crate::tui::{
     state::theme::Theme,
     renderer::LayeredView,
     widgets::{TreeState, TreeEvent, ScrollableState},
-    ModalState,
+    ModalState, Resource, ToastLevel,
 };
 use crate::{col, row, use_constraints};
-use crate::api::resilience::ResilienceConfig;
 use ratatui::text::Line;
 use std::collections::{HashSet, VecDeque};
+use crate::api::operations::Operation;
 use super::models::{QueueItem, QueueFilter, SortMode, OperationStatus, QueueResult};
 use super::tree_nodes::QueueTreeNode;
-use super::commands::{save_settings_command, execute_next_if_available};
+use super::commands::{save_settings_command, execute_next_if_available, execute_item, load_protected_envs_command};
 use super::utils::estimate_remaining_time;
-use super::views::{build_details_panel, build_clear_confirm_modal, build_delete_confirm_modal, build_interruption_warning_modal};
+use super::views::{build_details_panel, build_clear_confirm_modal, build_delete_confirm_modal, build_interruption_warning_modal, build_protected_write_modal};
 
 pub struct OperationQueueApp;
 
@@ -37,6 +37,8 @@ pub enum Msg {
     TogglePauseItem(String),
     DeleteItem(String),
     RetryItem(String),
+    RetryFailed(String),
+    FailedOperationsLoaded(String, Result<Vec<Operation>, String>),
 
     // Keyboard shortcuts for selected item
     IncreasePrioritySelected,
@@ -44,6 +46,7 @@ pub enum Msg {
     TogglePauseSelected,
     DeleteSelected,
     RetrySelected,
+    RetryFailedSelected,
 
     // Queue management
     AddItems(Vec<QueueItem>),
@@ -51,6 +54,7 @@ pub enum Msg {
     ConfirmClearQueue,
     RequestDeleteSelected,
     ConfirmDeleteSelected,
+    ConfirmProtectedWrite,
     CancelModal,
 
     // Execution
@@ -62,12 +66,17 @@ pub enum Msg {
     SetSortMode(SortMode),
     SetMaxConcurrent(usize),
 
+    // Results export
+    ExportResults,
+    ResultsExported(Result<String, String>),
+
     // Details panel scrolling
     DetailsScroll(crossterm::event::KeyCode),
     DetailsSetDimensions(usize, usize, usize, usize),  // (viewport_height, content_height, viewport_width, content_width)
 
     // State loading and persistence
     StateLoaded(Result<(Vec<QueueItem>, crate::config::repository::queue::QueueSettings, Vec<QueueItem>), String>),
+    ProtectedEnvironmentsLoaded(Result<HashSet<String>, String>),
     PersistenceError(String),
 
     // Interruption warnings
@@ -89,6 +98,11 @@ pub struct State {
     pub max_concurrent: usize,
     pub currently_running: HashSet<String>,
 
+    // Write protection; nothing executes while the list is loading, and a
+    // failed load treats every environment as protected
+    pub protected_envs: Resource<HashSet<String>>,
+    pub confirmed_envs: HashSet<String>,
+
     // Performance tracking
     pub recent_completion_times: VecDeque<u64>, // Store last 10 completion times in ms
 
@@ -102,6 +116,7 @@ pub struct State {
     pub clear_confirm_modal: ModalState<()>,
     pub delete_confirm_modal: ModalState<()>,
     pub interruption_warning_modal: ModalState<Vec<QueueItem>>,
+    pub protected_write_modal: ModalState<String>,
 
     // Loading state
     pub is_loading: bool,
@@ -115,6 +130,8 @@ impl Default for State {
             auto_play: false,
             max_concurrent: 3,
             currently_running: HashSet::new(),
+            protected_envs: Resource::Loading,
+            confirmed_envs: HashSet::new(),
             recent_completion_times: VecDeque::with_capacity(10),
             filter: QueueFilter::All,
             sort_mode: SortMode::Priority,
@@ -123,6 +140,7 @@ impl Default for State {
             clear_confirm_modal: ModalState::Closed,
             delete_confirm_modal: ModalState::Closed,
             interruption_warning_modal: ModalState::Closed,
+            protected_write_modal: ModalState::Closed,
             is_loading: true,
         }
     }
@@ -182,7 +200,7 @@ impl App for OperationQueueApp {
             Msg::StateLoaded
         );
 
-        (State::default(), cmd)
+        (State::default(), Command::Batch(vec![cmd, load_protected_envs_command()]))
     }
 
     fn update(state: &mut State, msg: Msg) -> Command<Msg> {
@@ -218,6 +236,32 @@ impl App for OperationQueueApp {
                 }
             }
 
+            Msg::ProtectedEnvironmentsLoaded(result) => {
+                if let Err(err) = &result {
+                    log::error!("Failed to load protected environments: {}", err);
+                }
+                state.protected_envs = Resource::from_result(result);
+
+                // Execution waits for the list, so pick up where auto-play left off
+                if state.auto_play {
+                    execute_next_if_available(state)
+                } else {
+                    Command::None
+                }
+            }
+
+            Msg::ConfirmProtectedWrite => {
+                if let Some(env) = state.protected_write_modal.data().cloned() {
+                    log::info!("Confirmed writes to protected environment: {}", env);
+                    state.confirmed_envs.insert(env);
+                }
+                state.protected_write_modal.close();
+                Command::Batch(vec![
+                    Command::set_focus(FocusId::new("queue-tree")),
+                    execute_next_if_available(state),
+                ])
+            }
+
             Msg::PersistenceError(err) => {
                 log::error!("Queue persistence error: {}", err);
                 Command::None
@@ -433,6 +477,42 @@ impl App for OperationQueueApp {
                 Command::None
             }
 
+            Msg::RetryFailed(id) => {
+                // Retry what is still recorded as failed for the item's run, which
+                // a headless `operations retry` may have narrowed down since
+                let Some(run_id) = state.queue_items.iter().find(|i| i.id == id).map(|item| item.run_id().to_string()) else {
+                    return Command::None;
+                };
+                Command::perform(
+                    async move {
+                        crate::global_config().list_failed_operations(&run_id).await
+                            .map(|failed| failed.into_iter().map(|f| f.operation).collect())
+                            .map_err(|e| e.to_string())
+                    },
+                    move |result| Msg::FailedOperationsLoaded(id.clone(), result)
+                )
+            }
+
+            Msg::FailedOperationsLoaded(id, result) => {
+                let Some(item) = state.queue_items.iter().find(|i| i.id == id) else {
+                    return Command::None;
+                };
+
+                // Queue the failed operations as a new batch; the original item keeps its results
+                let retry_item = match result {
+                    Ok(operations) => item.retry_with(operations),
+                    Err(err) => {
+                        log::warn!("Failed to load failed operations for {}, using its last result: {}", id, err);
+                        item.failed_subset()
+                    }
+                };
+
+                match retry_item {
+                    Some(retry_item) => Self::update(state, Msg::AddItems(vec![retry_item])),
+                    None => Command::notify("No failed operations left to retry", ToastLevel::Info),
+                }
+            }
+
             Msg::StartExecution(id) => {
                 // Mark as running and set start time
                 if let Some(item) = state.queue_items.iter_mut().find(|i| i.id == id) {
@@ -467,44 +547,7 @@ impl App for OperationQueueApp {
 
                 if let Some(item) = item {
                     let exec_cmd = Command::perform(
-                        async move {
-                            let start = std::time::Instant::now();
-
-                            // Get client for this environment from global client manager
-                            let client = match crate::client_manager().get_client(&item.metadata.environment_name).await {
-                                Ok(client) => client,
-                                Err(e) => {
-                                    let duration_ms = start.elapsed().as_millis() as u64;
-                                    return (item.id.clone(), QueueResult {
-                                        success: false,
-                                        operation_results: vec![],
-                                        error: Some(format!("Failed to get client: {}", e)),
-                                        duration_ms,
-                                    });
-                                }
-                            };
-
-                            let resilience = ResilienceConfig::default();
-                            let result = item.operations.execute(&client, &resilience).await;
-                            let duration_ms = start.elapsed().as_millis() as u64;
-
-                            let queue_result = match result {
-                                Ok(operation_results) => QueueResult {
-                                    success: operation_results.iter().all(|r| r.success),
-                                    operation_results,
-                                    error: None,
-                                    duration_ms,
-                                },
-                                Err(e) => QueueResult {
-                                    success: false,
-                                    operation_results: vec![],
-                                    error: Some(e.to_string()),
-                                    duration_ms,
-                                },
-                            };
-
-                            (item.id.clone(), queue_result)
-                        },
+                        execute_item(item),
                         |(id, result)| Msg::ExecutionCompleted(id, result),
                     );
 
@@ -529,9 +572,12 @@ impl App for OperationQueueApp {
                     item.status = new_status.clone();
                     item.result = Some(result.clone());
 
-                    // Persist to database
+                    // Persist to database, along with what is left to retry for the run
                     let item_id = id.clone();
                     let result_clone = result.clone();
+                    let run_id = item.run_id().to_string();
+                    let outcomes = result.outcomes(&item.operations);
+                    let metadata = item.metadata.clone();
                     persist_cmd = Command::perform(
                         async move {
                             let config = crate::global_config();
@@ -539,6 +585,8 @@ impl App for OperationQueueApp {
                                 .map_err(|e| format!("Failed to update status: {}", e))?;
                             config.update_queue_item_result(&item_id, &result_clone).await
                                 .map_err(|e| format!("Failed to update result: {}", e))?;
+                            config.record_failed_operations(&run_id, &metadata.environment_name, &metadata.description, &outcomes).await
+                                .map_err(|e| format!("Failed to record failed operations: {}", e))?;
                             Ok(())
                         },
                         |result| {
@@ -730,6 +778,13 @@ impl App for OperationQueueApp {
                 Command::None
             }
 
+            Msg::RetryFailedSelected => {
+                if let Some(id) = state.selected_item_id.clone() {
+                    return Self::update(state, Msg::RetryFailed(id));
+                }
+                Command::None
+            }
+
             Msg::AddItems(items) => {
                 let was_empty = state.queue_items.is_empty();
 
@@ -763,14 +818,10 @@ impl App for OperationQueueApp {
                     state.selected_item_id = state.queue_items.first().map(|item| item.id.clone());
                 }
 
-                // If in play mode and we have capacity, start executing
-                let exec_cmd = if state.auto_play && state.currently_running.len() < state.max_concurrent {
-                    execute_next_if_available(state)
-                } else {
-                    Command::None
-                };
-
-                Command::Batch(vec![persist_cmd, exec_cmd])
+                // Protection may have changed since the last load, so in play mode
+                // execution starts once the reload has arrived
+                state.protected_envs = Resource::Loading;
+                Command::Batch(vec![persist_cmd, load_protected_envs_command()])
             }
 
             Msg::RequestClearQueue => {
@@ -803,6 +854,11 @@ impl App for OperationQueueApp {
             Msg::CancelModal => {
                 state.clear_confirm_modal.close();
                 state.delete_confirm_modal.close();
+                if state.protected_write_modal.is_open() {
+                    // Declining a protected write stops auto-play so it doesn't ask again immediately
+                    state.protected_write_modal.close();
+                    state.auto_play = false;
+                }
                 Command::None
             }
 
@@ -823,6 +879,22 @@ impl App for OperationQueueApp {
                 Command::None
             }
 
+            Msg::ExportResults => {
+                if state.queue_items.is_empty() {
+                    return Command::notify("Queue is empty, nothing to export", ToastLevel::Info);
+                }
+                let items = state.queue_items.clone();
+                Command::perform(
+                    async move { super::export::export_results(&items).map_err(|e| e.to_string()) },
+                    Msg::ResultsExported,
+                )
+            }
+
+            Msg::ResultsExported(result) => match result {
+                Ok(path) => Command::notify(format!("Exported results to {}", path), ToastLevel::Success),
+                Err(err) => Command::notify(format!("Export failed: {}", err), ToastLevel::Error),
+            },
+
             Msg::Back => Command::navigate_to(AppId::AppLauncher),
         }
     }
@@ -904,9 +976,19 @@ impl App for OperationQueueApp {
             clear_button => Length(11),
         ];
 
+        // Overall progress across every operation in the queue
+        let total_ops: usize = state.queue_items.iter().map(|item| item.operations.len()).sum();
+        let finished_ops: usize = state.queue_items.iter()
+            .filter(|item| matches!(item.status, OperationStatus::Done | OperationStatus::Failed))
+            .map(|item| item.operations.len())
+            .sum();
+        let progress = Element::progress_bar(finished_ops, total_ops)
+            .label("Operations")
+            .build();
+
         let stats_and_estimates = col![
             Element::text(stats_text) => Length(1),
-            Element::None => Length(1),
+            progress => Length(1),
             Element::text(estimates_text) => Length(1),
         ];
 
@@ -959,6 +1041,12 @@ impl App for OperationQueueApp {
             view = view.with_app_modal(modal, Alignment::Center);
         }
 
+        // Add protected environment confirmation modal if open
+        if state.protected_write_modal.is_open() {
+            let modal = build_protected_write_modal(state);
+            view = view.with_app_modal(modal, Alignment::Center);
+        }
+
         view
     }
 
@@ -977,8 +1065,10 @@ impl App for OperationQueueApp {
             Subscription::keyboard(KeyBinding::new(KeyCode::Char('+')), "Increase priority (selected)", Msg::IncreasePrioritySelected),
             Subscription::keyboard(KeyBinding::new(KeyCode::Char('-')), "Decrease priority (selected)", Msg::DecreasePrioritySelected),
             Subscription::keyboard(KeyBinding::new(KeyCode::Char('r')), "Retry (selected)", Msg::RetrySelected),
+            Subscription::keyboard(KeyBinding::new(KeyCode::Char('R')), "Retry failed operations as new batch (selected)", Msg::RetryFailedSelected),
             Subscription::keyboard(KeyBinding::new(KeyCode::Char('d')), "Delete (selected)", Msg::RequestDeleteSelected),
             Subscription::keyboard(KeyBinding::new(KeyCode::Char('c')), "Clear interruption warning (selected)", Msg::ClearInterruptionFlagSelected),
+            Subscription::keyboard(KeyBinding::new(KeyCode::Char('e')), "Export results to CSV", Msg::ExportResults),
 
             // Event subscriptions
             Subscription::subscribe("queue:add_items", |value| {
@@ -1020,3 +1110,56 @@ impl App for OperationQueueApp {
         crate::tui::SuspendPolicy::AlwaysActive
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::operations::Operations;
+    use super::super::models::QueueMetadata;
+    use serde_json::json;
+
+    fn pending_item(environment_name: &str) -> QueueItem {
+        QueueItem::new(
+            Operations::from_operations(vec![Operation::create("contacts", json!({"firstname": "Jane"}))]),
+            QueueMetadata {
+                source: "Test".to_string(),
+                entity_type: "contacts".to_string(),
+                description: "One contact".to_string(),
+                row_number: None,
+                environment_name: environment_name.to_string(),
+                retry_of: None,
+            },
+            64,
+        )
+    }
+
+    #[test]
+    fn test_execution_waits_for_protected_environments() {
+        let mut state = State::default();
+        state.auto_play = true;
+        state.queue_items.push(pending_item("prod"));
+
+        // The protected list is still loading, so nothing runs yet
+        execute_next_if_available(&mut state);
+        assert!(state.currently_running.is_empty());
+        assert_eq!(state.queue_items[0].status, OperationStatus::Pending);
+
+        // Once it arrives, the write to the protected environment asks first
+        let protected = HashSet::from(["prod".to_string()]);
+        OperationQueueApp::update(&mut state, Msg::ProtectedEnvironmentsLoaded(Ok(protected)));
+        assert!(state.currently_running.is_empty());
+        assert_eq!(state.protected_write_modal.data().map(String::as_str), Some("prod"));
+    }
+
+    #[test]
+    fn test_failed_protection_load_asks_before_writing() {
+        let mut state = State::default();
+        state.queue_items.push(pending_item("dev"));
+
+        OperationQueueApp::update(&mut state, Msg::ProtectedEnvironmentsLoaded(Err("database locked".to_string())));
+        execute_next_if_available(&mut state);
+
+        assert!(state.currently_running.is_empty());
+        assert_eq!(state.protected_write_modal.data().map(String::as_str), Some("dev"));
+    }
+}
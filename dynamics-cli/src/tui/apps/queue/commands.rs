@@ -1,8 +1,12 @@
 //! Command helpers for queue execution
 
+use crate::api::resilience::ResilienceConfig;
+use crate::config::protection::{force_writes, WriteGuard};
 use crate::tui::command::Command;
+use crate::tui::element::FocusId;
+use crate::tui::Resource;
 use super::app::{State, Msg};
-use super::models::{OperationStatus, QueueResult};
+use super::models::{OperationStatus, QueueItem, QueueResult};
 use std::collections::HashSet;
 
 /// Helper function to save queue settings to database
@@ -30,6 +34,18 @@ pub fn save_settings_command(state: &State) -> Command<Msg> {
     )
 }
 
+/// Helper function to (re)load the set of protected environment names
+pub fn load_protected_envs_command() -> Command<Msg> {
+    Command::perform(
+        async move {
+            crate::global_config().list_protected_environments().await
+                .map(|envs| envs.into_iter().collect::<HashSet<String>>())
+                .map_err(|e| format!("Failed to load protected environments: {}", e))
+        },
+        Msg::ProtectedEnvironmentsLoaded
+    )
+}
+
 /// Helper function to execute the next available operation
 pub fn execute_next_if_available(state: &mut State) -> Command<Msg> {
     // Check if we can run more
@@ -46,6 +62,22 @@ pub fn execute_next_if_available(state: &mut State) -> Command<Msg> {
         .map(|item| item.id.clone());
 
     if let Some(id) = next {
+        // Writes to protected environments need a one-time confirmation per session
+        if let Some(env) = state.queue_items.iter().find(|i| i.id == id).map(|i| i.metadata.environment_name.clone()) {
+            let is_protected = match &state.protected_envs {
+                Resource::Loading => return Command::None,
+                Resource::Success(envs) => envs.contains(&env),
+                Resource::NotAsked | Resource::Failure(_) => true,
+            } && !state.confirmed_envs.contains(&env);
+            if WriteGuard::check(is_protected, force_writes()).requires_confirmation() {
+                if state.protected_write_modal.is_open() {
+                    return Command::None;
+                }
+                state.protected_write_modal.open_with(env);
+                return Command::set_focus(FocusId::new("confirmation-cancel"));
+            }
+        }
+
         // Mark as running immediately and set start time
         if let Some(item) = state.queue_items.iter_mut().find(|i| i.id == id) {
             item.status = OperationStatus::Running;
@@ -79,45 +111,7 @@ pub fn execute_next_if_available(state: &mut State) -> Command<Msg> {
 
         if let Some(item) = item {
             let exec_cmd = Command::perform(
-                async move {
-                    use crate::api::resilience::ResilienceConfig;
-                    let start = std::time::Instant::now();
-
-                    // Get client for this environment from global client manager
-                    let client = match crate::client_manager().get_client(&item.metadata.environment_name).await {
-                        Ok(client) => client,
-                        Err(e) => {
-                            let duration_ms = start.elapsed().as_millis() as u64;
-                            return (item.id.clone(), QueueResult {
-                                success: false,
-                                operation_results: vec![],
-                                error: Some(format!("Failed to get client: {}", e)),
-                                duration_ms,
-                            });
-                        }
-                    };
-
-                    let resilience = ResilienceConfig::default();
-                    let result = item.operations.execute(&client, &resilience).await;
-                    let duration_ms = start.elapsed().as_millis() as u64;
-
-                    let queue_result = match result {
-                        Ok(operation_results) => QueueResult {
-                            success: operation_results.iter().all(|r| r.success),
-                            operation_results,
-                            error: None,
-                            duration_ms,
-                        },
-                        Err(e) => QueueResult {
-                            success: false,
-                            operation_results: vec![],
-                            error: Some(e.to_string()),
-                            duration_ms,
-                        },
-                    };
-
-                    (item.id.clone(), queue_result)
-                },
+                execute_item(item),
                 |(id, result)| Msg::ExecutionCompleted(id, result),
             );
 
@@ -129,3 +123,47 @@ pub fn execute_next_if_available(state: &mut State) -> Command<Msg> {
         Command::None
     }
 }
+
+/// Execute a queue item's operations against its environment
+///
+/// Runs through the resilience layer with the configured retry and rate limit
+/// options. Returns the item ID with its result; failures keep each operation's
+/// HTTP status and error body.
+pub async fn execute_item(item: QueueItem) -> (String, QueueResult) {
+    let start = std::time::Instant::now();
+
+    // Get client for this environment from global client manager
+    let client = match crate::client_manager().get_client(&item.metadata.environment_name).await {
+        Ok(client) => client,
+        Err(e) => {
+            let duration_ms = start.elapsed().as_millis() as u64;
+            return (item.id, QueueResult {
+                success: false,
+                operation_results: vec![],
+                error: Some(format!("Failed to get client: {}", e)),
+                duration_ms,
+            });
+        }
+    };
+
+    let resilience = ResilienceConfig::load_from_options().await.unwrap_or_default();
+    let result = item.operations.execute(&client, &resilience).await;
+    let duration_ms = start.elapsed().as_millis() as u64;
+
+    let queue_result = match result {
+        Ok(operation_results) => QueueResult {
+            success: operation_results.iter().all(|r| r.success),
+            operation_results,
+            error: None,
+            duration_ms,
+        },
+        Err(e) => QueueResult {
+            success: false,
+            operation_results: vec![],
+            error: Some(e.to_string()),
+            duration_ms,
+        },
+    };
+
+    (item.id, queue_result)
+}
@@ -0,0 +1,110 @@
+//! Export of queue results to CSV
+
+use anyhow::{Context, Result};
+use super::models::QueueItem;
+
+/// Render one CSV row per operation, with its outcome if the item has run
+///
+/// Failed operations keep their HTTP status and the error body the server returned.
+pub fn results_csv(items: &[QueueItem]) -> Result<String> {
+    let mut csv = csv::Writer::from_writer(Vec::new());
+    csv.write_record([
+        "item_id", "source", "description", "environment", "item_status",
+        "operation", "type", "entity", "success", "status_code", "error", "response",
+    ]).context("Failed to write CSV header")?;
+
+    for item in items {
+        let results = item.result.as_ref().map(|r| r.operation_results.as_slice()).unwrap_or_default();
+        // A top-level failure (e.g. no client) applies to every operation without a result
+        let item_error = item.result.as_ref().and_then(|r| r.error.clone()).unwrap_or_default();
+
+        for (idx, operation) in item.operations.operations().iter().enumerate() {
+            let result = results.get(idx);
+            let success = match result {
+                Some(result) => result.success.to_string(),
+                None => String::new(),
+            };
+            let status_code = result.and_then(|r| r.status_code).map(|code| code.to_string()).unwrap_or_default();
+            let error = result.and_then(|r| r.error.clone()).unwrap_or_else(|| item_error.clone());
+            let response = result
+                .filter(|r| !r.success)
+                .and_then(|r| r.data.as_ref())
+                .map(|body| body.to_string())
+                .unwrap_or_default();
+
+            csv.write_record([
+                item.id.as_str(),
+                item.metadata.source.as_str(),
+                item.metadata.description.as_str(),
+                item.metadata.environment_name.as_str(),
+                item.status.word(),
+                &(idx + 1).to_string(),
+                operation.operation_type(),
+                operation.entity(),
+                &success,
+                &status_code,
+                &error,
+                &response,
+            ]).context("Failed to write CSV row")?;
+        }
+    }
+
+    let bytes = csv.into_inner().context("Failed to finish CSV")?;
+    String::from_utf8(bytes).context("CSV is not valid UTF-8")
+}
+
+/// Write the results CSV to the Downloads folder and return its path
+pub fn export_results(items: &[QueueItem]) -> Result<String> {
+    let content = results_csv(items)?;
+
+    let timestamp = chrono::Local::now().format("%Y%m%d_%H%M%S");
+    let downloads_dir = dirs::download_dir().context("Could not determine downloads directory")?;
+    let path = downloads_dir.join(format!("queue_results_{}.csv", timestamp));
+
+    std::fs::write(&path, content)
+        .with_context(|| format!("Failed to write {}", path.display()))?;
+
+    log::info!("Exported results of {} queue items to: {}", items.len(), path.display());
+    Ok(path.to_string_lossy().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::operations::{Operation, OperationResult, Operations};
+    use super::super::models::{OperationStatus, QueueMetadata, QueueResult};
+    use serde_json::json;
+
+    #[test]
+    fn test_results_csv_includes_status_code_and_error_body() {
+        let ok = Operation::create("contacts", json!({"firstname": "John"}));
+        let rejected = Operation::create("contacts", json!({"lastname": ""}));
+        let metadata = QueueMetadata {
+            source: "Deadlines Excel".to_string(),
+            entity_type: "contacts".to_string(),
+            description: "Row 5".to_string(),
+            row_number: Some(5),
+            environment_name: "dev".to_string(),
+            retry_of: None,
+        };
+        let mut item = QueueItem::new(Operations::from_operations(vec![ok.clone(), rejected.clone()]), metadata, 64);
+        item.status = OperationStatus::Failed;
+
+        let mut failed = OperationResult::error(rejected, "Invalid lastname".to_string(), Some(400));
+        failed.data = Some(json!({"error": {"code": "0x80040203"}}));
+        item.result = Some(QueueResult {
+            success: false,
+            operation_results: vec![OperationResult::success(ok, None), failed],
+            error: None,
+            duration_ms: 10,
+        });
+
+        let csv = results_csv(&[item.clone()]).unwrap();
+        let lines: Vec<&str> = csv.lines().collect();
+
+        assert_eq!(lines.len(), 3);
+        assert!(lines[1].ends_with("Failed,1,create,contacts,true,200,,"));
+        assert!(lines[2].contains("false,400,Invalid lastname,"));
+        assert!(lines[2].ends_with(r#""{""error"":{""code"":""0x80040203""}}""#));
+    }
+}
@@ -1,5 +1,6 @@
 pub mod app;
 pub mod commands;
+pub mod export;
 pub mod models;
 pub mod tree_nodes;
 pub mod utils;
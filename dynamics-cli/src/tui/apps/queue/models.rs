@@ -1,6 +1,6 @@
 //! Data models for the operation queue
 
-use crate::api::operations::{Operations, OperationResult};
+use crate::api::operations::{Operation, Operations, OperationResult};
 use serde::{Serialize, Deserialize};
 use std::time::Instant;
 
@@ -45,6 +45,67 @@ impl QueueItem {
             interrupted_at: None,
         }
     }
+
+    /// Split operations into queue items of at most `batch_size` operations each
+    ///
+    /// Each item shares the metadata, with its batch position appended to the description.
+    pub fn batches(operations: Vec<Operation>, metadata: QueueMetadata, priority: u8, batch_size: usize) -> Vec<QueueItem> {
+        let chunks: Vec<&[Operation]> = operations.chunks(batch_size.max(1)).collect();
+        let total = chunks.len();
+
+        chunks
+            .into_iter()
+            .enumerate()
+            .map(|(idx, chunk)| {
+                let description = if total == 1 {
+                    metadata.description.clone()
+                } else {
+                    format!("{} (batch {}/{})", metadata.description, idx + 1, total)
+                };
+                let metadata = QueueMetadata { description, ..metadata.clone() };
+                QueueItem::new(Operations::from_operations(chunk.to_vec()), metadata, priority)
+            })
+            .collect()
+    }
+
+    /// Build a new queue item containing only the operations that did not succeed
+    ///
+    /// Returns None if the item has no result or every operation succeeded.
+    pub fn failed_subset(&self) -> Option<QueueItem> {
+        let result = self.result.as_ref()?;
+        self.retry(Operations::from_unsuccessful(&result.operation_results), result.operation_results.len())
+    }
+
+    /// Build a new queue item retrying `operations`, e.g. the failures recorded for this item's run
+    ///
+    /// Returns None if there is nothing to retry.
+    pub fn retry_with(&self, operations: Vec<Operation>) -> Option<QueueItem> {
+        self.retry(Operations::from_operations(operations), self.operations.len())
+    }
+
+    fn retry(&self, operations: Operations, total: usize) -> Option<QueueItem> {
+        if operations.is_empty() {
+            return None;
+        }
+
+        let metadata = QueueMetadata {
+            description: format!(
+                "Retry {} failed of {}: {}",
+                operations.len(),
+                total,
+                self.metadata.description
+            ),
+            retry_of: Some(self.run_id().to_string()),
+            ..self.metadata.clone()
+        };
+
+        Some(QueueItem::new(operations, metadata, self.priority))
+    }
+
+    /// ID its failed operations are recorded under: the item it retries, or its own
+    pub fn run_id(&self) -> &str {
+        self.metadata.retry_of.as_deref().unwrap_or(&self.id)
+    }
 }
 
 /// Metadata about where a queue item came from
@@ -60,6 +121,9 @@ pub struct QueueMetadata {
     pub row_number: Option<usize>,
     /// Environment name for client lookup
     pub environment_name: String,
+    /// Run whose failed operations this item retries
+    #[serde(default)]
+    pub retry_of: Option<String>,
 }
 
 /// Status of a queue item
@@ -114,6 +178,26 @@ pub struct QueueResult {
     pub duration_ms: u64,
 }
 
+impl QueueResult {
+    /// Number of operations that did not succeed
+    pub fn failed_count(&self) -> usize {
+        self.operation_results.iter().filter(|r| !r.success).count()
+    }
+
+    /// Per-operation outcomes of running `operations`; when the run failed
+    /// before any operation was sent, every operation carries the run's error
+    pub fn outcomes(&self, operations: &Operations) -> Vec<OperationResult> {
+        if !self.operation_results.is_empty() || self.success {
+            return self.operation_results.clone();
+        }
+
+        let error = self.error.clone().unwrap_or_else(|| "Unknown error".to_string());
+        operations.operations().iter()
+            .map(|operation| OperationResult::error(operation.clone(), error.clone(), None))
+            .collect()
+    }
+}
+
 /// Filter for displaying queue items
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum QueueFilter {
@@ -176,3 +260,91 @@ impl SortMode {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::operations::Operation;
+    use serde_json::json;
+
+    fn metadata() -> QueueMetadata {
+        QueueMetadata {
+            source: "Deadlines Excel".to_string(),
+            entity_type: "contacts".to_string(),
+            description: "Rows 1-3".to_string(),
+            row_number: None,
+            environment_name: "dev".to_string(),
+            retry_of: None,
+        }
+    }
+
+    #[test]
+    fn test_failed_subset_contains_only_unsuccessful_operations() {
+        let ok = Operation::create("contacts", json!({"firstname": "John"}));
+        let rejected = Operation::create("contacts", json!({"lastname": ""}));
+        let rolled_back = Operation::create("contacts", json!({"firstname": "Jane"}));
+
+        let mut item = QueueItem::new(
+            Operations::from_operations(vec![ok.clone(), rejected.clone(), rolled_back.clone()]),
+            metadata(),
+            64,
+        );
+        item.status = OperationStatus::Failed;
+        item.result = Some(QueueResult {
+            success: false,
+            operation_results: vec![
+                OperationResult::success(ok, None),
+                OperationResult::error(rejected.clone(), "Invalid lastname".to_string(), Some(400)),
+                OperationResult::error(rolled_back, "Not applied".to_string(), None),
+            ],
+            error: None,
+            duration_ms: 10,
+        });
+
+        let retry = item.failed_subset().unwrap();
+        assert_ne!(retry.id, item.id);
+        assert_eq!(retry.status, OperationStatus::Pending);
+        assert_eq!(retry.operations.len(), 2);
+        assert_eq!(retry.priority, item.priority);
+        assert_eq!(retry.metadata.environment_name, "dev");
+        assert_eq!(retry.metadata.description, "Retry 2 failed of 3: Rows 1-3");
+        assert_eq!(retry.run_id(), item.id);
+
+        // A retry of the retry still reports to the original run
+        let again = retry.retry_with(vec![rejected]).unwrap();
+        assert_eq!(again.run_id(), item.id);
+        assert_eq!(again.metadata.description, "Retry 1 failed of 2: Retry 2 failed of 3: Rows 1-3");
+    }
+
+    #[test]
+    fn test_batches_split_operations_and_number_descriptions() {
+        let operations: Vec<Operation> = (0..5)
+            .map(|i| Operation::create("contacts", json!({"firstname": i.to_string()})))
+            .collect();
+
+        let items = QueueItem::batches(operations.clone(), metadata(), 10, 2);
+        let sizes: Vec<_> = items.iter().map(|item| item.operations.len()).collect();
+        assert_eq!(sizes, [2, 2, 1]);
+        assert_eq!(items[2].metadata.description, "Rows 1-3 (batch 3/3)");
+        assert!(items.iter().all(|item| item.status == OperationStatus::Pending && item.priority == 10));
+
+        let single = QueueItem::batches(operations, metadata(), 10, 50);
+        assert_eq!(single.len(), 1);
+        assert_eq!(single[0].metadata.description, "Rows 1-3");
+    }
+
+    #[test]
+    fn test_failed_subset_none_when_all_succeeded() {
+        let op = Operation::create("contacts", json!({"firstname": "John"}));
+        let mut item = QueueItem::new(Operations::from_operations(vec![op.clone()]), metadata(), 64);
+        assert!(item.failed_subset().is_none());
+
+        item.result = Some(QueueResult {
+            success: true,
+            operation_results: vec![OperationResult::success(op, None)],
+            error: None,
+            duration_ms: 10,
+        });
+        assert!(item.failed_subset().is_none());
+    }
+}
@@ -4,6 +4,7 @@ use crate::tui::element::{Element, FocusId};
 use crate::tui::widgets::ScrollableState;
 use super::app::{State, Msg};
 use super::models::OperationStatus;
+use crate::api::operations::{OperationOutcome, OperationResult};
 use ratatui::style::Style;
 use ratatui::text::{Line as RataLine, Span};
 use ratatui::prelude::Stylize;
@@ -134,12 +135,22 @@ fn build_operation_details(item: &super::models::QueueItem, child_idx: usize, th
         Operation::Delete { entity, id, .. } => {
             format!("DELETE /{}({})", entity, id)
         }
-        Operation::Upsert { entity, key_field, key_value, .. } => {
-            format!("PATCH /{}({}='{}')", entity, key_field, key_value)
+        Operation::Upsert { entity, keys, .. } => {
+            let keys: Vec<String> = keys
+                .iter()
+                .map(|(field, value)| match value {
+                    serde_json::Value::String(s) => format!("{}='{}'", field, s),
+                    other => format!("{}={}", field, other),
+                })
+                .collect();
+            format!("PATCH /{}({})", entity, keys.join(","))
         }
         Operation::AssociateRef { entity, entity_ref, navigation_property, .. } => {
             format!("POST /{}({})/{}/$ref", entity, entity_ref, navigation_property)
         }
+        Operation::DisassociateRef { entity, entity_ref, navigation_property, target_id } => {
+            format!("DELETE /{}({})/{}({})/$ref", entity, entity_ref, navigation_property, target_id)
+        }
     };
 
     lines.push(Element::styled_text(RataLine::from(vec![
@@ -195,6 +206,21 @@ fn build_operation_details(item: &super::models::QueueItem, child_idx: usize, th
                 Span::styled(target_ref.clone(), Style::default().fg(theme.text_primary)),
             ])).build());
         }
+        Operation::DisassociateRef { entity_ref, navigation_property, target_id, .. } => {
+            lines.push(Element::text(""));
+            lines.push(Element::styled_text(RataLine::from(vec![
+                Span::styled("Entity Ref: ", Style::default().fg(theme.border_primary)),
+                Span::styled(entity_ref.clone(), Style::default().fg(theme.text_primary)),
+            ])).build());
+            lines.push(Element::styled_text(RataLine::from(vec![
+                Span::styled("Navigation: ", Style::default().fg(theme.border_primary)),
+                Span::styled(navigation_property.clone(), Style::default().fg(theme.text_primary)),
+            ])).build());
+            lines.push(Element::styled_text(RataLine::from(vec![
+                Span::styled("Target ID: ", Style::default().fg(theme.border_primary)),
+                Span::styled(target_id.clone(), Style::default().fg(theme.text_primary)),
+            ])).build());
+        }
     }
 
     // Show result if operation completed
@@ -207,13 +233,15 @@ fn build_operation_details(item: &super::models::QueueItem, child_idx: usize, th
                 Span::styled("Result:", Style::default().fg(theme.accent_muted).bold()),
             ])).build());
 
-            let status_color = if op_result.success { theme.accent_success } else { theme.accent_error };
+            let outcome = op_result.outcome();
+            let status_color = match outcome {
+                OperationOutcome::Succeeded => theme.accent_success,
+                OperationOutcome::Failed => theme.accent_error,
+                OperationOutcome::NotApplied => theme.accent_warning,
+            };
             lines.push(Element::styled_text(RataLine::from(vec![
                 Span::styled("  Status: ", Style::default().fg(theme.border_primary)),
-                Span::styled(
-                    if op_result.success { "Success" } else { "Failed" },
-                    Style::default().fg(status_color)
-                ),
+                Span::styled(outcome.word(), Style::default().fg(status_color)),
             ])).build());
 
             if let Some(status_code) = op_result.status_code {
@@ -238,6 +266,21 @@ fn build_operation_details(item: &super::models::QueueItem, child_idx: usize, th
                     ])).build());
                 }
             }
+
+            // Full error body as returned by the server
+            if let (false, Some(body)) = (op_result.success, &op_result.data) {
+                lines.push(Element::text(""));
+                lines.push(Element::styled_text(RataLine::from(vec![
+                    Span::styled("  Response:", Style::default().fg(theme.border_primary).bold()),
+                ])).build());
+
+                let body = serde_json::to_string_pretty(body).unwrap_or_default();
+                for body_line in body.lines() {
+                    lines.push(Element::styled_text(RataLine::from(vec![
+                        Span::styled(format!("    {}", body_line), Style::default().fg(theme.text_secondary)),
+                    ])).build());
+                }
+            }
         }
     }
 
@@ -431,25 +474,23 @@ fn build_batch_overview(item: &super::models::QueueItem, theme: &crate::tui::sta
         // Show individual operation results
         if !result.operation_results.is_empty() {
             lines.push(Element::text(""));
-            lines.push(Element::styled_text(RataLine::from(vec![
-                Span::styled("Operation Results:", Style::default().fg(theme.accent_muted).bold()),
-            ])).build());
-
-            for (idx, op_result) in result.operation_results.iter().enumerate() {
-                let status_symbol = if op_result.success { "✓" } else { "✗" };
-                let status_color = if op_result.success { theme.accent_success } else { theme.accent_error };
-
-                let msg = if let Some(err) = &op_result.error {
-                    err.clone()
-                } else {
-                    "OK".to_string()
-                };
+            lines.push(build_operation_results_summary(&result.operation_results, theme));
+            lines.extend(build_operation_results_table(result.operation_results.iter().enumerate(), theme));
 
+            let failed_count = result.failed_count();
+            if failed_count > 0 {
+                lines.push(Element::text(""));
+                lines.push(
+                    Element::button(
+                        FocusId::new("retry-failed"),
+                        format!("[R] Retry {} failed as new batch", failed_count)
+                    )
+                    .on_press(Msg::RetryFailed(item.id.clone()))
+                    .build()
+                );
                 lines.push(Element::styled_text(RataLine::from(vec![
-                    Span::styled(format!("  {}. ", idx + 1), Style::default().fg(theme.border_primary)),
-                    Span::styled(status_symbol, Style::default().fg(status_color)),
-                    Span::raw(" "),
-                    Span::styled(msg, Style::default().fg(theme.text_primary)),
+                    Span::styled("Headless: ", Style::default().fg(theme.text_tertiary)),
+                    Span::styled(format!("dynamics-cli operations retry {}", item.run_id()), Style::default().fg(theme.text_secondary)),
                 ])).build());
             }
         }
@@ -484,6 +525,24 @@ pub fn build_delete_confirm_modal() -> Element<Msg> {
         .build()
 }
 
+pub fn build_protected_write_modal(state: &State) -> Element<Msg> {
+    use crate::tui::modals::ConfirmationModal;
+
+    let env = state.protected_write_modal.data().cloned().unwrap_or_default();
+
+    ConfirmationModal::new("Protected Environment")
+        .message(format!(
+            "'{}' is a protected environment.\nExecute queued writes against it for this session?",
+            env
+        ))
+        .confirm_text("Execute")
+        .cancel_text("Cancel")
+        .on_confirm(Msg::ConfirmProtectedWrite)
+        .on_cancel(Msg::CancelModal)
+        .width(60)
+        .build()
+}
+
 pub fn build_interruption_warning_modal(state: &State) -> Element<Msg> {
     use crate::tui::modals::WarningModal;
 
@@ -525,3 +584,76 @@ pub fn build_interruption_warning_modal(state: &State) -> Element<Msg> {
 
     modal.build()
 }
+
+/// Render a one-line count of succeeded, failed and not-applied operations
+pub fn build_operation_results_summary<M>(
+    results: &[OperationResult],
+    theme: &crate::tui::state::theme::Theme,
+) -> Element<M> {
+    let succeeded = results.iter().filter(|r| r.outcome() == OperationOutcome::Succeeded).count();
+    let failed = results.iter().filter(|r| r.outcome() == OperationOutcome::Failed).count();
+    let not_applied = results.len() - succeeded - failed;
+
+    let mut summary = vec![
+        Span::styled("Operation Results: ", Style::default().fg(theme.accent_muted).bold()),
+        Span::styled(format!("{} ok", succeeded), Style::default().fg(theme.accent_success)),
+    ];
+    if failed > 0 {
+        summary.push(Span::styled(", ", Style::default().fg(theme.border_primary)));
+        summary.push(Span::styled(format!("{} failed", failed), Style::default().fg(theme.accent_error)));
+    }
+    if not_applied > 0 {
+        summary.push(Span::styled(", ", Style::default().fg(theme.border_primary)));
+        summary.push(Span::styled(format!("{} not applied", not_applied), Style::default().fg(theme.accent_warning)));
+    }
+
+    Element::styled_text(RataLine::from(summary)).build()
+}
+
+/// Render per-item results of an executed batch as a table
+///
+/// Rows are `(index, result)` pairs so callers can show a subset while keeping the
+/// original positions. Failed and not-applied rows are followed by the server message.
+pub fn build_operation_results_table<'a, M>(
+    results: impl IntoIterator<Item = (usize, &'a OperationResult)>,
+    theme: &crate::tui::state::theme::Theme,
+) -> Vec<Element<M>> {
+    let mut lines = vec![
+        Element::styled_text(RataLine::from(vec![
+            Span::styled(
+                format!("  {:>3}  {:<16} {:<24} {}", "#", "Type", "Entity", "Status"),
+                Style::default().fg(theme.border_primary).bold()
+            ),
+        ])).build(),
+    ];
+
+    for (idx, op_result) in results {
+        let outcome = op_result.outcome();
+        let status_color = match outcome {
+            OperationOutcome::Succeeded => theme.accent_success,
+            OperationOutcome::Failed => theme.accent_error,
+            OperationOutcome::NotApplied => theme.accent_warning,
+        };
+        let status = match op_result.status_code {
+            Some(code) => format!("{} {}", outcome.symbol(), code),
+            None => format!("{} {}", outcome.symbol(), outcome.word()),
+        };
+
+        lines.push(Element::styled_text(RataLine::from(vec![
+            Span::styled(format!("  {:>3}  ", idx + 1), Style::default().fg(theme.border_primary)),
+            Span::styled(format!("{:<16} ", op_result.operation.operation_type()), Style::default().fg(theme.accent_secondary)),
+            Span::styled(format!("{:<24} ", op_result.operation.entity()), Style::default().fg(theme.text_primary)),
+            Span::styled(status, Style::default().fg(status_color)),
+        ])).build());
+
+        if let Some(error) = &op_result.error {
+            for error_line in error.lines() {
+                lines.push(Element::styled_text(RataLine::from(vec![
+                    Span::styled(format!("       {}", error_line), Style::default().fg(status_color)),
+                ])).build());
+            }
+        }
+    }
+
+    lines
+}
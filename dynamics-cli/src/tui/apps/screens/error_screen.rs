@@ -8,6 +8,14 @@ use crate::tui::element::ColumnBuilder;
 
 pub struct ErrorScreen;
 
+/// What Enter does, for the hint and the help menu
+fn continue_label(target: Option<AppId>) -> &'static str {
+    match target {
+        Some(AppId::AppLauncher) => "Return to the launcher",
+        _ => "Continue",
+    }
+}
+
 pub struct ErrorScreenParams {
     pub message: String,
     pub target: Option<AppId>,
@@ -75,7 +83,7 @@ impl App for ErrorScreen {
             Element::text(&state.error_message),
             Element::text(""),
             Element::styled_text(Line::from(vec![
-                Span::styled("Press Enter to continue", Style::default().fg(theme.border_primary)),
+                Span::styled(format!("Press Enter to {}", continue_label(state.target_app).to_lowercase()), Style::default().fg(theme.border_primary)),
             ])).build(),
         ];
 
@@ -95,9 +103,9 @@ impl App for ErrorScreen {
         LayeredView::new(panel)
     }
 
-    fn subscriptions(_state: &State) -> Vec<Subscription<Msg>> {
+    fn subscriptions(state: &State) -> Vec<Subscription<Msg>> {
         vec![
-            Subscription::keyboard(KeyCode::Enter, "Continue", Msg::Continue),
+            Subscription::keyboard(KeyCode::Enter, continue_label(state.target_app), Msg::Continue),
         ]
     }
 
@@ -187,16 +187,17 @@ impl App for LoadingScreen {
         let theme = &crate::global_runtime_config().theme;
 
         // Header
-        let all_done = state.tasks.iter().all(|t| {
-            matches!(t.status, TaskStatus::Completed | TaskStatus::Failed(_))
-        });
+        let done_count = state.tasks.iter()
+            .filter(|t| matches!(t.status, TaskStatus::Completed | TaskStatus::Failed(_)))
+            .count();
+        let all_done = done_count == state.tasks.len();
 
         let header_text = if state.countdown_ticks.is_some() {
             "All tasks completed! Returning in 1 second...".to_string()
         } else if all_done {
             "All tasks completed!".to_string()
         } else {
-            "Loading...".to_string()
+            format!("Loading... {}/{} loaded", done_count, state.tasks.len())
         };
 
         content.push(Element::styled_text(Line::from(vec![
@@ -5,7 +5,7 @@ use crate::tui::{
     app::App,
     command::Command,
     element::{Element, FocusId, ColumnBuilder, RowBuilder},
-    state::{config::RuntimeConfig, theme::Theme},
+    state::theme::Theme,
     subscription::Subscription,
     widgets::list::{ListItem, ListState},
     LayeredView, LayoutConstraint,
@@ -37,6 +37,7 @@ pub struct State {
     editing: Option<EditingState>,
     edit_input_state: crate::tui::widgets::TextInputState,
     edit_select_state: crate::tui::widgets::SelectState,
+    edit_number_state: crate::tui::widgets::NumberInputState,
     error: Option<String>,
 
     // Theme editor state (theme view)
@@ -49,6 +50,9 @@ pub struct State {
     create_theme_input_state: crate::tui::widgets::TextInputState,
     editing_color: Option<EditingColor>,
     color_picker_state: crate::tui::widgets::ColorPickerState,
+    theme_file_action: Option<ThemeFileAction>,
+    theme_file_input: String,
+    theme_file_input_state: crate::tui::widgets::TextInputState,
 
     // Keybind editor state (keybind view)
     keybinds: std::collections::HashMap<String, crate::tui::KeyBinding>,
@@ -61,6 +65,9 @@ pub struct State {
     keybind_apps: Vec<String>,
 }
 
+/// Rows taken by the theme sample panel (six lines plus borders)
+const THEME_SAMPLE_HEIGHT: u16 = 8;
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 enum ViewMode {
     AutoGenerated,  // Normal options list
@@ -74,6 +81,13 @@ struct EditingColor {
     color_name: String,
 }
 
+/// What the theme file path prompt is for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ThemeFileAction {
+    Export,
+    Import,
+}
+
 #[derive(Debug, Clone)]
 enum EditingState {
     TextInput {
@@ -84,6 +98,26 @@ enum EditingState {
         key: String,
         options: Vec<String>,
     },
+    Number {
+        key: String,
+    },
+}
+
+/// Number input for an integer option, bounded by its definition and starting at `current`
+fn number_input_for(opt: &OptionDefinition, current: &OptionValue) -> crate::tui::widgets::NumberInputState {
+    use crate::tui::widgets::NumberInputState;
+    let to_i64 = |n: u64| i64::try_from(n).unwrap_or(i64::MAX);
+
+    match &opt.ty {
+        OptionType::UInt { min, max } => {
+            NumberInputState::new(current.as_uint().map(to_i64).unwrap_or(0))
+                .bounds(Some(min.map_or(0, to_i64)), max.map(to_i64))
+        }
+        OptionType::Int { min, max } => {
+            NumberInputState::new(current.as_int().unwrap_or(0)).bounds(*min, *max)
+        }
+        _ => NumberInputState::default(),
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -101,6 +135,7 @@ pub enum Msg {
     StartEdit,
     EditInputEvent(crate::tui::widgets::TextInputEvent),
     EditSelectEvent(crate::tui::widgets::SelectEvent),
+    EditNumberEvent(crate::tui::widgets::NumberInputEvent),
     SaveValue,
     CancelEdit,
     ValueSaved(Result<(), String>),
@@ -110,7 +145,6 @@ pub enum Msg {
     BoolToggled(Result<(), String>),
 
     // Runtime config reload
-    ConfigReloaded(Result<(), String>),
 
     // Theme editor
     ThemesLoaded(std::collections::HashMap<String, crate::tui::Theme>),
@@ -133,6 +167,13 @@ pub enum Msg {
     CancelEditColor,
     SaveColor,
     ColorSaved(Result<(), String>),
+    ExportSelectedTheme,
+    ImportTheme,
+    ThemeFileInput(crate::tui::widgets::TextInputEvent),
+    SubmitThemeFile,
+    CancelThemeFile,
+    ThemeExported(Result<String, String>),
+    ThemeImported(Result<crate::tui::state::theme_file::ImportedTheme, String>),
 
     // Keybind editor
     KeybindsLoaded(std::collections::HashMap<String, crate::tui::KeyBinding>),
@@ -144,6 +185,10 @@ pub enum Msg {
     KeybindSaved(Result<(), String>),
     ResetKeybinds,
     ResetKeybindsCompleted(Result<(), String>),
+
+    // Caches
+    ClearMetadataCache,
+    MetadataCacheCleared(Result<u64, String>),
 }
 
 impl Default for State {
@@ -163,6 +208,7 @@ impl Default for State {
             editing: None,
             edit_input_state: crate::tui::widgets::TextInputState::new(),
             edit_select_state: crate::tui::widgets::SelectState::new(),
+            edit_number_state: crate::tui::widgets::NumberInputState::default(),
             error: None,
             themes: std::collections::HashMap::new(),
             theme_select_state: crate::tui::widgets::SelectState::new(),
@@ -176,6 +222,9 @@ impl Default for State {
                 Color::Rgb(180, 190, 254),
                 ColorPickerMode::HSL
             ),
+            theme_file_action: None,
+            theme_file_input: String::new(),
+            theme_file_input_state: crate::tui::widgets::TextInputState::new(),
             keybinds: std::collections::HashMap::new(),
             keybind_actions: Vec::new(),
             selected_keybind_idx: 0,
@@ -195,6 +244,17 @@ impl State {
         names.sort();
         names
     }
+
+    /// The selected theme as it currently looks, including a color still
+    /// being adjusted in the color picker
+    fn preview_theme(&self) -> Option<(String, crate::tui::Theme)> {
+        let name = self.theme_names().get(self.selected_theme_idx)?.clone();
+        let mut theme = self.themes.get(&name)?.clone();
+        if let Some(editing) = self.editing_color.as_ref().filter(|editing| editing.theme_name == name) {
+            theme.set_color(&editing.color_name, self.color_picker_state.color());
+        }
+        Some((name, theme))
+    }
 }
 
 impl crate::tui::AppState for State {}
@@ -461,7 +521,16 @@ impl App for SettingsApp {
                                 }
                                 return Command::set_focus(FocusId::new("edit-select"));
                             }
-                            OptionType::UInt { .. } | OptionType::Int { .. } | OptionType::Float { .. } | OptionType::String { .. } => {
+                            OptionType::UInt { .. } | OptionType::Int { .. } => {
+                                // Bounded number input for integers
+                                let current = state.values.get(&opt.key).unwrap_or(&opt.default);
+                                state.edit_number_state = number_input_for(opt, current);
+                                state.editing = Some(EditingState::Number {
+                                    key: opt.key.clone(),
+                                });
+                                return Command::set_focus(FocusId::new("edit-number"));
+                            }
+                            OptionType::Float { .. } | OptionType::String { .. } => {
                                 // Start text input for other types
                                 let current = state
                                     .values
@@ -564,7 +633,16 @@ impl App for SettingsApp {
                             }
                             return Command::set_focus(FocusId::new("edit-select"));
                         }
-                        OptionType::UInt { .. } | OptionType::Int { .. } | OptionType::Float { .. } | OptionType::String { .. } => {
+                        OptionType::UInt { .. } | OptionType::Int { .. } => {
+                            // Bounded number input for integers
+                            let current = state.values.get(&opt.key).unwrap_or(&opt.default);
+                            state.edit_number_state = number_input_for(opt, current);
+                            state.editing = Some(EditingState::Number {
+                                key: opt.key.clone(),
+                            });
+                            return Command::set_focus(FocusId::new("edit-number"));
+                        }
+                        OptionType::Float { .. } | OptionType::String { .. } => {
                             // Start text input for other types
                             let current = state
                                 .values
@@ -611,33 +689,22 @@ impl App for SettingsApp {
             }
 
             Msg::BoolToggled(Ok(())) => {
-                // Reload values and runtime config
-                Command::batch(vec![
-                    Command::perform(
-                        async {
-                            let config = crate::global_config();
-                            let mut values = std::collections::HashMap::new();
+                // Reload values (the runtime config reloads itself on option changes)
+                Command::perform(
+                    async {
+                        let config = crate::global_config();
+                        let mut values = std::collections::HashMap::new();
 
-                            for def in crate::options_registry().list_all() {
-                                if let Ok(value) = config.options.get(&def.key).await {
-                                    values.insert(def.key.clone(), value);
-                                }
+                        for def in crate::options_registry().list_all() {
+                            if let Ok(value) = config.options.get(&def.key).await {
+                                values.insert(def.key.clone(), value);
                             }
+                        }
 
-                            Ok(values)
-                        },
-                        Msg::ValuesLoaded,
-                    ),
-                    Command::perform(
-                        async {
-                            let new_config = crate::tui::state::RuntimeConfig::load_from_options().await
-                                .map_err(|e| e.to_string())?;
-                            crate::reload_runtime_config(new_config);
-                            Ok(())
-                        },
-                        Msg::ConfigReloaded,
-                    ),
-                ])
+                        Ok(values)
+                    },
+                    Msg::ValuesLoaded,
+                )
             }
 
             Msg::BoolToggled(Err(e)) => {
@@ -695,10 +762,10 @@ impl App for SettingsApp {
                         }
                         Command::None
                     }
-                    TextInputEvent::Changed(key_code) => {
+                    event => {
                         if let Some(EditingState::TextInput { input, .. }) = &mut state.editing {
-                            // Use TextInputState's handle_key to process the key and get new value
-                            if let Some(new_value) = state.edit_input_state.handle_key(key_code, input, None) {
+                            // Use TextInputState to apply the key, paste or selection change
+                            if let Some(new_value) = state.edit_input_state.handle_event(event, input, None) {
                                 *input = new_value;
                             }
                         }
@@ -781,6 +848,37 @@ impl App for SettingsApp {
                 Command::None
             }
 
+            Msg::EditNumberEvent(event) => {
+                use crate::tui::widgets::NumberInputEvent;
+
+                let submitted = matches!(event, NumberInputEvent::Submit);
+                state.edit_number_state.handle_event(event);
+                if !submitted {
+                    return Command::None;
+                }
+
+                let Some(EditingState::Number { key }) = state.editing.take() else {
+                    return Command::None;
+                };
+                let number = state.edit_number_state.value();
+                let value = match state.current_options.iter().find(|o| o.key == key).map(|o| &o.ty) {
+                    // Bounds keep UInt inputs at or above zero
+                    Some(OptionType::UInt { .. }) => OptionValue::UInt(u64::try_from(number).unwrap_or(0)),
+                    _ => OptionValue::Int(number),
+                };
+
+                Command::perform(
+                    async move {
+                        crate::global_config()
+                            .options
+                            .set(&key, value)
+                            .await
+                            .map_err(|e| e.to_string())
+                    },
+                    Msg::ValueSaved,
+                )
+            }
+
             Msg::SaveValue => {
                 if let Some(EditingState::TextInput { key, input }) = &state.editing {
                     let key = key.clone();
@@ -824,33 +922,22 @@ impl App for SettingsApp {
 
             Msg::ValueSaved(Ok(())) => {
                 state.error = None;
-                // Reload values and runtime config
-                Command::batch(vec![
-                    Command::perform(
-                        async {
-                            let config = crate::global_config();
-                            let mut values = std::collections::HashMap::new();
+                // Reload values (the runtime config reloads itself on option changes)
+                Command::perform(
+                    async {
+                        let config = crate::global_config();
+                        let mut values = std::collections::HashMap::new();
 
-                            for def in crate::options_registry().list_all() {
-                                if let Ok(value) = config.options.get(&def.key).await {
-                                    values.insert(def.key.clone(), value);
-                                }
+                        for def in crate::options_registry().list_all() {
+                            if let Ok(value) = config.options.get(&def.key).await {
+                                values.insert(def.key.clone(), value);
                             }
+                        }
 
-                            Ok(values)
-                        },
-                        Msg::ValuesLoaded,
-                    ),
-                    Command::perform(
-                        async {
-                            let new_config = crate::tui::state::RuntimeConfig::load_from_options().await
-                                .map_err(|e| e.to_string())?;
-                            crate::reload_runtime_config(new_config);
-                            Ok(())
-                        },
-                        Msg::ConfigReloaded,
-                    ),
-                ])
+                        Ok(values)
+                    },
+                    Msg::ValuesLoaded,
+                )
             }
 
             Msg::ValueSaved(Err(e)) => {
@@ -859,17 +946,6 @@ impl App for SettingsApp {
                 Command::None
             }
 
-            Msg::ConfigReloaded(Ok(())) => {
-                log::debug!("Runtime config reloaded successfully");
-                Command::None
-            }
-
-            Msg::ConfigReloaded(Err(e)) => {
-                log::error!("Failed to reload runtime config: {}", e);
-                state.error = Some(format!("Failed to reload config: {}", e));
-                Command::None
-            }
-
             // Theme editor messages
             Msg::ThemesLoaded(themes) => {
                 state.themes = themes;
@@ -964,11 +1040,6 @@ impl App for SettingsApp {
                         config.options.set_string("theme.active", theme_name.clone()).await
                             .map_err(|e| e.to_string())?;
 
-                        // Reload runtime config
-                        let new_config = RuntimeConfig::load_from_options().await
-                            .map_err(|e| e.to_string())?;
-                        crate::reload_runtime_config(new_config);
-
                         Ok(theme_name)
                     },
                     Msg::ThemeActivated
@@ -1003,10 +1074,13 @@ impl App for SettingsApp {
             Msg::CreateThemeInput(event) => {
                 use crate::tui::widgets::TextInputEvent;
                 match event {
-                    TextInputEvent::Changed(key_code) => {
-                        // Use TextInputState's handle_key to process the key and get new value
-                        if let Some(new_value) = state.create_theme_input_state.handle_key(
-                            key_code,
+                    TextInputEvent::Submit => {
+                        Self::update(state, Msg::SubmitCreateTheme)
+                    }
+                    event => {
+                        // Use TextInputState to apply the key, paste or selection change
+                        if let Some(new_value) = state.create_theme_input_state.handle_event(
+                            event,
                             &state.create_theme_input,
                             None
                         ) {
@@ -1014,9 +1088,6 @@ impl App for SettingsApp {
                         }
                         Command::None
                     }
-                    TextInputEvent::Submit => {
-                        Self::update(state, Msg::SubmitCreateTheme)
-                    }
                 }
             }
 
@@ -1228,15 +1299,6 @@ impl App for SettingsApp {
                             config.options.set_string(&key, hex).await
                                 .map_err(|e| e.to_string())?;
 
-                            // If editing active theme, reload runtime config
-                            let active = config.options.get_string("theme.active").await
-                                .map_err(|e| e.to_string())?;
-                            if active == theme_name {
-                                let new_config = RuntimeConfig::load_from_options().await
-                                    .map_err(|e| e.to_string())?;
-                                crate::reload_runtime_config(new_config);
-                            }
-
                             Ok(())
                         },
                         Msg::ColorSaved
@@ -1247,7 +1309,13 @@ impl App for SettingsApp {
             }
 
             Msg::ColorSaved(Ok(())) => {
-                state.editing_color = None;
+                // Keep the preview in step with what was saved
+                if let Some(editing) = state.editing_color.take() {
+                    let color = state.color_picker_state.color();
+                    if let Some(theme) = state.themes.get_mut(&editing.theme_name) {
+                        theme.set_color(&editing.color_name, color);
+                    }
+                }
                 Command::None
             }
 
@@ -1256,6 +1324,146 @@ impl App for SettingsApp {
                 Command::None
             }
 
+            Msg::ExportSelectedTheme => {
+                let theme_names = state.theme_names();
+                if let Some(theme_name) = theme_names.get(state.selected_theme_idx) {
+                    state.theme_file_action = Some(ThemeFileAction::Export);
+                    state.theme_file_input = format!("{}.theme.json", theme_name);
+                    state.theme_file_input_state = crate::tui::widgets::TextInputState::new();
+                    state.theme_file_input_state.set_cursor_to_end(&state.theme_file_input);
+                    return Command::set_focus(FocusId::new("theme-file-input"));
+                }
+                Command::None
+            }
+
+            Msg::ImportTheme => {
+                state.theme_file_action = Some(ThemeFileAction::Import);
+                state.theme_file_input.clear();
+                state.theme_file_input_state = crate::tui::widgets::TextInputState::new();
+                Command::set_focus(FocusId::new("theme-file-input"))
+            }
+
+            Msg::ThemeFileInput(event) => {
+                use crate::tui::widgets::TextInputEvent;
+                match event {
+                    TextInputEvent::Submit => {
+                        Self::update(state, Msg::SubmitThemeFile)
+                    }
+                    event => {
+                        if let Some(new_value) = state.theme_file_input_state.handle_event(
+                            event,
+                            &state.theme_file_input,
+                            None
+                        ) {
+                            state.theme_file_input = new_value;
+                        }
+                        Command::None
+                    }
+                }
+            }
+
+            Msg::CancelThemeFile => {
+                state.theme_file_action = None;
+                Command::None
+            }
+
+            Msg::SubmitThemeFile => {
+                let path = std::path::PathBuf::from(state.theme_file_input.trim());
+                if path.as_os_str().is_empty() {
+                    state.error = Some("File path cannot be empty".to_string());
+                    return Command::None;
+                }
+
+                match state.theme_file_action.take() {
+                    Some(ThemeFileAction::Export) => {
+                        let Some((theme_name, theme)) = state.preview_theme() else {
+                            return Command::None;
+                        };
+                        Command::perform(
+                            async move {
+                                crate::tui::state::theme_file::export_theme(&path, &theme_name, &theme)
+                                    .map_err(|e| e.to_string())?;
+                                Ok(path.display().to_string())
+                            },
+                            Msg::ThemeExported
+                        )
+                    }
+                    Some(ThemeFileAction::Import) => {
+                        Command::perform(
+                            async move {
+                                use crate::config::options::registrations::themes;
+                                use crate::tui::color::color_to_hex;
+                                let config = crate::global_config();
+                                let registry = crate::options_registry();
+
+                                let imported = crate::tui::state::theme_file::import_theme(&path)
+                                    .map_err(|e| format!("{:#}", e))?;
+
+                                // Importing under an existing name overwrites that theme
+                                if !themes::list_themes(&registry).contains(&imported.name) {
+                                    themes::register_theme(&registry, &imported.name, &imported.theme)
+                                        .map_err(|e| e.to_string())?;
+                                }
+                                for (color_name, color) in imported.theme.colors() {
+                                    let key = format!("theme.{}.{}", imported.name, color_name);
+                                    config.options.set_string(&key, color_to_hex(color)).await
+                                        .map_err(|e| format!("Failed to write {}: {}", key, e))?;
+                                }
+
+                                Ok(imported)
+                            },
+                            Msg::ThemeImported
+                        )
+                    }
+                    None => Command::None,
+                }
+            }
+
+            Msg::ThemeExported(Ok(path)) => {
+                Command::notify(format!("Exported theme to {}", path), crate::tui::ToastLevel::Success)
+            }
+
+            Msg::ThemeExported(Err(e)) => {
+                state.error = Some(format!("Failed to export theme: {}", e));
+                Command::None
+            }
+
+            Msg::ThemeImported(Ok(imported)) => {
+                for warning in &imported.warnings {
+                    log::warn!("Theme '{}': {}", imported.name, warning);
+                }
+                let notice = if imported.warnings.is_empty() {
+                    Command::notify(format!("Imported theme '{}'", imported.name), crate::tui::ToastLevel::Success)
+                } else {
+                    Command::notify(
+                        format!(
+                            "Imported theme '{}' with {} warning(s): {}",
+                            imported.name,
+                            imported.warnings.len(),
+                            imported.warnings[0]
+                        ),
+                        crate::tui::ToastLevel::Warning
+                    )
+                };
+
+                let reload = Command::perform(
+                    async {
+                        use crate::config::options::registrations::themes;
+                        let config = crate::global_config();
+                        let registry = crate::options_registry();
+                        let theme_names = themes::list_themes(&registry);
+                        crate::tui::state::config::load_all_themes(&config.options, theme_names).await
+                    },
+                    Msg::ThemesLoaded
+                );
+                Command::batch(vec![notice, reload])
+            }
+
+            Msg::ThemeImported(Err(e)) => {
+                state.error = Some(format!("Failed to import theme: {}", e));
+                Command::None
+            }
+
             // Keybind editor messages
             Msg::KeybindsLoaded(keybinds) => {
                 use crate::config::options::registrations::keybinds;
@@ -1353,11 +1561,6 @@ impl App for SettingsApp {
                             config.options.set_string(&key, keybind_str).await
                                 .map_err(|e| e.to_string())?;
 
-                            // Reload runtime config
-                            let new_config = RuntimeConfig::load_from_options().await
-                                .map_err(|e| e.to_string())?;
-                            crate::reload_runtime_config(new_config);
-
                             Ok(())
                         },
                         Msg::KeybindSaved
@@ -1417,11 +1620,6 @@ impl App for SettingsApp {
                         config.options.set_string("keybind.global.app_overview", "Ctrl+O".to_string()).await
                             .map_err(|e| e.to_string())?;
 
-                        // Reload runtime config
-                        let new_config = RuntimeConfig::load_from_options().await
-                            .map_err(|e| e.to_string())?;
-                        crate::reload_runtime_config(new_config);
-
                         Ok(())
                     },
                     Msg::ResetKeybindsCompleted
@@ -1456,6 +1654,24 @@ impl App for SettingsApp {
                 state.error = Some(format!("Failed to reset keybinds: {}", e));
                 Command::None
             }
+
+            Msg::ClearMetadataCache => {
+                Command::perform(
+                    async {
+                        crate::global_config().clear_metadata_cache().await.map_err(|e| e.to_string())
+                    },
+                    Msg::MetadataCacheCleared
+                )
+            }
+
+            Msg::MetadataCacheCleared(Ok(count)) => {
+                Command::notify(format!("Cleared {} cached metadata entries", count), crate::tui::ToastLevel::Success)
+            }
+
+            Msg::MetadataCacheCleared(Err(e)) => {
+                state.error = Some(format!("Failed to clear metadata cache: {}", e));
+                Command::None
+            }
         }
     }
 
@@ -1579,6 +1795,12 @@ impl App for SettingsApp {
             view = view.with_app_modal(modal, crate::tui::Alignment::Center);
         }
 
+        // Theme import/export path modal
+        if state.theme_file_action.is_some() {
+            let modal = Self::render_theme_file_modal(state);
+            view = view.with_app_modal(modal, crate::tui::Alignment::Center);
+        }
+
         // Color picker modal
         if state.editing_color.is_some() {
             let modal = Self::render_color_picker_modal(state);
@@ -1608,6 +1830,17 @@ impl App for SettingsApp {
             ));
         }
 
+        // The API options page also manages the metadata cache
+        let on_api_options = state.view_mode == ViewMode::AutoGenerated
+            && state.namespaces.get(state.selected_namespace).is_some_and(|namespace| namespace == "api");
+        if on_api_options && state.editing.is_none() {
+            subs.push(Subscription::keyboard(
+                KeyCode::Char('C'),
+                "Clear metadata cache",
+                Msg::ClearMetadataCache,
+            ));
+        }
+
         // If editing, allow Escape to cancel
         if state.editing.is_some() {
             subs.push(Subscription::keyboard(
@@ -1626,6 +1859,15 @@ impl App for SettingsApp {
             ));
         }
 
+        // If entering a theme file path, allow Escape to cancel
+        if state.theme_file_action.is_some() {
+            subs.push(Subscription::keyboard(
+                KeyCode::Esc,
+                "Cancel theme file",
+                Msg::CancelThemeFile,
+            ));
+        }
+
         // If editing color, allow Escape to cancel
         if state.editing_color.is_some() {
             subs.push(Subscription::keyboard(
@@ -1760,6 +2002,17 @@ impl App for SettingsApp {
         "Settings"
     }
 
+    fn palette_actions(_state: &State) -> Vec<crate::tui::PaletteAction<Msg>> {
+        vec![
+            crate::tui::PaletteAction::new("Clear Metadata Cache", Msg::ClearMetadataCache)
+                .detail("Fields, forms and views are fetched again on next load"),
+            crate::tui::PaletteAction::new("Import Theme", Msg::ImportTheme)
+                .detail("Load a theme from a JSON or TOML file"),
+            crate::tui::PaletteAction::new("Export Selected Theme", Msg::ExportSelectedTheme)
+                .detail("Save the selected theme to a JSON or TOML file"),
+        ]
+    }
+
     fn status(_state: &State) -> Option<Line<'static>> {
         Some(Line::from(vec![
             Span::raw("Configure application options")
@@ -1820,20 +2073,45 @@ impl SettingsApp {
         .on_press(Msg::DeleteSelectedTheme)
         .build();
 
+        let export_button = Element::button(
+            "theme-export",
+            "Export"
+        )
+        .on_press(Msg::ExportSelectedTheme)
+        .build();
+
+        let import_button = Element::button(
+            "theme-import",
+            "Import"
+        )
+        .on_press(Msg::ImportTheme)
+        .build();
+
         let buttons = row![
             create_button => Length(15),
             Element::text("") => Length(1),
             delete_button => Length(10),
+            Element::text("") => Length(1),
+            export_button => Length(10),
+            Element::text("") => Length(1),
+            import_button => Length(10),
             Element::text("") => Fill(1),
         ];
 
         // Color preview for selected theme
         let preview_panel = Self::render_theme_preview(state, theme);
 
+        // Sample widgets drawn in the selected theme
+        let sample_panel = match state.preview_theme() {
+            Some((_, preview)) => Self::render_theme_sample(&preview),
+            None => Element::text(""),
+        };
+
         // Combine selector, preview, and buttons
         let content = col![
             theme_select => Length(3),  // Same size as button (borders + content)
             preview_panel => Fill(1),  // 12 color buttons (3 lines each) + panel borders
+            sample_panel => Length(THEME_SAMPLE_HEIGHT),
             buttons => Length(3),  // Buttons with padding
         ];
 
@@ -1860,7 +2138,8 @@ impl SettingsApp {
         let theme_name = selected_theme_name.unwrap();
 
         // Get the selected theme from state (or fall back to runtime theme if not found)
-        let preview_theme = state.themes.get(theme_name).unwrap_or(runtime_theme);
+        let edited_theme = state.preview_theme().map(|(_, theme)| theme);
+        let preview_theme = edited_theme.as_ref().unwrap_or(runtime_theme);
 
         let colored_box = "████";
         let colors = preview_theme.colors();
@@ -1944,6 +2223,59 @@ impl SettingsApp {
             .build()
     }
 
+    /// Render sample widgets (panel, list with a selected row, buttons, status
+    /// text) in `preview` so a theme can be judged before it is activated
+    fn render_theme_sample(preview: &crate::tui::Theme) -> Element<Msg> {
+        let base = Style::default().bg(preview.bg_base);
+        let line = |spans: Vec<Span<'static>>| {
+            Element::styled_text(Line::from(spans)).background(base).build()
+        };
+
+        let rows = vec![
+            line(vec![
+                Span::styled("╭─ ", base.fg(preview.border_primary)),
+                Span::styled("Accounts", base.fg(preview.accent_tertiary).bold()),
+                Span::styled(" ─────────────────────╮", base.fg(preview.border_primary)),
+            ]),
+            line(vec![
+                Span::styled("│ ", base.fg(preview.border_primary)),
+                Span::styled("  Contoso Ltd        ", base.fg(preview.text_primary)),
+                Span::styled("active ", base.fg(preview.text_tertiary)),
+            ]),
+            line(vec![
+                Span::styled("│ ", base.fg(preview.border_primary)),
+                Span::styled("▶ Fabrikam Inc       ", Style::default().bg(preview.bg_surface).fg(preview.accent_primary).bold()),
+                Span::styled("active ", Style::default().bg(preview.bg_surface).fg(preview.text_secondary)),
+            ]),
+            line(vec![
+                Span::styled("│ ", base.fg(preview.border_primary)),
+                Span::styled("  Northwind Traders  ", base.fg(preview.text_secondary)),
+                Span::styled("closed ", base.fg(preview.border_secondary)),
+            ]),
+            line(vec![
+                Span::styled("╰──────────────────────────────╯", base.fg(preview.border_primary)),
+            ]),
+            line(vec![
+                Span::styled(" Save ", Style::default().bg(preview.accent_primary).fg(preview.bg_base).bold()),
+                Span::styled(" ", base),
+                Span::styled(" Cancel ", Style::default().bg(preview.bg_elevated).fg(preview.text_primary)),
+                Span::styled("  ", base),
+                Span::styled("✓ saved ", base.fg(preview.accent_success)),
+                Span::styled("! pending ", base.fg(preview.accent_warning)),
+                Span::styled("✗ failed ", base.fg(preview.accent_error)),
+                Span::styled("docs", base.fg(preview.accent_secondary).underlined()),
+            ]),
+        ];
+
+        let mut column = ColumnBuilder::new();
+        for row in rows {
+            column = column.add(row, LayoutConstraint::Length(1));
+        }
+        Element::panel(column.spacing(0).build())
+            .title("Sample")
+            .build()
+    }
+
     /// Render the keybind editor view
     fn render_keybind_editor(state: &mut State, theme: &crate::tui::Theme) -> (Element<Msg>, String) {
         use_constraints!();
@@ -2149,10 +2481,16 @@ impl SettingsApp {
                 Span::styled(hex_value, Style::default().fg(theme.text_secondary)),
             ]);
 
+            let sample = match state.preview_theme() {
+                Some((_, preview)) => Self::render_theme_sample(&preview),
+                None => Element::text(""),
+            };
+
             let content = col![
                 Element::styled_text(preview_line).build() => Length(1),
                 Element::text("") => Length(1),
                 picker => Length(9),
+                sample => Length(THEME_SAMPLE_HEIGHT),
                 Element::text("") => Length(1),
                 Element::styled_text(Line::from(vec![
                     Span::styled("Controls: ", Style::default().fg(theme.text_primary).bold()),
@@ -2183,6 +2521,60 @@ impl SettingsApp {
         }
     }
 
+    fn render_theme_file_modal(state: &mut State) -> Element<Msg> {
+        let theme = &crate::global_runtime_config().theme;
+        use_constraints!();
+
+        let input_widget = Element::text_input(
+            FocusId::new("theme-file-input"),
+            &state.theme_file_input,
+            &state.theme_file_input_state
+        )
+        .placeholder("ocean.theme.json")
+        .on_event(Msg::ThemeFileInput)
+        .build();
+
+        let input_panel = Element::panel(input_widget)
+            .title("File Path")
+            .build();
+
+        let (title, hint_text, action) = match state.theme_file_action {
+            Some(ThemeFileAction::Import) => (
+                "Import Theme",
+                "Missing or invalid colors fall back to the defaults.".to_string(),
+                " to import, ",
+            ),
+            _ => (
+                "Export Theme",
+                format!(
+                    "Saves '{}' as TOML for a .toml path, JSON otherwise.",
+                    state.theme_names().get(state.selected_theme_idx).cloned().unwrap_or_default()
+                ),
+                " to export, ",
+            ),
+        };
+
+        let content = col![
+            input_panel => Length(5),
+            Element::text("") => Length(1),
+            Element::styled_text(Line::from(vec![
+                Span::styled(hint_text, Style::default().fg(theme.text_tertiary))
+            ])).build() => Length(1),
+            Element::text("") => Length(1),
+            Element::styled_text(Line::from(vec![
+                Span::raw("Press "),
+                Span::styled("Enter", Style::default().fg(theme.accent_primary).bold()),
+                Span::raw(action),
+                Span::styled("Esc", Style::default().fg(theme.accent_primary).bold()),
+                Span::raw(" to cancel"),
+            ])).build() => Length(1),
+        ];
+
+        Element::panel(content)
+            .title(title)
+            .build()
+    }
+
     fn render_create_theme_modal(state: &mut State) -> Element<Msg> {
         let theme = &crate::global_runtime_config().theme;
         use_constraints!();
@@ -2273,6 +2665,22 @@ impl SettingsApp {
             } else {
                 return Element::text("Invalid edit state"); // Should not happen
             }
+        } else if let Some(EditingState::Number { key }) = &state.editing {
+            if key == &opt.key {
+                let number_widget = Element::number_input(
+                    FocusId::new("edit-number"),
+                    &state.edit_number_state
+                )
+                .on_event(Msg::EditNumberEvent)
+                .build();
+
+                (
+                    Element::panel(number_widget).title("Value").build(),
+                    "Up/Down to step (PgUp/PgDn by 10), Enter to save, Esc to cancel"
+                )
+            } else {
+                return Element::text("Invalid edit state"); // Should not happen
+            }
         } else {
             return Element::text("No edit state"); // Should not happen
         };
@@ -123,7 +123,7 @@ impl App for UpdateApp {
                 state.update_info = Resource::Loading;
                 Command::perform(
                     async {
-                        crate::update::check_for_updates().await
+                        crate::update::check_for_updates(crate::update::configured_channel().await).await
                             .map_err(|e| e.to_string())
                     },
                     Msg::UpdateInfoLoaded
@@ -157,7 +157,7 @@ impl App for UpdateApp {
                             commands.push(
                                 Command::perform(
                                     async {
-                                        crate::update::install_update(false).await
+                                        crate::update::install_update(crate::update::configured_channel().await, false).await
                                             .map_err(|e| e.to_string())
                                     },
                                     Msg::UpdateInstalled
@@ -178,7 +178,7 @@ impl App for UpdateApp {
                 state.installing = true;
                 Command::perform(
                     async {
-                        crate::update::install_update(false).await
+                        crate::update::install_update(crate::update::configured_channel().await, false).await
                             .map_err(|e| e.to_string())
                     },
                     Msg::UpdateInstalled
@@ -192,7 +192,7 @@ impl App for UpdateApp {
                         // Refresh update info to show we're up to date
                         Command::perform(
                             async {
-                                crate::update::check_for_updates().await
+                                crate::update::check_for_updates(crate::update::configured_channel().await).await
                                     .map_err(|e| e.to_string())
                             },
                             Msg::UpdateInfoLoaded
@@ -214,7 +214,7 @@ impl App for UpdateApp {
                 state.update_info = Resource::Loading;
                 Command::perform(
                     async {
-                        crate::update::check_for_updates().await
+                        crate::update::check_for_updates(crate::update::configured_channel().await).await
                             .map_err(|e| e.to_string())
                     },
                     Msg::UpdateInfoLoaded
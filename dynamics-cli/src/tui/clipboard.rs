@@ -0,0 +1,44 @@
+//! System clipboard access for widgets
+//!
+//! The clipboard is opened once and kept for the life of the process: on X11
+//! the copied text is only served while its owner is alive. Without a clipboard
+//! (headless, SSH without forwarding) copy and paste log a warning and do nothing.
+
+use std::sync::Mutex;
+
+use once_cell::sync::Lazy;
+
+static CLIPBOARD: Lazy<Mutex<Option<arboard::Clipboard>>> = Lazy::new(|| {
+    match arboard::Clipboard::new() {
+        Ok(clipboard) => Mutex::new(Some(clipboard)),
+        Err(e) => {
+            log::warn!("Clipboard unavailable, copy and paste are disabled: {}", e);
+            Mutex::new(None)
+        }
+    }
+});
+
+/// Copy text to the system clipboard
+pub fn copy(text: &str) {
+    let mut clipboard = CLIPBOARD.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some(clipboard) = clipboard.as_mut() {
+        match clipboard.set_text(text) {
+            Ok(()) => log::debug!("Copied {} characters to clipboard", text.chars().count()),
+            Err(e) => log::warn!("Failed to copy to clipboard: {}", e),
+        }
+    }
+}
+
+/// Read text from the system clipboard
+///
+/// Returns None when the clipboard is unavailable, empty, or holds no text.
+pub fn paste() -> Option<String> {
+    let mut clipboard = CLIPBOARD.lock().unwrap_or_else(|e| e.into_inner());
+    match clipboard.as_mut()?.get_text() {
+        Ok(text) => Some(text),
+        Err(e) => {
+            log::warn!("Failed to paste from clipboard: {}", e);
+            None
+        }
+    }
+}
@@ -11,6 +11,7 @@ use serde::{Serialize, Deserialize};
 /// - Widget events that should be auto-dispatched to Field types via AppState
 /// - App messages that should go directly to the update function
 /// - Unhandled keys that should pass through to global subscriptions
+/// - Keys the widget handled itself (e.g. copying to the clipboard)
 pub enum DispatchTarget<Msg> {
     /// Widget event - runtime tries auto-dispatch via AppState::dispatch_widget_event
     WidgetEvent(Box<dyn Any + Send>),
@@ -20,6 +21,9 @@ pub enum DispatchTarget<Msg> {
 
     /// Pass through to global subscriptions without blurring focus
     PassThrough,
+
+    /// Consumed by the widget without producing a message
+    Handled,
 }
 
 /// Commands represent side effects that apps want to perform.
@@ -68,6 +72,9 @@ pub enum Command<Msg> {
     /// Clear focus from all elements
     ClearFocus,
 
+    /// Show a transient toast notification
+    Notify { message: String, level: crate::tui::ToastLevel },
+
     /// Quit the application
     Quit,
 }
@@ -85,6 +92,8 @@ pub struct ParallelConfig {
     pub on_complete: Option<AppId>,
     pub caller: Option<AppId>,
     pub cancellable: bool,
+    /// Most tasks running at once; None runs them all together
+    pub max_concurrent: Option<usize>,
 }
 
 impl Default for ParallelConfig {
@@ -94,6 +103,7 @@ impl Default for ParallelConfig {
             on_complete: None,
             caller: None,
             cancellable: false,
+            max_concurrent: None,
         }
     }
 }
@@ -117,6 +127,8 @@ pub enum AppId {
     SelectQuestionnaire,
     CopyQuestionnaire,
     PushQuestionnaire,
+    QueryRunner,
+    SavedQueries,
 }
 
 impl<Msg> Command<Msg> {
@@ -191,6 +203,14 @@ impl<Msg> Command<Msg> {
         Command::ClearFocus
     }
 
+    /// Helper to show a toast notification
+    pub fn notify(message: impl Into<String>, level: crate::tui::ToastLevel) -> Self {
+        Command::Notify {
+            message: message.into(),
+            level,
+        }
+    }
+
     /// Start building a parallel task execution command
     pub fn perform_parallel() -> ParallelBuilder<Msg>
     where
@@ -257,6 +277,12 @@ impl<Msg: Send + 'static> ParallelBuilder<Msg> {
         self
     }
 
+    /// Run at most `limit` tasks at once; the rest wait on the loading screen for a free slot
+    pub fn max_concurrent(mut self, limit: usize) -> Self {
+        self.config.max_concurrent = Some(limit.max(1));
+        self
+    }
+
     /// Build the command with a message mapper that converts task results to messages
     /// The mapper receives (task_index, result) and should downcast the result to the expected type
     pub fn build(self, msg_mapper: impl Fn(usize, Box<dyn Any + Send>) -> Msg + Send + 'static) -> Command<Msg> {
@@ -0,0 +1,152 @@
+//! Command palette: a fuzzy-filtered launcher for actions offered by running apps
+
+use crossterm::event::KeyCode;
+use crate::tui::AppId;
+use crate::tui::widgets::TextInputState;
+use crate::tui::widgets::autocomplete::fuzzy_rank;
+
+/// An action an app offers in the command palette
+///
+/// Choosing it sends `msg` to the app that offered it, so the app's `update`
+/// decides what happens (navigate, open a modal, start a task...).
+#[derive(Clone)]
+pub struct PaletteAction<Msg> {
+    pub label: String,
+    pub detail: String,
+    pub msg: Msg,
+}
+
+impl<Msg> PaletteAction<Msg> {
+    pub fn new(label: impl Into<String>, msg: Msg) -> Self {
+        Self { label: label.into(), detail: String::new(), msg }
+    }
+
+    /// Secondary text shown next to the label
+    pub fn detail(mut self, detail: impl Into<String>) -> Self {
+        self.detail = detail.into();
+        self
+    }
+}
+
+/// A palette action with its message left behind in the app that offered it
+#[derive(Debug, Clone, PartialEq)]
+pub struct PaletteEntry {
+    /// App the action is sent to
+    pub app: AppId,
+    /// Position in that app's `palette_actions`
+    pub index: usize,
+    pub label: String,
+    pub detail: String,
+}
+
+/// Query, matches and selection of an open command palette
+#[derive(Debug, Clone, Default)]
+pub struct PaletteState {
+    query: String,
+    input_state: TextInputState,
+    entries: Vec<PaletteEntry>,
+    /// Indices into `entries`, best match first
+    matches: Vec<usize>,
+    selected: usize,
+}
+
+impl PaletteState {
+    /// Start with an empty query, listing every entry in the given order
+    pub fn new(entries: Vec<PaletteEntry>) -> Self {
+        let mut state = Self { entries, ..Self::default() };
+        state.refilter();
+        state
+    }
+
+    pub fn query(&self) -> &str {
+        &self.query
+    }
+
+    pub fn input_state(&self) -> &TextInputState {
+        &self.input_state
+    }
+
+    /// Matching entries, best first
+    pub fn matches(&self) -> impl Iterator<Item = &PaletteEntry> {
+        self.matches.iter().map(|&idx| &self.entries[idx])
+    }
+
+    /// Position of the selected entry within `matches`
+    pub fn selected(&self) -> usize {
+        self.selected
+    }
+
+    pub fn selected_entry(&self) -> Option<&PaletteEntry> {
+        self.matches.get(self.selected).map(|&idx| &self.entries[idx])
+    }
+
+    /// Up/Down move the selection (wrapping), other keys edit the query
+    pub fn handle_key(&mut self, key: KeyCode) {
+        let count = self.matches.len();
+        match key {
+            KeyCode::Up if count > 0 => {
+                self.selected = (self.selected + count - 1) % count;
+            }
+            KeyCode::Down if count > 0 => {
+                self.selected = (self.selected + 1) % count;
+            }
+            KeyCode::Up | KeyCode::Down => {}
+            _ => {
+                if let Some(query) = self.input_state.handle_key(key, &self.query, None) {
+                    self.query = query;
+                    self.refilter();
+                }
+            }
+        }
+    }
+
+    fn refilter(&mut self) {
+        self.matches = if self.query.is_empty() {
+            (0..self.entries.len()).collect()
+        } else {
+            fuzzy_rank(&self.query, self.entries.iter().map(|entry| entry.label.as_str()))
+                .into_iter()
+                .map(|(idx, _)| idx)
+                .collect()
+        };
+        self.selected = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(index: usize, label: &str) -> PaletteEntry {
+        PaletteEntry { app: AppId::AppLauncher, index, label: label.to_string(), detail: String::new() }
+    }
+
+    fn labels(state: &PaletteState) -> Vec<&str> {
+        state.matches().map(|entry| entry.label.as_str()).collect()
+    }
+
+    #[test]
+    fn test_typing_filters_and_resets_selection() {
+        let mut state = PaletteState::new(vec![
+            entry(0, "Open Settings"),
+            entry(1, "Switch Environment"),
+            entry(2, "Check for Updates"),
+        ]);
+        assert_eq!(labels(&state), vec!["Open Settings", "Switch Environment", "Check for Updates"]);
+
+        state.handle_key(KeyCode::Up);
+        assert_eq!(state.selected_entry().unwrap().label, "Check for Updates");
+
+        for c in "env".chars() {
+            state.handle_key(KeyCode::Char(c));
+        }
+        assert_eq!(state.query(), "env");
+        assert_eq!(labels(&state), vec!["Switch Environment"]);
+        assert_eq!(state.selected_entry().unwrap().index, 1);
+
+        state.handle_key(KeyCode::Char('z'));
+        assert!(state.selected_entry().is_none());
+        state.handle_key(KeyCode::Down);
+        assert_eq!(state.selected(), 0);
+    }
+}
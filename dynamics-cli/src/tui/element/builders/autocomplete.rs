@@ -10,6 +10,7 @@ pub struct AutocompleteBuilder<Msg> {
     pub(crate) placeholder: Option<String>,
     pub(crate) is_open: bool,
     pub(crate) filtered_options: Vec<String>,
+    pub(crate) match_indices: Vec<Vec<usize>>,
     pub(crate) highlight: usize,
     pub(crate) on_input: Option<fn(crossterm::event::KeyCode) -> Msg>,
     pub(crate) on_select: Option<fn(String) -> Msg>,
@@ -69,6 +70,7 @@ impl<Msg> AutocompleteBuilder<Msg> {
             placeholder: self.placeholder,
             is_open: self.is_open,
             filtered_options: self.filtered_options,
+            match_indices: self.match_indices,
             highlight: self.highlight,
             on_input: self.on_input,
             on_select: self.on_select,
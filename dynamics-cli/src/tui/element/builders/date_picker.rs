@@ -0,0 +1,51 @@
+use crate::tui::Element;
+use crate::tui::element::FocusId;
+use crate::tui::widgets::{DatePickerState, DatePickerEvent};
+
+/// Builder for date picker elements
+pub struct DatePickerBuilder<Msg> {
+    pub(crate) id: FocusId,
+    pub(crate) state: DatePickerState,
+    pub(crate) placeholder: Option<String>,
+    pub(crate) on_event: Option<fn(DatePickerEvent) -> Msg>,
+    pub(crate) on_focus: Option<Msg>,
+    pub(crate) on_blur: Option<Msg>,
+}
+
+impl<Msg> DatePickerBuilder<Msg> {
+    /// Text shown inline while no date is selected
+    pub fn placeholder(mut self, text: impl Into<String>) -> Self {
+        self.placeholder = Some(text.into());
+        self
+    }
+
+    /// Set the event handler
+    pub fn on_event(mut self, handler: fn(DatePickerEvent) -> Msg) -> Self {
+        self.on_event = Some(handler);
+        self
+    }
+
+    /// Set the focus handler
+    pub fn on_focus(mut self, msg: Msg) -> Self {
+        self.on_focus = Some(msg);
+        self
+    }
+
+    /// Set the blur handler
+    pub fn on_blur(mut self, msg: Msg) -> Self {
+        self.on_blur = Some(msg);
+        self
+    }
+
+    /// Build the date picker element
+    pub fn build(self) -> Element<Msg> {
+        Element::DatePicker {
+            id: self.id,
+            state: self.state,
+            placeholder: self.placeholder,
+            on_event: self.on_event,
+            on_focus: self.on_focus,
+            on_blur: self.on_blur,
+        }
+    }
+}
@@ -1,5 +1,6 @@
 use crate::tui::Element;
 use crate::tui::element::FocusId;
+use crate::tui::widgets::ListEvent;
 
 /// Builder for list elements
 pub struct ListBuilder<Msg> {
@@ -10,9 +11,15 @@ pub struct ListBuilder<Msg> {
     pub(crate) on_select: Option<fn(usize) -> Msg>,
     pub(crate) on_activate: Option<fn(usize) -> Msg>,
     pub(crate) on_navigate: Option<fn(crossterm::event::KeyCode) -> Msg>,
+    pub(crate) on_event: Option<fn(ListEvent) -> Msg>,
     pub(crate) on_focus: Option<Msg>,
     pub(crate) on_blur: Option<Msg>,
     pub(crate) on_render: Option<fn(usize) -> Msg>,
+    pub(crate) filterable: bool,
+    pub(crate) filter: Option<String>,
+    pub(crate) filter_matches: Option<Vec<usize>>,
+    pub(crate) show_scrollbar: bool,
+    pub(crate) reorderable: bool,
 }
 
 impl<Msg> ListBuilder<Msg> {
@@ -31,6 +38,30 @@ impl<Msg> ListBuilder<Msg> {
         self
     }
 
+    /// Set unified event callback (receives filter events, and navigation if on_navigate is unset)
+    pub fn on_event(mut self, msg: fn(ListEvent) -> Msg) -> Self {
+        self.on_event = Some(msg);
+        self
+    }
+
+    /// Let the user filter items by typing `/` (items need `ListItem::filter_text`)
+    pub fn filterable(mut self, filterable: bool) -> Self {
+        self.filterable = filterable;
+        self
+    }
+
+    /// Let the user move the selected item with Alt+Up/Down (emits `ListEvent::Reorder` to on_event)
+    pub fn reorderable(mut self, reorderable: bool) -> Self {
+        self.reorderable = reorderable;
+        self
+    }
+
+    /// Draw a scrollbar when items overflow (default: true)
+    pub fn show_scrollbar(mut self, show: bool) -> Self {
+        self.show_scrollbar = show;
+        self
+    }
+
     pub fn on_focus(mut self, msg: Msg) -> Self {
         self.on_focus = Some(msg);
         self
@@ -55,9 +86,15 @@ impl<Msg> ListBuilder<Msg> {
             on_select: self.on_select,
             on_activate: self.on_activate,
             on_navigate: self.on_navigate,
+            on_event: self.on_event,
             on_focus: self.on_focus,
             on_blur: self.on_blur,
             on_render: self.on_render,
+            filterable: self.filterable,
+            filter: self.filter,
+            filter_matches: self.filter_matches,
+            show_scrollbar: self.show_scrollbar,
+            reorderable: self.reorderable,
         }
     }
 }
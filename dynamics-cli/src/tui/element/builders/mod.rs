@@ -1,4 +1,5 @@
 // Builder modules
+mod text;
 mod styled_text;
 mod button;
 mod column;
@@ -7,16 +8,22 @@ mod container;
 mod panel;
 mod list;
 mod text_input;
+mod text_area;
 mod tree;
 mod table_tree;
+mod table;
 mod scrollable;
 mod select;
 mod autocomplete;
 mod file_browser;
 mod color_picker;
+mod date_picker;
+mod number_input;
 mod progress_bar;
+mod tabs;
 
 // Re-export builders
+pub use text::TextBuilder;
 pub use styled_text::StyledTextBuilder;
 pub use button::ButtonBuilder;
 pub use column::ColumnBuilder;
@@ -25,11 +32,16 @@ pub use container::ContainerBuilder;
 pub use panel::PanelBuilder;
 pub use list::ListBuilder;
 pub use text_input::TextInputBuilder;
+pub use text_area::TextAreaBuilder;
 pub use tree::TreeBuilder;
 pub use table_tree::TableTreeBuilder;
+pub use table::TableBuilder;
 pub use scrollable::ScrollableBuilder;
 pub use select::SelectBuilder;
 pub use autocomplete::AutocompleteBuilder;
 pub use file_browser::FileBrowserBuilder;
 pub use color_picker::ColorPickerBuilder;
+pub use date_picker::DatePickerBuilder;
+pub use number_input::NumberInputBuilder;
 pub use progress_bar::ProgressBarBuilder;
+pub use tabs::TabsBuilder;
@@ -0,0 +1,43 @@
+use crate::tui::Element;
+use crate::tui::element::FocusId;
+use crate::tui::widgets::{NumberInputState, NumberInputEvent};
+
+/// Builder for number input elements
+pub struct NumberInputBuilder<Msg> {
+    pub(crate) id: FocusId,
+    pub(crate) state: NumberInputState,
+    pub(crate) on_event: Option<fn(NumberInputEvent) -> Msg>,
+    pub(crate) on_focus: Option<Msg>,
+    pub(crate) on_blur: Option<Msg>,
+}
+
+impl<Msg> NumberInputBuilder<Msg> {
+    /// Set the event handler
+    pub fn on_event(mut self, handler: fn(NumberInputEvent) -> Msg) -> Self {
+        self.on_event = Some(handler);
+        self
+    }
+
+    /// Set the focus handler
+    pub fn on_focus(mut self, msg: Msg) -> Self {
+        self.on_focus = Some(msg);
+        self
+    }
+
+    /// Set the blur handler
+    pub fn on_blur(mut self, msg: Msg) -> Self {
+        self.on_blur = Some(msg);
+        self
+    }
+
+    /// Build the number input element
+    pub fn build(self) -> Element<Msg> {
+        Element::NumberInput {
+            id: self.id,
+            state: self.state,
+            on_event: self.on_event,
+            on_focus: self.on_focus,
+            on_blur: self.on_blur,
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::tui::Element;
+use crate::tui::{Element, SpinnerStyle};
 
 /// Builder for progress bar elements
 pub struct ProgressBarBuilder<Msg> {
@@ -8,6 +8,8 @@ pub struct ProgressBarBuilder<Msg> {
     pub(crate) show_percentage: bool,
     pub(crate) show_count: bool,
     pub(crate) width: Option<u16>,
+    pub(crate) indeterminate: Option<usize>,
+    pub(crate) spinner_style: SpinnerStyle,
     pub(crate) _phantom: std::marker::PhantomData<Msg>,
 }
 
@@ -36,6 +38,12 @@ impl<Msg> ProgressBarBuilder<Msg> {
         self
     }
 
+    /// Animation for indeterminate bars (default: sliding bar)
+    pub fn spinner_style(mut self, style: SpinnerStyle) -> Self {
+        self.spinner_style = style;
+        self
+    }
+
     pub fn build(self) -> Element<Msg> {
         Element::ProgressBar {
             current: self.current,
@@ -44,6 +52,8 @@ impl<Msg> ProgressBarBuilder<Msg> {
             show_percentage: self.show_percentage,
             show_count: self.show_count,
             width: self.width,
+            indeterminate: self.indeterminate,
+            spinner_style: self.spinner_style,
         }
     }
 }
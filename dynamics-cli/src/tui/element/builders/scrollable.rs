@@ -9,6 +9,7 @@ pub struct ScrollableBuilder<Msg> {
     pub(crate) content_height: Option<usize>,
     pub(crate) horizontal_scroll_offset: usize,
     pub(crate) content_width: Option<usize>,
+    pub(crate) show_scrollbar: bool,
     pub(crate) on_navigate: Option<fn(crossterm::event::KeyCode) -> Msg>,
     pub(crate) on_render: Option<fn(usize, usize, usize, usize) -> Msg>,
     pub(crate) on_focus: Option<Msg>,
@@ -28,6 +29,12 @@ impl<Msg> ScrollableBuilder<Msg> {
         self
     }
 
+    /// Draw scrollbars on overflowing edges (default: true)
+    pub fn show_scrollbar(mut self, show: bool) -> Self {
+        self.show_scrollbar = show;
+        self
+    }
+
     /// Set callback for navigation keys (Up/Down/PageUp/PageDown/Home/End/Left/Right)
     pub fn on_navigate(mut self, msg: fn(crossterm::event::KeyCode) -> Msg) -> Self {
         self.on_navigate = Some(msg);
@@ -58,6 +65,7 @@ impl<Msg> ScrollableBuilder<Msg> {
             content_height: self.content_height,
             horizontal_scroll_offset: self.horizontal_scroll_offset,
             content_width: self.content_width,
+            show_scrollbar: self.show_scrollbar,
             on_navigate: self.on_navigate,
             on_render: self.on_render,
             on_focus: self.on_focus,
@@ -0,0 +1,76 @@
+use crate::tui::Element;
+use crate::tui::element::FocusId;
+use crate::tui::widgets::{SortDirection, TableEvent};
+use ratatui::layout::{Alignment, Constraint};
+
+/// Builder for sortable table elements
+pub struct TableBuilder<Msg> {
+    pub(crate) id: FocusId,
+    pub(crate) headers: Vec<String>,
+    pub(crate) widths: Vec<Constraint>,
+    pub(crate) alignments: Vec<Alignment>,
+    pub(crate) rows: Vec<Vec<String>>,
+    pub(crate) selected: Option<usize>,
+    pub(crate) scroll_offset: usize,
+    pub(crate) focused_column: usize,
+    pub(crate) sort_column: Option<usize>,
+    pub(crate) sort_direction: SortDirection,
+    pub(crate) on_sort: Option<fn(usize) -> Msg>,
+    pub(crate) on_event: Option<fn(TableEvent) -> Msg>,
+    pub(crate) on_focus: Option<Msg>,
+    pub(crate) on_blur: Option<Msg>,
+    pub(crate) on_render: Option<fn(usize) -> Msg>,
+}
+
+impl<Msg> TableBuilder<Msg> {
+    /// Set the handler called with a column index when the user asks to sort by it
+    pub fn on_sort(mut self, handler: fn(usize) -> Msg) -> Self {
+        self.on_sort = Some(handler);
+        self
+    }
+
+    /// Set the event handler (navigation, column focus, row selection)
+    pub fn on_event(mut self, handler: fn(TableEvent) -> Msg) -> Self {
+        self.on_event = Some(handler);
+        self
+    }
+
+    /// Set the focus handler
+    pub fn on_focus(mut self, msg: Msg) -> Self {
+        self.on_focus = Some(msg);
+        self
+    }
+
+    /// Set the blur handler
+    pub fn on_blur(mut self, msg: Msg) -> Self {
+        self.on_blur = Some(msg);
+        self
+    }
+
+    /// Set the handler called with the number of visible rows on each render
+    pub fn on_render(mut self, handler: fn(usize) -> Msg) -> Self {
+        self.on_render = Some(handler);
+        self
+    }
+
+    /// Build the table element
+    pub fn build(self) -> Element<Msg> {
+        Element::Table {
+            id: self.id,
+            headers: self.headers,
+            widths: self.widths,
+            alignments: self.alignments,
+            rows: self.rows,
+            selected: self.selected,
+            scroll_offset: self.scroll_offset,
+            focused_column: self.focused_column,
+            sort_column: self.sort_column,
+            sort_direction: self.sort_direction,
+            on_sort: self.on_sort,
+            on_event: self.on_event,
+            on_focus: self.on_focus,
+            on_blur: self.on_blur,
+            on_render: self.on_render,
+        }
+    }
+}
@@ -12,6 +12,9 @@ pub struct TableTreeBuilder<Msg> {
     pub(crate) scroll_offset: usize,
     pub(crate) column_widths: Vec<Constraint>,
     pub(crate) column_headers: Vec<String>,
+    pub(crate) focused_column: usize,
+    pub(crate) column_offset: usize,
+    pub(crate) resizing_columns: bool,
     pub(crate) on_select: Option<fn(String) -> Msg>,
     pub(crate) on_event: Option<fn(TreeEvent) -> Msg>,
     pub(crate) on_focus: Option<Msg>,
@@ -54,6 +57,9 @@ impl<Msg> TableTreeBuilder<Msg> {
             scroll_offset: self.scroll_offset,
             column_widths: self.column_widths,
             column_headers: self.column_headers,
+            focused_column: self.focused_column,
+            column_offset: self.column_offset,
+            resizing_columns: self.resizing_columns,
             on_select: self.on_select,
             on_event: self.on_event,
             on_focus: self.on_focus,
@@ -0,0 +1,41 @@
+use crate::tui::Element;
+use crate::tui::element::FocusId;
+
+/// Builder for tab strip elements
+pub struct TabsBuilder<Msg> {
+    pub(crate) id: FocusId,
+    pub(crate) labels: Vec<String>,
+    pub(crate) selected: usize,
+    pub(crate) on_select: Option<fn(usize) -> Msg>,
+    pub(crate) on_focus: Option<Msg>,
+    pub(crate) on_blur: Option<Msg>,
+}
+
+impl<Msg> TabsBuilder<Msg> {
+    /// Set callback for tab selection (receives the 0-indexed tab)
+    pub fn on_select(mut self, msg: fn(usize) -> Msg) -> Self {
+        self.on_select = Some(msg);
+        self
+    }
+
+    pub fn on_focus(mut self, msg: Msg) -> Self {
+        self.on_focus = Some(msg);
+        self
+    }
+
+    pub fn on_blur(mut self, msg: Msg) -> Self {
+        self.on_blur = Some(msg);
+        self
+    }
+
+    pub fn build(self) -> Element<Msg> {
+        Element::Tabs {
+            id: self.id,
+            labels: self.labels,
+            selected: self.selected,
+            on_select: self.on_select,
+            on_focus: self.on_focus,
+            on_blur: self.on_blur,
+        }
+    }
+}
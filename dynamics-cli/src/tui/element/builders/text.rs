@@ -0,0 +1,32 @@
+use ratatui::style::Style;
+use crate::tui::Element;
+use crate::tui::element::WrapMode;
+
+/// Builder for plain text elements with a style and overflow handling
+pub struct TextBuilder<Msg> {
+    pub(crate) content: String,
+    pub(crate) style: Option<Style>,
+    pub(crate) wrap: WrapMode,
+    pub(crate) _phantom: std::marker::PhantomData<Msg>,
+}
+
+impl<Msg> TextBuilder<Msg> {
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = Some(style);
+        self
+    }
+
+    /// Set how text wider than its area is handled (default: Truncate)
+    pub fn wrap(mut self, wrap: WrapMode) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
+    pub fn build(self) -> Element<Msg> {
+        Element::Text {
+            content: self.content,
+            style: self.style,
+            wrap: self.wrap,
+        }
+    }
+}
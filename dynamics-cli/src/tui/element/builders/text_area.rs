@@ -0,0 +1,55 @@
+use crate::tui::Element;
+use crate::tui::element::FocusId;
+use crate::tui::widgets::TextInputEvent;
+
+/// Builder for multi-line text area elements
+pub struct TextAreaBuilder<Msg> {
+    pub(crate) id: FocusId,
+    pub(crate) value: String,
+    pub(crate) cursor_row: usize,
+    pub(crate) cursor_col: usize,
+    pub(crate) scroll_offset: usize,
+    pub(crate) rows: u16,
+    pub(crate) placeholder: Option<String>,
+    pub(crate) on_event: Option<fn(TextInputEvent) -> Msg>,
+    pub(crate) on_focus: Option<Msg>,
+    pub(crate) on_blur: Option<Msg>,
+}
+
+impl<Msg> TextAreaBuilder<Msg> {
+    pub fn placeholder(mut self, text: impl Into<String>) -> Self {
+        self.placeholder = Some(text.into());
+        self
+    }
+
+    /// Set event callback (Enter arrives as `Changed`, Ctrl+S/Ctrl+Enter as `Submit`)
+    pub fn on_event(mut self, msg: fn(TextInputEvent) -> Msg) -> Self {
+        self.on_event = Some(msg);
+        self
+    }
+
+    pub fn on_focus(mut self, msg: Msg) -> Self {
+        self.on_focus = Some(msg);
+        self
+    }
+
+    pub fn on_blur(mut self, msg: Msg) -> Self {
+        self.on_blur = Some(msg);
+        self
+    }
+
+    pub fn build(self) -> Element<Msg> {
+        Element::TextArea {
+            id: self.id,
+            value: self.value,
+            cursor_row: self.cursor_row,
+            cursor_col: self.cursor_col,
+            scroll_offset: self.scroll_offset,
+            rows: self.rows,
+            placeholder: self.placeholder,
+            on_event: self.on_event,
+            on_focus: self.on_focus,
+            on_blur: self.on_blur,
+        }
+    }
+}
@@ -8,6 +8,7 @@ pub struct TextInputBuilder<Msg> {
     pub(crate) value: String,
     pub(crate) cursor_pos: usize,
     pub(crate) scroll_offset: usize,
+    pub(crate) selection: Option<(usize, usize)>,
     pub(crate) placeholder: Option<String>,
     pub(crate) max_length: Option<usize>,
     pub(crate) masked: bool,
@@ -68,6 +69,7 @@ impl<Msg> TextInputBuilder<Msg> {
             value: self.value,
             cursor_pos: self.cursor_pos,
             scroll_offset: self.scroll_offset,
+            selection: self.selection,
             placeholder: self.placeholder,
             max_length: self.max_length,
             masked: self.masked,
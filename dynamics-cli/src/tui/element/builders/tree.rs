@@ -5,7 +5,7 @@ use crate::tui::widgets::TreeEvent;
 /// Builder for tree elements
 pub struct TreeBuilder<Msg> {
     pub(crate) id: FocusId,
-    pub(crate) items: Vec<Element<Msg>>,
+    pub(crate) rows: crate::tui::widgets::TreeRows<Msg>,
     pub(crate) node_ids: Vec<String>,
     pub(crate) selected: Option<String>,
     pub(crate) scroll_offset: usize,
@@ -57,7 +57,7 @@ impl<Msg> TreeBuilder<Msg> {
     pub fn build(self) -> Element<Msg> {
         Element::Tree {
             id: self.id,
-            items: self.items,
+            rows: self.rows,
             node_ids: self.node_ids,
             selected: self.selected,
             scroll_offset: self.scroll_offset,
@@ -34,6 +34,31 @@ pub enum Alignment {
     BottomRight,
 }
 
+/// Animation used by indeterminate progress bars
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SpinnerStyle {
+    /// A block sliding across the bar track
+    #[default]
+    Bar,
+    /// A braille spinner in front of the label
+    Dots,
+}
+
+/// Rows an expanded date picker needs: month header, weekday names and six weeks
+pub const DATE_PICKER_CALENDAR_HEIGHT: u16 = 8;
+
+/// How a text element handles content wider than its area
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WrapMode {
+    /// Cut the text off at the edge
+    #[default]
+    Truncate,
+    /// Cut the text off and end it with `…`
+    Ellipsis,
+    /// Soft-wrap at word boundaries onto as many lines as needed
+    Wrap,
+}
+
 /// A layer in a stack of UI elements
 #[derive(Clone)]
 pub struct Layer<Msg> {
@@ -85,7 +110,7 @@ pub enum Element<Msg> {
     None,
 
     /// Static text
-    Text { content: String, style: Option<Style> },
+    Text { content: String, style: Option<Style>, wrap: WrapMode },
 
     /// Styled text with multiple spans
     StyledText {
@@ -145,9 +170,15 @@ pub enum Element<Msg> {
         on_select: Option<fn(usize) -> Msg>,
         on_activate: Option<fn(usize) -> Msg>,
         on_navigate: Option<fn(crossterm::event::KeyCode) -> Msg>,
+        on_event: Option<fn(crate::tui::widgets::ListEvent) -> Msg>,  // Filter events (and navigation without on_navigate)
         on_focus: Option<Msg>,
         on_blur: Option<Msg>,
         on_render: Option<fn(usize) -> Msg>,  // Called with actual viewport height from renderer
+        filterable: bool,                // `/` opens a filter query box
+        filter: Option<String>,          // Query while the box is open
+        filter_matches: Option<Vec<usize>>,  // Matching item indices (others are dimmed)
+        show_scrollbar: bool,
+        reorderable: bool,               // Alt+Up/Down moves the selected item
     },
 
     /// Single-line text input
@@ -156,6 +187,7 @@ pub enum Element<Msg> {
         value: String,
         cursor_pos: usize,
         scroll_offset: usize,
+        selection: Option<(usize, usize)>,
         placeholder: Option<String>,
         max_length: Option<usize>,
         masked: bool,
@@ -166,10 +198,24 @@ pub enum Element<Msg> {
         on_blur: Option<Msg>,
     },
 
+    /// Multi-line text input with wrapping (Enter inserts a newline)
+    TextArea {
+        id: FocusId,
+        value: String,
+        cursor_row: usize,
+        cursor_col: usize,
+        scroll_offset: usize,
+        rows: u16,
+        placeholder: Option<String>,
+        on_event: Option<fn(crate::tui::widgets::TextInputEvent) -> Msg>,
+        on_focus: Option<Msg>,
+        on_blur: Option<Msg>,
+    },
+
     /// Hierarchical tree with expand/collapse
     Tree {
         id: FocusId,
-        items: Vec<Element<Msg>>,       // Flattened nodes
+        rows: crate::tui::widgets::TreeRows<Msg>,  // Flattened nodes, built when drawn
         node_ids: Vec<String>,           // Parallel array of node IDs
         selected: Option<String>,        // Selected node ID (not index!)
         scroll_offset: usize,
@@ -191,6 +237,9 @@ pub enum Element<Msg> {
         scroll_offset: usize,
         column_widths: Vec<ratatui::layout::Constraint>,  // Column layout constraints
         column_headers: Vec<String>,     // Column header labels
+        focused_column: usize,
+        column_offset: usize,            // First visible column (horizontal scroll)
+        resizing_columns: bool,
         on_select: Option<fn(String) -> Msg>,     // ID-based callbacks
         on_event: Option<fn(crate::tui::widgets::TreeEvent) -> Msg>,  // Unified event pattern
         on_focus: Option<Msg>,
@@ -198,6 +247,25 @@ pub enum Element<Msg> {
         on_render: Option<fn(usize) -> Msg>,  // Called with actual viewport height from renderer
     },
 
+    /// Flat table with sortable columns (no hierarchy, see TableTree for that)
+    Table {
+        id: FocusId,
+        headers: Vec<String>,
+        widths: Vec<ratatui::layout::Constraint>,     // Column layout constraints
+        alignments: Vec<ratatui::layout::Alignment>,  // Per-column cell alignment
+        rows: Vec<Vec<String>>,
+        selected: Option<usize>,
+        scroll_offset: usize,
+        focused_column: usize,
+        sort_column: Option<usize>,
+        sort_direction: crate::tui::widgets::SortDirection,
+        on_sort: Option<fn(usize) -> Msg>,  // Header click or `s` on the focused column
+        on_event: Option<fn(crate::tui::widgets::TableEvent) -> Msg>,  // Unified event pattern
+        on_focus: Option<Msg>,
+        on_blur: Option<Msg>,
+        on_render: Option<fn(usize) -> Msg>,  // Called with actual viewport height from renderer
+    },
+
     /// Scrollable wrapper for any element
     Scrollable {
         id: FocusId,
@@ -206,6 +274,7 @@ pub enum Element<Msg> {
         content_height: Option<usize>,   // If None, auto-detect from Column
         horizontal_scroll_offset: usize,
         content_width: Option<usize>,    // If None, auto-detect
+        show_scrollbar: bool,            // Track and thumb on overflowing edges
         on_navigate: Option<fn(crossterm::event::KeyCode) -> Msg>,
         on_render: Option<fn(usize, usize, usize, usize) -> Msg>,  // (viewport_height, content_height, viewport_width, content_width)
         on_focus: Option<Msg>,
@@ -235,6 +304,7 @@ pub enum Element<Msg> {
         placeholder: Option<String>,        // Placeholder text when empty
         is_open: bool,                      // Dropdown open?
         filtered_options: Vec<String>,      // Filtered options (top 15)
+        match_indices: Vec<Vec<usize>>,     // Matched char positions per filtered option
         highlight: usize,                   // Highlighted index in dropdown
         on_input: Option<fn(crossterm::event::KeyCode) -> Msg>,  // Text input changes
         on_select: Option<fn(String) -> Msg>,  // Option selected from dropdown
@@ -272,6 +342,25 @@ pub enum Element<Msg> {
         on_blur: Option<Msg>,
     },
 
+    /// Date picker widget (inline date that expands into a month calendar)
+    DatePicker {
+        id: FocusId,
+        state: crate::tui::widgets::DatePickerState,        // Widget state (mode, selection, cursor)
+        placeholder: Option<String>,
+        on_event: Option<fn(crate::tui::widgets::DatePickerEvent) -> Msg>,  // Unified event handler
+        on_focus: Option<Msg>,
+        on_blur: Option<Msg>,
+    },
+
+    /// Number input with bounds; arrows step the value
+    NumberInput {
+        id: FocusId,
+        state: crate::tui::widgets::NumberInputState,       // Widget state (value, typed text, bounds)
+        on_event: Option<fn(crate::tui::widgets::NumberInputEvent) -> Msg>,  // Unified event handler
+        on_focus: Option<Msg>,
+        on_blur: Option<Msg>,
+    },
+
     /// Progress bar showing completion (non-interactive)
     ProgressBar {
         current: usize,
@@ -280,6 +369,18 @@ pub enum Element<Msg> {
         show_percentage: bool,
         show_count: bool,
         width: Option<u16>,
+        indeterminate: Option<usize>,  // Animation frame when the total is unknown
+        spinner_style: SpinnerStyle,
+    },
+
+    /// Horizontal tab strip (Left/Right or number keys switch tabs)
+    Tabs {
+        id: FocusId,
+        labels: Vec<String>,
+        selected: usize,
+        on_select: Option<fn(usize) -> Msg>,  // Receives the 0-indexed tab
+        on_focus: Option<Msg>,
+        on_blur: Option<Msg>,
     },
 }
 
@@ -289,6 +390,17 @@ impl<Msg> Element<Msg> {
         Element::Text {
             content: content.into(),
             style: None,
+            wrap: WrapMode::Truncate,
+        }
+    }
+
+    /// Create a text element with a style or wrap mode
+    pub fn text_builder(content: impl Into<String>) -> TextBuilder<Msg> {
+        TextBuilder {
+            content: content.into(),
+            style: None,
+            wrap: WrapMode::default(),
+            _phantom: std::marker::PhantomData,
         }
     }
 
@@ -301,6 +413,18 @@ impl<Msg> Element<Msg> {
         }
     }
 
+    /// Create a tab strip element
+    pub fn tabs(id: impl Into<FocusId>, labels: Vec<String>, selected: usize) -> TabsBuilder<Msg> {
+        TabsBuilder {
+            id: id.into(),
+            labels,
+            selected,
+            on_select: None,
+            on_focus: None,
+            on_blur: None,
+        }
+    }
+
     /// Create a progress bar element
     pub fn progress_bar(current: usize, total: usize) -> ProgressBarBuilder<Msg> {
         ProgressBarBuilder {
@@ -310,6 +434,25 @@ impl<Msg> Element<Msg> {
             show_percentage: true,
             show_count: true,
             width: None,
+            indeterminate: None,
+            spinner_style: SpinnerStyle::default(),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+
+    /// Create a progress bar for work with an unknown total
+    ///
+    /// `frame` drives the animation; advance it from a timer subscription.
+    pub fn progress_bar_indeterminate(frame: usize) -> ProgressBarBuilder<Msg> {
+        ProgressBarBuilder {
+            current: 0,
+            total: 0,
+            label: None,
+            show_percentage: false,
+            show_count: false,
+            width: None,
+            indeterminate: Some(frame),
+            spinner_style: SpinnerStyle::default(),
             _phantom: std::marker::PhantomData,
         }
     }
@@ -447,10 +590,127 @@ impl<Msg> Element<Msg> {
         ])
     }
 
+    /// Create a confirmation modal that only confirms once `required_phrase` is typed
+    ///
+    /// For destructive actions: the Confirm button stays disabled (no `on_confirm`,
+    /// dimmed) until the input matches the phrase exactly.
+    pub fn modal_confirm_typed(
+        background: Element<Msg>,
+        title: impl Into<String>,
+        message: impl Into<String>,
+        required_phrase: &str,
+        input_id: impl Into<FocusId>,
+        input_value: &str,
+        input_state: &crate::tui::widgets::TextInputState,
+        on_input: fn(crate::tui::widgets::TextInputEvent) -> Msg,
+        cancel_id: impl Into<FocusId>,
+        on_cancel: Msg,
+        confirm_id: impl Into<FocusId>,
+        on_confirm: Msg,
+    ) -> Self {
+        use crate::tui::element::RowBuilder;
+        use ratatui::style::{Modifier, Style};
+
+        let confirm_button = if input_value == required_phrase {
+            Element::button(confirm_id, "Confirm").on_press(on_confirm).build()
+        } else {
+            Element::button(confirm_id, "Confirm")
+                .style(Style::default().add_modifier(Modifier::DIM))
+                .build()
+        };
+
+        let button_row = RowBuilder::new()
+            .add(
+                Element::button(cancel_id, "Cancel").on_press(on_cancel).build(),
+                LayoutConstraint::Fill(1),
+            )
+            .add(
+                Element::text("  "),
+                LayoutConstraint::Length(2),
+            )
+            .add(confirm_button, LayoutConstraint::Fill(1))
+            .spacing(0)
+            .build();
+
+        let input = Element::text_input(input_id, input_value, input_state)
+            .placeholder(required_phrase)
+            .on_event(on_input)
+            .build();
+
+        let modal_content = ColumnBuilder::new()
+            .add(
+                Element::text(title.into()),
+                LayoutConstraint::Length(1),
+            )
+            .add(
+                Element::text(""),
+                LayoutConstraint::Length(1),
+            )
+            .add(
+                Element::text(message.into()),
+                LayoutConstraint::Length(1),
+            )
+            .add(
+                Element::text(""),
+                LayoutConstraint::Length(1),
+            )
+            .add(
+                Element::text(format!("Type \"{}\" to confirm:", required_phrase)),
+                LayoutConstraint::Length(1),
+            )
+            .add(
+                Element::panel(input).build(),
+                LayoutConstraint::Length(3),
+            )
+            .add(
+                button_row,
+                LayoutConstraint::Length(3),
+            )
+            .spacing(0)
+            .build();
+
+        Element::stack(vec![
+            Layer::new(background),
+            Layer::new(
+                Element::panel(
+                    Element::container(modal_content)
+                        .padding(1)
+                        .build()
+                )
+                .title("Confirmation")
+                .width(60)
+                .height(15)
+                .build()
+            ).center(),
+        ])
+    }
+
+    /// Text shown by this element and its children, joined by spaces
+    ///
+    /// Used where a rendered item needs a plain string, e.g. copying a list row.
+    pub fn plain_text(&self) -> String {
+        let parts: Vec<String> = match self {
+            Element::Text { content, .. } => vec![content.clone()],
+            Element::StyledText { line, .. } => vec![line.to_string()],
+            Element::Column { items, .. } | Element::Row { items, .. } => {
+                items.iter().map(|(_, item)| item.plain_text()).collect()
+            }
+            Element::Container { child, .. } | Element::Panel { child, .. } => vec![child.plain_text()],
+            _ => Vec::new(),
+        };
+        parts
+            .iter()
+            .map(|part| part.trim())
+            .filter(|part| !part.is_empty())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
     /// Get the default layout constraint for this element type
     pub fn default_constraint(&self) -> LayoutConstraint {
         match self {
             Element::None => LayoutConstraint::Length(0),
+            Element::Text { wrap: WrapMode::Wrap, .. } => LayoutConstraint::Min(1),
             Element::Text { .. } => LayoutConstraint::Length(1),
             Element::StyledText { .. } => LayoutConstraint::Length(1),
             Element::Button { .. } => LayoutConstraint::Length(3),
@@ -473,14 +733,25 @@ impl<Msg> Element<Msg> {
             Element::Stack { .. } => LayoutConstraint::Fill(1),
             Element::List { .. } => LayoutConstraint::Fill(1),
             Element::TextInput { .. } => LayoutConstraint::Length(1),
+            Element::TextArea { rows, .. } => LayoutConstraint::Length(*rows),
             Element::Tree { .. } => LayoutConstraint::Fill(1),
             Element::TableTree { .. } => LayoutConstraint::Fill(1),
+            Element::Table { .. } => LayoutConstraint::Fill(1),
             Element::Scrollable { .. } => LayoutConstraint::Fill(1),
             Element::Select { .. } => LayoutConstraint::Length(1),  // Borderless like TextInput
             Element::Autocomplete { .. } => LayoutConstraint::Length(1),  // Borderless like TextInput
             Element::FileBrowser { .. } => LayoutConstraint::Fill(1),  // Fill available space like List
             Element::ColorPicker { .. } => LayoutConstraint::Length(9),  // 3 sliders + hex + labels
+            Element::DatePicker { state, .. } => {
+                if state.is_open() {
+                    LayoutConstraint::Length(DATE_PICKER_CALENDAR_HEIGHT)
+                } else {
+                    LayoutConstraint::Length(1)
+                }
+            }
+            Element::NumberInput { .. } => LayoutConstraint::Length(1),
             Element::ProgressBar { .. } => LayoutConstraint::Length(1),  // Single line
+            Element::Tabs { .. } => LayoutConstraint::Length(1),
         }
     }
 
@@ -500,6 +771,61 @@ impl<Msg> Element<Msg> {
         }
     }
 
+    /// Create a date picker element
+    pub fn date_picker(
+        id: impl Into<FocusId>,
+        state: &crate::tui::widgets::DatePickerState,
+    ) -> DatePickerBuilder<Msg> {
+        DatePickerBuilder {
+            id: id.into(),
+            state: state.clone(),
+            placeholder: None,
+            on_event: None,
+            on_focus: None,
+            on_blur: None,
+        }
+    }
+
+    /// Create a sortable table from column definitions and rows of cell text
+    pub fn table(
+        id: impl Into<FocusId>,
+        columns: &[crate::tui::widgets::TableColumn],
+        rows: &[Vec<String>],
+        state: &crate::tui::widgets::TableState,
+    ) -> TableBuilder<Msg> {
+        TableBuilder {
+            id: id.into(),
+            headers: columns.iter().map(|column| column.header.clone()).collect(),
+            widths: columns.iter().map(|column| column.width).collect(),
+            alignments: columns.iter().map(|column| column.alignment).collect(),
+            rows: rows.to_vec(),
+            selected: state.selected(),
+            scroll_offset: state.scroll_offset(),
+            focused_column: state.focused_column(),
+            sort_column: state.sort_column(),
+            sort_direction: state.sort_direction(),
+            on_sort: None,
+            on_event: None,
+            on_focus: None,
+            on_blur: None,
+            on_render: None,
+        }
+    }
+
+    /// Create a number input element
+    pub fn number_input(
+        id: impl Into<FocusId>,
+        state: &crate::tui::widgets::NumberInputState,
+    ) -> NumberInputBuilder<Msg> {
+        NumberInputBuilder {
+            id: id.into(),
+            state: state.clone(),
+            on_event: None,
+            on_focus: None,
+            on_blur: None,
+        }
+    }
+
     /// Create a text input element
     pub fn text_input(
         id: impl Into<FocusId>,
@@ -511,6 +837,7 @@ impl<Msg> Element<Msg> {
             value: value.to_string(),
             cursor_pos: state.cursor_pos(),
             scroll_offset: state.scroll_offset(),
+            selection: state.selection(),
             placeholder: None,
             max_length: None,
             masked: false,
@@ -522,6 +849,26 @@ impl<Msg> Element<Msg> {
         }
     }
 
+    /// Create a multi-line text area element
+    pub fn text_area(
+        id: impl Into<FocusId>,
+        value: &str,
+        state: &crate::tui::widgets::TextAreaState,
+    ) -> TextAreaBuilder<Msg> {
+        TextAreaBuilder {
+            id: id.into(),
+            value: value.to_string(),
+            cursor_row: state.cursor_row(),
+            cursor_col: state.cursor_col(),
+            scroll_offset: state.scroll_offset(),
+            rows: state.visible_rows() as u16,
+            placeholder: None,
+            on_event: None,
+            on_focus: None,
+            on_blur: None,
+        }
+    }
+
     /// Create a list element from items
     pub fn list<T>(
         id: impl Into<FocusId>,
@@ -549,9 +896,15 @@ impl<Msg> Element<Msg> {
             on_select: None,
             on_activate: None,
             on_navigate: None,
+            on_event: None,
             on_focus: None,
             on_blur: None,
             on_render: None,
+            filterable: false,
+            filter: state.filter().map(String::from),
+            filter_matches: state.filter_matches().map(<[_]>::to_vec),
+            show_scrollbar: true,
+            reorderable: false,
         }
     }
 
@@ -563,23 +916,17 @@ impl<Msg> Element<Msg> {
         theme: &crate::tui::Theme,
     ) -> TreeBuilder<Msg>
     where
-        T: crate::tui::widgets::TreeItem<Msg = Msg>,
+        T: crate::tui::widgets::TreeItem<Msg = Msg> + 'static,
     {
         // Force cache invalidation to rebuild visible_order with current items
         state.invalidate_cache();
 
-        // Flatten tree based on expansion state
-        let flattened = crate::tui::widgets::tree::flatten_tree(root_items, state);
-
-        // Extract elements and node IDs (parallel arrays) by consuming the vec
-        let (elements, node_ids): (Vec<Element<Msg>>, Vec<String>) = flattened
-            .into_iter()
-            .map(|node| (node.element, node.id))
-            .unzip();
+        // Flatten tree based on expansion state; rows are only built once scrolled into view
+        let (node_ids, rows) = crate::tui::widgets::tree::flatten_tree(root_items, state);
 
         TreeBuilder {
             id: id.into(),
-            items: elements,
+            rows,
             node_ids,
             selected: state.selected().map(String::from),
             scroll_offset: state.scroll_offset(),
@@ -593,6 +940,24 @@ impl<Msg> Element<Msg> {
         }
     }
 
+    /// Create a two-column tree comparing two JSON values side by side
+    ///
+    /// Added, removed and changed keys are colored by status; use
+    /// `json_diff::expand_changes` once to open just the branches that differ.
+    pub fn json_diff(
+        id: impl Into<FocusId>,
+        left: &serde_json::Value,
+        right: &serde_json::Value,
+        state: &mut crate::tui::widgets::TreeState,
+    ) -> TreeBuilder<Msg>
+    where
+        Msg: Clone + 'static,
+    {
+        let nodes = crate::tui::widgets::json_diff::diff_json::<Msg>(left, right);
+        let theme = &crate::global_runtime_config().theme;
+        Self::tree(id, &nodes, state, theme)
+    }
+
     /// Create a table-style tree element from TableTreeItem-implementing items
     pub fn table_tree<T>(
         id: impl Into<FocusId>,
@@ -611,8 +976,8 @@ impl<Msg> Element<Msg> {
         // Extract node IDs (parallel array)
         let node_ids: Vec<String> = flattened.iter().map(|node| node.id.clone()).collect();
 
-        // Get column configuration from the trait
-        let column_widths = T::column_widths();
+        // Get column configuration from the trait, keeping widths the user resized
+        let column_widths = state.column_widths().map(<[_]>::to_vec).unwrap_or_else(T::column_widths);
         let column_headers = T::column_headers();
 
         TableTreeBuilder {
@@ -623,6 +988,9 @@ impl<Msg> Element<Msg> {
             scroll_offset: state.scroll_offset(),
             column_widths,
             column_headers,
+            focused_column: state.focused_column(),
+            column_offset: state.column_offset(),
+            resizing_columns: state.is_resizing_columns(),
             on_select: None,
             on_event: None,
             on_focus: None,
@@ -644,6 +1012,7 @@ impl<Msg> Element<Msg> {
             content_height: None,
             horizontal_scroll_offset: state.horizontal_scroll_offset(),
             content_width: None,
+            show_scrollbar: true,
             on_navigate: None,
             on_render: None,
             on_focus: None,
@@ -722,6 +1091,7 @@ impl<Msg> Element<Msg> {
             placeholder: None,
             is_open: state.is_open(),
             filtered_options: state.filtered_options(),
+            match_indices: state.scored_matches().iter().map(|m| m.indices.clone()).collect(),
             highlight: state.highlighted(),
             on_input: None,
             on_select: None,
@@ -737,4 +1107,57 @@ impl<Msg> Default for Element<Msg> {
     fn default() -> Self {
         Element::None
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tui::widgets::{TextInputEvent, TextInputState};
+
+    #[derive(Clone, Debug, PartialEq)]
+    enum TestMsg {
+        Input,
+        Cancel,
+        Confirm,
+    }
+
+    fn typed_modal(input: &str) -> Element<TestMsg> {
+        Element::modal_confirm_typed(
+            Element::None,
+            "Delete environment",
+            "This cannot be undone.",
+            "production",
+            "confirm-input",
+            input,
+            &TextInputState::default(),
+            |_: TextInputEvent| TestMsg::Input,
+            "confirm-cancel",
+            TestMsg::Cancel,
+            "confirm-ok",
+            TestMsg::Confirm,
+        )
+    }
+
+    /// The `on_press` of the button with `id`, searching the whole tree
+    fn button_press(element: &Element<TestMsg>, id: &str) -> Option<Option<TestMsg>> {
+        match element {
+            Element::Button { id: button_id, on_press, .. } if button_id.0 == id => Some(on_press.clone()),
+            Element::Column { items, .. } | Element::Row { items, .. } => {
+                items.iter().find_map(|(_, item)| button_press(item, id))
+            }
+            Element::Container { child, .. } | Element::Panel { child, .. } => button_press(child, id),
+            Element::Stack { layers } => layers.iter().find_map(|layer| button_press(&layer.element, id)),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_modal_confirm_typed_enables_confirm_only_on_exact_phrase() {
+        for input in ["", "prod", "Production", "production ", "productions"] {
+            assert_eq!(button_press(&typed_modal(input), "confirm-ok"), Some(None), "input {:?}", input);
+        }
+
+        assert_eq!(button_press(&typed_modal("production"), "confirm-ok"), Some(Some(TestMsg::Confirm)));
+        assert_eq!(button_press(&typed_modal(""), "confirm-cancel"), Some(Some(TestMsg::Cancel)));
+    }
 }
\ No newline at end of file
@@ -0,0 +1,100 @@
+//! Panic containment for app code
+//!
+//! The multi-app runtime runs app dispatch (key/mouse handling, timers, async
+//! completions, pub/sub) through [`catch`] or [`catch_async`], so a panicking
+//! `update` tears down only that app instead of the whole TUI. The panic hook
+//! writes every panic with its backtrace to the log, and restores the terminal
+//! before the default hook prints for panics nothing is going to catch.
+
+use crossterm::{
+    event::DisableMouseCapture,
+    execute,
+    terminal::{disable_raw_mode, LeaveAlternateScreen},
+};
+use std::any::Any;
+use std::backtrace::Backtrace;
+use std::cell::Cell;
+use std::future::Future;
+use std::panic::{self, AssertUnwindSafe};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+thread_local! {
+    /// How many `catch` calls are active on this thread
+    static BOUNDARY_DEPTH: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Install the panic hook; call once before the terminal enters raw mode
+pub fn install_panic_hook() {
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        log::error!("Panic: {}\n{}", info, Backtrace::force_capture());
+
+        if BOUNDARY_DEPTH.with(Cell::get) == 0 {
+            restore_terminal();
+            default_hook(info);
+        }
+    }));
+}
+
+/// Leave raw mode and the alternate screen so a crash message is readable
+pub fn restore_terminal() {
+    let _ = disable_raw_mode();
+    let _ = execute!(std::io::stdout(), LeaveAlternateScreen, DisableMouseCapture, crossterm::cursor::Show);
+}
+
+/// Run `f`, turning a panic into its message
+pub fn catch<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    BOUNDARY_DEPTH.with(|depth| depth.set(depth.get() + 1));
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    BOUNDARY_DEPTH.with(|depth| depth.set(depth.get() - 1));
+    result.map_err(|payload| panic_message(payload.as_ref()))
+}
+
+/// Await `future`, turning a panic in any of its polls into its message
+pub fn catch_async<F: Future>(future: F) -> impl Future<Output = Result<F::Output, String>> {
+    CatchPanic { future: Box::pin(future) }
+}
+
+struct CatchPanic<F> {
+    future: Pin<Box<F>>,
+}
+
+impl<F: Future> Future for CatchPanic<F> {
+    type Output = Result<F::Output, String>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        match catch(|| self.future.as_mut().poll(cx)) {
+            Ok(Poll::Ready(output)) => Poll::Ready(Ok(output)),
+            Ok(Poll::Pending) => Poll::Pending,
+            Err(message) => Poll::Ready(Err(message)),
+        }
+    }
+}
+
+/// Text of a panic payload (`panic!` with a literal or a formatted message)
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_panics_become_messages() {
+        assert_eq!(catch(|| 1 + 1), Ok(2));
+        assert_eq!(catch(|| -> () { panic!("boom") }), Err("boom".to_string()));
+        assert_eq!(catch(|| -> () { panic!("bad index {}", 3) }), Err("bad index 3".to_string()));
+
+        let result = futures::executor::block_on(catch_async(async { panic!("async boom") }));
+        assert_eq!(result, Err::<(), _>("async boom".to_string()));
+        assert_eq!(BOUNDARY_DEPTH.with(Cell::get), 0);
+    }
+}
@@ -11,7 +11,10 @@ pub mod widgets;
 pub mod resource;
 pub mod modals;
 pub mod color;
+pub mod clipboard;
 pub mod lifecycle;
+pub mod command_palette;
+pub mod error_boundary;
 
 #[macro_use]
 pub mod macros;
@@ -23,13 +26,14 @@ mod test_validate;
 mod test_resource_handlers;
 
 pub use command::{Command, AppId};
-pub use element::{Element, LayoutConstraint, Layer, Alignment, FocusId};
+pub use element::{Element, LayoutConstraint, Layer, Alignment, FocusId, SpinnerStyle, WrapMode};
 pub use subscription::{Subscription, KeyBinding};
 pub use app::{App, AppState};
 pub use renderer::{Renderer, InteractionRegistry, RenderLayer, LayeredView};
 pub use runtime::{Runtime, AppRuntime};
 pub use multi_runtime::MultiAppRuntime;
-pub use state::{Theme, ThemeVariant, FocusMode, RuntimeConfig, ModalState};
+pub use state::{Theme, ThemeVariant, FocusMode, RuntimeConfig, ModalState, ToastLevel};
 pub use widgets::{ListItem, ListState, TextInputState};
 pub use resource::Resource;
-pub use lifecycle::{AppLifecycle, QuitPolicy, SuspendPolicy, KillReason};
\ No newline at end of file
+pub use lifecycle::{AppLifecycle, QuitPolicy, SuspendPolicy, KillReason};
+pub use command_palette::PaletteAction;
\ No newline at end of file
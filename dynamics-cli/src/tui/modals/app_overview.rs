@@ -77,6 +77,8 @@ impl<Msg: Clone> AppOverviewModal<Msg> {
             AppId::SelectQuestionnaire => "Select Questionnaire",
             AppId::CopyQuestionnaire => "Copy Questionnaire",
             AppId::PushQuestionnaire => "Push Questionnaire",
+            AppId::QueryRunner => "Query Runner",
+            AppId::SavedQueries => "Saved Queries",
         }
     }
 
@@ -0,0 +1,116 @@
+use crate::tui::Element;
+use crate::tui::command_palette::PaletteState;
+use crate::tui::element::{LayoutConstraint, ColumnBuilder};
+use crossterm::event::KeyCode;
+use ratatui::prelude::*;
+use ratatui::text::{Line, Span};
+
+/// Most matches shown at once; the window follows the selection
+const VISIBLE_MATCHES: usize = 10;
+
+/// Builder for the command palette: a query input above the matching actions
+///
+/// # Example
+/// ```rust
+/// let modal = CommandPaletteModal::new(&palette_state)
+///     .on_input(Msg::PaletteInput)
+///     .on_submit(Msg::PaletteRun)
+///     .build();
+/// ```
+pub struct CommandPaletteModal<'a, Msg> {
+    state: &'a PaletteState,
+    on_input: Option<fn(KeyCode) -> Msg>,
+    on_submit: Option<Msg>,
+    width: u16,
+}
+
+impl<'a, Msg: Clone> CommandPaletteModal<'a, Msg> {
+    /// Create a new command palette modal for the given state
+    pub fn new(state: &'a PaletteState) -> Self {
+        Self {
+            state,
+            on_input: None,
+            on_submit: None,
+            width: 70,
+        }
+    }
+
+    /// Set the message for keys typed into the query input
+    pub fn on_input(mut self, msg: fn(KeyCode) -> Msg) -> Self {
+        self.on_input = Some(msg);
+        self
+    }
+
+    /// Set the message sent when Enter runs the selected action
+    pub fn on_submit(mut self, msg: Msg) -> Self {
+        self.on_submit = Some(msg);
+        self
+    }
+
+    /// Set modal width (default: 70)
+    pub fn width(mut self, width: u16) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Build the modal Element
+    pub fn build(self) -> Element<Msg> {
+        let theme = &crate::global_runtime_config().theme;
+
+        let mut input = Element::text_input("command-palette-input", self.state.query(), self.state.input_state())
+            .placeholder("Type to search actions...");
+        if let Some(on_input) = self.on_input {
+            input = input.on_change(on_input);
+        }
+        if let Some(on_submit) = self.on_submit {
+            input = input.on_submit(on_submit);
+        }
+
+        let matches: Vec<_> = self.state.matches().collect();
+        let selected = self.state.selected();
+        let start = (selected + 1).saturating_sub(VISIBLE_MATCHES);
+
+        let mut rows = Vec::new();
+        for (idx, entry) in matches.iter().enumerate().skip(start).take(VISIBLE_MATCHES) {
+            let is_selected = idx == selected;
+            let label_style = if is_selected {
+                Style::default().fg(theme.accent_primary).bold()
+            } else {
+                Style::default().fg(theme.text_primary)
+            };
+            let mut spans = vec![
+                Span::styled(if is_selected { "▶ " } else { "  " }, Style::default().fg(theme.accent_primary)),
+                Span::styled(entry.label.clone(), label_style),
+            ];
+            if !entry.detail.is_empty() {
+                spans.push(Span::styled(format!("  {}", entry.detail), Style::default().fg(theme.text_tertiary)));
+            }
+
+            let mut row = Element::styled_text(Line::from(spans));
+            if is_selected {
+                row = row.background(Style::default().bg(theme.bg_surface));
+            }
+            rows.push(row.build());
+        }
+        if rows.is_empty() {
+            rows.push(Element::styled_text(Line::from(Span::styled(
+                "  No matching actions",
+                Style::default().fg(theme.text_tertiary),
+            ))).build());
+        }
+
+        let list_height = rows.len() as u16;
+        let mut column = ColumnBuilder::new()
+            .add(input.build(), LayoutConstraint::Length(1))
+            .add(Element::text(""), LayoutConstraint::Length(1));
+        for row in rows {
+            column = column.add(row, LayoutConstraint::Length(1));
+        }
+
+        Element::panel(column.spacing(0).build())
+            .title(format!("Command Palette ({})", matches.len()))
+            .width(self.width)
+            .height(list_height + 4) // input + blank + borders
+            .build()
+    }
+}
@@ -73,6 +73,7 @@ pub struct ExamplesModal<Msg> {
     on_label_input_event: Option<fn(crate::tui::widgets::TextInputEvent) -> Msg>,
     on_list_navigate: Option<fn(crossterm::event::KeyCode) -> Msg>,
     on_list_select: Option<fn(usize) -> Msg>,
+    on_list_event: Option<fn(crate::tui::widgets::ListEvent) -> Msg>,
     on_add: Option<Msg>,
     on_delete: Option<Msg>,
     on_close: Option<Msg>,
@@ -94,6 +95,7 @@ impl<Msg: Clone> ExamplesModal<Msg> {
             on_label_input_event: None,
             on_list_navigate: None,
             on_list_select: None,
+            on_list_event: None,
             on_add: None,
             on_delete: None,
             on_close: None,
@@ -162,6 +164,12 @@ impl<Msg: Clone> ExamplesModal<Msg> {
         self
     }
 
+    /// Set list event handler, which makes the pairs reorderable with Alt+Up/Down
+    pub fn on_list_event(mut self, handler: fn(crate::tui::widgets::ListEvent) -> Msg) -> Self {
+        self.on_list_event = Some(handler);
+        self
+    }
+
     /// Set the message sent when Add is clicked
     pub fn on_add(mut self, msg: Msg) -> Self {
         self.on_add = Some(msg);
@@ -236,15 +244,18 @@ impl<Msg: Clone> ExamplesModal<Msg> {
             .expect("ExamplesModal requires on_list_navigate");
         let select_handler = self.on_list_select
             .expect("ExamplesModal requires on_list_select");
-        let pairs_list = Element::list(
+        let mut pairs_list = Element::list(
             FocusId::new("examples-list"),
             &self.pairs,
             &self.list_state,
             theme,
         )
         .on_select(select_handler)
-        .on_navigate(list_handler)
-        .build();
+        .on_navigate(list_handler);
+        if let Some(event_handler) = self.on_list_event {
+            pairs_list = pairs_list.on_event(event_handler).reorderable(true);
+        }
+        let pairs_list = pairs_list.build();
 
         // Input panels
         let source_panel = Element::panel(source_input)
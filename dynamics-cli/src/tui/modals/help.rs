@@ -1,4 +1,4 @@
-use crate::tui::{Element, Theme, FocusId};
+use crate::tui::{Element, KeyBinding};
 use crate::tui::element::{LayoutConstraint, ColumnBuilder};
 use crate::tui::widgets::ScrollableState;
 use crossterm::event::KeyCode;
@@ -6,105 +6,95 @@ use ratatui::prelude::*;
 use ratatui::text::{Line, Span};
 use std::collections::HashMap;
 
-/// Helper to format a KeyCode for display (e.g., Char('i') → "i", F(1) → "F1")
-fn format_key(key: &KeyCode) -> String {
-    match key {
-        KeyCode::Char(' ') => "Space".to_string(),
-        KeyCode::Char(c) => c.to_string(),
-        KeyCode::F(n) => format!("F{}", n),
-        KeyCode::Enter => "Enter".to_string(),
-        KeyCode::Tab => "Tab".to_string(),
-        KeyCode::Backspace => "Backspace".to_string(),
-        KeyCode::Esc => "Esc".to_string(),
-        KeyCode::Up => "↑".to_string(),
-        KeyCode::Down => "↓".to_string(),
-        KeyCode::Left => "←".to_string(),
-        KeyCode::Right => "→".to_string(),
-        KeyCode::Home => "Home".to_string(),
-        KeyCode::End => "End".to_string(),
-        KeyCode::PageUp => "PgUp".to_string(),
-        KeyCode::PageDown => "PgDn".to_string(),
-        KeyCode::Delete => "Del".to_string(),
-        KeyCode::Insert => "Ins".to_string(),
-        _ => format!("{:?}", key),
-    }
-}
-
 /// Group keybindings by description and format as aliases (e.g., "n/N")
-fn group_and_format_bindings(bindings: &[(KeyCode, String)]) -> Vec<(String, String)> {
-    let mut grouped: HashMap<String, Vec<String>> = HashMap::new();
+fn group_and_format_bindings(bindings: &[(KeyBinding, String)]) -> Vec<(String, String)> {
+    let mut grouped: HashMap<&str, Vec<String>> = HashMap::new();
 
     for (key, desc) in bindings {
-        grouped.entry(desc.clone())
+        grouped.entry(desc.as_str())
             .or_default()
-            .push(format_key(key));
+            .push(key.to_string());
     }
 
     let mut result: Vec<(String, String)> = grouped.into_iter()
         .map(|(desc, mut keys)| {
-            keys.sort();  // Consistent ordering
-            let key_str = keys.join("/");
-            (key_str, desc)
+            // Shorter keys first, then alphabetical for consistent ordering
+            keys.sort_by(|a, b| a.len().cmp(&b.len()).then_with(|| a.cmp(b)));
+            keys.dedup();
+            (keys.join("/"), desc.to_string())
         })
         .collect();
 
     // Sort by key string for consistent display
-    result.sort_by(|a, b| a.0.cmp(&b.0));
+    result.sort();
     result
 }
 
+/// Which kind of section a group of bindings belongs to (decides its colors)
+#[derive(Clone, Copy)]
+enum SectionKind {
+    Global,
+    CurrentApp,
+    OtherApp,
+}
+
+struct Section {
+    title: String,
+    kind: SectionKind,
+    bindings: Vec<(String, String)>,
+}
+
 /// Builder for help menu modal with scrollable keybinding display
 ///
+/// Bindings with the same description are shown as aliases, and empty
+/// sections are left out.
+///
 /// # Example
 /// ```rust
 /// let modal = HelpModal::new()
-///     .global_bindings(vec![(KeyCode::F(1), "Toggle help".to_string())])
-///     .current_app("Migration Environment", vec![(KeyCode::Char('n'), "New migration".to_string())])
-///     .add_app("App Launcher", vec![(KeyCode::Enter, "Launch app".to_string())])
-///     .scroll_state(&mut scroll_state)
-///     .build(theme);
+///     .global_bindings(config.keybinds_for("global"))
+///     .current_app("Migration Environment", runtime.get_key_bindings())
+///     .add_app("App Launcher", other_runtime.get_key_bindings())
+///     .scroll_state(&scroll_state)
+///     .on_navigate(Msg::HelpScroll)
+///     .build();
 /// ```
-pub struct HelpModal<'a> {
-    global_bindings: Vec<(KeyCode, String)>,
-    current_app_title: Option<String>,
-    current_app_bindings: Vec<(KeyCode, String)>,
-    other_apps: Vec<(String, Vec<(KeyCode, String)>)>,
+pub struct HelpModal<'a, Msg> {
+    sections: Vec<Section>,
     scroll_state: Option<&'a ScrollableState>,
-    width: Option<u16>,
-    height: Option<u16>,
+    on_navigate: Option<fn(KeyCode) -> Msg>,
 }
 
-impl<'a> HelpModal<'a> {
+impl<'a, Msg: Clone> HelpModal<'a, Msg> {
     /// Create a new help modal builder
     pub fn new() -> Self {
         Self {
-            global_bindings: Vec::new(),
-            current_app_title: None,
-            current_app_bindings: Vec::new(),
-            other_apps: Vec::new(),
+            sections: Vec::new(),
             scroll_state: None,
-            width: Some(60),
-            height: Some(20),
+            on_navigate: None,
         }
     }
 
-    /// Set global keybindings
-    pub fn global_bindings(mut self, bindings: Vec<(KeyCode, String)>) -> Self {
-        self.global_bindings = bindings;
+    fn section(mut self, title: String, kind: SectionKind, bindings: &[(KeyBinding, String)]) -> Self {
+        if !bindings.is_empty() {
+            self.sections.push(Section { title, kind, bindings: group_and_format_bindings(bindings) });
+        }
         self
     }
 
+    /// Set global keybindings
+    pub fn global_bindings(self, bindings: Vec<(KeyBinding, String)>) -> Self {
+        self.section("Global".to_string(), SectionKind::Global, &bindings)
+    }
+
     /// Set current app keybindings
-    pub fn current_app(mut self, title: impl Into<String>, bindings: Vec<(KeyCode, String)>) -> Self {
-        self.current_app_title = Some(title.into());
-        self.current_app_bindings = bindings;
-        self
+    pub fn current_app(self, title: impl Into<String>, bindings: Vec<(KeyBinding, String)>) -> Self {
+        self.section(title.into(), SectionKind::CurrentApp, &bindings)
     }
 
     /// Add another app's keybindings
-    pub fn add_app(mut self, title: impl Into<String>, bindings: Vec<(KeyCode, String)>) -> Self {
-        self.other_apps.push((title.into(), bindings));
-        self
+    pub fn add_app(self, title: impl Into<String>, bindings: Vec<(KeyBinding, String)>) -> Self {
+        self.section(title.into(), SectionKind::OtherApp, &bindings)
     }
 
     /// Set the scroll state reference (required for scrolling)
@@ -113,21 +103,22 @@ impl<'a> HelpModal<'a> {
         self
     }
 
-    /// Set modal width (default: 60)
-    pub fn width(mut self, width: u16) -> Self {
-        self.width = Some(width);
+    /// Set callback for scroll keys
+    pub fn on_navigate(mut self, msg: fn(KeyCode) -> Msg) -> Self {
+        self.on_navigate = Some(msg);
         self
     }
 
-    /// Set modal height (default: 20)
-    pub fn height(mut self, height: u16) -> Self {
-        self.height = Some(height);
-        self
+    /// Number of lines the content takes, for scroll calculations
+    pub fn line_count(&self) -> usize {
+        let sections: usize = self.sections.iter()
+            .map(|section| 2 + section.bindings.len()) // header + bindings + blank
+            .sum();
+        2 + sections + 2 // title + blank, blank + footer
     }
 
-    /// Build the modal Element (with unit type since it's global UI)
-    pub fn build(self) -> Element<()> {
-        // Build ALL help content items (no skipping - scrollable widget handles scrolling)
+    /// Build the modal Element
+    pub fn build(self) -> Element<Msg> {
         let theme = &crate::global_runtime_config().theme;
         let mut help_items = vec![
             Element::styled_text(Line::from(vec![
@@ -136,56 +127,30 @@ impl<'a> HelpModal<'a> {
             Element::text(""),
         ];
 
-        // Section 1: Global Keys (highest priority)
-        if !self.global_bindings.is_empty() {
-            help_items.push(Element::styled_text(Line::from(vec![
-                Span::styled("▼ Global", Style::default().fg(theme.accent_muted).bold())
-            ])).build());
+        // Align descriptions across all sections
+        let key_width = self.sections.iter()
+            .flat_map(|section| section.bindings.iter())
+            .map(|(keys, _)| keys.chars().count())
+            .max()
+            .unwrap_or(0);
 
-            let formatted_global = group_and_format_bindings(&self.global_bindings);
-            for (key_str, description) in &formatted_global {
-                let line = Line::from(vec![
-                    Span::styled(format!("  {:13}", key_str), Style::default().fg(theme.accent_tertiary)),
-                    Span::raw("  "),
-                    Span::styled(description.clone(), Style::default().fg(theme.text_primary)),
-                ]);
-                help_items.push(Element::styled_text(line).build());
-            }
+        for section in self.sections {
+            let (header_color, key_color, desc_color) = match section.kind {
+                SectionKind::Global => (theme.accent_muted, theme.accent_tertiary, theme.text_primary),
+                SectionKind::CurrentApp => (theme.accent_secondary, theme.accent_success, theme.text_primary),
+                SectionKind::OtherApp => (theme.border_primary, theme.border_tertiary, theme.text_tertiary),
+            };
 
-            help_items.push(Element::text(""));
-        }
-
-        // Section 2: Current App Keys
-        if let Some(title) = self.current_app_title {
-            help_items.push(Element::styled_text(Line::from(vec![
-                Span::styled(format!("▼ {}", title), Style::default().fg(theme.accent_secondary).bold())
-            ])).build());
-
-            let formatted_current = group_and_format_bindings(&self.current_app_bindings);
-            for (key_str, description) in &formatted_current {
-                let line = Line::from(vec![
-                    Span::styled(format!("  {:13}", key_str), Style::default().fg(theme.accent_success)),
-                    Span::raw("  "),
-                    Span::styled(description.clone(), Style::default().fg(theme.text_primary)),
-                ]);
-                help_items.push(Element::styled_text(line).build());
-            }
-
-            help_items.push(Element::text(""));
-        }
-
-        // Section 3: Other Apps
-        for (app_title, app_bindings) in &self.other_apps {
             help_items.push(Element::styled_text(Line::from(vec![
-                Span::styled(format!("▼ {}", app_title), Style::default().fg(theme.border_primary).bold())
+                Span::styled(format!("▼ {}", section.title), Style::default().fg(header_color).bold())
             ])).build());
 
-            let formatted_other = group_and_format_bindings(app_bindings);
-            for (key_str, description) in &formatted_other {
+            for (keys, description) in section.bindings {
+                let padding = " ".repeat(key_width - keys.chars().count());
                 let line = Line::from(vec![
-                    Span::styled(format!("  {:13}", key_str), Style::default().fg(theme.border_tertiary)),
+                    Span::styled(format!("  {}{}", keys, padding), Style::default().fg(key_color)),
                     Span::raw("  "),
-                    Span::styled(description.clone(), Style::default().fg(theme.text_tertiary)),
+                    Span::styled(description, Style::default().fg(desc_color)),
                 ]);
                 help_items.push(Element::styled_text(line).build());
             }
@@ -206,32 +171,43 @@ impl<'a> HelpModal<'a> {
         let help_column = column_builder.spacing(0).build();
 
         // Wrap in scrollable if we have scroll state
-        let content = if let Some(scroll_state) = self.scroll_state {
-            Element::scrollable(
-                FocusId::new("help_scroll"),
-                help_column,
-                scroll_state,
-            ).build()
-        } else {
-            help_column
+        let content = match self.scroll_state {
+            Some(scroll_state) => {
+                let mut scrollable = Element::scrollable("help-scroll", help_column, scroll_state);
+                if let Some(on_navigate) = self.on_navigate {
+                    scrollable = scrollable.on_navigate(on_navigate);
+                }
+                scrollable.build()
+            }
+            None => help_column,
         };
 
-        // Wrap in container with padding
-        let padded_content = Element::container(content)
-            .padding(1)
-            .build();
+        Element::panel(content)
+            .title("Help")
+            .build()
+    }
+}
 
-        // Wrap in panel
-        let mut panel = Element::panel(padded_content)
-            .title("Help");
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-        if let Some(w) = self.width {
-            panel = panel.width(w);
-        }
-        if let Some(h) = self.height {
-            panel = panel.height(h);
-        }
+    #[test]
+    fn test_bindings_with_same_description_become_aliases() {
+        let bindings = vec![
+            (KeyBinding::new(KeyCode::Char('N')), "New".to_string()),
+            (KeyBinding::new(KeyCode::Char('n')), "New".to_string()),
+            (KeyBinding::new(KeyCode::Char('d')), "Delete".to_string()),
+        ];
+
+        assert_eq!(group_and_format_bindings(&bindings), vec![
+            ("d".to_string(), "Delete".to_string()),
+            ("n/Shift+N".to_string(), "New".to_string()),
+        ]);
 
-        panel.build()
+        let modal: HelpModal<()> = HelpModal::new()
+            .global_bindings(bindings)
+            .current_app("Empty", Vec::new());
+        assert_eq!(modal.line_count(), 2 + 4 + 2);
     }
 }
@@ -1,4 +1,5 @@
 pub mod app_overview;
+pub mod command_palette;
 pub mod confirmation;
 pub mod error;
 pub mod examples;
@@ -8,6 +9,7 @@ pub mod prefix_mappings;
 pub mod warning;
 
 pub use app_overview::AppOverviewModal;
+pub use command_palette::CommandPaletteModal;
 pub use confirmation::ConfirmationModal;
 pub use error::ErrorModal;
 pub use examples::{ExamplesModal, ExamplePairItem};
@@ -45,6 +45,7 @@ impl<Msg: Clone> ListItem for PrefixMappingItem<Msg> {
 ///     .list_state(list_state)
 ///     .on_add(Msg::AddPrefixMapping)
 ///     .on_delete(Msg::DeletePrefixMapping)
+///     .on_apply(Msg::ApplyPrefixMapping)
 ///     .on_close(Msg::ClosePrefixMappingsModal)
 ///     .build(theme);
 /// ```
@@ -59,6 +60,7 @@ pub struct PrefixMappingsModal<Msg> {
     on_list_select: Option<fn(usize) -> Msg>,
     on_add: Option<Msg>,
     on_delete: Option<Msg>,
+    on_apply: Option<Msg>,
     on_close: Option<Msg>,
     width: Option<u16>,
     height: Option<u16>,
@@ -78,6 +80,7 @@ impl<Msg: Clone> PrefixMappingsModal<Msg> {
             on_list_select: None,
             on_add: None,
             on_delete: None,
+            on_apply: None,
             on_close: None,
             width: Some(70),
             height: Some(25),
@@ -144,6 +147,12 @@ impl<Msg: Clone> PrefixMappingsModal<Msg> {
         self
     }
 
+    /// Set the message sent when Apply to Fields is clicked (the button is hidden without it)
+    pub fn on_apply(mut self, msg: Msg) -> Self {
+        self.on_apply = Some(msg);
+        self
+    }
+
     /// Set the message sent when Close is clicked
     pub fn on_close(mut self, msg: Msg) -> Self {
         self.on_close = Some(msg);
@@ -219,11 +228,22 @@ impl<Msg: Clone> PrefixMappingsModal<Msg> {
             .build();
 
         // Buttons
-        let buttons = button_row![
-            ("prefix-add", "Add", self.on_add.clone().expect("PrefixMappingsModal requires on_add")),
-            ("prefix-delete", "Delete", self.on_delete.clone().expect("PrefixMappingsModal requires on_delete")),
-            ("prefix-close", "Close", self.on_close.clone().expect("PrefixMappingsModal requires on_close")),
-        ];
+        let on_add = self.on_add.clone().expect("PrefixMappingsModal requires on_add");
+        let on_delete = self.on_delete.clone().expect("PrefixMappingsModal requires on_delete");
+        let on_close = self.on_close.clone().expect("PrefixMappingsModal requires on_close");
+        let buttons = match self.on_apply.clone() {
+            Some(on_apply) => button_row![
+                ("prefix-add", "Add", on_add),
+                ("prefix-apply", "Apply to Fields", on_apply),
+                ("prefix-delete", "Delete", on_delete),
+                ("prefix-close", "Close", on_close),
+            ],
+            None => button_row![
+                ("prefix-add", "Add", on_add),
+                ("prefix-delete", "Delete", on_delete),
+                ("prefix-close", "Close", on_close),
+            ],
+        };
 
         // Layout with explicit constraints
         let modal_body = col![
@@ -7,35 +7,15 @@ use anyhow::Result;
 use std::collections::HashMap;
 use std::time::Instant;
 
-use crate::tui::{AppId, Runtime, AppRuntime, apps::{AppLauncher, LoadingScreen, ErrorScreen, SettingsApp, UpdateApp, EnvironmentSelectorApp, migration::{MigrationEnvironmentApp, MigrationComparisonSelectApp, EntityComparisonApp}, DeadlinesFileSelectApp, DeadlinesMappingApp, DeadlinesInspectionApp, OperationQueueApp, SelectQuestionnaireApp, copy_questionnaires::{CopyQuestionnaireApp, PushQuestionnaireApp}}, Element, LayoutConstraint, Layer, Theme, ThemeVariant, App, ModalState, KeyBinding, AppLifecycle};
+use crate::tui::{AppId, Runtime, AppRuntime, apps::{AppLauncher, LoadingScreen, ErrorScreen, SettingsApp, UpdateApp, EnvironmentSelectorApp, migration::{MigrationEnvironmentApp, MigrationComparisonSelectApp, EntityComparisonApp}, DeadlinesFileSelectApp, DeadlinesMappingApp, DeadlinesInspectionApp, OperationQueueApp, SelectQuestionnaireApp, copy_questionnaires::{CopyQuestionnaireApp, PushQuestionnaireApp}, QueryRunnerApp, SavedQueriesApp}, Element, LayoutConstraint, Layer, Theme, ThemeVariant, App, ModalState, KeyBinding, AppLifecycle};
 use crate::tui::runtime::AppFactory;
 use crate::tui::element::{ColumnBuilder, RowBuilder, FocusId};
 use crate::tui::widgets::ScrollableState;
-use crate::tui::modals::AppOverviewModal;
-
-/// Group key bindings by description, combining keys with the same description into aliases
-fn group_bindings_by_description(bindings: &[(KeyBinding, &str)]) -> Vec<(String, String)> {
-    let mut grouped: HashMap<&str, Vec<String>> = HashMap::new();
-
-    for (key, desc) in bindings {
-        grouped.entry(*desc)
-            .or_insert_with(Vec::new)
-            .push(key.to_string());
-    }
-
-    let mut result: Vec<(String, String)> = grouped.into_iter()
-        .map(|(desc, mut keys)| {
-            // Sort keys to ensure consistent ordering (shorter keys first)
-            keys.sort_by_key(|k| k.len());
-            (keys.join("/"), desc.to_string())
-        })
-        .collect();
-
-    // Sort by the first key in each group for consistent ordering
-    result.sort_by(|a, b| a.0.cmp(&b.0));
-
-    result
-}
+use crate::tui::state::ToastStack;
+use crate::tui::modals::{AppOverviewModal, CommandPaletteModal, HelpModal};
+use crate::tui::command_palette::{PaletteEntry, PaletteState};
+use crate::tui::apps::screens::ErrorScreenParams;
+use crate::tui::error_boundary;
 
 /// Messages for global UI elements (help menu, quit modal, etc.)
 #[derive(Clone)]
@@ -50,6 +30,11 @@ enum GlobalMsg {
 
     // App overview modal
     CloseAppOverview,
+
+    // Command palette
+    PaletteInput(KeyCode),
+    PaletteRun,
+    ClosePalette,
 }
 
 /// Manages multiple app runtimes and handles navigation between them
@@ -74,6 +59,8 @@ pub struct MultiAppRuntime {
     help_scroll_state: ScrollableState,
     quit_modal: ModalState<()>,
     app_overview_modal: ModalState<()>,
+    palette_modal: ModalState<()>,
+    palette_state: PaletteState,
 
     // Global focus system
     global_interaction_registry: crate::tui::InteractionRegistry<GlobalMsg>,
@@ -82,6 +69,9 @@ pub struct MultiAppRuntime {
 
     /// Last time Tab key was pressed (for debouncing)
     last_tab_press: Option<Instant>,
+
+    /// Toast notifications raised by any app
+    toasts: ToastStack,
 }
 
 impl MultiAppRuntime {
@@ -106,6 +96,8 @@ impl MultiAppRuntime {
         factories.insert(AppId::SelectQuestionnaire, Box::new(std::marker::PhantomData::<SelectQuestionnaireApp>));
         factories.insert(AppId::CopyQuestionnaire, Box::new(std::marker::PhantomData::<CopyQuestionnaireApp>));
         factories.insert(AppId::PushQuestionnaire, Box::new(std::marker::PhantomData::<PushQuestionnaireApp>));
+        factories.insert(AppId::QueryRunner, Box::new(std::marker::PhantomData::<QueryRunnerApp>));
+        factories.insert(AppId::SavedQueries, Box::new(std::marker::PhantomData::<SavedQueriesApp>));
 
         // Mark all apps as NotCreated initially
         for app_id in factories.keys() {
@@ -122,10 +114,13 @@ impl MultiAppRuntime {
             help_scroll_state: ScrollableState::new(),
             quit_modal: ModalState::Closed,
             app_overview_modal: ModalState::Closed,
+            palette_modal: ModalState::Closed,
+            palette_state: PaletteState::default(),
             global_interaction_registry: crate::tui::InteractionRegistry::new(),
             global_focus_registry: crate::tui::renderer::FocusRegistry::new(),
             global_focused_id: None,
             last_tab_press: None,
+            toasts: ToastStack::new(),
         };
 
         // Eagerly create the AppLauncher since it's the starting app
@@ -202,58 +197,9 @@ impl MultiAppRuntime {
                 return Ok(true);
             }
             GlobalMsg::HelpScroll(key) => {
-                // Calculate content height (same as in render_help_menu)
-                // Use configured keybinds from global config
-                let config = crate::global_runtime_config();
-                let help_key = config.get_keybind("global.help");
-                let launcher_key = config.get_keybind("global.app_launcher");
-
-                let global_bindings = vec![
-                    (help_key, "Toggle help menu"),
-                    (launcher_key, "Go to app launcher"),
-                    (KeyBinding::new(KeyCode::Esc), "Close help menu"),
-                ];
-
-                let mut all_app_bindings: Vec<(AppId, &'static str, Vec<(KeyBinding, String)>)> = vec![];
-                for (app_id, runtime) in &self.runtimes {
-                    let title = runtime.get_title();
-                    let bindings = runtime.get_key_bindings();
-                    all_app_bindings.push((*app_id, title, bindings));
-                }
-
-                let current_app_data = all_app_bindings.iter()
-                    .find(|(id, _, _)| *id == self.active_app)
-                    .expect("Active app not found");
-
-                let other_apps: Vec<_> = all_app_bindings.iter()
-                    .filter(|(id, _, bindings)| *id != self.active_app && !bindings.is_empty())
-                    .collect();
-
-                // Calculate total items using grouped bindings
-                let global_grouped = group_bindings_by_description(&global_bindings);
-                let current_bindings_ref: Vec<(KeyBinding, &str)> = current_app_data.2.iter()
-                    .map(|(key, desc)| (*key, desc.as_str()))
-                    .collect();
-                let current_grouped = group_bindings_by_description(&current_bindings_ref);
-
-                let other_apps_grouped_count: usize = other_apps.iter()
-                    .map(|(_, _, bindings)| {
-                        let bindings_ref: Vec<(KeyBinding, &str)> = bindings.iter()
-                            .map(|(key, desc)| (*key, desc.as_str()))
-                            .collect();
-                        let grouped = group_bindings_by_description(&bindings_ref);
-                        2 + grouped.len() // section header + bindings
-                    })
-                    .sum();
-
-                let total_items = 2 + // title + blank
-                    (if !global_grouped.is_empty() { 2 + global_grouped.len() } else { 0 }) +
-                    (if !current_grouped.is_empty() { 2 + current_grouped.len() } else { 0 }) +
-                    other_apps_grouped_count +
-                    2; // blank + footer
-
-                // Get viewport height from last render (approximation: 20 lines for modal)
-                let content_height = 20usize.saturating_sub(4);
+                // Sizes are recorded by the last render_help_menu
+                let total_items = self.help_scroll_state.content_height().unwrap_or(0);
+                let content_height = self.help_scroll_state.viewport_height().unwrap_or(0);
                 self.help_scroll_state.handle_key(key, total_items, content_height);
                 return Ok(true);
             }
@@ -267,14 +213,44 @@ impl MultiAppRuntime {
                 self.global_focused_id = None; // Clear focus when closing modal
                 return Ok(true);
             }
+            GlobalMsg::PaletteInput(key) => {
+                self.palette_state.handle_key(key);
+                return Ok(true);
+            }
+            GlobalMsg::PaletteRun => {
+                let entry = self.palette_state.selected_entry().cloned();
+                self.palette_modal.close();
+                self.global_focused_id = None; // Clear focus when closing modal
+                if let Some(entry) = entry {
+                    return self.run_palette_entry(entry);
+                }
+                return Ok(true);
+            }
+            GlobalMsg::ClosePalette => {
+                self.palette_modal.close();
+                self.global_focused_id = None; // Clear focus when closing modal
+                return Ok(true);
+            }
         }
     }
 
+    /// Handle a key press; a panic in app code shows the error screen instead
     pub fn handle_key(&mut self, key_event: KeyEvent) -> Result<bool> {
+        let app = self.active_app;
+        match error_boundary::catch(|| self.dispatch_key(key_event)) {
+            Ok(result) => result,
+            Err(message) => {
+                self.recover_from_panic(app, message)?;
+                Ok(true)
+            }
+        }
+    }
+
+    fn dispatch_key(&mut self, key_event: KeyEvent) -> Result<bool> {
         log::debug!("🎹 MultiRuntime::handle_key: key={:?}, mods={:?}", key_event.code, key_event.modifiers);
 
         // Priority 1: Global modal keyboard handling (Tab, focused elements)
-        if self.quit_modal.is_open() || self.help_modal.is_open() || self.app_overview_modal.is_open() {
+        if self.quit_modal.is_open() || self.help_modal.is_open() || self.app_overview_modal.is_open() || self.palette_modal.is_open() {
             // Tab/Shift-Tab: Move focus within global modal
             if KeyBinding::new(KeyCode::Tab).matches(&key_event) {
                 // Check debouncing
@@ -344,6 +320,14 @@ impl MultiAppRuntime {
             return Ok(true);  // Consume all other keys (except Tab, handled above)
         }
 
+        // Priority 3.6: Command palette Esc to close (typing is handled by its focused input)
+        if self.palette_modal.is_open() {
+            if key_event.code == KeyCode::Esc {
+                return self.handle_global_msg(GlobalMsg::ClosePalette);
+            }
+            return Ok(true);
+        }
+
         // Priority 3.75: Check if app is capturing raw input (e.g., keybind capture mode)
         // If so, skip global keybind handling and delegate directly to the app
         let runtime = self.runtimes.get(&self.active_app)
@@ -472,6 +456,13 @@ impl MultiAppRuntime {
             return Ok(true);
         }
 
+        // Priority 7: Configurable command palette keybind
+        let palette_key = config.get_keybind("global.command_palette");
+        if palette_key.matches(&key_event) {
+            self.open_palette();
+            return Ok(true);
+        }
+
         // When help menu is open, intercept keys for help control
         if self.help_modal.is_open() {
             match key_event.code {
@@ -543,11 +534,23 @@ impl MultiAppRuntime {
         Ok(result)
     }
 
+    /// Handle a mouse event; a panic in app code shows the error screen instead
     pub fn handle_mouse(&mut self, mouse_event: MouseEvent) -> Result<bool> {
+        let app = self.active_app;
+        match error_boundary::catch(|| self.dispatch_mouse(mouse_event)) {
+            Ok(result) => result,
+            Err(message) => {
+                self.recover_from_panic(app, message)?;
+                Ok(true)
+            }
+        }
+    }
+
+    fn dispatch_mouse(&mut self, mouse_event: MouseEvent) -> Result<bool> {
         use crossterm::event::MouseEventKind;
 
         // When any global modal is open, check for interactions
-        if self.quit_modal.is_open() || self.help_modal.is_open() {
+        if self.quit_modal.is_open() || self.help_modal.is_open() || self.palette_modal.is_open() {
             match mouse_event.kind {
                 MouseEventKind::Down(_) => {
                     // Check for button clicks in global interaction registry
@@ -620,6 +623,18 @@ impl MultiAppRuntime {
             .expect("Active app not found in runtimes")
             .render_to_area(frame, app_area);
 
+        // Toasts from any app (background ones included) cover app content and app modals,
+        // but stay under the global modals drawn below
+        for runtime in self.runtimes.values_mut() {
+            for (message, level) in runtime.take_notifications() {
+                self.toasts.push(message, level);
+            }
+        }
+        self.toasts.prune(Instant::now());
+        if !self.toasts.is_empty() {
+            crate::tui::renderer::render_toasts(frame, app_area, &self.toasts, theme);
+        }
+
         // If help menu is open, overlay it on top
         if self.help_modal.is_open() {
             self.render_help_menu(frame, full_area);
@@ -630,6 +645,10 @@ impl MultiAppRuntime {
             self.render_app_overview(frame, full_area);
         }
 
+        if self.palette_modal.is_open() {
+            self.render_command_palette(frame);
+        }
+
         // If quit confirmation is open, overlay it on top (highest priority)
         if self.quit_modal.is_open() {
             self.render_quit_confirm(frame, full_area);
@@ -687,134 +706,22 @@ impl MultiAppRuntime {
             .style(Style::default().bg(theme.bg_surface));
         frame.render_widget(dim_overlay, area);
 
-        // Build help content directly as Element<GlobalMsg>
-        // Use configured keybinds from global config
-        let config = crate::global_runtime_config();
-        let help_key = config.get_keybind("global.help");
-        let launcher_key = config.get_keybind("global.app_launcher");
-
-        let global_bindings = vec![
-            (help_key, "Toggle help menu"),
-            (launcher_key, "Go to app launcher"),
-            (KeyBinding::new(KeyCode::Esc), "Close help menu"),
-        ];
-
-        // Get all apps' key bindings
-        let mut all_app_bindings: Vec<(AppId, &'static str, Vec<(KeyBinding, String)>)> = vec![];
-        for (app_id, runtime) in &self.runtimes {
-            let title = runtime.get_title();
-            let bindings = runtime.get_key_bindings();
-            all_app_bindings.push((*app_id, title, bindings));
-        }
+        // Global keys come from the keybind registry, so rebinds show up here
+        let mut global_bindings = crate::global_runtime_config().keybinds_for("global");
+        global_bindings.push((KeyBinding::new(KeyCode::Esc), "Close help menu".to_string()));
 
-        let current_app_data = all_app_bindings.iter()
-            .find(|(id, _, _)| *id == self.active_app)
-            .expect("Active app not found");
-
-        let other_apps: Vec<_> = all_app_bindings.iter()
-            .filter(|(id, _, bindings)| *id != self.active_app && !bindings.is_empty())
-            .collect();
-
-        // Pre-group all bindings to calculate max width across entire help menu
-        let global_grouped = group_bindings_by_description(&global_bindings);
-
-        let current_bindings_ref: Vec<(KeyBinding, &str)> = current_app_data.2.iter()
-            .map(|(key, desc)| (*key, desc.as_str()))
-            .collect();
-        let current_grouped = group_bindings_by_description(&current_bindings_ref);
-
-        let other_apps_grouped: Vec<(AppId, &'static str, Vec<(String, String)>)> = other_apps.iter()
-            .map(|(id, title, bindings)| {
-                let bindings_ref: Vec<(KeyBinding, &str)> = bindings.iter()
-                    .map(|(key, desc)| (*key, desc.as_str()))
-                    .collect();
-                let grouped = group_bindings_by_description(&bindings_ref);
-                (*id, *title, grouped)
-            })
-            .collect();
-
-        // Calculate max key width across ALL sections
-        let max_key_width = global_grouped.iter()
-            .chain(current_grouped.iter())
-            .chain(other_apps_grouped.iter().flat_map(|(_, _, grouped)| grouped.iter()))
-            .map(|(keys, _)| keys.len())
-            .max()
-            .unwrap_or(0);
-
-        // Build help content
-        let mut help_items: Vec<Element<GlobalMsg>> = vec![
-            Element::styled_text(Line::from(vec![
-                Span::styled("Keyboard Shortcuts", Style::default().fg(theme.accent_primary).bold())
-            ])).build(),
-            Element::text(""),
-        ];
-
-        // Global section
-        if !global_grouped.is_empty() {
-            help_items.push(Element::styled_text(Line::from(vec![
-                Span::styled("▼ Global", Style::default().fg(theme.accent_muted).bold())
-            ])).build());
-
-            for (keys, desc) in global_grouped {
-                let padding = " ".repeat(max_key_width - keys.len());
-                let line = Line::from(vec![
-                    Span::styled(format!("  {}{}", keys, padding), Style::default().fg(theme.accent_tertiary)),
-                    Span::raw("  "),
-                    Span::styled(desc, Style::default().fg(theme.text_primary)),
-                ]);
-                help_items.push(Element::styled_text(line).build());
-            }
-            help_items.push(Element::text(""));
-        }
+        let mut help = HelpModal::new()
+            .global_bindings(global_bindings);
 
-        // Current app section (only show if it has bindings)
-        if !current_grouped.is_empty() {
-            help_items.push(Element::styled_text(Line::from(vec![
-                Span::styled(format!("▼ {}", current_app_data.1), Style::default().fg(theme.accent_secondary).bold())
-            ])).build());
-
-            for (keys, desc) in current_grouped {
-                let padding = " ".repeat(max_key_width - keys.len());
-                let line = Line::from(vec![
-                    Span::styled(format!("  {}{}", keys, padding), Style::default().fg(theme.accent_success)),
-                    Span::raw("  "),
-                    Span::styled(desc, Style::default().fg(theme.text_primary)),
-                ]);
-                help_items.push(Element::styled_text(line).build());
-            }
-            help_items.push(Element::text(""));
+        if let Some(runtime) = self.runtimes.get(&self.active_app) {
+            help = help.current_app(runtime.get_title(), runtime.get_key_bindings());
         }
-
-        // Other apps sections
-        for (_, app_title, grouped) in other_apps_grouped {
-            help_items.push(Element::styled_text(Line::from(vec![
-                Span::styled(format!("▼ {}", app_title), Style::default().fg(theme.border_primary).bold())
-            ])).build());
-
-            for (keys, desc) in grouped {
-                let padding = " ".repeat(max_key_width - keys.len());
-                let line = Line::from(vec![
-                    Span::styled(format!("  {}{}", keys, padding), Style::default().fg(theme.border_tertiary)),
-                    Span::raw("  "),
-                    Span::styled(desc, Style::default().fg(theme.text_tertiary)),
-                ]);
-                help_items.push(Element::styled_text(line).build());
+        for (app_id, runtime) in &self.runtimes {
+            if *app_id != self.active_app {
+                help = help.add_app(runtime.get_title(), runtime.get_key_bindings());
             }
-            help_items.push(Element::text(""));
         }
 
-        help_items.push(Element::text(""));
-        help_items.push(Element::styled_text(Line::from(vec![
-            Span::styled("[ESC to close | ↑↓/PgUp/PgDn/Home/End to scroll]", Style::default().fg(theme.border_primary))
-        ])).build());
-
-        // Build column with all items
-        let mut column_builder = ColumnBuilder::new();
-        for item in help_items {
-            column_builder = column_builder.add(item, LayoutConstraint::Length(1));
-        }
-        let help_column = column_builder.spacing(0).build();
-
         // Calculate modal dimensions
         let modal_width = area.width.min(60);
         let modal_height = area.height.min(20);
@@ -831,26 +738,15 @@ impl MultiAppRuntime {
 
         let content_height = modal_height.saturating_sub(4) as usize;
 
-        // Count total items for scroll state
-        let total_items = 2 + // title + blank
-            (if !global_bindings.is_empty() { 2 + global_bindings.len() } else { 0 }) +
-            2 + current_app_data.2.len() +
-            other_apps.iter().map(|(_, _, bindings)| 2 + bindings.len()).sum::<usize>() +
-            2; // blank + footer
-
         // Set viewport height for scrolloff calculations
         self.help_scroll_state.set_viewport_height(content_height);
-        self.help_scroll_state.update_scroll(content_height, total_items);
+        self.help_scroll_state.update_scroll(content_height, help.line_count());
 
-        // Wrap in scrollable with on_navigate
-        let scrollable = Element::scrollable("help-scroll", help_column, &self.help_scroll_state)
+        let modal = help
+            .scroll_state(&self.help_scroll_state)
             .on_navigate(GlobalMsg::HelpScroll)
             .build();
 
-        let modal = Element::panel(scrollable)
-            .title("Help")
-            .build();
-
         // Render with global registries
         use crate::tui::Renderer;
         use crate::tui::renderer::DropdownRegistry;
@@ -1075,6 +971,63 @@ impl MultiAppRuntime {
         self.global_focus_registry.save_layer_focus(self.global_focused_id.clone());
     }
 
+    /// Open the command palette with the actions of all running apps, most recent app first
+    fn open_palette(&mut self) {
+        let mut entries = Vec::new();
+        for app_id in self.get_recent_apps() {
+            let Some(runtime) = self.runtimes.get(&app_id) else {
+                continue;
+            };
+            for (index, (label, detail)) in runtime.get_palette_actions().into_iter().enumerate() {
+                entries.push(PaletteEntry { app: app_id, index, label, detail });
+            }
+        }
+
+        self.palette_state = PaletteState::new(entries);
+        self.palette_modal.open_empty();
+        self.global_focused_id = Some(FocusId::new("command-palette-input"));
+    }
+
+    /// Send a palette action to the app that offered it, then apply any navigation it causes
+    fn run_palette_entry(&mut self, entry: PaletteEntry) -> Result<bool> {
+        log::info!("Running palette action '{}' from {:?}", entry.label, entry.app);
+        let Some(runtime) = self.runtimes.get_mut(&entry.app) else {
+            return Ok(true);
+        };
+        let result = runtime.run_palette_action(entry.index)?;
+
+        self.broadcast_events()?;
+        let _ = self.check_navigation()?;
+        Ok(result)
+    }
+
+    /// Render the command palette as a global modal near the top of the screen
+    fn render_command_palette(&mut self, frame: &mut Frame) {
+        use crate::tui::{Renderer, Alignment};
+
+        let modal = CommandPaletteModal::new(&self.palette_state)
+            .on_input(GlobalMsg::PaletteInput)
+            .on_submit(GlobalMsg::PaletteRun)
+            .build();
+        let view = crate::tui::LayeredView::new(Element::None)
+            .with_global_modal(modal, Alignment::TopCenter);
+
+        // Sync runtime's focus to active layer BEFORE clearing registries
+        self.global_focus_registry.save_layer_focus(self.global_focused_id.clone());
+
+        // Clear and render to global registries
+        self.global_interaction_registry = crate::tui::InteractionRegistry::new();
+        self.global_focus_registry = crate::tui::renderer::FocusRegistry::new();
+        let area = frame.size();
+        Renderer::render_layers(frame, &mut self.global_interaction_registry, &mut self.global_focus_registry, self.global_focused_id.as_ref(), &view, area, None);
+
+        // The input is the only focusable, keep it focused
+        if self.global_focused_id.as_ref().is_none_or(|id| !self.global_focus_registry.contains(id)) {
+            self.global_focused_id = self.global_focus_registry.restore_focus_from_layers();
+        }
+        self.global_focus_registry.save_layer_focus(self.global_focused_id.clone());
+    }
+
     /// Get apps ordered by recency (most recent first), filtered to Running/Background apps only
     pub fn get_recent_apps(&self) -> Vec<AppId> {
         let mut apps: Vec<(AppId, Instant)> = self.last_active_time
@@ -1096,21 +1049,83 @@ impl MultiAppRuntime {
 
     pub async fn poll_async(&mut self) -> Result<()> {
         // Poll all apps regardless of which is active
-        for runtime in self.runtimes.values_mut() {
-            runtime.poll_async().await?;
+        let app_ids: Vec<AppId> = self.runtimes.keys().copied().collect();
+        for app_id in app_ids {
+            let Some(runtime) = self.runtimes.get_mut(&app_id) else { continue };
+            match error_boundary::catch_async(runtime.poll_async()).await {
+                Ok(result) => result?,
+                Err(message) => self.recover_from_panic(app_id, message)?,
+            }
         }
         Ok(())
     }
 
+    /// Destroy every app as the TUI exits, waiting for the work their `on_destroy` issues
+    pub async fn shutdown(&mut self) {
+        for (app_id, mut runtime) in std::mem::take(&mut self.runtimes) {
+            match error_boundary::catch_async(runtime.shutdown()).await {
+                Ok(Ok(())) => {}
+                Ok(Err(e)) => log::error!("Failed to shut down {:?}: {}", app_id, e),
+                Err(message) => log::error!("{:?} panicked while shutting down: {}", app_id, message),
+            }
+            self.lifecycles.insert(app_id, AppLifecycle::Dead);
+        }
+    }
+
     /// Poll timer subscriptions for all apps
     pub fn poll_timers(&mut self) -> Result<()> {
         // Poll all apps regardless of which is active
-        for runtime in self.runtimes.values_mut() {
-            runtime.poll_timers()?;
+        let app_ids: Vec<AppId> = self.runtimes.keys().copied().collect();
+        for app_id in app_ids {
+            let Some(runtime) = self.runtimes.get_mut(&app_id) else { continue };
+            match error_boundary::catch(|| runtime.poll_timers()) {
+                Ok(result) => result?,
+                Err(message) => self.recover_from_panic(app_id, message)?,
+            }
         }
         Ok(())
     }
 
+    /// Replace an app that panicked with the error screen
+    ///
+    /// The app's state can't be trusted after a panic, so it is dropped
+    /// without `on_destroy`; the error screen leads back to the launcher.
+    fn recover_from_panic(&mut self, app_id: AppId, message: String) -> Result<()> {
+        let title = self.runtimes.remove(&app_id)
+            .map(|runtime| runtime.get_title())
+            .unwrap_or("App");
+        self.lifecycles.insert(app_id, AppLifecycle::Dead);
+        log::error!("{:?} panicked and was closed: {}", app_id, message);
+
+        self.help_modal.close();
+        self.palette_modal.close();
+        self.app_overview_modal.close();
+        self.global_focused_id = None;
+
+        // If the error screen itself failed, go straight back to the launcher
+        if app_id == AppId::ErrorScreen {
+            self.ensure_app_exists(AppId::AppLauncher, Box::new(()))?;
+            self.active_app = AppId::AppLauncher;
+            self.last_active_time.insert(AppId::AppLauncher, Instant::now());
+            return Ok(());
+        }
+
+        if let Some(mut runtime) = self.runtimes.remove(&AppId::ErrorScreen) {
+            runtime.on_destroy().ok();
+        }
+        self.lifecycles.insert(AppId::ErrorScreen, AppLifecycle::Dead);
+        self.ensure_app_exists(AppId::ErrorScreen, Box::new(ErrorScreenParams {
+            message: format!(
+                "{} crashed:\n\n{}\n\nThe backtrace was written to dynamics-cli.log.",
+                title, message,
+            ),
+            target: Some(AppId::AppLauncher),
+        }))?;
+        self.active_app = AppId::ErrorScreen;
+        self.last_active_time.insert(AppId::ErrorScreen, Instant::now());
+        Ok(())
+    }
+
     /// Check if any navigation commands were issued
     fn check_navigation(&mut self) -> Result<bool> {
         // Check ALL apps for navigation requests (not just active app)
@@ -1292,13 +1307,25 @@ impl MultiAppRuntime {
         }
 
         // Broadcast each event to all apps
+        let mut panicked = Vec::new();
         for (topic, data) in all_events {
             log::debug!("Broadcasting event '{}' to all apps", topic);
-            for runtime in self.runtimes.values_mut() {
-                runtime.handle_publish(&topic, data.clone())?;
+            for (app_id, runtime) in self.runtimes.iter_mut() {
+                // A panicked app is replaced below; don't send it the remaining events
+                if panicked.iter().any(|(id, _)| id == app_id) {
+                    continue;
+                }
+                match error_boundary::catch(|| runtime.handle_publish(&topic, data.clone())) {
+                    Ok(result) => result?,
+                    Err(message) => panicked.push((*app_id, message)),
+                }
             }
         }
 
+        for (app_id, message) in panicked {
+            self.recover_from_panic(app_id, message)?;
+        }
+
         Ok(())
     }
 
@@ -13,6 +13,7 @@ pub enum DropdownCallback<Msg> {
 pub struct DropdownInfo<Msg> {
     pub select_area: Rect,              // The area of the select widget
     pub options: Vec<String>,           // The dropdown options
+    pub match_indices: Vec<Vec<usize>>, // Char positions to highlight per option (autocomplete)
     pub selected: Option<usize>,        // Selected index (None for autocomplete)
     pub highlight: usize,               // Highlighted index
     pub on_select: DropdownCallback<Msg>,  // Callback when option selected
@@ -153,21 +153,20 @@ impl<Msg: Clone> FocusRegistry<Msg> {
 
     /// Move focus to the next focusable element in the active layer
     /// Returns the new focused ID, or None if there are no focusables
+    ///
+    /// Focus is trapped in the active layer: a current ID from a layer below
+    /// (e.g. the element focused before a modal opened) starts at the first element.
     pub fn next_focus(&self, current: Option<&FocusId>) -> Option<FocusId> {
         let layer = self.active_layer()?;
         if layer.focusables.is_empty() {
             return None;
         }
 
-        match current {
-            None => {
-                // No current focus, focus the first element
-                Some(layer.focusables[0].id.clone())
-            }
-            Some(id) => {
-                // Find current index
-                let current_index = layer.focusables.iter().position(|f| &f.id == id)?;
-                // Wrap around to next
+        match current.and_then(|id| layer.focusables.iter().position(|f| &f.id == id)) {
+            // No current focus in this layer, focus the first element
+            None => Some(layer.focusables[0].id.clone()),
+            // Wrap around to next
+            Some(current_index) => {
                 let next_index = (current_index + 1) % layer.focusables.len();
                 Some(layer.focusables[next_index].id.clone())
             }
@@ -176,28 +175,20 @@ impl<Msg: Clone> FocusRegistry<Msg> {
 
     /// Move focus to the previous focusable element in the active layer
     /// Returns the new focused ID, or None if there are no focusables
+    ///
+    /// Like `next_focus`, a current ID outside the active layer starts at the last element.
     pub fn prev_focus(&self, current: Option<&FocusId>) -> Option<FocusId> {
         let layer = self.active_layer()?;
         if layer.focusables.is_empty() {
             return None;
         }
 
-        match current {
-            None => {
-                // No current focus, focus the last element
-                Some(layer.focusables[layer.focusables.len() - 1].id.clone())
-            }
-            Some(id) => {
-                // Find current index
-                let current_index = layer.focusables.iter().position(|f| &f.id == id)?;
-                // Wrap around to previous
-                let prev_index = if current_index == 0 {
-                    layer.focusables.len() - 1
-                } else {
-                    current_index - 1
-                };
-                Some(layer.focusables[prev_index].id.clone())
-            }
+        match current.and_then(|id| layer.focusables.iter().position(|f| &f.id == id)) {
+            // No current focus in this layer, focus the last element
+            None => Some(layer.focusables[layer.focusables.len() - 1].id.clone()),
+            // Wrap around to previous
+            Some(0) => Some(layer.focusables[layer.focusables.len() - 1].id.clone()),
+            Some(current_index) => Some(layer.focusables[current_index - 1].id.clone()),
         }
     }
 
@@ -210,7 +201,7 @@ impl<Msg: Clone> FocusRegistry<Msg> {
         match (focusable.on_key)(key_event) {
             DispatchTarget::AppMsg(msg) => Some(msg),
             DispatchTarget::WidgetEvent(_) => None, // Widget events not supported in global focus
-            DispatchTarget::PassThrough => None,
+            DispatchTarget::PassThrough | DispatchTarget::Handled => None,
         }
     }
 
@@ -272,3 +263,79 @@ impl<Msg: Clone> FocusRegistry<Msg> {
         result
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn focusable(id: &'static str) -> FocusableInfo<()> {
+        FocusableInfo {
+            id: FocusId::new(id),
+            rect: Rect::default(),
+            on_key: Box::new(|_| DispatchTarget::PassThrough),
+            on_focus: None,
+            on_blur: None,
+            inside_panel: false,
+        }
+    }
+
+    /// One frame as `render_layers` registers it: only the topmost layer's focusables
+    fn render_frame(registry: &mut FocusRegistry<()>, layer_index: usize, ids: &[&'static str]) {
+        registry.clear();
+        if layer_index > 0 {
+            registry.push_layer(layer_index);
+        }
+        for id in ids {
+            registry.register_focusable(focusable(id));
+        }
+    }
+
+    #[test]
+    fn test_tab_cycles_only_within_topmost_layer() {
+        let mut registry = FocusRegistry::new();
+        render_frame(&mut registry, 0, &["list", "search"]);
+        registry.save_layer_focus(Some(FocusId::new("search")));
+        render_frame(&mut registry, 1, &["name", "ok", "cancel"]);
+
+        // The base element isn't reachable; Tab enters the modal instead
+        let base = FocusId::new("search");
+        assert_eq!(registry.next_focus(Some(&base)), Some(FocusId::new("name")));
+        assert_eq!(registry.prev_focus(Some(&base)), Some(FocusId::new("cancel")));
+
+        // And wraps around at both ends
+        assert_eq!(registry.next_focus(Some(&FocusId::new("cancel"))), Some(FocusId::new("name")));
+        assert_eq!(registry.prev_focus(Some(&FocusId::new("name"))), Some(FocusId::new("cancel")));
+    }
+
+    #[test]
+    fn test_closing_a_layer_restores_the_focus_from_before_it_opened() {
+        let mut registry = FocusRegistry::new();
+        render_frame(&mut registry, 0, &["list", "search"]);
+        registry.save_layer_focus(Some(FocusId::new("search")));
+
+        // Modal open: its own focus is kept per layer
+        render_frame(&mut registry, 1, &["name", "ok"]);
+        assert_eq!(registry.get_layer_focus(), None);
+        registry.save_layer_focus(Some(FocusId::new("ok")));
+
+        // Modal closed: the base layer's focus comes back
+        render_frame(&mut registry, 0, &["list", "search"]);
+        assert_eq!(registry.restore_focus_from_layers(), Some(FocusId::new("search")));
+
+        // Reopening the modal restores its last focus too
+        render_frame(&mut registry, 1, &["name", "ok"]);
+        assert_eq!(registry.get_layer_focus(), Some(FocusId::new("ok")));
+    }
+
+    #[test]
+    fn test_restore_skips_focus_that_no_longer_exists() {
+        let mut registry = FocusRegistry::new();
+        render_frame(&mut registry, 0, &["list", "search"]);
+        registry.save_layer_focus(Some(FocusId::new("search")));
+        render_frame(&mut registry, 1, &["ok"]);
+
+        // The modal removed the element that had focus
+        render_frame(&mut registry, 0, &["list"]);
+        assert_eq!(registry.restore_focus_from_layers(), None);
+    }
+}
@@ -1,4 +1,9 @@
 use ratatui::layout::Rect;
+use crate::tui::command::DispatchTarget;
+use crate::tui::element::FocusId;
+
+/// Turns a mouse wheel step (rows, negative is up) into the widget's scroll message
+pub type ScrollHandler<Msg> = Box<dyn Fn(isize) -> DispatchTarget<Msg> + Send>;
 
 /// Stores interaction handlers for UI elements
 /// Maps (Rect, InteractionType) -> Message
@@ -6,6 +11,8 @@ pub struct InteractionRegistry<Msg> {
     click_handlers: Vec<(Rect, Msg)>,
     hover_handlers: Vec<(Rect, Msg)>,
     hover_exit_handlers: Vec<(Rect, Msg)>,
+    /// Scroll-capable widget areas, with the widget's id for auto-dispatched events
+    scroll_handlers: Vec<(Rect, FocusId, ScrollHandler<Msg>)>,
     /// Current hover position (set by runtime before each render)
     current_hover_pos: Option<(u16, u16)>,
     /// Messages to dispatch immediately after render (e.g., from on_render callbacks)
@@ -18,6 +25,7 @@ impl<Msg: Clone> InteractionRegistry<Msg> {
             click_handlers: Vec::new(),
             hover_handlers: Vec::new(),
             hover_exit_handlers: Vec::new(),
+            scroll_handlers: Vec::new(),
             current_hover_pos: None,
             render_messages: Vec::new(),
         }
@@ -49,6 +57,10 @@ impl<Msg: Clone> InteractionRegistry<Msg> {
         self.hover_exit_handlers.push((rect, msg));
     }
 
+    pub fn register_scroll(&mut self, rect: Rect, id: FocusId, handler: ScrollHandler<Msg>) {
+        self.scroll_handlers.push((rect, id, handler));
+    }
+
     pub fn find_click(&self, x: u16, y: u16) -> Option<Msg> {
         // Search in reverse order so topmost layers are checked first
         for (rect, msg) in self.click_handlers.iter().rev() {
@@ -79,8 +91,18 @@ impl<Msg: Clone> InteractionRegistry<Msg> {
         None
     }
 
+    /// Find the topmost scrollable widget under the cursor and its message for `delta` rows
+    pub fn find_scroll(&self, x: u16, y: u16, delta: isize) -> Option<(FocusId, DispatchTarget<Msg>)> {
+        self.scroll_handlers
+            .iter()
+            .rev()
+            .find(|(rect, _, _)| self.point_in_rect(x, y, *rect))
+            .map(|(_, id, handler)| (id.clone(), handler(delta)))
+    }
+
     pub fn clear(&mut self) {
         self.click_handlers.clear();
+        self.scroll_handlers.clear();
         self.hover_handlers.clear();
         self.hover_exit_handlers.clear();
         self.current_hover_pos = None;
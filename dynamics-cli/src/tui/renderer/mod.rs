@@ -3,9 +3,10 @@ use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     widgets::{Block, Borders, Paragraph, Clear},
     style::{Style, Stylize},
+    text::{Line, Span},
 };
 use crossterm::event::KeyCode;
-use crate::tui::{Element, Theme, LayoutConstraint, Layer, Alignment as LayerAlignment};
+use crate::tui::{Element, Theme, LayoutConstraint, Layer, SpinnerStyle, Alignment as LayerAlignment};
 use crate::tui::element::FocusId;
 
 // Re-export registries
@@ -14,9 +15,10 @@ mod focus_registry;
 mod dropdown_registry;
 mod widgets;
 
-pub use interaction_registry::InteractionRegistry;
+pub use interaction_registry::{InteractionRegistry, ScrollHandler};
 pub use focus_registry::{FocusRegistry, FocusableInfo, LayerFocusContext};
 pub use dropdown_registry::{DropdownRegistry, DropdownInfo, DropdownCallback};
+pub use widgets::render_toasts;
 
 use widgets::*;
 
@@ -330,11 +332,18 @@ impl Renderer {
                 on_select,
                 on_activate,
                 on_navigate,
+                on_event,
                 on_focus,
                 on_blur,
                 on_render,
+                filterable,
+                filter,
+                filter_matches,
+                show_scrollbar,
+                reorderable,
             } => {
-                render_list(frame, registry, focus_registry, dropdown_registry, focused_id, id, items, *selected, *scroll_offset, on_select, on_activate, on_navigate, on_focus, on_blur, on_render, area, inside_panel, Self::render_element);
+                let filter = ListFilter { filterable: *filterable, query: filter.clone(), matches: filter_matches.clone() };
+                render_list(frame, registry, focus_registry, dropdown_registry, focused_id, id, items, *selected, *scroll_offset, on_select, on_activate, on_navigate, on_event, on_focus, on_blur, on_render, &filter, *show_scrollbar, *reorderable, area, inside_panel, Self::render_element);
             }
 
             Element::TextInput {
@@ -342,6 +351,7 @@ impl Renderer {
                 value,
                 cursor_pos,
                 scroll_offset,
+                selection,
                 placeholder,
                 max_length,
                 masked,
@@ -351,12 +361,27 @@ impl Renderer {
                 on_focus,
                 on_blur,
             } => {
-                render_text_input(frame, registry, focus_registry, focused_id, id, value, *cursor_pos, *scroll_offset, placeholder, max_length, *masked, on_change, on_submit, on_event, on_focus, on_blur, area, inside_panel);
+                render_text_input(frame, registry, focus_registry, focused_id, id, value, *cursor_pos, *scroll_offset, *selection, placeholder, max_length, *masked, on_change, on_submit, on_event, on_focus, on_blur, area, inside_panel);
+            }
+
+            Element::TextArea {
+                id,
+                value,
+                cursor_row,
+                cursor_col,
+                scroll_offset,
+                placeholder,
+                on_event,
+                on_focus,
+                on_blur,
+                ..
+            } => {
+                render_text_area(frame, registry, focus_registry, focused_id, id, value, *cursor_row, *cursor_col, *scroll_offset, placeholder, on_event, on_focus, on_blur, area, inside_panel);
             }
 
             Element::Tree {
                 id,
-                items,
+                rows,
                 node_ids,
                 selected,
                 scroll_offset,
@@ -368,7 +393,7 @@ impl Renderer {
                 on_blur,
                 on_render,
             } => {
-                render_tree(frame, registry, focus_registry, dropdown_registry, focused_id, id, items, node_ids, selected, *scroll_offset, on_select, on_toggle, on_navigate, on_event, on_focus, on_blur, on_render, area, inside_panel, Self::render_element);
+                render_tree(frame, registry, focus_registry, dropdown_registry, focused_id, id, rows, node_ids, selected, *scroll_offset, on_select, on_toggle, on_navigate, on_event, on_focus, on_blur, on_render, area, inside_panel, Self::render_element);
             }
 
             Element::TableTree {
@@ -379,13 +404,36 @@ impl Renderer {
                 scroll_offset,
                 column_widths,
                 column_headers,
+                focused_column,
+                column_offset,
+                resizing_columns,
                 on_select,
                 on_event,
                 on_focus,
                 on_blur,
                 on_render,
             } => {
-                render_table_tree(frame, registry, focus_registry, dropdown_registry, focused_id, id, flattened_nodes, node_ids, selected, *scroll_offset, column_widths, column_headers, on_select, on_event, on_focus, on_blur, on_render, area, inside_panel);
+                render_table_tree(frame, registry, focus_registry, dropdown_registry, focused_id, id, flattened_nodes, node_ids, selected, *scroll_offset, column_widths, column_headers, *focused_column, *column_offset, *resizing_columns, on_select, on_event, on_focus, on_blur, on_render, area, inside_panel);
+            }
+
+            Element::Table {
+                id,
+                headers,
+                widths,
+                alignments,
+                rows,
+                selected,
+                scroll_offset,
+                focused_column,
+                sort_column,
+                sort_direction,
+                on_sort,
+                on_event,
+                on_focus,
+                on_blur,
+                on_render,
+            } => {
+                render_table(frame, registry, focus_registry, focused_id, id, headers, widths, alignments, rows, *selected, *scroll_offset, *focused_column, *sort_column, *sort_direction, on_sort, on_event, on_focus, on_blur, on_render, area, inside_panel);
             }
 
             Element::Scrollable {
@@ -395,12 +443,13 @@ impl Renderer {
                 content_height,
                 horizontal_scroll_offset,
                 content_width,
+                show_scrollbar,
                 on_navigate,
                 on_render,
                 on_focus,
                 on_blur,
             } => {
-                render_scrollable(frame, registry, focus_registry, dropdown_registry, focused_id, id, child, *scroll_offset, content_height, *horizontal_scroll_offset, content_width, on_navigate, on_render, on_focus, on_blur, area, inside_panel, Self::render_element);
+                render_scrollable(frame, registry, focus_registry, dropdown_registry, focused_id, id, child, *scroll_offset, content_height, *horizontal_scroll_offset, content_width, *show_scrollbar, on_navigate, on_render, on_focus, on_blur, area, inside_panel, Self::render_element);
             }
 
             Element::Select {
@@ -426,6 +475,7 @@ impl Renderer {
                 placeholder,
                 is_open,
                 filtered_options,
+                match_indices,
                 highlight,
                 on_input,
                 on_select,
@@ -434,7 +484,7 @@ impl Renderer {
                 on_focus,
                 on_blur,
             } => {
-                render_autocomplete(frame, registry, focus_registry, dropdown_registry, focused_id, id, &[], current_input, placeholder, *is_open, filtered_options, *highlight, on_input, on_select, on_navigate, on_event, on_focus, on_blur, area, inside_panel);
+                render_autocomplete(frame, registry, focus_registry, dropdown_registry, focused_id, id, &[], current_input, placeholder, *is_open, filtered_options, match_indices, *highlight, on_input, on_select, on_navigate, on_event, on_focus, on_blur, area, inside_panel);
             }
 
             Element::FileBrowser {
@@ -468,6 +518,14 @@ impl Renderer {
                 render_color_picker(frame, registry, focus_registry, focused_id, id, *value, *mode, state, on_event, on_focus, on_blur, area, inside_panel);
             }
 
+            Element::DatePicker { id, state, placeholder, on_event, on_focus, on_blur } => {
+                render_date_picker(frame, registry, focus_registry, focused_id, id, state, placeholder, on_event, on_focus, on_blur, area, inside_panel);
+            }
+
+            Element::NumberInput { id, state, on_event, on_focus, on_blur } => {
+                render_number_input(frame, registry, focus_registry, focused_id, id, state, on_event, on_focus, on_blur, area, inside_panel);
+            }
+
             Element::Stack { layers } => {
                 render_stack(frame, registry, focus_registry, dropdown_registry, focused_id, layers, area, inside_panel, Self::render_element, Self::estimate_element_size);
             }
@@ -477,6 +535,10 @@ impl Renderer {
                 render_progress_bar(frame, element, area, theme);
             }
 
+            Element::Tabs { id, labels, selected, on_select, on_focus, on_blur } => {
+                render_tabs(frame, registry, focus_registry, focused_id, id, labels, *selected, on_select, on_focus, on_blur, area, inside_panel);
+            }
+
             // Primitives are handled at the top of the function
             Element::None | Element::Text { .. } | Element::StyledText { .. } => {
                 unreachable!("Primitives should be handled before the match statement")
@@ -488,9 +550,9 @@ impl Renderer {
     fn calculate_content_size<Msg>(element: &Element<Msg>, max_width: u16, max_height: u16) -> (u16, u16) {
         match element {
             Element::None => (0, 0),
-            Element::Text { content, .. } => {
-                let width = (content.len() as u16).min(max_width);
-                (width, 1)
+            Element::Text { content, wrap, .. } => {
+                let (width, height) = text_size(content, *wrap, max_width);
+                (width, height.min(max_height))
             }
             Element::StyledText { line, .. } => {
                 let width = (line.width() as u16).min(max_width);
@@ -566,14 +628,19 @@ impl Renderer {
                 (max_width.min(40), height)
             }
             Element::TextInput { .. } => (max_width.min(40), 1),
-            Element::Tree { items, .. } => {
-                let height = (items.len() as u16).min(max_height);
+            Element::TextArea { rows, .. } => (max_width.min(60), (*rows).min(max_height)),
+            Element::Tree { rows, .. } => {
+                let height = (rows.len() as u16).min(max_height);
                 (max_width.min(40), height)
             }
             Element::TableTree { flattened_nodes, .. } => {
                 let height = (flattened_nodes.len() as u16 + 3).min(max_height); // +3 for header and borders
                 (max_width.min(60), height)
             }
+            Element::Table { rows, .. } => {
+                let height = (rows.len() as u16 + 1).min(max_height); // +1 for header
+                (max_width.min(60), height)
+            }
             Element::Scrollable { child, .. } => {
                 Self::calculate_content_size(child, max_width, max_height)
             }
@@ -585,6 +652,13 @@ impl Renderer {
                 (max_width, entry_count.min(max_height))
             }
             Element::ColorPicker { .. } => (max_width.min(50), 9),
+            Element::DatePicker { state, .. } => date_picker_size(state, max_width),
+            Element::NumberInput { .. } => (max_width.min(30), 1),
+            Element::ProgressBar { label, spinner_style: SpinnerStyle::Dots, indeterminate: Some(_), .. } => {
+                // Spinner character, space, label
+                let label_width = label.as_ref().map(|l| l.len() + 1).unwrap_or(0) as u16;
+                ((label_width + 1).min(max_width), 1)
+            }
             Element::ProgressBar { label, show_percentage, show_count, width, .. } => {
                 // Calculate minimum width needed
                 let label_width = label.as_ref().map(|l| l.len() + 1).unwrap_or(0) as u16;
@@ -598,6 +672,7 @@ impl Renderer {
                 let total_width = (label_width + bar_width + status_width).min(max_width);
                 (total_width, 1)
             }
+            Element::Tabs { labels, .. } => (tabs_width(labels).min(max_width), 1),
             Element::Stack { layers } => {
                 // Stack size is the max of all layers
                 let mut max_w = 0u16;
@@ -616,7 +691,7 @@ impl Renderer {
     fn estimate_element_size<Msg>(element: &Element<Msg>, container: Rect) -> (u16, u16) {
         match element {
             Element::None => (0, 0),
-            Element::Text { content, .. } => (content.len() as u16, 1),
+            Element::Text { content, wrap, .. } => text_size(content, *wrap, container.width),
             Element::StyledText { line, .. } => (line.width() as u16, 1),
             Element::Button { label, .. } => (label.len() as u16 + 4, 3),
             Element::Panel { child, width, height, .. } => {
@@ -656,6 +731,10 @@ impl Renderer {
                 // Text input: fixed height (1 line), full width
                 (container.width, 1)
             }
+            Element::TextArea { rows, .. } => {
+                // Text area: its configured number of rows, full width
+                (container.width, (*rows).min(container.height))
+            }
             Element::Autocomplete { .. } => {
                 // Autocomplete: fixed height (3 lines including borders), full width
                 (container.width, 3)
@@ -668,6 +747,8 @@ impl Renderer {
                 // Color picker: fixed size (sliders + preview + hex)
                 (container.width.min(50), 9)
             }
+            Element::DatePicker { state, .. } => date_picker_size(state, container.width),
+            Element::NumberInput { .. } => (container.width, 1),
             Element::ProgressBar { .. } => {
                 // Progress bar: full width, 1 line height
                 (container.width, 1)
@@ -745,11 +826,20 @@ impl Renderer {
                     ("  ", theme.text_primary, theme.bg_base)
                 };
 
-                // Render the option text with background, padded to fill width
-                let text_content = format!("{}{}", prefix, option_text);
-                let padding_needed = line_area.width.saturating_sub(text_content.len() as u16);
-                let option_display = format!("{}{}", text_content, " ".repeat(padding_needed as usize));
-                let option_widget = Paragraph::new(option_display)
+                // Render the option text with background, padded to fill width;
+                // characters that matched the autocomplete input use the accent color
+                let matched = dropdown.match_indices.get(idx).map(Vec::as_slice).unwrap_or(&[]);
+                let mut spans = vec![Span::raw(prefix)];
+                for (char_idx, c) in option_text.chars().enumerate() {
+                    if matched.contains(&char_idx) {
+                        spans.push(Span::styled(c.to_string(), Style::default().fg(theme.accent_primary).bold()));
+                    } else {
+                        spans.push(Span::raw(c.to_string()));
+                    }
+                }
+                let text_width = (prefix.chars().count() + option_text.chars().count()) as u16;
+                spans.push(Span::raw(" ".repeat(line_area.width.saturating_sub(text_width) as usize)));
+                let option_widget = Paragraph::new(Line::from(spans))
                     .style(Style::default().fg(fg_color).bg(bg_color));
                 frame.render_widget(option_widget, line_area);
 
@@ -89,6 +89,7 @@ pub fn render_autocomplete<Msg: Clone + Send + 'static>(
     placeholder: &Option<String>,
     is_open: bool,
     filtered_options: &[String],
+    match_indices: &[Vec<usize>],
     highlight: usize,
     on_input: &Option<fn(KeyCode) -> Msg>,
     on_select: &Option<fn(String) -> Msg>,
@@ -163,6 +164,7 @@ pub fn render_autocomplete<Msg: Clone + Send + 'static>(
         dropdown_registry.register(DropdownInfo {
             select_area: area,
             options: filtered_options.to_vec(),
+            match_indices: match_indices.to_vec(),
             selected: None,  // No checkmark for autocomplete
             highlight,
             on_select: callback,
@@ -0,0 +1,138 @@
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Style, Stylize},
+    widgets::Paragraph,
+    text::{Line, Span},
+};
+use chrono::{Datelike, Local};
+use crossterm::event::{KeyCode, KeyEvent};
+use crate::tui::element::{FocusId, DATE_PICKER_CALENDAR_HEIGHT};
+use crate::tui::command::DispatchTarget;
+use crate::tui::renderer::{InteractionRegistry, FocusRegistry, FocusableInfo};
+use crate::tui::widgets::{DatePickerEvent, DatePickerState};
+
+/// Width of the calendar grid: seven 2-char days separated by spaces
+const DATE_PICKER_WIDTH: u16 = 20;
+
+const WEEKDAYS: &str = "Mo Tu We Th Fr Sa Su";
+
+/// Size of a date picker: one line inline, the month grid when open
+pub fn date_picker_size(state: &DatePickerState, max_width: u16) -> (u16, u16) {
+    if state.is_open() {
+        (DATE_PICKER_WIDTH.min(max_width), DATE_PICKER_CALENDAR_HEIGHT)
+    } else {
+        // " YYYY-MM-DD ▾"
+        (13.min(max_width), 1)
+    }
+}
+
+/// Create on_key handler for date picker
+pub fn date_picker_on_key<Msg: Clone + Send + 'static>(
+    on_event: fn(DatePickerEvent) -> Msg,
+    state: &DatePickerState,
+) -> Box<dyn Fn(KeyEvent) -> DispatchTarget<Msg> + Send> {
+    let is_open = state.is_open();
+    let cursor = state.cursor();
+
+    Box::new(move |key_event| match key_event.code {
+        KeyCode::Enter if is_open => {
+            // Confirm the highlighted day
+            DispatchTarget::AppMsg(on_event(DatePickerEvent::Selected(cursor)))
+        }
+        KeyCode::Esc if !is_open => DispatchTarget::PassThrough,  // Let runtime handle unfocus/modal close
+        key_code => DispatchTarget::AppMsg(on_event(DatePickerEvent::Changed(key_code))),
+    })
+}
+
+/// Render DatePicker element
+pub fn render_date_picker<Msg: Clone + Send + 'static>(
+    frame: &mut Frame,
+    _registry: &mut InteractionRegistry<Msg>,
+    focus_registry: &mut FocusRegistry<Msg>,
+    focused_id: Option<&FocusId>,
+    id: &FocusId,
+    state: &DatePickerState,
+    placeholder: &Option<String>,
+    on_event: &Option<fn(DatePickerEvent) -> Msg>,
+    on_focus: &Option<Msg>,
+    on_blur: &Option<Msg>,
+    area: Rect,
+    inside_panel: bool,
+) {
+    let theme = &crate::global_runtime_config().theme;
+    let is_focused = focused_id == Some(id);
+
+    // Register in focus registry
+    if let Some(event_handler) = on_event {
+        focus_registry.register_focusable(FocusableInfo {
+            id: id.clone(),
+            rect: area,
+            on_key: date_picker_on_key(*event_handler, state),
+            on_focus: on_focus.clone(),
+            on_blur: on_blur.clone(),
+            inside_panel,
+        });
+    }
+
+    if !state.is_open() {
+        let (text, style) = match state.selected() {
+            Some(date) => (date.format("%Y-%m-%d").to_string(), Style::default().fg(theme.text_primary)),
+            None => (
+                placeholder.clone().unwrap_or_default(),
+                Style::default().fg(theme.border_primary).italic(),
+            ),
+        };
+        let arrow_color = if is_focused { theme.accent_primary } else { theme.border_primary };
+        let line = Line::from(vec![
+            Span::styled(format!(" {} ", text), style),
+            Span::styled("▾", Style::default().fg(arrow_color)),
+        ]);
+        frame.render_widget(Paragraph::new(line), area);
+        return;
+    }
+
+    let cursor = state.cursor();
+    let today = Local::now().date_naive();
+
+    let header = format!("{} {}", cursor.format("%B"), cursor.year());
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled("◀ ", Style::default().fg(theme.border_primary)),
+            Span::styled(
+                format!("{:^width$}", header, width = DATE_PICKER_WIDTH as usize - 4),
+                Style::default().fg(theme.accent_primary).bold(),
+            ),
+            Span::styled(" ▶", Style::default().fg(theme.border_primary)),
+        ]),
+        Line::from(Span::styled(WEEKDAYS, Style::default().fg(theme.text_tertiary))),
+    ];
+
+    for week in state.month_grid() {
+        let mut spans = Vec::with_capacity(14);
+        for (i, day) in week.iter().enumerate() {
+            if i > 0 {
+                spans.push(Span::raw(" "));
+            }
+            let Some(day) = day else {
+                spans.push(Span::raw("  "));
+                continue;
+            };
+
+            let mut style = Style::default().fg(theme.text_primary);
+            if *day == today {
+                style = style.fg(theme.accent_secondary).bold();
+            }
+            if Some(*day) == state.selected() {
+                style = style.fg(theme.accent_success).bold();
+            }
+            if *day == cursor {
+                style = style.fg(theme.bg_base).bg(if is_focused { theme.accent_primary } else { theme.border_primary });
+            }
+            spans.push(Span::styled(format!("{:>2}", day.day()), style));
+        }
+        lines.push(Line::from(spans));
+    }
+
+    frame.render_widget(Paragraph::new(lines), area);
+}
@@ -1,25 +1,85 @@
-use ratatui::{Frame, style::Style, widgets::{Block, Borders}, layout::{Rect, Constraint, Direction, Layout}};
-use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{Frame, style::{Modifier, Style}, text::{Line, Span}, widgets::{Block, Borders, Paragraph}, layout::{Rect, Constraint, Direction, Layout}};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use crate::tui::{Element, Theme, LayoutConstraint};
 use crate::tui::element::FocusId;
 use crate::tui::command::DispatchTarget;
+use crate::tui::clipboard;
 use crate::tui::widgets::ListEvent;
-use crate::tui::renderer::{InteractionRegistry, FocusRegistry, DropdownRegistry, FocusableInfo};
+use crate::tui::renderer::{InteractionRegistry, FocusRegistry, DropdownRegistry, FocusableInfo, ScrollHandler};
+use super::scrollbar::render_vertical_scrollbar;
 
-/// Create on_key handler for lists (navigation and activation)
+/// Filter settings and state of a list, as carried by `Element::List`
+#[derive(Clone, Default)]
+pub struct ListFilter {
+    pub filterable: bool,
+    pub query: Option<String>,
+    pub matches: Option<Vec<usize>>,
+}
+
+impl ListFilter {
+    /// Whether item `idx` should be drawn dimmed
+    fn is_dimmed(&self, idx: usize) -> bool {
+        self.matches.as_ref().is_some_and(|matches| matches.binary_search(&idx).is_err())
+    }
+}
+
+/// Create on_key handler for lists (navigation, activation, filtering and reordering)
+///
+/// `selected_text` is the selected item's text, which Ctrl+C copies.
 pub fn list_on_key<Msg: Clone + Send + 'static>(
     selected: Option<usize>,
+    item_count: usize,
     on_navigate: Option<fn(KeyCode) -> Msg>,
     on_activate: Option<fn(usize) -> Msg>,
+    on_event: Option<fn(ListEvent) -> Msg>,
+    filter: ListFilter,
+    reorderable: bool,
+    selected_text: Option<String>,
 ) -> Box<dyn Fn(KeyEvent) -> DispatchTarget<Msg> + Send> {
+    let emit = move |event: ListEvent| match on_event {
+        Some(f) => DispatchTarget::AppMsg(f(event)),
+        None => DispatchTarget::WidgetEvent(Box::new(event)),
+    };
+
     Box::new(move |key_event| match key_event.code {
+        KeyCode::Char('c') if key_event.modifiers.contains(KeyModifiers::CONTROL) => {
+            if let Some(text) = &selected_text {
+                clipboard::copy(text);
+            }
+            DispatchTarget::Handled
+        }
+        // While the query box is open, typing edits the query and Esc closes it
+        KeyCode::Char(c) if filter.query.is_some() => {
+            let query = filter.query.clone().unwrap_or_default();
+            emit(ListEvent::FilterChanged(query + &c.to_string()))
+        }
+        KeyCode::Backspace if filter.query.is_some() => {
+            let mut query = filter.query.clone().unwrap_or_default();
+            query.pop();
+            emit(ListEvent::FilterChanged(query))
+        }
+        KeyCode::Esc if filter.query.is_some() => emit(ListEvent::FilterCleared),
+        KeyCode::Char('/') if filter.filterable => emit(ListEvent::FilterChanged(String::new())),
+        // Alt+Up/Down moves the selected item, but not past either end or within a filtered view
+        KeyCode::Up | KeyCode::Down if reorderable && key_event.modifiers.contains(KeyModifiers::ALT) => {
+            let to = match (selected, key_event.code) {
+                _ if filter.query.is_some() || filter.matches.is_some() => None,
+                (Some(from), KeyCode::Up) => from.checked_sub(1),
+                (Some(from), _) => Some(from + 1).filter(|&to| to < item_count),
+                (None, _) => None,
+            };
+            match (selected, to) {
+                (Some(from), Some(to)) => emit(ListEvent::Reorder { from, to }),
+                _ => DispatchTarget::Handled,
+            }
+        }
         // Navigation keys - handled by on_navigate callback
         KeyCode::Up | KeyCode::Down | KeyCode::PageUp | KeyCode::PageDown
         | KeyCode::Home | KeyCode::End => {
             if let Some(f) = on_navigate {
                 DispatchTarget::AppMsg(f(key_event.code))
             } else {
-                DispatchTarget::WidgetEvent(Box::new(ListEvent::Navigate(key_event.code)))
+                emit(ListEvent::Navigate(key_event.code))
             }
         }
         // Enter activates selected item
@@ -37,6 +97,39 @@ pub fn list_on_key<Msg: Clone + Send + 'static>(
     })
 }
 
+/// Create mouse wheel handler for lists
+///
+/// Emits `ListEvent::Scroll` when the list handles its own events; lists that only
+/// take `on_navigate` move the selection one row instead. Does nothing at the ends.
+pub fn list_on_scroll<Msg: Clone + Send + 'static>(
+    selected: Option<usize>,
+    scroll_offset: usize,
+    item_count: usize,
+    visible_height: usize,
+    on_navigate: Option<fn(KeyCode) -> Msg>,
+    on_event: Option<fn(ListEvent) -> Msg>,
+) -> ScrollHandler<Msg> {
+    let max_offset = item_count.saturating_sub(visible_height);
+
+    Box::new(move |delta| {
+        if on_event.is_none() && let Some(f) = on_navigate {
+            return match selected {
+                Some(0) if delta < 0 => DispatchTarget::Handled,
+                Some(sel) if delta > 0 && sel + 1 >= item_count => DispatchTarget::Handled,
+                _ => DispatchTarget::AppMsg(f(if delta < 0 { KeyCode::Up } else { KeyCode::Down })),
+            };
+        }
+
+        if (delta < 0 && scroll_offset == 0) || (delta > 0 && scroll_offset >= max_offset) {
+            return DispatchTarget::Handled;
+        }
+        match on_event {
+            Some(f) => DispatchTarget::AppMsg(f(ListEvent::Scroll(delta))),
+            None => DispatchTarget::WidgetEvent(Box::new(ListEvent::Scroll(delta))),
+        }
+    })
+}
+
 /// Create on_key handler for file browser (treats Enter as navigation)
 pub fn file_browser_on_key<Msg: Clone + Send + 'static>(
     on_navigate: Option<fn(KeyCode) -> Msg>,
@@ -104,6 +197,14 @@ pub fn render_file_browser<Msg: Clone + Send + 'static>(
     let start_idx = scroll_offset;
     let end_idx = (start_idx + visible_height).min(items.len());
 
+    // Keep the last column free for the scrollbar when items overflow
+    let show_scrollbar = items.len() > visible_height && area.width > 1;
+    let items_area = if show_scrollbar {
+        Rect { width: area.width - 1, ..area }
+    } else {
+        area
+    };
+
     // Create layout for visible items
     let visible_items: Vec<_> = items[start_idx..end_idx]
         .iter()
@@ -122,7 +223,7 @@ pub fn render_file_browser<Msg: Clone + Send + 'static>(
         let chunks = Layout::default()
             .direction(Direction::Vertical)
             .constraints(constraints)
-            .split(area);
+            .split(items_area);
 
         // Render each visible item
         for ((_, child), chunk) in visible_items.iter().zip(chunks.iter()) {
@@ -131,31 +232,14 @@ pub fn render_file_browser<Msg: Clone + Send + 'static>(
     }
 
     // Render scrollbar if needed
-    if items.len() > visible_height {
+    if show_scrollbar {
         let scrollbar_area = Rect {
             x: area.x + area.width - 1,
             y: area.y,
             width: 1,
             height: area.height,
         };
-
-        let scrollbar_position = if items.len() > visible_height {
-            (scroll_offset as f32 / (items.len() - visible_height) as f32 * (area.height - 1) as f32) as u16
-        } else {
-            0
-        };
-
-        // Render scrollbar thumb
-        if scrollbar_position < area.height {
-            let thumb_area = Rect {
-                x: scrollbar_area.x,
-                y: scrollbar_area.y + scrollbar_position,
-                width: 1,
-                height: 1,
-            };
-            let thumb = Block::default().style(Style::default().fg(theme.border_primary));
-            frame.render_widget(thumb, thumb_area);
-        }
+        render_vertical_scrollbar(frame, scrollbar_area, items.len(), visible_height, scroll_offset, theme);
     }
 
     // Only render focus border if NOT inside a panel
@@ -183,14 +267,29 @@ pub fn render_list<Msg: Clone + Send + 'static>(
     on_select: &Option<fn(usize) -> Msg>,
     on_activate: &Option<fn(usize) -> Msg>,
     on_navigate: &Option<fn(KeyCode) -> Msg>,
+    on_event: &Option<fn(ListEvent) -> Msg>,
     on_focus: &Option<Msg>,
     on_blur: &Option<Msg>,
     on_render: &Option<fn(usize) -> Msg>,
+    filter: &ListFilter,
+    show_scrollbar: bool,
+    reorderable: bool,
     area: Rect,
     inside_panel: bool,
     render_fn: impl Fn(&mut Frame, &mut InteractionRegistry<Msg>, &mut FocusRegistry<Msg>, &mut DropdownRegistry<Msg>, Option<&FocusId>, &Element<Msg>, Rect, bool),
 ) {
     let theme = &crate::global_runtime_config().theme;
+
+    // The open query box takes the last row
+    let full_area = area;
+    let (area, query_area) = match &filter.query {
+        Some(_) if area.height > 1 => (
+            Rect { height: area.height - 1, ..area },
+            Some(Rect { y: area.y + area.height - 1, height: 1, ..area }),
+        ),
+        _ => (area, None),
+    };
+
     // Call on_render with actual viewport height from renderer
     if let Some(render_fn) = on_render {
         registry.add_render_message(render_fn(area.height as usize));
@@ -199,8 +298,17 @@ pub fn render_list<Msg: Clone + Send + 'static>(
     // Register in focus registry
     focus_registry.register_focusable(FocusableInfo {
         id: id.clone(),
-        rect: area,
-        on_key: list_on_key(selected, on_navigate.clone(), on_activate.clone()),
+        rect: full_area,
+        on_key: list_on_key(
+            selected,
+            items.len(),
+            on_navigate.clone(),
+            on_activate.clone(),
+            on_event.clone(),
+            filter.clone(),
+            reorderable,
+            selected.and_then(|idx| items.get(idx)).map(|item| item.plain_text()),
+        ),
         on_focus: on_focus.clone(),
         on_blur: on_blur.clone(),
         inside_panel,
@@ -212,10 +320,26 @@ pub fn render_list<Msg: Clone + Send + 'static>(
     // Calculate visible height
     let visible_height = area.height as usize;
 
+    if items.len() > visible_height {
+        registry.register_scroll(
+            area,
+            id.clone(),
+            list_on_scroll(selected, scroll_offset, items.len(), visible_height, *on_navigate, *on_event),
+        );
+    }
+
     // Virtual scrolling: only render visible items
     let start_idx = scroll_offset;
     let end_idx = (start_idx + visible_height).min(items.len());
 
+    // Keep the last column free for the scrollbar when items overflow
+    let show_scrollbar = show_scrollbar && items.len() > visible_height && area.width > 1;
+    let items_area = if show_scrollbar {
+        Rect { width: area.width - 1, ..area }
+    } else {
+        area
+    };
+
     // Create layout for visible items
     let visible_items: Vec<_> = items[start_idx..end_idx]
         .iter()
@@ -234,11 +358,18 @@ pub fn render_list<Msg: Clone + Send + 'static>(
         let chunks = Layout::default()
             .direction(Direction::Vertical)
             .constraints(constraints)
-            .split(area);
+            .split(items_area);
 
-        // Render each visible item
-        for ((_, child), chunk) in visible_items.iter().zip(chunks.iter()) {
+        // Render each visible item, dimming those the filter doesn't match
+        for (idx, ((_, child), chunk)) in visible_items.iter().zip(chunks.iter()).enumerate() {
             render_fn(frame, registry, focus_registry, dropdown_registry, focused_id, child, *chunk, inside_panel);
+            if filter.is_dimmed(start_idx + idx) {
+                frame.buffer_mut().set_style(*chunk, Style::default().fg(theme.border_primary).add_modifier(Modifier::DIM));
+            }
+            // In a focused reorderable list the selected item is "grabbed": Alt+Up/Down moves it
+            if reorderable && is_focused && selected == Some(start_idx + idx) {
+                frame.buffer_mut().set_style(*chunk, Style::default().fg(theme.accent_secondary).add_modifier(Modifier::BOLD));
+            }
         }
 
         // Register click handlers for list items
@@ -253,31 +384,28 @@ pub fn render_list<Msg: Clone + Send + 'static>(
     }
 
     // Render scrollbar if needed
-    if items.len() > visible_height {
+    if show_scrollbar {
         let scrollbar_area = Rect {
             x: area.x + area.width - 1,
             y: area.y,
             width: 1,
             height: area.height,
         };
+        render_vertical_scrollbar(frame, scrollbar_area, items.len(), visible_height, scroll_offset, theme);
+    }
 
-        let scrollbar_position = if items.len() > visible_height {
-            (scroll_offset as f32 / (items.len() - visible_height) as f32 * (area.height - 1) as f32) as u16
-        } else {
-            0
+    if let (Some(query_area), Some(query)) = (query_area, &filter.query) {
+        let count = match &filter.matches {
+            Some(matches) => format!("  {}/{}", matches.len(), items.len()),
+            None => String::new(),
         };
-
-        // Render scrollbar thumb
-        if scrollbar_position < area.height {
-            let thumb_area = Rect {
-                x: scrollbar_area.x,
-                y: scrollbar_area.y + scrollbar_position,
-                width: 1,
-                height: 1,
-            };
-            let thumb = Block::default().style(Style::default().fg(theme.border_primary));
-            frame.render_widget(thumb, thumb_area);
-        }
+        let query_line = Line::from(vec![
+            Span::styled("/", Style::default().fg(theme.accent_primary)),
+            Span::styled(query.clone(), Style::default().fg(theme.text_primary)),
+            Span::styled(" ", Style::default().bg(theme.border_primary)),
+            Span::styled(count, Style::default().fg(theme.text_tertiary)),
+        ]);
+        frame.render_widget(Paragraph::new(query_line), query_area);
     }
 
     // Only render focus border if NOT inside a panel
@@ -286,6 +414,6 @@ pub fn render_list<Msg: Clone + Send + 'static>(
         let border = Block::default()
             .borders(Borders::ALL)
             .border_style(Style::default().fg(theme.accent_primary));
-        frame.render_widget(border, area);
+        frame.render_widget(border, full_area);
     }
 }
@@ -5,27 +5,40 @@ pub mod button;
 pub mod list;
 pub mod tree;
 pub mod table_tree;
+pub mod table;
 pub mod text_input;
+pub mod text_area;
 pub mod scrollable;
+pub mod scrollbar;
 pub mod select;
 pub mod autocomplete;
 pub mod panel;
 pub mod stack;
 pub mod color_picker;
+pub mod date_picker;
+pub mod number_input;
 pub mod progress_bar;
+pub mod tabs;
+pub mod toast;
 
 // Re-export all widget renderers
-pub use primitives::{render_primitive, is_primitive};
+pub use primitives::{render_primitive, is_primitive, text_size};
 pub use layout::{calculate_constraints, render_column, render_row, render_container};
 pub use button::render_button;
-pub use list::{render_list, render_file_browser};
+pub use list::{render_list, render_file_browser, ListFilter};
 pub use tree::render_tree;
 pub use table_tree::render_table_tree;
+pub use table::render_table;
 pub use text_input::render_text_input;
+pub use text_area::render_text_area;
 pub use scrollable::render_scrollable;
 pub use select::render_select;
 pub use autocomplete::render_autocomplete;
 pub use panel::render_panel;
 pub use stack::{render_stack, render_dim_overlay, calculate_layer_position};
 pub use color_picker::render_color_picker;
+pub use date_picker::{render_date_picker, date_picker_size};
+pub use number_input::render_number_input;
 pub use progress_bar::render_progress_bar;
+pub use tabs::{render_tabs, tabs_width};
+pub use toast::render_toasts;
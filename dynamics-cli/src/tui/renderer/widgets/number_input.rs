@@ -0,0 +1,83 @@
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Style, Stylize},
+    widgets::Paragraph,
+    text::{Line, Span},
+};
+use crossterm::event::{KeyCode, KeyEvent};
+use crate::tui::element::FocusId;
+use crate::tui::command::DispatchTarget;
+use crate::tui::renderer::{InteractionRegistry, FocusRegistry, FocusableInfo};
+use crate::tui::widgets::{NumberInputEvent, NumberInputState};
+
+/// Create on_key handler for number input
+///
+/// Stepping keys are resolved against the rendered state, so the app receives
+/// the new value as `Changed`; typing is passed on as `Key`.
+pub fn number_input_on_key<Msg: Clone + Send + 'static>(
+    on_event: fn(NumberInputEvent) -> Msg,
+    state: &NumberInputState,
+) -> Box<dyn Fn(KeyEvent) -> DispatchTarget<Msg> + Send> {
+    let state = state.clone();
+
+    Box::new(move |key_event| match key_event.code {
+        KeyCode::Enter => DispatchTarget::AppMsg(on_event(NumberInputEvent::Submit)),
+        KeyCode::Esc => DispatchTarget::PassThrough,  // Let runtime handle unfocus/modal close
+        key_code => match state.navigation_target(key_code) {
+            Some(value) => DispatchTarget::AppMsg(on_event(NumberInputEvent::Changed(value))),
+            None => DispatchTarget::AppMsg(on_event(NumberInputEvent::Key(key_code))),
+        },
+    })
+}
+
+/// Render NumberInput element
+pub fn render_number_input<Msg: Clone + Send + 'static>(
+    frame: &mut Frame,
+    _registry: &mut InteractionRegistry<Msg>,
+    focus_registry: &mut FocusRegistry<Msg>,
+    focused_id: Option<&FocusId>,
+    id: &FocusId,
+    state: &NumberInputState,
+    on_event: &Option<fn(NumberInputEvent) -> Msg>,
+    on_focus: &Option<Msg>,
+    on_blur: &Option<Msg>,
+    area: Rect,
+    inside_panel: bool,
+) {
+    let theme = &crate::global_runtime_config().theme;
+    let is_focused = focused_id == Some(id);
+
+    // Register in focus registry
+    if let Some(event_handler) = on_event {
+        focus_registry.register_focusable(FocusableInfo {
+            id: id.clone(),
+            rect: area,
+            on_key: number_input_on_key(*event_handler, state),
+            on_focus: on_focus.clone(),
+            on_blur: on_blur.clone(),
+            inside_panel,
+        });
+    }
+
+    // Out-of-range text is shown in the error color until it is committed (and clamped)
+    let value_color = if state.is_valid() { theme.text_primary } else { theme.accent_error };
+    let arrow_color = if is_focused { theme.accent_primary } else { theme.border_primary };
+    let mut spans = vec![
+        Span::styled(format!(" {}", state.text()), Style::default().fg(value_color)),
+        Span::styled(if is_focused { "│ " } else { "  " }, Style::default().fg(theme.accent_primary)),
+        Span::styled("▴▾", Style::default().fg(arrow_color)),
+    ];
+
+    let range = match (state.min(), state.max()) {
+        (Some(min), Some(max)) => format!("  {}–{}", min, max),
+        (Some(min), None) => format!("  ≥ {}", min),
+        (None, Some(max)) => format!("  ≤ {}", max),
+        (None, None) => String::new(),
+    };
+    if !range.is_empty() {
+        spans.push(Span::styled(range, Style::default().fg(theme.text_tertiary).italic()));
+    }
+
+    frame.render_widget(Paragraph::new(Line::from(spans)), area);
+}
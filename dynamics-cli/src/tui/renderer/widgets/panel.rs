@@ -8,9 +8,9 @@ use crate::tui::renderer::{InteractionRegistry, FocusRegistry, DropdownRegistry}
 pub fn element_contains_focused_non_button<Msg>(element: &Element<Msg>, focused_id: &FocusId) -> bool {
     match element {
         // Check all focusable widgets EXCEPT buttons
-        Element::TextInput { id, .. } | Element::Select { id, .. } | Element::Autocomplete { id, .. }
-        | Element::List { id, .. } | Element::Tree { id, .. } | Element::TableTree { id, .. }
-        | Element::Scrollable { id, .. } | Element::FileBrowser { id, .. } => id == focused_id,
+        Element::TextInput { id, .. } | Element::TextArea { id, .. } | Element::Select { id, .. } | Element::Autocomplete { id, .. }
+        | Element::List { id, .. } | Element::Tree { id, .. } | Element::TableTree { id, .. } | Element::Table { id, .. }
+        | Element::Scrollable { id, .. } | Element::FileBrowser { id, .. } | Element::DatePicker { id, .. } | Element::NumberInput { id, .. } => id == focused_id,
         // Recurse through containers
         Element::Column { items, .. } | Element::Row { items, .. } => {
             items.iter().any(|(_, child)| element_contains_focused_non_button(child, focused_id))
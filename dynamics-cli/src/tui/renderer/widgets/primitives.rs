@@ -1,5 +1,73 @@
-use ratatui::{Frame, style::Style, widgets::Paragraph, layout::Rect};
-use crate::tui::{Element, Theme};
+use ratatui::{Frame, style::Style, widgets::Paragraph, layout::Rect, text::Line};
+use crate::tui::{Element, Theme, WrapMode};
+
+/// Shorten text to `width` characters, ending with `…` when anything was cut
+pub fn ellipsize(content: &str, width: usize) -> String {
+    if content.chars().count() <= width {
+        return content.to_string();
+    }
+    let mut shortened: String = content.chars().take(width.saturating_sub(1)).collect();
+    if width > 0 {
+        shortened.push('…');
+    }
+    shortened
+}
+
+/// Soft-wrap text into lines of at most `width` characters
+///
+/// Breaks at spaces where possible; words longer than a line are split.
+/// Explicit newlines are kept.
+pub fn wrap_text(content: &str, width: usize) -> Vec<String> {
+    let width = width.max(1);
+    let mut lines = Vec::new();
+
+    for paragraph in content.split('\n') {
+        let mut line = String::new();
+        let mut line_len = 0;
+
+        for word in paragraph.split(' ') {
+            let mut word: Vec<char> = word.chars().collect();
+
+            // Start a new line if the word doesn't fit after a space
+            if line_len > 0 && line_len + 1 + word.len() > width {
+                lines.push(std::mem::take(&mut line));
+                line_len = 0;
+            }
+            if line_len > 0 {
+                line.push(' ');
+                line_len += 1;
+            }
+
+            // Hard-break words longer than a whole line
+            while line_len + word.len() > width {
+                let rest = word.split_off(width - line_len);
+                line.extend(word);
+                lines.push(std::mem::take(&mut line));
+                line_len = 0;
+                word = rest;
+            }
+            line_len += word.len();
+            line.extend(word);
+        }
+        lines.push(line);
+    }
+
+    lines
+}
+
+/// Size (width, height) a text element needs within `max_width`
+pub fn text_size(content: &str, wrap: WrapMode, max_width: u16) -> (u16, u16) {
+    match wrap {
+        WrapMode::Wrap => {
+            let lines = wrap_text(content, max_width as usize);
+            let width = lines.iter().map(|line| line.chars().count()).max().unwrap_or(0);
+            (width as u16, lines.len() as u16)
+        }
+        WrapMode::Truncate | WrapMode::Ellipsis => {
+            ((content.chars().count() as u16).min(max_width), 1)
+        }
+    }
+}
 
 /// Render primitive elements (None, Text, StyledText)
 pub fn render_primitive<Msg: Clone + Send + 'static>(
@@ -12,11 +80,19 @@ pub fn render_primitive<Msg: Clone + Send + 'static>(
     match element {
         Element::None => {}
 
-        Element::Text { content, style } => {
+        Element::Text { content, style, wrap } => {
             let default_style = Style::default().fg(theme.text_primary);
-            let widget = Paragraph::new(content.as_str())
-                .style(style.unwrap_or(default_style));
-            frame.render_widget(widget, area);
+            let widget = match wrap {
+                WrapMode::Truncate => Paragraph::new(content.as_str()),
+                WrapMode::Ellipsis => Paragraph::new(ellipsize(content, area.width as usize)),
+                WrapMode::Wrap => Paragraph::new(
+                    wrap_text(content, area.width as usize)
+                        .into_iter()
+                        .map(Line::from)
+                        .collect::<Vec<_>>(),
+                ),
+            };
+            frame.render_widget(widget.style(style.unwrap_or(default_style)), area);
         }
 
         Element::StyledText { line, background } => {
@@ -35,3 +111,21 @@ pub fn render_primitive<Msg: Clone + Send + 'static>(
 pub fn is_primitive<Msg>(element: &Element<Msg>) -> bool {
     matches!(element, Element::None | Element::Text { .. } | Element::StyledText { .. })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wrap_text_breaks_at_words_and_splits_long_words() {
+        assert_eq!(wrap_text("the quick brown fox", 10), vec!["the quick", "brown fox"]);
+        assert_eq!(wrap_text("abcdefghij xy", 4), vec!["abcd", "efgh", "ij", "xy"]);
+        assert_eq!(wrap_text("a\n\nb", 10), vec!["a", "", "b"]);
+    }
+
+    #[test]
+    fn test_ellipsize_only_when_cut() {
+        assert_eq!(ellipsize("hello", 5), "hello");
+        assert_eq!(ellipsize("hello world", 6), "hello…");
+    }
+}
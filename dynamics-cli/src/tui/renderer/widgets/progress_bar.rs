@@ -1,5 +1,7 @@
 use ratatui::{Frame, layout::Rect, text::{Line, Span}, widgets::Paragraph, style::Style, prelude::Stylize};
-use crate::tui::{Element, Theme};
+use crate::tui::{Element, SpinnerStyle, Theme};
+
+const SPINNER_FRAMES: &[&str] = &["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
 
 /// Render a progress bar widget
 pub fn render_progress_bar<Msg: Clone + Send + 'static>(
@@ -9,6 +11,16 @@ pub fn render_progress_bar<Msg: Clone + Send + 'static>(
     theme: &Theme,
 ) {
     let (current, total, label, show_percentage, show_count, width) = match element {
+        Element::ProgressBar {
+            indeterminate: Some(tick),
+            label,
+            width,
+            spinner_style,
+            ..
+        } => {
+            render_indeterminate(frame, area, theme, *tick, label, *width, *spinner_style);
+            return;
+        }
         Element::ProgressBar {
             current,
             total,
@@ -16,6 +28,7 @@ pub fn render_progress_bar<Msg: Clone + Send + 'static>(
             show_percentage,
             show_count,
             width,
+            ..
         } => (*current, *total, label, *show_percentage, *show_count, *width),
         _ => unreachable!("render_progress_bar called with non-ProgressBar element"),
     };
@@ -105,3 +118,55 @@ pub fn render_progress_bar<Msg: Clone + Send + 'static>(
     let line = Line::from(spans);
     frame.render_widget(Paragraph::new(line), area);
 }
+
+/// Render an animated bar for work with an unknown total
+fn render_indeterminate(
+    frame: &mut Frame,
+    area: Rect,
+    theme: &Theme,
+    tick: usize,
+    label: &Option<String>,
+    width: Option<u16>,
+    style: SpinnerStyle,
+) {
+    let mut spans = Vec::new();
+
+    if style == SpinnerStyle::Dots {
+        spans.push(Span::styled(
+            SPINNER_FRAMES[tick % SPINNER_FRAMES.len()],
+            Style::default().fg(theme.accent_primary),
+        ));
+        if let Some(label_text) = label {
+            spans.push(Span::styled(
+                format!(" {}", label_text),
+                Style::default().fg(theme.text_secondary),
+            ));
+        }
+        frame.render_widget(Paragraph::new(Line::from(spans)), area);
+        return;
+    }
+
+    if let Some(label_text) = label {
+        spans.push(Span::styled(
+            format!("{} ", label_text),
+            Style::default().fg(theme.text_secondary),
+        ));
+    }
+
+    let label_width = label.as_ref().map(|l| l.len() + 1).unwrap_or(0);
+    let bar_width = width
+        .map(|w| w as usize)
+        .unwrap_or_else(|| (area.width as usize).saturating_sub(label_width));
+
+    // A block a quarter of the track wide slides in from the left and out to the right
+    let block = (bar_width / 4).max(1);
+    let offset = (tick % (bar_width + block)) as isize - block as isize;
+    let start = offset.max(0) as usize;
+    let end = ((offset + block as isize).max(0) as usize).min(bar_width);
+
+    spans.push(Span::styled("░".repeat(start), Style::default().fg(theme.border_primary)));
+    spans.push(Span::styled("█".repeat(end - start), Style::default().fg(theme.accent_primary)));
+    spans.push(Span::styled("░".repeat(bar_width - end), Style::default().fg(theme.border_primary)));
+
+    frame.render_widget(Paragraph::new(Line::from(spans)), area);
+}
@@ -1,9 +1,10 @@
-use ratatui::{Frame, style::Style, widgets::{Block, Borders}, layout::{Rect, Constraint, Direction, Layout}};
+use ratatui::{Frame, style::Style, widgets::{Block, Borders}, layout::Rect};
 use crossterm::event::{KeyCode, KeyEvent};
-use crate::tui::{Element, Theme, LayoutConstraint};
+use crate::tui::{Element, LayoutConstraint};
 use crate::tui::element::FocusId;
 use crate::tui::command::DispatchTarget;
 use crate::tui::renderer::{InteractionRegistry, FocusRegistry, DropdownRegistry, FocusableInfo};
+use super::scrollbar::{render_vertical_scrollbar, render_horizontal_scrollbar};
 
 /// Apply horizontal scroll offset to an element by modifying its text content
 fn apply_horizontal_offset<Msg: Clone>(element: &Element<Msg>, offset: usize) -> Element<Msg> {
@@ -12,7 +13,7 @@ fn apply_horizontal_offset<Msg: Clone>(element: &Element<Msg>, offset: usize) ->
     }
 
     match element {
-        Element::Text { content, style } => {
+        Element::Text { content, style, wrap } => {
             let trimmed = if content.len() > offset {
                 content.chars().skip(offset).collect()
             } else {
@@ -21,6 +22,7 @@ fn apply_horizontal_offset<Msg: Clone>(element: &Element<Msg>, offset: usize) ->
             Element::Text {
                 content: trimmed,
                 style: *style,
+                wrap: *wrap,
             }
         }
         Element::StyledText { line, background } => {
@@ -154,6 +156,7 @@ pub fn render_scrollable<Msg: Clone + Send + 'static>(
     content_height: &Option<usize>,
     horizontal_scroll_offset: usize,
     content_width: &Option<usize>,
+    show_scrollbar: bool,
     on_navigate: &Option<fn(KeyCode) -> Msg>,
     on_render: &Option<fn(usize, usize, usize, usize) -> Msg>,
     on_focus: &Option<Msg>,
@@ -219,8 +222,8 @@ pub fn render_scrollable<Msg: Clone + Send + 'static>(
     }
 
     // Reserve space for scrollbars if needed
-    let needs_vertical_scrollbar = actual_content_height > viewport_height;
-    let needs_horizontal_scrollbar = actual_content_width > viewport_width;
+    let needs_vertical_scrollbar = show_scrollbar && actual_content_height > viewport_height;
+    let needs_horizontal_scrollbar = show_scrollbar && actual_content_width > viewport_width;
 
     let available_width = if needs_vertical_scrollbar {
         area.width.saturating_sub(1)
@@ -318,24 +321,7 @@ pub fn render_scrollable<Msg: Clone + Send + 'static>(
             width: 1,
             height: available_height,
         };
-
-        let scrollbar_position = if max_vertical_scroll > 0 {
-            (clamped_vertical_scroll as f32 / max_vertical_scroll as f32 * (available_height - 1) as f32) as u16
-        } else {
-            0
-        };
-
-        // Render scrollbar thumb
-        if scrollbar_position < available_height {
-            let thumb_area = Rect {
-                x: scrollbar_area.x,
-                y: scrollbar_area.y + scrollbar_position,
-                width: 1,
-                height: 1,
-            };
-            let thumb = Block::default().style(Style::default().fg(theme.border_primary));
-            frame.render_widget(thumb, thumb_area);
-        }
+        render_vertical_scrollbar(frame, scrollbar_area, actual_content_height, available_height as usize, clamped_vertical_scroll, theme);
     }
 
     // Render horizontal scrollbar if needed
@@ -346,24 +332,7 @@ pub fn render_scrollable<Msg: Clone + Send + 'static>(
             width: available_width,
             height: 1,
         };
-
-        let scrollbar_position = if max_horizontal_scroll > 0 {
-            (clamped_horizontal_scroll as f32 / max_horizontal_scroll as f32 * (available_width - 1) as f32) as u16
-        } else {
-            0
-        };
-
-        // Render scrollbar thumb
-        if scrollbar_position < available_width {
-            let thumb_area = Rect {
-                x: scrollbar_area.x + scrollbar_position,
-                y: scrollbar_area.y,
-                width: 1,
-                height: 1,
-            };
-            let thumb = Block::default().style(Style::default().fg(theme.border_primary));
-            frame.render_widget(thumb, thumb_area);
-        }
+        render_horizontal_scrollbar(frame, scrollbar_area, actual_content_width, available_width as usize, clamped_horizontal_scroll, theme);
     }
 
     // Only render focus border if NOT inside a panel
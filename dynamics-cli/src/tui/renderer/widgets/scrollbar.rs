@@ -0,0 +1,80 @@
+use ratatui::{Frame, style::Style, layout::Rect};
+use crate::tui::Theme;
+
+/// Thumb position and length along a scrollbar track
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Thumb {
+    pub start: u16,
+    pub len: u16,
+}
+
+/// Size the thumb to the visible share of the content and place it by scroll offset
+pub fn thumb(track_len: u16, content_len: usize, viewport_len: usize, offset: usize) -> Thumb {
+    if track_len == 0 || content_len <= viewport_len {
+        return Thumb { start: 0, len: track_len };
+    }
+
+    let track = track_len as usize;
+    let len = (track * viewport_len).div_ceil(content_len).clamp(1, track);
+    let max_offset = content_len - viewport_len;
+    let start = (track - len) * offset.min(max_offset) / max_offset;
+
+    Thumb { start: start as u16, len: len as u16 }
+}
+
+/// Draw a vertical scrollbar down the given one-column area
+pub fn render_vertical_scrollbar(
+    frame: &mut Frame,
+    area: Rect,
+    content_height: usize,
+    viewport_height: usize,
+    offset: usize,
+    theme: &Theme,
+) {
+    let thumb = thumb(area.height, content_height, viewport_height, offset);
+    let buffer = frame.buffer_mut();
+    for y in 0..area.height {
+        let (symbol, color) = if (thumb.start..thumb.start + thumb.len).contains(&y) {
+            ("█", theme.text_secondary)
+        } else {
+            ("│", theme.border_tertiary)
+        };
+        buffer.set_string(area.x, area.y + y, symbol, Style::default().fg(color));
+    }
+}
+
+/// Draw a horizontal scrollbar along the given one-row area
+pub fn render_horizontal_scrollbar(
+    frame: &mut Frame,
+    area: Rect,
+    content_width: usize,
+    viewport_width: usize,
+    offset: usize,
+    theme: &Theme,
+) {
+    let thumb = thumb(area.width, content_width, viewport_width, offset);
+    let buffer = frame.buffer_mut();
+    for x in 0..area.width {
+        let (symbol, color) = if (thumb.start..thumb.start + thumb.len).contains(&x) {
+            ("█", theme.text_secondary)
+        } else {
+            ("─", theme.border_tertiary)
+        };
+        buffer.set_string(area.x + x, area.y, symbol, Style::default().fg(color));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_thumb_is_proportional_and_reaches_track_end() {
+        assert_eq!(thumb(10, 40, 10, 0), Thumb { start: 0, len: 3 });
+        assert_eq!(thumb(10, 40, 10, 15), Thumb { start: 3, len: 3 });
+        assert_eq!(thumb(10, 40, 10, 30), Thumb { start: 7, len: 3 });
+        // Offsets past the end clamp, and huge content keeps a visible thumb
+        assert_eq!(thumb(10, 40, 10, 99), Thumb { start: 7, len: 3 });
+        assert_eq!(thumb(10, 10_000, 10, 0).len, 1);
+    }
+}
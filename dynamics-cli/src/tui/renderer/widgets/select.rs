@@ -176,6 +176,7 @@ pub fn render_select<Msg: Clone + Send + 'static>(
         dropdown_registry.register(DropdownInfo {
             select_area: area,
             options: options.to_vec(),
+            match_indices: Vec::new(),
             selected: Some(selected),
             highlight,
             on_select: callback,
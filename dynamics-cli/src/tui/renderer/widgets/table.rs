@@ -0,0 +1,243 @@
+use ratatui::{
+    Frame,
+    style::Style,
+    widgets::{Block, Row, Table, Cell},
+    layout::{Alignment, Constraint, Flex, Layout, Rect},
+    text::Line,
+    prelude::Stylize,
+};
+use crossterm::event::{KeyCode, KeyEvent};
+use crate::tui::element::FocusId;
+use crate::tui::command::DispatchTarget;
+use crate::tui::widgets::{SortDirection, TableEvent};
+use crate::tui::renderer::{InteractionRegistry, FocusRegistry, FocusableInfo, ScrollHandler};
+
+/// Create on_key handler for tables
+///
+/// Up/Down/PageUp/PageDown/Home/End move the row selection, Left/Right move the
+/// focused column, `s` sorts by the focused column and Enter chooses the row.
+pub fn table_on_key_event<Msg: Clone + Send + 'static>(
+    on_event: Option<fn(TableEvent) -> Msg>,
+    on_sort: Option<fn(usize) -> Msg>,
+    selected: Option<usize>,
+    focused_column: usize,
+    column_count: usize,
+) -> Box<dyn Fn(KeyEvent) -> DispatchTarget<Msg> + Send> {
+    Box::new(move |key_event| {
+        let event = match key_event.code {
+            KeyCode::Char('s') => {
+                return match on_sort {
+                    Some(f) => DispatchTarget::AppMsg(f(focused_column)),
+                    None => DispatchTarget::PassThrough,
+                };
+            }
+            KeyCode::Up | KeyCode::Down | KeyCode::PageUp | KeyCode::PageDown
+            | KeyCode::Home | KeyCode::End => TableEvent::Navigate(key_event.code),
+            KeyCode::Left => TableEvent::FocusColumn(focused_column.saturating_sub(1)),
+            KeyCode::Right => TableEvent::FocusColumn((focused_column + 1).min(column_count.saturating_sub(1))),
+            KeyCode::Enter => match selected {
+                Some(row) => TableEvent::Select(row),
+                None => return DispatchTarget::PassThrough,
+            },
+            // Unhandled key - pass through to global subscriptions
+            _ => return DispatchTarget::PassThrough,
+        };
+        match on_event {
+            Some(f) => DispatchTarget::AppMsg(f(event)),
+            None => DispatchTarget::PassThrough,
+        }
+    })
+}
+
+/// Mouse wheel handler: scroll until the first or last row is in view
+fn table_on_scroll<Msg: Clone + Send + 'static>(
+    on_event: fn(TableEvent) -> Msg,
+    scroll_offset: usize,
+    row_count: usize,
+    visible_height: usize,
+) -> ScrollHandler<Msg> {
+    let max_offset = row_count.saturating_sub(visible_height);
+    Box::new(move |delta| {
+        if (delta < 0 && scroll_offset == 0) || (delta > 0 && scroll_offset >= max_offset) {
+            return DispatchTarget::Handled;
+        }
+        DispatchTarget::AppMsg(on_event(TableEvent::Scroll(delta)))
+    })
+}
+
+/// Header label with the sort arrow on the sorted column
+fn header_label(header: &str, column: usize, sort_column: Option<usize>, sort_direction: SortDirection) -> String {
+    if sort_column == Some(column) {
+        format!("{} {}", header, sort_direction.arrow())
+    } else {
+        header.to_string()
+    }
+}
+
+/// Render Table element
+#[allow(clippy::too_many_arguments)]
+pub fn render_table<Msg: Clone + Send + 'static>(
+    frame: &mut Frame,
+    registry: &mut InteractionRegistry<Msg>,
+    focus_registry: &mut FocusRegistry<Msg>,
+    focused_id: Option<&FocusId>,
+    id: &FocusId,
+    headers: &[String],
+    widths: &[Constraint],
+    alignments: &[Alignment],
+    rows: &[Vec<String>],
+    selected: Option<usize>,
+    scroll_offset: usize,
+    focused_column: usize,
+    sort_column: Option<usize>,
+    sort_direction: SortDirection,
+    on_sort: &Option<fn(usize) -> Msg>,
+    on_event: &Option<fn(TableEvent) -> Msg>,
+    on_focus: &Option<Msg>,
+    on_blur: &Option<Msg>,
+    on_render: &Option<fn(usize) -> Msg>,
+    area: Rect,
+    inside_panel: bool,
+) {
+    let theme = &crate::global_runtime_config().theme;
+    let is_focused = focused_id == Some(id);
+
+    // Rows below the header
+    let visible_height = area.height.saturating_sub(1) as usize;
+    if let Some(render_fn) = on_render {
+        registry.add_render_message(render_fn(visible_height));
+    }
+
+    if on_event.is_some() || on_sort.is_some() {
+        focus_registry.register_focusable(FocusableInfo {
+            id: id.clone(),
+            rect: area,
+            on_key: table_on_key_event(*on_event, *on_sort, selected, focused_column, headers.len()),
+            on_focus: on_focus.clone(),
+            on_blur: on_blur.clone(),
+            inside_panel,
+        });
+    }
+
+    if let Some(event_fn) = on_event {
+        if rows.len() > visible_height {
+            registry.register_scroll(area, id.clone(), table_on_scroll(*event_fn, scroll_offset, rows.len(), visible_height));
+        }
+    }
+
+    let alignment = |column: usize| alignments.get(column).copied().unwrap_or(Alignment::Left);
+
+    // Header: sort arrow on the sorted column, focused column underlined
+    let header_cells: Vec<Cell> = headers
+        .iter()
+        .enumerate()
+        .map(|(i, header)| {
+            let label = header_label(header, i, sort_column, sort_direction);
+            let cell = Cell::from(Line::from(label).alignment(alignment(i)));
+            if is_focused && i == focused_column {
+                cell.style(Style::default().underlined())
+            } else {
+                cell
+            }
+        })
+        .collect();
+    let header = Row::new(header_cells)
+        .style(Style::default().fg(theme.accent_primary).bold())
+        .height(1);
+
+    // Virtual scrolling: only render visible rows
+    let start_idx = scroll_offset.min(rows.len());
+    let end_idx = (start_idx + visible_height).min(rows.len());
+    let body: Vec<Row> = rows[start_idx..end_idx]
+        .iter()
+        .enumerate()
+        .map(|(offset, cells)| {
+            let row = Row::new(cells.iter().enumerate().map(|(i, text)| {
+                Cell::from(Line::from(text.as_str()).alignment(alignment(i)))
+            }));
+            if selected == Some(start_idx + offset) {
+                let style = Style::default().bg(theme.bg_surface);
+                row.style(if is_focused { style.fg(theme.accent_primary) } else { style })
+            } else {
+                row.style(Style::default().fg(theme.text_primary))
+            }
+        })
+        .collect();
+
+    // Same layout the table uses, so header clicks line up with the columns
+    let column_areas = Layout::horizontal(widths.to_vec())
+        .flex(Flex::Start)
+        .spacing(1)
+        .split(area);
+
+    let table = Table::new(body, widths.to_vec())
+        .header(header)
+        .flex(Flex::Start)
+        .column_spacing(1);
+    frame.render_widget(table, area);
+
+    // Clicking a header sorts by it
+    if let Some(sort_fn) = on_sort {
+        for (column, column_area) in column_areas.iter().enumerate().take(headers.len()) {
+            registry.register_click(Rect { height: 1, ..*column_area }, sort_fn(column));
+        }
+    }
+
+    // Clicking a row chooses it
+    if let Some(event_fn) = on_event {
+        for (offset, _) in rows[start_idx..end_idx].iter().enumerate() {
+            let row_area = Rect {
+                x: area.x,
+                y: area.y + 1 + offset as u16,
+                width: area.width,
+                height: 1,
+            };
+            registry.register_click(row_area, event_fn(TableEvent::Select(start_idx + offset)));
+        }
+    }
+
+    // Scrollbar thumb when rows overflow
+    if rows.len() > visible_height && visible_height > 0 {
+        let track_height = area.height.saturating_sub(1);
+        let max_offset = rows.len() - visible_height;
+        let position = (scroll_offset.min(max_offset) as f32 / max_offset as f32
+            * track_height.saturating_sub(1) as f32) as u16;
+        let thumb_area = Rect {
+            x: area.x + area.width.saturating_sub(1),
+            y: area.y + 1 + position,
+            width: 1,
+            height: 1,
+        };
+        frame.render_widget(Block::default().style(Style::default().bg(theme.border_primary)), thumb_area);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::KeyModifiers;
+
+    #[derive(Clone, Debug, PartialEq)]
+    enum Msg {
+        Sort(usize),
+        Table(String),
+    }
+
+    fn describe(event: TableEvent) -> Msg {
+        Msg::Table(format!("{:?}", event))
+    }
+
+    #[test]
+    fn test_keys_sort_focused_column_and_navigate() {
+        let handler = table_on_key_event(Some(describe), Some(Msg::Sort), Some(3), 1, 3);
+        let key = |code| KeyEvent::new(code, KeyModifiers::empty());
+
+        assert!(matches!(handler(key(KeyCode::Char('s'))), DispatchTarget::AppMsg(Msg::Sort(1))));
+        assert!(matches!(handler(key(KeyCode::Right)), DispatchTarget::AppMsg(Msg::Table(e)) if e == "FocusColumn(2)"));
+        assert!(matches!(handler(key(KeyCode::Enter)), DispatchTarget::AppMsg(Msg::Table(e)) if e == "Select(3)"));
+        assert!(matches!(handler(key(KeyCode::Char('x'))), DispatchTarget::PassThrough));
+
+        assert_eq!(header_label("Name", 0, Some(0), SortDirection::Descending), "Name ▼");
+        assert_eq!(header_label("Name", 1, Some(0), SortDirection::Descending), "Name");
+    }
+}
@@ -5,30 +5,166 @@ use ratatui::{
     layout::{Rect, Constraint},
     prelude::Stylize,
 };
-use crossterm::event::{KeyCode, KeyEvent};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use crate::tui::{Theme, LayoutConstraint};
 use crate::tui::element::FocusId;
 use crate::tui::command::DispatchTarget;
+use crate::tui::clipboard;
 use crate::tui::widgets::{TreeEvent, FlatTableNode};
 use crate::tui::renderer::{InteractionRegistry, FocusRegistry, DropdownRegistry, FocusableInfo};
+use super::tree::tree_on_scroll;
+
+/// Columns step this many cells per resize key press
+const RESIZE_STEP: u16 = 2;
+/// Narrowest a column can be resized to
+const MIN_COLUMN_WIDTH: u16 = 3;
+/// Narrowest a Fill column is drawn when the table overflows
+const MIN_FILL_WIDTH: u16 = 8;
+
+/// Rendered column layout, captured so key handlers can scroll and resize
+#[derive(Clone)]
+pub struct ColumnGeometry {
+    pub constraints: Vec<Constraint>,
+    pub widths: Vec<u16>,
+    pub area_width: u16,
+    pub focused: usize,
+    pub first_visible: usize,
+    pub resizing: bool,
+}
+
+impl ColumnGeometry {
+    fn new(constraints: &[Constraint], area_width: u16, focused: usize, offset: usize, resizing: bool) -> Self {
+        let widths = natural_widths(constraints, area_width);
+        let focused = focused.min(widths.len().saturating_sub(1));
+        let first_visible = first_visible_column(&widths, area_width, offset, focused);
+        Self { constraints: constraints.to_vec(), widths, area_width, focused, first_visible, resizing }
+    }
+
+    /// Whether the columns are wider than the table
+    fn overflows(&self) -> bool {
+        span_width(&self.widths) > self.area_width
+    }
+
+    fn focus(&self, column: usize) -> TreeEvent {
+        TreeEvent::FocusColumn {
+            column,
+            first_visible: first_visible_column(&self.widths, self.area_width, self.first_visible, column),
+        }
+    }
+
+    fn resize(&self, grow: bool) -> TreeEvent {
+        let current = self.widths.get(self.focused).copied().unwrap_or(MIN_COLUMN_WIDTH);
+        let width = if grow {
+            current.saturating_add(RESIZE_STEP)
+        } else {
+            current.saturating_sub(RESIZE_STEP).max(MIN_COLUMN_WIDTH)
+        };
+        let mut constraints = self.constraints.clone();
+        if let Some(constraint) = constraints.get_mut(self.focused) {
+            *constraint = Constraint::Length(width);
+        }
+        TreeEvent::ResizeColumns(constraints)
+    }
+}
+
+/// Width each column wants, resolving relative constraints against the table width
+fn natural_widths(constraints: &[Constraint], area_width: u16) -> Vec<u16> {
+    let fixed: Vec<Option<u16>> = constraints
+        .iter()
+        .map(|constraint| match *constraint {
+            Constraint::Length(n) | Constraint::Min(n) | Constraint::Max(n) => Some(n),
+            Constraint::Percentage(p) => Some((area_width as u32 * p as u32 / 100) as u16),
+            Constraint::Ratio(a, b) => Some((area_width as u64 * a as u64 / b.max(1) as u64) as u16),
+            Constraint::Fill(_) => None,
+        })
+        .collect();
+
+    // Fill columns share what is left after fixed columns and the 1-cell column spacing
+    let spacing = constraints.len().saturating_sub(1) as u16;
+    let used: u16 = fixed.iter().flatten().fold(spacing, |sum, w| sum.saturating_add(*w));
+    let leftover = area_width.saturating_sub(used) as u32;
+    let fill_weight: u32 = constraints
+        .iter()
+        .map(|c| if let Constraint::Fill(w) = c { *w as u32 } else { 0 })
+        .sum();
+
+    constraints
+        .iter()
+        .zip(fixed)
+        .map(|(constraint, width)| match (constraint, width) {
+            (_, Some(width)) => width,
+            (Constraint::Fill(w), None) => ((leftover * *w as u32 / fill_weight.max(1)) as u16).max(MIN_FILL_WIDTH),
+            _ => MIN_FILL_WIDTH,
+        })
+        .collect()
+}
+
+/// Total width of columns drawn side by side with 1-cell spacing
+fn span_width(widths: &[u16]) -> u16 {
+    widths
+        .iter()
+        .fold(widths.len().saturating_sub(1) as u16, |sum, w| sum.saturating_add(*w))
+}
+
+/// First column to draw so that `focused` is on screen, moving as little as possible from `offset`
+fn first_visible_column(widths: &[u16], area_width: u16, offset: usize, focused: usize) -> usize {
+    let mut first = offset.min(focused);
+    while first < focused && span_width(&widths[first..=focused]) > area_width {
+        first += 1;
+    }
+    first
+}
 
 /// Create on_key handler for table trees (reuse tree event handling)
+///
+/// Shift+Left/Right moves between columns, Ctrl+R toggles resize mode, in which
+/// Left/Right narrow or widen the focused column until Enter or Esc.
+///
+/// `selected_row` holds the selected row's cells, which Ctrl+C copies tab-separated.
 pub fn table_tree_on_key_event<Msg: Clone + Send + 'static>(
     on_event: fn(TreeEvent) -> Msg,
+    columns: ColumnGeometry,
+    selected_row: Option<Vec<String>>,
 ) -> Box<dyn Fn(KeyEvent) -> DispatchTarget<Msg> + Send> {
-    Box::new(move |key_event| match key_event.code {
-        // Navigation keys
-        KeyCode::Up | KeyCode::Down | KeyCode::PageUp | KeyCode::PageDown
-        | KeyCode::Home | KeyCode::End | KeyCode::Left | KeyCode::Right => {
-            DispatchTarget::AppMsg(on_event(TreeEvent::Navigate(key_event.code)))
+    Box::new(move |key_event| {
+        let shift = key_event.modifiers.contains(KeyModifiers::SHIFT);
+        let ctrl = key_event.modifiers.contains(KeyModifiers::CONTROL);
+        let last_column = columns.widths.len().saturating_sub(1);
+
+        if ctrl && key_event.code == KeyCode::Char('c') {
+            if let Some(cells) = &selected_row {
+                clipboard::copy(&cells.join("\t"));
+            }
+            return DispatchTarget::Handled;
         }
-        // Enter toggles expansion
-        KeyCode::Enter => {
-            DispatchTarget::AppMsg(on_event(TreeEvent::Toggle))
+
+        let event = match key_event.code {
+            KeyCode::Char('r') if ctrl => Some(TreeEvent::ToggleColumnResize),
+            KeyCode::Enter | KeyCode::Esc if columns.resizing => Some(TreeEvent::ToggleColumnResize),
+            KeyCode::Left if columns.resizing => Some(columns.resize(false)),
+            KeyCode::Right if columns.resizing => Some(columns.resize(true)),
+            KeyCode::Left if shift => Some(columns.focus(columns.focused.saturating_sub(1))),
+            KeyCode::Right if shift => Some(columns.focus((columns.focused + 1).min(last_column))),
+            _ => None,
+        };
+        if let Some(event) = event {
+            return DispatchTarget::AppMsg(on_event(event));
         }
-        _ => {
-            // Unhandled key - pass through to global subscriptions
-            DispatchTarget::PassThrough
+
+        match key_event.code {
+            // Navigation keys
+            KeyCode::Up | KeyCode::Down | KeyCode::PageUp | KeyCode::PageDown
+            | KeyCode::Home | KeyCode::End | KeyCode::Left | KeyCode::Right => {
+                DispatchTarget::AppMsg(on_event(TreeEvent::Navigate(key_event.code)))
+            }
+            // Enter toggles expansion
+            KeyCode::Enter => {
+                DispatchTarget::AppMsg(on_event(TreeEvent::Toggle))
+            }
+            _ => {
+                // Unhandled key - pass through to global subscriptions
+                DispatchTarget::PassThrough
+            }
         }
     })
 }
@@ -49,6 +185,9 @@ pub fn render_table_tree<Msg: Clone + Send + 'static>(
     scroll_offset: usize,
     column_widths: &[Constraint],
     column_headers: &[String],
+    focused_column: usize,
+    column_offset: usize,
+    resizing_columns: bool,
     on_select: &Option<fn(String) -> Msg>,
     on_event: &Option<fn(TreeEvent) -> Msg>,
     on_focus: &Option<Msg>,
@@ -65,9 +204,17 @@ pub fn render_table_tree<Msg: Clone + Send + 'static>(
         registry.add_render_message(render_fn(content_height));
     }
 
+    let columns = ColumnGeometry::new(column_widths, area.width, focused_column, column_offset, resizing_columns);
+
     // Register in focus registry
     if let Some(event_fn) = on_event {
-        let on_key_handler = table_tree_on_key_event(*event_fn);
+        let selected_row = selected.as_ref().and_then(|selected_id| {
+            flattened_nodes
+                .iter()
+                .find(|node| &node.id == selected_id)
+                .map(|node| node.columns.clone())
+        });
+        let on_key_handler = table_tree_on_key_event(*event_fn, columns.clone(), selected_row);
         focus_registry.register_focusable(FocusableInfo {
             id: id.clone(),
             rect: area,
@@ -84,6 +231,32 @@ pub fn render_table_tree<Msg: Clone + Send + 'static>(
     // Calculate visible height (subtract header only, no borders)
     let visible_height = area.height.saturating_sub(1) as usize;
 
+    if on_event.is_some() && flattened_nodes.len() > visible_height {
+        registry.register_scroll(
+            area,
+            id.clone(),
+            tree_on_scroll(None, scroll_offset, flattened_nodes.len(), visible_height, None, *on_event),
+        );
+    }
+
+    // Horizontal scrolling: when the columns don't fit, draw them at their natural
+    // widths starting from the first visible one, so header and body stay aligned
+    let scrolled = columns.overflows() || columns.first_visible > 0;
+    let (visible_columns, constraints): (std::ops::Range<usize>, Vec<Constraint>) = if scrolled {
+        let mut remaining = area.width;
+        let mut constraints = Vec::new();
+        for width in &columns.widths[columns.first_visible..] {
+            if remaining == 0 {
+                break;
+            }
+            constraints.push(Constraint::Length((*width).min(remaining)));
+            remaining = remaining.saturating_sub(width + 1);
+        }
+        (columns.first_visible..columns.first_visible + constraints.len(), constraints)
+    } else {
+        (0..column_widths.len(), column_widths.to_vec())
+    };
+
     // Virtual scrolling: only render visible rows
     let start_idx = scroll_offset;
     let end_idx = (start_idx + visible_height).min(flattened_nodes.len());
@@ -114,8 +287,13 @@ pub fn render_table_tree<Msg: Clone + Send + 'static>(
                 columns[0] = format!("{}{}{}", indent, expansion_indicator, columns[0]);
             }
 
-            // Convert to cells
-            let cells: Vec<Cell> = columns.into_iter().map(Cell::from).collect();
+            // Convert the visible columns to cells
+            let cells: Vec<Cell> = columns
+                .into_iter()
+                .skip(visible_columns.start)
+                .take(visible_columns.len())
+                .map(Cell::from)
+                .collect();
 
             // Apply selection highlighting
             let mut row = Row::new(cells);
@@ -127,17 +305,31 @@ pub fn render_table_tree<Msg: Clone + Send + 'static>(
         })
         .collect();
 
-    // Create header row
+    // Create header row, marking the focused column while scrolling or resizing
     let header_cells: Vec<Cell> = column_headers
         .iter()
-        .map(|h| Cell::from(h.as_str()))
+        .enumerate()
+        .skip(visible_columns.start)
+        .take(visible_columns.len())
+        .map(|(i, h)| {
+            let cell = Cell::from(h.as_str());
+            if !is_focused || i != columns.focused {
+                cell
+            } else if columns.resizing {
+                cell.style(Style::default().fg(theme.accent_warning).reversed())
+            } else if scrolled {
+                cell.style(Style::default().underlined())
+            } else {
+                cell
+            }
+        })
         .collect();
     let header = Row::new(header_cells)
         .style(Style::default().fg(theme.accent_primary).bold())
         .height(1);
 
     // Create table widget without borders (parent panel handles that)
-    let table = Table::new(rows, column_widths)
+    let table = Table::new(rows, constraints)
         .header(header);
 
     frame.render_widget(table, area);
@@ -191,3 +383,41 @@ pub fn render_table_tree<Msg: Clone + Send + 'static>(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_natural_widths_give_fill_columns_the_leftover() {
+        let constraints = [Constraint::Length(10), Constraint::Fill(1), Constraint::Percentage(20)];
+
+        assert_eq!(natural_widths(&constraints, 100), vec![10, 68, 20]);
+        // Too narrow: Fill keeps a usable minimum instead of collapsing
+        assert_eq!(natural_widths(&constraints, 20), vec![10, MIN_FILL_WIDTH, 4]);
+    }
+
+    #[test]
+    fn test_first_visible_column_scrolls_just_enough() {
+        let widths = [10, 10, 10, 10];
+
+        // 21 cells fit two columns plus spacing
+        assert_eq!(first_visible_column(&widths, 21, 0, 1), 0);
+        assert_eq!(first_visible_column(&widths, 21, 0, 3), 2);
+        // Moving back left scrolls back to the focused column
+        assert_eq!(first_visible_column(&widths, 21, 2, 1), 1);
+    }
+
+    #[test]
+    fn test_resize_only_changes_focused_column() {
+        let constraints = [Constraint::Length(10), Constraint::Fill(1)];
+        let columns = ColumnGeometry::new(&constraints, 40, 1, 0, true);
+
+        let TreeEvent::ResizeColumns(widths) = columns.resize(true) else { panic!("expected resize") };
+        assert_eq!(widths, vec![Constraint::Length(10), Constraint::Length(31)]);
+
+        let narrow = ColumnGeometry::new(&[Constraint::Length(4)], 40, 0, 0, true);
+        let TreeEvent::ResizeColumns(widths) = narrow.resize(false) else { panic!("expected resize") };
+        assert_eq!(widths, vec![Constraint::Length(MIN_COLUMN_WIDTH)]);
+    }
+}
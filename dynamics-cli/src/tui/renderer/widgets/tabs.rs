@@ -0,0 +1,144 @@
+use ratatui::{Frame, style::{Modifier, Style}, widgets::Paragraph, layout::Rect, text::{Line, Span}};
+use crossterm::event::{KeyCode, KeyEvent};
+use crate::tui::element::FocusId;
+use crate::tui::command::DispatchTarget;
+use crate::tui::renderer::{InteractionRegistry, FocusRegistry, FocusableInfo};
+
+/// Gap between tabs in the strip
+const TAB_GAP: u16 = 2;
+
+/// Text drawn for a tab; the first nine get their number key as a prefix
+pub fn tab_title(index: usize, label: &str) -> String {
+    if index < 9 {
+        format!("[{}] {}", index + 1, label)
+    } else {
+        label.to_string()
+    }
+}
+
+/// Width of the whole tab strip
+pub fn tabs_width(labels: &[String]) -> u16 {
+    let titles: u16 = labels
+        .iter()
+        .enumerate()
+        .map(|(i, label)| tab_title(i, label).chars().count() as u16)
+        .sum();
+    titles + TAB_GAP * labels.len().saturating_sub(1) as u16
+}
+
+/// Create on_key handler for tabs (Left/Right cycle, number keys jump)
+pub fn tabs_on_key<Msg: Clone + Send + 'static>(
+    selected: usize,
+    count: usize,
+    on_select: fn(usize) -> Msg,
+) -> Box<dyn Fn(KeyEvent) -> DispatchTarget<Msg> + Send> {
+    Box::new(move |key_event| {
+        if count == 0 {
+            return DispatchTarget::PassThrough;
+        }
+        match key_event.code {
+            KeyCode::Left => DispatchTarget::AppMsg(on_select((selected + count - 1) % count)),
+            KeyCode::Right => DispatchTarget::AppMsg(on_select((selected + 1) % count)),
+            KeyCode::Char(c @ '1'..='9') if (c as usize - '1' as usize) < count => {
+                DispatchTarget::AppMsg(on_select(c as usize - '1' as usize))
+            }
+            // Unhandled key - pass through to global subscriptions
+            _ => DispatchTarget::PassThrough,
+        }
+    })
+}
+
+/// Render Tabs element
+pub fn render_tabs<Msg: Clone + Send + 'static>(
+    frame: &mut Frame,
+    registry: &mut InteractionRegistry<Msg>,
+    focus_registry: &mut FocusRegistry<Msg>,
+    focused_id: Option<&FocusId>,
+    id: &FocusId,
+    labels: &[String],
+    selected: usize,
+    on_select: &Option<fn(usize) -> Msg>,
+    on_focus: &Option<Msg>,
+    on_blur: &Option<Msg>,
+    area: Rect,
+    inside_panel: bool,
+) {
+    let theme = &crate::global_runtime_config().theme;
+
+    // Tabs without a callback are display-only
+    if let Some(select_fn) = on_select {
+        focus_registry.register_focusable(FocusableInfo {
+            id: id.clone(),
+            rect: area,
+            on_key: tabs_on_key(selected, labels.len(), *select_fn),
+            on_focus: on_focus.clone(),
+            on_blur: on_blur.clone(),
+            inside_panel,
+        });
+    }
+
+    let is_focused = focused_id == Some(id);
+    let active_style = if is_focused {
+        Style::default().fg(theme.accent_primary).add_modifier(Modifier::BOLD | Modifier::UNDERLINED)
+    } else {
+        Style::default().fg(theme.accent_primary).add_modifier(Modifier::ITALIC)
+    };
+    let inactive_style = Style::default().fg(theme.text_secondary);
+
+    let mut spans = Vec::new();
+    let mut x = area.x;
+    let right_edge = area.x + area.width;
+
+    for (i, label) in labels.iter().enumerate() {
+        if i > 0 {
+            spans.push(Span::raw(" ".repeat(TAB_GAP as usize)));
+            x = x.saturating_add(TAB_GAP);
+        }
+
+        let title = tab_title(i, label);
+        let width = title.chars().count() as u16;
+
+        // Register click area for the visible part of the tab
+        if let Some(select_fn) = on_select {
+            if x < right_edge {
+                let tab_area = Rect { x, y: area.y, width: width.min(right_edge - x), height: 1 };
+                registry.register_click(tab_area, select_fn(i));
+            }
+        }
+
+        let style = if i == selected { active_style } else { inactive_style };
+        spans.push(Span::styled(title, style));
+        x = x.saturating_add(width);
+    }
+
+    frame.render_widget(Paragraph::new(Line::from(spans)), area);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::KeyModifiers;
+
+    fn press(handler: &(dyn Fn(KeyEvent) -> DispatchTarget<usize> + Send), code: KeyCode) -> Option<usize> {
+        match handler(KeyEvent::new(code, KeyModifiers::NONE)) {
+            DispatchTarget::AppMsg(index) => Some(index),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_arrows_wrap_and_number_keys_jump() {
+        let handler = tabs_on_key(0, 3, |i| i);
+
+        assert_eq!(press(&*handler, KeyCode::Left), Some(2));
+        assert_eq!(press(&*handler, KeyCode::Right), Some(1));
+        assert_eq!(press(&*handler, KeyCode::Char('3')), Some(2));
+        assert_eq!(press(&*handler, KeyCode::Char('4')), None);
+    }
+
+    #[test]
+    fn test_tabs_width_counts_titles_and_gaps() {
+        let labels = vec!["Fields".to_string(), "Views".to_string()];
+        assert_eq!(tabs_width(&labels), 10 + 2 + 9);
+    }
+}
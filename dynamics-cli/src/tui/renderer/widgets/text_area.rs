@@ -0,0 +1,138 @@
+use ratatui::{Frame, style::{Style, Stylize}, widgets::Paragraph, layout::Rect, text::{Line, Span}};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use crate::tui::element::FocusId;
+use crate::tui::command::DispatchTarget;
+use crate::tui::renderer::{InteractionRegistry, FocusRegistry, FocusableInfo};
+use crate::tui::widgets::TextInputEvent;
+use crate::tui::clipboard;
+use crate::tui::widgets::text_area::{wrap_lines, WrappedRow};
+
+/// Create on_key handler for text areas (Enter is a newline, Ctrl+S/Ctrl+Enter submit)
+///
+/// Ctrl+C copies the whole value and Ctrl+V pastes at the cursor.
+pub fn text_area_on_key<Msg: Clone + Send + 'static>(
+    on_event: Option<fn(TextInputEvent) -> Msg>,
+    value: String,
+) -> Box<dyn Fn(KeyEvent) -> DispatchTarget<Msg> + Send> {
+    Box::new(move |key_event| {
+        let ctrl = key_event.modifiers.contains(KeyModifiers::CONTROL);
+        let event = match key_event.code {
+            KeyCode::Esc => return DispatchTarget::PassThrough,  // Let runtime handle unfocus/modal close
+            KeyCode::Enter | KeyCode::Char('s') if ctrl => TextInputEvent::Submit,
+            KeyCode::Char('c') if ctrl => {
+                clipboard::copy(&value);
+                return DispatchTarget::Handled;
+            }
+            KeyCode::Char('v') if ctrl => match clipboard::paste() {
+                Some(text) => TextInputEvent::Paste(text),
+                None => return DispatchTarget::Handled,
+            },
+            code => TextInputEvent::Changed(code),
+        };
+
+        match on_event {
+            Some(f) => DispatchTarget::AppMsg(f(event)),
+            // No handler - use WidgetEvent for auto-dispatch
+            None => DispatchTarget::WidgetEvent(Box::new(event)),
+        }
+    })
+}
+
+/// Render TextArea element
+pub fn render_text_area<Msg: Clone + Send + 'static>(
+    frame: &mut Frame,
+    _registry: &mut InteractionRegistry<Msg>,
+    focus_registry: &mut FocusRegistry<Msg>,
+    focused_id: Option<&FocusId>,
+    id: &FocusId,
+    value: &str,
+    cursor_row: usize,
+    cursor_col: usize,
+    scroll_offset: usize,
+    placeholder: &Option<String>,
+    on_event: &Option<fn(TextInputEvent) -> Msg>,
+    on_focus: &Option<Msg>,
+    on_blur: &Option<Msg>,
+    area: Rect,
+    inside_panel: bool,
+) {
+    let theme = &crate::global_runtime_config().theme;
+
+    focus_registry.register_focusable(FocusableInfo {
+        id: id.clone(),
+        rect: area,
+        on_key: text_area_on_key(*on_event, value.to_string()),
+        on_focus: on_focus.clone(),
+        on_blur: on_blur.clone(),
+        inside_panel,
+    });
+
+    let is_focused = focused_id == Some(id);
+
+    if value.is_empty() && !is_focused {
+        let placeholder_text = format!(" {}", placeholder.as_deref().unwrap_or(""));
+        let placeholder_style = Style::default().fg(theme.border_primary).italic();
+        frame.render_widget(Paragraph::new(placeholder_text).style(placeholder_style), area);
+        return;
+    }
+
+    // Leave one column of padding on each side, like TextInput
+    let visible_width = (area.width.saturating_sub(2) as usize).max(1);
+    let visible_height = area.height as usize;
+    let mut rows = wrap_lines(value, visible_width);
+
+    // Row holding the cursor; a cursor just past a full row wraps onto a new one
+    let mut cursor_at = rows
+        .iter()
+        .rposition(|row| row.line == cursor_row && row.start_col <= cursor_col)
+        .map(|i| (i, cursor_col - rows[i].start_col))
+        .unwrap_or((0, 0));
+    if cursor_at.1 >= visible_width {
+        rows.insert(cursor_at.0 + 1, WrappedRow {
+            line: cursor_row,
+            start_col: cursor_col,
+            text: String::new(),
+        });
+        cursor_at = (cursor_at.0 + 1, 0);
+    }
+
+    // Start at the first row of the scrolled-to line, then follow the cursor down
+    let mut first_row = rows.iter().position(|row| row.line >= scroll_offset).unwrap_or(0);
+    if cursor_at.0 < first_row {
+        first_row = cursor_at.0;
+    } else if cursor_at.0 >= first_row + visible_height {
+        first_row = cursor_at.0 + 1 - visible_height;
+    }
+
+    let text_style = Style::default().fg(theme.text_primary);
+    let cursor_style = Style::default()
+        .fg(theme.text_primary)
+        .bg(theme.border_primary);
+
+    let lines: Vec<Line> = rows
+        .iter()
+        .enumerate()
+        .skip(first_row)
+        .take(visible_height)
+        .map(|(i, row)| {
+            if !is_focused || i != cursor_at.0 {
+                return Line::from(Span::styled(format!(" {}", row.text), text_style));
+            }
+
+            let chars: Vec<char> = row.text.chars().collect();
+            let col = cursor_at.1.min(chars.len());
+            let before: String = chars[..col].iter().collect();
+            let cursor_char = chars.get(col).map(|c| c.to_string()).unwrap_or_else(|| " ".to_string());
+            let after: String = chars.get(col + 1..).map(|rest| rest.iter().collect()).unwrap_or_default();
+
+            Line::from(vec![
+                Span::raw(" "),
+                Span::styled(before, text_style),
+                Span::styled(cursor_char, cursor_style),
+                Span::styled(after, text_style),
+            ])
+        })
+        .collect();
+
+    frame.render_widget(Paragraph::new(lines), area);
+}
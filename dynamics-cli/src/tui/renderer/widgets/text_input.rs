@@ -1,20 +1,72 @@
 use ratatui::{Frame, style::{Style, Stylize}, widgets::Paragraph, layout::Rect, text::{Line, Span}};
-use crossterm::event::{KeyCode, KeyEvent};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use crate::tui::{Element, Theme};
 use crate::tui::element::FocusId;
 use crate::tui::command::DispatchTarget;
 use crate::tui::renderer::{InteractionRegistry, FocusRegistry, FocusableInfo};
 use crate::tui::widgets::TextInputEvent;
+use crate::tui::clipboard;
+
+/// Handle clipboard and selection keys, returning None for every other key
+///
+/// Ctrl+C copies `copy_text` (the selection or whole value; None for masked inputs).
+/// Ctrl+V, Ctrl+A and Shift+Left/Right/Home/End become events passed to `emit`.
+fn text_input_special_key<Msg>(
+    key_event: &KeyEvent,
+    copy_text: &Option<String>,
+    emit: impl FnOnce(TextInputEvent) -> DispatchTarget<Msg>,
+) -> Option<DispatchTarget<Msg>> {
+    let ctrl = key_event.modifiers.contains(KeyModifiers::CONTROL);
+    let shift = key_event.modifiers.contains(KeyModifiers::SHIFT);
+    match key_event.code {
+        KeyCode::Char('c') if ctrl => {
+            if let Some(text) = copy_text {
+                clipboard::copy(text);
+            }
+            Some(DispatchTarget::Handled)
+        }
+        KeyCode::Char('v') if ctrl => Some(match clipboard::paste() {
+            Some(text) => emit(TextInputEvent::Paste(text)),
+            None => DispatchTarget::Handled,
+        }),
+        KeyCode::Char('a') if ctrl => Some(emit(TextInputEvent::SelectAll)),
+        KeyCode::Left | KeyCode::Right | KeyCode::Home | KeyCode::End if shift => {
+            Some(emit(TextInputEvent::Select(key_event.code)))
+        }
+        _ => None,
+    }
+}
 
 /// Create on_key handler for text inputs (all keys pass to on_change, Enter also fires on_submit)
+///
+/// `copy_text` is what Ctrl+C copies (None for masked inputs). Pasting and selecting
+/// need the widget event path; with an on_change handler, Shift+arrows just move the cursor.
 pub fn text_input_on_key<Msg: Clone + Send + 'static>(
     on_change: Option<fn(KeyCode) -> Msg>,
     on_submit: Option<Msg>,
+    copy_text: Option<String>,
 ) -> Box<dyn Fn(KeyEvent) -> DispatchTarget<Msg> + Send> {
-    Box::new(move |key_event| match key_event.code {
+    Box::new(move |key_event| {
+        if let Some(target) = text_input_special_key(&key_event, &copy_text, |event| match (on_change, event) {
+            (Some(f), TextInputEvent::Select(code)) => DispatchTarget::AppMsg(f(code)),
+            (Some(_), _) => DispatchTarget::Handled,
+            (None, event) => DispatchTarget::WidgetEvent(Box::new(event)),
+        }) {
+            return target;
+        }
+        text_input_key(key_event, on_change, on_submit.clone())
+    })
+}
+
+fn text_input_key<Msg>(
+    key_event: KeyEvent,
+    on_change: Option<fn(KeyCode) -> Msg>,
+    on_submit: Option<Msg>,
+) -> DispatchTarget<Msg> {
+    match key_event.code {
         KeyCode::Enter => {
             // Enter fires on_submit (app handles whether to also send on_change)
-            if let Some(msg) = on_submit.clone() {
+            if let Some(msg) = on_submit {
                 DispatchTarget::AppMsg(msg)
             } else {
                 // No handler - use WidgetEvent for auto-dispatch
@@ -31,17 +83,25 @@ pub fn text_input_on_key<Msg: Clone + Send + 'static>(
                 DispatchTarget::WidgetEvent(Box::new(TextInputEvent::Changed(key_event.code)))
             }
         }
-    })
+    }
 }
 
 /// Create on_key handler for text inputs using unified event pattern
 pub fn text_input_on_key_event<Msg: Clone + Send + 'static>(
     on_event: fn(TextInputEvent) -> Msg,
+    copy_text: Option<String>,
 ) -> Box<dyn Fn(KeyEvent) -> DispatchTarget<Msg> + Send> {
-    Box::new(move |key_event| match key_event.code {
-        KeyCode::Enter => DispatchTarget::AppMsg(on_event(TextInputEvent::Submit)),
-        KeyCode::Esc => DispatchTarget::PassThrough,  // Let runtime handle unfocus/modal close
-        _ => DispatchTarget::AppMsg(on_event(TextInputEvent::Changed(key_event.code))),
+    Box::new(move |key_event| {
+        if let Some(target) = text_input_special_key(&key_event, &copy_text, |event| {
+            DispatchTarget::AppMsg(on_event(event))
+        }) {
+            return target;
+        }
+        match key_event.code {
+            KeyCode::Enter => DispatchTarget::AppMsg(on_event(TextInputEvent::Submit)),
+            KeyCode::Esc => DispatchTarget::PassThrough,  // Let runtime handle unfocus/modal close
+            _ => DispatchTarget::AppMsg(on_event(TextInputEvent::Changed(key_event.code))),
+        }
     })
 }
 
@@ -56,6 +116,7 @@ pub fn render_text_input<Msg: Clone + Send + 'static>(
     value: &str,
     cursor_pos: usize,
     scroll_offset: usize,
+    selection: Option<(usize, usize)>,
     placeholder: &Option<String>,
     max_length: &Option<usize>,
     masked: bool,
@@ -69,10 +130,14 @@ pub fn render_text_input<Msg: Clone + Send + 'static>(
 ) {
     let theme = &crate::global_runtime_config().theme;
     // Choose handler based on which callback is provided
+    let copy_text = (!masked).then(|| match selection {
+        Some((start, end)) => value.chars().skip(start).take(end - start).collect(),
+        None => value.to_string(),
+    });
     let on_key = if let Some(event_handler) = on_event {
-        text_input_on_key_event(*event_handler)
+        text_input_on_key_event(*event_handler, copy_text)
     } else {
-        text_input_on_key(on_change.clone(), on_submit.clone())
+        text_input_on_key(on_change.clone(), on_submit.clone(), copy_text)
     };
 
     // Register in focus registry
@@ -116,35 +181,43 @@ pub fn render_text_input<Msg: Clone + Send + 'static>(
         let placeholder_style = Style::default().fg(theme.border_primary).italic();
         Paragraph::new(placeholder_text).style(placeholder_style)
     } else if is_focused && cursor_in_visible <= visible_text.len() {
-        // Show text with block cursor
+        // Show text with block cursor and highlighted selection
         let chars: Vec<char> = visible_text.chars().collect();
 
-        // Split into: before cursor, at cursor, after cursor
-        let before: String = chars[..cursor_in_visible].iter().collect();
-        let cursor_char = if cursor_in_visible < chars.len() {
-            chars[cursor_in_visible].to_string()
-        } else {
-            " ".to_string()  // Cursor at end - use space
-        };
-        let after: String = if cursor_in_visible < chars.len() {
-            chars[cursor_in_visible + 1..].iter().collect()
-        } else {
-            String::new()
-        };
-
-        // Create styled spans
         let text_style = Style::default().fg(theme.text_primary);
+        let selected_style = text_style.bg(theme.bg_surface);
         let cursor_style = Style::default()
             .fg(theme.text_primary)
             .bg(theme.border_primary);  // Semi-transparent block cursor
 
+        let style_at = |visible_idx: usize| {
+            let idx = start_idx + visible_idx;
+            if visible_idx == cursor_in_visible {
+                cursor_style
+            } else if selection.is_some_and(|(start, end)| (start..end).contains(&idx)) {
+                selected_style
+            } else {
+                text_style
+            }
+        };
+
+        // Group runs of equally styled characters into spans
         let mut spans = vec![Span::raw(" ")];  // Left padding
-        if !before.is_empty() {
-            spans.push(Span::styled(before, text_style));
+        let mut run = String::new();
+        let mut run_style = text_style;
+        for (i, c) in chars.iter().enumerate() {
+            let style = style_at(i);
+            if style != run_style && !run.is_empty() {
+                spans.push(Span::styled(std::mem::take(&mut run), run_style));
+            }
+            run_style = style;
+            run.push(*c);
+        }
+        if !run.is_empty() {
+            spans.push(Span::styled(run, run_style));
         }
-        spans.push(Span::styled(cursor_char, cursor_style));
-        if !after.is_empty() {
-            spans.push(Span::styled(after, text_style));
+        if cursor_in_visible >= chars.len() {
+            spans.push(Span::styled(" ", cursor_style));  // Cursor at end - use space
         }
 
         Paragraph::new(Line::from(spans))
@@ -0,0 +1,56 @@
+use std::time::Instant;
+use ratatui::{Frame, style::{Style, Stylize}, widgets::{Block, Borders, Clear, Paragraph}, layout::Rect};
+use crate::tui::Theme;
+use crate::tui::state::ToastStack;
+use super::primitives::ellipsize;
+
+/// Widest a toast gets, borders included
+const MAX_TOAST_WIDTH: u16 = 50;
+
+/// Draw toasts stacked down the top-right corner of `area`, newest on top
+///
+/// Toasts register nothing, so they never take focus or clicks.
+pub fn render_toasts(frame: &mut Frame, area: Rect, toasts: &ToastStack, theme: &Theme) {
+    let now = Instant::now();
+    let max_width = MAX_TOAST_WIDTH.min(area.width.saturating_sub(2));
+    if max_width < 5 {
+        return;
+    }
+
+    let mut y = area.y;
+    for toast in toasts.newest_first() {
+        if y + 3 > area.y + area.height {
+            break;
+        }
+
+        // Two columns of border plus one of padding on each side
+        let text = ellipsize(&toast.message, max_width as usize - 4);
+        let width = (text.chars().count() as u16 + 4).min(max_width);
+        let toast_area = Rect {
+            x: area.x + area.width - 1 - width,
+            y,
+            width,
+            height: 3,
+        };
+
+        let (accent, text_color) = if toast.is_fading(now) {
+            (theme.border_primary, theme.text_tertiary)
+        } else {
+            (toast.level.color(theme), theme.text_primary)
+        };
+
+        frame.render_widget(Clear, toast_area);
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(accent))
+            .style(Style::default().bg(theme.bg_elevated));
+        frame.render_widget(
+            Paragraph::new(format!(" {} ", text))
+                .style(Style::default().fg(text_color).bold())
+                .block(block),
+            toast_area,
+        );
+
+        y += 3;
+    }
+}
@@ -3,8 +3,8 @@ use crossterm::event::{KeyCode, KeyEvent};
 use crate::tui::{Element, Theme, LayoutConstraint};
 use crate::tui::element::FocusId;
 use crate::tui::command::DispatchTarget;
-use crate::tui::widgets::TreeEvent;
-use crate::tui::renderer::{InteractionRegistry, FocusRegistry, DropdownRegistry, FocusableInfo};
+use crate::tui::widgets::{TreeEvent, TreeRows};
+use crate::tui::renderer::{InteractionRegistry, FocusRegistry, DropdownRegistry, FocusableInfo, ScrollHandler};
 
 /// Create on_key handler for trees (navigation and toggle) - old pattern
 pub fn tree_on_key<Msg: Clone + Send + 'static>(
@@ -66,6 +66,39 @@ pub fn tree_on_key_event<Msg: Clone + Send + 'static>(
     })
 }
 
+/// Create mouse wheel handler for trees and table trees
+///
+/// Emits `TreeEvent::Scroll` when the tree handles its own events; trees that only
+/// take `on_navigate` move the selection one row instead. Does nothing at the ends.
+pub fn tree_on_scroll<Msg: Clone + Send + 'static>(
+    selected_idx: Option<usize>,
+    scroll_offset: usize,
+    node_count: usize,
+    visible_height: usize,
+    on_navigate: Option<fn(KeyCode) -> Msg>,
+    on_event: Option<fn(TreeEvent) -> Msg>,
+) -> ScrollHandler<Msg> {
+    let max_offset = node_count.saturating_sub(visible_height);
+
+    Box::new(move |delta| {
+        if on_event.is_none() && let Some(f) = on_navigate {
+            return match selected_idx {
+                Some(0) if delta < 0 => DispatchTarget::Handled,
+                Some(idx) if delta > 0 && idx + 1 >= node_count => DispatchTarget::Handled,
+                _ => DispatchTarget::AppMsg(f(if delta < 0 { KeyCode::Up } else { KeyCode::Down })),
+            };
+        }
+
+        if (delta < 0 && scroll_offset == 0) || (delta > 0 && scroll_offset >= max_offset) {
+            return DispatchTarget::Handled;
+        }
+        match on_event {
+            Some(f) => DispatchTarget::AppMsg(f(TreeEvent::Scroll(delta))),
+            None => DispatchTarget::WidgetEvent(Box::new(TreeEvent::Scroll(delta))),
+        }
+    })
+}
+
 /// Render Tree element
 pub fn render_tree<Msg: Clone + Send + 'static>(
     frame: &mut Frame,
@@ -75,7 +108,7 @@ pub fn render_tree<Msg: Clone + Send + 'static>(
     dropdown_registry: &mut DropdownRegistry<Msg>,
     focused_id: Option<&FocusId>,
     id: &FocusId,
-    items: &[Element<Msg>],
+    rows: &TreeRows<Msg>,
     node_ids: &[String],
     selected: &Option<String>,
     scroll_offset: usize,
@@ -117,15 +150,23 @@ pub fn render_tree<Msg: Clone + Send + 'static>(
     // Calculate visible height
     let visible_height = area.height as usize;
 
-    // Virtual scrolling: only render visible items
+    if rows.len() > visible_height {
+        let selected_idx = selected.as_ref().and_then(|selected| node_ids.iter().position(|id| id == selected));
+        registry.register_scroll(
+            area,
+            id.clone(),
+            tree_on_scroll(selected_idx, scroll_offset, rows.len(), visible_height, *on_navigate, *on_event),
+        );
+    }
+
+    // Virtual scrolling: only build and render visible rows
     // Clamp start_idx to prevent out-of-bounds when items list shrinks (e.g., from filtering)
-    let start_idx = scroll_offset.min(items.len());
-    let end_idx = (start_idx + visible_height).min(items.len());
+    let start_idx = scroll_offset.min(rows.len());
+    let end_idx = (start_idx + visible_height).min(rows.len());
 
     // Create layout for visible items
-    let visible_items: Vec<_> = items[start_idx..end_idx]
-        .iter()
-        .map(|item| (LayoutConstraint::Length(1), item.clone()))
+    let visible_items: Vec<_> = (start_idx..end_idx)
+        .map(|idx| (LayoutConstraint::Length(1), rows.row(idx)))
         .collect();
 
     if !visible_items.is_empty() {
@@ -160,7 +201,7 @@ pub fn render_tree<Msg: Clone + Send + 'static>(
     }
 
     // Render scrollbar if needed
-    if items.len() > visible_height {
+    if rows.len() > visible_height {
         let scrollbar_area = Rect {
             x: area.x + area.width - 1,
             y: area.y,
@@ -168,8 +209,8 @@ pub fn render_tree<Msg: Clone + Send + 'static>(
             height: area.height,
         };
 
-        let scrollbar_position = if items.len() > visible_height {
-            (scroll_offset as f32 / (items.len() - visible_height) as f32 * (area.height - 1) as f32) as u16
+        let scrollbar_position = if rows.len() > visible_height {
+            (scroll_offset as f32 / (rows.len() - visible_height) as f32 * (area.height - 1) as f32) as u16
         } else {
             0
         };
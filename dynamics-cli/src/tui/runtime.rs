@@ -1,7 +1,7 @@
 use ratatui::Frame;
 use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, MouseEvent, MouseEventKind, MouseButton};
 use anyhow::Result;
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::time::{Duration, Instant};
 use std::sync::{Arc, Mutex};
 use serde_json::Value;
@@ -9,12 +9,15 @@ use std::pin::Pin;
 use std::future::Future;
 use std::any::Any;
 
-use crate::tui::{App, AppId, Command, Renderer, InteractionRegistry, Subscription, AppState, KeyBinding, QuitPolicy, SuspendPolicy};
+use crate::tui::{App, AppId, Command, Renderer, InteractionRegistry, Subscription, AppState, KeyBinding, QuitPolicy, SuspendPolicy, ToastLevel};
 use crate::tui::command::{ParallelConfig, DispatchTarget};
 use crate::tui::renderer::{FocusRegistry, FocusableInfo, DropdownRegistry};
 use crate::tui::element::FocusId;
 use crate::tui::state::{RuntimeConfig, FocusMode};
 
+/// Rows scrolled per mouse wheel step
+const WHEEL_SCROLL_ROWS: isize = 3;
+
 /// Trait for creating app instances (factory pattern for lazy initialization)
 pub trait AppFactory: Send {
     /// Create a new app instance with typed parameters
@@ -35,12 +38,15 @@ pub trait AppRuntime {
     fn get_title(&self) -> &'static str;
     fn get_status(&self) -> Option<ratatui::text::Line<'static>>;
     fn get_key_bindings(&self) -> Vec<(KeyBinding, String)>;
+    fn get_palette_actions(&self) -> Vec<(String, String)>;
+    fn run_palette_action(&mut self, index: usize) -> Result<bool>;
     fn poll_timers(&mut self) -> Result<()>;
     fn poll_async(&mut self) -> Pin<Box<dyn Future<Output = Result<()>> + '_>>;
     fn take_navigation(&mut self) -> Option<AppId>;
     fn peek_navigation(&self) -> Option<AppId>;
     fn take_start_app(&mut self) -> Option<(AppId, Box<dyn Any + Send>)>;
     fn take_publishes(&mut self) -> Vec<(String, Value)>;
+    fn take_notifications(&mut self) -> Vec<(String, ToastLevel)>;
     fn handle_publish(&mut self, topic: &str, data: Value) -> Result<()>;
     fn focus_next(&mut self) -> Result<()>;
     fn focus_previous(&mut self) -> Result<()>;
@@ -50,6 +56,8 @@ pub trait AppRuntime {
     fn on_suspend(&mut self) -> Result<()>;
     fn on_resume(&mut self) -> Result<()>;
     fn on_destroy(&mut self) -> Result<()>;
+    /// Destroy the app as the TUI exits, waiting for the commands `on_destroy` issued
+    fn shutdown(&mut self) -> Pin<Box<dyn Future<Output = Result<()>> + '_>>;
 
     /// Check if the app is capturing raw input (e.g., keybind capture mode)
     /// When true, global keybinds should be bypassed to allow the app to handle all keys
@@ -58,12 +66,34 @@ pub trait AppRuntime {
     }
 }
 
+/// A parallel task wrapped to report its (task_index, task_name) when done
+type ParallelFuture = Pin<Box<dyn Future<Output = (usize, String)> + Send>>;
+
 /// Tracks the state of a parallel task execution
 struct ParallelTaskCoordinator<Msg> {
     total_tasks: usize,
     results: Arc<Mutex<Vec<Option<Box<dyn Any + Send>>>>>,
     msg_mapper: Arc<dyn Fn(usize, Box<dyn Any + Send>) -> Msg + Send>,
     config: ParallelConfig,
+    /// Tasks waiting for a slot under `config.max_concurrent`, with their names
+    queued: VecDeque<(String, ParallelFuture)>,
+}
+
+/// Load an app's saved session unless it is older than `tui.session_max_age_minutes`
+async fn load_session(app: &'static str, key: String) -> Option<Value> {
+    let config = crate::global_config();
+    let max_age_minutes = config.options.get_uint("tui.session_max_age_minutes").await.unwrap_or(60);
+    if max_age_minutes == 0 {
+        return None;
+    }
+
+    match config.get_app_session(app, &key, Duration::from_secs(max_age_minutes * 60)).await {
+        Ok(snapshot) => snapshot,
+        Err(e) => {
+            log::warn!("Failed to load session for {}: {}", app, e);
+            None
+        }
+    }
 }
 
 /// The runtime manages app lifecycle, event routing, and command execution
@@ -102,11 +132,14 @@ pub struct Runtime<A: App> {
     pending_async: Vec<std::pin::Pin<Box<dyn std::future::Future<Output = A::Msg> + Send>>>,
 
     /// Pending parallel coordination tasks (task_index, task_name)
-    pending_parallel: Vec<std::pin::Pin<Box<dyn std::future::Future<Output = (usize, String)> + Send>>>,
+    pending_parallel: Vec<ParallelFuture>,
 
     /// Pending publish events to broadcast globally
     pending_publishes: Vec<(String, serde_json::Value)>,
 
+    /// Pending toast notifications for MultiAppRuntime to show
+    pending_notifications: Vec<(String, ToastLevel)>,
+
     /// Pending app start with params (app_id, params)
     pending_start_app: Option<(AppId, Box<dyn Any + Send>)>,
 
@@ -118,6 +151,9 @@ pub struct Runtime<A: App> {
 
     /// Previous layer count (to detect modal open/close)
     previous_layer_count: usize,
+
+    /// Saved session being loaded for restore after init
+    pending_session: Option<Pin<Box<dyn Future<Output = Option<Value>> + Send>>>,
 }
 
 impl<A: App> Runtime<A> {
@@ -142,10 +178,12 @@ impl<A: App> Runtime<A> {
             pending_async: Vec::new(),
             pending_parallel: Vec::new(),
             pending_publishes: Vec::new(),
+            pending_notifications: Vec::new(),
             pending_start_app: None,
             parallel_coordinator: None,
             explicitly_unfocused: false,
             previous_layer_count: 1,  // Start with 1 (base layer)
+            pending_session: None,
         };
 
         // Initialize subscriptions
@@ -154,6 +192,11 @@ impl<A: App> Runtime<A> {
         // Execute init command
         runtime.execute_command(init_command).ok();
 
+        // Look up a saved session to restore once it has loaded
+        if let Some(key) = A::session_key(&runtime.state) {
+            runtime.pending_session = Some(Box::pin(load_session(A::title(), key)));
+        }
+
         runtime
     }
 
@@ -177,7 +220,13 @@ impl<A: App> Runtime<A> {
         std::mem::take(&mut self.pending_publishes)
     }
 
-    /// Get keyboard bindings for help menu
+    /// Take pending toast notifications
+    pub fn take_notifications(&mut self) -> Vec<(String, ToastLevel)> {
+        std::mem::take(&mut self.pending_notifications)
+    }
+
+    /// Get keyboard bindings for help menu: the app's current keyboard
+    /// subscriptions followed by its extra help bindings
     pub fn get_key_bindings(&self) -> Vec<(KeyBinding, String)> {
         use crate::tui::Subscription;
 
@@ -187,9 +236,29 @@ impl<A: App> Runtime<A> {
                 Subscription::Keyboard { key, description, .. } => Some((key, description)),
                 _ => None,
             })
+            .chain(A::help_bindings(&self.state))
             .collect()
     }
 
+    /// Get the labels and details of the app's command palette actions
+    pub fn get_palette_actions(&self) -> Vec<(String, String)> {
+        A::palette_actions(&self.state)
+            .into_iter()
+            .map(|action| (action.label, action.detail))
+            .collect()
+    }
+
+    /// Send the message of the palette action at `index` to the app
+    pub fn run_palette_action(&mut self, index: usize) -> Result<bool> {
+        let Some(action) = A::palette_actions(&self.state).into_iter().nth(index) else {
+            return Ok(true);
+        };
+        let command = A::update(&mut self.state, action.msg);
+        let result = self.execute_command(command)?;
+        self.update_subscriptions();
+        Ok(result)
+    }
+
     /// Get the app's title (static string)
     pub fn get_title(&self) -> &'static str {
         A::title()
@@ -216,55 +285,20 @@ impl<A: App> Runtime<A> {
         self.registry = registry;
     }
 
-    /// Focus the next element (Tab)
+    /// Focus the next element (Tab), staying within the topmost layer
     pub fn focus_next(&mut self) -> Result<()> {
-        let focusable_ids = self.focus_registry.focusable_ids_in_active_layer();
-
-        if focusable_ids.is_empty() {
-            return Ok(());
+        match self.focus_registry.next_focus(self.focused_id.as_ref()) {
+            Some(next_id) => self.execute_command(Command::set_focus(next_id)).map(|_| ()),
+            None => Ok(()),
         }
-
-        let next_id = if let Some(current_id) = &self.focused_id {
-            if let Some(pos) = focusable_ids.iter().position(|id| id == current_id) {
-                focusable_ids[(pos + 1) % focusable_ids.len()].clone()
-            } else {
-                focusable_ids[0].clone()
-            }
-        } else {
-            focusable_ids[0].clone()
-        };
-
-        let cmd = Command::set_focus(next_id);
-        self.execute_command(cmd)?;
-        Ok(())
     }
 
-    /// Focus the previous element (Shift-Tab)
+    /// Focus the previous element (Shift-Tab), staying within the topmost layer
     pub fn focus_previous(&mut self) -> Result<()> {
-        let focusable_ids = self.focus_registry.focusable_ids_in_active_layer();
-
-        if focusable_ids.is_empty() {
-            return Ok(());
+        match self.focus_registry.prev_focus(self.focused_id.as_ref()) {
+            Some(prev_id) => self.execute_command(Command::set_focus(prev_id)).map(|_| ()),
+            None => Ok(()),
         }
-
-        let prev_id = if let Some(current_id) = &self.focused_id {
-            if let Some(pos) = focusable_ids.iter().position(|id| id == current_id) {
-                let prev_pos = if pos == 0 {
-                    focusable_ids.len() - 1
-                } else {
-                    pos - 1
-                };
-                focusable_ids[prev_pos].clone()
-            } else {
-                focusable_ids[0].clone()
-            }
-        } else {
-            focusable_ids[focusable_ids.len() - 1].clone()
-        };
-
-        let cmd = Command::set_focus(prev_id);
-        self.execute_command(cmd)?;
-        Ok(())
     }
 
     /// Poll timer subscriptions and fire those that are ready
@@ -304,6 +338,19 @@ impl<A: App> Runtime<A> {
         let waker = futures::task::noop_waker();
         let mut cx = Context::from_waker(&waker);
 
+        // Restore a saved session once it has loaded
+        if let Some(future) = self.pending_session.as_mut() {
+            if let Poll::Ready(snapshot) = future.as_mut().poll(&mut cx) {
+                self.pending_session = None;
+                if let Some(snapshot) = snapshot {
+                    log::info!("Restoring session for {}", A::title());
+                    let command = A::restore_state(&mut self.state, snapshot);
+                    self.execute_command(command)?;
+                    self.update_subscriptions();
+                }
+            }
+        }
+
         // Poll regular async commands
         let mut completed = Vec::new();
         for (i, future) in self.pending_async.iter_mut().enumerate() {
@@ -348,6 +395,7 @@ impl<A: App> Runtime<A> {
                 }),
             ));
         }
+        self.start_queued_parallel();
 
         // AFTER all completion events are published, check if all tasks are complete
         if let Some(coordinator) = &self.parallel_coordinator {
@@ -389,6 +437,56 @@ impl<A: App> Runtime<A> {
         Ok(())
     }
 
+    /// Run `on_destroy` as the TUI exits and wait for the async work it issues
+    ///
+    /// Work still in flight is abandoned, so `on_destroy` has to reissue
+    /// anything that must finish (e.g. unsaved edits); its results are discarded.
+    pub async fn shutdown(&mut self) -> Result<()> {
+        self.pending_async.clear();
+        let command = A::on_destroy(&mut self.state);
+        self.execute_command(command)?;
+        for future in std::mem::take(&mut self.pending_async) {
+            future.await;
+        }
+        Ok(())
+    }
+
+    /// Save the app's resumable state in the background, if it has any
+    fn save_session(&self) {
+        let (Some(key), Some(snapshot)) = (A::session_key(&self.state), A::suspend_state(&self.state)) else {
+            return;
+        };
+        tokio::spawn(async move {
+            if let Err(e) = crate::global_config().save_app_session(A::title(), &key, &snapshot).await {
+                log::warn!("Failed to save session for {}: {}", A::title(), e);
+            }
+        });
+    }
+
+    /// Start queued parallel tasks until the concurrency limit is reached
+    fn start_queued_parallel(&mut self) {
+        let Some(coordinator) = &mut self.parallel_coordinator else {
+            return;
+        };
+        let limit = coordinator.config.max_concurrent.unwrap_or(usize::MAX);
+
+        while self.pending_parallel.len() < limit {
+            let Some((task_name, future)) = coordinator.queued.pop_front() else {
+                break;
+            };
+
+            // Publish InProgress status as the task starts
+            self.pending_publishes.push((
+                "loading:progress".to_string(),
+                serde_json::json!({
+                    "task": task_name,
+                    "status": "InProgress",
+                }),
+            ));
+            self.pending_parallel.push(future);
+        }
+    }
+
     /// Update subscriptions based on current state
     fn update_subscriptions(&mut self) {
         self.key_subscriptions.clear();
@@ -464,6 +562,7 @@ impl<A: App> Runtime<A> {
                         }
                         // Fall through to global subscriptions for other keys
                     }
+                    DispatchTarget::Handled => return Ok(true),
                 }
             }
         }
@@ -498,6 +597,34 @@ impl<A: App> Runtime<A> {
     pub fn handle_mouse(&mut self, mouse_event: MouseEvent) -> Result<bool> {
         let pos = (mouse_event.column, mouse_event.row);
 
+        // Wheel over a scrollable widget scrolls it directly, focused or not
+        // (Shift+wheel falls through to horizontal scrolling of the focused element)
+        let wheel_delta = match mouse_event.kind {
+            MouseEventKind::ScrollUp => Some(-WHEEL_SCROLL_ROWS),
+            MouseEventKind::ScrollDown => Some(WHEEL_SCROLL_ROWS),
+            _ => None,
+        };
+        if let Some(delta) = wheel_delta
+            && !mouse_event.modifiers.contains(crossterm::event::KeyModifiers::SHIFT)
+            && let Some((id, target)) = self.registry.find_scroll(pos.0, pos.1, delta)
+            // Only widgets in the active layer, so a modal doesn't scroll what's behind it
+            && self.focus_registry.find_at_position(pos.0, pos.1).as_ref() == Some(&id)
+        {
+            return match target {
+                DispatchTarget::WidgetEvent(boxed_event) => {
+                    self.state.dispatch_widget_event(&id, boxed_event.as_ref());
+                    Ok(true)
+                }
+                DispatchTarget::AppMsg(msg) => {
+                    let command = A::update(&mut self.state, msg);
+                    let result = self.execute_command(command)?;
+                    self.update_subscriptions();
+                    Ok(result)
+                }
+                DispatchTarget::PassThrough | DispatchTarget::Handled => Ok(true),
+            };
+        }
+
         match mouse_event.kind {
             MouseEventKind::Down(MouseButton::Left) => {
                 // STEP 1: Handle focus change
@@ -583,7 +710,7 @@ impl<A: App> Runtime<A> {
                                     self.update_subscriptions();
                                     return Ok(result);
                                 }
-                                DispatchTarget::PassThrough => {
+                                DispatchTarget::PassThrough | DispatchTarget::Handled => {
                                     // Scroll wheel on unfocused widget - ignore
                                 }
                             }
@@ -621,7 +748,7 @@ impl<A: App> Runtime<A> {
                                     self.update_subscriptions();
                                     return Ok(result);
                                 }
-                                DispatchTarget::PassThrough => {
+                                DispatchTarget::PassThrough | DispatchTarget::Handled => {
                                     // Scroll wheel on unfocused widget - ignore
                                 }
                             }
@@ -735,6 +862,11 @@ impl<A: App> Runtime<A> {
                 Ok(true)
             }
 
+            Command::Notify { message, level } => {
+                self.pending_notifications.push((message, level));
+                Ok(true)
+            }
+
             Command::Perform(future) => {
                 // Add to pending async commands
                 self.pending_async.push(future);
@@ -763,31 +895,15 @@ impl<A: App> Runtime<A> {
                 let results = Arc::new(Mutex::new((0..total_tasks).map(|_| None).collect::<Vec<_>>()));
                 let msg_mapper = Arc::new(msg_mapper);
 
-                // Set up coordinator
-                self.parallel_coordinator = Some(ParallelTaskCoordinator {
-                    total_tasks,
-                    results: results.clone(),
-                    msg_mapper: msg_mapper.clone(),
-                    config: config.clone(),
-                });
-
-                // Spawn all tasks as separate futures
+                // Wrap each task; they start in order as slots free up
+                let mut queued = VecDeque::with_capacity(total_tasks);
                 for (idx, task) in tasks.into_iter().enumerate() {
                     let results_ref = results.clone();
                     let task_name = task.description.clone();
                     let future = task.future;
 
-                    // Immediately publish InProgress status
-                    self.pending_publishes.push((
-                        "loading:progress".to_string(),
-                        serde_json::json!({
-                            "task": task_name,
-                            "status": "InProgress",
-                        }),
-                    ));
-
                     // Create wrapper future that stores result and signals completion
-                    let wrapper_future = Box::pin(async move {
+                    let wrapper_future: ParallelFuture = Box::pin(async move {
                         // Execute the actual task
                         let result = future.await;
 
@@ -801,9 +917,19 @@ impl<A: App> Runtime<A> {
                         (idx, task_name)
                     });
 
-                    self.pending_parallel.push(wrapper_future);
+                    queued.push_back((task.description, wrapper_future));
                 }
 
+                // Set up coordinator
+                self.parallel_coordinator = Some(ParallelTaskCoordinator {
+                    total_tasks,
+                    results: results.clone(),
+                    msg_mapper: msg_mapper.clone(),
+                    config: config.clone(),
+                    queued,
+                });
+                self.start_queued_parallel();
+
                 Ok(true)
             }
 
@@ -908,6 +1034,14 @@ impl<A: App> Runtime<A> {
             log::debug!("Runtime: layer count changed {} -> {}, clearing explicit unfocus flag",
                        self.previous_layer_count, current_layer_count);
             self.explicitly_unfocused = false;
+
+            // With restoring turned off, a closed modal leaves nothing focused (hover
+            // focus modes then pick up the element under the mouse)
+            if current_layer_count < self.previous_layer_count && !config.restore_modal_focus {
+                log::debug!("Runtime: modal closed, not restoring focus");
+                self.focused_id = None;
+                self.explicitly_unfocused = true;
+            }
             self.previous_layer_count = current_layer_count;
         }
 
@@ -995,6 +1129,14 @@ where
         Runtime::get_key_bindings(self)
     }
 
+    fn get_palette_actions(&self) -> Vec<(String, String)> {
+        Runtime::get_palette_actions(self)
+    }
+
+    fn run_palette_action(&mut self, index: usize) -> Result<bool> {
+        Runtime::run_palette_action(self, index)
+    }
+
     fn poll_timers(&mut self) -> Result<()> {
         Runtime::poll_timers(self)
     }
@@ -1019,6 +1161,10 @@ where
         Runtime::take_publishes(self)
     }
 
+    fn take_notifications(&mut self) -> Vec<(String, ToastLevel)> {
+        Runtime::take_notifications(self)
+    }
+
     fn handle_publish(&mut self, topic: &str, data: Value) -> Result<()> {
         Runtime::handle_publish(self, topic, data)
     }
@@ -1036,6 +1182,7 @@ where
     }
 
     fn on_suspend(&mut self) -> Result<()> {
+        Runtime::save_session(self);
         let command = A::on_suspend(&mut self.state);
         self.execute_command(command)?;
         Ok(())
@@ -1048,11 +1195,16 @@ where
     }
 
     fn on_destroy(&mut self) -> Result<()> {
+        Runtime::save_session(self);
         let command = A::on_destroy(&mut self.state);
         self.execute_command(command)?;
         Ok(())
     }
 
+    fn shutdown(&mut self) -> Pin<Box<dyn Future<Output = Result<()>> + '_>> {
+        Box::pin(Runtime::shutdown(self))
+    }
+
     fn is_capturing_raw_input(&self) -> bool {
         A::is_capturing_raw_input(&self.state)
     }
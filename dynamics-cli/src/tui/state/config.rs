@@ -2,6 +2,7 @@ use super::{Theme, ThemeVariant, FocusMode};
 use crate::config::options::Options;
 use crate::tui::color::hex_to_color;
 use crate::tui::KeyBinding;
+use crate::tui::apps::migration::entity_comparison::{MatchAlgorithm, MatchSettings};
 use ratatui::style::Color;
 use std::collections::HashMap;
 
@@ -18,11 +19,20 @@ pub struct RuntimeConfig {
     /// How keyboard focus is acquired (click, hover, or hybrid)
     pub focus_mode: FocusMode,
 
+    /// Return focus to the element focused before a modal opened when it closes
+    pub restore_modal_focus: bool,
+
     /// Global keybinds mapping action names to key combinations
     pub keybinds: HashMap<String, KeyBinding>,
 
     /// Tab key debouncing duration in milliseconds
     pub tab_debouncing_ms: u64,
+
+    /// Idle time before pending mapping edits are saved, in milliseconds
+    pub autosave_delay_ms: u64,
+
+    /// Entity comparison auto-matching algorithm and fuzzy threshold
+    pub match_settings: MatchSettings,
 }
 
 impl Default for RuntimeConfig {
@@ -32,8 +42,11 @@ impl Default for RuntimeConfig {
         Self {
             theme: Theme::new(ThemeVariant::default()),
             focus_mode: FocusMode::default(),
+            restore_modal_focus: true,
             keybinds: HashMap::new(),
             tab_debouncing_ms: 150,
+            autosave_delay_ms: 1000,
+            match_settings: MatchSettings::default(),
         }
     }
 }
@@ -41,29 +54,22 @@ impl Default for RuntimeConfig {
 impl RuntimeConfig {
     /// Create a new config with explicit settings
     pub fn new(theme: Theme, focus_mode: FocusMode, keybinds: HashMap<String, KeyBinding>) -> Self {
-        let default = Self::default();
-        Self { theme, focus_mode, keybinds, tab_debouncing_ms: default.tab_debouncing_ms }
+        Self { theme, focus_mode, keybinds, ..Self::default() }
     }
 
     /// Create config with custom theme variant and default focus mode
     pub fn with_theme(variant: ThemeVariant) -> Self {
-        let default = Self::default();
         Self {
             theme: Theme::new(variant),
-            focus_mode: FocusMode::default(),
-            keybinds: default.keybinds,
-            tab_debouncing_ms: default.tab_debouncing_ms,
+            ..Self::default()
         }
     }
 
     /// Create config with custom focus mode and default theme
     pub fn with_focus_mode(mode: FocusMode) -> Self {
-        let default = Self::default();
         Self {
-            theme: Theme::new(ThemeVariant::default()),
             focus_mode: mode,
-            keybinds: default.keybinds,
-            tab_debouncing_ms: default.tab_debouncing_ms,
+            ..Self::default()
         }
     }
 
@@ -103,6 +109,21 @@ impl RuntimeConfig {
         }
     }
 
+    /// Resolved keybinds of one category (e.g. "global"), labelled with their
+    /// display names, sorted by action
+    pub fn keybinds_for(&self, category: &str) -> Vec<(KeyBinding, String)> {
+        use crate::config::options::registrations::keybinds;
+
+        let registry = crate::options_registry();
+        keybinds::list_actions_for_app(&registry, category)
+            .into_iter()
+            .map(|action| {
+                let binding = self.get_keybind(&format!("{}.{}", category, action));
+                (binding, keybinds::get_action_display_name(&registry, category, &action))
+            })
+            .collect()
+    }
+
     /// Load runtime config from the options system
     pub async fn load_from_options() -> anyhow::Result<Self> {
         use std::str::FromStr;
@@ -121,6 +142,9 @@ impl RuntimeConfig {
             _ => FocusMode::default(),
         };
 
+        let restore_modal_focus = config.options.get_bool("tui.restore_modal_focus").await
+            .unwrap_or(true);
+
         // Load active theme from options (defaults to mocha if not found)
         let theme_name = config.options.get_string("theme.active").await
             .unwrap_or_else(|_| "mocha".to_string());
@@ -136,6 +160,20 @@ impl RuntimeConfig {
         let tab_debouncing_ms = config.options.get_uint("keys.tab.debouncing").await
             .unwrap_or_else(|_| 150);
 
+        // Load mapping auto-save delay from options (defaults to 1s if not found)
+        let autosave_delay_ms = config.options.get_uint("tui.autosave_delay_ms").await
+            .unwrap_or(1000);
+
+        // Load auto-matching settings from options (defaults to prefix matching at 80)
+        let match_settings = MatchSettings {
+            algorithm: config.options.get_string("tui.match_algorithm").await
+                .map(|algorithm| MatchAlgorithm::from_option(&algorithm))
+                .unwrap_or_default(),
+            threshold: config.options.get_uint("tui.match_threshold").await
+                .map(|threshold| threshold.min(100) as u8)
+                .unwrap_or(80),
+        };
+
         // Load keybinds from options database (now app-scoped)
         let mut keybinds = HashMap::new();
         let apps = keybinds::list_apps(&config.options.registry());
@@ -175,8 +213,11 @@ impl RuntimeConfig {
         Ok(Self {
             theme,
             focus_mode,
+            restore_modal_focus,
             keybinds,
             tab_debouncing_ms,
+            autosave_delay_ms,
+            match_settings,
         })
     }
 }
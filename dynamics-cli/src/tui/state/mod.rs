@@ -1,9 +1,12 @@
 pub mod config;
 pub mod focus;
 pub mod theme;
+pub mod theme_file;
 pub mod modal;
+pub mod toast;
 
 pub use config::RuntimeConfig;
 pub use focus::FocusMode;
 pub use theme::{Theme, ThemeVariant};
 pub use modal::ModalState;
+pub use toast::{Toast, ToastLevel, ToastStack};
@@ -7,6 +7,8 @@ use ratatui::style::{Color, Style};
 pub enum ThemeVariant {
     Mocha,  // Dark theme (default)
     Latte,  // Light theme
+    HighContrast,    // Black background, WCAG-AA text contrast everywhere
+    ColorblindSafe,  // Blue/orange instead of green/red for match states
 }
 
 impl Default for ThemeVariant {
@@ -52,6 +54,8 @@ impl Theme {
         match variant {
             ThemeVariant::Mocha => Self::mocha(),
             ThemeVariant::Latte => Self::latte(),
+            ThemeVariant::HighContrast => Self::high_contrast(),
+            ThemeVariant::ColorblindSafe => Self::colorblind_safe(),
         }
     }
 
@@ -121,6 +125,77 @@ impl Theme {
         }
     }
 
+    /// Pure black surfaces with near-white text; every text color keeps at
+    /// least the WCAG-AA 4.5:1 ratio on `bg_base`, `bg_surface` and `bg_elevated`
+    pub fn high_contrast() -> Self {
+        Self {
+            // Accent colors (saturated, bright on black)
+            accent_primary: Color::Rgb(0xff, 0xff, 0x00),    // yellow focus
+            accent_secondary: Color::Rgb(0x00, 0xff, 0xff),  // cyan
+            accent_tertiary: Color::Rgb(0xff, 0x8c, 0xff),   // magenta
+            accent_error: Color::Rgb(0xff, 0x6b, 0x6b),      // light red
+            accent_warning: Color::Rgb(0xff, 0xb3, 0x00),    // amber
+            accent_success: Color::Rgb(0x3d, 0xff, 0x8a),    // bright green
+            accent_info: Color::Rgb(0x7f, 0xdb, 0xff),       // light blue
+            accent_muted: Color::Rgb(0xff, 0xc8, 0x8a),      // light orange
+
+            // Text
+            text_primary: Color::Rgb(0xff, 0xff, 0xff),
+            text_secondary: Color::Rgb(0xe6, 0xe6, 0xe6),
+            text_tertiary: Color::Rgb(0xc8, 0xc8, 0xc8),
+
+            // UI structure
+            border_primary: Color::Rgb(0xff, 0xff, 0xff),
+            border_secondary: Color::Rgb(0xa8, 0xa8, 0xa8),
+            border_tertiary: Color::Rgb(0x8a, 0x8a, 0x8a),
+            bg_base: Color::Rgb(0x00, 0x00, 0x00),
+            bg_surface: Color::Rgb(0x1c, 0x1c, 0x1c),
+            bg_elevated: Color::Rgb(0x2a, 0x2a, 0x2a),
+
+            // Flexible palette
+            palette_1: Color::Rgb(0xff, 0xff, 0xff),
+            palette_2: Color::Rgb(0xff, 0xd0, 0xd0),
+            palette_3: Color::Rgb(0xff, 0xa8, 0xe0),
+            palette_4: Color::Rgb(0x8a, 0xe0, 0xff),
+        }
+    }
+
+    /// Mocha's surfaces with accents from the Okabe-Ito palette, so states
+    /// never rely on telling red from green: matched/success is blue,
+    /// unmatched/error is orange, warnings are yellow
+    pub fn colorblind_safe() -> Self {
+        Self {
+            // Accent colors (Okabe-Ito, lightened where needed for a dark background)
+            accent_primary: Color::Rgb(0xb4, 0xbe, 0xfe),    // lavender (neutral focus)
+            accent_secondary: Color::Rgb(0x6c, 0xb6, 0xff),  // blue
+            accent_tertiary: Color::Rgb(0xcc, 0x79, 0xa7),   // reddish purple
+            accent_error: Color::Rgb(0xe6, 0x9f, 0x00),      // orange
+            accent_warning: Color::Rgb(0xf0, 0xe4, 0x42),    // yellow
+            accent_success: Color::Rgb(0x56, 0xb4, 0xe9),    // sky blue
+            accent_info: Color::Rgb(0x9a, 0xd0, 0xf0),       // pale blue
+            accent_muted: Color::Rgb(0xf5, 0xc8, 0xa0),      // pale orange
+
+            // Text
+            text_primary: Color::Rgb(0xcd, 0xd6, 0xf4),
+            text_secondary: Color::Rgb(0xba, 0xc2, 0xde),
+            text_tertiary: Color::Rgb(0xa6, 0xad, 0xc8),
+
+            // UI structure
+            border_primary: Color::Rgb(0x7f, 0x84, 0x9c),
+            border_secondary: Color::Rgb(0x6c, 0x70, 0x86),
+            border_tertiary: Color::Rgb(0x93, 0x99, 0xb2),
+            bg_base: Color::Rgb(0x1e, 0x1e, 0x2e),
+            bg_surface: Color::Rgb(0x31, 0x32, 0x44),
+            bg_elevated: Color::Rgb(0x45, 0x47, 0x5a),
+
+            // Flexible palette
+            palette_1: Color::Rgb(0xf5, 0xe0, 0xdc),
+            palette_2: Color::Rgb(0xe0, 0xc0, 0xe0),
+            palette_3: Color::Rgb(0xcc, 0x79, 0xa7),         // reddish purple
+            palette_4: Color::Rgb(0x4c, 0xd6, 0xb0),         // bluish green (example-value matches)
+        }
+    }
+
     // Helper methods following semantic naming
     pub fn error_style(&self) -> Style {
         Style::default().fg(self.accent_error)
@@ -177,6 +252,36 @@ impl Theme {
             ("palette_4", self.palette_4),
         ]
     }
+
+    /// Set a color by its field name; returns false for an unknown name
+    pub fn set_color(&mut self, name: &str, color: Color) -> bool {
+        let slot = match name {
+            "accent_primary" => &mut self.accent_primary,
+            "accent_secondary" => &mut self.accent_secondary,
+            "accent_tertiary" => &mut self.accent_tertiary,
+            "accent_error" => &mut self.accent_error,
+            "accent_warning" => &mut self.accent_warning,
+            "accent_success" => &mut self.accent_success,
+            "accent_info" => &mut self.accent_info,
+            "accent_muted" => &mut self.accent_muted,
+            "text_primary" => &mut self.text_primary,
+            "text_secondary" => &mut self.text_secondary,
+            "text_tertiary" => &mut self.text_tertiary,
+            "border_primary" => &mut self.border_primary,
+            "border_secondary" => &mut self.border_secondary,
+            "border_tertiary" => &mut self.border_tertiary,
+            "bg_base" => &mut self.bg_base,
+            "bg_surface" => &mut self.bg_surface,
+            "bg_elevated" => &mut self.bg_elevated,
+            "palette_1" => &mut self.palette_1,
+            "palette_2" => &mut self.palette_2,
+            "palette_3" => &mut self.palette_3,
+            "palette_4" => &mut self.palette_4,
+            _ => return false,
+        };
+        *slot = color;
+        true
+    }
 }
 
 impl Default for Theme {
@@ -209,3 +314,44 @@ pub const COLOR_NAMES: &[&str] = &[
     "palette_3",
     "palette_4",
 ];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// WCAG contrast ratio between two RGB colors
+    fn contrast(a: Color, b: Color) -> f64 {
+        let luminance = |color: Color| {
+            let Color::Rgb(r, g, b) = color else { panic!("expected RGB color") };
+            let channel = |c: u8| {
+                let c = c as f64 / 255.0;
+                if c <= 0.03928 { c / 12.92 } else { ((c + 0.055) / 1.055).powf(2.4) }
+            };
+            0.2126 * channel(r) + 0.7152 * channel(g) + 0.0722 * channel(b)
+        };
+        let (la, lb) = (luminance(a), luminance(b));
+        (la.max(lb) + 0.05) / (la.min(lb) + 0.05)
+    }
+
+    #[test]
+    fn test_high_contrast_text_meets_wcag_aa() {
+        let theme = Theme::high_contrast();
+        for bg in [theme.bg_base, theme.bg_surface, theme.bg_elevated] {
+            for fg in [theme.text_primary, theme.text_secondary, theme.text_tertiary, theme.accent_primary,
+                       theme.accent_success, theme.accent_error, theme.accent_warning] {
+                assert!(contrast(fg, bg) >= 4.5, "{:?} on {:?} is {:.2}:1", fg, bg, contrast(fg, bg));
+            }
+        }
+    }
+
+    #[test]
+    fn test_colorblind_safe_match_states_avoid_red_green() {
+        let theme = Theme::colorblind_safe();
+        let Color::Rgb(r, g, b) = theme.accent_success else { panic!() };
+        assert!(b > r && b > g, "matched should read as blue");
+        let Color::Rgb(r, _, b) = theme.accent_error else { panic!() };
+        assert!(r > b, "unmatched should read as orange");
+        assert!(contrast(theme.accent_success, theme.bg_base) >= 4.5);
+        assert!(contrast(theme.accent_error, theme.bg_base) >= 4.5);
+    }
+}
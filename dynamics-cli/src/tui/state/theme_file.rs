@@ -0,0 +1,141 @@
+//! Theme files: share a theme as JSON or TOML
+//!
+//! A file holds the theme name and its colors as hex strings:
+//!
+//! ```toml
+//! name = "ocean"
+//!
+//! [colors]
+//! accent_primary = "#89b4fa"
+//! bg_base = "#1e1e2e"
+//! ```
+//!
+//! The format follows the extension (`.toml`, anything else is JSON). Importing
+//! is lenient: missing or unreadable colors fall back to the default theme and
+//! are reported as warnings instead of failing the whole file.
+
+use super::theme::{Theme, COLOR_NAMES};
+use crate::tui::color::{color_to_hex, hex_to_color};
+use anyhow::{Context, Result};
+use serde_json::{Map, Value};
+use std::path::Path;
+
+/// A theme read from a file
+#[derive(Debug, Clone)]
+pub struct ImportedTheme {
+    pub name: String,
+    pub theme: Theme,
+    /// One line per color that was missing, invalid or unknown
+    pub warnings: Vec<String>,
+}
+
+/// Write `theme` to `path` under `name`
+pub fn export_theme(path: &Path, name: &str, theme: &Theme) -> Result<()> {
+    let text = theme_to_string(name, theme, is_toml(path))?;
+    std::fs::write(path, text)
+        .with_context(|| format!("Failed to write {}", path.display()))
+}
+
+/// Read a theme from `path`; the file stem names it when the file doesn't
+pub fn import_theme(path: &Path) -> Result<ImportedTheme> {
+    let text = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+    let fallback_name = path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .map(|name| name.split('.').next().unwrap_or(name).to_string())
+        .unwrap_or_default();
+    parse_theme(&text, is_toml(path), &fallback_name)
+}
+
+fn is_toml(path: &Path) -> bool {
+    path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("toml"))
+}
+
+fn theme_to_string(name: &str, theme: &Theme, toml: bool) -> Result<String> {
+    let colors: Map<String, Value> = theme
+        .colors()
+        .into_iter()
+        .map(|(color_name, color)| (color_name.to_string(), color_to_hex(color).into()))
+        .collect();
+    let file = serde_json::json!({ "name": name, "colors": colors });
+
+    if toml {
+        toml::to_string(&file).context("Failed to serialize theme as TOML")
+    } else {
+        serde_json::to_string_pretty(&file).context("Failed to serialize theme as JSON")
+    }
+}
+
+fn parse_theme(text: &str, toml: bool, fallback_name: &str) -> Result<ImportedTheme> {
+    let file: Value = if toml {
+        toml::from_str(text).context("Theme file is not valid TOML")?
+    } else {
+        serde_json::from_str(text).context("Theme file is not valid JSON")?
+    };
+    let colors = file
+        .get("colors")
+        .and_then(Value::as_object)
+        .context("Theme file has no 'colors' table")?;
+
+    // Names become option keys (theme.NAME.color), so they can't contain dots
+    let name = file
+        .get("name")
+        .and_then(Value::as_str)
+        .map(str::trim)
+        .filter(|name| !name.is_empty() && !name.contains('.'))
+        .unwrap_or(fallback_name)
+        .to_string();
+    if name.is_empty() {
+        anyhow::bail!("Theme file has no usable name");
+    }
+
+    let mut theme = Theme::default();
+    let mut warnings = Vec::new();
+    for color_name in COLOR_NAMES {
+        match colors.get(*color_name) {
+            None => warnings.push(format!("'{}' is missing, using the default", color_name)),
+            Some(value) => match value.as_str().map(hex_to_color) {
+                Some(Ok(color)) => {
+                    theme.set_color(color_name, color);
+                }
+                _ => warnings.push(format!("'{}' is not a #RRGGBB color, using the default", color_name)),
+            },
+        }
+    }
+    for key in colors.keys().filter(|key| !COLOR_NAMES.contains(&key.as_str())) {
+        warnings.push(format!("'{}' is not a theme color, ignored", key));
+    }
+
+    Ok(ImportedTheme { name, theme, warnings })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::style::Color;
+
+    #[test]
+    fn test_round_trip_and_fallbacks() {
+        let mut theme = Theme::latte();
+        theme.set_color("accent_primary", Color::Rgb(0x12, 0x34, 0x56));
+
+        for toml in [false, true] {
+            let text = theme_to_string("ocean", &theme, toml).unwrap();
+            let imported = parse_theme(&text, toml, "file").unwrap();
+            assert_eq!(imported.name, "ocean");
+            assert!(imported.warnings.is_empty());
+            assert_eq!(imported.theme.colors(), theme.colors());
+        }
+
+        let partial = r##"{"name": "bad.name", "colors": {"bg_base": "#000000", "accent_error": "red", "glow": "#ffffff"}}"##;
+        let imported = parse_theme(partial, false, "partial").unwrap();
+        assert_eq!(imported.name, "partial");
+        assert_eq!(imported.theme.bg_base, Color::Rgb(0, 0, 0));
+        assert_eq!(imported.theme.accent_error, Theme::default().accent_error);
+        assert_eq!(imported.warnings.len(), COLOR_NAMES.len());
+        assert!(imported.warnings.iter().any(|w| w.contains("'glow'")));
+
+        assert!(parse_theme("{}", false, "x").is_err());
+    }
+}
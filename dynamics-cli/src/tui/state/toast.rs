@@ -0,0 +1,130 @@
+//! Transient notifications shown in a corner of the screen
+//!
+//! Apps raise them with `Command::notify`; the multi-app runtime collects them
+//! into a `ToastStack` and drops each one once its timeout has passed.
+
+use std::time::{Duration, Instant};
+
+use ratatui::style::Color;
+
+use super::Theme;
+
+/// How long a toast stays on screen
+const TOAST_TIMEOUT: Duration = Duration::from_secs(4);
+
+/// Final stretch of a toast's life during which it is drawn dimmed
+const TOAST_FADE: Duration = Duration::from_millis(800);
+
+/// Most toasts shown at once; older ones are dropped first
+const MAX_TOASTS: usize = 5;
+
+/// Severity of a toast, which picks its color
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ToastLevel {
+    Info,
+    Success,
+    Warning,
+    Error,
+}
+
+impl ToastLevel {
+    /// Accent color for this severity
+    pub fn color(&self, theme: &Theme) -> Color {
+        match self {
+            Self::Info => theme.accent_info,
+            Self::Success => theme.accent_success,
+            Self::Warning => theme.accent_warning,
+            Self::Error => theme.accent_error,
+        }
+    }
+}
+
+/// A single notification
+#[derive(Debug, Clone)]
+pub struct Toast {
+    pub message: String,
+    pub level: ToastLevel,
+    expires_at: Instant,
+}
+
+impl Toast {
+    /// Whether the toast is in its last moments and should be drawn dimmed
+    pub fn is_fading(&self, now: Instant) -> bool {
+        self.expires_at.saturating_duration_since(now) < TOAST_FADE
+    }
+}
+
+/// Toasts currently on screen, oldest first
+#[derive(Debug, Clone, Default)]
+pub struct ToastStack {
+    toasts: Vec<Toast>,
+}
+
+impl ToastStack {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a toast that expires after the default timeout
+    pub fn push(&mut self, message: impl Into<String>, level: ToastLevel) {
+        self.push_at(message, level, Instant::now());
+    }
+
+    fn push_at(&mut self, message: impl Into<String>, level: ToastLevel, now: Instant) {
+        self.toasts.push(Toast {
+            message: message.into(),
+            level,
+            expires_at: now + TOAST_TIMEOUT,
+        });
+        if self.toasts.len() > MAX_TOASTS {
+            self.toasts.remove(0);
+        }
+    }
+
+    /// Drop toasts whose timeout has passed
+    pub fn prune(&mut self, now: Instant) {
+        self.toasts.retain(|toast| toast.expires_at > now);
+    }
+
+    /// Toasts to draw, newest first
+    pub fn newest_first(&self) -> impl Iterator<Item = &Toast> {
+        self.toasts.iter().rev()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.toasts.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_toasts_expire_and_list_newest_first() {
+        let start = Instant::now();
+        let mut stack = ToastStack::new();
+        stack.push_at("saved", ToastLevel::Success, start);
+        stack.push_at("failed", ToastLevel::Error, start + Duration::from_secs(2));
+
+        let messages: Vec<_> = stack.newest_first().map(|t| t.message.as_str()).collect();
+        assert_eq!(messages, ["failed", "saved"]);
+
+        let later = start + TOAST_TIMEOUT;
+        assert!(stack.newest_first().last().unwrap().is_fading(later - Duration::from_millis(1)));
+        stack.prune(later);
+        let messages: Vec<_> = stack.newest_first().map(|t| t.message.as_str()).collect();
+        assert_eq!(messages, ["failed"]);
+    }
+
+    #[test]
+    fn test_stack_keeps_only_the_newest_toasts() {
+        let mut stack = ToastStack::new();
+        for i in 0..MAX_TOASTS + 2 {
+            stack.push(format!("toast {}", i), ToastLevel::Info);
+        }
+
+        assert_eq!(stack.newest_first().count(), MAX_TOASTS);
+        assert_eq!(stack.newest_first().last().unwrap().message, "toast 2");
+    }
+}
@@ -1,6 +1,49 @@
 use crossterm::event::KeyCode;
+use std::time::{Duration, Instant};
 use crate::tui::widgets::TextInputState;
 
+/// Default pause after the last keystroke before remote suggestions are requested
+const DEFAULT_DEBOUNCE_MS: u64 = 300;
+
+/// Fuzzy match `input` against each option, returning (option index, score)
+/// for the matches, best first
+pub fn fuzzy_rank<'a>(input: &str, options: impl IntoIterator<Item = &'a str>) -> Vec<(usize, i64)> {
+    fuzzy_rank_with_indices(input, options)
+        .into_iter()
+        .map(|(idx, score, _)| (idx, score))
+        .collect()
+}
+
+/// Like [`fuzzy_rank`], also returning the char positions in each option that matched
+pub fn fuzzy_rank_with_indices<'a>(
+    input: &str,
+    options: impl IntoIterator<Item = &'a str>,
+) -> Vec<(usize, i64, Vec<usize>)> {
+    use fuzzy_matcher::FuzzyMatcher;
+    use fuzzy_matcher::skim::SkimMatcherV2;
+
+    let matcher = SkimMatcherV2::default();
+    let mut scored: Vec<(usize, i64, Vec<usize>)> = options
+        .into_iter()
+        .enumerate()
+        .filter_map(|(idx, opt)| matcher.fuzzy_indices(opt, input).map(|(score, indices)| (idx, score, indices)))
+        .collect();
+
+    // Sort by score descending (higher score = better match), stable for ties
+    scored.sort_by(|a, b| b.1.cmp(&a.1));
+    scored
+}
+
+/// An option that matched the autocomplete input
+#[derive(Debug, Clone, PartialEq)]
+pub struct AutocompleteMatch {
+    pub option: String,
+    /// Fuzzy score, higher is better (0 for remote suggestions)
+    pub score: i64,
+    /// Char positions in `option` that matched the input
+    pub indices: Vec<usize>,
+}
+
 /// Manages state for Autocomplete input widgets
 /// Combines text input with fuzzy-matched dropdown suggestions
 #[derive(Debug, Clone)]
@@ -14,11 +57,17 @@ pub struct AutocompleteState {
     /// Index of highlighted option in dropdown
     highlight_index: usize,
 
-    /// Filtered and scored options (option_text, score)
-    filtered_options: Vec<(String, i64)>,
+    /// Filtered options, best match first (top 15)
+    filtered_options: Vec<AutocompleteMatch>,
 
     /// Total count of available options (for validation)
     total_option_count: usize,
+
+    /// Remote mode: how long the input must be idle before suggestions are requested
+    debounce: Duration,
+
+    /// Remote mode: when the input last changed
+    last_input: Option<Instant>,
 }
 
 impl Default for AutocompleteState {
@@ -36,9 +85,32 @@ impl AutocompleteState {
             highlight_index: 0,
             filtered_options: Vec::new(),
             total_option_count: 0,
+            debounce: Duration::from_millis(DEFAULT_DEBOUNCE_MS),
+            last_input: None,
         }
     }
 
+    /// Set the remote-mode debounce time (default: 300ms)
+    pub fn with_debounce_ms(mut self, ms: u64) -> Self {
+        self.debounce = Duration::from_millis(ms);
+        self
+    }
+
+    /// Remote-mode debounce time
+    pub fn debounce(&self) -> Duration {
+        self.debounce
+    }
+
+    /// Record that the input changed, restarting the debounce time
+    pub fn mark_input_changed(&mut self) {
+        self.last_input = Some(Instant::now());
+    }
+
+    /// Whether the input has been idle for at least the debounce time
+    pub fn is_settled(&self) -> bool {
+        self.last_input.is_none_or(|changed| changed.elapsed() >= self.debounce)
+    }
+
     /// Get reference to text input state
     pub fn input_state(&self) -> &TextInputState {
         &self.input_state
@@ -69,15 +141,23 @@ impl AutocompleteState {
 
     /// Get filtered options (top 15 by score)
     pub fn filtered_options(&self) -> Vec<String> {
-        self.filtered_options.iter().map(|(opt, _)| opt.clone()).collect()
+        self.filtered_options.iter().map(|m| m.option.clone()).collect()
+    }
+
+    /// Filtered options with their scores and matched positions, best first
+    pub fn scored_matches(&self) -> &[AutocompleteMatch] {
+        &self.filtered_options
+    }
+
+    /// Reorder options with equal scores using `compare` (e.g. prefer shorter names)
+    pub fn tie_break_by(&mut self, mut compare: impl FnMut(&AutocompleteMatch, &AutocompleteMatch) -> std::cmp::Ordering) {
+        self.filtered_options.sort_by(|a, b| b.score.cmp(&a.score).then_with(|| compare(a, b)));
+        self.highlight_index = 0;
     }
 
     /// Update filtered options using fuzzy matching
     /// Automatically opens/closes dropdown based on results
     pub fn update_filtered_options(&mut self, input: &str, all_options: &[String]) {
-        use fuzzy_matcher::FuzzyMatcher;
-        use fuzzy_matcher::skim::SkimMatcherV2;
-
         self.total_option_count = all_options.len();
 
         if input.is_empty() {
@@ -88,22 +168,13 @@ impl AutocompleteState {
             return;
         }
 
-        // Fuzzy match and score all options
-        let matcher = SkimMatcherV2::default();
-        let mut scored: Vec<(String, i64)> = all_options
-            .iter()
-            .filter_map(|opt| {
-                matcher.fuzzy_match(opt, input)
-                    .map(|score| (opt.clone(), score))
-            })
+        // Fuzzy match and score all options, keeping the top 15 results
+        self.filtered_options = fuzzy_rank_with_indices(input, all_options.iter().map(String::as_str))
+            .into_iter()
+            .take(15)
+            .map(|(idx, score, indices)| AutocompleteMatch { option: all_options[idx].clone(), score, indices })
             .collect();
 
-        // Sort by score descending (higher score = better match)
-        scored.sort_by(|a, b| b.1.cmp(&a.1));
-
-        // Take top 15 results
-        self.filtered_options = scored.into_iter().take(15).collect();
-
         // Auto-open dropdown if we have results
         if !self.filtered_options.is_empty() {
             self.is_open = true;
@@ -115,6 +186,19 @@ impl AutocompleteState {
         }
     }
 
+    /// Show suggestions fetched by the app, in the order given (top 15)
+    /// Automatically opens/closes dropdown based on results
+    pub fn set_remote_options(&mut self, options: Vec<String>) {
+        self.total_option_count = options.len();
+        self.filtered_options = options
+            .into_iter()
+            .take(15)
+            .map(|option| AutocompleteMatch { option, score: 0, indices: Vec::new() })
+            .collect();
+        self.is_open = !self.filtered_options.is_empty();
+        self.highlight_index = 0;
+    }
+
     /// Open the dropdown
     pub fn open(&mut self) {
         if !self.filtered_options.is_empty() {
@@ -150,11 +234,7 @@ impl AutocompleteState {
 
     /// Get the currently highlighted option text
     pub fn get_highlighted_option(&self) -> Option<String> {
-        if self.highlight_index < self.filtered_options.len() {
-            Some(self.filtered_options[self.highlight_index].0.clone())
-        } else {
-            None
-        }
+        self.filtered_options.get(self.highlight_index).map(|m| m.option.clone())
     }
 
     /// Handle a key press in the text input
@@ -207,3 +287,42 @@ impl AutocompleteState {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_remote_options_wait_for_debounce() {
+        let mut state = AutocompleteState::new().with_debounce_ms(20);
+        assert!(state.is_settled());
+
+        state.mark_input_changed();
+        assert!(!state.is_settled());
+        std::thread::sleep(Duration::from_millis(25));
+        assert!(state.is_settled());
+
+        state.set_remote_options(vec!["account".to_string(), "accountleads".to_string()]);
+        assert!(state.is_open());
+        assert_eq!(state.get_highlighted_option().as_deref(), Some("account"));
+
+        state.set_remote_options(Vec::new());
+        assert!(!state.is_open());
+    }
+
+    #[test]
+    fn test_matches_carry_scores_and_positions() {
+        let options: Vec<String> = ["contact", "account", "accountleads"].iter().map(|s| s.to_string()).collect();
+        let mut state = AutocompleteState::new();
+        state.update_filtered_options("acnt", &options);
+
+        let matches = state.scored_matches();
+        assert_eq!(matches.len(), 2);
+        assert!(matches[0].score >= matches[1].score);
+        let account = matches.iter().find(|m| m.option == "account").unwrap();
+        assert_eq!(account.indices, vec![0, 1, 5, 6]);
+
+        state.tie_break_by(|a, b| b.option.len().cmp(&a.option.len()));
+        assert!(state.scored_matches().windows(2).all(|pair| pair[0].score >= pair[1].score));
+    }
+}
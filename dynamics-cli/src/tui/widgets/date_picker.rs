@@ -0,0 +1,226 @@
+//! Date picker widget state and logic
+
+use chrono::{Datelike, Days, Local, Months, NaiveDate};
+use crossterm::event::KeyCode;
+use super::events::DatePickerEvent;
+
+/// Date picker display mode
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DatePickerMode {
+    /// Compact single line showing the selected date
+    Inline,
+    /// Month calendar grid for picking a day
+    Calendar,
+}
+
+impl DatePickerMode {
+    /// Toggle between inline and calendar
+    pub fn toggle(&self) -> Self {
+        match self {
+            Self::Inline => Self::Calendar,
+            Self::Calendar => Self::Inline,
+        }
+    }
+}
+
+/// Date picker widget state
+#[derive(Debug, Clone)]
+pub struct DatePickerState {
+    /// Display mode (inline or calendar)
+    mode: DatePickerMode,
+
+    /// Confirmed date, if any
+    selected: Option<NaiveDate>,
+
+    /// Day highlighted in the calendar
+    cursor: NaiveDate,
+}
+
+impl Default for DatePickerState {
+    fn default() -> Self {
+        Self::new(None)
+    }
+}
+
+impl DatePickerState {
+    /// Create a new date picker, collapsed, with the calendar opening on `date` (or today)
+    pub fn new(date: Option<NaiveDate>) -> Self {
+        Self {
+            mode: DatePickerMode::Inline,
+            selected: date,
+            cursor: date.unwrap_or_else(|| Local::now().date_naive()),
+        }
+    }
+
+    /// Get current display mode
+    pub fn mode(&self) -> DatePickerMode {
+        self.mode
+    }
+
+    /// Is the calendar expanded?
+    pub fn is_open(&self) -> bool {
+        self.mode == DatePickerMode::Calendar
+    }
+
+    /// Get the confirmed date
+    pub fn selected(&self) -> Option<NaiveDate> {
+        self.selected
+    }
+
+    /// Get the day highlighted in the calendar
+    pub fn cursor(&self) -> NaiveDate {
+        self.cursor
+    }
+
+    /// Set the confirmed date and collapse the calendar
+    pub fn select(&mut self, date: NaiveDate) {
+        self.selected = Some(date);
+        self.cursor = date;
+        self.mode = DatePickerMode::Inline;
+    }
+
+    /// Clear the confirmed date
+    pub fn clear(&mut self) {
+        self.selected = None;
+    }
+
+    /// Handle a widget event
+    ///
+    /// Returns the date when one was selected
+    pub fn handle_event(&mut self, event: DatePickerEvent) -> Option<NaiveDate> {
+        match event {
+            DatePickerEvent::Changed(key) => {
+                self.handle_key(key);
+                None
+            }
+            DatePickerEvent::Selected(date) => {
+                self.select(date);
+                Some(date)
+            }
+        }
+    }
+
+    /// Handle keyboard input
+    ///
+    /// Enter opens the calendar; while open, arrows move by day/week, PageUp/PageDown
+    /// by month and Esc collapses it again. Returns true if the cursor moved.
+    pub fn handle_key(&mut self, key: KeyCode) -> bool {
+        if !self.is_open() {
+            if matches!(key, KeyCode::Enter | KeyCode::Char(' ')) {
+                self.mode = DatePickerMode::Calendar;
+            }
+            return false;
+        }
+
+        let moved = match key {
+            KeyCode::Left => self.cursor.checked_sub_days(Days::new(1)),
+            KeyCode::Right => self.cursor.checked_add_days(Days::new(1)),
+            KeyCode::Up => self.cursor.checked_sub_days(Days::new(7)),
+            KeyCode::Down => self.cursor.checked_add_days(Days::new(7)),
+            KeyCode::PageUp => self.cursor.checked_sub_months(Months::new(1)),
+            KeyCode::PageDown => self.cursor.checked_add_months(Months::new(1)),
+            KeyCode::Home => self.cursor.with_day(1),
+            KeyCode::End => Some(last_day_of_month(self.cursor)),
+            KeyCode::Esc => {
+                // Drop the unconfirmed cursor so reopening starts at the selection
+                self.mode = DatePickerMode::Inline;
+                if let Some(selected) = self.selected {
+                    self.cursor = selected;
+                }
+                None
+            }
+            _ => None,
+        };
+
+        match moved {
+            Some(date) if date != self.cursor => {
+                self.cursor = date;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Weeks of the cursor's month, Monday first; days outside the month are None
+    pub fn month_grid(&self) -> Vec<[Option<NaiveDate>; 7]> {
+        let first = self.cursor.with_day(1).unwrap_or(self.cursor);
+        let last = last_day_of_month(self.cursor);
+        let lead = first.weekday().num_days_from_monday() as usize;
+
+        let mut weeks = Vec::new();
+        let mut week = [None; 7];
+        for (i, day) in first.iter_days().take_while(|day| *day <= last).enumerate() {
+            let slot = (lead + i) % 7;
+            week[slot] = Some(day);
+            if slot == 6 {
+                weeks.push(std::mem::replace(&mut week, [None; 7]));
+            }
+        }
+        if week.iter().any(Option::is_some) {
+            weeks.push(week);
+        }
+        weeks
+    }
+}
+
+fn last_day_of_month(date: NaiveDate) -> NaiveDate {
+    let first = date.with_day(1).unwrap_or(date);
+    first
+        .checked_add_months(Months::new(1))
+        .and_then(|next| next.pred_opt())
+        .unwrap_or(date)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_enter_opens_and_keys_move_cursor() {
+        let mut state = DatePickerState::new(Some(date(2024, 1, 31)));
+        assert!(!state.handle_key(KeyCode::Right));
+        state.handle_key(KeyCode::Enter);
+        assert!(state.is_open());
+
+        state.handle_key(KeyCode::Right);
+        assert_eq!(state.cursor(), date(2024, 2, 1));
+        state.handle_key(KeyCode::PageDown);
+        assert_eq!(state.cursor(), date(2024, 3, 1));
+        state.handle_key(KeyCode::End);
+        assert_eq!(state.cursor(), date(2024, 3, 31));
+
+        // Esc returns to the confirmed date
+        state.handle_key(KeyCode::Esc);
+        assert!(!state.is_open());
+        assert_eq!(state.cursor(), date(2024, 1, 31));
+    }
+
+    #[test]
+    fn test_selected_event_confirms_and_collapses() {
+        let mut state = DatePickerState::new(None);
+        state.handle_key(KeyCode::Enter);
+
+        let picked = state.handle_event(DatePickerEvent::Selected(date(2024, 5, 6)));
+
+        assert_eq!(picked, Some(date(2024, 5, 6)));
+        assert_eq!(state.selected(), Some(date(2024, 5, 6)));
+        assert_eq!(state.mode(), DatePickerMode::Inline);
+    }
+
+    #[test]
+    fn test_month_grid_starts_on_monday() {
+        // February 2024 starts on a Thursday and has 29 days
+        let state = DatePickerState::new(Some(date(2024, 2, 10)));
+        let grid = state.month_grid();
+
+        assert_eq!(grid.len(), 5);
+        assert_eq!(grid[0][..3], [None, None, None]);
+        assert_eq!(grid[0][3], Some(date(2024, 2, 1)));
+        assert_eq!(grid[4][3], Some(date(2024, 2, 29)));
+        assert_eq!(grid[4][4], None);
+    }
+}
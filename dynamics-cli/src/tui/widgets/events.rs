@@ -9,6 +9,9 @@ pub enum AutocompleteEvent {
     Navigate(KeyCode),
     /// Item selected from dropdown (via click)
     Select(String),
+    /// Remote mode: the input has been idle for the debounce time, fetch
+    /// suggestions for this text (see `AutocompleteField::handle_remote_event`)
+    InputChanged(String),
 }
 
 /// Event type for TextInput widget
@@ -18,6 +21,12 @@ pub enum TextInputEvent {
     Changed(KeyCode),
     /// Submit action (Enter key)
     Submit,
+    /// Text pasted from the clipboard (Ctrl+V), inserted at the cursor
+    Paste(String),
+    /// Move the cursor while extending the selection (Shift+Left/Right/Home/End)
+    Select(KeyCode),
+    /// Select the whole value (Ctrl+A)
+    SelectAll,
 }
 
 /// Event type for List widget
@@ -25,8 +34,18 @@ pub enum TextInputEvent {
 pub enum ListEvent {
     /// Navigation keys (Up/Down/PageUp/PageDown/Home/End)
     Navigate(KeyCode),
+    /// Mouse wheel over the list: move the viewport by this many rows
+    Scroll(isize),
     /// Item selected (Enter or click)
     Select,
+    /// Filter query typed (`/` opens the query box with an empty query)
+    FilterChanged(String),
+    /// Filter closed with Esc
+    FilterCleared,
+    /// Move the item at `from` to `to` (Alt+Up/Down in a reorderable list)
+    ///
+    /// The owning app moves the item in its backing vec; `ListState` moves the selection.
+    Reorder { from: usize, to: usize },
 }
 
 /// Event type for Tree widget
@@ -34,6 +53,8 @@ pub enum ListEvent {
 pub enum TreeEvent {
     /// Navigation keys (Up/Down/Left/Right)
     Navigate(KeyCode),
+    /// Mouse wheel over the tree: move the viewport by this many rows
+    Scroll(isize),
     /// Toggle node expansion (Enter key)
     Toggle,
     /// Toggle multi-selection on current node (Space key)
@@ -46,6 +67,25 @@ pub enum TreeEvent {
     ExtendSelectionUp,
     /// Extend selection down (Shift+Down)
     ExtendSelectionDown,
+    /// Move the focused table column (Shift+Left/Right), scrolling it into view
+    FocusColumn { column: usize, first_visible: usize },
+    /// Enter or leave column resize mode (Ctrl+R)
+    ToggleColumnResize,
+    /// Column widths after a resize (Left/Right in resize mode)
+    ResizeColumns(Vec<ratatui::layout::Constraint>),
+}
+
+/// Event type for Table widget (sorting goes through `on_sort`)
+#[derive(Clone, Debug)]
+pub enum TableEvent {
+    /// Row navigation (Up/Down/PageUp/PageDown/Home/End)
+    Navigate(KeyCode),
+    /// Mouse wheel over the rows: move the viewport by this many rows
+    Scroll(isize),
+    /// Column focus moved (Left/Right); `s` sorts by the focused column
+    FocusColumn(usize),
+    /// Row chosen (Enter or click)
+    Select(usize),
 }
 
 /// Event type for Select widget
@@ -80,3 +120,23 @@ pub enum ColorPickerEvent {
     /// Color confirmed (Enter key)
     Submitted(ratatui::style::Color),
 }
+
+/// Event type for NumberInput widget
+#[derive(Clone, Debug)]
+pub enum NumberInputEvent {
+    /// Key typed into the text (digits, `-`, Backspace)
+    Key(KeyCode),
+    /// Value stepped to this number (Up/Down/PageUp/PageDown/Home/End)
+    Changed(i64),
+    /// Value confirmed (Enter key)
+    Submit,
+}
+
+/// Event type for DatePicker widget
+#[derive(Clone, Debug)]
+pub enum DatePickerEvent {
+    /// Key pressed (for state updates)
+    Changed(KeyCode),
+    /// Day confirmed in the calendar (Enter key or click)
+    Selected(chrono::NaiveDate),
+}
@@ -1,8 +1,22 @@
 use crate::tui::command::Command;
-use super::{AutocompleteState, TextInputState, SelectState};
+use super::{AutocompleteState, TextAreaState, TextInputState, SelectState};
 use super::events::{AutocompleteEvent, TextInputEvent, SelectEvent};
 
 /// Field that combines value + state for Autocomplete widget
+///
+/// Suggestions either come from a static list passed to [`Self::handle_event`],
+/// or are fetched by the app in remote mode:
+///
+/// ```rust
+/// Msg::EntityEvent(AutocompleteEvent::InputChanged(query)) if state.entity.is_settled(&query) => {
+///     Command::perform(search_entities(query.clone()), move |found| Msg::EntitiesFound(query.clone(), found))
+/// }
+/// Msg::EntityEvent(event) => state.entity.handle_remote_event(event, Msg::EntityEvent),
+/// Msg::EntitiesFound(query, found) => {
+///     state.entity.set_remote_options(&query, found);
+///     Command::None
+/// }
+/// ```
 #[derive(Clone, Default)]
 pub struct AutocompleteField {
     pub value: String,
@@ -43,10 +57,58 @@ impl AutocompleteField {
                 self.state.close();
                 self.state.set_cursor_to_end(&self.value);
             }
+            AutocompleteEvent::InputChanged(_) => {}
         }
         Command::None
     }
 
+    /// Handle autocomplete event for a field whose suggestions the app fetches
+    ///
+    /// Each edit schedules an `InputChanged` through `on_event` after the
+    /// debounce time; only the one for the last edit passes [`Self::is_settled`],
+    /// so the app makes one request per pause in typing.
+    pub fn handle_remote_event<Msg: Send + 'static>(
+        &mut self,
+        event: AutocompleteEvent,
+        on_event: fn(AutocompleteEvent) -> Msg,
+    ) -> Command<Msg> {
+        let AutocompleteEvent::Input(key) = event else {
+            return self.handle_event(event, &[]);
+        };
+        let Some(new_value) = self.state.handle_input_key(key, &self.value, None) else {
+            return Command::None;
+        };
+
+        self.value = new_value;
+        self.state.mark_input_changed();
+        if self.value.is_empty() {
+            self.state.set_remote_options(Vec::new());
+            return Command::None;
+        }
+
+        let query = self.value.clone();
+        let debounce = self.state.debounce();
+        Command::perform(
+            async move {
+                tokio::time::sleep(debounce).await;
+                query
+            },
+            move |query| on_event(AutocompleteEvent::InputChanged(query)),
+        )
+    }
+
+    /// Whether `query` from an `InputChanged` event is still the input and typing has paused
+    pub fn is_settled(&self, query: &str) -> bool {
+        self.value == query && self.state.is_settled()
+    }
+
+    /// Show suggestions fetched for `query`; results for outdated input are dropped
+    pub fn set_remote_options(&mut self, query: &str, options: Vec<String>) {
+        if self.value == query {
+            self.state.set_remote_options(options);
+        }
+    }
+
     /// Get current value
     pub fn value(&self) -> &str {
         &self.value
@@ -79,6 +141,43 @@ impl TextInputField {
 
     /// Handle text input event and return command (usually None unless Submit)
     /// Returns Some(value) on Submit, None otherwise
+    pub fn handle_event(&mut self, event: TextInputEvent, max_length: Option<usize>) -> Option<String> {
+        if let TextInputEvent::Submit = event {
+            return Some(self.value.clone());
+        }
+        if let Some(new_value) = self.state.handle_event(event, &self.value, max_length) {
+            self.value = new_value;
+        }
+        None
+    }
+
+    /// Get current value
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// Set value (useful for initialization)
+    /// Cursor is positioned at the end of the value
+    pub fn set_value(&mut self, value: String) {
+        self.value = value;
+        self.state.set_cursor_to_end(&self.value);
+    }
+}
+
+/// Field that combines value + state for TextArea widget
+#[derive(Clone, Default)]
+pub struct TextAreaField {
+    pub value: String,
+    pub state: TextAreaState,
+}
+
+impl TextAreaField {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Handle text input event (Enter inserts a newline)
+    /// Returns Some(value) on Submit, None otherwise
     pub fn handle_event(&mut self, event: TextInputEvent, max_length: Option<usize>) -> Option<String> {
         match event {
             TextInputEvent::Changed(key) => {
@@ -90,6 +189,14 @@ impl TextInputField {
             TextInputEvent::Submit => {
                 Some(self.value.clone())
             }
+            TextInputEvent::Paste(text) => {
+                if let Some(new_value) = self.state.insert_str(&text, &self.value, max_length) {
+                    self.value = new_value;
+                }
+                None
+            }
+            // Text areas don't track a selection
+            TextInputEvent::Select(_) | TextInputEvent::SelectAll => None,
         }
     }
 
@@ -0,0 +1,242 @@
+//! Side-by-side diff of two JSON values, displayed through the Tree widget
+
+use std::collections::BTreeSet;
+use std::marker::PhantomData;
+
+use ratatui::{style::{Color, Style}, text::{Line, Span}, prelude::Stylize};
+use serde_json::Value;
+
+use crate::tui::{Element, LayoutConstraint, Theme};
+use crate::tui::element::RowBuilder;
+use super::tree::{TreeItem, TreeState};
+
+/// How a key differs between the left and right value
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JsonDiffStatus {
+    Unchanged,
+    /// Only present on the right
+    Added,
+    /// Only present on the left
+    Removed,
+    /// Present on both sides with different values
+    Changed,
+}
+
+impl JsonDiffStatus {
+    /// Color used for this status
+    pub fn color(&self, theme: &Theme) -> Color {
+        match self {
+            Self::Unchanged => theme.text_secondary,
+            Self::Added => theme.accent_success,
+            Self::Removed => theme.accent_error,
+            Self::Changed => theme.accent_warning,
+        }
+    }
+}
+
+/// One key (or array index) of the diff, with both sides' values
+#[derive(Debug, Clone)]
+pub struct JsonDiffNode<Msg> {
+    path: String,
+    key: String,
+    left: Option<Value>,
+    right: Option<Value>,
+    status: JsonDiffStatus,
+    children: Vec<JsonDiffNode<Msg>>,
+    _msg: PhantomData<fn() -> Msg>,
+}
+
+impl<Msg> JsonDiffNode<Msg> {
+    fn new(path: String, key: String, left: Option<&Value>, right: Option<&Value>) -> Self {
+        let status = match (left, right) {
+            (Some(l), Some(r)) if l == r => JsonDiffStatus::Unchanged,
+            (Some(_), Some(_)) => JsonDiffStatus::Changed,
+            (Some(_), None) => JsonDiffStatus::Removed,
+            _ => JsonDiffStatus::Added,
+        };
+
+        Self {
+            children: diff_children(&path, left, right),
+            path,
+            key,
+            left: left.cloned(),
+            right: right.cloned(),
+            status,
+            _msg: PhantomData,
+        }
+    }
+
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    pub fn status(&self) -> JsonDiffStatus {
+        self.status
+    }
+}
+
+/// Diff two JSON values key by key
+///
+/// Returns one node per top-level key (or array index) found on either side.
+pub fn diff_json<Msg>(left: &Value, right: &Value) -> Vec<JsonDiffNode<Msg>> {
+    diff_children("", Some(left), Some(right))
+}
+
+/// Expand every branch that contains a change so unchanged subtrees stay collapsed
+pub fn expand_changes<Msg>(nodes: &[JsonDiffNode<Msg>], state: &mut TreeState) {
+    for node in nodes {
+        if node.status == JsonDiffStatus::Changed && !node.children.is_empty() {
+            state.expand(&node.path);
+            expand_changes(&node.children, state);
+        }
+    }
+}
+
+fn diff_children<Msg>(path: &str, left: Option<&Value>, right: Option<&Value>) -> Vec<JsonDiffNode<Msg>> {
+    let child_path = |key: &str| {
+        if path.is_empty() { key.to_string() } else { format!("{}/{}", path, key) }
+    };
+
+    match (left, right) {
+        (Some(Value::Array(l)), Some(Value::Array(r))) => (0..l.len().max(r.len()))
+            .map(|i| JsonDiffNode::new(child_path(&i.to_string()), format!("[{}]", i), l.get(i), r.get(i)))
+            .collect(),
+        (Some(Value::Array(items)), _) | (None, Some(Value::Array(items))) => {
+            let is_left = left.is_some_and(Value::is_array);
+            items.iter().enumerate().map(|(i, item)| {
+                let (l, r) = if is_left { (Some(item), None) } else { (None, Some(item)) };
+                JsonDiffNode::new(child_path(&i.to_string()), format!("[{}]", i), l, r)
+            }).collect()
+        }
+        _ => {
+            let l = left.and_then(Value::as_object);
+            let r = right.and_then(Value::as_object);
+
+            // Keys from both sides, sorted so added keys sit among the rest
+            let keys: BTreeSet<&String> = l.into_iter().chain(r).flat_map(|o| o.keys()).collect();
+
+            keys.into_iter()
+                .map(|key| JsonDiffNode::new(
+                    child_path(key),
+                    key.clone(),
+                    l.and_then(|o| o.get(key)),
+                    r.and_then(|o| o.get(key)),
+                ))
+                .collect()
+        }
+    }
+}
+
+/// Short one-line rendering of a value; containers show their size
+fn preview(value: &Value) -> String {
+    match value {
+        Value::Object(map) => format!("{{{} keys}}", map.len()),
+        Value::Array(items) => format!("[{} items]", items.len()),
+        other => other.to_string(),
+    }
+}
+
+impl<Msg: Clone> TreeItem for JsonDiffNode<Msg> {
+    type Msg = Msg;
+
+    fn id(&self) -> String {
+        self.path.clone()
+    }
+
+    fn has_children(&self) -> bool {
+        !self.children.is_empty()
+    }
+
+    fn children(&self) -> Vec<Self> {
+        self.children.clone()
+    }
+
+    fn to_element(
+        &self,
+        depth: usize,
+        is_selected: bool,
+        _is_multi_selected: bool,
+        is_expanded: bool,
+    ) -> Element<Self::Msg> {
+        let theme = &crate::global_runtime_config().theme;
+        let indent = "  ".repeat(depth);
+        let icon = match (self.has_children(), is_expanded) {
+            (false, _) => "  ",
+            (true, true) => "▼ ",
+            (true, false) => "▶ ",
+        };
+        let color = self.status.color(theme);
+        // Bold keys make differences easy to spot when scanning the rows
+        let key_style = match self.status {
+            JsonDiffStatus::Unchanged => Style::default().fg(theme.text_primary),
+            _ => Style::default().fg(theme.text_primary).bold(),
+        };
+
+        let side = |value: &Option<Value>, icon: &str| {
+            let mut spans = vec![
+                Span::raw(indent.clone()),
+                Span::styled(icon.to_string(), Style::default().fg(theme.border_primary)),
+            ];
+            if let Some(value) = value {
+                spans.push(Span::styled(format!("{}: ", self.key), key_style));
+                spans.push(Span::styled(preview(value), Style::default().fg(color)));
+            }
+
+            let mut builder = Element::styled_text(Line::from(spans));
+            if is_selected {
+                builder = builder.background(Style::default().bg(theme.bg_surface));
+            }
+            builder.build()
+        };
+
+        RowBuilder::new()
+            .add(side(&self.left, icon), LayoutConstraint::Fill(1))
+            .add(side(&self.right, "  "), LayoutConstraint::Fill(1))
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn find<'a>(nodes: &'a [JsonDiffNode<()>], key: &str) -> &'a JsonDiffNode<()> {
+        nodes.iter().find(|n| n.key() == key).unwrap()
+    }
+
+    #[test]
+    fn test_diff_marks_added_removed_and_changed_keys() {
+        let left = json!({"name": "a", "old": 1, "nested": {"x": 1, "y": 2}, "same": {"z": 0}});
+        let right = json!({"name": "a", "nested": {"x": 1, "y": 3}, "same": {"z": 0}, "new": true});
+
+        let nodes: Vec<JsonDiffNode<()>> = diff_json(&left, &right);
+
+        let keys: Vec<_> = nodes.iter().map(|n| n.key()).collect();
+        assert_eq!(keys, ["name", "nested", "new", "old", "same"]);
+        assert_eq!(find(&nodes, "name").status(), JsonDiffStatus::Unchanged);
+        assert_eq!(find(&nodes, "old").status(), JsonDiffStatus::Removed);
+        assert_eq!(find(&nodes, "new").status(), JsonDiffStatus::Added);
+
+        let nested = find(&nodes, "nested");
+        assert_eq!(nested.status(), JsonDiffStatus::Changed);
+        assert_eq!(find(&nested.children, "y").status(), JsonDiffStatus::Changed);
+        assert_eq!(find(&nested.children, "y").id(), "nested/y");
+    }
+
+    #[test]
+    fn test_expand_changes_leaves_unchanged_subtrees_collapsed() {
+        let left = json!({"a": {"b": [1, 2]}, "same": {"z": 0}});
+        let right = json!({"a": {"b": [1, 2, 3]}, "same": {"z": 0}});
+        let nodes: Vec<JsonDiffNode<()>> = diff_json(&left, &right);
+        let mut state = TreeState::new();
+
+        expand_changes(&nodes, &mut state);
+
+        assert!(state.is_expanded("a"));
+        assert!(state.is_expanded("a/b"));
+        assert!(!state.is_expanded("same"));
+        let added = &find(&find(&nodes, "a").children, "b").children[2];
+        assert_eq!(added.status(), JsonDiffStatus::Added);
+    }
+}
@@ -12,6 +12,44 @@ pub trait ListItem {
     fn height(&self) -> u16 {
         1
     }
+
+    /// Optional: text matched by the list filter (items without it never match)
+    fn filter_text(&self) -> Option<String> {
+        None
+    }
+}
+
+/// Move a viewport by `delta` rows without scrolling past either end
+///
+/// Returns the new offset and the selection pulled back inside the viewport
+/// (keeping `scroll_off` rows from its edges), so a later `update_scroll`
+/// doesn't snap the view back to where the selection was.
+pub(crate) fn scroll_viewport(
+    offset: usize,
+    delta: isize,
+    item_count: usize,
+    visible_height: usize,
+    scroll_off: usize,
+    selected: Option<usize>,
+) -> (usize, Option<usize>) {
+    let max_offset = item_count.saturating_sub(visible_height);
+    let offset = offset.saturating_add_signed(delta).min(max_offset);
+
+    let Some(sel) = selected else {
+        return (offset, None);
+    };
+    if item_count == 0 || visible_height == 0 {
+        return (offset, Some(sel));
+    }
+
+    let scroll_off = scroll_off.min(visible_height.saturating_sub(1) / 2);
+    let first = if offset == 0 { 0 } else { offset + scroll_off };
+    let last = if offset == max_offset {
+        item_count - 1
+    } else {
+        offset + visible_height - 1 - scroll_off
+    };
+    (offset, Some(sel.clamp(first, last)))
 }
 
 /// Manages list selection and scrolling state
@@ -22,6 +60,8 @@ pub struct ListState {
     scroll_off: usize, // Rows from edge before scrolling (like vim scrolloff)
     wrap_around: bool, // Wrap to bottom/top when reaching edges
     viewport_height: Option<usize>, // Last known viewport height from renderer
+    filter: Option<String>, // Filter query while the query box is open
+    filter_matches: Option<Vec<usize>>, // Indices matching a non-empty query
 }
 
 impl Default for ListState {
@@ -39,6 +79,8 @@ impl ListState {
             scroll_off: 3,
             wrap_around: true,
             viewport_height: None,
+            filter: None,
+            filter_matches: None,
         }
     }
 
@@ -50,6 +92,8 @@ impl ListState {
             scroll_off: 3,
             wrap_around: true,
             viewport_height: None,
+            filter: None,
+            filter_matches: None,
         }
     }
 
@@ -80,6 +124,58 @@ impl ListState {
         self.scroll_offset
     }
 
+    /// Get the filter query, if the query box is open
+    pub fn filter(&self) -> Option<&str> {
+        self.filter.as_deref()
+    }
+
+    /// Get indices of items matching the filter (None when not filtering)
+    pub fn filter_matches(&self) -> Option<&[usize]> {
+        self.filter_matches.as_deref()
+    }
+
+    /// Fuzzy-match items against the current filter query
+    ///
+    /// Call after a `ListEvent::FilterChanged` (or when the items change) so
+    /// navigation is restricted to matches. Moves the selection onto the first
+    /// match if the selected item no longer matches.
+    pub fn apply_filter<T: ListItem>(&mut self, items: &[T]) {
+        use fuzzy_matcher::FuzzyMatcher;
+        use fuzzy_matcher::skim::SkimMatcherV2;
+
+        let query = match self.filter.as_deref() {
+            Some(query) if !query.is_empty() => query,
+            _ => {
+                self.filter_matches = None;
+                return;
+            }
+        };
+
+        let matcher = SkimMatcherV2::default();
+        let matches: Vec<usize> = items
+            .iter()
+            .enumerate()
+            .filter(|(_, item)| {
+                item.filter_text()
+                    .is_some_and(|text| matcher.fuzzy_match(&text, query).is_some())
+            })
+            .map(|(i, _)| i)
+            .collect();
+
+        if !self.selected.is_some_and(|sel| matches.contains(&sel)) {
+            if let Some(&first) = matches.first() {
+                self.select_and_scroll(Some(first), items.len());
+            }
+        }
+        self.filter_matches = Some(matches);
+    }
+
+    /// Close the filter and restore navigation over all items
+    pub fn clear_filter(&mut self) {
+        self.filter = None;
+        self.filter_matches = None;
+    }
+
     /// Set selected index (useful for initialization)
     /// Note: This does NOT adjust scroll. Use select_and_scroll() if you need
     /// to ensure the selected item is visible.
@@ -106,6 +202,10 @@ impl ListState {
         // Use stored viewport_height if available, otherwise use provided value
         let height = self.viewport_height.unwrap_or(visible_height);
 
+        if let Some(matches) = self.filter_matches.clone() {
+            return self.handle_filtered_key(key, &matches, item_count, height);
+        }
+
         match key {
             KeyCode::Up => {
                 self.move_up(item_count, height);
@@ -135,6 +235,35 @@ impl ListState {
         }
     }
 
+    /// Navigate between filter matches only
+    fn handle_filtered_key(&mut self, key: KeyCode, matches: &[usize], item_count: usize, height: usize) -> bool {
+        if matches.is_empty() {
+            return false;
+        }
+
+        // Position of the selection among the matches (or where it would be)
+        let current = self.selected.map(|sel| matches.partition_point(|&m| m < sel));
+        let last = matches.len() - 1;
+        let position = match (key, current) {
+            (KeyCode::Up, Some(0)) if self.wrap_around => last,
+            (KeyCode::Up, Some(pos)) => pos.saturating_sub(1),
+            (KeyCode::Down, Some(pos)) if self.selected == Some(matches[pos.min(last)]) => {
+                if pos < last { pos + 1 } else if self.wrap_around { 0 } else { last }
+            }
+            (KeyCode::Down, Some(pos)) => pos.min(last),
+            (KeyCode::PageUp, Some(pos)) => pos.saturating_sub(height),
+            (KeyCode::PageDown, Some(pos)) => (pos + height).min(last),
+            (KeyCode::Home, _) => 0,
+            (KeyCode::End, _) => last,
+            (KeyCode::Up | KeyCode::Down | KeyCode::PageUp | KeyCode::PageDown, None) => 0,
+            _ => return false,
+        };
+
+        self.selected = Some(matches[position]);
+        self.update_scroll(height, item_count);
+        true
+    }
+
     fn move_up(&mut self, item_count: usize, visible_height: usize) {
         if item_count == 0 {
             return;
@@ -235,6 +364,24 @@ impl ListState {
         }
     }
 
+    /// Scroll the viewport by `delta` rows (mouse wheel), dragging the selection along
+    pub fn scroll_by(&mut self, delta: isize, item_count: usize, visible_height: usize) {
+        let height = self.viewport_height.unwrap_or(visible_height);
+        let (offset, selected) = scroll_viewport(
+            self.scroll_offset, delta, item_count, height, self.scroll_off, self.selected,
+        );
+        self.scroll_offset = offset;
+
+        // While filtering, only matches can be selected
+        self.selected = match (&self.filter_matches, selected) {
+            (Some(matches), Some(sel)) if !matches.contains(&sel) => {
+                let visible = offset..offset + height;
+                matches.iter().copied().filter(|m| visible.contains(m)).min_by_key(|m| m.abs_diff(sel)).or(self.selected)
+            }
+            _ => selected,
+        };
+    }
+
     /// Handle list event (unified event pattern)
     /// Returns Some(selected_index) on Select event, None otherwise
     pub fn handle_event(&mut self, event: crate::tui::widgets::events::ListEvent, item_count: usize, visible_height: usize) -> Option<usize> {
@@ -245,7 +392,99 @@ impl ListState {
                 self.handle_key(key, item_count, visible_height);
                 None
             }
+            ListEvent::Scroll(delta) => {
+                self.scroll_by(delta, item_count, visible_height);
+                None
+            }
             ListEvent::Select => self.selected,
+            ListEvent::FilterChanged(query) => {
+                // Matches are refreshed by apply_filter, which needs the items
+                self.filter = Some(query);
+                None
+            }
+            ListEvent::FilterCleared => {
+                self.clear_filter();
+                None
+            }
+            ListEvent::Reorder { from, to } => {
+                // Keep the selection on the item that moved
+                if self.selected == Some(from) && to < item_count {
+                    self.selected = Some(to);
+                    self.update_scroll(self.viewport_height.unwrap_or(visible_height), item_count);
+                }
+                None
+            }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tui::widgets::events::ListEvent;
+
+    struct Named(&'static str);
+
+    impl ListItem for Named {
+        type Msg = ();
+
+        fn to_element(&self, _is_selected: bool, _is_hovered: bool) -> Element<()> {
+            Element::text(self.0)
+        }
+
+        fn filter_text(&self) -> Option<String> {
+            Some(self.0.to_string())
+        }
+    }
+
+    #[test]
+    fn test_filter_restricts_navigation_to_matches() {
+        let items = [Named("account"), Named("contact"), Named("annotation"), Named("lead")];
+        let mut state = ListState::with_selection().with_wrap_around(false);
+
+        state.handle_event(ListEvent::FilterChanged("nta".to_string()), items.len(), 10);
+        state.apply_filter(&items);
+        assert_eq!(state.filter_matches(), Some(&[1, 2][..]));
+        assert_eq!(state.selected(), Some(1));
+
+        state.handle_key(KeyCode::Down, items.len(), 10);
+        assert_eq!(state.selected(), Some(2));
+        state.handle_key(KeyCode::Down, items.len(), 10);
+        assert_eq!(state.selected(), Some(2));
+
+        state.handle_event(ListEvent::FilterCleared, items.len(), 10);
+        state.handle_key(KeyCode::Down, items.len(), 10);
+        assert_eq!(state.selected(), Some(3));
+        assert_eq!(state.filter(), None);
+    }
+
+    #[test]
+    fn test_reorder_keeps_selection_on_moved_item() {
+        let mut state = ListState::new();
+        state.select(Some(2));
+
+        state.handle_event(ListEvent::Reorder { from: 2, to: 1 }, 4, 10);
+        assert_eq!(state.selected(), Some(1));
+
+        // Another item moving doesn't touch the selection
+        state.handle_event(ListEvent::Reorder { from: 3, to: 2 }, 4, 10);
+        assert_eq!(state.selected(), Some(1));
+    }
+
+    #[test]
+    fn test_scroll_stops_at_ends_and_drags_selection() {
+        let mut state = ListState::with_selection().with_scroll_off(1);
+
+        state.handle_event(ListEvent::Scroll(-3), 20, 5);
+        assert_eq!((state.scroll_offset(), state.selected()), (0, Some(0)));
+
+        state.handle_event(ListEvent::Scroll(6), 20, 5);
+        assert_eq!((state.scroll_offset(), state.selected()), (6, Some(7)));
+        // The selection stays put while the viewport keeps it in view
+        state.update_scroll(5, 20);
+        assert_eq!(state.scroll_offset(), 6);
+
+        state.handle_event(ListEvent::Scroll(100), 20, 5);
+        assert_eq!((state.scroll_offset(), state.selected()), (15, Some(16)));
+    }
+}
@@ -1,21 +1,31 @@
 pub mod autocomplete;
 pub mod color_picker;
+pub mod date_picker;
 pub mod events;
 pub mod fields;
+pub mod json_diff;
 pub mod file_browser;
 pub mod list;
+pub mod number_input;
 pub mod scrollable;
 pub mod select;
+pub mod table;
+pub mod text_area;
 pub mod text_input;
 pub mod tree;
 
-pub use autocomplete::AutocompleteState;
+pub use autocomplete::{AutocompleteMatch, AutocompleteState};
 pub use color_picker::{ColorPickerState, ColorPickerMode, Channel};
-pub use events::{AutocompleteEvent, ColorPickerEvent, FileBrowserEvent, ListEvent, SelectEvent, TextInputEvent, TreeEvent};
-pub use fields::{AutocompleteField, SelectField, TextInputField};
+pub use date_picker::{DatePickerState, DatePickerMode};
+pub use events::{AutocompleteEvent, ColorPickerEvent, DatePickerEvent, FileBrowserEvent, ListEvent, NumberInputEvent, SelectEvent, TableEvent, TextInputEvent, TreeEvent};
+pub use fields::{AutocompleteField, SelectField, TextAreaField, TextInputField};
+pub use json_diff::{JsonDiffNode, JsonDiffStatus};
 pub use file_browser::{FileBrowserState, FileBrowserEntry, FileBrowserAction};
 pub use list::{ListItem, ListState};
+pub use number_input::NumberInputState;
 pub use scrollable::ScrollableState;
 pub use select::SelectState;
+pub use table::{SortDirection, TableColumn, TableState};
+pub use text_area::TextAreaState;
 pub use text_input::TextInputState;
-pub use tree::{TreeItem, TableTreeItem, TreeState, FlatTableNode};
+pub use tree::{TreeItem, TableTreeItem, TreeState, TreeSnapshot, FlatTableNode, TreeRows};
@@ -0,0 +1,204 @@
+//! Number input widget state and logic
+
+use crossterm::event::KeyCode;
+use super::events::NumberInputEvent;
+
+/// Number input widget state
+///
+/// Holds the committed value and the text being typed. Up/Down step the value,
+/// PageUp/PageDown step ten times as far, and Home/End jump to the bounds.
+/// Typing only accepts digits (and a leading `-` when negatives are allowed);
+/// the text is clamped into range when committed.
+#[derive(Debug, Clone)]
+pub struct NumberInputState {
+    /// Last valid, in-range value
+    value: i64,
+
+    /// Text being edited
+    text: String,
+
+    min: Option<i64>,
+    max: Option<i64>,
+    step: i64,
+}
+
+impl Default for NumberInputState {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl NumberInputState {
+    /// Create a state holding `value`, unbounded with a step of 1
+    pub fn new(value: i64) -> Self {
+        Self {
+            value,
+            text: value.to_string(),
+            min: None,
+            max: None,
+            step: 1,
+        }
+    }
+
+    /// Set the allowed range (the current value is clamped into it)
+    pub fn bounds(mut self, min: Option<i64>, max: Option<i64>) -> Self {
+        self.min = min;
+        self.max = max;
+        self.set_value(self.value);
+        self
+    }
+
+    /// Set how far Up/Down move the value (PageUp/PageDown move ten steps)
+    pub fn step(mut self, step: i64) -> Self {
+        self.step = step.max(1);
+        self
+    }
+
+    /// Last committed value
+    pub fn value(&self) -> i64 {
+        self.value
+    }
+
+    /// Text shown in the input
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn min(&self) -> Option<i64> {
+        self.min
+    }
+
+    pub fn max(&self) -> Option<i64> {
+        self.max
+    }
+
+    /// Whether the typed text is a number within bounds
+    pub fn is_valid(&self) -> bool {
+        self.text.parse::<i64>().is_ok_and(|n| self.clamp(n) == n)
+    }
+
+    /// Set the value (clamped into bounds), replacing the typed text
+    pub fn set_value(&mut self, value: i64) {
+        self.value = self.clamp(value);
+        self.text = self.value.to_string();
+    }
+
+    /// Value `steps` steps away from the current one, clamped into bounds
+    pub fn stepped(&self, steps: i64) -> i64 {
+        self.clamp(self.current().saturating_add(self.step.saturating_mul(steps)))
+    }
+
+    /// Target of a navigation key (Up/Down/PageUp/PageDown/Home/End), if it is one
+    pub fn navigation_target(&self, key: KeyCode) -> Option<i64> {
+        match key {
+            KeyCode::Up => Some(self.stepped(1)),
+            KeyCode::Down => Some(self.stepped(-1)),
+            KeyCode::PageUp => Some(self.stepped(10)),
+            KeyCode::PageDown => Some(self.stepped(-10)),
+            KeyCode::Home => self.min,
+            KeyCode::End => self.max,
+            _ => None,
+        }
+    }
+
+    /// Commit the typed text, clamping it into bounds; unparseable text
+    /// falls back to the last value. Returns the committed value.
+    pub fn commit(&mut self) -> i64 {
+        let value = self.current();
+        self.set_value(value);
+        self.value
+    }
+
+    /// Apply a widget event
+    /// Returns Some(value) when the value changed
+    pub fn handle_event(&mut self, event: NumberInputEvent) -> Option<i64> {
+        let before = self.value;
+        match event {
+            NumberInputEvent::Key(key) => {
+                match self.navigation_target(key) {
+                    Some(target) => self.set_value(target),
+                    None => self.handle_key(key),
+                }
+            }
+            NumberInputEvent::Changed(value) => self.set_value(value),
+            NumberInputEvent::Submit => {
+                self.commit();
+            }
+        }
+        (self.value != before).then_some(self.value)
+    }
+
+    /// Edit the typed text; anything but digits, a leading `-` and Backspace is rejected
+    fn handle_key(&mut self, key: KeyCode) {
+        match key {
+            KeyCode::Char(c) if c.is_ascii_digit() => {
+                // Replace a lone "0" rather than building "05"
+                if self.text == "0" {
+                    self.text.clear();
+                }
+                self.text.push(c);
+            }
+            KeyCode::Char('-') if self.text.is_empty() && self.min.is_none_or(|min| min < 0) => {
+                self.text.push('-');
+            }
+            KeyCode::Backspace => {
+                self.text.pop();
+            }
+            _ => return,
+        }
+
+        // Typed values take effect as soon as they are in range
+        if let Ok(value) = self.text.parse::<i64>() {
+            if self.clamp(value) == value {
+                self.value = value;
+            }
+        }
+    }
+
+    /// The typed number, or the last value while the text isn't a number
+    fn current(&self) -> i64 {
+        self.text.parse().unwrap_or(self.value)
+    }
+
+    fn clamp(&self, value: i64) -> i64 {
+        let value = self.min.map_or(value, |min| value.max(min));
+        self.max.map_or(value, |max| value.min(max))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_steps_stay_in_bounds() {
+        let mut state = NumberInputState::new(95).bounds(Some(0), Some(100)).step(5);
+        assert_eq!(state.handle_event(NumberInputEvent::Key(KeyCode::Up)), Some(100));
+        assert_eq!(state.handle_event(NumberInputEvent::Key(KeyCode::Up)), None);
+        assert_eq!(state.handle_event(NumberInputEvent::Key(KeyCode::PageDown)), Some(50));
+        assert_eq!(state.handle_event(NumberInputEvent::Key(KeyCode::Home)), Some(0));
+        assert_eq!(state.text(), "0");
+
+        assert_eq!(NumberInputState::new(500).bounds(Some(0), Some(100)).value(), 100);
+    }
+
+    #[test]
+    fn test_typing_rejects_non_numeric_and_clamps_on_commit() {
+        let mut state = NumberInputState::new(0).bounds(Some(0), Some(720));
+        for c in ['8', 'x', '0', '-', '0'] {
+            state.handle_event(NumberInputEvent::Key(KeyCode::Char(c)));
+        }
+        assert_eq!(state.text(), "800");
+        assert!(!state.is_valid());
+        assert_eq!(state.value(), 80);
+
+        assert_eq!(state.handle_event(NumberInputEvent::Submit), Some(720));
+        assert_eq!(state.text(), "720");
+
+        let mut negative = NumberInputState::new(0).bounds(Some(-10), Some(10));
+        negative.handle_event(NumberInputEvent::Key(KeyCode::Backspace));
+        negative.handle_event(NumberInputEvent::Key(KeyCode::Char('-')));
+        negative.handle_event(NumberInputEvent::Key(KeyCode::Char('3')));
+        assert_eq!(negative.value(), -3);
+    }
+}
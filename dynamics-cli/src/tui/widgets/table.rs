@@ -0,0 +1,209 @@
+//! Flat data table state: row selection, the focused column and the sort order
+
+use ratatui::layout::{Alignment, Constraint};
+use std::cmp::Ordering;
+use super::events::TableEvent;
+use super::list::ListState;
+
+/// Rows assumed visible until the app reports the real viewport height
+const DEFAULT_VIEWPORT_HEIGHT: usize = 20;
+
+/// Sort order of the sorted column
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SortDirection {
+    #[default]
+    Ascending,
+    Descending,
+}
+
+impl SortDirection {
+    pub fn toggled(self) -> Self {
+        match self {
+            Self::Ascending => Self::Descending,
+            Self::Descending => Self::Ascending,
+        }
+    }
+
+    /// Indicator drawn after the sorted column's header
+    pub fn arrow(self) -> &'static str {
+        match self {
+            Self::Ascending => "▲",
+            Self::Descending => "▼",
+        }
+    }
+}
+
+/// Header, width and alignment of one table column
+#[derive(Debug, Clone)]
+pub struct TableColumn {
+    pub header: String,
+    pub width: Constraint,
+    pub alignment: Alignment,
+}
+
+impl TableColumn {
+    /// Left-aligned column sharing the leftover width
+    pub fn new(header: impl Into<String>) -> Self {
+        Self {
+            header: header.into(),
+            width: Constraint::Fill(1),
+            alignment: Alignment::Left,
+        }
+    }
+
+    /// Set the width constraint (default: `Fill(1)`)
+    pub fn width(mut self, width: Constraint) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Set the cell alignment (default: left)
+    pub fn align(mut self, alignment: Alignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+}
+
+/// Table widget state
+///
+/// The table only records which column is sorted and in which direction; the
+/// app re-sorts its rows when `on_sort` fires, usually with [`TableState::sort_rows`].
+#[derive(Debug, Clone)]
+pub struct TableState {
+    rows: ListState,
+    focused_column: usize,
+    sort_column: Option<usize>,
+    sort_direction: SortDirection,
+    viewport_height: usize,
+}
+
+impl Default for TableState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TableState {
+    /// Create an unsorted table state with the first row selected
+    pub fn new() -> Self {
+        Self {
+            rows: ListState::with_selection(),
+            focused_column: 0,
+            sort_column: None,
+            sort_direction: SortDirection::Ascending,
+            viewport_height: DEFAULT_VIEWPORT_HEIGHT,
+        }
+    }
+
+    pub fn selected(&self) -> Option<usize> {
+        self.rows.selected()
+    }
+
+    pub fn scroll_offset(&self) -> usize {
+        self.rows.scroll_offset()
+    }
+
+    pub fn focused_column(&self) -> usize {
+        self.focused_column
+    }
+
+    pub fn sort_column(&self) -> Option<usize> {
+        self.sort_column
+    }
+
+    pub fn sort_direction(&self) -> SortDirection {
+        self.sort_direction
+    }
+
+    /// Rows drawn below the header (report it from the table's `on_render`)
+    pub fn set_viewport_height(&mut self, height: usize) {
+        self.viewport_height = height.max(1);
+        self.rows.set_viewport_height(self.viewport_height);
+    }
+
+    /// Sort by `column`: ascending the first time, toggling on repeats
+    pub fn sort_by(&mut self, column: usize) {
+        if self.sort_column == Some(column) {
+            self.sort_direction = self.sort_direction.toggled();
+        } else {
+            self.sort_column = Some(column);
+            self.sort_direction = SortDirection::Ascending;
+        }
+    }
+
+    /// Sort `rows` by the sorted column; cells that both parse as numbers
+    /// compare numerically, anything else case-insensitively as text
+    pub fn sort_rows(&self, rows: &mut [Vec<String>]) {
+        let Some(column) = self.sort_column else { return };
+        fn cell(row: &[String], column: usize) -> &str {
+            row.get(column).map(String::as_str).unwrap_or("")
+        }
+        rows.sort_by(|a, b| {
+            let ordering = compare_cells(cell(a, column), cell(b, column));
+            match self.sort_direction {
+                SortDirection::Ascending => ordering,
+                SortDirection::Descending => ordering.reverse(),
+            }
+        });
+    }
+
+    /// Apply a widget event
+    /// Returns Some(row) when a row is chosen (Enter or click)
+    pub fn handle_event(&mut self, event: TableEvent, row_count: usize) -> Option<usize> {
+        match event {
+            TableEvent::Navigate(key) => {
+                self.rows.handle_key(key, row_count, self.viewport_height);
+                None
+            }
+            TableEvent::Scroll(delta) => {
+                self.rows.scroll_by(delta, row_count, self.viewport_height);
+                None
+            }
+            TableEvent::FocusColumn(column) => {
+                self.focused_column = column;
+                None
+            }
+            TableEvent::Select(row) => {
+                self.rows.select_and_scroll(Some(row), row_count);
+                Some(row)
+            }
+        }
+    }
+}
+
+fn compare_cells(a: &str, b: &str) -> Ordering {
+    match (a.trim().parse::<f64>(), b.trim().parse::<f64>()) {
+        (Ok(x), Ok(y)) => x.partial_cmp(&y).unwrap_or(Ordering::Equal),
+        _ => a.to_lowercase().cmp(&b.to_lowercase()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rows(values: &[&str]) -> Vec<Vec<String>> {
+        values.iter().map(|v| vec![v.to_string()]).collect()
+    }
+
+    #[test]
+    fn test_sort_by_toggles_and_sorts_numbers_numerically() {
+        let mut state = TableState::new();
+        state.sort_by(0);
+        assert_eq!(state.sort_direction(), SortDirection::Ascending);
+
+        let mut data = rows(&["10", "9", "100"]);
+        state.sort_rows(&mut data);
+        assert_eq!(data, rows(&["9", "10", "100"]));
+
+        state.sort_by(0);
+        assert_eq!(state.sort_direction(), SortDirection::Descending);
+        let mut data = rows(&["beta", "Alpha", "gamma"]);
+        state.sort_rows(&mut data);
+        assert_eq!(data, rows(&["gamma", "beta", "Alpha"]));
+
+        // A new column starts ascending again
+        state.sort_by(2);
+        assert_eq!((state.sort_column(), state.sort_direction()), (Some(2), SortDirection::Ascending));
+    }
+}
@@ -0,0 +1,327 @@
+use crossterm::event::KeyCode;
+
+/// Manages cursor and vertical scrolling state for multi-line text
+#[derive(Debug, Clone)]
+pub struct TextAreaState {
+    cursor_row: usize,      // Line index
+    cursor_col: usize,      // Character index within the line
+    scroll_offset: usize,   // First visible line
+    visible_rows: usize,    // Height of the text area in rows
+}
+
+impl Default for TextAreaState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TextAreaState {
+    /// Create a new TextAreaState with cursor at start and 8 visible rows
+    pub fn new() -> Self {
+        Self {
+            cursor_row: 0,
+            cursor_col: 0,
+            scroll_offset: 0,
+            visible_rows: 8,
+        }
+    }
+
+    /// Set how many rows the text area occupies
+    pub fn with_visible_rows(mut self, rows: usize) -> Self {
+        self.visible_rows = rows.max(1);
+        self
+    }
+
+    /// Get current cursor line
+    pub fn cursor_row(&self) -> usize {
+        self.cursor_row
+    }
+
+    /// Get current cursor column within the line
+    pub fn cursor_col(&self) -> usize {
+        self.cursor_col
+    }
+
+    /// Get first visible line
+    pub fn scroll_offset(&self) -> usize {
+        self.scroll_offset
+    }
+
+    /// Get height of the text area in rows
+    pub fn visible_rows(&self) -> usize {
+        self.visible_rows
+    }
+
+    /// Set cursor position to the end of the given text
+    pub fn set_cursor_to_end(&mut self, text: &str) {
+        let lines = split_lines(text);
+        self.cursor_row = lines.len() - 1;
+        self.cursor_col = lines[self.cursor_row].len();
+        self.update_scroll();
+    }
+
+    /// Handle a key press and update text value
+    /// Returns Some(new_value) if text changed, None if only cursor moved
+    pub fn handle_key(
+        &mut self,
+        key: KeyCode,
+        current_value: &str,
+        max_length: Option<usize>,
+    ) -> Option<String> {
+        let mut lines = split_lines(current_value);
+        self.cursor_row = self.cursor_row.min(lines.len() - 1);
+        self.cursor_col = self.cursor_col.min(lines[self.cursor_row].len());
+
+        let at_max = max_length.is_some_and(|max| current_value.chars().count() >= max);
+
+        let changed = match key {
+            KeyCode::Char(_) | KeyCode::Enter if at_max => false,
+            KeyCode::Char(c) => {
+                lines[self.cursor_row].insert(self.cursor_col, c);
+                self.cursor_col += 1;
+                true
+            }
+            KeyCode::Enter => {
+                // Split the line at the cursor
+                let rest = lines[self.cursor_row].split_off(self.cursor_col);
+                lines.insert(self.cursor_row + 1, rest);
+                self.cursor_row += 1;
+                self.cursor_col = 0;
+                true
+            }
+            KeyCode::Backspace => {
+                if self.cursor_col > 0 {
+                    lines[self.cursor_row].remove(self.cursor_col - 1);
+                    self.cursor_col -= 1;
+                    true
+                } else if self.cursor_row > 0 {
+                    // Join with the previous line
+                    let line = lines.remove(self.cursor_row);
+                    self.cursor_row -= 1;
+                    self.cursor_col = lines[self.cursor_row].len();
+                    lines[self.cursor_row].extend(line);
+                    true
+                } else {
+                    false
+                }
+            }
+            KeyCode::Delete => {
+                if self.cursor_col < lines[self.cursor_row].len() {
+                    lines[self.cursor_row].remove(self.cursor_col);
+                    true
+                } else if self.cursor_row + 1 < lines.len() {
+                    // Join the next line onto this one
+                    let line = lines.remove(self.cursor_row + 1);
+                    lines[self.cursor_row].extend(line);
+                    true
+                } else {
+                    false
+                }
+            }
+            KeyCode::Left => {
+                if self.cursor_col > 0 {
+                    self.cursor_col -= 1;
+                } else if self.cursor_row > 0 {
+                    self.cursor_row -= 1;
+                    self.cursor_col = lines[self.cursor_row].len();
+                }
+                false
+            }
+            KeyCode::Right => {
+                if self.cursor_col < lines[self.cursor_row].len() {
+                    self.cursor_col += 1;
+                } else if self.cursor_row + 1 < lines.len() {
+                    self.cursor_row += 1;
+                    self.cursor_col = 0;
+                }
+                false
+            }
+            KeyCode::Up => {
+                self.move_rows(&lines, -1);
+                false
+            }
+            KeyCode::Down => {
+                self.move_rows(&lines, 1);
+                false
+            }
+            KeyCode::PageUp => {
+                self.move_rows(&lines, -(self.visible_rows as isize));
+                false
+            }
+            KeyCode::PageDown => {
+                self.move_rows(&lines, self.visible_rows as isize);
+                false
+            }
+            KeyCode::Home => {
+                self.cursor_col = 0;
+                false
+            }
+            KeyCode::End => {
+                self.cursor_col = lines[self.cursor_row].len();
+                false
+            }
+            _ => false,
+        };
+
+        self.update_scroll();
+
+        if changed {
+            Some(join_lines(&lines))
+        } else {
+            None
+        }
+    }
+
+    /// Insert pasted text (which may span lines) at the cursor
+    /// Text past max_length is dropped. Returns Some(new_value) if text changed
+    pub fn insert_str(&mut self, text: &str, current_value: &str, max_length: Option<usize>) -> Option<String> {
+        let room = max_length.map_or(usize::MAX, |max| max.saturating_sub(current_value.chars().count()));
+        let pasted: String = text.replace("\r\n", "\n").chars().take(room).collect();
+        if pasted.is_empty() {
+            return None;
+        }
+
+        let mut lines = split_lines(current_value);
+        self.cursor_row = self.cursor_row.min(lines.len() - 1);
+        self.cursor_col = self.cursor_col.min(lines[self.cursor_row].len());
+
+        // Splice the pasted lines in between the text before and after the cursor
+        let rest = lines[self.cursor_row].split_off(self.cursor_col);
+        let mut pasted_lines = split_lines(&pasted).into_iter();
+        if let Some(first) = pasted_lines.next() {
+            lines[self.cursor_row].extend(first);
+        }
+        for line in pasted_lines {
+            self.cursor_row += 1;
+            lines.insert(self.cursor_row, line);
+        }
+        self.cursor_col = lines[self.cursor_row].len();
+        lines[self.cursor_row].extend(rest);
+
+        self.update_scroll();
+        Some(join_lines(&lines))
+    }
+
+    /// Move the cursor up (negative) or down by whole lines, clamping the column
+    fn move_rows(&mut self, lines: &[Vec<char>], delta: isize) {
+        let last = lines.len() as isize - 1;
+        self.cursor_row = (self.cursor_row as isize + delta).clamp(0, last) as usize;
+        self.cursor_col = self.cursor_col.min(lines[self.cursor_row].len());
+    }
+
+    /// Update scroll offset to keep the cursor line visible
+    ///
+    /// Wrapped lines can still push the cursor below the last row; the renderer
+    /// scrolls further in that case.
+    fn update_scroll(&mut self) {
+        if self.cursor_row < self.scroll_offset {
+            self.scroll_offset = self.cursor_row;
+        } else if self.cursor_row >= self.scroll_offset + self.visible_rows {
+            self.scroll_offset = self.cursor_row + 1 - self.visible_rows;
+        }
+    }
+}
+
+/// A slice of a logical line that fits on one row
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WrappedRow {
+    pub line: usize,
+    pub start_col: usize,
+    pub text: String,
+}
+
+/// Break text into rows of at most `width` characters
+///
+/// Every logical line produces at least one row, so empty lines stay visible.
+pub fn wrap_lines(text: &str, width: usize) -> Vec<WrappedRow> {
+    let width = width.max(1);
+    let mut rows = Vec::new();
+
+    for (line, chars) in split_lines(text).into_iter().enumerate() {
+        if chars.is_empty() {
+            rows.push(WrappedRow { line, start_col: 0, text: String::new() });
+            continue;
+        }
+        for (i, chunk) in chars.chunks(width).enumerate() {
+            rows.push(WrappedRow { line, start_col: i * width, text: chunk.iter().collect() });
+        }
+    }
+
+    rows
+}
+
+fn split_lines(text: &str) -> Vec<Vec<char>> {
+    text.split('\n').map(|line| line.chars().collect()).collect()
+}
+
+fn join_lines(lines: &[Vec<char>]) -> String {
+    lines
+        .iter()
+        .map(|line| line.iter().collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn type_keys(state: &mut TextAreaState, value: &mut String, keys: &[KeyCode]) {
+        for key in keys {
+            if let Some(new_value) = state.handle_key(*key, value, None) {
+                *value = new_value;
+            }
+        }
+    }
+
+    #[test]
+    fn test_enter_splits_and_backspace_joins_lines() {
+        let mut state = TextAreaState::new();
+        let mut value = String::from("ab");
+        state.set_cursor_to_end(&value);
+
+        type_keys(&mut state, &mut value, &[KeyCode::Left, KeyCode::Enter]);
+        assert_eq!(value, "a\nb");
+        assert_eq!((state.cursor_row(), state.cursor_col()), (1, 0));
+
+        type_keys(&mut state, &mut value, &[KeyCode::Backspace]);
+        assert_eq!(value, "ab");
+        assert_eq!((state.cursor_row(), state.cursor_col()), (0, 1));
+    }
+
+    #[test]
+    fn test_up_down_clamp_column_and_scroll() {
+        let mut state = TextAreaState::new().with_visible_rows(2);
+        let mut value = String::from("long line\nab\nthird");
+        state.set_cursor_to_end(&value);
+        assert_eq!(state.scroll_offset(), 1);
+
+        type_keys(&mut state, &mut value, &[KeyCode::Up]);
+        assert_eq!((state.cursor_row(), state.cursor_col()), (1, 2));
+
+        type_keys(&mut state, &mut value, &[KeyCode::Up, KeyCode::End]);
+        assert_eq!((state.cursor_row(), state.cursor_col()), (0, 9));
+        assert_eq!(state.scroll_offset(), 0);
+    }
+
+    #[test]
+    fn test_paste_spans_lines_and_keeps_text_after_cursor() {
+        let mut state = TextAreaState::new();
+        let mut value = String::from("ab");
+        state.set_cursor_to_end(&value);
+        type_keys(&mut state, &mut value, &[KeyCode::Left]);
+
+        value = state.insert_str("1\r\n2", &value, None).unwrap();
+
+        assert_eq!(value, "a1\n2b");
+        assert_eq!((state.cursor_row(), state.cursor_col()), (1, 1));
+    }
+
+    #[test]
+    fn test_wrap_lines_keeps_empty_lines() {
+        let rows = wrap_lines("abcde\n\nxy", 2);
+        let texts: Vec<_> = rows.iter().map(|r| (r.line, r.start_col, r.text.as_str())).collect();
+
+        assert_eq!(texts, vec![(0, 0, "ab"), (0, 2, "cd"), (0, 4, "e"), (1, 0, ""), (2, 0, "xy")]);
+    }
+}
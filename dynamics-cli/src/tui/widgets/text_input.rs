@@ -1,10 +1,12 @@
 use crossterm::event::KeyCode;
+use super::events::TextInputEvent;
 
-/// Manages text input cursor and scrolling state
+/// Manages text input cursor, selection and scrolling state
 #[derive(Debug, Clone)]
 pub struct TextInputState {
     cursor_pos: usize,      // Character index (0 = before first char)
     scroll_offset: usize,   // For horizontal scrolling when text > width
+    selection_anchor: Option<usize>,  // Other end of the selection; the cursor is the moving end
 }
 
 impl Default for TextInputState {
@@ -19,6 +21,7 @@ impl TextInputState {
         Self {
             cursor_pos: 0,
             scroll_offset: 0,
+            selection_anchor: None,
         }
     }
 
@@ -32,12 +35,79 @@ impl TextInputState {
         self.scroll_offset
     }
 
+    /// Get the selected character range (start, end), if any text is selected
+    pub fn selection(&self) -> Option<(usize, usize)> {
+        match self.selection_anchor {
+            Some(anchor) if anchor != self.cursor_pos => {
+                Some((anchor.min(self.cursor_pos), anchor.max(self.cursor_pos)))
+            }
+            _ => None,
+        }
+    }
+
+    /// Get the selected part of the given text
+    pub fn selected_text(&self, text: &str) -> Option<String> {
+        let (start, end) = self.selection()?;
+        Some(text.chars().skip(start).take(end - start).collect())
+    }
+
+    /// Select the whole text, leaving the cursor at the end
+    pub fn select_all(&mut self, text: &str) {
+        self.selection_anchor = Some(0);
+        self.cursor_pos = text.chars().count();
+    }
+
+    /// Drop the selection without moving the cursor
+    pub fn clear_selection(&mut self) {
+        self.selection_anchor = None;
+    }
+
     /// Set cursor position to the end of the given text
     pub fn set_cursor_to_end(&mut self, text: &str) {
         self.cursor_pos = text.chars().count();
+        self.selection_anchor = None;
+    }
+
+    /// Apply a widget event (Submit is left to the caller)
+    /// Returns Some(new_value) if text changed
+    pub fn handle_event(
+        &mut self,
+        event: TextInputEvent,
+        current_value: &str,
+        max_length: Option<usize>,
+    ) -> Option<String> {
+        match event {
+            TextInputEvent::Changed(key) => self.handle_key(key, current_value, max_length),
+            TextInputEvent::Paste(text) => self.insert_str(&text, current_value, max_length),
+            TextInputEvent::Select(key) => {
+                self.handle_select_key(key, current_value);
+                None
+            }
+            TextInputEvent::SelectAll => {
+                self.select_all(current_value);
+                None
+            }
+            TextInputEvent::Submit => None,
+        }
+    }
+
+    /// Move the cursor and extend the selection (Shift+Left/Right/Home/End)
+    pub fn handle_select_key(&mut self, key: KeyCode, current_value: &str) {
+        let char_count = current_value.chars().count();
+        let cursor = self.cursor_pos.min(char_count);
+        let target = match key {
+            KeyCode::Left => cursor.saturating_sub(1),
+            KeyCode::Right => (cursor + 1).min(char_count),
+            KeyCode::Home => 0,
+            KeyCode::End => char_count,
+            _ => return,
+        };
+        self.selection_anchor.get_or_insert(cursor);
+        self.cursor_pos = target;
     }
 
     /// Handle a key press and update text value
+    /// Typing, Backspace and Delete replace the selection; arrows collapse it.
     /// Returns Some(new_value) if text changed, None if only cursor moved
     pub fn handle_key(
         &mut self,
@@ -45,6 +115,32 @@ impl TextInputState {
         current_value: &str,
         max_length: Option<usize>,
     ) -> Option<String> {
+        if let Some((start, end)) = self.selection() {
+            self.selection_anchor = None;
+            match key {
+                KeyCode::Char(_) | KeyCode::Backspace | KeyCode::Delete => {
+                    let mut chars: Vec<char> = current_value.chars().collect();
+                    chars.drain(start..end.min(chars.len()));
+                    self.cursor_pos = start;
+                    if let KeyCode::Char(c) = key {
+                        chars.insert(start, c);
+                        self.cursor_pos += 1;
+                    }
+                    return Some(chars.into_iter().collect());
+                }
+                KeyCode::Left => {
+                    self.cursor_pos = start;
+                    return None;
+                }
+                KeyCode::Right => {
+                    self.cursor_pos = end;
+                    return None;
+                }
+                _ => {}
+            }
+        }
+        self.selection_anchor = None;
+
         let char_count = current_value.chars().count();
 
         match key {
@@ -111,6 +207,35 @@ impl TextInputState {
         }
     }
 
+    /// Insert pasted text at the cursor, replacing the selection
+    /// Line breaks become spaces; text past max_length is dropped.
+    /// Returns Some(new_value) if text changed
+    pub fn insert_str(&mut self, text: &str, current_value: &str, max_length: Option<usize>) -> Option<String> {
+        let mut chars: Vec<char> = current_value.chars().collect();
+        if let Some((start, end)) = self.selection() {
+            chars.drain(start..end.min(chars.len()));
+            self.cursor_pos = start;
+        }
+        self.selection_anchor = None;
+        let room = max_length.map_or(usize::MAX, |max| max.saturating_sub(chars.len()));
+        let pasted: Vec<char> = text
+            .trim_end_matches(['\r', '\n'])
+            .replace("\r\n", " ")
+            .chars()
+            .map(|c| if c == '\n' || c == '\r' { ' ' } else { c })
+            .take(room)
+            .collect();
+
+        if pasted.is_empty() && chars.len() == current_value.chars().count() {
+            return None;
+        }
+
+        let cursor = self.cursor_pos.min(chars.len());
+        self.cursor_pos = cursor + pasted.len();
+        chars.splice(cursor..cursor, pasted);
+        Some(chars.into_iter().collect())
+    }
+
     /// Update scroll offset to keep cursor visible
     /// Called during rendering
     pub fn update_scroll(&mut self, visible_width: usize, text: &str) {
@@ -131,6 +256,41 @@ impl TextInputState {
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shift_selection_is_replaced_by_typing() {
+        let mut state = TextInputState::new();
+        let value = "hello world";
+        state.set_cursor_to_end(value);
+
+        for _ in 0..5 {
+            state.handle_select_key(KeyCode::Left, value);
+        }
+        assert_eq!(state.selection(), Some((6, 11)));
+        assert_eq!(state.selected_text(value).as_deref(), Some("world"));
+
+        let value = state.handle_key(KeyCode::Char('x'), value, None).unwrap();
+        assert_eq!(value, "hello x");
+        assert_eq!(state.cursor_pos(), 7);
+        assert_eq!(state.selection(), None);
+    }
+
+    #[test]
+    fn test_select_all_then_delete_and_arrow_collapse() {
+        let mut state = TextInputState::new();
+        state.select_all("abc");
+        assert_eq!(state.handle_key(KeyCode::Delete, "abc", None).as_deref(), Some(""));
+
+        state.set_cursor_to_end("abc");
+        state.handle_select_key(KeyCode::Home, "abc");
+        assert_eq!(state.handle_key(KeyCode::Right, "abc", None), None);
+        assert_eq!((state.cursor_pos(), state.selection()), (3, None));
+    }
+}
+
 /*
  * ============================================================================
  * PHASE 2 / V2 FEATURES (not yet implemented)
@@ -139,16 +299,10 @@ impl TextInputState {
  * The following features are deferred to a future version:
  *
  * 1. TEXT SELECTION
- *    - Shift+Left/Right to select characters
- *    - Ctrl+A to select all
- *    - Visual highlight of selected text
- *    - Delete selection on typing
+ *    - Shift+Ctrl+Left/Right to select by word
  *
  * 2. CLIPBOARD OPERATIONS
- *    - Ctrl+C to copy
- *    - Ctrl+X to cut
- *    - Ctrl+V to paste
- *    - Integration with system clipboard
+ *    - Ctrl+X to cut the selection
  *
  * 3. WORD NAVIGATION
  *    - Ctrl+Left/Right to jump by word
@@ -1,5 +1,7 @@
 use crossterm::event::KeyCode;
 use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
+use serde::{Deserialize, Serialize};
 use crate::tui::{Element, Theme};
 
 /// Trait for items that can be displayed in a tree
@@ -69,6 +71,7 @@ pub trait TableTreeItem: TreeItem {
 pub struct TreeState {
     // Core state
     expanded: HashSet<String>,      // IDs of expanded nodes
+    pending_expanded: Option<HashSet<String>>,  // Restored IDs, applied once the tree is known
     selected: Option<String>,        // Selected node ID (primary/anchor)
     scroll_offset: usize,
     scroll_off: usize,               // Scrolloff distance (vim-like)
@@ -83,6 +86,12 @@ pub struct TreeState {
     node_depths: HashMap<String, usize>,     // id → depth
     visible_order: Vec<String>,              // DFS order of visible nodes
     cache_valid: bool,                       // Whether cache needs rebuild
+
+    // Table columns (TableTree only)
+    focused_column: usize,
+    column_offset: usize,                    // First visible column when scrolled horizontally
+    resizing_columns: bool,
+    column_widths: Option<Vec<ratatui::layout::Constraint>>,  // Widths changed by the user
 }
 
 impl Default for TreeState {
@@ -96,6 +105,7 @@ impl TreeState {
     pub fn new() -> Self {
         Self {
             expanded: HashSet::new(),
+            pending_expanded: None,
             selected: None,
             scroll_offset: 0,
             scroll_off: 5,
@@ -106,9 +116,33 @@ impl TreeState {
             node_depths: HashMap::new(),
             visible_order: vec![],
             cache_valid: false,
+            focused_column: 0,
+            column_offset: 0,
+            resizing_columns: false,
+            column_widths: None,
         }
     }
 
+    /// Get focused table column
+    pub fn focused_column(&self) -> usize {
+        self.focused_column
+    }
+
+    /// Get first visible table column
+    pub fn column_offset(&self) -> usize {
+        self.column_offset
+    }
+
+    /// Check if table columns are being resized
+    pub fn is_resizing_columns(&self) -> bool {
+        self.resizing_columns
+    }
+
+    /// Get column widths set by the user, if any
+    pub fn column_widths(&self) -> Option<&[ratatui::layout::Constraint]> {
+        self.column_widths.as_deref()
+    }
+
     /// Set the viewport height (called by renderer with actual area height)
     pub fn set_viewport_height(&mut self, height: usize) {
         self.viewport_height = Some(height);
@@ -180,6 +214,40 @@ impl TreeState {
         self.cache_valid = false;
     }
 
+    /// IDs of all expanded nodes, e.g. to carry expansion across a data reload
+    pub fn expanded_ids(&self) -> Vec<String> {
+        let mut ids: Vec<String> = self.expanded.iter().cloned().collect();
+        ids.sort();
+        ids
+    }
+
+    /// Expand the given nodes again after a reload
+    ///
+    /// The IDs are applied the next time the tree is flattened; IDs that no longer
+    /// exist in the tree are dropped.
+    pub fn restore_expanded(&mut self, ids: impl IntoIterator<Item = String>) {
+        self.pending_expanded.get_or_insert_with(HashSet::new).extend(ids);
+        self.cache_valid = false;
+    }
+
+    /// Capture expansion, selection and scroll position before a reload
+    pub fn snapshot(&self) -> TreeSnapshot {
+        TreeSnapshot {
+            expanded: self.expanded_ids(),
+            selected: self.selected.clone(),
+            scroll_offset: self.scroll_offset,
+        }
+    }
+
+    /// Re-apply a snapshot taken with `snapshot()`
+    ///
+    /// Selection and scroll are kept only if the selected node still exists.
+    pub fn restore(&mut self, snapshot: TreeSnapshot) {
+        self.restore_expanded(snapshot.expanded);
+        self.selected = snapshot.selected;
+        self.scroll_offset = snapshot.scroll_offset;
+    }
+
     /// Toggle expansion of a node
     pub fn toggle(&mut self, node_id: &str) {
         if self.expanded.contains(node_id) {
@@ -445,6 +513,22 @@ impl TreeState {
         }
     }
 
+    /// Scroll the viewport by `delta` rows (mouse wheel), dragging the selection along
+    pub fn scroll_by(&mut self, delta: isize) {
+        let Some(height) = self.viewport_height else {
+            return;
+        };
+        let selected_idx = self.selected.as_ref()
+            .and_then(|selected| self.visible_order.iter().position(|id| id == selected));
+        let (offset, selected_idx) = crate::tui::widgets::list::scroll_viewport(
+            self.scroll_offset, delta, self.visible_order.len(), height, self.scroll_off, selected_idx,
+        );
+        self.scroll_offset = offset;
+        if let Some(idx) = selected_idx {
+            self.selected = Some(self.visible_order[idx].clone());
+        }
+    }
+
     /// Update scroll offset based on selection and visible height
     pub fn update_scroll(&mut self, visible_height: usize) {
         if let Some(selected) = &self.selected {
@@ -487,6 +571,10 @@ impl TreeState {
                 self.handle_key(key);
                 None
             }
+            TreeEvent::Scroll(delta) => {
+                self.scroll_by(delta);
+                None
+            }
             TreeEvent::Toggle => {
                 // Toggle current selection
                 if let Some(id) = self.selected.clone() {
@@ -520,6 +608,19 @@ impl TreeState {
                 self.extend_selection_down();
                 None
             }
+            TreeEvent::FocusColumn { column, first_visible } => {
+                self.focused_column = column;
+                self.column_offset = first_visible;
+                None
+            }
+            TreeEvent::ToggleColumnResize => {
+                self.resizing_columns = !self.resizing_columns;
+                None
+            }
+            TreeEvent::ResizeColumns(widths) => {
+                self.column_widths = Some(widths);
+                None
+            }
         }
     }
 
@@ -533,6 +634,15 @@ impl TreeState {
         self.node_depths.clear();
         self.visible_order.clear();
 
+        // Apply restored expansion once there is a tree to check the IDs against
+        if !root_items.is_empty() && let Some(pending) = self.pending_expanded.take() {
+            let mut existing = HashSet::new();
+            for item in root_items {
+                collect_ids(item, &mut existing);
+            }
+            self.expanded.extend(pending.into_iter().filter(|id| existing.contains(id)));
+        }
+
         for item in root_items {
             self.build_metadata_recursive(item, None, 0);
         }
@@ -542,8 +652,9 @@ impl TreeState {
         // Validate current selection - clear if it no longer exists
         if let Some(selected) = &self.selected {
             if !self.visible_order.contains(selected) {
-                // Selected item no longer exists in tree, clear selection
+                // Selected item no longer exists in tree, clear selection and start from the top
                 self.selected = None;
+                self.scroll_offset = 0;
             }
         }
 
@@ -581,11 +692,64 @@ impl TreeState {
     }
 }
 
+/// Every node ID in the tree, expanded or not
+fn collect_ids<T: TreeItem>(item: &T, ids: &mut HashSet<String>) {
+    ids.insert(item.id());
+    if item.has_children() {
+        for child in item.children() {
+            collect_ids(&child, ids);
+        }
+    }
+}
+
+/// Tree view state saved across a data reload
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TreeSnapshot {
+    expanded: Vec<String>,
+    selected: Option<String>,
+    scroll_offset: usize,
+}
+
 /// Internal structure for flattened tree nodes
-pub(crate) struct FlatNode<Msg> {
-    pub id: String,
-    pub element: Element<Msg>,
-    pub depth: usize,
+struct FlatNode<T> {
+    item: T,
+    depth: usize,
+    is_selected: bool,
+    is_multi_selected: bool,
+    is_expanded: bool,
+}
+
+/// Flattened tree rows whose elements are built on demand
+///
+/// Large trees only pay for the rows the renderer actually draws, instead of
+/// turning every visible node into an Element each frame.
+pub struct TreeRows<Msg> {
+    len: usize,
+    build: Rc<dyn Fn(usize) -> Element<Msg>>,
+}
+
+impl<Msg> Clone for TreeRows<Msg> {
+    fn clone(&self) -> Self {
+        Self {
+            len: self.len,
+            build: Rc::clone(&self.build),
+        }
+    }
+}
+
+impl<Msg> TreeRows<Msg> {
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Build the element for the row at `index` (in display order)
+    pub fn row(&self, index: usize) -> Element<Msg> {
+        (self.build)(index)
+    }
 }
 
 /// Internal structure for flattened table tree nodes
@@ -599,48 +763,62 @@ pub struct FlatTableNode {
 }
 
 /// Flatten tree into displayable nodes based on expansion state
-pub(crate) fn flatten_tree<T: TreeItem>(
+///
+/// Returns the node IDs in display order alongside rows that render each node
+/// lazily through `TreeItem::to_element`.
+pub(crate) fn flatten_tree<T: TreeItem + 'static>(
     root_items: &[T],
     state: &mut TreeState,
-) -> Vec<FlatNode<T::Msg>> {
+) -> (Vec<String>, TreeRows<T::Msg>) {
     // Rebuild metadata cache if invalid
     if !state.cache_valid {
         state.rebuild_metadata(root_items);
     }
 
-    let mut result = vec![];
+    let mut node_ids = vec![];
+    let mut nodes = vec![];
     for item in root_items {
-        flatten_recursive(item, state, 0, &mut result);
+        flatten_recursive(item.clone(), state, 0, &mut node_ids, &mut nodes);
     }
-    result
+
+    let rows = TreeRows {
+        len: nodes.len(),
+        build: Rc::new(move |index| {
+            let node: &FlatNode<T> = &nodes[index];
+            node.item.to_element(node.depth, node.is_selected, node.is_multi_selected, node.is_expanded)
+        }),
+    };
+    (node_ids, rows)
 }
 
 fn flatten_recursive<T: TreeItem>(
-    item: &T,
+    item: T,
     state: &TreeState,
     depth: usize,
-    result: &mut Vec<FlatNode<T::Msg>>,
+    node_ids: &mut Vec<String>,
+    nodes: &mut Vec<FlatNode<T>>,
 ) {
     let id = item.id();
     let is_expanded = state.is_expanded(&id);
-    let is_selected = state.selected() == Some(&id);
-    let is_multi_selected = state.is_multi_selected(&id);
-    let has_children = item.has_children();
-
-    // Render node (delegates to TreeItem::to_element)
-    let element = item.to_element(depth, is_selected, is_multi_selected, is_expanded);
 
-    result.push(FlatNode {
-        id: id.clone(),
-        element,
+    // Collect children before the item moves into the list
+    let children = if is_expanded && item.has_children() {
+        item.children()
+    } else {
+        Vec::new()
+    };
+
+    nodes.push(FlatNode {
+        is_selected: state.selected() == Some(&id),
+        is_multi_selected: state.is_multi_selected(&id),
+        item,
         depth,
+        is_expanded,
     });
+    node_ids.push(id);
 
-    // Recursively flatten children if expanded
-    if is_expanded && has_children {
-        for child in item.children() {
-            flatten_recursive(&child, state, depth + 1, result);
-        }
+    for child in children {
+        flatten_recursive(child, state, depth + 1, node_ids, nodes);
     }
 }
 
@@ -690,3 +868,84 @@ fn flatten_table_recursive<T: TableTreeItem>(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    thread_local! {
+        static BUILT: Cell<usize> = const { Cell::new(0) };
+    }
+
+    #[derive(Clone)]
+    struct Node {
+        id: String,
+        children: usize,
+    }
+
+    impl TreeItem for Node {
+        type Msg = ();
+
+        fn id(&self) -> String {
+            self.id.clone()
+        }
+
+        fn has_children(&self) -> bool {
+            self.children > 0
+        }
+
+        fn children(&self) -> Vec<Self> {
+            (0..self.children)
+                .map(|i| Node { id: format!("{}/{}", self.id, i), children: 0 })
+                .collect()
+        }
+
+        fn to_element(&self, depth: usize, _: bool, _: bool, _: bool) -> Element<()> {
+            BUILT.with(|built| built.set(built.get() + 1));
+            Element::text(format!("{}{}", "  ".repeat(depth), self.id))
+        }
+    }
+
+    #[test]
+    fn test_flatten_builds_rows_only_on_demand() {
+        let roots: Vec<Node> = (0..100).map(|i| Node { id: i.to_string(), children: 99 }).collect();
+        let mut state = TreeState::new();
+        state.expand("7");
+
+        let (node_ids, rows) = flatten_tree(&roots, &mut state);
+
+        assert_eq!(node_ids.len(), 199);
+        assert_eq!(rows.len(), node_ids.len());
+        assert_eq!(BUILT.with(Cell::get), 0);
+
+        // Rows line up with node IDs, children indented under their parent
+        assert_eq!(node_ids[8], "7/0");
+        match rows.row(8) {
+            Element::Text { content, .. } => assert_eq!(content, "  7/0"),
+            _ => panic!("expected a text row"),
+        }
+        assert_eq!(BUILT.with(Cell::get), 1);
+    }
+
+    #[test]
+    fn test_restore_reapplies_existing_expansion_and_selection() {
+        let roots: Vec<Node> = (0..3).map(|i| Node { id: i.to_string(), children: 2 }).collect();
+        let mut state = TreeState::new();
+        state.expand("0");
+        state.expand("2");
+        flatten_tree(&roots, &mut state);
+        state.select(Some("2/1".to_string()));
+        let snapshot = state.snapshot();
+
+        // Reload: fresh state, and node "0" is gone from the new data
+        let reloaded: Vec<Node> = (1..3).map(|i| Node { id: i.to_string(), children: 2 }).collect();
+        let mut state = TreeState::new();
+        state.restore(snapshot);
+        let (node_ids, _) = flatten_tree(&reloaded, &mut state);
+
+        assert_eq!(node_ids, ["1", "2", "2/0", "2/1"]);
+        assert_eq!(state.expanded_ids(), ["2"]);
+        assert_eq!(state.selected(), Some("2/1"));
+    }
+}
@@ -3,7 +3,8 @@
 //! Provides simple functions for:
 //! - Checking current version
 //! - Checking for available updates
-//! - Installing updates
+//! - Installing updates (checksum-verified, keeping a backup of the old binary)
+//! - Rolling back to the backed up binary
 //!
 //! Used by both CLI and TUI (future)
 
@@ -39,30 +40,59 @@ pub enum UpdateProgress {
     Complete,
 }
 
+/// Which GitHub releases count as updates
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum)]
+pub enum Channel {
+    /// Only the latest stable release (default)
+    #[default]
+    Stable,
+    /// Pre-releases too, whichever has the highest version
+    Prerelease,
+}
+
+impl Channel {
+    /// Option key the chosen channel is persisted under
+    pub const OPTION_KEY: &'static str = "update.channel";
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Stable => "stable",
+            Self::Prerelease => "prerelease",
+        }
+    }
+
+    fn from_str(value: &str) -> Self {
+        match value {
+            "prerelease" => Self::Prerelease,
+            _ => Self::Stable,
+        }
+    }
+}
+
+/// Channel saved in the options, stable if unset or unreadable
+pub async fn configured_channel() -> Channel {
+    crate::global_config().options.get_string(Channel::OPTION_KEY).await
+        .map(|value| Channel::from_str(&value))
+        .unwrap_or_default()
+}
+
+/// Save the channel used by later checks, including the background one
+pub async fn set_channel(channel: Channel) -> Result<()> {
+    crate::global_config().options.set_string(Channel::OPTION_KEY, channel.as_str().to_string()).await
+}
+
 /// Get the current version of this binary
 pub fn current_version() -> &'static str {
     env!("CARGO_PKG_VERSION")
 }
 
-/// Check for available updates from GitHub releases
-pub async fn check_for_updates() -> Result<UpdateInfo> {
+/// Check for available updates from GitHub releases on `channel`
+pub async fn check_for_updates(channel: Channel) -> Result<UpdateInfo> {
     let current = current_version();
 
     // Run blocking self_update calls in a blocking thread pool
     tokio::task::spawn_blocking(move || {
-        // Build the updater
-        let updater = self_update::backends::github::Update::configure()
-            .repo_owner(REPO_OWNER)
-            .repo_name(REPO_NAME)
-            .bin_name(BIN_NAME)
-            .current_version(current)
-            .build()
-            .context("Failed to configure update checker")?;
-
-        // Get the latest release
-        let latest_release = updater
-            .get_latest_release()
-            .context("Failed to fetch latest release from GitHub")?;
+        let latest_release = fetch_release(None, channel, current)?;
 
         let latest = latest_release.version.trim_start_matches('v');
         let current_ver = Version::parse(current).context("Invalid current version")?;
@@ -82,58 +112,45 @@ pub async fn check_for_updates() -> Result<UpdateInfo> {
     .context("Task panicked while checking for updates")?
 }
 
+/// Background check run when the TUI starts
+///
+/// Opt-in through `update.check_on_startup` and throttled to once a day by the
+/// last check timestamp. Returns the release info only when a newer version
+/// exists; failures are logged and otherwise ignored.
+pub async fn startup_check() -> Option<UpdateInfo> {
+    let config = crate::global_config();
+    if !config.options.get_bool("update.check_on_startup").await.unwrap_or(false) {
+        return None;
+    }
+    if !crate::config::repository::update_metadata::should_check_for_updates(&config.pool).await.unwrap_or(false) {
+        return None;
+    }
+
+    let info = match check_for_updates(configured_channel().await).await {
+        Ok(info) => info,
+        Err(e) => {
+            log::debug!("Startup update check failed: {}", e);
+            return None;
+        }
+    };
+    if let Err(e) = crate::config::repository::update_metadata::set_last_check_time(&config.pool, chrono::Utc::now()).await {
+        log::warn!("Failed to save update check time: {}", e);
+    }
+
+    info.needs_update.then_some(info)
+}
+
 /// Install the latest update
 ///
 /// # Arguments
+/// * `channel` - Which releases to consider
 /// * `show_progress` - Whether to show download progress (for CLI)
 ///
 /// # Returns
 /// The version that was installed
-pub async fn install_update(show_progress: bool) -> Result<String> {
-    let current = current_version();
-
-    log::info!("Installing update from {}/{}", REPO_OWNER, REPO_NAME);
-
-    // Run blocking self_update calls in a blocking thread pool
-    tokio::task::spawn_blocking(move || {
-        // On Windows, zip archives have flat structure (no subdirectory)
-        // Use .identifier(".zip") to prefer zip over msi
-        #[cfg(target_os = "windows")]
-        let status = self_update::backends::github::Update::configure()
-            .repo_owner(REPO_OWNER)
-            .repo_name(REPO_NAME)
-            .bin_name(BIN_NAME)
-            .show_download_progress(show_progress)
-            .current_version(current)
-            .bin_path_in_archive("{{ bin }}")  // Windows: flat structure
-            .identifier(".zip")
-            .build()
-            .context("Failed to configure updater")?
-            .update()
-            .map_err(|e| {
-                log::error!("self_update failed: {}", e);
-                anyhow::anyhow!("Failed to install update: {}", e)
-            })?;
-
-        // On Unix, tar.gz archives have subdirectory structure
-        #[cfg(not(target_os = "windows"))]
-        let status = self_update::backends::github::Update::configure()
-            .repo_owner(REPO_OWNER)
-            .repo_name(REPO_NAME)
-            .bin_name(BIN_NAME)
-            .show_download_progress(show_progress)
-            .current_version(current)
-            .bin_path_in_archive("{{ bin }}-{{ target }}/{{ bin }}")  // Unix: has subdirectory
-            .build()
-            .context("Failed to configure updater")?
-            .update()
-            .context("Failed to install update")?;
-
-        log::info!("Updated to version: {}", status.version());
-        Ok(status.version().to_string())
-    })
-    .await
-    .context("Task panicked while installing update")?
+pub async fn install_update(channel: Channel, show_progress: bool) -> Result<String> {
+    log::info!("Installing {} update from {}/{}", channel.as_str(), REPO_OWNER, REPO_NAME);
+    install_release(None, channel, show_progress).await
 }
 
 /// Install a specific version
@@ -142,53 +159,194 @@ pub async fn install_update(show_progress: bool) -> Result<String> {
 /// * `version` - The version to install (e.g., "0.2.0")
 /// * `show_progress` - Whether to show download progress
 pub async fn install_version(version: &str, show_progress: bool) -> Result<String> {
-    let current = current_version();
-    let version = version.to_string();
-
     log::info!("Installing version {} from {}/{}", version, REPO_OWNER, REPO_NAME);
+    install_release(Some(format!("v{}", version)), Channel::Stable, show_progress).await
+}
 
-    // Run blocking self_update calls in a blocking thread pool
-    tokio::task::spawn_blocking(move || {
-        // On Windows, zip archives have flat structure (no subdirectory)
-        // Use .identifier(".zip") to prefer zip over msi
-        #[cfg(target_os = "windows")]
-        let status = self_update::backends::github::Update::configure()
-            .repo_owner(REPO_OWNER)
-            .repo_name(REPO_NAME)
-            .bin_name(BIN_NAME)
-            .show_download_progress(show_progress)
-            .current_version(current)
-            .target_version_tag(&format!("v{}", version))
-            .bin_path_in_archive("{{ bin }}")  // Windows: flat structure
-            .identifier(".zip")
-            .build()
-            .context("Failed to configure updater")?
-            .update()
-            .map_err(|e| {
-                log::error!("self_update failed: {}", e);
-                anyhow::anyhow!("Failed to install version: {}", e)
-            })?;
-
-        // On Unix, tar.gz archives have subdirectory structure
-        #[cfg(not(target_os = "windows"))]
-        let status = self_update::backends::github::Update::configure()
+/// Restore the binary saved before the last update
+///
+/// # Returns
+/// The version that was restored
+pub async fn rollback() -> Result<String> {
+    tokio::task::spawn_blocking(|| {
+        let backup = backup_path()?;
+        if !backup.exists() {
+            anyhow::bail!("No backup found at {}; nothing to roll back to", backup.display());
+        }
+        let version = std::fs::read_to_string(backup.with_extension("version"))
+            .map(|v| v.trim().to_string())
+            .unwrap_or_else(|_| "unknown".to_string());
+
+        self_update::self_replace::self_replace(&backup)
+            .context("Failed to restore the previous binary")?;
+        // The backup now is the running binary; a second rollback would be a no-op
+        std::fs::remove_file(&backup).ok();
+        std::fs::remove_file(backup.with_extension("version")).ok();
+
+        log::info!("Rolled back to version: {}", version);
+        Ok(version)
+    })
+    .await
+    .context("Task panicked while rolling back")?
+}
+
+/// Whether a backup from a previous update is available for `rollback`
+pub fn has_backup() -> bool {
+    backup_path().is_ok_and(|path| path.exists())
+}
+
+// On Windows, zip archives have flat structure (no subdirectory)
+// Use the .zip asset, not the msi
+#[cfg(target_os = "windows")]
+const ARCHIVE_EXTENSION: &str = ".zip";
+#[cfg(target_os = "windows")]
+fn bin_path_in_archive(_target: &str) -> String {
+    format!("{}.exe", BIN_NAME)
+}
+
+// On Unix, tar.gz archives have subdirectory structure
+#[cfg(not(target_os = "windows"))]
+const ARCHIVE_EXTENSION: &str = ".tar.gz";
+#[cfg(not(target_os = "windows"))]
+fn bin_path_in_archive(target: &str) -> String {
+    format!("{}-{}/{}", BIN_NAME, target, BIN_NAME)
+}
+
+/// Fetch a release by tag (e.g. "v0.2.0"), or the latest on `channel` (blocking)
+fn fetch_release(tag: Option<&str>, channel: Channel, current: &str) -> Result<self_update::update::Release> {
+    use self_update::update::ReleaseUpdate;
+
+    if tag.is_none() && channel == Channel::Prerelease {
+        // `releases/latest` skips pre-releases, so look through the full list
+        let releases = self_update::backends::github::ReleaseList::configure()
             .repo_owner(REPO_OWNER)
             .repo_name(REPO_NAME)
-            .bin_name(BIN_NAME)
-            .show_download_progress(show_progress)
-            .current_version(current)
-            .target_version_tag(&format!("v{}", version))
-            .bin_path_in_archive("{{ bin }}-{{ target }}/{{ bin }}")  // Unix: has subdirectory
             .build()
-            .context("Failed to configure updater")?
-            .update()
-            .context("Failed to install version")?;
+            .context("Failed to configure release list")?
+            .fetch()
+            .context("Failed to fetch releases from GitHub")?;
+        return newest_release(releases).context("No releases found on GitHub");
+    }
+
+    let updater = self_update::backends::github::Update::configure()
+        .repo_owner(REPO_OWNER)
+        .repo_name(REPO_NAME)
+        .bin_name(BIN_NAME)
+        .current_version(current)
+        .build()
+        .context("Failed to configure updater")?;
+    match tag {
+        Some(tag) => updater.get_release_version(tag),
+        None => updater.get_latest_release(),
+    }
+    .context("Failed to fetch latest release from GitHub")
+}
+
+/// Release with the highest semver version; unparsable tags are skipped
+fn newest_release(releases: Vec<self_update::update::Release>) -> Option<self_update::update::Release> {
+    releases.into_iter()
+        .filter_map(|release| Version::parse(release.version.trim_start_matches('v')).ok().map(|v| (v, release)))
+        .max_by(|(a, _), (b, _)| a.cmp(b))
+        .map(|(_, release)| release)
+}
+
+/// Download, verify and install a release, backing up the current binary first
+///
+/// `tag` selects a release by tag (e.g. "v0.2.0"); `None` installs the latest on `channel`.
+async fn install_release(tag: Option<String>, channel: Channel, show_progress: bool) -> Result<String> {
+    let current = current_version();
+
+    // Run blocking self_update calls in a blocking thread pool
+    tokio::task::spawn_blocking(move || {
+        let release = fetch_release(tag.as_deref(), channel, current)?;
+
+        let target = self_update::get_target();
+        let asset = release.assets.iter()
+            .find(|a| a.name.contains(target) && a.name.ends_with(ARCHIVE_EXTENSION))
+            .with_context(|| format!("No {} asset found for target {}", ARCHIVE_EXTENSION, target))?;
+        let checksum_name = format!("{}.sha256", asset.name);
+        let checksum_asset = release.assets.iter()
+            .find(|a| a.name == checksum_name)
+            .with_context(|| format!("Release v{} has no published checksum ({})", release.version, checksum_name))?;
+
+        let tmp_dir = self_update::TempDir::new().context("Failed to create temporary directory")?;
+        let archive_path = tmp_dir.path().join(&asset.name);
+
+        let archive = std::fs::File::create(&archive_path).context("Failed to create download file")?;
+        download(&asset.download_url, show_progress, archive).context("Failed to download release")?;
+        let mut checksum = Vec::new();
+        download(&checksum_asset.download_url, false, &mut checksum).context("Failed to download checksum")?;
+
+        // Nothing has been touched yet, so a mismatch leaves the install as it was
+        verify_checksum(&std::fs::read(&archive_path)?, &String::from_utf8_lossy(&checksum))?;
+
+        let bin_path = bin_path_in_archive(target);
+        self_update::Extract::from_source(&archive_path)
+            .extract_file(tmp_dir.path(), &bin_path)
+            .context("Failed to extract binary from archive")?;
 
-        log::info!("Installed version: {}", status.version());
-        Ok(status.version().to_string())
+        backup_current_binary(current)?;
+        self_update::self_replace::self_replace(tmp_dir.path().join(&bin_path))
+            .context("Failed to replace the current binary")?;
+
+        let version = release.version.trim_start_matches('v').to_string();
+        log::info!("Installed version: {}", version);
+        Ok(version)
     })
     .await
-    .context("Task panicked while installing version")?
+    .context("Task panicked while installing update")?
+}
+
+fn download(url: &str, show_progress: bool, dest: impl std::io::Write) -> Result<()> {
+    let mut download = self_update::Download::from_url(url);
+    download.show_progress(show_progress);
+    download.set_header(
+        reqwest::header::ACCEPT,
+        reqwest::header::HeaderValue::from_static("application/octet-stream"),
+    );
+    download.download_to(dest)?;
+    Ok(())
+}
+
+/// Compare the SHA256 of `data` with a published checksum file
+///
+/// The file holds the hex digest, optionally followed by the file name
+/// (`sha256sum` format).
+fn verify_checksum(data: &[u8], published: &str) -> Result<()> {
+    use sha2::{Digest, Sha256};
+
+    let expected = published.split_whitespace().next()
+        .context("Published checksum is empty")?
+        .to_lowercase();
+    let actual = format!("{:x}", Sha256::digest(data));
+
+    if actual != expected {
+        anyhow::bail!("Checksum mismatch: expected {}, got {}", expected, actual);
+    }
+    Ok(())
+}
+
+/// Backup of the binary replaced by the last update, kept next to the config database
+fn backup_path() -> Result<std::path::PathBuf> {
+    let db_path = crate::config::Config::get_db_path()?;
+    let dir = db_path.parent().context("Config database has no parent directory")?;
+    Ok(dir.join("backup").join(format!("{}{}", BIN_NAME, std::env::consts::EXE_SUFFIX)))
+}
+
+fn backup_current_binary(version: &str) -> Result<()> {
+    let backup = backup_path()?;
+    if let Some(dir) = backup.parent() {
+        std::fs::create_dir_all(dir)
+            .with_context(|| format!("Failed to create backup directory: {}", dir.display()))?;
+    }
+    let exe = std::env::current_exe().context("Failed to locate the current binary")?;
+    std::fs::copy(&exe, &backup)
+        .with_context(|| format!("Failed to back up {} to {}", exe.display(), backup.display()))?;
+    std::fs::write(backup.with_extension("version"), version)
+        .context("Failed to record the backed up version")?;
+
+    log::info!("Backed up version {} to {}", version, backup.display());
+    Ok(())
 }
 
 #[cfg(test)]
@@ -202,4 +360,32 @@ mod tests {
         // Should be a valid semver
         Version::parse(version).expect("Current version should be valid semver");
     }
+
+    #[test]
+    fn test_newest_release_includes_prereleases() {
+        let release = |version: &str| self_update::update::Release {
+            version: version.to_string(),
+            ..Default::default()
+        };
+
+        let newest = newest_release(vec![release("0.8.0"), release("0.9.0-beta.1"), release("nightly")]);
+        assert_eq!(newest.unwrap().version, "0.9.0-beta.1");
+
+        // A stable release beats its own pre-releases
+        let newest = newest_release(vec![release("0.9.0-beta.2"), release("0.9.0")]);
+        assert_eq!(newest.unwrap().version, "0.9.0");
+
+        assert!(newest_release(vec![release("nightly")]).is_none());
+    }
+
+    #[test]
+    fn test_verify_checksum() {
+        // sha256("hello")
+        let digest = "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824";
+
+        assert!(verify_checksum(b"hello", digest).is_ok());
+        assert!(verify_checksum(b"hello", &format!("{}  *dynamics-cli.tar.gz\n", digest.to_uppercase())).is_ok());
+        assert!(verify_checksum(b"hellO", digest).unwrap_err().to_string().contains("Checksum mismatch"));
+        assert!(verify_checksum(b"hello", "  ").is_err());
+    }
 }
@@ -119,6 +119,12 @@ fn infer_event_type_and_handler(
             };
             (quote! { crate::tui::widgets::TextInputEvent }, handle)
         }
+        "TextAreaField" => {
+            let handle = quote! {
+                self.#field_name.handle_event(event.clone(), None);
+            };
+            (quote! { crate::tui::widgets::TextInputEvent }, handle)
+        }
         "AutocompleteField" => {
             let options = if let Some(expr) = options_expr {
                 let tokens: proc_macro2::TokenStream = expr.parse().unwrap();
@@ -39,8 +39,8 @@ pub fn test_fql_to_xml(fql: &str, expected_xml: &str) -> Result<()> {
     let ast = parse(tokens, fql)?;
     println!("AST: {:?}", ast);
 
-    // Step 3: Generate XML
-    let xml = to_fetchxml(ast)?;
+    // Step 3: Generate XML (one document per union branch)
+    let xml = to_fetchxml(ast)?.join("\n");
     println!("Generated XML:\n{}", xml);
     println!("Expected XML:\n{}", expected_xml);
 